@@ -1,4 +1,7 @@
-use crate::{eth::subscription::SubscriptionId, types::ReorgOptions};
+use crate::{
+    eth::subscription::SubscriptionId,
+    types::{L1BlockValues, ReorgOptions},
+};
 use alloy_primitives::{
     Address, B64, B256, Bytes, TxHash, U256,
     map::{HashMap, HashSet},
@@ -17,8 +20,9 @@
     },
 };
 use alloy_serde::WithOtherFields;
-use foundry_common::serde_helpers::{
-    deserialize_number, deserialize_number_opt, deserialize_number_seq,
+use foundry_common::{
+    provider::failure::RpcFailure,
+    serde_helpers::{deserialize_number, deserialize_number_opt, deserialize_number_seq},
 };
 
 pub mod block;
@@ -375,6 +379,10 @@ pub enum EthRequest {
     #[serde(rename = "anvil_impersonateSignature", with = "sequence")]
     ImpersonateSignature(Bytes, Address),
 
+    /// Sets the result of the EIP-1271 `isValidSignature` function of a contract for a hash
+    #[serde(rename = "anvil_setERC1271Result")]
+    SetERC1271Result(Address, B256, bool),
+
     /// Returns true if automatic mining is enabled, and false.
     #[serde(rename = "anvil_getAutomine", alias = "hardhat_getAutomine", with = "empty_params")]
     GetAutoMine(()),
@@ -424,6 +432,10 @@ pub enum EthRequest {
     #[serde(rename = "anvil_setRpcUrl", with = "sequence")]
     SetRpcUrl(String),
 
+    /// Injects failures into the requests sent to the fork endpoint
+    #[serde(rename = "anvil_injectRpcFailure", with = "sequence")]
+    InjectRpcFailure(RpcFailure),
+
     /// Modifies the balance of an account.
     #[serde(
         rename = "anvil_setBalance",
@@ -507,6 +519,14 @@ pub enum EthRequest {
     )]
     SetNextBlockBaseFeePerGas(U256),
 
+    /// Sets the parent beacon block root of the next block
+    #[serde(rename = "anvil_setBeaconRoot", with = "sequence")]
+    SetBeaconRoot(B256),
+
+    /// Sets the L1 attributes of the OP-stack `L1Block` predeploy
+    #[serde(rename = "anvil_setL1BlockValues", with = "sequence")]
+    SetL1BlockValues(L1BlockValues),
+
     /// Sets the specific timestamp
     /// Accepts timestamp (Unix epoch) with millisecond precision and returns the number of seconds
     /// between the given timestamp and the current time.
@@ -534,6 +554,10 @@ pub enum EthRequest {
     #[serde(rename = "anvil_metadata", alias = "hardhat_metadata", with = "empty_params")]
     AnvilMetadata(()),
 
+    /// Retrieves the named accounts set up at genesis with `--accounts-config`
+    #[serde(rename = "anvil_listNamedAccounts", with = "empty_params")]
+    ListNamedAccounts(()),
+
     // Ganache compatible calls
     /// Snapshot the state of the blockchain at the current block.
     ///
@@ -934,6 +958,21 @@ fn test_custom_drop_tx() {
         let _req = serde_json::from_value::<EthRequest>(value).unwrap();
     }
 
+    #[test]
+    fn test_custom_inject_rpc_failure() {
+        let s = r#"{"method": "anvil_injectRpcFailure", "params": [{"count": 3, "methods": ["eth_getStorageAt"]}]}"#;
+        let value: serde_json::Value = serde_json::from_str(s).unwrap();
+        let req = serde_json::from_value::<EthRequest>(value).unwrap();
+        match req {
+            EthRequest::InjectRpcFailure(failure) => {
+                assert_eq!(failure.count, Some(3));
+                assert_eq!(failure.methods, vec!["eth_getStorageAt".to_string()]);
+                assert_eq!(failure.status, None);
+            }
+            _ => unreachable!(),
+        }
+    }
+
     #[test]
     fn test_custom_reset() {
         let s = r#"{"method": "anvil_reset", "params": [{"forking": {"jsonRpcUrl": "https://ethereumpublicnode.com",
@@ -1103,6 +1142,22 @@ fn test_custom_set_code() {
         let _req = serde_json::from_value::<EthRequest>(value).unwrap();
     }
 
+    #[test]
+    fn test_custom_list_named_accounts() {
+        let s = r#"{"method": "anvil_listNamedAccounts", "params": []}"#;
+        let value: serde_json::Value = serde_json::from_str(s).unwrap();
+        let req = serde_json::from_value::<EthRequest>(value).unwrap();
+        assert!(matches!(req, EthRequest::ListNamedAccounts(())));
+    }
+
+    #[test]
+    fn test_custom_set_erc1271_result() {
+        let s = r#"{"method": "anvil_setERC1271Result", "params":
+["0xd84de507f3fada7df80908082d3239466db55a71", "0x1234567890123456789012345678901234567890123456789012345678901234", true]}"#;
+        let value: serde_json::Value = serde_json::from_str(s).unwrap();
+        let _req = serde_json::from_value::<EthRequest>(value).unwrap();
+    }
+
     #[test]
     fn test_custom_set_nonce() {
         let s = r#"{"method": "anvil_setNonce", "params":
@@ -1135,6 +1190,31 @@ fn test_serde_custom_set_storage_at() {
         let _req = serde_json::from_value::<EthRequest>(value).unwrap();
     }
 
+    #[test]
+    fn test_serde_custom_beacon_root() {
+        let s = r#"{"method": "anvil_setBeaconRoot", "params":
+["0x4a3b5c2fb5b4c0e04a6d1b3f4f39d1df2e8c4f5d6f8e4a07ac2a49d6b1e1d5aa"]}"#;
+        let value: serde_json::Value = serde_json::from_str(s).unwrap();
+        let _req = serde_json::from_value::<EthRequest>(value).unwrap();
+    }
+
+    #[test]
+    fn test_serde_custom_set_l1_block_values() {
+        let s = r#"{"method": "anvil_setL1BlockValues", "params":
+[{"basefee": "0x3b9aca00", "baseFeeScalar": 1368, "blobBaseFeeScalar": 810949}]}"#;
+        let value: serde_json::Value = serde_json::from_str(s).unwrap();
+        let req = serde_json::from_value::<EthRequest>(value).unwrap();
+        match req {
+            EthRequest::SetL1BlockValues(values) => {
+                assert_eq!(values.basefee, Some(U256::from(1_000_000_000)));
+                assert_eq!(values.base_fee_scalar, Some(1368));
+                assert_eq!(values.blob_base_fee_scalar, Some(810949));
+                assert_eq!(values.number, None);
+            }
+            _ => unreachable!(),
+        }
+    }
+
     #[test]
     fn test_serde_custom_coinbase() {
         let s = r#"{"method": "anvil_setCoinbase", "params":
@@ -1,6 +1,6 @@
-use alloy_primitives::Bytes;
+use alloy_primitives::{B256, Bytes, U256};
 use alloy_rpc_types::TransactionRequest;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Represents the options used in `anvil_reorg`
 #[derive(Debug, Clone, Deserialize)]
@@ -18,3 +18,37 @@ pub enum TransactionData {
     JSON(TransactionRequest),
     Raw(Bytes),
 }
+
+/// The L1 attributes of the OP-stack `L1Block` predeploy, used in `anvil_setL1BlockValues`
+///
+/// Only the set values are updated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct L1BlockValues {
+    /// The L1 block number
+    pub number: Option<u64>,
+    /// The L1 block timestamp
+    pub timestamp: Option<u64>,
+    /// The L1 base fee
+    pub basefee: Option<U256>,
+    /// The L1 block hash
+    pub hash: Option<B256>,
+    /// The number of L2 blocks since the start of the epoch
+    pub sequence_number: Option<u64>,
+    /// The scalar of the L1 blob base fee, since Ecotone
+    pub blob_base_fee_scalar: Option<u32>,
+    /// The scalar of the L1 base fee, since Ecotone
+    pub base_fee_scalar: Option<u32>,
+    /// The versioned hash of the batcher address
+    pub batcher_hash: Option<B256>,
+    /// The L1 fee overhead, before Ecotone
+    pub l1_fee_overhead: Option<U256>,
+    /// The L1 fee scalar, before Ecotone
+    pub l1_fee_scalar: Option<U256>,
+    /// The L1 blob base fee
+    pub blob_base_fee: Option<U256>,
+    /// The operator fee constant, since Isthmus
+    pub operator_fee_constant: Option<u64>,
+    /// The operator fee scalar, since Isthmus
+    pub operator_fee_scalar: Option<u32>,
+}
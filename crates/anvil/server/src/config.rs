@@ -6,7 +6,7 @@
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "clap", derive(clap::Parser), command(next_help_heading = "Server options"))]
 pub struct ServerConfig {
-    /// The cors `allow_origin` header
+    /// The cors `allow_origin` header, or a comma separated list of allowed origins.
     #[cfg_attr(feature = "clap", arg(long, default_value = "*"))]
     pub allow_origin: HeaderValueWrapper,
 
@@ -14,9 +14,37 @@ pub struct ServerConfig {
     #[cfg_attr(feature = "clap", arg(long, conflicts_with = "allow_origin"))]
     pub no_cors: bool,
 
+    /// Additional request headers allowed by CORS, e.g. `authorization` when behind a proxy.
+    #[cfg_attr(
+        feature = "clap",
+        arg(long, value_name = "HEADERS", value_delimiter = ',', conflicts_with = "no_cors")
+    )]
+    #[serde(default)]
+    pub cors_allow_headers: Vec<String>,
+
+    /// How long browsers may cache the CORS preflight responses, in seconds.
+    #[cfg_attr(feature = "clap", arg(long, value_name = "SECONDS", conflicts_with = "no_cors"))]
+    pub cors_max_age: Option<u64>,
+
     /// Disable the default request body size limit. At time of writing the default limit is 2MB.
     #[cfg_attr(feature = "clap", arg(long))]
     pub no_request_size_limit: bool,
+
+    /// The maximum size of a request body, in bytes, instead of the default limit.
+    #[cfg_attr(
+        feature = "clap",
+        arg(long, value_name = "BYTES", conflicts_with = "no_request_size_limit")
+    )]
+    pub max_request_size: Option<usize>,
+
+    /// Log every HTTP and WS request with its methods, params size, latency and origin.
+    #[cfg_attr(feature = "clap", arg(long))]
+    #[serde(default)]
+    pub log_requests: bool,
+
+    /// Log the HTTP and WS requests taking longer than this many milliseconds as slow requests.
+    #[cfg_attr(feature = "clap", arg(long, value_name = "MS"))]
+    pub slow_request_threshold: Option<u64>,
 }
 
 impl ServerConfig {
@@ -31,6 +59,18 @@ pub const fn set_cors(mut self, cors: bool) -> Self {
         self.no_cors = !cors;
         self
     }
+
+    /// Sets the maximum size of a request body, in bytes.
+    pub const fn with_max_request_size(mut self, max_request_size: usize) -> Self {
+        self.max_request_size = Some(max_request_size);
+        self
+    }
+
+    /// Whether to log every HTTP and WS request.
+    pub const fn set_log_requests(mut self, log_requests: bool) -> Self {
+        self.log_requests = log_requests;
+        self
+    }
 }
 
 impl Default for ServerConfig {
@@ -38,7 +78,12 @@ fn default() -> Self {
         Self {
             allow_origin: "*".parse::<HeaderValue>().unwrap().into(),
             no_cors: false,
+            cors_allow_headers: Vec::new(),
+            cors_max_age: None,
             no_request_size_limit: false,
+            max_request_size: None,
+            log_requests: false,
+            slow_request_threshold: None,
         }
     }
 }
@@ -1,29 +1,126 @@
 use crate::RpcHandler;
 use anvil_rpc::{
     error::RpcError,
-    request::{Request, RpcCall},
+    request::{Request, RequestParams, RpcCall},
     response::{Response, RpcResponse},
 };
 use axum::{
-    Json,
+    Extension, Json,
     extract::{State, rejection::JsonRejection},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode, header},
     response::{IntoResponse, Response as AxumResponse},
 };
 use futures::{FutureExt, future};
+use std::time::{Duration, Instant};
+
+/// The target of the request logs, which are displayed along with the other node logs.
+const REQUEST_LOG_TARGET: &str = "node::user";
+
+/// Logging of the HTTP and WS requests.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct RequestLog {
+    /// Whether to log every request.
+    pub all: bool,
+    /// The latency above which requests are logged as slow requests.
+    pub slow_threshold: Option<Duration>,
+}
+
+impl RequestLog {
+    pub(crate) const fn is_enabled(&self) -> bool {
+        self.all || self.slow_threshold.is_some()
+    }
+
+    /// Handles the request, and logs it along with the `origin` of the caller if enabled.
+    pub(crate) async fn handle_request<Handler: RpcHandler>(
+        self,
+        req: Request,
+        origin: Option<&str>,
+        handler: Handler,
+    ) -> Option<Response> {
+        let summary = self.is_enabled().then(|| RequestSummary::new(&req, origin));
+        let start = Instant::now();
+        let response = handle_request(req, handler).await;
+        if let Some(summary) = summary {
+            self.log(&summary, start.elapsed());
+        }
+        response
+    }
+
+    fn log(&self, summary: &RequestSummary, latency: Duration) {
+        let RequestSummary { methods, params_size, origin } = summary;
+        let latency_ms = latency.as_millis();
+        if self.slow_threshold.is_some_and(|threshold| latency >= threshold) {
+            warn!(target: REQUEST_LOG_TARGET, %methods, params_size, latency_ms, ?origin, "slow request");
+        } else if self.all {
+            info!(target: REQUEST_LOG_TARGET, %methods, params_size, latency_ms, ?origin, "request");
+        }
+    }
+}
+
+/// The logged fields of a request.
+#[derive(Debug, PartialEq, Eq)]
+struct RequestSummary {
+    /// The methods called, comma separated for batches.
+    methods: String,
+    /// The size of the serialized params of all calls, in bytes.
+    params_size: usize,
+    /// The `Origin` of the caller, or its address forwarded by a proxy.
+    origin: Option<String>,
+}
+
+impl RequestSummary {
+    fn new(request: &Request, origin: Option<&str>) -> Self {
+        let calls = match request {
+            Request::Single(call) => std::slice::from_ref(call),
+            Request::Batch(calls) => calls.as_slice(),
+        };
+        let mut methods = Vec::with_capacity(calls.len());
+        let mut params_size = 0;
+        for call in calls {
+            let (method, params) = match call {
+                RpcCall::MethodCall(call) => (call.method.as_str(), &call.params),
+                RpcCall::Notification(notification) => {
+                    (notification.method.as_str(), &notification.params)
+                }
+                RpcCall::Invalid { .. } => ("<invalid>", &RequestParams::None),
+            };
+            methods.push(method);
+            if !matches!(params, RequestParams::None) {
+                params_size += serde_json::to_vec(params).map_or(0, |params| params.len());
+            }
+        }
+        Self { methods: methods.join(","), params_size, origin: origin.map(ToString::to_string) }
+    }
+}
+
+/// Returns the `Origin` of the caller, or its address forwarded by a proxy.
+pub(crate) fn request_origin(headers: &HeaderMap) -> Option<&str> {
+    [header::ORIGIN.as_str(), "x-forwarded-for"]
+        .into_iter()
+        .find_map(|name| headers.get(name)?.to_str().ok())
+}
 
 /// Handles incoming JSON-RPC Request.
 // NOTE: `handler` must come first because the `request` extractor consumes the request body.
 pub async fn handle<Http: RpcHandler, Ws>(
     State((handler, _)): State<(Http, Ws)>,
+    Extension(log): Extension<RequestLog>,
+    headers: HeaderMap,
     request: Result<Json<Request>, JsonRejection>,
 ) -> AxumResponse {
     match request {
-        Ok(Json(req)) => handle_request(req, handler)
+        Ok(Json(req)) => log
+            .handle_request(req, request_origin(&headers), handler)
             .await
             .map(Json)
             .map(IntoResponse::into_response)
             .unwrap_or_else(|| StatusCode::NO_CONTENT.into_response()),
+        // Reject the requests over the body size limit at the HTTP level, so that proxies can
+        // tell them apart from invalid calls
+        Err(err) if err.status() == StatusCode::PAYLOAD_TOO_LARGE => {
+            warn!(target: "rpc", ?err, "request too large");
+            err.into_response()
+        }
         Err(err) => {
             warn!(target: "rpc", ?err, "invalid request");
             Json(Response::error(RpcError::invalid_request())).into_response()
@@ -80,7 +177,7 @@ async fn handle_call<Handler: RpcHandler>(call: RpcCall, handler: Handler) -> Op
 mod tests {
     use super::*;
     use anvil_rpc::{
-        request::{RequestParams, RpcNotification, Version},
+        request::{Id, RpcMethodCall, RpcNotification, Version},
         response::ResponseResult,
     };
     use axum::body::to_bytes;
@@ -119,6 +216,31 @@ fn run_ready<F: Future>(future: F) -> F::Output {
         }
     }
 
+    #[test]
+    fn summarizes_request_for_logs() {
+        let call = RpcCall::MethodCall(RpcMethodCall {
+            jsonrpc: Version::V2,
+            method: "eth_getBalance".to_owned(),
+            params: RequestParams::Array(vec!["0x01".into(), "latest".into()]),
+            id: Id::Number(1),
+        });
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", "10.0.0.1".parse().unwrap());
+
+        let summary = RequestSummary::new(
+            &Request::Batch(vec![call, notification()]),
+            request_origin(&headers),
+        );
+        assert_eq!(
+            summary,
+            RequestSummary {
+                methods: "eth_getBalance,eth_subscribe".to_string(),
+                params_size: r#"["0x01","latest"]"#.len(),
+                origin: Some("10.0.0.1".to_string()),
+            }
+        );
+    }
+
     #[test]
     fn empty_batch_returns_invalid_request() {
         let response = run_ready(handle_request(Request::Batch(vec![]), TestHandler));
@@ -137,6 +259,8 @@ fn notification_only_batch_returns_no_response() {
     fn http_notification_only_batch_returns_no_content() {
         let response = run_ready(handle(
             State((TestHandler, ())),
+            Extension(RequestLog::default()),
+            HeaderMap::new(),
             Ok(Json(Request::Batch(vec![notification()]))),
         ));
 
@@ -12,14 +12,17 @@
     response::{ResponseResult, RpcResponse},
 };
 use axum::{
-    Router,
+    Extension, Router,
     extract::DefaultBodyLimit,
-    http::{HeaderValue, Method, header},
+    http::{HeaderName, HeaderValue, Method, header},
     routing::{MethodRouter, post},
 };
 use serde::de::DeserializeOwned;
-use std::fmt;
-use tower_http::{cors::CorsLayer, trace::TraceLayer};
+use std::{fmt, time::Duration};
+use tower_http::{
+    cors::{AllowOrigin, CorsLayer},
+    trace::TraceLayer,
+};
 
 mod config;
 pub use config::ServerConfig;
@@ -57,28 +60,69 @@ fn router_inner<S: Clone + Send + Sync + 'static>(
     root_method_router: MethodRouter<S>,
     state: S,
 ) -> Router {
-    let ServerConfig { allow_origin, no_cors, no_request_size_limit } = config;
-
+    let ServerConfig {
+        allow_origin,
+        no_cors,
+        cors_allow_headers,
+        cors_max_age,
+        no_request_size_limit,
+        max_request_size,
+        log_requests,
+        slow_request_threshold,
+    } = config;
+
+    let request_log = handler::RequestLog {
+        all: log_requests,
+        slow_threshold: slow_request_threshold.map(Duration::from_millis),
+    };
     let mut router = Router::new()
         .route("/", root_method_router)
         .with_state(state)
+        .layer(Extension(request_log))
         .layer(TraceLayer::new_for_http());
     if !no_cors {
+        let allow_headers = std::iter::once(header::CONTENT_TYPE)
+            .chain(cors_allow_headers.iter().filter_map(|name| {
+                name.parse::<HeaderName>()
+                    .inspect_err(|_| warn!(target: "rpc", ?name, "invalid CORS header, ignoring"))
+                    .ok()
+            }))
+            .collect::<Vec<_>>();
         // See [`tower_http::cors`](https://docs.rs/tower-http/latest/tower_http/cors/index.html)
         // for more details.
-        router = router.layer(
-            CorsLayer::new()
-                .allow_origin(allow_origin.0)
-                .allow_headers([header::CONTENT_TYPE])
-                .allow_methods([Method::GET, Method::POST]),
-        );
+        let mut cors = CorsLayer::new()
+            .allow_origin(cors_allow_origin(allow_origin.0))
+            .allow_headers(allow_headers)
+            .allow_methods([Method::GET, Method::POST]);
+        if let Some(max_age) = cors_max_age {
+            cors = cors.max_age(Duration::from_secs(max_age));
+        }
+        router = router.layer(cors);
     }
     if no_request_size_limit {
         router = router.layer(DefaultBodyLimit::disable());
+    } else if let Some(max_request_size) = max_request_size {
+        router = router.layer(DefaultBodyLimit::max(max_request_size));
     }
     router
 }
 
+/// Returns the origins allowed by CORS, either a single value such as `*` or a comma separated
+/// list of origins.
+fn cors_allow_origin(allow_origin: HeaderValue) -> AllowOrigin {
+    let origins = allow_origin.to_str().ok().filter(|origins| origins.contains(','));
+    match origins {
+        Some(origins) => AllowOrigin::list(
+            origins
+                .split(',')
+                .map(str::trim)
+                .filter(|origin| !origin.is_empty())
+                .filter_map(|origin| origin.parse().ok()),
+        ),
+        None => allow_origin.into(),
+    }
+}
+
 /// Helper trait that is used to execute ethereum rpc calls
 #[async_trait::async_trait]
 pub trait RpcHandler: Clone + Send + Sync + 'static {
@@ -1,4 +1,4 @@
-use crate::{RpcHandler, error::RequestError, handler::handle_request};
+use crate::{RpcHandler, error::RequestError, handler::RequestLog};
 use anvil_rpc::{
     error::RpcError,
     request::Request,
@@ -120,6 +120,10 @@ pub struct PubSubConnection<Handler: PubSubRpcHandler, Connection> {
     processing: Vec<Pin<Box<dyn Future<Output = Option<Response>> + Send>>>,
     /// pending messages to send
     pending: VecDeque<String>,
+    /// the logging of the requests
+    request_log: RequestLog,
+    /// the origin of the client, as logged with its requests
+    origin: Option<String>,
 }
 
 impl<Handler: PubSubRpcHandler, Connection> PubSubConnection<Handler, Connection> {
@@ -130,9 +134,22 @@ pub fn new(connection: Connection, handler: Handler) -> Self {
             context: Default::default(),
             pending: Default::default(),
             processing: Default::default(),
+            request_log: Default::default(),
+            origin: None,
         }
     }
 
+    /// Logs the requests of the client at `origin` with `request_log`.
+    pub(crate) fn with_request_log(
+        mut self,
+        request_log: RequestLog,
+        origin: Option<String>,
+    ) -> Self {
+        self.request_log = request_log;
+        self.origin = origin;
+        self
+    }
+
     /// Returns a compatibility `RpcHandler`
     fn compat_helper(&self) -> ContextAwareHandler<Handler> {
         ContextAwareHandler { handler: self.handler.clone(), context: self.context.clone() }
@@ -140,9 +157,11 @@ fn compat_helper(&self) -> ContextAwareHandler<Handler> {
 
     fn process_request(&mut self, req: serde_json::Result<Request>) {
         let handler = self.compat_helper();
+        let request_log = self.request_log;
+        let origin = request_log.is_enabled().then(|| self.origin.clone()).flatten();
         self.processing.push(Box::pin(async move {
             match req {
-                Ok(req) => handle_request(req, handler).await,
+                Ok(req) => request_log.handle_request(req, origin.as_deref(), handler).await,
                 Err(err) => {
                     error!(target: "rpc", ?err, "invalid request");
                     Some(Response::error(RpcError::invalid_request()))
@@ -1,10 +1,17 @@
-use crate::{PubSubRpcHandler, error::RequestError, pubsub::PubSubConnection};
+use crate::{
+    PubSubRpcHandler,
+    error::RequestError,
+    handler::{RequestLog, request_origin},
+    pubsub::PubSubConnection,
+};
 use anvil_rpc::request::Request;
 use axum::{
+    Extension,
     extract::{
         State, WebSocketUpgrade,
         ws::{Message, WebSocket},
     },
+    http::HeaderMap,
     response::Response,
 };
 use futures::{Sink, Stream, ready};
@@ -19,8 +26,13 @@
 pub async fn handle_ws<Http, Ws: PubSubRpcHandler>(
     ws: WebSocketUpgrade,
     State((_, handler)): State<(Http, Ws)>,
+    Extension(log): Extension<RequestLog>,
+    headers: HeaderMap,
 ) -> Response {
-    ws.on_upgrade(|socket| PubSubConnection::new(SocketConn(socket), handler))
+    let origin = request_origin(&headers).map(ToString::to_string);
+    ws.on_upgrade(move |socket| {
+        PubSubConnection::new(SocketConn(socket), handler).with_request_log(log, origin)
+    })
 }
 
 #[pin_project::pin_project]
@@ -1,16 +1,22 @@
 use crate::{
     AccountGenerator, CHAIN_ID, NodeConfig,
-    config::{DEFAULT_MNEMONIC, ForkChoice},
-    eth::{EthApi, backend::db::SerializableState, pool::transactions::TransactionOrder},
+    config::{DEFAULT_MNEMONIC, EngineApiConfig, ForkChoice},
+    eth::{
+        EthApi,
+        backend::{db::SerializableState, fork::ForkRetryMode},
+        pool::transactions::TransactionOrder,
+    },
 };
 use alloy_genesis::Genesis;
 use alloy_network::Network;
 use alloy_primitives::{B256, U256, utils::Unit};
+use alloy_rpc_types_engine::JwtSecret;
 use alloy_signer_local::coins_bip39::{English, Mnemonic};
+use anvil_core::types::L1BlockValues;
 use anvil_server::ServerConfig;
 use clap::Parser;
 use core::fmt;
-use foundry_common::shell;
+use foundry_common::{named_accounts::NamedAccountsConfig, shell};
 use foundry_config::{Chain, Config, FigmentProviders};
 use foundry_evm::hardfork::{EthereumHardfork, OpHardfork};
 use foundry_evm_networks::NetworkConfigs;
@@ -125,6 +131,14 @@ pub struct NodeArgs {
     #[arg(long, value_name = "PATH", value_parser= read_genesis_file)]
     pub init: Option<Genesis>,
 
+    /// Set up the named accounts of the given `accounts.toml` file at genesis.
+    ///
+    /// Named accounts have a role (`deployer`, `treasury`, `user` or a custom one) setting their
+    /// balance, and optionally code or an EIP-7702 delegation. They can be listed with
+    /// `anvil_listNamedAccounts`.
+    #[arg(long, value_name = "PATH", value_hint = clap::ValueHint::FilePath)]
+    pub accounts_config: Option<PathBuf>,
+
     /// This is an alias for both --load-state and --dump-state.
     ///
     /// It initializes the chain with the state and block environment stored at the file, if it
@@ -174,6 +188,32 @@ pub struct NodeArgs {
     #[arg(long, help = IPC_HELP, value_name = "PATH", visible_alias = "ipcpath")]
     pub ipc: Option<Option<String>>,
 
+    /// Serve a stub of the Engine API, to smoke-test consensus clients and staking tooling against
+    /// anvil as a mock execution layer.
+    ///
+    /// Supports `engine_exchangeCapabilities`, `engine_newPayloadV1` to `V4` and
+    /// `engine_forkchoiceUpdatedV1` to `V3`, authenticated with a JWT. Payloads are acknowledged
+    /// but not executed, and payload building is not supported.
+    #[arg(long, help_heading = "Server options")]
+    pub engine_api: bool,
+
+    /// Port number of the Engine API.
+    #[arg(
+        long,
+        default_value = "8551",
+        value_name = "NUM",
+        requires = "engine_api",
+        help_heading = "Server options"
+    )]
+    pub engine_port: u16,
+
+    /// The file of the hex-encoded secret authenticating the Engine API requests.
+    ///
+    /// A random secret is written to the file if it doesn't exist. Without a file, a random secret
+    /// is generated and printed on startup.
+    #[arg(long, value_name = "PATH", requires = "engine_api", help_heading = "Server options")]
+    pub jwt_secret: Option<PathBuf>,
+
     /// Don't keep full chain history.
     /// If a number argument is specified, at most this number of states is kept in memory.
     ///
@@ -239,6 +279,22 @@ pub fn into_node_config(self) -> eyre::Result<NodeConfig> {
             }
         }
 
+        let engine_api = if self.engine_api {
+            let (jwt_secret, generated_secret) = match &self.jwt_secret {
+                Some(path) if path.exists() => (JwtSecret::from_file(path)?, false),
+                Some(path) => (JwtSecret::try_create_random(path)?, false),
+                None => (JwtSecret::random(), true),
+            };
+            Some(EngineApiConfig { port: self.engine_port, jwt_secret, generated_secret })
+        } else {
+            None
+        };
+
+        let named_accounts = match &self.accounts_config {
+            Some(path) => NamedAccountsConfig::load(path)?.resolve()?,
+            None => vec![],
+        };
+
         let hardfork = match &self.hardfork {
             Some(hf) => {
                 if self.evm.networks.is_optimism() {
@@ -252,6 +308,14 @@ pub fn into_node_config(self) -> eyre::Result<NodeConfig> {
             None => None,
         };
 
+        let l1_block_values = L1BlockValues {
+            basefee: self.evm.l1_base_fee.map(U256::from),
+            blob_base_fee: self.evm.l1_blob_base_fee.map(U256::from),
+            base_fee_scalar: self.evm.l1_base_fee_scalar,
+            blob_base_fee_scalar: self.evm.l1_blob_base_fee_scalar,
+            ..Default::default()
+        };
+
         Ok(NodeConfig::default()
             .with_gas_limit(self.evm.gas_limit)
             .disable_block_gas_limit(self.evm.disable_block_gas_limit)
@@ -276,11 +340,13 @@ pub fn into_node_config(self) -> eyre::Result<NodeConfig> {
                     .and_then(|f| f.block)
                     .map(|num| ForkChoice::Block(num as i128)),
             })
+            .with_replay_block(self.evm.replay_block)
             .with_fork_headers(self.evm.fork_headers)
             .with_fork_chain_id(self.evm.fork_chain_id.map(u64::from).map(U256::from))
             .fork_request_timeout(self.evm.fork_request_timeout.map(Duration::from_millis))
             .fork_request_retries(self.evm.fork_request_retries)
             .fork_retry_backoff(self.evm.fork_retry_backoff.map(Duration::from_millis))
+            .with_fork_retry_mode(self.evm.fork_retry)
             .fork_compute_units_per_second(compute_units_per_second)
             .with_fork_urls(self.evm.fork_url.into_iter().map(|f| f.url).collect())
             .with_base_fee(self.evm.block_base_fee_per_gas)
@@ -293,11 +359,13 @@ pub fn into_node_config(self) -> eyre::Result<NodeConfig> {
             .with_chain_id(self.evm.chain_id)
             .with_transaction_order(self.order)
             .with_genesis(self.init)
+            .with_named_accounts(named_accounts)?
             .with_steps_tracing(self.evm.steps_tracing)
             .with_print_logs(!self.evm.disable_console_log)
             .with_print_traces(self.evm.print_traces)
             .with_auto_impersonate(self.evm.auto_impersonate)
             .with_ipc(self.ipc)
+            .with_engine_api(engine_api)
             .with_code_size_limit(self.evm.code_size_limit)
             .disable_code_size_limit(self.evm.disable_code_size_limit)
             .set_pruned_history(self.prune_history)
@@ -306,6 +374,7 @@ pub fn into_node_config(self) -> eyre::Result<NodeConfig> {
             .with_max_transactions(self.max_transactions)
             .with_max_persisted_states(self.max_persisted_states)
             .with_networks(self.evm.networks)
+            .with_l1_block_values(l1_block_values)
             .with_disable_default_create2_deployer(self.evm.disable_default_create2_deployer)
             .with_disable_pool_balance_checks(self.evm.disable_pool_balance_checks)
             .with_slots_in_an_epoch(self.slots_in_an_epoch)
@@ -500,12 +569,39 @@ pub struct AnvilEvmArgs {
     )]
     pub fork_transaction_hash: Option<B256>,
 
+    /// Replay a block of the remote endpoint: fork at its parent block, and execute all its
+    /// transactions locally in the first mined block, with the original timestamp and coinbase.
+    ///
+    /// The traces and state diffs of the replayed transactions are then available with
+    /// `trace_replayBlockTransactions`, `trace_block` and `debug_traceTransaction`, without an
+    /// archive node supporting them.
+    ///
+    /// See --fork-url.
+    #[arg(
+        long,
+        requires = "fork_url",
+        value_name = "BLOCK",
+        help_heading = "Fork config",
+        conflicts_with_all = ["fork_block_number", "fork_transaction_hash"]
+    )]
+    pub replay_block: Option<u64>,
+
     /// Initial retry backoff on encountering errors.
     ///
     /// See --fork-url.
     #[arg(long, requires = "fork_url", value_name = "BACKOFF", help_heading = "Fork config")]
     pub fork_retry_backoff: Option<u64>,
 
+    /// How to handle requests to the fork endpoint that fail.
+    ///
+    /// `retry` retries them with an exponential backoff, `fail-open` additionally answers them
+    /// with the last successful response to the same request if retrying fails, and
+    /// `propagate` returns the error right away.
+    ///
+    /// Default value `retry`
+    #[arg(long, requires = "fork_url", value_name = "MODE", help_heading = "Fork config")]
+    pub fork_retry: Option<ForkRetryMode>,
+
     /// Specify chain id to skip fetching it from remote endpoint. This enables offline-start mode.
     ///
     /// You still must pass both `--fork-url` and `--fork-block-number`, and already have your
@@ -641,6 +737,22 @@ pub struct AnvilEvmArgs {
 
     #[command(flatten)]
     pub networks: NetworkConfigs,
+
+    /// The initial L1 base fee of the `L1Block` predeploy, in Optimism mode.
+    #[arg(long, value_name = "FEE", help_heading = "Optimism")]
+    pub l1_base_fee: Option<u128>,
+
+    /// The initial L1 blob base fee of the `L1Block` predeploy, in Optimism mode.
+    #[arg(long, value_name = "FEE", help_heading = "Optimism")]
+    pub l1_blob_base_fee: Option<u128>,
+
+    /// The initial L1 base fee scalar of the `L1Block` predeploy, in Optimism mode.
+    #[arg(long, value_name = "SCALAR", help_heading = "Optimism")]
+    pub l1_base_fee_scalar: Option<u32>,
+
+    /// The initial L1 blob base fee scalar of the `L1Block` predeploy, in Optimism mode.
+    #[arg(long, value_name = "SCALAR", help_heading = "Optimism")]
+    pub l1_blob_base_fee_scalar: Option<u32>,
 }
 
 /// Resolves an alias passed as fork-url to the matching url defined in the rpc_endpoints section
@@ -897,6 +1009,23 @@ fn test_parse_fork_url() {
         );
     }
 
+    #[test]
+    fn can_parse_fork_retry_mode() {
+        let args: NodeArgs = NodeArgs::parse_from([
+            "anvil",
+            "--fork-url",
+            "http://localhost:8545",
+            "--fork-retry",
+            "fail-open",
+        ]);
+        let config = args.into_node_config().unwrap();
+        assert_eq!(config.fork_retry_mode, ForkRetryMode::FailOpen);
+
+        let args: NodeArgs = NodeArgs::parse_from(["anvil"]);
+        let config = args.into_node_config().unwrap();
+        assert_eq!(config.fork_retry_mode, ForkRetryMode::Retry);
+    }
+
     #[test]
     fn can_parse_ethereum_hardfork() {
         let args: NodeArgs = NodeArgs::parse_from(["anvil", "--hardfork", "berlin"]);
@@ -1068,6 +1197,7 @@ fn fork_dependent_args_require_fork_url() {
             vec!["anvil", "--retries", "3"],
             vec!["anvil", "--fork-block-number", "100"],
             vec!["anvil", "--fork-retry-backoff", "500"],
+            vec!["anvil", "--fork-retry", "propagate"],
         ];
         for args in &cases {
             let result = NodeArgs::try_parse_from(args);
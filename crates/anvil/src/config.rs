@@ -3,7 +3,7 @@
     eth::{
         backend::{
             db::{Db, SerializableState},
-            fork::{ClientFork, ClientForkConfig},
+            fork::{ClientFork, ClientForkConfig, ForkRetryMode},
             genesis::GenesisConfig,
             mem::fork_db::ForkedDatabase,
             time::duration_since_unix_epoch,
@@ -18,20 +18,23 @@
 use alloy_evm::EvmEnv;
 use alloy_genesis::Genesis;
 use alloy_network::{AnyNetwork, BlockResponse, TransactionResponse};
-use alloy_primitives::{BlockNumber, TxHash, U256, hex, map::HashMap, utils::Unit};
+use alloy_primitives::{Address, BlockNumber, TxHash, U256, hex, map::HashMap, utils::Unit};
 use alloy_provider::Provider;
 use alloy_rpc_types::BlockNumberOrTag;
+use alloy_rpc_types_engine::JwtSecret;
 use alloy_signer::Signer;
 use alloy_signer_local::{
     MnemonicBuilder, PrivateKeySigner,
     coins_bip39::{English, Mnemonic},
 };
 use alloy_transport::TransportError;
+use anvil_core::types::L1BlockValues;
 use anvil_server::ServerConfig;
 use eyre::{Context, Result};
 use foundry_common::{
     ALCHEMY_FREE_TIER_CUPS, NON_ARCHIVE_NODE_WARNING, REQUEST_TIMEOUT,
-    provider::{ProviderBuilder, RetryProvider},
+    named_accounts::NamedAccount,
+    provider::{ProviderBuilder, RetryProvider, failure::RpcFailureInjector},
 };
 use foundry_config::Config;
 use foundry_evm::{
@@ -140,6 +143,8 @@ pub struct NodeConfig {
     pub fork_urls: Vec<String>,
     /// pins the block number or transaction hash for the state fork
     pub fork_choice: Option<ForkChoice>,
+    /// The block of the fork to replay on top of its parent block
+    pub replay_block: Option<u64>,
     /// headers to use with fork RPC endpoints
     pub fork_headers: Vec<String>,
     /// specifies chain id for cache to skip fetching from remote in offline-start mode
@@ -160,16 +165,24 @@ pub struct NodeConfig {
     pub config_out: Option<PathBuf>,
     /// The genesis to use to initialize the node
     pub genesis: Option<Genesis>,
+    /// The named accounts to set up at genesis
+    pub named_accounts: Vec<NamedAccount>,
     /// Timeout in for requests sent to remote JSON-RPC server in forking mode
     pub fork_request_timeout: Duration,
     /// Number of request retries for spurious networks
     pub fork_request_retries: u32,
     /// The initial retry backoff
     pub fork_retry_backoff: Duration,
+    /// How failed requests to the fork endpoint are handled
+    pub fork_retry_mode: ForkRetryMode,
+    /// Injects failures into the requests sent to the fork endpoint
+    pub fork_failure_injector: RpcFailureInjector,
     /// available CUPS
     pub compute_units_per_second: u64,
     /// The ipc path
     pub ipc_path: Option<Option<String>>,
+    /// The Engine API stub to serve, if any
+    pub engine_api: Option<EngineApiConfig>,
     /// Enable transaction/call steps tracing for debug calls returning geth-style traces
     pub enable_steps_tracing: bool,
     /// Enable printing of `console.log` invocations.
@@ -202,6 +215,8 @@ pub struct NodeConfig {
     pub precompile_factory: Option<Arc<dyn PrecompileFactory>>,
     /// Networks to enable features for.
     pub networks: NetworkConfigs,
+    /// The initial L1 attributes of the OP-stack `L1Block` predeploy, in Optimism mode.
+    pub l1_block_values: L1BlockValues,
     /// Do not print log messages.
     pub silent: bool,
     /// The path where persisted states are cached (used with `max_persisted_states`).
@@ -243,6 +258,26 @@ fn as_string(&self, fork: Option<&ClientFork>) -> String {
             let _ = write!(s, "\n({idx}) 0x{hex}");
         }
 
+        if !self.named_accounts.is_empty() {
+            let _ = write!(
+                s,
+                r#"
+
+Named Accounts
+==================
+"#
+            );
+            for account in &self.named_accounts {
+                let balance = alloy_primitives::utils::format_ether(account.balance);
+                let _ = write!(s, "\n{}: {} ({balance} ETH)", account.name, account.address);
+                if let Some(delegate) = account.delegate {
+                    let _ = write!(s, " delegated to {delegate}");
+                } else if account.code.is_some() {
+                    let _ = write!(s, " with code");
+                }
+            }
+        }
+
         if let Some(generator) = &self.account_generator {
             let _ = write!(
                 s,
@@ -286,6 +321,10 @@ fn as_string(&self, fork: Option<&ClientFork>) -> String {
             if let Some(tx_hash) = fork.transaction_hash() {
                 let _ = writeln!(s, "Transaction hash: {tx_hash}");
             }
+
+            if let Some(number) = self.replay_block {
+                let _ = writeln!(s, "Replayed block:   {number}");
+            }
         } else {
             let _ = write!(
                 s,
@@ -477,6 +516,7 @@ fn default() -> Self {
             max_transactions: 1_000,
             fork_urls: vec![],
             fork_choice: None,
+            replay_block: None,
             account_generator: None,
             base_fee: None,
             disable_min_priority_fee: false,
@@ -492,14 +532,18 @@ fn default() -> Self {
             transaction_order: Default::default(),
             config_out: None,
             genesis: None,
+            named_accounts: vec![],
             fork_request_timeout: REQUEST_TIMEOUT,
             fork_headers: vec![],
             fork_request_retries: 5,
             fork_retry_backoff: Duration::from_millis(1_000),
+            fork_retry_mode: Default::default(),
+            fork_failure_injector: Default::default(),
             fork_chain_id: None,
             // alchemy max cpus <https://docs.alchemy.com/reference/compute-units#what-are-cups-compute-units-per-second>
             compute_units_per_second: ALCHEMY_FREE_TIER_CUPS,
             ipc_path: None,
+            engine_api: None,
             code_size_limit: None,
             prune_history: Default::default(),
             max_persisted_states: None,
@@ -511,6 +555,7 @@ fn default() -> Self {
             memory_limit: None,
             precompile_factory: None,
             networks: Default::default(),
+            l1_block_values: Default::default(),
             silent: false,
             cache_path: None,
         }
@@ -732,6 +777,19 @@ pub fn with_genesis(mut self, genesis: Option<Genesis>) -> Self {
         self
     }
 
+    /// Sets the named accounts to set up at genesis
+    ///
+    /// The named accounts with a private key are also added to the signer accounts.
+    pub fn with_named_accounts(mut self, accounts: Vec<NamedAccount>) -> eyre::Result<Self> {
+        for account in &accounts {
+            if let Some(private_key) = account.private_key {
+                self.signer_accounts.push(PrivateKeySigner::from_bytes(&private_key)?);
+            }
+        }
+        self.named_accounts = accounts;
+        Ok(self)
+    }
+
     /// Returns the genesis timestamp to use
     pub fn get_genesis_timestamp(&self) -> u64 {
         self.genesis_timestamp
@@ -851,6 +909,13 @@ pub fn with_ipc(mut self, ipc_path: Option<Option<String>>) -> Self {
         self
     }
 
+    /// Sets the Engine API stub to serve
+    #[must_use]
+    pub fn with_engine_api(mut self, engine_api: Option<EngineApiConfig>) -> Self {
+        self.engine_api = engine_api;
+        self
+    }
+
     /// Sets the file path to write the Anvil node's config info to.
     #[must_use]
     pub fn set_config_out(mut self, config_out: Option<PathBuf>) -> Self {
@@ -902,6 +967,17 @@ pub fn with_fork_choice<U: Into<ForkChoice>>(mut self, fork_choice: Option<U>) -
         self
     }
 
+    /// Sets the block of the fork to replay: the node forks at its parent block, and mines its
+    /// transactions in the first block
+    #[must_use]
+    pub fn with_replay_block(mut self, replay_block: Option<u64>) -> Self {
+        if let Some(block) = replay_block {
+            self.fork_choice = Some(ForkChoice::Block(block.saturating_sub(1) as i128));
+        }
+        self.replay_block = replay_block;
+        self
+    }
+
     /// Sets the `fork_chain_id` to use to fork off local cache from
     #[must_use]
     pub const fn with_fork_chain_id(mut self, fork_chain_id: Option<U256>) -> Self {
@@ -943,6 +1019,15 @@ pub const fn fork_retry_backoff(mut self, fork_retry_backoff: Option<Duration>)
         self
     }
 
+    /// Sets how failed requests to the fork endpoint are handled
+    #[must_use]
+    pub const fn with_fork_retry_mode(mut self, fork_retry_mode: Option<ForkRetryMode>) -> Self {
+        if let Some(fork_retry_mode) = fork_retry_mode {
+            self.fork_retry_mode = fork_retry_mode;
+        }
+        self
+    }
+
     /// Sets the number of assumed available compute units per second
     ///
     /// See also, <https://docs.alchemy.com/reference/compute-units#what-are-cups-compute-units-per-second>
@@ -1085,6 +1170,13 @@ pub fn with_optimism(mut self) -> Self {
         self
     }
 
+    /// Sets the initial L1 attributes of the OP-stack `L1Block` predeploy, in Optimism mode.
+    #[must_use]
+    pub const fn with_l1_block_values(mut self, l1_block_values: L1BlockValues) -> Self {
+        self.l1_block_values = l1_block_values;
+        self
+    }
+
     /// Makes the node silent to not emit anything on stdout
     #[must_use]
     pub const fn silent(self) -> Self {
@@ -1192,6 +1284,7 @@ pub(crate) async fn setup<N>(&mut self) -> Result<mem::Backend<N>>
             balance: self.genesis_balance,
             accounts: self.genesis_accounts.iter().map(|acc| acc.address()).collect(),
             genesis_init: self.genesis.clone(),
+            named_accounts: self.named_accounts.clone(),
         };
 
         let mut decoder_builder = CallTraceDecoderBuilder::new();
@@ -1224,6 +1317,13 @@ pub(crate) async fn setup<N>(&mut self) -> Result<mem::Backend<N>>
         )
         .await?;
 
+        // the replayed block is mined with its original timestamp
+        if let Some(timestamp) =
+            backend.get_fork().and_then(|fork| fork.config.read().replay_timestamp)
+        {
+            backend.time().set_next_block_timestamp(timestamp)?;
+        }
+
         // Writes the default create2 deployer to the backend,
         // if the option is not disabled and we are not forking.
         if !self.disable_default_create2_deployer && self.fork_urls.is_empty() {
@@ -1254,6 +1354,18 @@ pub async fn setup_fork_db(
         Ok((db, Some(fork)))
     }
 
+    /// Returns the builder of providers to the fork endpoint.
+    fn fork_provider_builder(&self, eth_rpc_url: &str) -> ProviderBuilder {
+        let builder = ProviderBuilder::new(eth_rpc_url)
+            .timeout(self.fork_request_timeout)
+            .initial_backoff(self.fork_retry_backoff.as_millis() as u64)
+            .compute_units_per_second(self.compute_units_per_second)
+            .max_retry(self.fork_request_retries)
+            .headers(self.fork_headers.clone())
+            .failure_injector(self.fork_failure_injector.clone());
+        self.fork_retry_mode.configure(builder)
+    }
+
     /// Configures everything related to forking based on the passed `eth_rpc_url`:
     ///  - returning a tuple of a [ForkedDatabase] and [ClientForkConfig] which can be used to build
     ///    a [ClientFork] to fork from.
@@ -1271,12 +1383,7 @@ pub async fn setup_fork_db_config(
         // where discovery calls (get_chain_id, find_latest_fork_block, get_block)
         // hit different endpoints that may be at different chain tips.
         let provider = Arc::new(
-            ProviderBuilder::new(&eth_rpc_url)
-                .timeout(self.fork_request_timeout)
-                .initial_backoff(self.fork_retry_backoff.as_millis() as u64)
-                .compute_units_per_second(self.compute_units_per_second)
-                .max_retry(self.fork_request_retries)
-                .headers(self.fork_headers.clone())
+            self.fork_provider_builder(&eth_rpc_url)
                 .build()
                 .wrap_err("failed to establish provider to fork url")?,
         );
@@ -1307,6 +1414,18 @@ pub async fn setup_fork_db_config(
             (bn, None, None)
         };
 
+        // the transactions of the replayed block are forced into the first mined block
+        let (force_transactions, replayed) = match self.replay_block {
+            Some(number) => {
+                let (transactions, timestamp, coinbase) =
+                    replayed_block_transactions(number, &provider)
+                        .await
+                        .wrap_err_with(|| format!("failed to fetch block {number} to replay"))?;
+                (Some(transactions), Some((timestamp, coinbase)))
+            }
+            None => (force_transactions, None),
+        };
+
         let block = provider
             .get_block(BlockNumberOrTag::Number(fork_block_number).into())
             .await
@@ -1341,6 +1460,9 @@ pub async fn setup_fork_db_config(
             basefee: evm_env.block_env.basefee,
             ..block_env_from_header(&block.header)
         };
+        if let Some((_, coinbase)) = replayed {
+            evm_env.block_env.beneficiary = coinbase;
+        }
 
         // Determine chain_id early so we can use it consistently
         let chain_id = if let Some(chain_id) = self.chain_id {
@@ -1437,12 +1559,7 @@ pub async fn setup_fork_db_config(
         let provider = if self.fork_urls.len() > 1 {
             debug!(target: "node", urls=?self.fork_urls, "using multi-endpoint round-robin provider");
             Arc::new(
-                ProviderBuilder::new(&eth_rpc_url)
-                    .timeout(self.fork_request_timeout)
-                    .initial_backoff(self.fork_retry_backoff.as_millis() as u64)
-                    .compute_units_per_second(self.compute_units_per_second)
-                    .max_retry(self.fork_request_retries)
-                    .headers(self.fork_headers.clone())
+                self.fork_provider_builder(&eth_rpc_url)
                     .build_fallback(self.fork_urls.clone())
                     .wrap_err("failed to establish round-robin provider to fork urls")?,
             )
@@ -1478,6 +1595,9 @@ pub async fn setup_fork_db_config(
             blob_gas_used: block.header.blob_gas_used().map(|g| g as u128),
             blob_excess_gas_and_price: evm_env.block_env.blob_excess_gas_and_price,
             force_transactions,
+            replay_timestamp: replayed.map(|(timestamp, _)| timestamp),
+            retry_mode: self.fork_retry_mode,
+            failure_injector: self.fork_failure_injector.clone(),
         };
 
         debug!(target: "node", fork_number=config.block_number, fork_hash=%config.block_hash, "set up fork db");
@@ -1573,6 +1693,28 @@ async fn derive_block_and_transactions(
     }
 }
 
+/// Returns the transactions of the given block, to replay them on top of its parent block, along
+/// with the timestamp and the coinbase of the block.
+async fn replayed_block_transactions(
+    block_number: u64,
+    provider: &Arc<RetryProvider>,
+) -> eyre::Result<(Vec<PoolTransaction<FoundryTxEnvelope>>, u64, Address)> {
+    let block = provider
+        .get_block_by_number(block_number.into())
+        .full()
+        .await?
+        .ok_or_else(|| eyre::eyre!("block {block_number} not found"))?;
+    let transactions = block
+        .transactions
+        .as_transactions()
+        .ok_or_else(|| eyre::eyre!("failed to get transactions from full block"))?
+        .iter()
+        .map(|transaction| PoolTransaction::try_from(transaction.clone()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| eyre::eyre!("Err converting to pool transactions {e}"))?;
+    Ok((transactions, block.header.timestamp(), block.header.beneficiary()))
+}
+
 /// Fork delimiter used to specify which block or transaction to fork from.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ForkChoice {
@@ -1638,6 +1780,17 @@ pub fn from_args(val: Option<Option<usize>>) -> Self {
     }
 }
 
+/// The configuration of the Engine API stub.
+#[derive(Clone, Debug)]
+pub struct EngineApiConfig {
+    /// The port to serve the Engine API on
+    pub port: u16,
+    /// The secret authenticating the requests
+    pub jwt_secret: JwtSecret,
+    /// Whether the secret was generated, rather than read from a file
+    pub generated_secret: bool,
+}
+
 /// Can create dev accounts
 #[derive(Clone, Debug)]
 pub struct AccountGenerator {
@@ -73,11 +73,12 @@
         block::BlockInfo,
         transaction::{MaybeImpersonatedTransaction, PendingTransaction},
     },
-    types::{ReorgOptions, TransactionData},
+    types::{L1BlockValues, ReorgOptions, TransactionData},
 };
 use anvil_rpc::{error::RpcError, response::ResponseResult};
 use foundry_common::{
-    provider::ProviderBuilder,
+    named_accounts::NamedAccount,
+    provider::{ProviderBuilder, failure::RpcFailure},
     version::{COMMIT_SHA, SEMVER_VERSION},
 };
 use foundry_evm::decode::RevertDecoder;
@@ -376,6 +377,38 @@ pub async fn anvil_set_next_block_base_fee_per_gas(&self, basefee: U256) -> Resu
         Ok(())
     }
 
+    /// Sets the parent beacon block root of the next block, which is stored in the EIP-4788
+    /// beacon roots contract when the block is mined.
+    ///
+    /// Handler for RPC call: `anvil_setBeaconRoot`
+    pub async fn anvil_set_beacon_root(&self, root: B256) -> Result<()> {
+        node_info!("anvil_setBeaconRoot");
+        if !self.backend.is_eip4844() {
+            return Err(RpcError::invalid_params(
+                "anvil_setBeaconRoot is only supported when EIP-4788 is active",
+            )
+            .into());
+        }
+        self.backend.set_next_beacon_root(root);
+        Ok(())
+    }
+
+    /// Sets the L1 attributes of the OP-stack `L1Block` predeploy, leaving the unset ones
+    /// untouched.
+    ///
+    /// Handler for RPC call: `anvil_setL1BlockValues`
+    pub async fn anvil_set_l1_block_values(&self, values: L1BlockValues) -> Result<()> {
+        node_info!("anvil_setL1BlockValues");
+        if !self.backend.is_optimism() {
+            return Err(RpcError::invalid_params(
+                "anvil_setL1BlockValues is only supported in Optimism mode",
+            )
+            .into());
+        }
+        self.backend.set_l1_block_values(&values).await?;
+        Ok(())
+    }
+
     /// Sets the coinbase address.
     ///
     /// Handler for RPC call: `anvil_setCoinbase`
@@ -426,6 +459,14 @@ pub async fn anvil_node_info(&self) -> Result<NodeInfo> {
         })
     }
 
+    /// Retrieves the named accounts set up at genesis with `--accounts-config`, sorted by name.
+    ///
+    /// Handler for RPC call: `anvil_listNamedAccounts`
+    pub async fn anvil_list_named_accounts(&self) -> Result<Vec<NamedAccount>> {
+        node_info!("anvil_listNamedAccounts");
+        Ok(self.backend.named_accounts().to_vec())
+    }
+
     /// Retrieves metadata about the Anvil instance.
     ///
     /// Handler for RPC call: `anvil_metadata`
@@ -532,8 +573,12 @@ pub async fn anvil_set_rpc_url(&self, url: String) -> Result<()> {
         if let Some(fork) = self.backend.get_fork() {
             let mut config = fork.config.write();
             // let interval = config.provider.get_interval();
+            let builder = ProviderBuilder::new(&url)
+                .max_retry(10)
+                .initial_backoff(1000)
+                .failure_injector(config.failure_injector.clone());
             let new_provider = Arc::new(
-                ProviderBuilder::new(&url).max_retry(10).initial_backoff(1000).build().map_err(
+                config.retry_mode.configure(builder).build().map_err(
                     |_| {
                         TransportErrorKind::custom_str(
                             format!("Failed to parse invalid url {url}").as_str(),
@@ -551,6 +596,16 @@ pub async fn anvil_set_rpc_url(&self, url: String) -> Result<()> {
         Ok(())
     }
 
+    /// Injects failures into the requests sent to the fork endpoint, so clients can be tested
+    /// against an unreliable provider
+    ///
+    /// Handler for ETH RPC call: `anvil_injectRpcFailure`
+    pub async fn anvil_inject_rpc_failure(&self, failure: RpcFailure) -> Result<()> {
+        node_info!("anvil_injectRpcFailure");
+        self.backend.node_config.read().await.fork_failure_injector.inject(failure);
+        Ok(())
+    }
+
     /// Returns the number of transactions currently pending for inclusion in the next block(s), as
     /// well as the ones that are being scheduled for future execution only.
     /// Ref: [Here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_status)
@@ -730,6 +785,27 @@ pub async fn anvil_impersonate_signature(
         self.backend.impersonate_signature(signature, address).await
     }
 
+    /// Sets the result of the EIP-1271 `isValidSignature` function of `contract` for `hash`,
+    /// whatever the signature, so smart contract wallet signatures can be faked.
+    ///
+    /// If `contract` has no code, a single `STOP` opcode is set as its code so it passes code
+    /// size checks.
+    ///
+    /// Handler for ETH RPC call: `anvil_setERC1271Result`
+    pub async fn anvil_set_erc1271_result(
+        &self,
+        contract: Address,
+        hash: B256,
+        valid: bool,
+    ) -> Result<()> {
+        node_info!("anvil_setERC1271Result");
+        if self.backend.get_code(contract, None).await?.is_empty() {
+            self.backend.set_code(contract, Bytes::from_static(&[0])).await?;
+        }
+        self.backend.set_erc1271_result(contract, hash, valid);
+        Ok(())
+    }
+
     /// Returns a new block event stream that yields Notifications when a new block was added
     pub fn new_block_notifications(&self) -> NewBlockNotifications {
         self.backend.new_block_notifications()
@@ -1694,6 +1770,9 @@ pub async fn execute(&self, request: EthRequest) -> ResponseResult {
             EthRequest::AutoImpersonateAccount(enable) => {
                 self.anvil_auto_impersonate_account(enable).await.to_rpc_result()
             }
+            EthRequest::SetERC1271Result(contract, hash, valid) => {
+                self.anvil_set_erc1271_result(contract, hash, valid).await.to_rpc_result()
+            }
             EthRequest::ImpersonateSignature(signature, address) => {
                 self.anvil_impersonate_signature(signature, address).await.to_rpc_result()
             }
@@ -1748,12 +1827,21 @@ pub async fn execute(&self, request: EthRequest) -> ResponseResult {
             EthRequest::SetNextBlockBaseFeePerGas(gas) => {
                 self.anvil_set_next_block_base_fee_per_gas(gas).await.to_rpc_result()
             }
+            EthRequest::SetBeaconRoot(root) => {
+                self.anvil_set_beacon_root(root).await.to_rpc_result()
+            }
+            EthRequest::SetL1BlockValues(values) => {
+                self.anvil_set_l1_block_values(values).await.to_rpc_result()
+            }
             EthRequest::DumpState(preserve_historical_states) => self
                 .anvil_dump_state(preserve_historical_states.and_then(|s| s.params))
                 .await
                 .to_rpc_result(),
             EthRequest::LoadState(buf) => self.anvil_load_state(buf).await.to_rpc_result(),
             EthRequest::NodeInfo(_) => self.anvil_node_info().await.to_rpc_result(),
+            EthRequest::ListNamedAccounts(_) => {
+                self.anvil_list_named_accounts().await.to_rpc_result()
+            }
             EthRequest::AnvilMetadata(_) => self.anvil_metadata().await.to_rpc_result(),
             EthRequest::EvmSnapshot(_) => self.evm_snapshot().await.to_rpc_result(),
             EthRequest::EvmRevert(id) => self.evm_revert(id).await.to_rpc_result(),
@@ -1800,6 +1888,9 @@ pub async fn execute(&self, request: EthRequest) -> ResponseResult {
                 self.evm_mine_detailed(mine.and_then(|p| p.params)).await.to_rpc_result()
             }
             EthRequest::SetRpcUrl(url) => self.anvil_set_rpc_url(url).await.to_rpc_result(),
+            EthRequest::InjectRpcFailure(failure) => {
+                self.anvil_inject_rpc_failure(failure).await.to_rpc_result()
+            }
             EthRequest::EthSendUnsignedTransaction(tx) => {
                 self.eth_send_unsigned_transaction(*tx).await.to_rpc_result()
             }
@@ -3435,7 +3526,8 @@ async fn build_tx_request(
         request: WithOtherFields<TransactionRequest>,
         nonce: u64,
     ) -> Result<FoundryTypedTx> {
-        let mut request = Into::<FoundryTransactionRequest>::into(request);
+        let mut request = FoundryTransactionRequest::try_from(request)
+            .map_err(|err| BlockchainError::InvalidTransactionRequest(err.to_string()))?;
         let from = request.from().or(self.accounts()?.first().copied());
 
         // Fill common fields for all tx types
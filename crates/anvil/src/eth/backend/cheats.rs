@@ -2,7 +2,7 @@
 
 use alloy_evm::precompiles::{Precompile, PrecompileInput};
 use alloy_primitives::{
-    Address, Bytes,
+    Address, B256, Bytes,
     map::{AddressHashSet, foldhash::HashMap},
 };
 use parking_lot::RwLock;
@@ -83,6 +83,17 @@ pub fn get_recover_override(&self, sig: &Bytes) -> Option<Address> {
     pub fn has_recover_overrides(&self) -> bool {
         !self.state.read().signature_overrides.is_empty()
     }
+
+    /// Sets the result of the EIP-1271 `isValidSignature` function of `contract` for `hash`.
+    pub fn set_erc1271_result(&self, contract: Address, hash: B256, valid: bool) {
+        trace!(target: "cheats", %contract, %hash, valid, "set ERC-1271 result");
+        self.state.write().erc1271_results.insert((contract, hash), valid);
+    }
+
+    /// Returns all EIP-1271 results overrides.
+    pub fn erc1271_results(&self) -> HashMap<(Address, B256), bool> {
+        self.state.read().erc1271_results.clone()
+    }
 }
 
 /// Container type for all the state variables
@@ -94,6 +105,8 @@ pub struct CheatsState {
     pub auto_impersonate_accounts: bool,
     /// Overrides for ecrecover: Signature => Address
     pub signature_overrides: HashMap<Bytes, Address>,
+    /// Overrides for EIP-1271 `isValidSignature`: (Contract, Hash) => Valid
+    pub erc1271_results: HashMap<(Address, B256), bool>,
 }
 
 impl CheatEcrecover {
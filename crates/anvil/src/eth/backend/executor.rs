@@ -107,6 +107,8 @@ pub struct AnvilBlockExecutor<E> {
     evm: E,
     /// Parent block hash — needed for EIP-2935 system call.
     parent_hash: B256,
+    /// Parent beacon block root — needed for EIP-4788 system call.
+    parent_beacon_block_root: Option<B256>,
     /// The active spec id, used to gate hardfork-specific behavior.
     spec_id: SpecId,
     /// Receipt builder.
@@ -126,6 +128,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("AnvilBlockExecutor")
             .field("evm", &self.evm)
             .field("parent_hash", &self.parent_hash)
+            .field("parent_beacon_block_root", &self.parent_beacon_block_root)
             .field("spec_id", &self.spec_id)
             .field("gas_used", &self.gas_used)
             .field("blob_gas_used", &self.blob_gas_used)
@@ -140,6 +143,7 @@ pub fn new(evm: E, parent_hash: B256, spec_id: SpecId) -> Self {
         Self {
             evm,
             parent_hash,
+            parent_beacon_block_root: None,
             spec_id,
             receipt_builder: FoundryReceiptBuilder,
             receipts: Vec::new(),
@@ -148,6 +152,12 @@ pub fn new(evm: E, parent_hash: B256, spec_id: SpecId) -> Self {
             state_hook: None,
         }
     }
+
+    /// Sets the parent beacon block root to store in the EIP-4788 beacon roots contract.
+    pub const fn with_parent_beacon_block_root(mut self, root: Option<B256>) -> Self {
+        self.parent_beacon_block_root = root;
+        self
+    }
 }
 
 impl<E> BlockExecutor for AnvilBlockExecutor<E>
@@ -163,6 +173,28 @@ impl<E> BlockExecutor for AnvilBlockExecutor<E>
     type Result = AnvilTxResult<E::HaltReason>;
 
     fn apply_pre_execution_changes(&mut self) -> Result<(), BlockExecutionError> {
+        // EIP-4788: store parent beacon block root in beacon roots contract.
+        if self.spec_id >= SpecId::CANCUN
+            && let Some(parent_beacon_block_root) = self.parent_beacon_block_root
+        {
+            let result = self
+                .evm
+                .transact_system_call(
+                    eip4788::SYSTEM_ADDRESS,
+                    eip4788::BEACON_ROOTS_ADDRESS,
+                    Bytes::copy_from_slice(parent_beacon_block_root.as_slice()),
+                )
+                .map_err(BlockExecutionError::other)?;
+
+            if let Some(hook) = &mut self.state_hook {
+                hook.on_state(
+                    StateChangeSource::PreBlock(StateChangePreBlockSource::BeaconRootContract),
+                    &result.state,
+                );
+            }
+            self.evm.db_mut().commit(result.state);
+        }
+
         // EIP-2935: store parent block hash in history storage contract.
         if self.spec_id >= SpecId::PRAGUE {
             let result = self
@@ -238,7 +270,9 @@ fn commit_transaction(
         }
 
         let receipt = if tx_type == FoundryTxType::Deposit {
-            let deposit_nonce = state.get(&sender).map(|acc| acc.info.nonce);
+            // The deposit nonce is the nonce of the sender before the deposit, which always
+            // increments it, even if it halts.
+            let deposit_nonce = state.get(&sender).map(|acc| acc.info.nonce.saturating_sub(1));
             let receipt = alloy_consensus::Receipt {
                 status: Eip658Value::Eip658(result.is_success()),
                 cumulative_gas_used: self.gas_used,
@@ -4,7 +4,7 @@
 use alloy_consensus::{BlockHeader, TrieAccount};
 use alloy_eips::eip2930::AccessListResult;
 use alloy_network::{
-    AnyNetwork, AnyRpcBlock, BlockResponse, Network, TransactionResponse,
+    AnyNetwork, AnyRpcBlock, AnyTxEnvelope, BlockResponse, Network, TransactionResponse,
     primitives::HeaderResponse,
 };
 use alloy_primitives::{
@@ -21,18 +21,19 @@
     simulate::{SimulatePayload, SimulatedBlock},
     trace::{
         geth::{GethDebugTracingOptions, GethTrace, TraceResult},
+        otterscan::TransactionsWithReceipts,
         parity::{LocalizedTransactionTrace as Trace, TraceResultsWithTransactionHash, TraceType},
     },
 };
 use alloy_transport::TransportError;
-use foundry_common::provider::{ProviderBuilder, RetryProvider};
+use foundry_common::provider::{ProviderBuilder, RetryProvider, failure::RpcFailureInjector};
 use foundry_primitives::{FoundryTxEnvelope, FoundryTxReceipt};
 use parking_lot::{
     RawRwLock, RwLock,
     lock_api::{RwLockReadGuard, RwLockWriteGuard},
 };
 use revm::context_interface::block::BlobExcessGasAndPrice;
-use std::{sync::Arc, time::Duration};
+use std::{fmt, str::FromStr, sync::Arc, time::Duration};
 use tokio::sync::RwLock as AsyncRwLock;
 
 /// Represents a fork of a remote client
@@ -296,6 +297,32 @@ pub async fn trace_replay_block_transactions(
         self.provider().raw_request("trace_replayBlockTransactions".into(), params).await
     }
 
+    /// Searches the history of an address before the given block, in reverse chronological order,
+    /// with the upstream `ots_searchTransactionsBefore` endpoint.
+    pub async fn ots_search_transactions_before(
+        &self,
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> Result<TransactionsWithReceipts<alloy_rpc_types::Transaction<AnyTxEnvelope>>, TransportError>
+    {
+        let params = (address, block_number, page_size);
+        self.provider().raw_request("ots_searchTransactionsBefore".into(), params).await
+    }
+
+    /// Searches the history of an address after the given block, in reverse chronological order,
+    /// with the upstream `ots_searchTransactionsAfter` endpoint.
+    pub async fn ots_search_transactions_after(
+        &self,
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> Result<TransactionsWithReceipts<alloy_rpc_types::Transaction<AnyTxEnvelope>>, TransportError>
+    {
+        let params = (address, block_number, page_size);
+        self.provider().raw_request("ots_searchTransactionsAfter".into(), params).await
+    }
+
     /// Reset the fork to a fresh forked state, and optionally update the fork config
     pub async fn reset(
         &self,
@@ -694,6 +721,12 @@ pub struct ClientForkConfig<N: Network = AnyNetwork> {
     pub total_difficulty: U256,
     /// Transactions to force include in the forked chain
     pub force_transactions: Option<Vec<PoolTransaction<FoundryTxEnvelope>>>,
+    /// Timestamp of the block replayed with `--replay-block`, used for the first mined block
+    pub replay_timestamp: Option<u64>,
+    /// How failed requests to the remote endpoint are handled
+    pub retry_mode: ForkRetryMode,
+    /// Injects failures into the requests sent to the remote endpoint
+    pub failure_injector: RpcFailureInjector,
 }
 
 impl<N: Network> ClientForkConfig<N> {
@@ -717,7 +750,9 @@ fn update_urls(&mut self, urls: Vec<String>) -> Result<(), BlockchainError> {
             .max_retry(self.retries)
             .initial_backoff(self.backoff.as_millis() as u64)
             .compute_units_per_second(self.compute_units_per_second)
-            .headers(self.headers.clone());
+            .headers(self.headers.clone())
+            .failure_injector(self.failure_injector.clone());
+        let builder = self.retry_mode.configure(builder);
 
         self.provider = Arc::new(if urls.len() > 1 {
             builder
@@ -749,6 +784,53 @@ pub fn update_block(
     }
 }
 
+/// How the fork handles requests to the remote endpoint that fail.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ForkRetryMode {
+    /// Retry failed requests with an exponential backoff, then return the error.
+    #[default]
+    Retry,
+    /// Retry failed requests, then answer with the last successful response to the same request
+    /// if there is one.
+    FailOpen,
+    /// Return the error right away, without retrying.
+    Propagate,
+}
+
+impl ForkRetryMode {
+    /// Configures the provider builder of the remote endpoint for this mode.
+    pub fn configure<N: Network>(self, builder: ProviderBuilder<N>) -> ProviderBuilder<N> {
+        match self {
+            Self::Retry => builder,
+            Self::FailOpen => builder.stale_cache(true),
+            Self::Propagate => builder.max_retry(0),
+        }
+    }
+}
+
+impl FromStr for ForkRetryMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "retry" => Ok(Self::Retry),
+            "fail-open" | "failopen" => Ok(Self::FailOpen),
+            "propagate" => Ok(Self::Propagate),
+            _ => Err(format!("Unknown fork retry mode: `{s}`")),
+        }
+    }
+}
+
+impl fmt::Display for ForkRetryMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Retry => f.write_str("retry"),
+            Self::FailOpen => f.write_str("fail-open"),
+            Self::Propagate => f.write_str("propagate"),
+        }
+    }
+}
+
 /// Contains cached state fetched to serve EthApi requests
 ///
 /// This is used as a cache so repeated requests to the same data are not sent to the remote client
@@ -3,6 +3,7 @@
 use crate::eth::backend::db::Db;
 use alloy_genesis::{Genesis, GenesisAccount};
 use alloy_primitives::{Address, U256};
+use foundry_common::named_accounts::NamedAccount;
 use foundry_evm::backend::DatabaseResult;
 use revm::{bytecode::Bytecode, primitives::KECCAK_EMPTY, state::AccountInfo};
 use tokio::sync::RwLockWriteGuard;
@@ -20,6 +21,8 @@ pub struct GenesisConfig {
     pub accounts: Vec<Address>,
     /// The `genesis.json` if provided
     pub genesis_init: Option<Genesis>,
+    /// The named accounts, from `--accounts-config`
+    pub named_accounts: Vec<NamedAccount>,
 }
 
 impl GenesisConfig {
@@ -57,6 +60,17 @@ pub fn apply_genesis_json_alloc(
         Ok(())
     }
 
+    /// Funds the named accounts and sets their code or delegation
+    pub fn apply_named_accounts(&self, db: &mut dyn Db) -> DatabaseResult<()> {
+        for account in &self.named_accounts {
+            db.set_balance(account.address, account.balance)?;
+            if let Some(code) = &account.code {
+                db.set_code(account.address, code.clone())?;
+            }
+        }
+        Ok(())
+    }
+
     /// Converts a [`GenesisAccount`] to an [`AccountInfo`]
     fn genesis_to_account_info(&self, acc: &GenesisAccount) -> AccountInfo {
         let GenesisAccount { code, balance, nonce, .. } = acc.clone();
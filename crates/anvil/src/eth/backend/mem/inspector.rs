@@ -1,7 +1,8 @@
 //! Anvil specific [`revm::Inspector`] implementation
 
 use crate::eth::macros::node_info;
-use alloy_primitives::{Address, Log, U256};
+use alloy_primitives::{Address, B256, Bytes, Log, U256, map::HashMap};
+use foundry_common::{ERC1271_INVALID_VALUE, ERC1271_MAGIC_VALUE};
 use foundry_evm::{
     call_inspectors,
     decode::decode_console_logs,
@@ -16,13 +17,17 @@
     context::ContextTr,
     inspector::JournalExt,
     interpreter::{
-        CallInputs, CallOutcome, CreateInputs, CreateOutcome, Interpreter,
-        interpreter::EthInterpreter,
+        CallInputs, CallOutcome, CreateInputs, CreateOutcome, Gas, InstructionResult, Interpreter,
+        InterpreterResult, interpreter::EthInterpreter,
     },
 };
 use revm_inspectors::transfer::TransferInspector;
 use std::sync::Arc;
 
+/// Gas charged for an overridden EIP-1271 `isValidSignature` call, roughly what a smart wallet
+/// spends to check an ECDSA signature with the `ecrecover` precompile.
+const ERC1271_OVERRIDE_GAS: u64 = 6_000;
+
 /// The [`revm::Inspector`] used when transacting in the evm
 #[derive(Clone, Debug, Default)]
 pub struct AnvilInspector {
@@ -32,6 +37,8 @@ pub struct AnvilInspector {
     pub log_collector: Option<LogCollector>,
     /// Collects all internal ETH transfers as ERC20 transfer events.
     pub transfer: Option<TransferInspector>,
+    /// Overridden results of EIP-1271 `isValidSignature` calls: (Contract, Hash) => Valid
+    pub erc1271_results: HashMap<(Address, B256), bool>,
 }
 
 /// Configuration for per-transaction inspector lifecycle.
@@ -135,6 +142,47 @@ pub fn with_trace_printer(mut self) -> Self {
         self.tracer = Some(TracingInspector::new(TracingInspectorConfig::all().with_state_diffs()));
         self
     }
+
+    /// Overrides the results of EIP-1271 `isValidSignature` calls
+    pub fn with_erc1271_results(mut self, results: HashMap<(Address, B256), bool>) -> Self {
+        self.erc1271_results = results;
+        self
+    }
+
+    /// Returns the overridden outcome of the call if it's an EIP-1271 `isValidSignature` call
+    /// with a registered result.
+    fn erc1271_outcome<CTX: ContextTr>(
+        &self,
+        ecx: &mut CTX,
+        inputs: &CallInputs,
+    ) -> Option<CallOutcome> {
+        if self.erc1271_results.is_empty() {
+            return None;
+        }
+        let input = inputs.input.bytes(ecx);
+        if input.get(..4)? != ERC1271_MAGIC_VALUE.as_slice() {
+            return None;
+        }
+        let hash = B256::try_from(input.get(4..36)?).ok()?;
+        let valid = *self.erc1271_results.get(&(inputs.target_address, hash))?;
+
+        let mut gas = Gas::new(inputs.gas_limit);
+        let (result, output) = if gas.record_regular_cost(ERC1271_OVERRIDE_GAS) {
+            let mut output = [0u8; 32];
+            let value = if valid { ERC1271_MAGIC_VALUE } else { ERC1271_INVALID_VALUE };
+            output[..4].copy_from_slice(value.as_slice());
+            (InstructionResult::Return, Bytes::copy_from_slice(&output))
+        } else {
+            gas.spend_all();
+            (InstructionResult::OutOfGas, Bytes::new())
+        };
+        Some(CallOutcome {
+            result: InterpreterResult { result, output, gas },
+            memory_offset: inputs.return_memory_offset.clone(),
+            was_precompile_called: false,
+            precompile_call_logs: vec![],
+        })
+    }
 }
 
 /// Prints the traces for the inspector
@@ -200,7 +248,7 @@ fn call(&mut self, ecx: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome
             [&mut self.tracer, &mut self.log_collector, &mut self.transfer],
             |inspector| inspector.call(ecx, inputs).map(Some),
         );
-        None
+        self.erc1271_outcome(ecx, inputs)
     }
 
     fn call_end(&mut self, ecx: &mut CTX, inputs: &CallInputs, outcome: &mut CallOutcome) {
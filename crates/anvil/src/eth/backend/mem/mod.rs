@@ -43,7 +43,7 @@
     transaction::Recovered,
 };
 use alloy_eips::{
-    BlockNumHash, Encodable2718, eip2935, eip4844::kzg_to_versioned_hash,
+    BlockNumHash, Encodable2718, eip2935, eip4788, eip4844::kzg_to_versioned_hash,
     eip7685::EMPTY_REQUESTS_HASH, eip7840::BlobParams, eip7910::SystemContract,
 };
 use alloy_evm::{
@@ -83,14 +83,18 @@
 };
 use alloy_serde::{OtherFields, WithOtherFields};
 use alloy_trie::{HashBuilder, Nibbles, proof::ProofRetainer};
-use anvil_core::eth::{
-    block::{Block, BlockInfo, create_block},
-    transaction::{MaybeImpersonatedTransaction, PendingTransaction, TransactionInfo},
+use anvil_core::{
+    eth::{
+        block::{Block, BlockInfo, create_block},
+        transaction::{MaybeImpersonatedTransaction, PendingTransaction, TransactionInfo},
+    },
+    types::L1BlockValues,
 };
 use anvil_rpc::error::RpcError;
 use chrono::Datelike;
 use eyre::{Context, Result};
 use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use foundry_common::named_accounts::NamedAccount;
 use foundry_evm::{
     backend::{DatabaseError, DatabaseResult, RevertStateSnapshotAction},
     constants::DEFAULT_CREATE2_DEPLOYER_RUNTIME_CODE,
@@ -244,6 +248,8 @@ pub struct Backend<N: Network> {
     new_block_listeners: Arc<Mutex<Vec<UnboundedSender<NewBlockNotification>>>>,
     /// Keeps track of active state snapshots at a specific block.
     active_state_snapshots: Arc<Mutex<HashMap<U256, (u64, B256)>>>,
+    /// The parent beacon block root of the next block, set with `anvil_setBeaconRoot`.
+    next_beacon_root: Arc<RwLock<Option<B256>>>,
     enable_steps_tracing: bool,
     print_logs: bool,
     print_traces: bool,
@@ -280,6 +286,7 @@ fn clone(&self) -> Self {
             genesis: self.genesis.clone(),
             new_block_listeners: self.new_block_listeners.clone(),
             active_state_snapshots: self.active_state_snapshots.clone(),
+            next_beacon_root: self.next_beacon_root.clone(),
             enable_steps_tracing: self.enable_steps_tracing,
             print_logs: self.print_logs,
             print_traces: self.print_traces,
@@ -419,6 +426,11 @@ pub const fn genesis_time(&self) -> u64 {
         self.genesis.timestamp
     }
 
+    /// Returns the named accounts set up at genesis.
+    pub fn named_accounts(&self) -> &[NamedAccount] {
+        &self.genesis.named_accounts
+    }
+
     /// Returns balance of the given account.
     pub async fn current_balance(&self, address: Address) -> DatabaseResult<U256> {
         Ok(self.get_account(address).await?.balance)
@@ -484,6 +496,24 @@ pub fn is_eip4844(&self) -> bool {
         (self.spec_id() as u8) >= (SpecId::CANCUN as u8)
     }
 
+    /// Sets the parent beacon block root of the next block, stored in the EIP-4788 beacon roots
+    /// contract when it is mined.
+    pub fn set_next_beacon_root(&self, root: B256) {
+        *self.next_beacon_root.write() = Some(root);
+    }
+
+    /// Sets the L1 attributes of the OP-stack `L1Block` predeploy.
+    pub async fn set_l1_block_values(&self, values: &L1BlockValues) -> DatabaseResult<()> {
+        crate::eth::backend::optimism::set_l1_block_values(&mut **self.db.write().await, values)
+    }
+
+    /// Returns the parent beacon block root of the next block, if Cancun is active.
+    ///
+    /// Defaults to the zero hash when none was set.
+    fn next_parent_beacon_block_root(&self, spec_id: SpecId) -> Option<B256> {
+        (spec_id >= SpecId::CANCUN).then(|| self.next_beacon_root.read().unwrap_or_default())
+    }
+
     /// Returns true for post Prague
     pub fn is_eip7702(&self) -> bool {
         (self.spec_id() as u8) >= (SpecId::PRAGUE as u8)
@@ -703,7 +733,8 @@ fn next_evm_env(&self) -> EvmEnv {
 
     /// Builds [`Inspector`] with the configured options.
     fn build_inspector(&self) -> AnvilInspector {
-        let mut inspector = AnvilInspector::default();
+        let mut inspector =
+            AnvilInspector::default().with_erc1271_results(self.cheats.erc1271_results());
 
         if self.print_logs {
             inspector = inspector.with_log_collector();
@@ -717,7 +748,9 @@ fn build_inspector(&self) -> AnvilInspector {
 
     /// Builds an inspector configured for block mining (tracing always enabled).
     fn build_mining_inspector(&self) -> AnvilInspector {
-        let mut inspector = AnvilInspector::default().with_tracing();
+        let mut inspector = AnvilInspector::default()
+            .with_tracing()
+            .with_erc1271_results(self.cheats.erc1271_results());
         if self.enable_steps_tracing {
             inspector = inspector.with_steps_tracing();
         }
@@ -843,6 +876,11 @@ pub async fn impersonate_signature(
         Ok(())
     }
 
+    /// Overrides the result of the EIP-1271 `isValidSignature` function of `contract` for `hash`.
+    pub fn set_erc1271_result(&self, contract: Address, hash: B256, valid: bool) {
+        self.cheats.set_erc1271_result(contract, hash, valid);
+    }
+
     /// Returns code by its hash
     pub async fn debug_code_by_hash(
         &self,
@@ -1265,6 +1303,7 @@ fn execute_with_block_executor<DB>(
         db: DB,
         evm_env: &EvmEnv,
         parent_hash: B256,
+        parent_beacon_block_root: Option<B256>,
         spec_id: SpecId,
         pool_transactions: &[Arc<PoolTransaction<FoundryTxEnvelope>>],
         gas_config: &PoolTxGasConfig,
@@ -1282,7 +1321,8 @@ fn execute_with_block_executor<DB>(
         macro_rules! run {
             ($evm:expr) => {{
                 self.inject_precompiles($evm.precompiles_mut());
-                let mut executor = AnvilBlockExecutor::new($evm, parent_hash, spec_id);
+                let mut executor = AnvilBlockExecutor::new($evm, parent_hash, spec_id)
+                    .with_parent_beacon_block_root(parent_beacon_block_root);
                 executor.apply_pre_execution_changes().expect("pre-execution changes failed");
                 let pool_result = execute_pool_transactions(
                     &mut executor,
@@ -1972,6 +2012,7 @@ pub async fn with_genesis(
             fees,
             genesis,
             active_state_snapshots: Arc::new(Mutex::new(Default::default())),
+            next_beacon_root: Default::default(),
             enable_steps_tracing,
             print_logs,
             print_traces,
@@ -2034,6 +2075,11 @@ async fn apply_genesis(&self) -> Result<(), DatabaseError> {
             // the evm
             db.insert_block_hash(U256::from(self.best_number()), self.best_hash());
 
+            // Deploy EIP-4788 beacon roots contract if Cancun is active.
+            if self.spec_id() >= SpecId::CANCUN {
+                db.set_code(eip4788::BEACON_ROOTS_ADDRESS, eip4788::BEACON_ROOTS_CODE.clone())?;
+            }
+
             // Deploy EIP-2935 blockhash history storage contract if Prague is active.
             if self.spec_id() >= SpecId::PRAGUE {
                 db.set_code(
@@ -2047,6 +2093,9 @@ async fn apply_genesis(&self) -> Result<(), DatabaseError> {
         // apply the genesis.json alloc
         self.genesis.apply_genesis_json_alloc(db)?;
 
+        // apply the named accounts
+        self.genesis.apply_named_accounts(&mut **self.db.write().await)?;
+
         // Initialize Tempo precompiles and fee tokens when in Tempo mode (not in fork mode).
         // In fork mode, precompiles are inherited from the forked origin.
         if self.networks.is_tempo() && !self.is_fork() {
@@ -2069,6 +2118,14 @@ async fn apply_genesis(&self) -> Result<(), DatabaseError> {
             trace!(target: "backend", "initialized Tempo precompiles and fee tokens for {} accounts", test_accounts.len());
         }
 
+        // Deploy the OP-stack predeploys when in Optimism mode (not in fork mode).
+        if self.is_optimism() && !self.is_fork() {
+            let values = self.node_config.read().await.l1_block_values;
+            let mut db = self.db.write().await;
+            crate::eth::backend::optimism::initialize_op_predeploys(&mut **db, &values)?;
+            trace!(target: "backend", "initialized OP-stack predeploys");
+        }
+
         trace!(target: "backend", "set genesis balances");
 
         Ok(())
@@ -2477,6 +2534,7 @@ pub async fn mine_block(
     fn build_block_info(
         evm_env: &EvmEnv,
         parent_hash: B256,
+        parent_beacon_block_root: Option<B256>,
         number: u64,
         state_root: B256,
         block_result: BlockExecutionResult<FoundryReceiptEnvelope>,
@@ -2512,7 +2570,7 @@ fn build_block_info(
             mix_hash: evm_env.block_env.prevrandao.unwrap_or_default(),
             nonce: Default::default(),
             base_fee_per_gas: (spec_id >= SpecId::LONDON).then_some(evm_env.block_env.basefee),
-            parent_beacon_block_root: is_cancun.then_some(Default::default()),
+            parent_beacon_block_root,
             blob_gas_used: cumulative_blob_gas_used,
             excess_blob_gas: if is_cancun { evm_env.block_env.blob_excess_gas() } else { None },
             withdrawals_root: is_shanghai.then_some(EMPTY_WITHDRAWALS),
@@ -2579,6 +2637,9 @@ async fn do_mine_block(
                 evm_env.block_env.timestamp = U256::from(self.time.next_timestamp());
 
                 let spec_id = *evm_env.spec_id();
+                let parent_beacon_block_root = self.next_parent_beacon_block_root(spec_id);
+                // The beacon root set with `anvil_setBeaconRoot` only applies to the next block.
+                self.next_beacon_root.write().take();
 
                 let inspector_tx_config = self.inspector_tx_config();
                 let gas_config = self.pool_tx_gas_config(&evm_env);
@@ -2587,6 +2648,7 @@ async fn do_mine_block(
                     &mut **db,
                     &evm_env,
                     best_hash,
+                    parent_beacon_block_root,
                     spec_id,
                     &pool_transactions,
                     &gas_config,
@@ -2604,6 +2666,7 @@ async fn do_mine_block(
                 let block_info = Self::build_block_info(
                     &evm_env,
                     best_hash,
+                    parent_beacon_block_root,
                     block_number,
                     state_root,
                     block_result,
@@ -2777,6 +2840,7 @@ pub async fn with_pending_block<F, T>(
         let parent_hash = self.blockchain.storage.read().best_hash;
 
         let spec_id = *evm_env.spec_id();
+        let parent_beacon_block_root = self.next_parent_beacon_block_root(spec_id);
 
         let inspector_tx_config = self.inspector_tx_config();
         let gas_config = self.pool_tx_gas_config(&evm_env);
@@ -2785,6 +2849,7 @@ pub async fn with_pending_block<F, T>(
             &mut cache_db,
             &evm_env,
             parent_hash,
+            parent_beacon_block_root,
             spec_id,
             &pool_transactions,
             &gas_config,
@@ -2800,6 +2865,7 @@ pub async fn with_pending_block<F, T>(
         let block_info = Self::build_block_info(
             &evm_env,
             parent_hash,
+            parent_beacon_block_root,
             block_number,
             state_root,
             block_result,
@@ -4002,7 +4068,7 @@ pub async fn simulate(
                     // create the transaction from a request
                     let from = request.from.unwrap_or_default();
 
-                    let mut request = Into::<FoundryTransactionRequest>::into(WithOtherFields::new(request));
+                    let mut request = FoundryTransactionRequest::from(request);
                     request.prep_for_submission();
 
                     let typed_tx = request.build_unsigned().map_err(|e| BlockchainError::InvalidTransactionRequest(e.to_string()))?;
@@ -4551,7 +4617,9 @@ pub fn transaction_build(
                 fields.insert("v".to_string(), serde_json::to_value("0x0").unwrap());
                 fields.insert("r".to_string(), serde_json::to_value(B256::ZERO).unwrap());
                 fields.insert(String::from("s"), serde_json::to_value(B256::ZERO).unwrap());
-                fields.insert(String::from("nonce"), serde_json::to_value("0x0").unwrap());
+                // The nonce of a mined deposit is the nonce of its sender before execution
+                let nonce = U64::from(info.as_ref().map(|info| info.nonce).unwrap_or_default());
+                fields.insert(String::from("nonce"), serde_json::to_value(nonce).unwrap());
 
                 let inner = UnknownTypedTransaction {
                     ty: AnyTxType(DEPOSIT_TX_TYPE_ID),
@@ -4596,17 +4664,19 @@ pub fn transaction_build(
                     memo: Default::default(),
                 };
 
-                let envelope = AnyTxEnvelope::Unknown(UnknownTxEnvelope {
-                    hash: eth_transaction.hash(),
-                    inner,
-                });
+                // Impersonated transactions are identified by the provided hash, as for the
+                // standard transactions below
+                let hash = tx_hash.unwrap_or_else(|| eth_transaction.hash());
+                let effective_gas_price = eth_transaction.effective_gas_price(base_fee);
+                let envelope = AnyTxEnvelope::Unknown(UnknownTxEnvelope { hash, inner });
 
                 let tx = Transaction {
                     inner: Recovered::new_unchecked(envelope, from),
                     block_hash: block.as_ref().map(|block| block.header.hash_slow()),
                     block_number: block.as_ref().map(|block| block.header.number()),
                     transaction_index: info.as_ref().map(|info| info.transaction_index),
-                    effective_gas_price: None,
+                    // Tempo transactions pay EIP-1559 fees, in their fee token
+                    effective_gas_price: Some(effective_gas_price),
                     block_timestamp: block.as_ref().map(|block| block.header.timestamp()),
                 };
 
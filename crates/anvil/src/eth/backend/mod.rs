@@ -13,5 +13,6 @@
 pub mod genesis;
 pub mod info;
 pub mod notifications;
+pub mod optimism;
 pub mod tempo;
 pub mod validate;
@@ -0,0 +1,246 @@
+//! OP-stack predeploy initialization for Anvil.
+//!
+//! When running in Optimism mode, Anvil deploys the `L1Block` and `GasPriceOracle` predeploys so
+//! that op-stack contract suites can query the L1 attributes out of the box.
+//!
+//! The predeploys only implement the getters of the upstream contracts, reading the same storage
+//! layout: the L1 attributes set with `anvil_setL1BlockValues` are also the ones used by the EVM
+//! to charge the L1 data fee, and the `GasPriceOracle` storage is seeded as after the Isthmus
+//! upgrade, so that the upstream contracts can be etched over the predeploys.
+
+use super::db::Db;
+use alloy_primitives::{Address, Bytes, U256, address, keccak256};
+use anvil_core::types::L1BlockValues;
+use foundry_evm::backend::DatabaseResult;
+use revm::{
+    bytecode::opcode::{
+        self, AND, CALLDATALOAD, DUP1, EQ, GAS, JUMPDEST, JUMPI, MLOAD, MSTORE, POP, PUSH0, PUSH1,
+        PUSH2, PUSH4, PUSH20, PUSH32, RETURN, REVERT, SHL, SHR, SLOAD, STATICCALL,
+    },
+    database::DatabaseRef,
+};
+
+/// Address of the `L1Block` predeploy.
+pub const L1_BLOCK_ADDRESS: Address = address!("0x4200000000000000000000000000000000000015");
+/// Address of the `GasPriceOracle` predeploy.
+pub const GAS_PRICE_ORACLE_ADDRESS: Address =
+    address!("0x420000000000000000000000000000000000000F");
+/// The account sending the L1 attributes deposit transactions.
+const DEPOSITOR_ACCOUNT: Address = address!("0xDeaDDEaDDeAdDeAdDEAdDEaddeAddEAdDEAd0001");
+
+/// The location of a storage variable of a predeploy.
+#[derive(Clone, Copy, Debug)]
+struct Field {
+    slot: u8,
+    /// The offset of the variable in the slot, in bytes.
+    offset: u8,
+    bits: usize,
+}
+
+impl Field {
+    const fn new(slot: u8, offset: u8, bits: usize) -> Self {
+        Self { slot, offset, bits }
+    }
+}
+
+// `L1Block`
+const NUMBER: Field = Field::new(0, 0, 64);
+const TIMESTAMP: Field = Field::new(0, 8, 64);
+const BASEFEE: Field = Field::new(1, 0, 256);
+const HASH: Field = Field::new(2, 0, 256);
+const SEQUENCE_NUMBER: Field = Field::new(3, 0, 64);
+const BLOB_BASE_FEE_SCALAR: Field = Field::new(3, 8, 32);
+const BASE_FEE_SCALAR: Field = Field::new(3, 12, 32);
+const BATCHER_HASH: Field = Field::new(4, 0, 256);
+const L1_FEE_OVERHEAD: Field = Field::new(5, 0, 256);
+const L1_FEE_SCALAR: Field = Field::new(6, 0, 256);
+const BLOB_BASE_FEE: Field = Field::new(7, 0, 256);
+const OPERATOR_FEE_CONSTANT: Field = Field::new(8, 0, 64);
+const OPERATOR_FEE_SCALAR: Field = Field::new(8, 8, 32);
+
+// `GasPriceOracle`
+const IS_ECOTONE: Field = Field::new(0, 0, 8);
+const IS_FJORD: Field = Field::new(0, 1, 8);
+const IS_ISTHMUS: Field = Field::new(0, 2, 8);
+
+/// The value returned by a predeploy getter.
+#[derive(Clone, Copy, Debug)]
+enum Getter {
+    /// A storage variable of the predeploy.
+    Storage(Field),
+    /// A constant value.
+    Constant(U256),
+    /// A constant address.
+    Address(Address),
+    /// The base fee of the current block.
+    BaseFee,
+    /// The value returned by the given getter of the `L1Block` predeploy.
+    L1Block(&'static str),
+}
+
+const L1_BLOCK_GETTERS: &[(&str, Getter)] = &[
+    ("number()", Getter::Storage(NUMBER)),
+    ("timestamp()", Getter::Storage(TIMESTAMP)),
+    ("basefee()", Getter::Storage(BASEFEE)),
+    ("hash()", Getter::Storage(HASH)),
+    ("sequenceNumber()", Getter::Storage(SEQUENCE_NUMBER)),
+    ("blobBaseFeeScalar()", Getter::Storage(BLOB_BASE_FEE_SCALAR)),
+    ("baseFeeScalar()", Getter::Storage(BASE_FEE_SCALAR)),
+    ("batcherHash()", Getter::Storage(BATCHER_HASH)),
+    ("l1FeeOverhead()", Getter::Storage(L1_FEE_OVERHEAD)),
+    ("l1FeeScalar()", Getter::Storage(L1_FEE_SCALAR)),
+    ("blobBaseFee()", Getter::Storage(BLOB_BASE_FEE)),
+    ("operatorFeeConstant()", Getter::Storage(OPERATOR_FEE_CONSTANT)),
+    ("operatorFeeScalar()", Getter::Storage(OPERATOR_FEE_SCALAR)),
+    ("DEPOSITOR_ACCOUNT()", Getter::Address(DEPOSITOR_ACCOUNT)),
+];
+
+const GAS_PRICE_ORACLE_GETTERS: &[(&str, Getter)] = &[
+    ("DECIMALS()", Getter::Constant(U256::from_limbs([6, 0, 0, 0]))),
+    ("decimals()", Getter::Constant(U256::from_limbs([6, 0, 0, 0]))),
+    ("isEcotone()", Getter::Storage(IS_ECOTONE)),
+    ("isFjord()", Getter::Storage(IS_FJORD)),
+    ("isIsthmus()", Getter::Storage(IS_ISTHMUS)),
+    ("gasPrice()", Getter::BaseFee),
+    ("baseFee()", Getter::BaseFee),
+    ("l1BaseFee()", Getter::L1Block("basefee()")),
+    ("blobBaseFee()", Getter::L1Block("blobBaseFee()")),
+    ("baseFeeScalar()", Getter::L1Block("baseFeeScalar()")),
+    ("blobBaseFeeScalar()", Getter::L1Block("blobBaseFeeScalar()")),
+    ("overhead()", Getter::L1Block("l1FeeOverhead()")),
+    ("scalar()", Getter::L1Block("l1FeeScalar()")),
+];
+
+/// Deploys the OP-stack predeploys and sets the initial L1 attributes.
+pub fn initialize_op_predeploys(db: &mut dyn Db, values: &L1BlockValues) -> DatabaseResult<()> {
+    db.set_code(L1_BLOCK_ADDRESS, getters_code(L1_BLOCK_GETTERS))?;
+    db.set_code(GAS_PRICE_ORACLE_ADDRESS, getters_code(GAS_PRICE_ORACLE_GETTERS))?;
+    for field in [IS_ECOTONE, IS_FJORD, IS_ISTHMUS] {
+        set_field(db, GAS_PRICE_ORACLE_ADDRESS, field, U256::ONE)?;
+    }
+    set_l1_block_values(db, values)
+}
+
+/// Sets the given L1 attributes in the storage of the `L1Block` predeploy, leaving the others
+/// untouched.
+pub fn set_l1_block_values(db: &mut dyn Db, values: &L1BlockValues) -> DatabaseResult<()> {
+    let L1BlockValues {
+        number,
+        timestamp,
+        basefee,
+        hash,
+        sequence_number,
+        blob_base_fee_scalar,
+        base_fee_scalar,
+        batcher_hash,
+        l1_fee_overhead,
+        l1_fee_scalar,
+        blob_base_fee,
+        operator_fee_constant,
+        operator_fee_scalar,
+    } = *values;
+    let fields = [
+        (NUMBER, number.map(U256::from)),
+        (TIMESTAMP, timestamp.map(U256::from)),
+        (BASEFEE, basefee),
+        (HASH, hash.map(Into::into)),
+        (SEQUENCE_NUMBER, sequence_number.map(U256::from)),
+        (BLOB_BASE_FEE_SCALAR, blob_base_fee_scalar.map(U256::from)),
+        (BASE_FEE_SCALAR, base_fee_scalar.map(U256::from)),
+        (BATCHER_HASH, batcher_hash.map(Into::into)),
+        (L1_FEE_OVERHEAD, l1_fee_overhead),
+        (L1_FEE_SCALAR, l1_fee_scalar),
+        (BLOB_BASE_FEE, blob_base_fee),
+        (OPERATOR_FEE_CONSTANT, operator_fee_constant.map(U256::from)),
+        (OPERATOR_FEE_SCALAR, operator_fee_scalar.map(U256::from)),
+    ];
+    for (field, value) in fields {
+        if let Some(value) = value {
+            set_field(db, L1_BLOCK_ADDRESS, field, value)?;
+        }
+    }
+    Ok(())
+}
+
+/// Sets a storage variable of a predeploy, leaving the other variables packed in its slot
+/// untouched.
+fn set_field(db: &mut dyn Db, address: Address, field: Field, value: U256) -> DatabaseResult<()> {
+    let slot = U256::from(field.slot);
+    let shift = field.offset as usize * 8;
+    let mask = (U256::MAX >> (256 - field.bits)) << shift;
+    let word = db.storage_ref(address, slot)?;
+    let word = (word & !mask) | ((value << shift) & mask);
+    db.set_storage_at(address, slot.into(), word.into())
+}
+
+/// Returns the runtime code of a contract implementing the given getters, and reverting on any
+/// other call.
+fn getters_code(getters: &[(&str, Getter)]) -> Bytes {
+    /// `DUP1 PUSH4 <selector> EQ PUSH2 <dest> JUMPI`
+    const DISPATCH_LEN: usize = 11;
+    /// `PUSH1 0 DUP1 REVERT`
+    const REVERT_LEN: usize = 4;
+
+    let bodies = getters.iter().map(|(_, getter)| getter_code(*getter)).collect::<Vec<_>>();
+
+    // Load the selector, and jump to the matching getter.
+    let mut code = vec![PUSH1, 0, CALLDATALOAD, PUSH1, 0xe0, SHR];
+    let mut dest = code.len() + getters.len() * DISPATCH_LEN + REVERT_LEN;
+    for ((signature, _), body) in getters.iter().zip(&bodies) {
+        code.extend([DUP1, PUSH4]);
+        code.extend(selector(signature));
+        code.extend([EQ, PUSH2]);
+        code.extend(u16::try_from(dest).expect("code too large").to_be_bytes());
+        code.push(JUMPI);
+        dest += body.len();
+    }
+    code.extend([PUSH1, 0, DUP1, REVERT]);
+
+    for body in bodies {
+        code.extend(body);
+    }
+    code.into()
+}
+
+/// Returns the code of a getter, returning the value as a single word.
+fn getter_code(getter: Getter) -> Vec<u8> {
+    let mut code = vec![JUMPDEST];
+    match getter {
+        Getter::Storage(field) => {
+            code.extend([PUSH1, field.slot, SLOAD]);
+            if field.offset > 0 {
+                code.extend([PUSH1, field.offset * 8, SHR]);
+            }
+            if field.bits < 256 {
+                let len = field.bits / 8;
+                code.push(PUSH0 + len as u8);
+                code.extend(std::iter::repeat_n(0xff, len));
+                code.push(AND);
+            }
+        }
+        Getter::Constant(value) => {
+            code.push(PUSH32);
+            code.extend(value.to_be_bytes::<32>());
+        }
+        Getter::Address(address) => {
+            code.push(PUSH20);
+            code.extend_from_slice(address.as_slice());
+        }
+        Getter::BaseFee => code.push(opcode::BASEFEE),
+        Getter::L1Block(signature) => {
+            // Store the selector, call the predeploy and load the returned word.
+            code.push(PUSH4);
+            code.extend(selector(signature));
+            code.extend([PUSH1, 0xe0, SHL, PUSH1, 0, MSTORE]);
+            code.extend([PUSH1, 0x20, PUSH1, 0, PUSH1, 4, PUSH1, 0, PUSH20]);
+            code.extend_from_slice(L1_BLOCK_ADDRESS.as_slice());
+            code.extend([GAS, STATICCALL, POP, PUSH1, 0, MLOAD]);
+        }
+    }
+    code.extend([PUSH1, 0, MSTORE, PUSH1, 0x20, PUSH1, 0, RETURN]);
+    code
+}
+
+fn selector(signature: &str) -> [u8; 4] {
+    keccak256(signature)[..4].try_into().unwrap()
+}
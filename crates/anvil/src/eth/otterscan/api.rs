@@ -13,10 +13,14 @@
     Block, BlockId, BlockNumberOrTag as BlockNumber, BlockTransactions,
     trace::{
         otterscan::{
-            BlockDetails, ContractCreator, InternalOperation, OtsBlock, OtsBlockTransactions,
-            OtsReceipt, OtsSlimBlock, OtsTransactionReceipt, TraceEntry, TransactionsWithReceipts,
+            BlockDetails, ContractCreator, InternalOperation, OperationType, OtsBlock,
+            OtsBlockTransactions, OtsReceipt, OtsSlimBlock, OtsTransactionReceipt, TraceEntry,
+            TransactionsWithReceipts,
+        },
+        parity::{
+            Action, CallType, CreateAction, CreateOutput, CreationMethod, TraceOutput,
+            TransactionTrace,
         },
-        parity::{Action, CreateAction, CreateOutput, TraceOutput},
     },
 };
 use foundry_primitives::FoundryNetwork;
@@ -49,13 +53,26 @@ pub async fn ots_get_api_level(&self) -> Result<u64> {
 
     /// Trace internal ETH transfers, contracts creation (CREATE/CREATE2) and self-destructs for a
     /// certain transaction.
+    ///
+    /// Transactions predating the fork are traced by the forked node.
     pub async fn ots_get_internal_operations(&self, hash: B256) -> Result<Vec<InternalOperation>> {
         node_info!("ots_getInternalOperations");
 
-        self.backend
-            .mined_transaction(hash)
-            .map(|tx| tx.ots_internal_operations())
-            .ok_or_else(|| BlockchainError::DataUnavailable)
+        if let Some(tx) = self.backend.mined_transaction(hash) {
+            return Ok(tx.ots_internal_operations());
+        }
+
+        if let Some(fork) = self.get_fork() {
+            let traces = fork.trace_transaction(hash).await?;
+            if !traces.is_empty() {
+                return Ok(traces
+                    .iter()
+                    .filter_map(|trace| internal_operation(&trace.trace))
+                    .collect());
+            }
+        }
+
+        Err(BlockchainError::DataUnavailable)
     }
 
     /// Check if an ETH address contains code at a certain block number.
@@ -145,6 +162,8 @@ pub async fn ots_get_block_transactions(
     }
 
     /// Address history navigation. searches backwards from certain point in time.
+    ///
+    /// When forking, the history predating the fork is searched by the forked node.
     pub async fn ots_search_transactions_before(
         &self,
         address: Address,
@@ -185,10 +204,36 @@ pub async fn ots_search_transactions_before(
             }
         }
 
-        self.build_ots_search_transactions(res, first_page, last_page).await
+        let mut result = self.build_ots_search_transactions(res, first_page, last_page).await?;
+
+        // continue with the history predating the fork
+        if let Some(fork) = self.get_fork()
+            && (from < to || last_page)
+            && result.txs.len() < page_size
+        {
+            let before = if block_number == 0 { to } else { block_number.min(to) };
+            match fork
+                .ots_search_transactions_before(address, before, page_size - result.txs.len())
+                .await
+            {
+                Ok(forked) => {
+                    result.txs.extend(forked.txs);
+                    result.receipts.extend(forked.receipts);
+                    result.last_page = forked.last_page;
+                }
+                Err(err) => {
+                    trace!(target: "node", "failed to search the forked history: {err}");
+                    result.last_page = true;
+                }
+            }
+        }
+
+        Ok(result)
     }
 
     /// Address history navigation. searches forward from certain point in time.
+    ///
+    /// When forking, the history predating the fork is searched by the forked node.
     pub async fn ots_search_transactions_after(
         &self,
         address: Address,
@@ -197,6 +242,40 @@ pub async fn ots_search_transactions_after(
     ) -> Result<TransactionsWithReceipts<alloy_rpc_types::Transaction<AnyTxEnvelope>>> {
         node_info!("ots_searchTransactionsAfter");
 
+        // start with the history predating the fork
+        let mut forked = None;
+        if let Some(fork) = self.get_fork()
+            && block_number < fork.block_number()
+        {
+            match fork.ots_search_transactions_after(address, block_number, page_size).await {
+                Ok(mut result) => {
+                    // the forked node may be ahead of the fork
+                    let len = result.txs.len();
+                    let (txs, receipts) = result
+                        .txs
+                        .into_iter()
+                        .zip(result.receipts)
+                        .filter(|(tx, _)| {
+                            tx.block_number.is_some_and(|n| fork.predates_fork_inclusive(n))
+                        })
+                        .unzip();
+                    result.txs = txs;
+                    result.receipts = receipts;
+                    if result.txs.len() == len && !result.first_page {
+                        return Ok(result);
+                    }
+                    forked = Some(result);
+                }
+                Err(err) => {
+                    trace!(target: "node", "failed to search the forked history: {err}");
+                }
+            }
+        }
+        let block_number = match self.get_fork() {
+            Some(fork) if forked.is_some() => fork.block_number(),
+            _ => block_number,
+        };
+
         let best = self.backend.best_number();
         // we go from the first post-fork block, up to the tip
         let first_block = self.get_fork().map(|f| f.block_number() + 1).unwrap_or(1);
@@ -235,7 +314,13 @@ pub async fn ots_search_transactions_after(
 
         // Results are always sent in reverse chronological order, according to the Otterscan spec
         res.reverse();
-        self.build_ots_search_transactions(res, first_page, last_page).await
+        let mut result = self.build_ots_search_transactions(res, first_page, last_page).await?;
+        if let Some(forked) = forked {
+            result.txs.extend(forked.txs);
+            result.receipts.extend(forked.receipts);
+            result.last_page = forked.last_page;
+        }
+        Ok(result)
     }
 
     /// Given a sender address and a nonce, returns the tx hash or null if not found. It returns
@@ -441,3 +526,35 @@ pub async fn build_ots_search_transactions(
         Ok(TransactionsWithReceipts { txs, receipts, first_page, last_page })
     }
 }
+
+/// Converts the parity trace of a forked transaction to an internal operation, as
+/// [`ots_internal_operations`](crate::eth::backend::mem::storage::MinedTransaction::ots_internal_operations)
+/// does for mined transactions.
+fn internal_operation(trace: &TransactionTrace) -> Option<InternalOperation> {
+    match (&trace.action, &trace.result) {
+        (Action::Call(call), _) if call.call_type == CallType::Call && !call.value.is_zero() => {
+            Some(InternalOperation {
+                r#type: OperationType::OpTransfer,
+                from: call.from,
+                to: call.to,
+                value: call.value,
+            })
+        }
+        (Action::Create(create), Some(TraceOutput::Create(output))) => Some(InternalOperation {
+            r#type: match create.creation_method {
+                CreationMethod::Create2 => OperationType::OpCreate2,
+                _ => OperationType::OpCreate,
+            },
+            from: create.from,
+            to: output.address,
+            value: create.value,
+        }),
+        (Action::Selfdestruct(selfdestruct), _) => Some(InternalOperation {
+            r#type: OperationType::OpSelfDestruct,
+            from: selfdestruct.address,
+            to: selfdestruct.refund_address,
+            value: selfdestruct.balance,
+        }),
+        _ => None,
+    }
+}
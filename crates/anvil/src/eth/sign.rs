@@ -3,10 +3,13 @@
 use alloy_dyn_abi::TypedData;
 use alloy_network::{Network, TxSignerSync};
 use alloy_primitives::{Address, B256, Signature, map::AddressHashMap};
-use alloy_signer::Signer as AlloySigner;
+use alloy_signer::{Signer as AlloySigner, SignerSync};
 use alloy_signer_local::PrivateKeySigner;
 use foundry_primitives::{FoundryTxEnvelope, FoundryTypedTx};
-use tempo_primitives::TempoSignature;
+use rayon::prelude::*;
+use tempo_primitives::{
+    AASigned, TempoSignature, TempoTransaction, transaction::PrimitiveSignature,
+};
 
 /// Network-agnostic signing: messages, typed data, and hashes.
 #[async_trait::async_trait]
@@ -48,6 +51,18 @@ fn sign_transaction_from(
         sender: &Address,
         tx: N::UnsignedTx,
     ) -> Result<N::TxEnvelope, BlockchainError>;
+
+    /// Signs many unsigned transactions from the same sender.
+    ///
+    /// The envelopes are returned in the order of `txs`, so a batch built in nonce order can be
+    /// submitted as is. Signs the transactions one by one by default.
+    fn sign_transactions_from(
+        &self,
+        sender: &Address,
+        txs: Vec<N::UnsignedTx>,
+    ) -> Result<Vec<N::TxEnvelope>, BlockchainError> {
+        txs.into_iter().map(|tx| self.sign_transaction_from(sender, tx)).collect()
+    }
 }
 
 /// Maintains developer keys
@@ -109,42 +124,71 @@ fn sign_transaction_from(
         tx: FoundryTypedTx,
     ) -> Result<FoundryTxEnvelope, BlockchainError> {
         let signer = self.accounts.get(sender).ok_or(BlockchainError::NoSignerAvailable)?;
-        let envelope = match tx {
-            FoundryTypedTx::Legacy(mut t) => {
-                let sig = signer.sign_transaction_sync(&mut t)?;
-                FoundryTxEnvelope::Legacy(t.into_signed(sig))
-            }
-            FoundryTypedTx::Eip2930(mut t) => {
-                let sig = signer.sign_transaction_sync(&mut t)?;
-                FoundryTxEnvelope::Eip2930(t.into_signed(sig))
-            }
-            FoundryTypedTx::Eip1559(mut t) => {
-                let sig = signer.sign_transaction_sync(&mut t)?;
-                FoundryTxEnvelope::Eip1559(t.into_signed(sig))
-            }
-            FoundryTypedTx::Eip7702(mut t) => {
-                let sig = signer.sign_transaction_sync(&mut t)?;
-                FoundryTxEnvelope::Eip7702(t.into_signed(sig))
-            }
-            FoundryTypedTx::Eip4844(mut t) => {
-                let sig = signer.sign_transaction_sync(&mut t)?;
-                FoundryTxEnvelope::Eip4844(t.into_signed(sig))
-            }
-            FoundryTypedTx::Deposit(_) => {
-                unreachable!("op deposit txs should not be signed")
-            }
-            FoundryTypedTx::PostExec(_) => {
-                unreachable!("op post-exec txs should not be signed")
-            }
-            FoundryTypedTx::Tempo(mut t) => {
-                let sig = signer.sign_transaction_sync(&mut t)?;
-                FoundryTxEnvelope::Tempo(t.into_signed(sig.into()))
-            }
-        };
-        Ok(envelope)
+        sign_typed_tx(signer, tx)
+    }
+
+    /// Signs the transactions concurrently, the key of the sender being shared across threads.
+    fn sign_transactions_from(
+        &self,
+        sender: &Address,
+        txs: Vec<FoundryTypedTx>,
+    ) -> Result<Vec<FoundryTxEnvelope>, BlockchainError> {
+        let signer = self.accounts.get(sender).ok_or(BlockchainError::NoSignerAvailable)?;
+        txs.into_par_iter().map(|tx| sign_typed_tx(signer, tx)).collect()
     }
 }
 
+/// Signs a transaction with a secp256k1 key.
+fn sign_typed_tx(
+    signer: &PrivateKeySigner,
+    tx: FoundryTypedTx,
+) -> Result<FoundryTxEnvelope, BlockchainError> {
+    let envelope = match tx {
+        FoundryTypedTx::Legacy(mut t) => {
+            let sig = signer.sign_transaction_sync(&mut t)?;
+            FoundryTxEnvelope::Legacy(t.into_signed(sig))
+        }
+        FoundryTypedTx::Eip2930(mut t) => {
+            let sig = signer.sign_transaction_sync(&mut t)?;
+            FoundryTxEnvelope::Eip2930(t.into_signed(sig))
+        }
+        FoundryTypedTx::Eip1559(mut t) => {
+            let sig = signer.sign_transaction_sync(&mut t)?;
+            FoundryTxEnvelope::Eip1559(t.into_signed(sig))
+        }
+        FoundryTypedTx::Eip7702(mut t) => {
+            let sig = signer.sign_transaction_sync(&mut t)?;
+            FoundryTxEnvelope::Eip7702(t.into_signed(sig))
+        }
+        FoundryTypedTx::Eip4844(mut t) => {
+            let sig = signer.sign_transaction_sync(&mut t)?;
+            FoundryTxEnvelope::Eip4844(t.into_signed(sig))
+        }
+        FoundryTypedTx::Deposit(_) => {
+            unreachable!("op deposit txs should not be signed")
+        }
+        FoundryTypedTx::PostExec(_) => {
+            unreachable!("op post-exec txs should not be signed")
+        }
+        FoundryTypedTx::Tempo(t) => FoundryTxEnvelope::Tempo(sign_tempo(signer, t)?),
+    };
+    Ok(envelope)
+}
+
+/// Signs a Tempo transaction with a secp256k1 key.
+///
+/// Tempo transactions are signed over their own signature hash, which commits to the `0x76` type
+/// and leaves out the fee payer signature so that the fees can be sponsored after signing. Unlike
+/// legacy transactions, the chain ID is part of the signed payload and is not applied to `v`.
+pub fn sign_tempo(
+    signer: &PrivateKeySigner,
+    tx: TempoTransaction,
+) -> Result<AASigned, BlockchainError> {
+    let signature = signer.sign_hash_sync(&tx.signature_hash())?;
+    let signature = TempoSignature::Primitive(PrimitiveSignature::Secp256k1(signature));
+    Ok(AASigned::new_unhashed(tx, signature))
+}
+
 /// Builds a TxEnvelope from UnsignedTx with a zeroed signature.
 ///
 /// Used for impersonated accounts, where transactions are accepted without a valid signature.
@@ -48,7 +48,8 @@
 
 mod config;
 pub use config::{
-    AccountGenerator, CHAIN_ID, DEFAULT_GAS_LIMIT, ForkChoice, NodeConfig, VERSION_MESSAGE,
+    AccountGenerator, CHAIN_ID, DEFAULT_GAS_LIMIT, EngineApiConfig, ForkChoice, NodeConfig,
+    VERSION_MESSAGE,
 };
 
 mod error;
@@ -256,6 +257,23 @@ pub async fn try_spawn(mut config: NodeConfig) -> Result<(EthApi<FoundryNetwork>
         servers.push(tokio::task::spawn(srv.map_err(Into::into)));
     }
 
+    let mut engine_addresses = Vec::new();
+    if let Some(engine) = &config.engine_api {
+        for addr in &config.host {
+            let tcp_listener =
+                tokio::net::TcpListener::bind(SocketAddr::new(*addr, engine.port)).await?;
+            engine_addresses.push(tcp_listener.local_addr()?);
+
+            let srv = server::engine::serve_on(
+                tcp_listener,
+                api.clone(),
+                server_config.clone(),
+                engine.jwt_secret,
+            );
+            servers.push(tokio::task::spawn(srv.map_err(Into::into)));
+        }
+    }
+
     let tokio_handle = Handle::current();
     let (signal, on_shutdown) = shutdown::signal();
     let task_manager = TaskManager::new(tokio_handle, on_shutdown);
@@ -269,6 +287,7 @@ pub async fn try_spawn(mut config: NodeConfig) -> Result<(EthApi<FoundryNetwork>
         servers,
         ipc_task,
         addresses,
+        engine_addresses,
         _signal: Some(signal),
         task_manager,
     };
@@ -287,6 +306,8 @@ pub struct NodeHandle {
     config: NodeConfig,
     /// The address of the running rpc server.
     addresses: Vec<SocketAddr>,
+    /// The addresses of the running Engine API server, if enabled.
+    engine_addresses: Vec<SocketAddr>,
     /// Join handle for the Node Service.
     pub node_service: JoinHandle<Result<(), NodeError>>,
     /// Join handles (one per socket) for the Anvil server.
@@ -329,6 +350,22 @@ pub(crate) fn print(&self, fork: Option<&ClientFork>) -> Result<()> {
                     .collect::<Vec<String>>()
                     .join(", ")
             )?;
+            if let Some(engine) = &self.config.engine_api {
+                sh_println!(
+                    "Engine API listening on {}",
+                    self.engine_addresses
+                        .iter()
+                        .map(|addr| addr.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )?;
+                if engine.generated_secret {
+                    sh_println!(
+                        "Engine API JWT secret: {}",
+                        alloy_primitives::hex::encode_prefixed(engine.jwt_secret.as_bytes())
+                    )?;
+                }
+            }
         }
         Ok(())
     }
@@ -351,6 +388,11 @@ pub fn ws_endpoint(&self) -> String {
         format!("ws://{}", self.socket_address())
     }
 
+    /// Returns the http endpoint of the Engine API server, if enabled.
+    pub fn engine_endpoint(&self) -> Option<String> {
+        self.engine_addresses.first().map(|addr| format!("http://{addr}"))
+    }
+
     /// Returns the path of the launched ipc server, if any.
     pub fn ipc_path(&self) -> Option<String> {
         self.config.get_ipc_path()
@@ -0,0 +1,170 @@
+//! A stub of the Engine API, to use anvil as a mock execution layer for consensus clients and
+//! staking tooling.
+//!
+//! Payloads are acknowledged without being executed: a payload is `VALID` if its parent is a block
+//! of the node or a previously received payload, and `SYNCING` otherwise. Payload building is not
+//! supported.
+
+use crate::EthApi;
+use alloy_primitives::{B256, map::HashMap};
+use alloy_rpc_types_engine::{
+    ForkchoiceState, ForkchoiceUpdated, JwtSecret, PayloadStatus, PayloadStatusEnum,
+};
+use anvil_rpc::{error::RpcError, response::ResponseResult};
+use anvil_server::{RpcHandler, ServerConfig};
+use axum::{
+    Router,
+    extract::{Request, State},
+    http::{StatusCode, header},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+};
+use foundry_primitives::FoundryNetwork;
+use parking_lot::RwLock;
+use serde::Deserialize;
+use serde_json::Value;
+use std::{io, sync::Arc};
+use tokio::net::TcpListener;
+
+/// The Engine API methods supported by the stub.
+const CAPABILITIES: &[&str] = &[
+    "engine_exchangeCapabilities",
+    "engine_newPayloadV1",
+    "engine_newPayloadV2",
+    "engine_newPayloadV3",
+    "engine_newPayloadV4",
+    "engine_forkchoiceUpdatedV1",
+    "engine_forkchoiceUpdatedV2",
+    "engine_forkchoiceUpdatedV3",
+];
+
+/// An Engine API call.
+///
+/// The payloads are kept as JSON: only their block and parent hashes are used.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "method", content = "params")]
+pub enum EngineRequest {
+    #[serde(rename = "engine_exchangeCapabilities")]
+    ExchangeCapabilities((Vec<String>,)),
+
+    #[serde(
+        rename = "engine_newPayloadV1",
+        alias = "engine_newPayloadV2",
+        alias = "engine_newPayloadV3",
+        alias = "engine_newPayloadV4"
+    )]
+    NewPayload(Vec<Value>),
+
+    #[serde(
+        rename = "engine_forkchoiceUpdatedV1",
+        alias = "engine_forkchoiceUpdatedV2",
+        alias = "engine_forkchoiceUpdatedV3"
+    )]
+    ForkchoiceUpdated(Vec<Value>),
+}
+
+/// A `RpcHandler` that expects `EngineRequest` rpc calls via http
+#[derive(Clone)]
+pub struct EngineRpcHandler {
+    /// Access to the node
+    api: EthApi<FoundryNetwork>,
+    /// The parent hashes of the received payloads, by block hash
+    payloads: Arc<RwLock<HashMap<B256, B256>>>,
+}
+
+impl EngineRpcHandler {
+    /// Creates a new instance of the handler using the given `EthApi`
+    pub fn new(api: EthApi<FoundryNetwork>) -> Self {
+        Self { api, payloads: Default::default() }
+    }
+
+    /// Returns whether the block is a block of the node or a received payload.
+    async fn is_known(&self, hash: B256) -> bool {
+        self.payloads.read().contains_key(&hash)
+            || matches!(self.api.block_by_hash(hash).await, Ok(Some(_)))
+    }
+
+    async fn new_payload(&self, params: Vec<Value>) -> Result<PayloadStatus, RpcError> {
+        let payload = params.first().ok_or_else(|| RpcError::invalid_params("missing payload"))?;
+        let hash = |field: &str| {
+            payload
+                .get(field)
+                .and_then(|hash| serde_json::from_value::<B256>(hash.clone()).ok())
+                .ok_or_else(|| RpcError::invalid_params(format!("missing payload `{field}`")))
+        };
+        let (block_hash, parent_hash) = (hash("blockHash")?, hash("parentHash")?);
+
+        if !self.is_known(parent_hash).await {
+            return Ok(PayloadStatus::from_status(PayloadStatusEnum::Syncing));
+        }
+        self.payloads.write().insert(block_hash, parent_hash);
+        Ok(PayloadStatus::new(PayloadStatusEnum::Valid, Some(block_hash)))
+    }
+
+    async fn forkchoice_updated(&self, params: Vec<Value>) -> Result<ForkchoiceUpdated, RpcError> {
+        let state =
+            params.first().ok_or_else(|| RpcError::invalid_params("missing forkchoice state"))?;
+        let state = serde_json::from_value::<ForkchoiceState>(state.clone())
+            .map_err(|err| RpcError::invalid_params(err.to_string()))?;
+
+        if !self.is_known(state.head_block_hash).await {
+            return Ok(ForkchoiceUpdated::from_status(PayloadStatusEnum::Syncing));
+        }
+        if params.get(1).is_some_and(|attributes| !attributes.is_null()) {
+            warn!(target: "engine", "payload building is not supported, ignoring payload attributes");
+        }
+        Ok(ForkchoiceUpdated::from_status(PayloadStatusEnum::Valid)
+            .with_latest_valid_hash(state.head_block_hash))
+    }
+}
+
+#[async_trait::async_trait]
+impl RpcHandler for EngineRpcHandler {
+    type Request = EngineRequest;
+
+    async fn on_request(&self, request: Self::Request) -> ResponseResult {
+        trace!(target: "engine", "received engine request {:?}", request);
+        let result = match request {
+            EngineRequest::ExchangeCapabilities(_) => return ResponseResult::success(CAPABILITIES),
+            EngineRequest::NewPayload(params) => {
+                self.new_payload(params).await.map(ResponseResult::success)
+            }
+            EngineRequest::ForkchoiceUpdated(params) => {
+                self.forkchoice_updated(params).await.map(ResponseResult::success)
+            }
+        };
+        result.unwrap_or_else(ResponseResult::Error)
+    }
+}
+
+/// Configures an [`axum::Router`] that handles Engine API calls authenticated with a JWT.
+///
+/// CORS is always disabled, as the Engine API is not meant to be called from browsers.
+pub fn router(api: EthApi<FoundryNetwork>, config: ServerConfig, secret: JwtSecret) -> Router {
+    anvil_server::http_router(config.set_cors(false), EngineRpcHandler::new(api))
+        .layer(middleware::from_fn_with_state(secret, authenticate))
+}
+
+/// Serves the Engine API on the given listener.
+pub async fn serve_on(
+    tcp_listener: TcpListener,
+    api: EthApi<FoundryNetwork>,
+    config: ServerConfig,
+    secret: JwtSecret,
+) -> io::Result<()> {
+    axum::serve(tcp_listener, router(api, config, secret).into_make_service()).await
+}
+
+/// Rejects the requests without a valid `Authorization: Bearer <jwt>` header.
+async fn authenticate(State(secret): State<JwtSecret>, request: Request, next: Next) -> Response {
+    let token = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    match token.map(|token| secret.validate(token)) {
+        Some(Ok(())) => next.run(request).await,
+        Some(Err(err)) => (StatusCode::UNAUTHORIZED, err.to_string()).into_response(),
+        None => (StatusCode::UNAUTHORIZED, "missing JWT").into_response(),
+    }
+}
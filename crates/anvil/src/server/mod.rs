@@ -1,5 +1,5 @@
 //! This module provides the infrastructure to launch an Ethereum JSON-RPC server
-//! (via HTTP, WebSocket, and IPC) and Beacon Node REST API.
+//! (via HTTP, WebSocket, and IPC), Beacon Node REST API and Engine API.
 
 use crate::{EthApi, IpcTask};
 use anvil_server::{ServerConfig, ipc::IpcEndpoint};
@@ -11,6 +11,7 @@
 use tokio::net::TcpListener;
 
 mod beacon;
+pub mod engine;
 mod rpc_handlers;
 
 /// Configures a server that handles [`EthApi`] related JSON-RPC calls via HTTP and WS.
@@ -8,6 +8,7 @@
 use alloy_rpc_types::TransactionRequest;
 use alloy_sol_types::SolCall;
 use anvil::{NodeConfig, spawn};
+use foundry_common::named_accounts::NamedAccount;
 use foundry_evm::hardfork::EthereumHardfork;
 
 #[tokio::test(flavor = "multi_thread")]
@@ -230,3 +231,105 @@ async fn test_fake_signature_transaction() {
 
     assert!(result.is_ok(), "ecrecover failed: {:?}", result.err());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_set_erc1271_result() {
+    let (api, handle) = spawn(NodeConfig::test()).await;
+    let provider = handle.http_provider();
+    alloy_sol_types::sol! {
+        #[sol(rpc)]
+        interface IERC1271 {
+            function isValidSignature(bytes32 hash, bytes signature) external view returns (bytes4);
+        }
+    }
+
+    let wallet = Address::random();
+    let hash = B256::random();
+    let contract = IERC1271::new(wallet, &provider);
+
+    api.anvil_set_erc1271_result(wallet, hash, true).await.unwrap();
+    assert!(!provider.get_code_at(wallet).await.unwrap().is_empty());
+    let result = contract.isValidSignature(hash, bytes!("1234")).call().await.unwrap();
+    assert_eq!(result, IERC1271::isValidSignatureCall::SELECTOR);
+
+    // The overridden call is charged like a signature check.
+    let gas = contract.isValidSignature(hash, bytes!("1234")).estimate_gas().await.unwrap();
+    assert!(gas >= 27_000, "overridden call charged {gas} gas");
+
+    api.anvil_set_erc1271_result(wallet, hash, false).await.unwrap();
+    let result = contract.isValidSignature(hash, bytes!("1234")).call().await.unwrap();
+    assert_eq!(result, [0xff; 4]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_list_named_accounts() {
+    let deployer = NamedAccount {
+        name: "deployer".to_string(),
+        role: Some("deployer".to_string()),
+        address: alloy_signer_local::PrivateKeySigner::from_bytes(&B256::repeat_byte(1))
+            .unwrap()
+            .address(),
+        private_key: Some(B256::repeat_byte(1)),
+        balance: U256::from(10u64).pow(U256::from(22u64)),
+        code: None,
+        delegate: None,
+    };
+    let delegate = Address::repeat_byte(0x42);
+    let user = NamedAccount {
+        name: "user1".to_string(),
+        role: Some("user".to_string()),
+        address: Address::repeat_byte(0x11),
+        private_key: None,
+        balance: U256::from(1u64),
+        code: Some([&[0xef, 0x01, 0x00][..], delegate.as_slice()].concat().into()),
+        delegate: Some(delegate),
+    };
+    let config =
+        NodeConfig::test().with_named_accounts(vec![deployer.clone(), user.clone()]).unwrap();
+    let (api, handle) = spawn(config).await;
+    let provider = handle.http_provider();
+
+    assert_eq!(
+        api.anvil_list_named_accounts().await.unwrap(),
+        vec![deployer.clone(), user.clone()]
+    );
+    assert!(api.accounts().unwrap().contains(&deployer.address));
+
+    assert_eq!(provider.get_balance(deployer.address).await.unwrap(), deployer.balance);
+    assert_eq!(provider.get_balance(user.address).await.unwrap(), user.balance);
+    assert_eq!(provider.get_code_at(user.address).await.unwrap(), user.code.unwrap());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_server_cors_origins_and_request_size_limit() {
+    let server_config = anvil_server::ServerConfig {
+        allow_origin: "https://a.dev,https://b.dev".parse().unwrap(),
+        max_request_size: Some(1024),
+        ..Default::default()
+    };
+    let (_api, handle) = spawn(NodeConfig::test().with_server_config(server_config)).await;
+    let client = reqwest::Client::new();
+    let send = |origin: &'static str, body: String| {
+        client
+            .post(handle.http_endpoint())
+            .header(reqwest::header::ORIGIN, origin)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+    };
+    let chain_id = r#"{"jsonrpc":"2.0","id":1,"method":"eth_chainId","params":[]}"#.to_string();
+
+    let response = send("https://b.dev", chain_id.clone()).await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    assert_eq!(response.headers()[reqwest::header::ACCESS_CONTROL_ALLOW_ORIGIN], "https://b.dev");
+
+    let response = send("https://c.dev", chain_id).await.unwrap();
+    assert!(!response.headers().contains_key(reqwest::header::ACCESS_CONTROL_ALLOW_ORIGIN));
+
+    let too_large = format!(
+        r#"{{"jsonrpc":"2.0","id":1,"method":"eth_call","params":[{{"data":"0x{}"}}]}}"#,
+        "00".repeat(1024)
+    );
+    let response = send("https://a.dev", too_large).await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::PAYLOAD_TOO_LARGE);
+}
@@ -0,0 +1,57 @@
+use crate::utils::http_provider;
+use alloy_eips::{BlockNumberOrTag, eip4788::BEACON_ROOTS_ADDRESS};
+use alloy_network::TransactionBuilder;
+use alloy_primitives::{B256, U256};
+use alloy_provider::Provider;
+use alloy_rpc_types::TransactionRequest;
+use anvil::{NodeConfig, spawn};
+use foundry_evm::hardfork::EthereumHardfork;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn eip4788_contract_deployed_at_genesis() {
+    let node_config = NodeConfig::test().with_hardfork(Some(EthereumHardfork::Cancun.into()));
+    let (_api, handle) = spawn(node_config).await;
+    let provider = http_provider(&handle.http_endpoint());
+
+    let code = provider.get_code_at(BEACON_ROOTS_ADDRESS).await.unwrap();
+    assert!(!code.is_empty(), "EIP-4788 beacon roots contract should be deployed at genesis");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn eip4788_stores_beacon_root() {
+    let node_config = NodeConfig::test().with_hardfork(Some(EthereumHardfork::Cancun.into()));
+    let (api, handle) = spawn(node_config).await;
+    let provider = http_provider(&handle.http_endpoint());
+
+    let root = B256::repeat_byte(0x42);
+    api.anvil_set_beacon_root(root).await.unwrap();
+    api.mine_one().await;
+    // The root only applies to the next block.
+    api.mine_one().await;
+
+    let block1 = provider.get_block_by_number(BlockNumberOrTag::from(1)).await.unwrap().unwrap();
+    assert_eq!(block1.header.parent_beacon_block_root, Some(root));
+    let block2 = provider.get_block_by_number(BlockNumberOrTag::from(2)).await.unwrap().unwrap();
+    assert_eq!(block2.header.parent_beacon_block_root, Some(B256::ZERO));
+
+    // The beacon roots contract is queried with the timestamp of the block, as a 32-byte
+    // big-endian word.
+    let get_root = async |timestamp: u64| {
+        let call_data: [u8; 32] = U256::from(timestamp).to_be_bytes();
+        let tx = TransactionRequest::default().with_to(BEACON_ROOTS_ADDRESS).with_input(call_data);
+        B256::from_slice(&provider.call(tx.into()).await.unwrap())
+    };
+    assert_eq!(get_root(block1.header.timestamp).await, root);
+    assert_eq!(get_root(block2.header.timestamp).await, B256::ZERO);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn eip4788_not_supported_before_cancun() {
+    let node_config = NodeConfig::test().with_hardfork(Some(EthereumHardfork::Shanghai.into()));
+    let (api, handle) = spawn(node_config).await;
+    let provider = http_provider(&handle.http_endpoint());
+
+    let code = provider.get_code_at(BEACON_ROOTS_ADDRESS).await.unwrap();
+    assert!(code.is_empty(), "EIP-4788 contract should NOT be deployed before Cancun");
+    assert!(api.anvil_set_beacon_root(B256::repeat_byte(0x42)).await.is_err());
+}
@@ -1,16 +1,17 @@
 use crate::utils::{http_provider, http_provider_with_signer};
-use alloy_consensus::{BlobTransactionSidecar, SidecarBuilder, SimpleCoder, Transaction};
+use alloy_consensus::{BlobTransactionSidecar, SidecarBuilder, Signed, SimpleCoder, Transaction};
 use alloy_eips::{
     Typed2718,
     eip4844::{BLOB_TX_MIN_BLOB_GASPRICE, DATA_GAS_PER_BLOB, MAX_DATA_GAS_PER_BLOCK_DENCUN},
 };
 use alloy_network::{EthereumWallet, ReceiptResponse, TransactionBuilder, TransactionBuilder4844};
 use alloy_primitives::{Address, U256, b256};
-use alloy_provider::{Provider, ProviderBuilder};
+use alloy_provider::{Provider, ProviderBuilder, RootProvider};
 use alloy_rpc_types::{BlockId, TransactionRequest};
 use alloy_serde::WithOtherFields;
 use anvil::{NodeConfig, spawn};
 use foundry_evm::hardfork::EthereumHardfork;
+use foundry_primitives::{FoundryNetwork, FoundryTxEnvelope, PooledTransactionsExt};
 use foundry_test_utils::rpc;
 
 #[tokio::test(flavor = "multi_thread")]
@@ -485,3 +486,63 @@ async fn can_get_blobs_by_tx_hash() {
     let blobs = api.anvil_get_blob_by_tx_hash(hash).unwrap().unwrap();
     assert_eq!(blobs, sidecar.blobs);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn can_get_and_send_pooled_blob_transaction() {
+    let node_config = NodeConfig::test().with_hardfork(Some(EthereumHardfork::Cancun.into()));
+    let (api, handle) = spawn(node_config.clone()).await;
+
+    // Keep the transaction pending so the node still has its blob sidecar.
+    api.anvil_set_auto_mine(false).await.unwrap();
+
+    let wallets = handle.dev_wallets().collect::<Vec<_>>();
+    let from = wallets[0].address();
+    let to = wallets[1].address();
+    let provider = http_provider(&handle.http_endpoint());
+
+    let eip1559_est = provider.estimate_eip1559_fees().await.unwrap();
+    let gas_price = provider.get_gas_price().await.unwrap();
+
+    let sidecar: SidecarBuilder<SimpleCoder> = SidecarBuilder::from_slice(b"Hello World");
+
+    let sidecar: BlobTransactionSidecar = sidecar.build().unwrap();
+    let tx = TransactionRequest::default()
+        .with_from(from)
+        .with_to(to)
+        .with_nonce(0)
+        .with_max_fee_per_blob_gas(gas_price + 1)
+        .with_max_fee_per_gas(eip1559_est.max_fee_per_gas)
+        .with_max_priority_fee_per_gas(eip1559_est.max_priority_fee_per_gas)
+        .with_blob_sidecar_4844(sidecar.clone())
+        .value(U256::from(5));
+
+    let tx = WithOtherFields::new(tx);
+    let hash = *provider.send_transaction(tx).await.unwrap().tx_hash();
+
+    let provider =
+        RootProvider::<FoundryNetwork>::new_http(handle.http_endpoint().parse().unwrap());
+    let pooled = provider.get_pooled_transaction(hash).await.unwrap().unwrap();
+    assert_eq!(pooled.hash(), hash);
+    assert_eq!(pooled.sidecar().unwrap().sidecar.as_eip4844(), Some(&sidecar));
+
+    // The transaction can not be broadcast without its sidecar.
+    let FoundryTxEnvelope::Eip4844(signed) = pooled.clone() else {
+        panic!("expected an EIP-4844 transaction")
+    };
+    let (tx, signature, tx_hash) = signed.into_parts();
+    let stripped = FoundryTxEnvelope::Eip4844(Signed::new_unchecked(
+        tx.tx().clone().into(),
+        signature,
+        tx_hash,
+    ));
+    assert!(provider.send_pooled_transaction(&stripped).await.is_err());
+
+    // Rebroadcast the pooled transaction to a fresh node.
+    let (_api, handle) = spawn(node_config).await;
+    let provider =
+        RootProvider::<FoundryNetwork>::new_http(handle.http_endpoint().parse().unwrap());
+    let receipt =
+        provider.send_pooled_transaction(&pooled).await.unwrap().get_receipt().await.unwrap();
+    assert_eq!(receipt.transaction_hash(), hash);
+    assert_eq!(receipt.blob_gas_used(), Some(131072));
+}
@@ -0,0 +1,82 @@
+use alloy_primitives::B256;
+use alloy_provider::Provider;
+use alloy_rpc_types_engine::{Claims, JwtSecret};
+use anvil::{EngineApiConfig, NodeConfig, spawn};
+use serde_json::{Value, json};
+
+async fn engine_call(url: &str, token: Option<&str>, method: &str, params: Value) -> (u16, Value) {
+    let mut request = reqwest::Client::new()
+        .post(url)
+        .header("Content-Type", "application/json")
+        .body(json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }).to_string());
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    let response = request.send().await.unwrap();
+    let status = response.status().as_u16();
+    let body = response.text().await.unwrap();
+    (status, serde_json::from_str(&body).unwrap_or(Value::Null))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_engine_api_stub() {
+    let secret = JwtSecret::random();
+    let engine = EngineApiConfig { port: 0, jwt_secret: secret, generated_secret: true };
+    let (api, handle) = spawn(NodeConfig::test().with_engine_api(Some(engine))).await;
+    let url = handle.engine_endpoint().unwrap();
+    let token = secret.encode(&Claims::default()).unwrap();
+
+    // Requests must be authenticated.
+    let (status, _) = engine_call(&url, None, "engine_exchangeCapabilities", json!([[]])).await;
+    assert_eq!(status, 401);
+    let other = JwtSecret::random().encode(&Claims::default()).unwrap();
+    let (status, _) =
+        engine_call(&url, Some(&other), "engine_exchangeCapabilities", json!([[]])).await;
+    assert_eq!(status, 401);
+
+    let (status, response) =
+        engine_call(&url, Some(&token), "engine_exchangeCapabilities", json!([[]])).await;
+    assert_eq!(status, 200);
+    assert!(response["result"].as_array().unwrap().contains(&json!("engine_forkchoiceUpdatedV3")));
+
+    let genesis = api.block_by_number(0.into()).await.unwrap().unwrap().header.hash;
+    let block_hash = B256::repeat_byte(1);
+
+    // A payload building on an unknown block can't be validated.
+    let (_, response) = engine_call(
+        &url,
+        Some(&token),
+        "engine_newPayloadV3",
+        json!([{ "blockHash": B256::repeat_byte(2), "parentHash": B256::repeat_byte(3) }, [], B256::ZERO]),
+    )
+    .await;
+    assert_eq!(response["result"]["status"], "SYNCING");
+
+    let (_, response) = engine_call(
+        &url,
+        Some(&token),
+        "engine_newPayloadV3",
+        json!([{ "blockHash": block_hash, "parentHash": genesis }, [], B256::ZERO]),
+    )
+    .await;
+    assert_eq!(response["result"]["status"], "VALID");
+    assert_eq!(response["result"]["latestValidHash"], json!(block_hash));
+
+    let (_, response) = engine_call(
+        &url,
+        Some(&token),
+        "engine_forkchoiceUpdatedV3",
+        json!([{
+            "headBlockHash": block_hash,
+            "safeBlockHash": genesis,
+            "finalizedBlockHash": genesis
+        }, null]),
+    )
+    .await;
+    assert_eq!(response["result"]["payloadStatus"]["status"], "VALID");
+    assert_eq!(response["result"]["payloadId"], Value::Null);
+
+    // The stub doesn't execute payloads.
+    let provider = handle.http_provider();
+    assert_eq!(provider.get_block_number().await.unwrap(), 0);
+}
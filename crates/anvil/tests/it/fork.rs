@@ -20,8 +20,12 @@
 };
 use alloy_serde::WithOtherFields;
 use alloy_signer_local::PrivateKeySigner;
-use anvil::{EthereumHardfork, NodeConfig, NodeHandle, PrecompileFactory, eth::EthApi, spawn};
-use foundry_common::provider::get_http_provider;
+use anvil::{
+    EthereumHardfork, NodeConfig, NodeHandle, PrecompileFactory,
+    eth::{EthApi, backend::fork::ForkRetryMode},
+    spawn,
+};
+use foundry_common::provider::{failure::RpcFailure, get_http_provider};
 use foundry_config::Config;
 use foundry_evm_networks::NetworkConfigs;
 use foundry_primitives::FoundryNetwork;
@@ -2070,3 +2074,124 @@ async fn test_anvil_reset_with_url_updates_fork_urls() {
         "ClientForkConfig.fork_urls should reflect the new URL after anvil_reset"
     );
 }
+
+/// Spawns a node with a few blocks and a fork of it with the given retry mode.
+async fn spawn_fork_with_retry_mode(
+    mode: ForkRetryMode,
+) -> (EthApi<FoundryNetwork>, NodeHandle, NodeHandle) {
+    let (origin_api, origin_handle) = spawn(NodeConfig::test()).await;
+    origin_api.anvil_mine(Some(U256::from(3)), None).await.unwrap();
+
+    let (api, handle) = spawn(
+        NodeConfig::test()
+            .with_eth_rpc_url(Some(origin_handle.http_endpoint()))
+            .fork_retry_backoff(Some(Duration::from_millis(10)))
+            .with_fork_retry_mode(Some(mode)),
+    )
+    .await;
+    (api, origin_handle, handle)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fork_retry_recovers_from_injected_failures() {
+    let (api, _origin, _handle) = spawn_fork_with_retry_mode(ForkRetryMode::Retry).await;
+
+    api.anvil_inject_rpc_failure(RpcFailure { count: Some(2), ..Default::default() })
+        .await
+        .unwrap();
+    let balance = api.balance(Address::random(), Some(BlockId::number(0))).await.unwrap();
+    assert_eq!(balance, U256::ZERO);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fork_retry_propagates_injected_failures() {
+    let (api, _origin, _handle) = spawn_fork_with_retry_mode(ForkRetryMode::Propagate).await;
+
+    api.anvil_inject_rpc_failure(RpcFailure { count: Some(1), ..Default::default() })
+        .await
+        .unwrap();
+    let account = Address::random();
+    assert!(api.balance(account, Some(BlockId::number(0))).await.is_err());
+    // The failure was consumed by the first request.
+    assert!(api.balance(account, Some(BlockId::number(0))).await.is_ok());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fork_retry_fail_open_serves_stale_responses() {
+    let (api, _origin, _handle) = spawn_fork_with_retry_mode(ForkRetryMode::FailOpen).await;
+    let account = Address::random();
+    api.balance(account, Some(BlockId::number(0))).await.unwrap();
+
+    // Fail every request until cleared.
+    api.anvil_inject_rpc_failure(RpcFailure::default()).await.unwrap();
+    // Answered with the previous response.
+    assert_eq!(api.balance(account, Some(BlockId::number(0))).await.unwrap(), U256::ZERO);
+    // Never requested before, so there is nothing to fall back to.
+    assert!(api.balance(Address::random(), Some(BlockId::number(0))).await.is_err());
+
+    api.anvil_inject_rpc_failure(RpcFailure { count: Some(0), ..Default::default() })
+        .await
+        .unwrap();
+    assert!(api.balance(Address::random(), Some(BlockId::number(0))).await.is_ok());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fork_replay_block() {
+    use alloy_rpc_types::trace::parity::TraceType;
+
+    let (origin_api, origin_handle) = spawn(NodeConfig::test()).await;
+    let provider = origin_handle.http_provider();
+    origin_api.anvil_set_auto_mine(false).await.unwrap();
+
+    let accounts: Vec<_> = origin_handle.dev_wallets().collect();
+    let from = accounts[0].address();
+    let to = accounts[1].address();
+    let mut hashes = Vec::new();
+    for _ in 0..2 {
+        let tx = TransactionRequest::default().to(to).value(U256::from(1000u64)).from(from);
+        let pending = provider.send_transaction(WithOtherFields::new(tx)).await.unwrap();
+        hashes.push(*pending.tx_hash());
+    }
+    origin_api.mine_one().await;
+    let block = provider.get_block(BlockId::latest()).await.unwrap().unwrap();
+    assert_eq!(block.transactions.hashes().collect::<Vec<_>>(), hashes);
+
+    let (api, handle) = spawn(
+        NodeConfig::test()
+            .with_eth_rpc_url(Some(origin_handle.http_endpoint()))
+            .with_replay_block(Some(block.header.number)),
+    )
+    .await;
+    let fork_provider = handle.http_provider();
+
+    // the transactions of the replayed block are mined in the first block
+    for _ in 0..50 {
+        if api.block_number().unwrap().to::<u64>() == block.header.number {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    let replayed = fork_provider.get_block(BlockId::latest()).await.unwrap().unwrap();
+    assert_eq!(replayed.header.number, block.header.number);
+    assert_eq!(replayed.header.timestamp, block.header.timestamp);
+    assert_eq!(replayed.header.beneficiary, block.header.beneficiary);
+    assert_eq!(replayed.transactions.hashes().collect::<Vec<_>>(), hashes);
+    for hash in &hashes {
+        let receipt = fork_provider.get_transaction_receipt(*hash).await.unwrap().unwrap();
+        assert!(receipt.status());
+    }
+
+    let results = api
+        .trace_replay_block_transactions(
+            block.header.number.into(),
+            [TraceType::Trace, TraceType::StateDiff].into_iter().collect(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    for (result, hash) in results.iter().zip(&hashes) {
+        assert_eq!(result.transaction_hash, *hash);
+        assert!(!result.full_trace.trace.is_empty());
+        assert!(result.full_trace.state_diff.is_some());
+    }
+}
@@ -4,8 +4,10 @@
 mod api;
 mod beacon_api;
 mod eip2935;
+mod eip4788;
 mod eip4844;
 mod eip7702;
+mod engine_api;
 mod fork;
 mod gas;
 mod genesis;
@@ -3,16 +3,49 @@
 use crate::utils::{http_provider, http_provider_with_signer};
 use alloy_eips::eip2718::Encodable2718;
 use alloy_network::{EthereumWallet, NetworkTransactionBuilder, TransactionBuilder};
-use alloy_primitives::{Address, Bloom, TxHash, TxKind, U256, b256};
+use alloy_primitives::{Address, B256, Bloom, TxHash, TxKind, U256, b256};
 use alloy_provider::Provider;
 use alloy_rpc_types::{BlockId, TransactionRequest};
 use alloy_serde::WithOtherFields;
-use anvil::{NodeConfig, eth::fees::INITIAL_BASE_FEE, spawn};
+use alloy_sol_types::sol;
+use anvil::{
+    NodeConfig,
+    eth::{
+        backend::optimism::{GAS_PRICE_ORACLE_ADDRESS, L1_BLOCK_ADDRESS},
+        fees::INITIAL_BASE_FEE,
+    },
+    spawn,
+};
+use anvil_core::types::L1BlockValues;
 use foundry_evm_networks::NetworkConfigs;
 use op_alloy_consensus::TxDeposit;
 use op_alloy_rpc_types::OpTransactionFields;
 use serde_json::{Value, json};
 
+sol! {
+    #[sol(rpc)]
+    interface IL1Block {
+        function number() external view returns (uint64);
+        function timestamp() external view returns (uint64);
+        function basefee() external view returns (uint256);
+        function sequenceNumber() external view returns (uint64);
+        function blobBaseFeeScalar() external view returns (uint32);
+        function baseFeeScalar() external view returns (uint32);
+        function blobBaseFee() external view returns (uint256);
+    }
+
+    #[sol(rpc)]
+    interface IGasPriceOracle {
+        function decimals() external pure returns (uint256);
+        function l1BaseFee() external view returns (uint256);
+        function baseFeeScalar() external view returns (uint32);
+        function blobBaseFeeScalar() external view returns (uint32);
+        function isEcotone() external view returns (bool);
+        function isFjord() external view returns (bool);
+        function isIsthmus() external view returns (bool);
+    }
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_deposits_not_supported_if_optimism_disabled() {
     let (_api, handle) = spawn(NodeConfig::test()).await;
@@ -226,6 +259,53 @@ async fn test_deposit_tx_checks_sufficient_funds_after_applying_deposited_value(
     assert_eq!(recipient_new_balance, U256::from(send_value));
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_raw_deposit_transaction_mined_with_deposit_semantics() {
+    let (_api, handle) =
+        spawn(NodeConfig::test().with_networks(NetworkConfigs::with_optimism())).await;
+    let provider = http_provider(&handle.http_endpoint());
+
+    let sender = Address::random();
+    let recipient = Address::random();
+    let mint = 1_000_000_000_000_000_000_u128;
+
+    for nonce in 0..2u64 {
+        let deposit_tx = TxDeposit {
+            source_hash: B256::random(),
+            from: sender,
+            to: TxKind::Call(recipient),
+            mint,
+            value: U256::from(1),
+            gas_limit: 100_000,
+            is_system_transaction: false,
+            input: Vec::new().into(),
+        };
+        let mut tx_buffer = Vec::new();
+        deposit_tx.encode_2718(&mut tx_buffer);
+
+        let tx_hash =
+            provider.send_raw_transaction(&tx_buffer).await.unwrap().watch().await.unwrap();
+
+        let receipt: Value =
+            provider.raw_request("eth_getTransactionReceipt".into(), [tx_hash]).await.unwrap();
+        assert_eq!(receipt["status"], "0x1");
+        assert_eq!(receipt["type"], "0x7e");
+        assert_eq!(receipt["depositNonce"], json!(format!("{nonce:#x}")));
+        assert_eq!(receipt["depositReceiptVersion"], "0x1");
+
+        let tx: Value =
+            provider.raw_request("eth_getTransactionByHash".into(), [tx_hash]).await.unwrap();
+        assert_eq!(tx["type"], "0x7e");
+        assert_eq!(tx["nonce"], json!(format!("{nonce:#x}")));
+    }
+
+    // the minted value is credited to the sender, which pays no gas
+    let sender_balance = provider.get_balance(sender).await.unwrap();
+    assert_eq!(sender_balance, U256::from(2 * mint - 2));
+    assert_eq!(provider.get_balance(recipient).await.unwrap(), U256::from(2));
+    assert_eq!(provider.get_transaction_count(sender).await.unwrap(), 2);
+}
+
 #[test]
 fn preserves_op_fields_in_convert_to_anvil_receipt() {
     let receipt_json = json!({
@@ -346,3 +426,61 @@ async fn test_optimism_base_fee_params() {
         "Should not be using Ethereum base fee params"
     );
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_op_predeploys() {
+    let node_config = NodeConfig::test()
+        .with_networks(NetworkConfigs::with_optimism())
+        .with_l1_block_values(L1BlockValues {
+            basefee: Some(U256::from(7)),
+            base_fee_scalar: Some(1368),
+            ..Default::default()
+        });
+    let (api, handle) = spawn(node_config).await;
+    let provider = http_provider(&handle.http_endpoint());
+
+    let l1_block = IL1Block::new(L1_BLOCK_ADDRESS, &provider);
+    let oracle = IGasPriceOracle::new(GAS_PRICE_ORACLE_ADDRESS, &provider);
+    assert_eq!(l1_block.basefee().call().await.unwrap(), U256::from(7));
+    assert_eq!(l1_block.baseFeeScalar().call().await.unwrap(), 1368);
+    assert_eq!(oracle.decimals().call().await.unwrap(), U256::from(6));
+    assert_eq!(oracle.l1BaseFee().call().await.unwrap(), U256::from(7));
+    assert!(oracle.isEcotone().call().await.unwrap());
+    assert!(oracle.isFjord().call().await.unwrap());
+    assert!(oracle.isIsthmus().call().await.unwrap());
+    // The flags are packed in the first slot, as in the upstream contract.
+    let flags = provider.get_storage_at(GAS_PRICE_ORACLE_ADDRESS, U256::ZERO).await.unwrap();
+    assert_eq!(flags, U256::from(0x010101));
+
+    api.anvil_set_l1_block_values(L1BlockValues {
+        number: Some(100),
+        timestamp: Some(1_700_000_000),
+        sequence_number: Some(3),
+        blob_base_fee_scalar: Some(810949),
+        blob_base_fee: Some(U256::from(2)),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(l1_block.number().call().await.unwrap(), 100);
+    assert_eq!(l1_block.timestamp().call().await.unwrap(), 1_700_000_000);
+    assert_eq!(l1_block.sequenceNumber().call().await.unwrap(), 3);
+    assert_eq!(l1_block.blobBaseFeeScalar().call().await.unwrap(), 810949);
+    assert_eq!(l1_block.blobBaseFee().call().await.unwrap(), U256::from(2));
+    // The values that are not set are kept.
+    assert_eq!(l1_block.basefee().call().await.unwrap(), U256::from(7));
+    assert_eq!(l1_block.baseFeeScalar().call().await.unwrap(), 1368);
+    assert_eq!(oracle.baseFeeScalar().call().await.unwrap(), 1368);
+    assert_eq!(oracle.blobBaseFeeScalar().call().await.unwrap(), 810949);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_set_l1_block_values_not_supported_if_optimism_disabled() {
+    let (api, handle) = spawn(NodeConfig::test()).await;
+    let provider = handle.http_provider();
+
+    let code = provider.get_code_at(L1_BLOCK_ADDRESS).await.unwrap();
+    assert!(code.is_empty());
+    assert!(api.anvil_set_l1_block_values(L1BlockValues::default()).await.is_err());
+}
@@ -503,3 +503,64 @@ async fn ots_get_contract_creator() {
     assert_eq!(creator.creator, sender);
     assert_eq!(creator.hash, receipt.transaction_hash);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn ots_forked_history() {
+    let (_origin_api, origin_handle) = spawn(NodeConfig::test()).await;
+    let origin_provider = origin_handle.http_provider();
+    let sender = origin_handle.dev_accounts().next().unwrap();
+    let to = Address::random();
+
+    let tx = TransactionRequest::default().from(sender).to(to).value(U256::from(100));
+    let forked_receipt = origin_provider
+        .send_transaction(WithOtherFields::new(tx))
+        .await
+        .unwrap()
+        .get_receipt()
+        .await
+        .unwrap();
+
+    let (api, handle) =
+        spawn(NodeConfig::test().with_eth_rpc_url(Some(origin_handle.http_endpoint()))).await;
+    let provider = handle.http_provider();
+
+    // internal operations of transactions predating the fork
+    let res = api.ots_get_internal_operations(forked_receipt.transaction_hash).await.unwrap();
+    assert_eq!(
+        res,
+        [InternalOperation {
+            r#type: OperationType::OpTransfer,
+            from: sender,
+            to,
+            value: U256::from(100)
+        }],
+    );
+
+    let tx = TransactionRequest::default().from(sender).to(to).value(U256::from(200));
+    let local_receipt = provider
+        .send_transaction(WithOtherFields::new(tx))
+        .await
+        .unwrap()
+        .get_receipt()
+        .await
+        .unwrap();
+
+    // the history spans the fork
+    let result = api.ots_search_transactions_before(sender, 0, 10).await.unwrap();
+    let hashes = result.txs.iter().map(|tx| tx.tx_hash()).collect::<Vec<_>>();
+    assert_eq!(hashes, [local_receipt.transaction_hash, forked_receipt.transaction_hash]);
+    assert_eq!(result.receipts.len(), 2);
+    assert!(result.first_page);
+    assert!(result.last_page);
+
+    let result = api.ots_search_transactions_after(sender, 0, 10).await.unwrap();
+    let hashes = result.txs.iter().map(|tx| tx.tx_hash()).collect::<Vec<_>>();
+    assert_eq!(hashes, [local_receipt.transaction_hash, forked_receipt.transaction_hash]);
+    assert!(result.first_page);
+    assert!(result.last_page);
+
+    // block details of blocks predating the fork
+    let details =
+        api.ots_get_block_details(forked_receipt.block_number.unwrap().into()).await.unwrap();
+    assert_eq!(details.block.transaction_count, 1);
+}
@@ -1,12 +1,19 @@
 use crate::utils::http_provider_with_signer;
+use alloy_consensus::TxEip1559;
 use alloy_dyn_abi::TypedData;
-use alloy_network::EthereumWallet;
-use alloy_primitives::{Address, U256};
+use alloy_eips::eip2718::Encodable2718;
+use alloy_network::{EthereumWallet, ReceiptResponse};
+use alloy_primitives::{Address, TxKind, U256};
 use alloy_provider::Provider;
 use alloy_rpc_types::TransactionRequest;
 use alloy_serde::WithOtherFields;
 use alloy_signer::Signer;
-use anvil::{NodeConfig, spawn};
+use anvil::{
+    NodeConfig,
+    eth::sign::{DevSigner, Signer as _},
+    spawn,
+};
+use foundry_primitives::FoundryTypedTx;
 
 #[tokio::test(flavor = "multi_thread")]
 async fn can_sign_typed_data() {
@@ -310,6 +317,55 @@ async fn can_sign_transaction() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn can_sign_transactions_in_batch() {
+    let (api, handle) = spawn(NodeConfig::test()).await;
+    let provider = handle.http_provider();
+
+    let wallets = handle.dev_wallets().collect::<Vec<_>>();
+    let from = wallets[0].address();
+    let to = wallets[1].address();
+    let dev_signer = DevSigner::new(vec![wallets[0].clone()]);
+
+    let txs = (0..20u64)
+        .map(|nonce| {
+            FoundryTypedTx::Eip1559(TxEip1559 {
+                chain_id: api.chain_id(),
+                nonce,
+                to: TxKind::Call(to),
+                value: U256::from(nonce),
+                max_priority_fee_per_gas: 1_000_000_000,
+                max_fee_per_gas: 10_000_000_000,
+                gas_limit: 21_000,
+                ..Default::default()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // the envelopes are returned in the order of the transactions
+    let envelopes = dev_signer.sign_transactions_from(&from, txs.clone()).unwrap();
+    assert_eq!(envelopes.len(), txs.len());
+    for (tx, envelope) in txs.into_iter().zip(&envelopes) {
+        assert_eq!(envelope.hash(), dev_signer.sign_transaction_from(&from, tx).unwrap().hash());
+        assert_eq!(envelope.recover().unwrap(), from);
+    }
+
+    for envelope in &envelopes {
+        let receipt = provider
+            .send_raw_transaction(&envelope.encoded_2718())
+            .await
+            .unwrap()
+            .get_receipt()
+            .await
+            .unwrap();
+        assert!(receipt.status());
+    }
+    assert_eq!(provider.get_transaction_count(from).await.unwrap(), 20);
+
+    let err = dev_signer.sign_transactions_from(&to, vec![]).unwrap_err();
+    assert!(err.to_string().contains("No signer available"), "{err}");
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn rejects_different_chain_id() {
     let (_api, handle) = spawn(NodeConfig::test()).await;
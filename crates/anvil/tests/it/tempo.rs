@@ -1980,7 +1980,7 @@ async fn test_tempo_aa_get_transaction_by_hash() {
 
     let pending = provider.send_raw_transaction(&encoded).await.unwrap();
     let tx_hash = *pending.tx_hash();
-    pending.get_receipt().await.unwrap();
+    let receipt = pending.get_receipt().await.unwrap();
 
     let tx = api.transaction_by_hash(tx_hash).await.unwrap();
     assert!(tx.is_some(), "Transaction should be retrievable by hash");
@@ -1988,6 +1988,69 @@ async fn test_tempo_aa_get_transaction_by_hash() {
     let tx = tx.unwrap();
     assert_eq!(tx.ty(), 0x76, "Transaction type should be 0x76 (Tempo)");
     assert_eq!(TransactionResponse::from(&tx), sender, "From address should match sender");
+    assert_eq!(TransactionResponse::tx_hash(&tx), tx_hash, "Hash should match");
+    assert_eq!(
+        tx.effective_gas_price,
+        Some(ReceiptResponse::effective_gas_price(&receipt)),
+        "Effective gas price should match the receipt"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tempo_aa_signed_by_dev_signer() {
+    use anvil::eth::sign::{DevSigner, Signer as _};
+    use foundry_primitives::{FoundryTxEnvelope, FoundryTypedTx};
+
+    let (_api, handle) = spawn(NodeConfig::test_tempo()).await;
+    let provider = handle.http_provider();
+
+    let accounts: Vec<Address> = handle.dev_accounts().collect();
+    let sender = accounts[0];
+    let recipient = accounts[1];
+    let signer = dev_key(0);
+
+    let token = IERC20::new(PATH_USD, &provider);
+    let chain_id = provider.get_chain_id().await.unwrap();
+    let base_fee = provider.get_gas_price().await.unwrap();
+
+    let transfer_call = token.transfer(recipient, U256::from(25_000));
+    let calldata: Bytes = transfer_call.calldata().clone();
+
+    let tempo_tx = TempoTransaction {
+        chain_id,
+        fee_token: Some(PATH_USD),
+        max_priority_fee_per_gas: base_fee / 10,
+        max_fee_per_gas: base_fee * 2,
+        gas_limit: TIP20_TRANSFER_GAS,
+        calls: vec![Call { to: TxKind::Call(PATH_USD), value: U256::ZERO, input: calldata }],
+        access_list: Default::default(),
+        nonce_key: U256::from(502),
+        nonce: 0,
+        fee_payer_signature: None,
+        valid_before: None,
+        valid_after: None,
+        key_authorization: None,
+        tempo_authorization_list: vec![],
+    };
+
+    // The dev signer must sign over the Tempo signature hash, like an external wallet.
+    let sig_hash = tempo_tx.signature_hash();
+    let signature = signer.sign_hash(&sig_hash).await.unwrap();
+    let expected = TempoSignature::Primitive(PrimitiveSignature::Secp256k1(signature));
+
+    let dev_signer = DevSigner::new(vec![signer]);
+    let envelope =
+        dev_signer.sign_transaction_from(&sender, FoundryTypedTx::Tempo(tempo_tx)).unwrap();
+    let FoundryTxEnvelope::Tempo(ref signed) = envelope else {
+        panic!("expected a Tempo transaction");
+    };
+    assert_eq!(*signed.signature(), expected);
+    assert_eq!(envelope.recover_tempo_sender().unwrap().unwrap(), sender);
+
+    let tx_hash = provider.send_raw_transaction(&envelope.encoded_2718()).await.unwrap();
+    let receipt = tx_hash.get_receipt().await.unwrap();
+    assert!(receipt.status(), "Tempo AA transaction signed by the dev signer should succeed");
+    assert_eq!(receipt.from(), sender);
 }
 
 // ============================================================================
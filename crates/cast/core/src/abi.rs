@@ -0,0 +1,156 @@
+//! ABI encoding and decoding based off of function signatures.
+
+use crate::{Error, Result};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use alloy_dyn_abi::{DynSolType, DynSolValue, JsonAbiExt};
+use alloy_json_abi::Function;
+use alloy_primitives::hex;
+
+/// Parses a function signature, e.g. `transfer(address,uint256)(bool)`.
+///
+/// Constructor signatures and bare tuples, e.g. `(uint256,bool)`, are accepted as well.
+pub fn parse_function(sig: &str) -> Result<Function> {
+    let sig = sig.trim();
+    let sig = match sig.strip_prefix("constructor") {
+        Some(params) => format!("f{params}"),
+        None if sig.starts_with('(') => format!("f{sig}"),
+        None => sig.to_string(),
+    };
+    Function::parse(&sig).map_err(|err| Error::msg(format!("invalid function signature: {err}")))
+}
+
+/// ABI-encodes the arguments of a function, without its selector.
+pub fn abi_encode(sig: &str, args: &[impl AsRef<str>]) -> Result<Vec<u8>> {
+    let values = coerce_args(&parse_function(sig)?, args)?;
+    Ok(DynSolValue::Tuple(values).abi_encode_params())
+}
+
+/// ABI-encodes the arguments of a function, or of a bare tuple, with the packed encoding.
+pub fn abi_encode_packed(sig: &str, args: &[impl AsRef<str>]) -> Result<Vec<u8>> {
+    let values = coerce_args(&parse_function(sig)?, args)?;
+    Ok(values.iter().flat_map(DynSolValue::abi_encode_packed).collect())
+}
+
+/// ABI-encodes a call to a function: its selector followed by its arguments.
+pub fn calldata_encode(sig: &str, args: &[impl AsRef<str>]) -> Result<Vec<u8>> {
+    let func = parse_function(sig)?;
+    let values = coerce_args(&func, args)?;
+    Ok(func.abi_encode_input(&values)?)
+}
+
+/// Decodes hex ABI-encoded data with the input types of a function if `input`, or with its output
+/// types otherwise.
+///
+/// If `with_selector`, input data starts with the 4 bytes selector of the function, which is
+/// skipped. Output data never has a selector.
+pub fn abi_decode(
+    sig: &str,
+    data: &str,
+    input: bool,
+    with_selector: bool,
+) -> Result<Vec<DynSolValue>> {
+    let func = parse_function(sig)?;
+    let data = hex::decode(data.trim())?;
+    let mut data = data.as_slice();
+    if input && with_selector && data.len() >= 4 {
+        data = &data[4..];
+    }
+    let values = if input { func.abi_decode_input(data) } else { func.abi_decode_output(data) }?;
+    if values.is_empty() {
+        return Err(Error::msg("no data was decoded"));
+    }
+    Ok(values)
+}
+
+/// Formats a decoded value the way cast prints it.
+pub fn format_value(value: &DynSolValue) -> String {
+    match value {
+        DynSolValue::Address(address) => address.to_checksum(None),
+        DynSolValue::Bool(b) => b.to_string(),
+        DynSolValue::Int(i, _) => i.to_string(),
+        DynSolValue::Uint(u, _) => u.to_string(),
+        DynSolValue::FixedBytes(bytes, size) => hex::encode_prefixed(&bytes[..*size]),
+        DynSolValue::Bytes(bytes) => hex::encode_prefixed(bytes),
+        DynSolValue::Function(function) => function.to_string(),
+        DynSolValue::String(s) => format!("{s:?}"),
+        DynSolValue::Array(values) | DynSolValue::FixedArray(values) => {
+            format!("[{}]", format_values(values))
+        }
+        DynSolValue::Tuple(values) | DynSolValue::CustomStruct { tuple: values, .. } => {
+            format!("({})", format_values(values))
+        }
+    }
+}
+
+fn format_values(values: &[DynSolValue]) -> String {
+    values.iter().map(format_value).collect::<Vec<_>>().join(", ")
+}
+
+fn coerce_args(func: &Function, args: &[impl AsRef<str>]) -> Result<Vec<DynSolValue>> {
+    if func.inputs.len() != args.len() {
+        return Err(Error::msg(format!(
+            "encode length mismatch: expected {} types, got {}",
+            func.inputs.len(),
+            args.len()
+        )));
+    }
+    func.inputs
+        .iter()
+        .zip(args)
+        .map(
+            |(param, arg)| Ok(DynSolType::parse(&param.selector_type())?.coerce_str(arg.as_ref())?),
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_decode_roundtrip() {
+        let sig = "transfer(address,uint256)(bool)";
+        let args = ["0x8dbd1b711dc621e1404633da156fcc779e1c6f3e", "42"];
+
+        let calldata = calldata_encode(sig, &args).unwrap();
+        assert_eq!(&calldata[..4], &hex!("a9059cbb"));
+        assert_eq!(&calldata[4..], abi_encode(sig, &args).unwrap().as_slice());
+
+        let decoded = abi_decode(sig, &hex::encode(&calldata), true, true).unwrap();
+        let formatted = decoded.iter().map(format_value).collect::<Vec<_>>();
+        assert_eq!(formatted, ["0x8DbD1b711DC621e1404633da156FcC779e1c6f3E", "42"]);
+    }
+
+    #[test]
+    fn encode_constructor_and_tuple() {
+        let expected = hex!("0000000000000000000000000000000000000000000000000000000000000001");
+        assert_eq!(abi_encode("constructor(uint a)", &["1"]).unwrap(), expected);
+        assert_eq!(abi_encode("(uint256)", &["1"]).unwrap(), expected);
+    }
+
+    #[test]
+    fn encode_packed() {
+        let encoded = abi_encode_packed("(uint64 a, string b)", &["200", "hi"]).unwrap();
+        assert_eq!(encoded, hex!("00000000000000c86869"));
+    }
+
+    #[test]
+    fn decode_outputs() {
+        let data = "0x0000000000000000000000000000000000000000000000000000000000000001";
+        let decoded = abi_decode("balanceOf(address)(uint256,bool)", data, false, false);
+        assert!(decoded.is_err());
+
+        let decoded = abi_decode("balanceOf(address)(uint256)", data, false, false).unwrap();
+        assert_eq!(format_value(&decoded[0]), "1");
+    }
+
+    #[test]
+    fn rejects_wrong_argument_count() {
+        let err = abi_encode("f(uint256,bool)", &["1"]).unwrap_err();
+        assert_eq!(err.to_string(), "encode length mismatch: expected 2 types, got 1");
+    }
+}
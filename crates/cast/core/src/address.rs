@@ -0,0 +1,23 @@
+//! Address derivation.
+
+use alloy_primitives::{Address, B256};
+
+/// Computes the address of the contract created by `deployer` with the given nonce.
+pub fn compute_address(deployer: Address, nonce: u64) -> Address {
+    deployer.create(nonce)
+}
+
+/// Computes the address of the contract created by `deployer` with `CREATE2`.
+pub fn compute_create2_address(deployer: Address, salt: B256, init_code: &[u8]) -> Address {
+    deployer.create2_from_code(salt, init_code)
+}
+
+/// Computes the address of the contract created by `deployer` with `CREATE2`, given the hash of
+/// its init code.
+pub fn compute_create2_address_from_hash(
+    deployer: Address,
+    salt: B256,
+    init_code_hash: B256,
+) -> Address {
+    deployer.create2(salt, init_code_hash)
+}
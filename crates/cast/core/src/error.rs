@@ -0,0 +1,28 @@
+use alloc::string::String;
+
+/// Result type of the cast core utilities.
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+/// Errors of the cast core utilities.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Hex(#[from] alloy_primitives::hex::FromHexError),
+    #[error(transparent)]
+    Abi(#[from] alloy_dyn_abi::Error),
+    #[error(transparent)]
+    Units(#[from] alloy_primitives::utils::UnitsError),
+    #[error(transparent)]
+    Eip2718(#[from] alloy_eips::eip2718::Eip2718Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error("{0}")]
+    Other(String),
+}
+
+impl Error {
+    /// Creates a new error with the given message.
+    pub fn msg(msg: impl Into<String>) -> Self {
+        Self::Other(msg.into())
+    }
+}
@@ -0,0 +1,36 @@
+//! Hashing utilities.
+
+use crate::{Result, abi::parse_function};
+use alloy_primitives::{B256, Selector, hex, keccak256};
+
+/// Hashes `data` with keccak-256, hex-decoding it first if it is prefixed with `0x`.
+pub fn keccak(data: &str) -> Result<B256> {
+    Ok(if data.starts_with("0x") {
+        keccak256(hex::decode(data.trim_end())?)
+    } else {
+        keccak256(data)
+    })
+}
+
+/// Returns the selector of a function signature.
+pub fn selector(sig: &str) -> Result<Selector> {
+    Ok(parse_function(sig)?.selector())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::b256;
+
+    #[test]
+    fn hashes() {
+        assert_eq!(
+            keccak("0x").unwrap(),
+            b256!("0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+        );
+        assert_eq!(
+            selector("transfer(address to, uint256 amount)").unwrap(),
+            [0xa9, 0x05, 0x9c, 0xbb]
+        );
+    }
+}
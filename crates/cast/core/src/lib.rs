@@ -0,0 +1,34 @@
+//! # cast-core
+//!
+//! Pure functions of cast: ABI encoding and decoding, unit conversion, hashing, transaction
+//! decoding and address derivation.
+//!
+//! They perform no I/O, so they can be compiled to `wasm32-unknown-unknown` and used from
+//! JavaScript through the bindings of the `wasm` module:
+//!
+//! ```sh
+//! wasm-pack build crates/cast/core --target web
+//! ```
+
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+extern crate alloc;
+
+pub mod abi;
+pub mod address;
+pub mod hash;
+pub mod tx;
+pub mod units;
+
+#[cfg(any(target_arch = "wasm32", test))]
+mod wasm;
+
+// Only depended on to enable their JavaScript backend.
+#[cfg(target_arch = "wasm32")]
+use getrandom as _;
+#[cfg(target_arch = "wasm32")]
+use getrandom_03 as _;
+
+mod error;
+pub use error::{Error, Result};
@@ -0,0 +1,23 @@
+//! Transaction decoding.
+
+use crate::Result;
+use alloc::string::String;
+use alloy_consensus::transaction::{Recovered, SignerRecoverable};
+use alloy_eips::eip2718::Decodable2718;
+use alloy_primitives::hex;
+use serde::Serialize;
+
+/// Decodes a raw, hex-encoded, EIP-2718 transaction envelope into pretty JSON.
+///
+/// The signer is recovered and included as `from` if the signature is valid.
+pub fn decode_raw_transaction<T>(raw: &str) -> Result<String>
+where
+    T: Decodable2718 + SignerRecoverable + Serialize,
+{
+    let raw = hex::decode(raw.trim())?;
+    let tx = T::decode_2718(&mut raw.as_slice())?;
+    Ok(match tx.recover_signer() {
+        Ok(signer) => serde_json::to_string_pretty(&Recovered::new_unchecked(tx, signer))?,
+        Err(_) => serde_json::to_string_pretty(&tx)?,
+    })
+}
@@ -0,0 +1,57 @@
+//! Conversions between units of ether and numbers with arbitrary decimals.
+
+use crate::{Error, Result};
+use alloc::string::{String, ToString};
+use alloy_dyn_abi::DynSolType;
+use alloy_primitives::{
+    U256,
+    utils::{ParseUnits, Unit},
+};
+
+/// Formats `value` in the given unit, trimming an empty fractional part.
+pub fn format_units(value: U256, unit: Unit) -> String {
+    let mut formatted = ParseUnits::U256(value).format_units(unit);
+    if let Some(dot) = formatted.find('.')
+        && formatted[dot + 1..].chars().all(|c| c == '0')
+    {
+        formatted.truncate(dot);
+    }
+    formatted
+}
+
+/// Converts a number, optionally suffixed with its unit (e.g. `1ether`), into the given unit.
+pub fn to_unit(value: &str, unit: &str) -> Result<String> {
+    let value = DynSolType::Uint(256)
+        .coerce_str(value)?
+        .as_uint()
+        .ok_or_else(|| Error::msg("could not convert to uint"))?
+        .0;
+    Ok(format_units(value, unit.parse()?))
+}
+
+/// Converts an amount of the given unit into wei.
+pub fn to_wei(value: &str, unit: &str) -> Result<String> {
+    Ok(ParseUnits::parse_units(value, unit.parse()?)?.to_string())
+}
+
+/// Converts an amount of wei into the given unit.
+pub fn from_wei(value: &str, unit: &str) -> Result<String> {
+    let value =
+        value.parse::<U256>().map_err(|err| Error::msg(alloc::format!("invalid number: {err}")))?;
+    Ok(ParseUnits::U256(value).format_units(unit.parse()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_units() {
+        assert_eq!(to_unit("1ether", "wei").unwrap(), "1000000000000000000");
+        assert_eq!(to_unit("1000000000 gwei", "ether").unwrap(), "1");
+        assert_eq!(to_unit("1500000000", "gwei").unwrap(), "1.500000000");
+        assert_eq!(to_wei("100", "gwei").unwrap(), "100000000000");
+        assert_eq!(from_wei("12340000005", "gwei").unwrap(), "12.340000005");
+        assert_eq!(from_wei("0x64", "wei").unwrap(), "100");
+    }
+}
@@ -0,0 +1,142 @@
+//! JavaScript bindings.
+
+use crate::{abi, address, hash, tx, units};
+use alloc::{string::String, vec::Vec};
+use alloy_consensus::TxEnvelope;
+use alloy_primitives::{Address, B256, hex};
+use wasm_bindgen::prelude::*;
+
+fn js_err(err: impl core::fmt::Display) -> JsError {
+    JsError::new(&alloc::format!("{err}"))
+}
+
+/// ABI-encodes the arguments of a function, without its selector.
+#[wasm_bindgen(js_name = abiEncode)]
+pub fn abi_encode(sig: &str, args: Vec<String>) -> Result<String, JsError> {
+    abi::abi_encode(sig, &args).map(hex::encode_prefixed).map_err(js_err)
+}
+
+/// ABI-encodes a call to a function: its selector followed by its arguments.
+#[wasm_bindgen(js_name = calldataEncode)]
+pub fn calldata_encode(sig: &str, args: Vec<String>) -> Result<String, JsError> {
+    abi::calldata_encode(sig, &args).map(hex::encode_prefixed).map_err(js_err)
+}
+
+/// Decodes ABI-encoded data with the input or output types of a function.
+#[wasm_bindgen(js_name = abiDecode)]
+pub fn abi_decode(sig: &str, data: &str, input: bool) -> Result<Vec<String>, JsError> {
+    let values = abi::abi_decode(sig, data, input, false).map_err(js_err)?;
+    Ok(values.iter().map(abi::format_value).collect())
+}
+
+/// Decodes calldata, prefixed with its selector, with the input types of a function.
+#[wasm_bindgen(js_name = calldataDecode)]
+pub fn calldata_decode(sig: &str, data: &str) -> Result<Vec<String>, JsError> {
+    let values = abi::abi_decode(sig, data, true, true).map_err(js_err)?;
+    Ok(values.iter().map(abi::format_value).collect())
+}
+
+/// Converts a number, optionally suffixed with its unit, into the given unit.
+#[wasm_bindgen(js_name = toUnit)]
+pub fn to_unit(value: &str, unit: &str) -> Result<String, JsError> {
+    units::to_unit(value, unit).map_err(js_err)
+}
+
+/// Converts an amount of the given unit into wei.
+#[wasm_bindgen(js_name = toWei)]
+pub fn to_wei(value: &str, unit: &str) -> Result<String, JsError> {
+    units::to_wei(value, unit).map_err(js_err)
+}
+
+/// Converts an amount of wei into the given unit.
+#[wasm_bindgen(js_name = fromWei)]
+pub fn from_wei(value: &str, unit: &str) -> Result<String, JsError> {
+    units::from_wei(value, unit).map_err(js_err)
+}
+
+/// Hashes data with keccak-256, hex-decoding it first if it is prefixed with `0x`.
+#[wasm_bindgen]
+pub fn keccak(data: &str) -> Result<String, JsError> {
+    hash::keccak(data).map(hex::encode_prefixed).map_err(js_err)
+}
+
+/// Returns the selector of a function signature.
+#[wasm_bindgen]
+pub fn selector(sig: &str) -> Result<String, JsError> {
+    hash::selector(sig).map(hex::encode_prefixed).map_err(js_err)
+}
+
+/// Computes the address of the contract created by `deployer` with the given nonce.
+#[wasm_bindgen(js_name = computeAddress)]
+pub fn compute_address(deployer: &str, nonce: u64) -> Result<String, JsError> {
+    let deployer = deployer.parse::<Address>().map_err(js_err)?;
+    Ok(address::compute_address(deployer, nonce).to_checksum(None))
+}
+
+/// Computes the address of the contract created by `deployer` with `CREATE2`.
+#[wasm_bindgen(js_name = computeCreate2Address)]
+pub fn compute_create2_address(
+    deployer: &str,
+    salt: &str,
+    init_code: &str,
+) -> Result<String, JsError> {
+    let deployer = deployer.parse::<Address>().map_err(js_err)?;
+    let salt = salt.parse::<B256>().map_err(js_err)?;
+    let init_code = hex::decode(init_code).map_err(js_err)?;
+    Ok(address::compute_create2_address(deployer, salt, &init_code).to_checksum(None))
+}
+
+/// Decodes a raw, hex-encoded, EIP-2718 Ethereum transaction into pretty JSON.
+#[wasm_bindgen(js_name = decodeTransaction)]
+pub fn decode_transaction(raw: &str) -> Result<String, JsError> {
+    tx::decode_raw_transaction::<TxEnvelope>(raw).map_err(js_err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Only the successful calls are tested: `JsError`s can't be created outside of WebAssembly.
+
+    #[test]
+    fn encodes_and_decodes_calldata() {
+        let sig = "transfer(address,uint256)(bool)";
+        let args = vec!["0x8DbD1b711DC621e1404633da156FcC779e1c6f3E".into(), "42".into()];
+        let calldata = calldata_encode(sig, args.clone()).unwrap();
+        assert!(calldata.starts_with("0xa9059cbb"));
+        assert_eq!(calldata[10..], abi_encode(sig, args.clone()).unwrap()[2..]);
+        assert_eq!(calldata_decode(sig, &calldata).unwrap(), args);
+        assert_eq!(abi_decode(sig, &format!("0x{:064x}", 1), false).unwrap(), ["true"]);
+    }
+
+    #[test]
+    fn converts_units() {
+        assert_eq!(to_unit("1ether", "gwei").unwrap(), "1000000000");
+        assert_eq!(to_wei("1", "gwei").unwrap(), "1000000000");
+        assert_eq!(from_wei("1000000000", "gwei").unwrap(), "1");
+    }
+
+    #[test]
+    fn hashes() {
+        assert_eq!(
+            keccak("0x").unwrap(),
+            "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(selector("transfer(address,uint256)").unwrap(), "0xa9059cbb");
+    }
+
+    #[test]
+    fn computes_addresses() {
+        let deployer = "0xb20a608c624Ca5003905aA834De7156C68b2E1d0";
+        assert_eq!(
+            compute_address(deployer, 0).unwrap(),
+            address::compute_address(deployer.parse().unwrap(), 0).to_checksum(None)
+        );
+        let salt = B256::ZERO.to_string();
+        assert_eq!(
+            compute_create2_address(deployer, &salt, "0x").unwrap(),
+            address::compute_create2_address(deployer.parse().unwrap(), B256::ZERO, &[])
+                .to_checksum(None)
+        );
+    }
+}
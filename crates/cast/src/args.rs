@@ -1,15 +1,16 @@
 use crate::{
     Cast, SimpleCast,
+    bundle::TxBundle,
     cmd::erc20::IERC20,
-    opts::{Cast as CastArgs, CastSubcommand, ToBaseArgs},
+    opts::{Cast as CastArgs, CastSubcommand, ToBaseArgs, TxNetwork},
     traces::identifier::SignaturesIdentifier,
     tx::CastTxSender,
 };
 use alloy_dyn_abi::{DynSolValue, ErrorExt, EventExt};
 use alloy_eips::eip7702::SignedAuthorization;
 use alloy_ens::{ProviderEnsExt, namehash};
-use alloy_network::Ethereum;
-use alloy_primitives::{Address, B256, eip191_hash_message, hex, keccak256};
+use alloy_network::{Ethereum, Network};
+use alloy_primitives::{Address, B256, TxHash, eip191_hash_message, hex, keccak256};
 use alloy_provider::Provider;
 use alloy_rpc_types::{BlockId, BlockNumberOrTag::Latest};
 use clap::{CommandFactory, Parser};
@@ -17,6 +18,7 @@
 use eyre::{Result, WrapErr};
 use foundry_cli::utils::{self, LoadConfig};
 use foundry_common::{
+    ConfirmationWaiter,
     abi::{get_error, get_event},
     fmt::{format_tokens, format_uint_exp, serialize_value_as_json},
     fs,
@@ -29,8 +31,13 @@
     shell, stdin,
 };
 use foundry_evm_networks::NetworkVariant;
+use foundry_primitives::FoundryNetwork;
 use op_alloy_network::Optimism;
-use std::time::Instant;
+use std::{
+    path::Path,
+    str::FromStr,
+    time::{Duration, Instant},
+};
 use tempo_alloy::TempoNetwork;
 
 /// Run the `cast` command-line interface.
@@ -338,6 +345,7 @@ pub async fn run_command(args: CastArgs) -> Result<()> {
                 }
             }
         }
+        CastSubcommand::BalanceSnapshot(cmd) => cmd.run().await?,
         CastSubcommand::BaseFee { block, rpc } => {
             let config = rpc.load_config()?;
             let provider = utils::get_provider(&config)?;
@@ -399,7 +407,8 @@ pub async fn run_command(args: CastArgs) -> Result<()> {
             };
             sh_println!("{number}")?
         }
-        CastSubcommand::Chain { rpc } => {
+        CastSubcommand::Chain { command: Some(command), .. } => command.run().await?,
+        CastSubcommand::Chain { command: None, rpc } => {
             let config = rpc.load_config()?;
             let provider = utils::get_provider(&config)?;
             sh_println!("{}", Cast::new(provider).chain().await?)?
@@ -409,6 +418,7 @@ pub async fn run_command(args: CastArgs) -> Result<()> {
             let provider = utils::get_provider(&config)?;
             sh_println!("{}", Cast::new(provider).chain_id().await?)?
         }
+        CastSubcommand::CompareRpc(cmd) => cmd.run().await?,
         CastSubcommand::Client { rpc } => {
             let config = rpc.load_config()?;
             let provider = utils::get_provider(&config)?;
@@ -430,10 +440,19 @@ pub async fn run_command(args: CastArgs) -> Result<()> {
             let address = stdin::unwrap_line(address)?;
             let computed = {
                 // For CREATE2, init_code_hash is needed to compute the address
+                let salt = salt.unwrap_or(B256::ZERO);
                 if let Some(init_code_hash) = init_code_hash {
-                    address.create2(salt.unwrap_or(B256::ZERO), init_code_hash)
+                    cast_core::address::compute_create2_address_from_hash(
+                        address,
+                        salt,
+                        init_code_hash,
+                    )
                 } else if let Some(init_code) = init_code {
-                    address.create2(salt.unwrap_or(B256::ZERO), keccak256(hex::decode(init_code)?))
+                    cast_core::address::compute_create2_address(
+                        address,
+                        salt,
+                        &hex::decode(init_code)?,
+                    )
                 } else {
                     // For CREATE, rpc is needed to compute the address
                     let config = rpc.load_config()?;
@@ -476,6 +495,7 @@ pub async fn run_command(args: CastArgs) -> Result<()> {
             }
         }
         CastSubcommand::FindBlock(cmd) => cmd.run().await?,
+        CastSubcommand::Gas(cmd) => cmd.run().await?,
         CastSubcommand::GasPrice { rpc } => {
             let config = rpc.load_config()?;
             let provider = utils::get_provider(&config)?;
@@ -536,11 +556,15 @@ pub async fn run_command(args: CastArgs) -> Result<()> {
         CastSubcommand::Call(cmd) => cmd.run().await?,
         CastSubcommand::Estimate(cmd) => cmd.run().await?,
         CastSubcommand::MakeTx(cmd) => cmd.run().await?,
-        CastSubcommand::PublishTx { raw_tx, cast_async, rpc } => {
+        CastSubcommand::Permit(cmd) => cmd.run().await?,
+        CastSubcommand::PublishTx { raw_tx, bundle, cast_async, rpc } => {
             let config = rpc.load_config()?;
             let provider = utils::get_provider(&config)?;
+            if let Some(bundle) = bundle {
+                return publish_bundle(&provider, &bundle, cast_async).await;
+            }
             let cast = Cast::new(&provider);
-            let pending_tx = cast.publish(raw_tx).await?;
+            let pending_tx = cast.publish(raw_tx.expect("required by clap")).await?;
             let tx_hash = pending_tx.inner().tx_hash();
 
             if cast_async {
@@ -550,22 +574,72 @@ pub async fn run_command(args: CastArgs) -> Result<()> {
                 sh_println!("{}", serde_json::json!(receipt))?;
             }
         }
-        CastSubcommand::Receipt { tx_hash, field, cast_async, confirmations, rpc } => {
+        CastSubcommand::Receipt { tx_hash, field, cast_async, confirmations, network, rpc } => {
             let config = rpc.load_config()?;
             let provider = utils::get_provider(&config)?;
-            sh_println!(
-                "{}",
-                CastTxSender::new(provider)
-                    .receipt(tx_hash, field, confirmations, None, cast_async)
-                    .await?
-            )?
+            let network = match network {
+                Some(network) => network,
+                None => match NetworkVariant::from(provider.get_chain_id().await?) {
+                    NetworkVariant::Optimism => TxNetwork::Optimism,
+                    NetworkVariant::Tempo => TxNetwork::Tempo,
+                    NetworkVariant::Ethereum => TxNetwork::Ethereum,
+                },
+            };
+            let receipt = match network {
+                TxNetwork::Optimism => {
+                    let provider = ProviderBuilder::<Optimism>::from_config(&config)?.build()?;
+                    CastTxSender::new(provider)
+                        .receipt(tx_hash, field, confirmations, None, cast_async)
+                        .await?
+                }
+                TxNetwork::Tempo => {
+                    let provider =
+                        ProviderBuilder::<TempoNetwork>::from_config(&config)?.build()?;
+                    CastTxSender::new(provider)
+                        .receipt(tx_hash, field, confirmations, None, cast_async)
+                        .await?
+                }
+                TxNetwork::Foundry => {
+                    let provider =
+                        ProviderBuilder::<FoundryNetwork>::from_config(&config)?.build()?;
+                    CastTxSender::new(provider)
+                        .receipt(tx_hash, field, confirmations, None, cast_async)
+                        .await?
+                }
+                // Unknown chain-specific fields are kept as is.
+                TxNetwork::Ethereum => {
+                    CastTxSender::new(provider)
+                        .receipt(tx_hash, field, confirmations, None, cast_async)
+                        .await?
+                }
+            };
+            sh_println!("{receipt}")?
         }
         CastSubcommand::Run(cmd) => cmd.run().await?,
         CastSubcommand::SendTx(cmd) => cmd.run().await?,
         CastSubcommand::BatchMakeTx(cmd) => cmd.run().await?,
         CastSubcommand::BatchSend(cmd) => cmd.run().await?,
-        CastSubcommand::Tx { tx_hash, from, nonce, field, raw, rpc, to_request, network } => {
+        CastSubcommand::Tx {
+            tx_hash,
+            from,
+            nonce,
+            field,
+            raw,
+            rpc,
+            to_request,
+            network,
+            wait,
+            confirmations,
+            timeout,
+        } => {
             let config = rpc.load_config()?;
+            if wait && let Some(tx_hash) = &tx_hash {
+                let tx_hash = TxHash::from_str(tx_hash).wrap_err("invalid tx hash")?;
+                let timeout = timeout.unwrap_or(config.transaction_timeout);
+                ConfirmationWaiter::new(confirmations, Duration::from_secs(timeout))
+                    .wait(&utils::get_provider(&config)?, tx_hash)
+                    .await?;
+            }
             // Can use either --raw or specify raw as a field
             let is_raw = raw || field.as_ref().is_some_and(|f| f == "raw");
             let output = match network {
@@ -733,53 +807,7 @@ pub async fn run_command(args: CastArgs) -> Result<()> {
             "{}",
             SimpleCast::right_shift(&value, &bits, base_in.as_deref(), &base_out)?
         )?,
-        CastSubcommand::Source {
-            address,
-            directory,
-            explorer_api_url,
-            explorer_url,
-            etherscan,
-            flatten,
-        } => {
-            let config = etherscan.load_config()?;
-            let chain = config.chain.unwrap_or_default();
-            let api_key = config.get_etherscan_api_key(Some(chain));
-            match (directory, flatten) {
-                (Some(dir), false) => {
-                    SimpleCast::expand_etherscan_source_to_directory(
-                        chain,
-                        address,
-                        api_key,
-                        dir,
-                        explorer_api_url,
-                        explorer_url,
-                    )
-                    .await?
-                }
-                (None, false) => sh_println!(
-                    "{}",
-                    SimpleCast::etherscan_source(
-                        chain,
-                        address,
-                        api_key,
-                        explorer_api_url,
-                        explorer_url
-                    )
-                    .await?
-                )?,
-                (dir, true) => {
-                    SimpleCast::etherscan_source_flatten(
-                        chain,
-                        address,
-                        api_key,
-                        dir,
-                        explorer_api_url,
-                        explorer_url,
-                    )
-                    .await?;
-                }
-            }
-        }
+        CastSubcommand::Source(cmd) => cmd.run().await?,
         CastSubcommand::Create2(cmd) => {
             cmd.run()?;
         }
@@ -791,11 +819,10 @@ pub async fn run_command(args: CastArgs) -> Result<()> {
         CastSubcommand::DecodeTransaction { tx, network } => {
             let tx = stdin::unwrap_line(tx)?;
             let decoded_tx = match network {
-                Some(NetworkVariant::Optimism) => {
-                    SimpleCast::decode_raw_transaction::<Optimism>(&tx)?
-                }
-                Some(NetworkVariant::Tempo) => {
-                    SimpleCast::decode_raw_transaction::<TempoNetwork>(&tx)?
+                Some(TxNetwork::Optimism) => SimpleCast::decode_raw_transaction::<Optimism>(&tx)?,
+                Some(TxNetwork::Tempo) => SimpleCast::decode_raw_transaction::<TempoNetwork>(&tx)?,
+                Some(TxNetwork::Foundry) => {
+                    SimpleCast::decode_raw_transaction::<FoundryNetwork>(&tx)?
                 }
                 _ => SimpleCast::decode_raw_transaction::<Ethereum>(&tx)?,
             };
@@ -807,6 +834,9 @@ pub async fn run_command(args: CastArgs) -> Result<()> {
         }
         CastSubcommand::TxPool { command } => command.run().await?,
         CastSubcommand::Erc20Token { command } => command.run().await?,
+        CastSubcommand::Token { command } => command.run().await?,
+        CastSubcommand::Merkle { command } => command.run()?,
+        CastSubcommand::Gov { command } => command.run().await?,
         CastSubcommand::Tip20Token { command } => command.run().await?,
         CastSubcommand::Keychain { command } => command.run().await?,
         CastSubcommand::DAEstimate(cmd) => {
@@ -838,3 +868,40 @@ fn print_tokens(tokens: &[DynSolValue]) {
 
     Ok(())
 }
+
+/// Publishes the signed transactions of a bundle in order, after checking them against the chain.
+async fn publish_bundle<N: Network, P: Provider<N>>(
+    provider: &P,
+    path: &Path,
+    cast_async: bool,
+) -> Result<()> {
+    let bundle = TxBundle::read(path)?;
+    let transactions = bundle.signed_transactions()?;
+
+    let chain_id = provider.get_chain_id().await?;
+    if chain_id != bundle.chain_id {
+        eyre::bail!("bundle is for chain {}, but the RPC is on chain {chain_id}", bundle.chain_id);
+    }
+    // Fail before sending anything if a nonce was used since the bundle was created.
+    for (tx, _) in &transactions {
+        let nonce = provider.get_transaction_count(tx.from()).pending().await?;
+        if tx.nonce() < nonce {
+            eyre::bail!(
+                "nonce {} of {} was already used, the bundle is stale",
+                tx.nonce(),
+                tx.from()
+            );
+        }
+    }
+
+    for (_, raw) in transactions {
+        let pending_tx = provider.send_raw_transaction(raw).await?;
+        if cast_async {
+            sh_println!("{:#x}", pending_tx.tx_hash())?;
+        } else {
+            let receipt = pending_tx.get_receipt().await?;
+            sh_println!("{}", serde_json::json!(receipt))?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,220 @@
+//! Unsigned transaction bundles, for signing transactions on an air-gapped machine.
+//!
+//! A bundle is created online with `cast mktx --bundle`, which pins the chain id, nonce and fees
+//! of each transaction. It is then signed offline with `cast mktx --offline --bundle`, and
+//! broadcast online with `cast publish --bundle`. The signing hash of each transaction is
+//! recorded when the bundle is created and checked on both sides of the boundary.
+
+use alloy_consensus::{SignableTransaction, TxEnvelope, transaction::SignerRecoverable};
+use alloy_eips::{Decodable2718, Encodable2718};
+use alloy_network::{EthereumWallet, TransactionBuilder};
+use alloy_primitives::{Address, B256, Bytes};
+use alloy_rpc_types::TransactionRequest;
+use eyre::{Result, WrapErr, bail, ensure};
+use foundry_common::fs;
+use foundry_wallets::WalletSigner;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The version of the bundle format.
+pub const TX_BUNDLE_VERSION: u32 = 1;
+
+/// A list of transactions of a single chain, signed offline.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxBundle {
+    pub version: u32,
+    pub chain_id: u64,
+    pub transactions: Vec<BundledTx>,
+}
+
+/// A transaction of a [`TxBundle`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundledTx {
+    /// The unsigned transaction, with all its fields set.
+    pub request: TransactionRequest,
+    /// The hash to sign, committing to all the fields of the transaction.
+    pub signing_hash: B256,
+    /// The signed EIP-2718 encoded transaction, once signed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signed: Option<Bytes>,
+}
+
+impl BundledTx {
+    /// Returns the sender of the transaction.
+    pub fn from(&self) -> Address {
+        self.request.from.unwrap_or_default()
+    }
+
+    /// Returns the nonce of the transaction.
+    pub fn nonce(&self) -> u64 {
+        self.request.nonce.unwrap_or_default()
+    }
+
+    /// Checks that the transaction matches its signing hash and, if signed, that the signature
+    /// is the sender's and covers the same transaction.
+    fn verify(&self, chain_id: u64) -> Result<()> {
+        ensure!(
+            self.request.chain_id == Some(chain_id),
+            "transaction {} is not for chain {chain_id}",
+            self.signing_hash
+        );
+        let signing_hash = signing_hash(&self.request)?;
+        ensure!(
+            signing_hash == self.signing_hash,
+            "transaction was modified: expected signing hash {}, got {signing_hash}",
+            self.signing_hash
+        );
+
+        if let Some(signed) = &self.signed {
+            let envelope = TxEnvelope::decode_2718(&mut signed.as_ref())
+                .wrap_err("invalid signed transaction")?;
+            ensure!(
+                envelope.signature_hash() == self.signing_hash,
+                "signed transaction {} doesn't match its unsigned transaction",
+                envelope.tx_hash()
+            );
+            let signer = envelope.recover_signer()?;
+            ensure!(
+                signer == self.from(),
+                "transaction {} is signed by {signer}, expected {}",
+                envelope.tx_hash(),
+                self.from()
+            );
+        }
+        Ok(())
+    }
+}
+
+impl TxBundle {
+    /// Creates an empty bundle for the given chain.
+    pub fn new(chain_id: u64) -> Self {
+        Self { version: TX_BUNDLE_VERSION, chain_id, transactions: Vec::new() }
+    }
+
+    /// Reads and verifies a bundle.
+    pub fn read(path: &Path) -> Result<Self> {
+        let bundle: Self = fs::read_json_file(path)
+            .wrap_err_with(|| format!("failed to read bundle {}", path.display()))?;
+        ensure!(
+            bundle.version == TX_BUNDLE_VERSION,
+            "unsupported bundle version {}, expected {TX_BUNDLE_VERSION}",
+            bundle.version
+        );
+        bundle.verify()?;
+        Ok(bundle)
+    }
+
+    /// Writes the bundle.
+    pub fn write(&self, path: &Path) -> Result<()> {
+        fs::write_pretty_json_file(path, self)?;
+        Ok(())
+    }
+
+    /// Verifies all the transactions of the bundle.
+    pub fn verify(&self) -> Result<()> {
+        for tx in &self.transactions {
+            tx.verify(self.chain_id)?;
+        }
+        Ok(())
+    }
+
+    /// Adds an unsigned transaction to the bundle.
+    pub fn push(&mut self, request: TransactionRequest) -> Result<()> {
+        ensure!(request.from.is_some(), "bundled transactions must have a sender");
+        let tx = BundledTx { signing_hash: signing_hash(&request)?, request, signed: None };
+        tx.verify(self.chain_id)?;
+        self.transactions.push(tx);
+        Ok(())
+    }
+
+    /// Returns the nonce following the last transaction of `from` in the bundle, if any.
+    pub fn next_nonce(&self, from: Address) -> Option<u64> {
+        self.transactions.iter().filter(|tx| tx.from() == from).map(|tx| tx.nonce() + 1).max()
+    }
+
+    /// Signs the unsigned transactions sent by the signer, without connecting to a node.
+    ///
+    /// Returns the number of signed transactions.
+    pub async fn sign(&mut self, signer: WalletSigner) -> Result<usize> {
+        let from = alloy_signer::Signer::address(&signer);
+        let wallet = EthereumWallet::new(signer);
+        let mut signed = 0;
+        for tx in &mut self.transactions {
+            if tx.signed.is_some() || tx.from() != from {
+                continue;
+            }
+            let envelope = tx.request.clone().build(&wallet).await?;
+            tx.signed = Some(envelope.encoded_2718().into());
+            tx.verify(self.chain_id)?;
+            signed += 1;
+        }
+        Ok(signed)
+    }
+
+    /// Returns the signed transactions, failing if one of them is not signed.
+    pub fn signed_transactions(&self) -> Result<Vec<(&BundledTx, &Bytes)>> {
+        self.transactions
+            .iter()
+            .map(|tx| match &tx.signed {
+                Some(signed) => Ok((tx, signed)),
+                None => bail!(
+                    "transaction {} of {} is not signed, sign the bundle with `cast mktx --offline`",
+                    tx.nonce(),
+                    tx.from()
+                ),
+            })
+            .collect()
+    }
+}
+
+fn signing_hash(request: &TransactionRequest) -> Result<B256> {
+    let tx = request.clone().build_unsigned()?;
+    Ok(tx.signature_hash())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{U256, address};
+    use alloy_signer_local::PrivateKeySigner;
+
+    fn request(nonce: u64) -> TransactionRequest {
+        TransactionRequest::default()
+            .with_from(address!("0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"))
+            .with_to(address!("0x70997970C51812dc3A010C7d01b50e0d17dc79C8"))
+            .with_value(U256::from(1))
+            .with_chain_id(1)
+            .with_nonce(nonce)
+            .with_gas_limit(21000)
+            .with_max_fee_per_gas(2_000_000_000)
+            .with_max_priority_fee_per_gas(1_000_000_000)
+    }
+
+    #[tokio::test]
+    async fn signs_and_verifies_bundle() {
+        let mut bundle = TxBundle::new(1);
+        bundle.push(request(0)).unwrap();
+        assert_eq!(bundle.next_nonce(bundle.transactions[0].from()), Some(1));
+        bundle.push(request(1)).unwrap();
+        assert!(bundle.signed_transactions().is_err());
+
+        let signer: PrivateKeySigner =
+            "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80".parse().unwrap();
+        assert_eq!(bundle.sign(WalletSigner::Local(signer)).await.unwrap(), 2);
+        assert_eq!(bundle.signed_transactions().unwrap().len(), 2);
+        bundle.verify().unwrap();
+
+        // Tampering with a pinned field breaks the integrity check.
+        let mut tampered = bundle.clone();
+        tampered.transactions[0].request.value = Some(U256::from(2));
+        assert!(tampered.verify().is_err());
+
+        // So does swapping the signed transactions.
+        let mut swapped = bundle;
+        let signed = swapped.transactions[1].signed.clone();
+        swapped.transactions[0].signed = signed;
+        assert!(swapped.verify().is_err());
+    }
+}
@@ -0,0 +1,335 @@
+//! `cast balance-snapshot`: signed reports of token balances at a block.
+
+use crate::cmd::erc20::IERC20;
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockId;
+use alloy_network::{AnyNetwork, BlockResponse, primitives::HeaderResponse};
+use alloy_primitives::{Address, B256, Bytes, U256, keccak256};
+use alloy_provider::Provider;
+use alloy_rpc_types::EIP1186AccountProofResponse;
+use alloy_signer::Signer;
+use alloy_sol_types::{SolCall, SolValue, sol};
+use clap::Parser;
+use eyre::{Context, OptionExt, Result, eyre};
+use foundry_cli::{
+    opts::RpcOpts,
+    utils::{LoadConfig, get_provider},
+};
+use foundry_common::{addresses::MULTICALL3, fs};
+use foundry_wallets::WalletOpts;
+use serde::Serialize;
+use std::{path::PathBuf, str::FromStr};
+
+sol! {
+    #[sol(rpc)]
+    interface IMulticall3 {
+        struct Call3 {
+            address target;
+            bool allowFailure;
+            bytes callData;
+        }
+
+        struct Result {
+            bool success;
+            bytes returnData;
+        }
+
+        function aggregate3(Call3[] calldata calls) external payable returns (Result[] memory returnData);
+        function getEthBalance(address addr) external view returns (uint256 balance);
+    }
+}
+
+/// The number of storage slots probed for the `balanceOf` mapping of tokens.
+const MAX_PROBED_SLOTS: u64 = 32;
+
+/// CLI arguments for `cast balance-snapshot`.
+#[derive(Debug, Parser)]
+pub struct BalanceSnapshotArgs {
+    /// The token and holder pairs to snapshot, as `TOKEN:HOLDER`.
+    ///
+    /// Use `ETH` as the token for the native balance of the holder.
+    #[arg(value_name = "TOKEN:HOLDER")]
+    pairs: Vec<BalancePair>,
+
+    /// Read the pairs from a file, one `TOKEN,HOLDER` or `TOKEN:HOLDER` pair per line.
+    ///
+    /// Empty lines and lines starting with `#` are ignored.
+    #[arg(long, short, value_name = "PATH")]
+    file: Option<PathBuf>,
+
+    /// The block to snapshot the balances at. Defaults to the latest block.
+    #[arg(long, short = 'B')]
+    block: Option<BlockId>,
+
+    /// Include the EIP-1186 proofs of the balances.
+    ///
+    /// Native balances are proven by the account proof of the holder. Token balances are proven
+    /// by the storage proof of the balance, whose slot is found by probing the first slots of
+    /// the `balanceOf` mapping.
+    #[arg(long)]
+    proofs: bool,
+
+    /// The maximum number of balances queried per multicall.
+    #[arg(long, default_value_t = 500, value_name = "SIZE")]
+    batch_size: usize,
+
+    /// Sign the snapshot with the wallet.
+    #[arg(long)]
+    sign: bool,
+
+    #[command(flatten)]
+    rpc: RpcOpts,
+
+    #[command(flatten)]
+    wallet: WalletOpts,
+}
+
+/// A token and a holder whose balance to snapshot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BalancePair {
+    /// The token, or `None` for the native balance.
+    token: Option<Address>,
+    holder: Address,
+}
+
+impl FromStr for BalancePair {
+    type Err = eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (token, holder) = s
+            .split_once([':', ','])
+            .ok_or_else(|| eyre!("invalid pair `{s}`, expected `TOKEN:HOLDER`"))?;
+        let token = match token.trim() {
+            token if token.eq_ignore_ascii_case("eth") => None,
+            token => Some(token.parse().wrap_err_with(|| format!("invalid token `{token}`"))?),
+        };
+        let holder = holder.trim();
+        let holder = holder.parse().wrap_err_with(|| format!("invalid holder `{holder}`"))?;
+        Ok(Self { token, holder })
+    }
+}
+
+/// The balances of the snapshot and the block they were read at.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BalanceSnapshot {
+    chain_id: u64,
+    block_number: u64,
+    block_hash: B256,
+    timestamp: u64,
+    balances: Vec<BalanceEntry>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BalanceEntry {
+    /// The token, or `null` for the native balance.
+    token: Option<Address>,
+    holder: Address,
+    /// The balance, or `null` if it could not be read.
+    balance: Option<U256>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proof: Option<BalanceProof>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BalanceProof {
+    /// The storage slot of the token balance, `null` for native balances.
+    slot: Option<B256>,
+    proof: EIP1186AccountProofResponse,
+}
+
+/// A snapshot signed with EIP-191 over its JSON serialization.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SignedBalanceSnapshot<'a> {
+    snapshot: &'a BalanceSnapshot,
+    /// The signed message, the compact JSON serialization of the snapshot.
+    message: String,
+    signer: Address,
+    signature: Bytes,
+}
+
+impl BalanceSnapshotArgs {
+    pub async fn run(self) -> Result<()> {
+        let Self { mut pairs, file, block, proofs, batch_size, sign, rpc, wallet } = self;
+        if let Some(file) = file {
+            pairs.extend(read_pairs(&fs::read_to_string(file)?)?);
+        }
+        eyre::ensure!(!pairs.is_empty(), "no token and holder pairs provided");
+        eyre::ensure!(batch_size > 0, "batch size must be greater than 0");
+
+        let config = rpc.load_config()?;
+        let provider = get_provider(&config)?;
+
+        // Pin the block so that every balance is read at the same state.
+        let block =
+            provider.get_block(block.unwrap_or_default()).await?.ok_or_eyre("block not found")?;
+        let header = block.header();
+        let block_number = header.number();
+        let block_id = BlockId::number(block_number);
+
+        let balances = query_balances(&provider, &pairs, block_id, batch_size).await?;
+        let mut entries = Vec::with_capacity(pairs.len());
+        for (pair, balance) in pairs.iter().zip(balances) {
+            let proof = match (proofs, balance) {
+                (true, Some(balance)) => prove_balance(&provider, pair, balance, block_id).await?,
+                _ => None,
+            };
+            entries.push(BalanceEntry { token: pair.token, holder: pair.holder, balance, proof });
+        }
+
+        let snapshot = BalanceSnapshot {
+            chain_id: provider.get_chain_id().await?,
+            block_number,
+            block_hash: header.hash(),
+            timestamp: header.timestamp(),
+            balances: entries,
+        };
+
+        if sign {
+            let signer = wallet.signer().await?;
+            let message = serde_json::to_string(&snapshot)?;
+            let signature = signer.sign_message(message.as_bytes()).await?;
+            let signed = SignedBalanceSnapshot {
+                snapshot: &snapshot,
+                signer: signer.address(),
+                signature: signature.as_bytes().into(),
+                message,
+            };
+            sh_println!("{}", serde_json::to_string_pretty(&signed)?)?;
+        } else {
+            sh_println!("{}", serde_json::to_string_pretty(&snapshot)?)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses the pairs of a file, one per line.
+fn read_pairs(content: &str) -> Result<Vec<BalancePair>> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(BalancePair::from_str)
+        .collect()
+}
+
+/// Reads the balances of the pairs at `block`, batched with Multicall3 if it is deployed.
+///
+/// Balances that can't be read, e.g. because the token is not an ERC20, are `None`.
+async fn query_balances<P: Provider<AnyNetwork>>(
+    provider: &P,
+    pairs: &[BalancePair],
+    block: BlockId,
+    batch_size: usize,
+) -> Result<Vec<Option<U256>>> {
+    if provider.get_code_at(MULTICALL3).block_id(block).await?.is_empty() {
+        sh_warn!("Multicall3 is not deployed, querying balances one by one")?;
+        let mut balances = Vec::with_capacity(pairs.len());
+        for pair in pairs {
+            let balance = match pair.token {
+                None => Some(provider.get_balance(pair.holder).block_id(block).await?),
+                Some(token) => IERC20::new(token, provider)
+                    .balanceOf(pair.holder)
+                    .block(block)
+                    .call()
+                    .await
+                    .ok(),
+            };
+            balances.push(balance);
+        }
+        return Ok(balances);
+    }
+
+    let multicall = IMulticall3::new(MULTICALL3, provider);
+    let mut balances = Vec::with_capacity(pairs.len());
+    for batch in pairs.chunks(batch_size) {
+        let calls = batch
+            .iter()
+            .map(|pair| match pair.token {
+                None => IMulticall3::Call3 {
+                    target: MULTICALL3,
+                    allowFailure: true,
+                    callData: IMulticall3::getEthBalanceCall { addr: pair.holder }
+                        .abi_encode()
+                        .into(),
+                },
+                Some(token) => IMulticall3::Call3 {
+                    target: token,
+                    allowFailure: true,
+                    callData: IERC20::balanceOfCall { owner: pair.holder }.abi_encode().into(),
+                },
+            })
+            .collect::<Vec<_>>();
+        let results = multicall.aggregate3(calls).block(block).call().await?;
+        balances.extend(results.into_iter().map(|result| {
+            result.success.then(|| U256::abi_decode(&result.returnData).ok()).flatten()
+        }));
+    }
+    Ok(balances)
+}
+
+/// Returns the EIP-1186 proof of the balance of `pair`, if its storage slot can be found.
+async fn prove_balance<P: Provider<AnyNetwork>>(
+    provider: &P,
+    pair: &BalancePair,
+    balance: U256,
+    block: BlockId,
+) -> Result<Option<BalanceProof>> {
+    let Some(token) = pair.token else {
+        let proof = provider.get_proof(pair.holder, vec![]).block_id(block).await?;
+        return Ok(Some(BalanceProof { slot: None, proof }));
+    };
+
+    // A zero balance matches any unused slot.
+    if !balance.is_zero() {
+        for slot in 0..MAX_PROBED_SLOTS {
+            let key = balance_slot(pair.holder, slot);
+            let value = provider.get_storage_at(token, key.into()).block_id(block).await?;
+            if value == balance {
+                let proof = provider.get_proof(token, vec![key]).block_id(block).await?;
+                return Ok(Some(BalanceProof { slot: Some(key), proof }));
+            }
+        }
+    }
+
+    sh_warn!("could not find the balance slot of {} in token {token}", pair.holder)?;
+    Ok(None)
+}
+
+/// Returns the storage slot of the balance of `holder` in a Solidity `mapping(address => uint256)`
+/// at slot `slot`.
+fn balance_slot(holder: Address, slot: u64) -> B256 {
+    keccak256((holder, U256::from(slot)).abi_encode())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, hex};
+
+    #[test]
+    fn parses_pairs() {
+        let holder = address!("0x0000000000000000000000000000000000000001");
+        let token = address!("0x0000000000000000000000000000000000000002");
+        let content = format!("# treasury\n\neth,{holder}\n{token}:{holder}\n");
+        assert_eq!(
+            read_pairs(&content).unwrap(),
+            [BalancePair { token: None, holder }, BalancePair { token: Some(token), holder }]
+        );
+        assert!(BalancePair::from_str("0x01").is_err());
+    }
+
+    #[test]
+    fn computes_balance_slot() {
+        // `keccak256(abi.encode(address(1), uint256(0)))`
+        assert_eq!(
+            balance_slot(address!("0x0000000000000000000000000000000000000001"), 0),
+            keccak256(hex!(
+                "00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000000"
+            ))
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use alloy_chains::Chain;
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockId;
+use alloy_network::{AnyNetwork, BlockResponse, TransactionBuilder};
+use alloy_primitives::{Address, B256, Bytes, hex};
+use alloy_provider::Provider;
+use alloy_rpc_types::{Filter, TransactionRequest};
+use alloy_transport::TransportError;
+use clap::Parser;
+use eyre::{OptionExt, Result};
+use foundry_cli::{
+    opts::RpcOpts,
+    utils::{self, LoadConfig},
+};
+use foundry_common::shell;
+use serde::Serialize;
+use std::{borrow::Cow, collections::BTreeMap};
+
+/// The block ranges tried for `eth_getLogs`, from the largest to the smallest.
+const LOG_RANGES: [u64; 9] = [100_000, 50_000, 10_000, 5_000, 2_000, 1_000, 500, 100, 10];
+
+/// The RPC namespaces probed with one of their methods, and whether the method takes a transaction
+/// hash.
+///
+/// Transaction hashes are set to a missing transaction, so that any error other than an unknown
+/// method means that the namespace is available.
+const NAMESPACES: [(&str, &str, bool); 4] = [
+    ("debug", "debug_traceTransaction", true),
+    ("trace", "trace_transaction", true),
+    ("ots", "ots_getApiLevel", false),
+    ("txpool", "txpool_status", false),
+];
+
+/// The opcodes probed by executing init code with `eth_call`.
+const OPCODES: [(&str, &str); 3] = [
+    // PUSH0 PUSH0 RETURN
+    ("EIP-3855 (PUSH0)", "5f5ff3"),
+    // PUSH0 PUSH0 TSTORE PUSH0 PUSH0 RETURN
+    ("EIP-1153 (TSTORE)", "5f5f5d5f5ff3"),
+    // PUSH0 PUSH0 PUSH0 MCOPY PUSH0 PUSH0 RETURN
+    ("EIP-5656 (MCOPY)", "5f5f5f5e5f5ff3"),
+];
+
+/// CLI arguments for `cast chain`.
+#[derive(Debug, Parser, Clone)]
+pub enum ChainSubcommand {
+    /// Probe an RPC endpoint for its chain, client and capabilities.
+    ///
+    /// Reports the supported namespaces, the maximum block range of `eth_getLogs`, the depth of
+    /// the available historical state and the supported EIPs.
+    #[command(visible_alias = "d")]
+    Detect(ChainDetectArgs),
+}
+
+impl ChainSubcommand {
+    pub async fn run(self) -> Result<()> {
+        match self {
+            Self::Detect(args) => args.run().await,
+        }
+    }
+}
+
+/// CLI arguments for `cast chain detect`.
+#[derive(Debug, Parser, Clone)]
+pub struct ChainDetectArgs {
+    #[command(flatten)]
+    rpc: RpcOpts,
+}
+
+/// The capabilities of an RPC endpoint.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EndpointReport {
+    chain_id: u64,
+    /// The name of the chain, if known.
+    chain: Option<String>,
+    client_version: Option<String>,
+    latest_block: u64,
+    /// The availability of the RPC namespaces.
+    namespaces: BTreeMap<&'static str, bool>,
+    /// The largest block range accepted by `eth_getLogs`, if any.
+    max_logs_block_range: Option<u64>,
+    /// The oldest block whose state is available.
+    oldest_state_block: Option<u64>,
+    /// The support of EIPs, detected from the latest block and by executing code.
+    eips: BTreeMap<&'static str, bool>,
+}
+
+impl ChainDetectArgs {
+    pub async fn run(self) -> Result<()> {
+        let config = self.rpc.load_config()?;
+        let provider = utils::get_provider(&config)?;
+
+        let chain_id = provider.get_chain_id().await?;
+        let client_version = provider.get_client_version().await.ok();
+        let block =
+            provider.get_block(BlockId::latest()).await?.ok_or_eyre("latest block not found")?;
+        let header = block.header();
+        let latest_block = header.number();
+
+        let mut namespaces = BTreeMap::new();
+        for (namespace, method, with_tx) in NAMESPACES {
+            let params = if with_tx { vec![B256::ZERO.to_string()] } else { vec![] };
+            let result =
+                provider.raw_request::<_, serde_json::Value>(Cow::Borrowed(method), params).await;
+            namespaces.insert(namespace, !result.as_ref().is_err_and(is_unsupported_method));
+        }
+
+        let mut eips = BTreeMap::from([
+            ("EIP-1559 (base fee)", header.base_fee_per_gas().is_some()),
+            ("EIP-4895 (withdrawals)", header.withdrawals_root().is_some()),
+            ("EIP-4844 (blobs)", header.blob_gas_used().is_some()),
+            ("EIP-4788 (beacon block root)", header.parent_beacon_block_root().is_some()),
+            ("EIP-7685 (requests)", header.requests_hash().is_some()),
+        ]);
+        for (eip, code) in OPCODES {
+            let tx = TransactionRequest::default().with_input(Bytes::from(hex::decode(code)?));
+            eips.insert(eip, provider.call(tx.into()).await.is_ok());
+        }
+
+        let report = EndpointReport {
+            chain_id,
+            chain: Chain::from_id(chain_id).named().map(|chain| chain.to_string()),
+            client_version,
+            latest_block,
+            namespaces,
+            max_logs_block_range: max_logs_block_range(&provider, latest_block).await,
+            oldest_state_block: oldest_state_block(&provider, latest_block).await,
+            eips,
+        };
+
+        if shell::is_json() {
+            sh_println!("{}", serde_json::to_string_pretty(&report)?)?;
+            return Ok(());
+        }
+
+        let chain = report.chain.as_deref().unwrap_or("unknown");
+        sh_println!("Chain: {chain} ({})", report.chain_id)?;
+        sh_println!("Client: {}", report.client_version.as_deref().unwrap_or("unknown"))?;
+        sh_println!("Latest block: {}", report.latest_block)?;
+        let supported = |yes| if yes { "yes" } else { "no" };
+        sh_println!("Namespaces:")?;
+        for (namespace, available) in &report.namespaces {
+            sh_println!("  {namespace}: {}", supported(*available))?;
+        }
+        match report.max_logs_block_range {
+            Some(range) if range == LOG_RANGES[0] => {
+                sh_println!("Max logs block range: {range} or more")?
+            }
+            Some(range) => sh_println!("Max logs block range: {range}")?,
+            None => sh_println!("Max logs block range: unavailable")?,
+        }
+        match report.oldest_state_block {
+            Some(0) => sh_println!("State: archive")?,
+            Some(block) => {
+                sh_println!("State: from block {block} ({} blocks)", latest_block - block + 1)?
+            }
+            None => sh_println!("State: unavailable")?,
+        }
+        sh_println!("EIPs:")?;
+        for (eip, enabled) in &report.eips {
+            sh_println!("  {eip}: {}", supported(*enabled))?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` if the error means that the method is not available on the endpoint.
+fn is_unsupported_method(err: &TransportError) -> bool {
+    match err {
+        TransportError::ErrorResp(payload) => {
+            is_unsupported_method_error(payload.code, &payload.message)
+        }
+        // Some endpoints reject unknown methods at the HTTP level.
+        _ => true,
+    }
+}
+
+fn is_unsupported_method_error(code: i64, message: &str) -> bool {
+    let message = message.to_lowercase();
+    code == -32601
+        || (message.contains("method")
+            && ["not found", "does not exist", "not supported", "not available", "unsupported"]
+                .iter()
+                .any(|reason| message.contains(reason)))
+        || message.contains("not enabled")
+        || message.contains("not whitelisted")
+}
+
+/// Returns the largest block range of [`LOG_RANGES`] accepted by `eth_getLogs`.
+async fn max_logs_block_range<P: Provider<AnyNetwork>>(provider: &P, latest: u64) -> Option<u64> {
+    for range in LOG_RANGES {
+        // The zero address emits no logs, so the response stays empty.
+        let filter = Filter::new()
+            .address(Address::ZERO)
+            .from_block(latest.saturating_sub(range - 1))
+            .to_block(latest);
+        if provider.get_logs(&filter).await.is_ok() {
+            return Some(range);
+        }
+    }
+    None
+}
+
+/// Returns the oldest block whose state is available, assuming all later blocks are too.
+async fn oldest_state_block<P: Provider<AnyNetwork>>(provider: &P, latest: u64) -> Option<u64> {
+    let has_state =
+        async |block: u64| provider.get_balance(Address::ZERO).block_id(block.into()).await.is_ok();
+    if !has_state(latest).await {
+        return None;
+    }
+
+    let (mut low, mut high) = (0, latest);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if has_state(mid).await {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    Some(low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_unsupported_methods() {
+        assert!(is_unsupported_method_error(-32601, "the method debug_foo does not exist"));
+        assert!(is_unsupported_method_error(-32000, "Method not found"));
+        assert!(is_unsupported_method_error(-32000, "namespace trace is not enabled"));
+        assert!(!is_unsupported_method_error(-32000, "transaction not found"));
+        assert!(!is_unsupported_method_error(-32000, "genesis is not traceable"));
+    }
+}
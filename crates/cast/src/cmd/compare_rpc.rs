@@ -0,0 +1,282 @@
+//! `cast compare-rpc`: the consistency of the responses of several RPC endpoints.
+
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockId;
+use alloy_ens::NameOrAddress;
+use alloy_network::{AnyNetwork, BlockResponse, TransactionBuilder, primitives::HeaderResponse};
+use alloy_primitives::{Address, Bytes};
+use alloy_provider::{Provider, RootProvider};
+use alloy_rpc_types::TransactionRequest;
+use clap::{Parser, Subcommand};
+use eyre::{OptionExt, Result};
+use foundry_cli::{
+    opts::RpcOpts,
+    utils::{self, LoadConfig},
+};
+use foundry_common::{
+    abi::{encode_function_args, get_func},
+    shell,
+};
+use foundry_config::Config;
+use serde::Serialize;
+use std::str::FromStr;
+
+/// CLI arguments for `cast compare-rpc`.
+#[derive(Debug, Parser)]
+pub struct CompareRpcArgs {
+    /// The endpoints to compare, as URLs or aliases of the `[rpc_endpoints]` config.
+    #[arg(long = "endpoint", short = 'e', value_name = "URL_OR_ALIAS", required = true)]
+    endpoints: Vec<String>,
+
+    /// The number of blocks an endpoint can be behind the most recent one before being flagged
+    /// as lagging.
+    #[arg(long, default_value_t = 2, value_name = "BLOCKS")]
+    max_lag: u64,
+
+    #[command(subcommand)]
+    query: CompareQuery,
+
+    #[command(flatten)]
+    rpc: RpcOpts,
+}
+
+/// The query sent to each endpoint.
+///
+/// Queries are made at the latest block known by all the endpoints unless a block is given, so
+/// that lagging endpoints are not reported as inconsistent.
+#[derive(Clone, Debug, Subcommand)]
+pub enum CompareQuery {
+    /// Compare the header of a block.
+    Block {
+        /// The block to compare.
+        block: Option<BlockId>,
+    },
+    /// Compare the balance of an account.
+    Balance {
+        /// The account to query.
+        #[arg(value_parser = NameOrAddress::from_str)]
+        who: NameOrAddress,
+
+        /// The block to query at.
+        #[arg(long, short = 'B')]
+        block: Option<BlockId>,
+    },
+    /// Compare the result of a call.
+    Call {
+        /// The contract to call.
+        to: Address,
+
+        /// The signature of the function to call.
+        sig: String,
+
+        /// The arguments of the function to call.
+        #[arg(allow_negative_numbers = true)]
+        args: Vec<String>,
+
+        /// The block to query at.
+        #[arg(long, short = 'B')]
+        block: Option<BlockId>,
+    },
+}
+
+/// The response of an endpoint.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EndpointResponse {
+    endpoint: String,
+    /// The latest block of the endpoint, if reachable.
+    latest_block: Option<u64>,
+    /// The number of blocks the endpoint is behind the most recent one.
+    lag: u64,
+    lagging: bool,
+    /// The response to the query, or the error returned by the endpoint.
+    #[serde(flatten)]
+    response: QueryResponse,
+    /// Whether the response differs from the one of most endpoints.
+    inconsistent: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum QueryResponse {
+    Response(String),
+    Error(String),
+}
+
+/// The comparison of the endpoints.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CompareReport {
+    /// The block the query was made at.
+    block: BlockId,
+    endpoints: Vec<EndpointResponse>,
+}
+
+impl CompareReport {
+    fn is_consistent(&self) -> bool {
+        self.endpoints.iter().all(|endpoint| !endpoint.inconsistent)
+    }
+}
+
+impl CompareRpcArgs {
+    pub async fn run(self) -> Result<()> {
+        let Self { endpoints, max_lag, query, rpc } = self;
+        let config = rpc.load_config()?;
+
+        let mut providers = Vec::with_capacity(endpoints.len());
+        for endpoint in &endpoints {
+            providers.push(endpoint_provider(&config, endpoint)?);
+        }
+
+        let latest = futures::future::join_all(
+            providers.iter().map(|provider| async { provider.get_block_number().await.ok() }),
+        )
+        .await;
+        let reachable = latest.iter().flatten();
+        let most_recent =
+            reachable.clone().max().copied().ok_or_eyre("no endpoint is reachable")?;
+        let common = reachable.min().copied().unwrap_or_default();
+
+        let block = match &query {
+            CompareQuery::Block { block }
+            | CompareQuery::Balance { block, .. }
+            | CompareQuery::Call { block, .. } => *block,
+        }
+        .unwrap_or_else(|| BlockId::number(common));
+        let responses = futures::future::join_all(
+            providers.iter().map(|provider| run_query(provider, &query, block)),
+        )
+        .await;
+
+        let expected = majority(&responses).cloned();
+        let endpoints = endpoints
+            .into_iter()
+            .zip(latest)
+            .zip(responses)
+            .map(|((endpoint, latest_block), response)| {
+                let lag = most_recent - latest_block.unwrap_or(most_recent);
+                EndpointResponse {
+                    endpoint,
+                    latest_block,
+                    lag,
+                    lagging: lag > max_lag,
+                    inconsistent: expected.as_ref() != Some(&response),
+                    response,
+                }
+            })
+            .collect();
+        let report = CompareReport { block, endpoints };
+
+        if shell::is_json() {
+            sh_println!("{}", serde_json::to_string_pretty(&report)?)?;
+        } else {
+            print_report(&report)?;
+        }
+        eyre::ensure!(report.is_consistent(), "the endpoints returned inconsistent responses");
+        Ok(())
+    }
+}
+
+/// Returns the provider of an endpoint, resolving the aliases of the config.
+fn endpoint_provider(config: &Config, endpoint: &str) -> Result<RootProvider<AnyNetwork>> {
+    let url = match config.get_rpc_url_with_alias(endpoint) {
+        Some(url) => url?.into_owned(),
+        None => endpoint.to_string(),
+    };
+    let mut config = config.clone();
+    config.eth_rpc_url = Some(url);
+    utils::get_provider(&config)
+}
+
+/// Sends the query to an endpoint.
+async fn run_query(
+    provider: &RootProvider<AnyNetwork>,
+    query: &CompareQuery,
+    block: BlockId,
+) -> QueryResponse {
+    let response = async {
+        eyre::Ok(match query {
+            CompareQuery::Block { .. } => {
+                let block = provider.get_block(block).await?.ok_or_eyre("block not found")?;
+                let header = block.header();
+                format!(
+                    "number={} hash={} stateRoot={} transactions={}",
+                    header.number(),
+                    header.hash(),
+                    header.state_root(),
+                    block.transactions().len()
+                )
+            }
+            CompareQuery::Balance { who, .. } => {
+                let who = who.resolve(provider).await?;
+                provider.get_balance(who).block_id(block).await?.to_string()
+            }
+            CompareQuery::Call { to, sig, args, .. } => {
+                let func = get_func(sig)?;
+                let input = encode_function_args(&func, args)?;
+                let tx = TransactionRequest::default().with_to(*to).with_input(Bytes::from(input));
+                provider.call(tx.into()).block(block).await?.to_string()
+            }
+        })
+    };
+    match response.await {
+        Ok(response) => QueryResponse::Response(response),
+        Err(err) => QueryResponse::Error(format!("{err:#}")),
+    }
+}
+
+/// Returns the response returned by most endpoints, preferring successful responses.
+fn majority(responses: &[QueryResponse]) -> Option<&QueryResponse> {
+    responses.iter().max_by_key(|response| {
+        let count = responses.iter().filter(|other| other == response).count();
+        (matches!(response, QueryResponse::Response(_)), count)
+    })
+}
+
+fn print_report(report: &CompareReport) -> Result<()> {
+    sh_println!("Block: {}", report.block)?;
+    for endpoint in &report.endpoints {
+        let mut flags = Vec::new();
+        if endpoint.lagging {
+            flags.push("lagging");
+        }
+        if endpoint.inconsistent {
+            flags.push("inconsistent");
+        }
+        let flags =
+            if flags.is_empty() { String::new() } else { format!(" [{}]", flags.join(", ")) };
+        let latest = match endpoint.latest_block {
+            Some(latest) => format!("latest block {latest}, {} behind", endpoint.lag),
+            None => "unreachable".to_string(),
+        };
+        sh_println!("{} ({latest}){flags}", endpoint.endpoint)?;
+        match &endpoint.response {
+            QueryResponse::Response(response) => sh_println!("  {response}")?,
+            QueryResponse::Error(err) => sh_println!("  error: {err}")?,
+        }
+    }
+    if report.is_consistent() {
+        sh_println!("All endpoints returned the same response")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_majority_response() {
+        let ok = |s: &str| QueryResponse::Response(s.to_string());
+        let err = |s: &str| QueryResponse::Error(s.to_string());
+
+        let responses = [ok("1"), ok("2"), ok("1")];
+        assert_eq!(majority(&responses), Some(&ok("1")));
+
+        // Successful responses are preferred over the most common error.
+        let responses = [err("timeout"), err("timeout"), ok("1")];
+        assert_eq!(majority(&responses), Some(&ok("1")));
+
+        assert_eq!(majority(&[]), None);
+    }
+}
@@ -1,22 +1,54 @@
-//! Estimates the data availability size of a block for opstack.
+//! Estimates the data availability size of a block for opstack, or the L1 data fee of a pending
+//! transaction.
 
-use alloy_consensus::BlockHeader;
-use alloy_network::{AnyNetwork, BlockResponse, Ethereum, Network, eip2718::Encodable2718};
+use alloy_consensus::{BlockHeader, SignableTransaction, TxEip1559, TxEnvelope};
+use alloy_network::{
+    AnyNetwork, BlockResponse, Ethereum, Network, TransactionBuilder, eip2718::Encodable2718,
+};
+use alloy_primitives::{Address, Bytes, Signature, TxKind, U256, address, utils::format_units};
 use alloy_provider::Provider;
-use alloy_rpc_types::BlockId;
+use alloy_rpc_types::{BlockId, TransactionRequest};
+use alloy_serde::WithOtherFields;
+use alloy_sol_types::sol;
 use clap::Parser;
 use eyre::Result;
 use foundry_cli::{opts::RpcOpts, utils::LoadConfig};
-use foundry_common::provider::ProviderBuilder;
+use foundry_common::{provider::ProviderBuilder, shell};
 use foundry_config::Config;
 use foundry_evm_networks::NetworkVariant;
 use op_alloy_network::Optimism;
+use serde::Serialize;
+
+sol! {
+    #[sol(rpc)]
+    interface IGasPriceOracle {
+        function isFjord() external view returns (bool);
+        function l1BaseFee() external view returns (uint256);
+        function blobBaseFee() external view returns (uint256);
+        function baseFeeScalar() external view returns (uint32);
+        function blobBaseFeeScalar() external view returns (uint32);
+    }
+}
+
+/// Address of the `GasPriceOracle` predeploy.
+const GAS_PRICE_ORACLE: Address = address!("0x420000000000000000000000000000000000000F");
 
 /// CLI arguments for `cast da-estimate`.
 #[derive(Debug, Parser)]
 pub struct DAEstimateArgs {
     /// The block to estimate the data availability size for.
-    pub block: BlockId,
+    #[arg(required_unless_present = "data", conflicts_with = "data")]
+    pub block: Option<BlockId>,
+    /// Estimate the fees of a pending transaction with this calldata instead, including the L1
+    /// data fee computed from the current `GasPriceOracle` parameters.
+    #[arg(long, value_name = "CALLDATA")]
+    pub data: Option<Bytes>,
+    /// The recipient of the pending transaction. Omit it for a contract creation.
+    #[arg(long, requires = "data")]
+    pub to: Option<Address>,
+    /// The L2 execution gas of the pending transaction. Estimated by the node if not specified.
+    #[arg(long, requires = "data", value_name = "GAS")]
+    pub gas_limit: Option<u64>,
     #[command(flatten)]
     pub rpc: RpcOpts,
     /// Specify the Network for correct encoding.
@@ -27,7 +59,7 @@ pub struct DAEstimateArgs {
 impl DAEstimateArgs {
     /// Load the RPC URL from the config file.
     pub async fn run(self) -> Result<()> {
-        let Self { block, rpc, network } = self;
+        let Self { block, data, to, gas_limit, rpc, network } = self;
         let config = rpc.load_config()?;
         let network = match network {
             Some(n) => n,
@@ -36,6 +68,14 @@ pub async fn run(self) -> Result<()> {
                 provider.get_chain_id().await?.into()
             }
         };
+        if let Some(data) = data {
+            eyre::ensure!(
+                network == NetworkVariant::Optimism,
+                "L1 data fees are only charged on OP stack chains"
+            );
+            return l1_fee_estimate(&config, data, to, gas_limit).await;
+        }
+        let block = block.expect("required without --data");
         match network {
             NetworkVariant::Optimism => da_estimate::<Optimism>(&config, block).await,
             NetworkVariant::Ethereum => da_estimate::<Ethereum>(&config, block).await,
@@ -62,3 +102,143 @@ pub async fn da_estimate<N: Network>(config: &Config, block_id: BlockId) -> Resu
     )?;
     Ok(())
 }
+
+/// The L1 fee parameters of the `GasPriceOracle` predeploy.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct L1FeeParams {
+    l1_base_fee: U256,
+    blob_base_fee: U256,
+    base_fee_scalar: u32,
+    blob_base_fee_scalar: u32,
+}
+
+impl L1FeeParams {
+    /// Returns the L1 data fee of a transaction, given its estimated size scaled by 1e6, as
+    /// computed by the Fjord `GasPriceOracle`.
+    fn l1_fee(&self, estimated_size: u64) -> U256 {
+        let fee_scaled = U256::from(self.base_fee_scalar) * U256::from(16) * self.l1_base_fee
+            + U256::from(self.blob_base_fee_scalar) * self.blob_base_fee;
+        U256::from(estimated_size) * fee_scaled / U256::from(1_000_000_000_000u64)
+    }
+}
+
+/// The estimated fees of a pending transaction.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FeeEstimate {
+    l2_gas: u64,
+    l2_gas_price: u128,
+    l2_execution_fee: U256,
+    /// The estimated size of the transaction once compressed, in bytes.
+    l1_data_size: u64,
+    l1_fee_params: L1FeeParams,
+    l1_data_fee: U256,
+    total_fee: U256,
+}
+
+/// Estimates the L2 execution and L1 data fees of a pending transaction.
+async fn l1_fee_estimate(
+    config: &Config,
+    data: Bytes,
+    to: Option<Address>,
+    gas_limit: Option<u64>,
+) -> Result<()> {
+    let provider = ProviderBuilder::<AnyNetwork>::from_config(config)?.build()?;
+    let oracle = IGasPriceOracle::new(GAS_PRICE_ORACLE, &provider);
+    eyre::ensure!(
+        oracle.isFjord().call().await?,
+        "the L1 data fee can only be estimated on chains with the Fjord upgrade"
+    );
+    let params = L1FeeParams {
+        l1_base_fee: oracle.l1BaseFee().call().await?,
+        blob_base_fee: oracle.blobBaseFee().call().await?,
+        base_fee_scalar: oracle.baseFeeScalar().call().await?,
+        blob_base_fee_scalar: oracle.blobBaseFeeScalar().call().await?,
+    };
+
+    let kind = to.map_or(TxKind::Create, TxKind::Call);
+    let l2_gas = match gas_limit {
+        Some(gas_limit) => gas_limit,
+        None => {
+            let tx = TransactionRequest::default().with_kind(kind).with_input(data.clone());
+            provider.estimate_gas(WithOtherFields::new(tx)).await?
+        }
+    };
+    let l2_gas_price = provider.get_gas_price().await?;
+
+    // The fee is charged on the signed transaction, so sign it with the largest signature to get
+    // an upper bound of its size.
+    let tx = TxEip1559 {
+        chain_id: provider.get_chain_id().await?,
+        gas_limit: l2_gas,
+        max_fee_per_gas: l2_gas_price,
+        to: kind,
+        input: data,
+        ..Default::default()
+    };
+    let signature = Signature::new(U256::MAX, U256::MAX, false);
+    let encoded = TxEnvelope::from(tx.into_signed(signature)).encoded_2718();
+    let estimated_size = op_alloy_flz::tx_estimated_size_fjord(&encoded);
+
+    let l2_execution_fee = U256::from(l2_gas) * U256::from(l2_gas_price);
+    let l1_data_fee = params.l1_fee(estimated_size);
+    let estimate = FeeEstimate {
+        l2_gas,
+        l2_gas_price,
+        l2_execution_fee,
+        l1_data_size: estimated_size / 1_000_000,
+        l1_fee_params: params,
+        l1_data_fee,
+        total_fee: l2_execution_fee + l1_data_fee,
+    };
+    if shell::is_json() {
+        sh_println!("{}", serde_json::to_string_pretty(&estimate)?)?;
+    } else {
+        print_estimate(&estimate)?;
+    }
+    Ok(())
+}
+
+fn print_estimate(estimate: &FeeEstimate) -> Result<()> {
+    let gwei = |fee: U256| -> Result<String> { Ok(format!("{} gwei", format_units(fee, "gwei")?)) };
+    let eth = |fee: U256| -> Result<String> { Ok(format!("{} ETH", format_units(fee, "ether")?)) };
+    let params = &estimate.l1_fee_params;
+
+    sh_println!("L2 execution fee: {}", eth(estimate.l2_execution_fee)?)?;
+    sh_println!("  gas: {}", estimate.l2_gas)?;
+    sh_println!("  gas price: {}", gwei(U256::from(estimate.l2_gas_price))?)?;
+    sh_println!("L1 data fee: {}", eth(estimate.l1_data_fee)?)?;
+    sh_println!("  estimated size: {} bytes", estimate.l1_data_size)?;
+    sh_println!(
+        "  L1 base fee: {} (scalar {})",
+        gwei(params.l1_base_fee)?,
+        params.base_fee_scalar
+    )?;
+    sh_println!(
+        "  L1 blob base fee: {} (scalar {})",
+        gwei(params.blob_base_fee)?,
+        params.blob_base_fee_scalar
+    )?;
+    sh_println!("Total fee: {}", eth(estimate.total_fee)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_fjord_l1_fee() {
+        let params = L1FeeParams {
+            l1_base_fee: U256::from(10_000_000_000u64),
+            blob_base_fee: U256::from(1),
+            base_fee_scalar: 2_269,
+            blob_base_fee_scalar: 1_055_762,
+        };
+        // The minimum size of 100 bytes.
+        let fee = params.l1_fee(100_000_000);
+        // 100 * (2269 * 16 * 10 gwei + 1055762 * 1) / 1e6
+        assert_eq!(fee, U256::from(36_304_000_105u64));
+    }
+}
@@ -0,0 +1,302 @@
+use crate::Cast;
+use alloy_eips::BlockNumberOrTag;
+use alloy_primitives::utils::format_units;
+use alloy_provider::Provider;
+use alloy_rpc_types::FeeHistory;
+use clap::Parser;
+use eyre::Result;
+use foundry_cli::{
+    opts::RpcOpts,
+    utils::{self, LoadConfig},
+};
+use foundry_common::shell;
+use serde::Serialize;
+
+/// The maximum base fee increase per block, in basis points, as defined by EIP-1559.
+const MAX_BASE_FEE_CHANGE_BPS: u128 = 1_250;
+
+/// The recommendation tiers: name, targeted number of blocks until inclusion and the priority fee
+/// percentile to pay.
+const TIERS: [(&str, u64, Percentile); 3] = [
+    ("slow", 10, Percentile::Lowest),
+    ("standard", 3, Percentile::Median),
+    ("fast", 1, Percentile::Highest),
+];
+
+/// One of the requested priority fee percentiles.
+#[derive(Clone, Copy)]
+enum Percentile {
+    Lowest,
+    Median,
+    Highest,
+}
+
+/// CLI arguments for `cast gas`.
+#[derive(Clone, Debug, Parser)]
+pub struct GasArgs {
+    /// The number of blocks to analyze.
+    #[arg(long, default_value_t = 20, value_name = "BLOCKS")]
+    history: u64,
+
+    /// The priority fee percentiles to compute, between 0 and 100.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        default_value = "10,50,90",
+        value_parser = parse_percentile,
+        value_name = "PERCENTILES"
+    )]
+    percentiles: Vec<f64>,
+
+    /// The newest block to analyze.
+    ///
+    /// Can also be the tags earliest, finalized, safe, latest, or pending.
+    #[arg(long, short = 'B', default_value = "latest")]
+    block: BlockNumberOrTag,
+
+    #[command(flatten)]
+    rpc: RpcOpts,
+}
+
+fn parse_percentile(s: &str) -> Result<f64> {
+    let percentile: f64 = s.trim().parse()?;
+    eyre::ensure!((0.0..=100.0).contains(&percentile), "percentile must be between 0 and 100");
+    Ok(percentile)
+}
+
+/// The fee analysis of a range of blocks.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GasReport {
+    oldest_block: u64,
+    newest_block: u64,
+    /// The average block time, in seconds.
+    block_time: f64,
+    base_fee: FeeStats,
+    /// The median over the analyzed blocks of each priority fee percentile.
+    priority_fees: Vec<PriorityFee>,
+    /// The blob base fee, if the chain supports blobs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blob_base_fee: Option<FeeStats>,
+    recommendations: Vec<Recommendation>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FeeStats {
+    /// The fee of the block after the newest block.
+    next: u128,
+    min: u128,
+    max: u128,
+    mean: u128,
+    /// The change of the fee over the analyzed blocks, in percent.
+    trend: f64,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PriorityFee {
+    percentile: f64,
+    fee: u128,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Recommendation {
+    name: &'static str,
+    /// The targeted number of blocks until inclusion.
+    target_blocks: u64,
+    /// The targeted time until inclusion, in seconds.
+    target_seconds: f64,
+    max_fee_per_gas: u128,
+    max_priority_fee_per_gas: u128,
+}
+
+impl GasArgs {
+    pub async fn run(self) -> Result<()> {
+        let Self { history, mut percentiles, block, rpc } = self;
+        eyre::ensure!(history > 0, "the number of blocks must be greater than 0");
+        percentiles.sort_by(f64::total_cmp);
+        percentiles.dedup();
+
+        let config = rpc.load_config()?;
+        let provider = utils::get_provider(&config)?;
+        let fee_history = provider.get_fee_history(history, block, &percentiles).await?;
+        eyre::ensure!(!fee_history.gas_used_ratio.is_empty(), "no blocks to analyze");
+
+        let newest_block = fee_history.oldest_block + fee_history.gas_used_ratio.len() as u64 - 1;
+        let cast = Cast::new(&provider);
+        let oldest_timestamp: u64 = cast.timestamp(fee_history.oldest_block).await?.to();
+        let newest_timestamp: u64 = cast.timestamp(newest_block).await?.to();
+        let block_time = if newest_block > fee_history.oldest_block {
+            (newest_timestamp - oldest_timestamp) as f64
+                / (newest_block - fee_history.oldest_block) as f64
+        } else {
+            0.0
+        };
+
+        let report = analyze(&fee_history, &percentiles, block_time)?;
+        if shell::is_json() {
+            sh_println!("{}", serde_json::to_string_pretty(&report)?)?;
+        } else {
+            print_report(&report)?;
+        }
+        Ok(())
+    }
+}
+
+/// Analyzes the fee history of blocks computed with the sorted `percentiles`.
+fn analyze(history: &FeeHistory, percentiles: &[f64], block_time: f64) -> Result<GasReport> {
+    let blocks = history.gas_used_ratio.len() as u64;
+    let base_fee = fee_stats(&history.base_fee_per_gas).ok_or_else(|| {
+        eyre::eyre!("the fee history has no base fees, the chain may not support EIP-1559")
+    })?;
+    let blob_base_fee = fee_stats(&history.base_fee_per_blob_gas).filter(|stats| stats.max > 0);
+
+    // Empty blocks report zero rewards, skew the percentiles down and are ignored.
+    let rewards = history
+        .reward
+        .iter()
+        .flatten()
+        .zip(&history.gas_used_ratio)
+        .filter(|(_, ratio)| **ratio > 0.0)
+        .map(|(rewards, _)| rewards)
+        .collect::<Vec<_>>();
+    let priority_fees = percentiles
+        .iter()
+        .enumerate()
+        .map(|(i, &percentile)| {
+            let mut fees =
+                rewards.iter().filter_map(|rewards| rewards.get(i).copied()).collect::<Vec<_>>();
+            fees.sort_unstable();
+            PriorityFee { percentile, fee: fees.get(fees.len() / 2).copied().unwrap_or_default() }
+        })
+        .collect::<Vec<_>>();
+
+    let recommendations = TIERS
+        .iter()
+        .map(|&(name, target_blocks, percentile)| {
+            let priority_fee = match percentile {
+                Percentile::Lowest => priority_fees.first(),
+                Percentile::Median => priority_fees.get(priority_fees.len() / 2),
+                Percentile::Highest => priority_fees.last(),
+            }
+            .map_or(0, |fee| fee.fee);
+            Recommendation {
+                name,
+                target_blocks,
+                target_seconds: target_blocks as f64 * block_time,
+                max_fee_per_gas: max_base_fee(base_fee.next, target_blocks) + priority_fee,
+                max_priority_fee_per_gas: priority_fee,
+            }
+        })
+        .collect();
+
+    Ok(GasReport {
+        oldest_block: history.oldest_block,
+        newest_block: history.oldest_block + blocks - 1,
+        block_time,
+        base_fee,
+        priority_fees,
+        blob_base_fee,
+        recommendations,
+    })
+}
+
+/// Returns the statistics of the fees of a range of blocks, followed by the fee of the next block.
+fn fee_stats(fees: &[u128]) -> Option<FeeStats> {
+    let (&next, _) = fees.split_last()?;
+    let min = *fees.iter().min()?;
+    let max = *fees.iter().max()?;
+    let mean = fees.iter().sum::<u128>() / fees.len() as u128;
+    let first = fees[0];
+    let trend = if first == 0 { 0.0 } else { (next as f64 - first as f64) / first as f64 * 100.0 };
+    Some(FeeStats { next, min, max, mean, trend })
+}
+
+/// Returns the highest base fee `blocks` blocks from now, given the `base_fee` of the next block.
+fn max_base_fee(base_fee: u128, blocks: u64) -> u128 {
+    (1..blocks).fold(base_fee, |fee, _| fee + fee * MAX_BASE_FEE_CHANGE_BPS / 10_000)
+}
+
+fn print_report(report: &GasReport) -> Result<()> {
+    let gwei = |fee: u128| -> Result<String> { Ok(format!("{} gwei", format_units(fee, "gwei")?)) };
+    let stats = |name: &str, stats: &FeeStats| -> Result<()> {
+        sh_println!(
+            "{name}: {} (min {}, max {}, mean {}, trend {:+.2}%)",
+            gwei(stats.next)?,
+            gwei(stats.min)?,
+            gwei(stats.max)?,
+            gwei(stats.mean)?,
+            stats.trend
+        )?;
+        Ok(())
+    };
+
+    sh_println!(
+        "Blocks {} to {} (~{:.1}s per block)",
+        report.oldest_block,
+        report.newest_block,
+        report.block_time
+    )?;
+    stats("Base fee", &report.base_fee)?;
+    if let Some(blob_base_fee) = &report.blob_base_fee {
+        stats("Blob base fee", blob_base_fee)?;
+    }
+    sh_println!("Priority fees:")?;
+    for fee in &report.priority_fees {
+        sh_println!("  p{}: {}", fee.percentile, gwei(fee.fee)?)?;
+    }
+    sh_println!("Recommendations:")?;
+    for tier in &report.recommendations {
+        sh_println!(
+            "  {:<8} (~{} blocks, ~{:.0}s): max fee {}, max priority fee {}",
+            tier.name,
+            tier.target_blocks,
+            tier.target_seconds,
+            gwei(tier.max_fee_per_gas)?,
+            gwei(tier.max_priority_fee_per_gas)?
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn analyzes_fee_history() {
+        let history = FeeHistory {
+            oldest_block: 100,
+            base_fee_per_gas: vec![100, 110, 120, 140],
+            gas_used_ratio: vec![0.9, 0.0, 0.8],
+            reward: Some(vec![vec![1, 5, 9], vec![0, 0, 0], vec![3, 7, 11]]),
+            base_fee_per_blob_gas: vec![0, 0, 0, 0],
+            blob_gas_used_ratio: vec![0.0, 0.0, 0.0],
+        };
+        let report = analyze(&history, &[10.0, 50.0, 90.0], 12.0).unwrap();
+
+        assert_eq!(report.newest_block, 102);
+        assert_eq!(
+            report.base_fee,
+            FeeStats { next: 140, min: 100, max: 140, mean: 117, trend: 40.0 }
+        );
+        assert_eq!(report.blob_base_fee, None);
+        let fees = report.priority_fees.iter().map(|fee| fee.fee).collect::<Vec<_>>();
+        assert_eq!(fees, [3, 7, 11]);
+
+        let fast = report.recommendations.iter().find(|tier| tier.name == "fast").unwrap();
+        assert_eq!(fast.max_fee_per_gas, 140 + 11);
+        assert_eq!(fast.target_seconds, 12.0);
+        let slow = report.recommendations.iter().find(|tier| tier.name == "slow").unwrap();
+        assert_eq!(slow.max_priority_fee_per_gas, 3);
+        assert_eq!(slow.max_fee_per_gas, max_base_fee(140, 10) + 3);
+    }
+
+    #[test]
+    fn bounds_base_fee_increase() {
+        assert_eq!(max_base_fee(1_000_000, 1), 1_000_000);
+        assert_eq!(max_base_fee(1_000_000, 3), 1_265_625);
+    }
+}
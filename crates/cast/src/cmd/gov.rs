@@ -0,0 +1,741 @@
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockId;
+use alloy_network::{BlockResponse, TransactionBuilder};
+use alloy_primitives::{Address, B256, Bytes, U256, keccak256};
+use alloy_provider::Provider;
+use alloy_rpc_types::TransactionRequest;
+use alloy_sol_types::{SolCall, SolValue, sol};
+use chrono::DateTime;
+use clap::{Parser, ValueEnum};
+use eyre::{OptionExt, Result, bail};
+use foundry_cli::{
+    opts::RpcOpts,
+    utils::{self, LoadConfig, parse_ether_value},
+};
+use foundry_common::shell;
+use foundry_config::Config;
+use foundry_evm::{
+    core::{FoundryBlock, decode::RevertDecoder, evm::EthEvmNetwork},
+    executors::{Executor, TracingExecutor},
+    opts::EvmOpts,
+    traces::TraceMode,
+};
+use revm::context::Block;
+use serde::Serialize;
+use std::path::PathBuf;
+
+sol! {
+    interface IGovernor {
+        function propose(address[] targets, uint256[] values, bytes[] calldatas, string description) external returns (uint256);
+        function queue(address[] targets, uint256[] values, bytes[] calldatas, bytes32 descriptionHash) external returns (uint256);
+        function execute(address[] targets, uint256[] values, bytes[] calldatas, bytes32 descriptionHash) external payable returns (uint256);
+        function castVote(uint256 proposalId, uint8 support) external returns (uint256);
+        function state(uint256 proposalId) external view returns (uint8);
+        function votingDelay() external view returns (uint256);
+        function votingPeriod() external view returns (uint256);
+        function proposalSnapshot(uint256 proposalId) external view returns (uint256);
+        function proposalDeadline(uint256 proposalId) external view returns (uint256);
+        function proposalEta(uint256 proposalId) external view returns (uint256);
+        function CLOCK_MODE() external view returns (string);
+        function timelock() external view returns (address);
+    }
+
+    interface IGovernorBravo {
+        function propose(address[] targets, uint256[] values, string[] signatures, bytes[] calldatas, string description) external returns (uint256);
+        function queue(uint256 proposalId) external;
+        function execute(uint256 proposalId) external payable;
+        function castVote(uint256 proposalId, uint8 support) external;
+        function state(uint256 proposalId) external view returns (uint8);
+        function votingDelay() external view returns (uint256);
+        function votingPeriod() external view returns (uint256);
+        function timelock() external view returns (address);
+    }
+
+    interface ITimelock {
+        function getMinDelay() external view returns (uint256);
+        function delay() external view returns (uint256);
+    }
+}
+
+/// The `Succeeded` state of a proposal, shared by OpenZeppelin and Bravo governors.
+const STATE_SUCCEEDED: u8 = 4;
+/// The `Queued` state of a proposal.
+const STATE_QUEUED: u8 = 5;
+/// The `Executed` state of a proposal.
+const STATE_EXECUTED: u8 = 7;
+
+/// The names of the proposal states, shared by OpenZeppelin and Bravo governors.
+const STATES: [&str; 8] =
+    ["Pending", "Active", "Canceled", "Defeated", "Succeeded", "Queued", "Expired", "Executed"];
+
+/// Governance proposal helpers.
+#[derive(Debug, Parser, Clone)]
+pub enum GovSubcommand {
+    /// Encode a governance proposal and print its voting schedule.
+    ///
+    /// With an RPC endpoint, the voting schedule is estimated from the governor settings, and
+    /// `--simulate` runs the proposal through propose, vote, queue and execute on a fork.
+    #[command(visible_alias = "p")]
+    Propose(GovProposeArgs),
+}
+
+impl GovSubcommand {
+    pub async fn run(self) -> Result<()> {
+        match self {
+            Self::Propose(args) => args.run().await,
+        }
+    }
+}
+
+/// The governor implementations supported by `cast gov`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GovernorKind {
+    /// OpenZeppelin's `Governor`.
+    #[default]
+    #[value(name = "oz")]
+    OpenZeppelin,
+    /// Compound's `GovernorBravo`.
+    Bravo,
+}
+
+/// CLI arguments for `cast gov propose`.
+#[derive(Debug, Parser, Clone)]
+pub struct GovProposeArgs {
+    /// The governor contract.
+    governor: Address,
+
+    /// The implementation of the governor.
+    #[arg(long, value_enum, default_value_t)]
+    kind: GovernorKind,
+
+    /// The target of an action, in order.
+    #[arg(long = "target", value_name = "ADDRESS", required = true)]
+    targets: Vec<Address>,
+
+    /// The value sent by an action, in order. Defaults to zero for all actions.
+    #[arg(long = "value", value_name = "VALUE", value_parser = parse_ether_value)]
+    values: Vec<U256>,
+
+    /// The calldata of an action, in order, e.g. from `cast calldata`.
+    #[arg(long = "calldata", value_name = "CALLDATA", required = true)]
+    calldatas: Vec<Bytes>,
+
+    /// The description of the proposal.
+    #[arg(long, required_unless_present = "description_file")]
+    description: Option<String>,
+
+    /// Read the description of the proposal from a file.
+    #[arg(long, value_name = "PATH", conflicts_with = "description")]
+    description_file: Option<PathBuf>,
+
+    /// Simulate the proposal lifecycle on a fork: propose, vote, queue and execute.
+    #[arg(long)]
+    simulate: bool,
+
+    /// The proposer of the simulated proposal, who must hold enough votes to propose.
+    #[arg(long, value_name = "ADDRESS", requires = "simulate")]
+    proposer: Option<Address>,
+
+    /// A voter in favor of the simulated proposal, who must hold votes at the proposal snapshot.
+    #[arg(long = "voter", value_name = "ADDRESS", requires = "simulate")]
+    voters: Vec<Address>,
+
+    /// The average block time in seconds, to estimate dates from block numbers.
+    #[arg(long, value_name = "SECONDS", default_value = "12")]
+    block_time: u64,
+
+    #[command(flatten)]
+    rpc: RpcOpts,
+}
+
+/// The clock of a governor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum Clock {
+    BlockNumber,
+    Timestamp,
+}
+
+/// A point of the voting schedule, in the clock of the governor.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Milestone {
+    /// The block number or timestamp, depending on the clock.
+    clock: u64,
+    /// The estimated timestamp.
+    timestamp: u64,
+}
+
+/// The estimated voting schedule of a proposal made now.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VotingSchedule {
+    clock: Clock,
+    voting_starts: Milestone,
+    voting_ends: Milestone,
+    /// The delay of the timelock, if any, in seconds.
+    timelock_delay: Option<u64>,
+    /// The earliest estimated timestamp at which the proposal can be executed.
+    executable_from: u64,
+}
+
+/// An encoded proposal.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Proposal {
+    governor: Address,
+    kind: GovernorKind,
+    /// The id of the proposal, if known before proposing.
+    proposal_id: Option<U256>,
+    targets: Vec<Address>,
+    values: Vec<U256>,
+    calldatas: Vec<Bytes>,
+    description_hash: B256,
+    propose_calldata: Bytes,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    schedule: Option<VotingSchedule>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    simulation: Option<Vec<String>>,
+}
+
+impl GovProposeArgs {
+    pub async fn run(self) -> Result<()> {
+        let description = match (&self.description, &self.description_file) {
+            (Some(description), _) => description.clone(),
+            (None, Some(path)) => foundry_common::fs::read_to_string(path)?,
+            (None, None) => unreachable!("required by clap"),
+        };
+        let values = if self.values.is_empty() {
+            vec![U256::ZERO; self.targets.len()]
+        } else {
+            self.values.clone()
+        };
+        if values.len() != self.targets.len() || self.calldatas.len() != self.targets.len() {
+            bail!(
+                "the proposal has {} targets, {} values and {} calldatas, they must match",
+                self.targets.len(),
+                values.len(),
+                self.calldatas.len()
+            );
+        }
+
+        let mut proposal = Proposal::new(
+            self.governor,
+            self.kind,
+            self.targets.clone(),
+            values,
+            self.calldatas.clone(),
+            &description,
+        );
+
+        let config = self.rpc.load_config()?;
+        if self.rpc.url(Some(&config))?.is_some() {
+            proposal.schedule = Some(self.voting_schedule(&config).await?);
+        } else if self.simulate {
+            bail!("`--simulate` requires an RPC endpoint, set `--rpc-url`");
+        }
+        if self.simulate {
+            proposal.simulation = Some(self.simulate(&proposal, &description).await?);
+        }
+
+        if shell::is_json() {
+            sh_println!("{}", serde_json::to_string_pretty(&proposal)?)?;
+            return Ok(());
+        }
+
+        match proposal.proposal_id {
+            Some(id) => sh_println!("Proposal id: {id}")?,
+            None => sh_println!("Proposal id: assigned when proposed")?,
+        }
+        sh_println!("Description hash: {}", proposal.description_hash)?;
+        sh_println!("Propose calldata: {}", proposal.propose_calldata)?;
+        if let Some(schedule) = &proposal.schedule {
+            let unit = match schedule.clock {
+                Clock::BlockNumber => "block",
+                Clock::Timestamp => "timestamp",
+            };
+            sh_println!("Voting schedule (estimated, if proposed now):")?;
+            sh_println!(
+                "  Voting starts: {unit} {} (~{})",
+                schedule.voting_starts.clock,
+                format_timestamp(schedule.voting_starts.timestamp)
+            )?;
+            sh_println!(
+                "  Voting ends: {unit} {} (~{})",
+                schedule.voting_ends.clock,
+                format_timestamp(schedule.voting_ends.timestamp)
+            )?;
+            if let Some(delay) = schedule.timelock_delay {
+                sh_println!("  Timelock delay: {delay}s")?;
+            }
+            sh_println!("  Executable from: ~{}", format_timestamp(schedule.executable_from))?;
+        }
+        if let Some(simulation) = &proposal.simulation {
+            sh_println!("Simulation:")?;
+            for step in simulation {
+                sh_println!("  {step}")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Estimates the voting schedule of the proposal from the governor settings.
+    async fn voting_schedule(&self, config: &Config) -> Result<VotingSchedule> {
+        let provider = utils::get_provider(config)?;
+        let block =
+            provider.get_block(BlockId::latest()).await?.ok_or_eyre("latest block not found")?;
+        let (number, timestamp) = (block.header().number(), block.header().timestamp());
+
+        let call = async |input: Vec<u8>| {
+            let tx = TransactionRequest::default().with_to(self.governor).with_input(input);
+            provider.call(tx.into()).await
+        };
+        let (voting_delay, voting_period, clock) = match self.kind {
+            GovernorKind::OpenZeppelin => {
+                let clock = match call(IGovernor::CLOCK_MODECall {}.abi_encode()).await {
+                    Ok(mode)
+                        if IGovernor::CLOCK_MODECall::abi_decode_returns(&mode)
+                            .is_ok_and(|mode| mode.contains("mode=timestamp")) =>
+                    {
+                        Clock::Timestamp
+                    }
+                    _ => Clock::BlockNumber,
+                };
+                (
+                    IGovernor::votingDelayCall::abi_decode_returns(
+                        &call(IGovernor::votingDelayCall {}.abi_encode()).await?,
+                    )?,
+                    IGovernor::votingPeriodCall::abi_decode_returns(
+                        &call(IGovernor::votingPeriodCall {}.abi_encode()).await?,
+                    )?,
+                    clock,
+                )
+            }
+            GovernorKind::Bravo => (
+                IGovernorBravo::votingDelayCall::abi_decode_returns(
+                    &call(IGovernorBravo::votingDelayCall {}.abi_encode()).await?,
+                )?,
+                IGovernorBravo::votingPeriodCall::abi_decode_returns(
+                    &call(IGovernorBravo::votingPeriodCall {}.abi_encode()).await?,
+                )?,
+                Clock::BlockNumber,
+            ),
+        };
+        let (voting_delay, voting_period) = (voting_delay.to::<u64>(), voting_period.to::<u64>());
+
+        // Governors without a timelock execute proposals as soon as they succeed.
+        let timelock = match call(IGovernor::timelockCall {}.abi_encode()).await {
+            Ok(timelock) => IGovernor::timelockCall::abi_decode_returns(&timelock).ok(),
+            Err(_) => None,
+        }
+        .filter(|timelock| !timelock.is_zero());
+        let timelock_delay = match timelock {
+            Some(timelock) => {
+                let call = async |input: Vec<u8>| {
+                    let tx = TransactionRequest::default().with_to(timelock).with_input(input);
+                    provider.call(tx.into()).await
+                };
+                let delay = match call(ITimelock::getMinDelayCall {}.abi_encode()).await {
+                    Ok(delay) => ITimelock::getMinDelayCall::abi_decode_returns(&delay).ok(),
+                    Err(_) => match call(ITimelock::delayCall {}.abi_encode()).await {
+                        Ok(delay) => ITimelock::delayCall::abi_decode_returns(&delay).ok(),
+                        Err(_) => None,
+                    },
+                };
+                delay.map(|delay| delay.to::<u64>())
+            }
+            None => None,
+        };
+
+        let milestone = |clock_value: u64| Milestone {
+            clock: clock_value,
+            timestamp: match clock {
+                Clock::BlockNumber => {
+                    timestamp + clock_value.saturating_sub(number) * self.block_time
+                }
+                Clock::Timestamp => clock_value,
+            },
+        };
+        let now = match clock {
+            Clock::BlockNumber => number,
+            Clock::Timestamp => timestamp,
+        };
+        // Votes open the clock tick after the snapshot, and close after the deadline.
+        let voting_starts = milestone(now + voting_delay + 1);
+        let voting_ends = milestone(now + voting_delay + voting_period + 1);
+        let executable_from = voting_ends.timestamp + timelock_delay.unwrap_or_default();
+
+        Ok(VotingSchedule { clock, voting_starts, voting_ends, timelock_delay, executable_from })
+    }
+
+    /// Runs the proposal through its lifecycle on a fork, and returns the steps taken.
+    async fn simulate(&self, proposal: &Proposal, description: &str) -> Result<Vec<String>> {
+        let figment = self.rpc.clone().into_figment(false);
+        let evm_opts = figment.extract::<EvmOpts>()?;
+        let mut config = Config::from_provider(figment)?.sanitized();
+        let create2_deployer = evm_opts.create2_deployer;
+        let (mut evm_env, tx_env, fork, _, networks) =
+            TracingExecutor::<EthEvmNetwork>::get_fork_material(&mut config, evm_opts).await?;
+        evm_env.cfg_env.disable_block_gas_limit = true;
+        evm_env.cfg_env.tx_gas_limit_cap = Some(u64::MAX);
+        let mut executor = TracingExecutor::<EthEvmNetwork>::new(
+            (evm_env, tx_env),
+            fork,
+            None,
+            TraceMode::None,
+            networks,
+            create2_deployer,
+            None,
+        )?;
+        let executor: &mut Executor<EthEvmNetwork> = &mut executor;
+
+        let governor = self.governor;
+        let proposer = self.proposer.or(self.voters.first().copied()).ok_or_eyre(
+            "`--simulate` requires a `--proposer` or a `--voter` holding enough votes to propose",
+        )?;
+        let voters = if self.voters.is_empty() { vec![proposer] } else { self.voters.clone() };
+        let targets = proposal.targets.clone();
+        let values = proposal.values.clone();
+        let calldatas = proposal.calldatas.clone();
+        let description_hash = proposal.description_hash;
+        let total_value = values.iter().sum::<U256>();
+
+        let mut steps = Vec::new();
+        match self.kind {
+            GovernorKind::OpenZeppelin => {
+                let id = IGovernor::proposeCall::abi_decode_returns(&transact(
+                    executor,
+                    proposer,
+                    governor,
+                    IGovernor::proposeCall {
+                        targets: targets.clone(),
+                        values: values.clone(),
+                        calldatas: calldatas.clone(),
+                        description: description.to_string(),
+                    },
+                    U256::ZERO,
+                )?)?;
+                steps.push(format!("proposed by {proposer}, id {id}"));
+
+                let timestamp_clock = view::<IGovernor::CLOCK_MODECall>(
+                    executor,
+                    governor,
+                    IGovernor::CLOCK_MODECall {},
+                )
+                .is_ok_and(|mode| mode.contains("mode=timestamp"));
+                let clock = if timestamp_clock { Clock::Timestamp } else { Clock::BlockNumber };
+
+                let snapshot =
+                    view(executor, governor, IGovernor::proposalSnapshotCall { proposalId: id })?;
+                advance(executor, clock, snapshot.to::<u64>() + 1, self.block_time);
+                for voter in &voters {
+                    transact(
+                        executor,
+                        *voter,
+                        governor,
+                        IGovernor::castVoteCall { proposalId: id, support: 1 },
+                        U256::ZERO,
+                    )?;
+                    steps.push(format!("voted for by {voter}"));
+                }
+
+                let deadline =
+                    view(executor, governor, IGovernor::proposalDeadlineCall { proposalId: id })?;
+                advance(executor, clock, deadline.to::<u64>() + 1, self.block_time);
+                expect_state(
+                    view(executor, governor, IGovernor::stateCall { proposalId: id })?,
+                    STATE_SUCCEEDED,
+                )?;
+                steps.push("succeeded".to_string());
+
+                // Governors without a timelock don't need queueing.
+                let queue = IGovernor::queueCall {
+                    targets: targets.clone(),
+                    values: values.clone(),
+                    calldatas: calldatas.clone(),
+                    descriptionHash: description_hash,
+                };
+                if transact(executor, proposer, governor, queue, U256::ZERO).is_ok() {
+                    let eta =
+                        view(executor, governor, IGovernor::proposalEtaCall { proposalId: id })?;
+                    steps.push(format!("queued, executable from {}", format_timestamp(eta.to())));
+                    advance(executor, Clock::Timestamp, eta.to::<u64>() + 1, self.block_time);
+                }
+
+                executor.set_balance(proposer, total_value)?;
+                transact(
+                    executor,
+                    proposer,
+                    governor,
+                    IGovernor::executeCall {
+                        targets,
+                        values,
+                        calldatas,
+                        descriptionHash: description_hash,
+                    },
+                    total_value,
+                )?;
+                expect_state(
+                    view(executor, governor, IGovernor::stateCall { proposalId: id })?,
+                    STATE_EXECUTED,
+                )?;
+                steps.push("executed".to_string());
+            }
+            GovernorKind::Bravo => {
+                let voting_delay = view(executor, governor, IGovernorBravo::votingDelayCall {})?;
+                let voting_period = view(executor, governor, IGovernorBravo::votingPeriodCall {})?;
+                let id = IGovernorBravo::proposeCall::abi_decode_returns(&transact(
+                    executor,
+                    proposer,
+                    governor,
+                    IGovernorBravo::proposeCall {
+                        targets,
+                        values,
+                        signatures: vec![String::new(); proposal.targets.len()],
+                        calldatas,
+                        description: description.to_string(),
+                    },
+                    U256::ZERO,
+                )?)?;
+                steps.push(format!("proposed by {proposer}, id {id}"));
+
+                let start =
+                    executor.evm_env().block_env.number().to::<u64>() + voting_delay.to::<u64>();
+                advance(executor, Clock::BlockNumber, start + 1, self.block_time);
+                for voter in &voters {
+                    transact(
+                        executor,
+                        *voter,
+                        governor,
+                        IGovernorBravo::castVoteCall { proposalId: id, support: 1 },
+                        U256::ZERO,
+                    )?;
+                    steps.push(format!("voted for by {voter}"));
+                }
+
+                advance(
+                    executor,
+                    Clock::BlockNumber,
+                    start + voting_period.to::<u64>() + 1,
+                    self.block_time,
+                );
+                expect_state(
+                    view(executor, governor, IGovernorBravo::stateCall { proposalId: id })?,
+                    STATE_SUCCEEDED,
+                )?;
+                steps.push("succeeded".to_string());
+
+                transact(
+                    executor,
+                    proposer,
+                    governor,
+                    IGovernorBravo::queueCall { proposalId: id },
+                    U256::ZERO,
+                )?;
+                expect_state(
+                    view(executor, governor, IGovernorBravo::stateCall { proposalId: id })?,
+                    STATE_QUEUED,
+                )?;
+                let timelock = view(executor, governor, IGovernorBravo::timelockCall {})?;
+                let delay = view(executor, timelock, ITimelock::delayCall {})?;
+                let eta = executor.evm_env().block_env.timestamp().to::<u64>() + delay.to::<u64>();
+                steps.push(format!("queued, executable from {}", format_timestamp(eta)));
+                advance(executor, Clock::Timestamp, eta + 1, self.block_time);
+
+                executor.set_balance(proposer, total_value)?;
+                transact(
+                    executor,
+                    proposer,
+                    governor,
+                    IGovernorBravo::executeCall { proposalId: id },
+                    total_value,
+                )?;
+                expect_state(
+                    view(executor, governor, IGovernorBravo::stateCall { proposalId: id })?,
+                    STATE_EXECUTED,
+                )?;
+                steps.push("executed".to_string());
+            }
+        }
+
+        Ok(steps)
+    }
+}
+
+impl Proposal {
+    fn new(
+        governor: Address,
+        kind: GovernorKind,
+        targets: Vec<Address>,
+        values: Vec<U256>,
+        calldatas: Vec<Bytes>,
+        description: &str,
+    ) -> Self {
+        let description_hash = keccak256(description);
+        let (proposal_id, propose_calldata) = match kind {
+            GovernorKind::OpenZeppelin => {
+                let id = keccak256(
+                    (targets.clone(), values.clone(), calldatas.clone(), description_hash)
+                        .abi_encode_params(),
+                );
+                let calldata = IGovernor::proposeCall {
+                    targets: targets.clone(),
+                    values: values.clone(),
+                    calldatas: calldatas.clone(),
+                    description: description.to_string(),
+                }
+                .abi_encode();
+                (Some(id.into()), calldata)
+            }
+            // Bravo assigns sequential ids when proposals are made.
+            GovernorKind::Bravo => {
+                let calldata = IGovernorBravo::proposeCall {
+                    targets: targets.clone(),
+                    values: values.clone(),
+                    signatures: vec![String::new(); targets.len()],
+                    calldatas: calldatas.clone(),
+                    description: description.to_string(),
+                }
+                .abi_encode();
+                (None, calldata)
+            }
+        };
+        Self {
+            governor,
+            kind,
+            proposal_id,
+            targets,
+            values,
+            calldatas,
+            description_hash,
+            propose_calldata: propose_calldata.into(),
+            schedule: None,
+            simulation: None,
+        }
+    }
+}
+
+/// Sends a call on the fork, failing if it reverts.
+fn transact<C: SolCall>(
+    executor: &mut Executor<EthEvmNetwork>,
+    from: Address,
+    to: Address,
+    call: C,
+    value: U256,
+) -> Result<Bytes> {
+    let res = executor.transact_raw(from, to, call.abi_encode().into(), value)?;
+    if res.reverted {
+        bail!(
+            "`{}` reverted: {}",
+            C::SIGNATURE,
+            RevertDecoder::new().decode(&res.result, res.exit_reason)
+        );
+    }
+    Ok(res.result)
+}
+
+/// Calls a view function on the fork.
+fn view<C: SolCall>(
+    executor: &mut Executor<EthEvmNetwork>,
+    to: Address,
+    call: C,
+) -> Result<C::Return> {
+    let res = executor.call_raw(Address::ZERO, to, call.abi_encode().into(), U256::ZERO)?;
+    if res.reverted {
+        bail!(
+            "`{}` reverted: {}",
+            C::SIGNATURE,
+            RevertDecoder::new().decode(&res.result, res.exit_reason)
+        );
+    }
+    Ok(C::abi_decode_returns(&res.result)?)
+}
+
+/// Fails if the proposal isn't in the expected state.
+fn expect_state(state: u8, expected: u8) -> Result<()> {
+    if state != expected {
+        bail!(
+            "the proposal is {} instead of {}",
+            STATES.get(state as usize).copied().unwrap_or("in an unknown state"),
+            STATES[expected as usize]
+        );
+    }
+    Ok(())
+}
+
+/// Moves the fork forward to the given block number or timestamp, keeping the other one in line
+/// with the block time.
+fn advance(executor: &mut Executor<EthEvmNetwork>, clock: Clock, to: u64, block_time: u64) {
+    let block_env = &mut executor.evm_env_mut().block_env;
+    let number = block_env.number().to::<u64>();
+    let timestamp = block_env.timestamp().to::<u64>();
+    let (number, timestamp) = match clock {
+        Clock::BlockNumber if to > number => (to, timestamp + (to - number) * block_time),
+        Clock::Timestamp if to > timestamp => {
+            (number + (to - timestamp).div_ceil(block_time.max(1)), to)
+        }
+        _ => return,
+    };
+    block_env.set_number(U256::from(number));
+    block_env.set_timestamp(U256::from(timestamp));
+}
+
+fn format_timestamp(timestamp: u64) -> String {
+    DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|date| date.to_rfc3339())
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256};
+
+    #[test]
+    fn computes_openzeppelin_proposal_id() {
+        let proposal = Proposal::new(
+            address!("0x0000000000000000000000000000000000000001"),
+            GovernorKind::OpenZeppelin,
+            vec![address!("0x0000000000000000000000000000000000000002")],
+            vec![U256::ZERO],
+            vec![Bytes::new()],
+            "# Proposal",
+        );
+
+        assert_eq!(proposal.description_hash, keccak256("# Proposal"));
+        let expected = keccak256(
+            (
+                vec![address!("0x0000000000000000000000000000000000000002")],
+                vec![U256::ZERO],
+                vec![Bytes::new()],
+                proposal.description_hash,
+            )
+                .abi_encode_params(),
+        );
+        assert_eq!(proposal.proposal_id, Some(expected.into()));
+        let call = IGovernor::proposeCall::abi_decode(&proposal.propose_calldata).unwrap();
+        assert_eq!(call.description, "# Proposal");
+    }
+
+    #[test]
+    fn encodes_bravo_proposal() {
+        let proposal = Proposal::new(
+            Address::ZERO,
+            GovernorKind::Bravo,
+            vec![Address::ZERO, Address::ZERO],
+            vec![U256::ZERO, U256::from(1)],
+            vec![Bytes::new(), Bytes::new()],
+            "",
+        );
+
+        assert_eq!(proposal.proposal_id, None);
+        assert_eq!(
+            proposal.description_hash,
+            b256!("0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+        );
+        let call = IGovernorBravo::proposeCall::abi_decode(&proposal.propose_calldata).unwrap();
+        assert_eq!(call.signatures, vec![String::new(), String::new()]);
+        assert_eq!(call.values, vec![U256::ZERO, U256::from(1)]);
+    }
+}
@@ -0,0 +1,345 @@
+use alloy_dyn_abi::{DynSolType, DynSolValue};
+use alloy_primitives::{B256, keccak256};
+use clap::{Parser, ValueHint};
+use eyre::{Result, WrapErr, bail, ensure};
+use foundry_common::{fs, shell};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// Build, prove and verify OpenZeppelin `StandardMerkleTree`s.
+#[derive(Debug, Parser, Clone)]
+pub enum MerkleSubcommand {
+    /// Build a merkle tree from a CSV or JSON file of leaves, and print its root.
+    ///
+    /// CSV files have one leaf per line, with an optional header line. JSON files are an array
+    /// of leaves, each leaf being an array of values.
+    #[command(visible_alias = "b")]
+    Build {
+        /// The file of leaves.
+        #[arg(value_hint = ValueHint::FilePath)]
+        leaves: PathBuf,
+
+        /// The ABI types of the values of a leaf, e.g. `address,uint256`.
+        #[arg(long, value_delimiter = ',', required = true)]
+        types: Vec<String>,
+
+        /// Write the tree to the given file, in the format of the OpenZeppelin library.
+        #[arg(long, short, value_hint = ValueHint::FilePath, value_name = "PATH")]
+        out: Option<PathBuf>,
+    },
+
+    /// Print the proof of a leaf of a tree built with `cast merkle build`.
+    #[command(visible_alias = "p")]
+    Proof {
+        /// The tree file.
+        #[arg(value_hint = ValueHint::FilePath)]
+        tree: PathBuf,
+
+        /// The values of the leaf.
+        #[arg(required_unless_present_any = ["index", "all"], allow_negative_numbers = true)]
+        values: Vec<String>,
+
+        /// The index of the leaf in the leaves file, instead of its values.
+        #[arg(long, conflicts_with = "values")]
+        index: Option<usize>,
+
+        /// Print the proofs of all the leaves, as JSON.
+        #[arg(long, conflicts_with_all = ["values", "index"])]
+        all: bool,
+    },
+
+    /// Verify the proof of a leaf against a root.
+    #[command(visible_alias = "v")]
+    Verify {
+        /// The root of the tree.
+        root: B256,
+
+        /// The values of the leaf.
+        #[arg(required_unless_present = "leaf", allow_negative_numbers = true)]
+        values: Vec<String>,
+
+        /// The ABI types of the values of the leaf, e.g. `address,uint256`.
+        #[arg(long, value_delimiter = ',', requires = "values")]
+        types: Vec<String>,
+
+        /// The hash of the leaf, instead of its values.
+        #[arg(long, conflicts_with_all = ["values", "types"])]
+        leaf: Option<B256>,
+
+        /// The proof of the leaf.
+        #[arg(long, value_delimiter = ',')]
+        proof: Vec<B256>,
+    },
+}
+
+impl MerkleSubcommand {
+    pub fn run(self) -> Result<()> {
+        match self {
+            Self::Build { leaves, types, out } => {
+                let values = read_leaves(&leaves)?;
+                let tree = StandardMerkleTree::build(types, values)?;
+                if let Some(out) = out {
+                    fs::write_pretty_json_file(&out, &tree)?;
+                }
+                if shell::is_json() {
+                    sh_println!("{}", serde_json::to_string(&tree)?)?;
+                } else {
+                    sh_println!("{}", tree.root())?;
+                }
+            }
+            Self::Proof { tree, values, index, all } => {
+                let tree: StandardMerkleTree = fs::read_json_file(&tree)?;
+                tree.validate()?;
+                if all {
+                    let proofs = (0..tree.values.len())
+                        .map(|index| {
+                            Ok(serde_json::json!({
+                                "value": tree.values[index].value,
+                                "proof": tree.proof(index)?,
+                            }))
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    sh_println!("{}", serde_json::to_string_pretty(&proofs)?)?;
+                    return Ok(());
+                }
+
+                let index = match index {
+                    Some(index) => index,
+                    None => tree.leaf_index(&values)?,
+                };
+                let proof = tree.proof(index)?;
+                if shell::is_json() {
+                    sh_println!("{}", serde_json::to_string(&proof)?)?;
+                } else {
+                    for node in proof {
+                        sh_println!("{node}")?;
+                    }
+                }
+            }
+            Self::Verify { root, values, types, leaf, proof } => {
+                let leaf = match leaf {
+                    Some(leaf) => leaf,
+                    None => {
+                        let types = parse_types(&types)?;
+                        leaf_hash(&types, &values)?
+                    }
+                };
+                ensure!(process_proof(leaf, &proof) == root, "invalid proof");
+                sh_println!("valid")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A merkle tree compatible with the `StandardMerkleTree` of the OpenZeppelin library, and its
+/// `standard-v1` JSON format.
+///
+/// Leaves are the double keccak256 hash of the ABI encoded values, and pairs are hashed sorted.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StandardMerkleTree {
+    pub format: String,
+    pub leaf_encoding: Vec<String>,
+    pub tree: Vec<B256>,
+    pub values: Vec<TreeValue>,
+}
+
+/// A leaf of a [`StandardMerkleTree`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TreeValue {
+    pub value: Vec<Value>,
+    pub tree_index: usize,
+}
+
+impl StandardMerkleTree {
+    /// The format of the tree dumps.
+    pub const FORMAT: &str = "standard-v1";
+
+    /// Builds a tree from the values of its leaves, in order.
+    pub fn build(leaf_encoding: Vec<String>, values: Vec<Vec<Value>>) -> Result<Self> {
+        ensure!(!values.is_empty(), "at least one leaf is required");
+        let types = parse_types(&leaf_encoding)?;
+
+        let mut leaves = values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let strings = value.iter().map(value_to_string).collect::<Vec<_>>();
+                let hash = leaf_hash(&types, &strings)
+                    .wrap_err_with(|| format!("invalid leaf {index}"))?;
+                Ok((hash, index))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        leaves.sort();
+
+        let mut tree = vec![B256::ZERO; 2 * leaves.len() - 1];
+        let mut tree_indexes = vec![0; leaves.len()];
+        for (i, (hash, index)) in leaves.iter().enumerate() {
+            let tree_index = tree.len() - 1 - i;
+            tree[tree_index] = *hash;
+            tree_indexes[*index] = tree_index;
+        }
+        for i in (0..tree.len() - leaves.len()).rev() {
+            tree[i] = hash_pair(tree[2 * i + 1], tree[2 * i + 2]);
+        }
+
+        let values = values
+            .into_iter()
+            .zip(tree_indexes)
+            .map(|(value, tree_index)| TreeValue { value, tree_index })
+            .collect();
+        Ok(Self { format: Self::FORMAT.to_string(), leaf_encoding, tree, values })
+    }
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> B256 {
+        self.tree[0]
+    }
+
+    /// Checks that the tree is well-formed and that its leaves match its values.
+    pub fn validate(&self) -> Result<()> {
+        ensure!(self.format == Self::FORMAT, "unsupported tree format `{}`", self.format);
+        ensure!(!self.tree.is_empty(), "empty tree");
+        let types = parse_types(&self.leaf_encoding)?;
+        for (index, value) in self.values.iter().enumerate() {
+            let strings = value.value.iter().map(value_to_string).collect::<Vec<_>>();
+            ensure!(
+                self.tree.get(value.tree_index) == Some(&leaf_hash(&types, &strings)?),
+                "leaf {index} doesn't match its value"
+            );
+        }
+        for i in 0..self.tree.len() / 2 {
+            ensure!(
+                self.tree[i] == hash_pair(self.tree[2 * i + 1], self.tree[2 * i + 2]),
+                "invalid node {i}"
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the index of the leaf with the given values.
+    pub fn leaf_index(&self, values: &[String]) -> Result<usize> {
+        let leaf = leaf_hash(&parse_types(&self.leaf_encoding)?, values)?;
+        self.values
+            .iter()
+            .position(|value| self.tree[value.tree_index] == leaf)
+            .ok_or_else(|| eyre::eyre!("leaf not found in the tree"))
+    }
+
+    /// Returns the proof of the leaf at the given index.
+    pub fn proof(&self, index: usize) -> Result<Vec<B256>> {
+        let Some(value) = self.values.get(index) else {
+            bail!("index {index} is out of bounds, the tree has {} leaves", self.values.len());
+        };
+        let mut tree_index = value.tree_index;
+        let mut proof = Vec::new();
+        while tree_index > 0 {
+            let sibling = if tree_index % 2 == 1 { tree_index + 1 } else { tree_index - 1 };
+            proof.push(self.tree[sibling]);
+            tree_index = (tree_index - 1) / 2;
+        }
+        Ok(proof)
+    }
+}
+
+/// Returns the root computed from a leaf and its proof.
+pub fn process_proof(leaf: B256, proof: &[B256]) -> B256 {
+    proof.iter().fold(leaf, |hash, node| hash_pair(hash, *node))
+}
+
+/// Returns the hash of a leaf: `keccak256(bytes.concat(keccak256(abi.encode(values))))`.
+pub fn leaf_hash(types: &[DynSolType], values: &[String]) -> Result<B256> {
+    ensure!(types.len() == values.len(), "expected {} values, got {}", types.len(), values.len());
+    let values = types
+        .iter()
+        .zip(values)
+        .map(|(ty, value)| {
+            ty.coerce_str(value).wrap_err_with(|| format!("invalid {ty} value `{value}`"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let encoded = DynSolValue::Tuple(values).abi_encode_params();
+    Ok(keccak256(keccak256(encoded)))
+}
+
+fn hash_pair(a: B256, b: B256) -> B256 {
+    let (a, b) = if a < b { (a, b) } else { (b, a) };
+    keccak256([a.as_slice(), b.as_slice()].concat())
+}
+
+fn parse_types(types: &[String]) -> Result<Vec<DynSolType>> {
+    ensure!(!types.is_empty(), "the leaf types are required, use --types");
+    types.iter().map(|ty| Ok(DynSolType::parse(ty.trim())?)).collect()
+}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Reads the values of the leaves from a JSON or CSV file.
+fn read_leaves(path: &Path) -> Result<Vec<Vec<Value>>> {
+    if path.extension().is_some_and(|ext| ext == "json") {
+        return fs::read_json_file(path).wrap_err("expected an array of arrays of values");
+    }
+
+    let content = fs::read_to_string(path)?;
+    let mut lines = content.lines().map(str::trim).filter(|line| !line.is_empty()).peekable();
+    // Skip the header, whose first column can't be a value.
+    if lines.peek().is_some_and(|line| {
+        line.split(',').next().is_some_and(|first| {
+            let first = first.trim().trim_matches('"');
+            !first.starts_with("0x") && !first.starts_with(|c: char| c.is_ascii_digit())
+        })
+    }) {
+        lines.next();
+    }
+    Ok(lines
+        .map(|line| {
+            line.split(',')
+                .map(|value| Value::String(value.trim().trim_matches('"').to_string()))
+                .collect()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // <https://github.com/OpenZeppelin/merkle-tree#building-a-tree>
+    #[test]
+    fn builds_openzeppelin_tree() {
+        let values = vec![
+            vec![
+                Value::from("0x1111111111111111111111111111111111111111"),
+                Value::from("5000000000000000000"),
+            ],
+            vec![
+                Value::from("0x2222222222222222222222222222222222222222"),
+                Value::from("2500000000000000000"),
+            ],
+        ];
+        let tree =
+            StandardMerkleTree::build(vec!["address".into(), "uint256".into()], values).unwrap();
+        assert_eq!(
+            tree.root().to_string(),
+            "0xd4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77"
+        );
+        tree.validate().unwrap();
+
+        let index = tree
+            .leaf_index(&[
+                "0x2222222222222222222222222222222222222222".into(),
+                "2500000000000000000".into(),
+            ])
+            .unwrap();
+        assert_eq!(index, 1);
+        let proof = tree.proof(index).unwrap();
+        assert_eq!(process_proof(tree.tree[tree.values[1].tree_index], &proof), tree.root());
+    }
+}
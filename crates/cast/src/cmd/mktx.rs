@@ -1,10 +1,14 @@
-use crate::tx::{self, CastTxBuilder};
+use crate::{
+    bundle::TxBundle,
+    tx::{self, CastTxBuilder},
+};
 use alloy_consensus::{SignableTransaction, Signed};
 use alloy_eips::Encodable2718;
 use alloy_ens::NameOrAddress;
 use alloy_network::{Ethereum, EthereumWallet, Network, NetworkTransactionBuilder};
-use alloy_primitives::{Address, hex};
+use alloy_primitives::{Address, U64, hex};
 use alloy_provider::Provider;
+use alloy_rpc_types::TransactionRequest;
 use alloy_signer::{Signature, Signer};
 use clap::Parser;
 use eyre::Result;
@@ -13,7 +17,10 @@
     utils::LoadConfig,
 };
 use foundry_common::{FoundryTransactionBuilder, provider::ProviderBuilder};
-use std::{path::PathBuf, str::FromStr};
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 use tempo_alloy::TempoNetwork;
 
 /// CLI arguments for `cast mktx`.
@@ -60,6 +67,20 @@ pub struct MakeTxArgs {
     /// Call `eth_signTransaction` using the `--from` argument or $ETH_FROM as sender
     #[arg(long, requires = "from", conflicts_with = "raw_unsigned")]
     ethsign: bool,
+
+    /// Add the unsigned transaction to a bundle file, to be signed offline with `--offline`.
+    ///
+    /// The chain id, nonce and fees of the transaction are pinned in the bundle. The nonce
+    /// follows the previous transactions of the sender in the bundle, if any.
+    #[arg(long, value_name = "PATH", requires = "from", conflicts_with_all = ["raw_unsigned", "ethsign"])]
+    bundle: Option<PathBuf>,
+
+    /// Sign the transactions of the bundle without connecting to a node, and write them back to
+    /// the bundle.
+    ///
+    /// The signed bundle is broadcast with `cast publish --bundle`.
+    #[arg(long, requires = "bundle")]
+    offline: bool,
 }
 
 #[derive(Debug, Parser)]
@@ -81,6 +102,15 @@ pub enum MakeTxSubcommands {
 
 impl MakeTxArgs {
     pub async fn run(self) -> Result<()> {
+        if let Some(bundle) = &self.bundle {
+            if self.tx.tempo.is_tempo() {
+                eyre::bail!("bundles only support Ethereum transactions");
+            }
+            if self.offline {
+                return sign_bundle(bundle, &self.eth).await;
+            }
+        }
+
         if self.tx.tempo.is_tempo() {
             self.run_generic::<TempoNetwork>().await
         } else {
@@ -94,7 +124,19 @@ pub async fn run_generic<N: Network>(self) -> Result<()>
         N::UnsignedTx: SignableTransaction<Signature>,
         N::TransactionRequest: FoundryTransactionBuilder<N>,
     {
-        let Self { to, mut sig, mut args, command, tx, path, eth, raw_unsigned, ethsign } = self;
+        let Self {
+            to,
+            mut sig,
+            mut args,
+            command,
+            mut tx,
+            path,
+            eth,
+            raw_unsigned,
+            ethsign,
+            bundle,
+            offline: _,
+        } = self;
 
         let print_sponsor_hash = tx.tempo.print_sponsor_hash;
 
@@ -117,6 +159,24 @@ pub async fn run_generic<N: Network>(self) -> Result<()>
 
         let provider = ProviderBuilder::<N>::from_config(&config)?.build()?;
 
+        // Add to an existing bundle, following the nonces of its transactions.
+        let bundle = match bundle {
+            Some(path) => {
+                let bundle = if path.exists() {
+                    TxBundle::read(&path)?
+                } else {
+                    TxBundle::new(provider.get_chain_id().await?)
+                };
+                if tx.nonce.is_none()
+                    && let Some(nonce) = bundle.next_nonce(config.sender)
+                {
+                    tx.nonce = Some(U64::from(nonce));
+                }
+                Some((path, bundle))
+            }
+            None => None,
+        };
+
         let tx_builder = CastTxBuilder::new(&provider, tx.clone(), &config)
             .await?
             .with_to(to)
@@ -159,6 +219,22 @@ pub async fn run_generic<N: Network>(self) -> Result<()>
             return Ok(());
         }
 
+        if let Some((path, mut bundle)) = bundle {
+            let (tx, _) = tx_builder.build(config.sender).await?;
+            // Bundles only hold Ethereum transactions, see `run`.
+            let tx: TransactionRequest = serde_json::from_value(serde_json::to_value(tx)?)?;
+            bundle.push(tx)?;
+            bundle.write(&path)?;
+
+            sh_println!(
+                "Added transaction {} to {}, {} unsigned",
+                bundle.transactions.last().expect("just pushed").signing_hash,
+                path.display(),
+                bundle.transactions.iter().filter(|tx| tx.signed.is_none()).count()
+            )?;
+            return Ok(());
+        }
+
         if ethsign {
             // Use "eth_signTransaction" to sign the transaction only works if the node/RPC has
             // unlocked accounts.
@@ -186,3 +262,20 @@ pub async fn run_generic<N: Network>(self) -> Result<()>
         Ok(())
     }
 }
+
+/// Signs the transactions of a bundle with the wallet, without connecting to a node.
+async fn sign_bundle(path: &Path, eth: &EthereumOpts) -> Result<()> {
+    let mut bundle = TxBundle::read(path)?;
+    let signer = eth.wallet.signer().await?;
+    let from = signer.address();
+    tx::validate_from_address(eth.wallet.from, from)?;
+
+    let signed = bundle.sign(signer).await?;
+    if signed == 0 {
+        eyre::bail!("no unsigned transaction of {from} in {}", path.display());
+    }
+    bundle.write(path)?;
+
+    sh_println!("Signed {signed} transactions of {from} on chain {}", bundle.chain_id)?;
+    Ok(())
+}
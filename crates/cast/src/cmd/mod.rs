@@ -8,10 +8,13 @@
 pub mod access_list;
 pub mod artifact;
 pub mod b2e_payload;
+pub mod balance_snapshot;
 pub mod batch_mktx;
 pub mod batch_send;
 pub mod bind;
 pub mod call;
+pub mod chain;
+pub mod compare_rpc;
 pub mod constructor_args;
 pub mod create2;
 pub mod creation_code;
@@ -19,16 +22,22 @@
 pub mod erc20;
 pub mod estimate;
 pub mod find_block;
+pub mod gas;
+pub mod gov;
 pub mod interface;
 pub mod keychain;
 pub mod logs;
+pub mod merkle;
 pub(crate) mod miner;
 pub mod mktx;
+pub mod permit;
 pub mod rpc;
 pub mod run;
 pub mod send;
+pub mod source;
 pub mod storage;
 pub mod tip20;
+pub mod token;
 pub mod trace;
 pub mod txpool;
 pub mod wallet;
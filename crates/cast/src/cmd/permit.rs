@@ -0,0 +1,274 @@
+use crate::{
+    cmd::{erc20::build_provider_with_signer, send::cast_send},
+    tx::SendTxOpts,
+};
+use alloy_ens::NameOrAddress;
+use alloy_network::{AnyNetwork, Ethereum};
+use alloy_primitives::{
+    Address, B256, Bytes, U256,
+    aliases::{U48, U160},
+    hex,
+};
+use alloy_provider::{Provider, RootProvider};
+use alloy_signer::{Signature, Signer};
+use alloy_sol_types::{Eip712Domain, SolStruct, sol};
+use clap::Parser;
+use eyre::{Result, WrapErr, bail};
+use foundry_cli::utils::{LoadConfig, get_provider};
+use foundry_common::{addresses::PERMIT2, shell};
+use serde_json::json;
+use std::{
+    borrow::Cow,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+sol! {
+    /// The EIP-2612 permit.
+    struct Permit {
+        address owner;
+        address spender;
+        uint256 value;
+        uint256 nonce;
+        uint256 deadline;
+    }
+
+    /// The allowance granted by a Permit2 `PermitSingle`.
+    struct PermitDetails {
+        address token;
+        uint160 amount;
+        uint48 expiration;
+        uint48 nonce;
+    }
+
+    /// The Permit2 permit of a single token.
+    struct PermitSingle {
+        PermitDetails details;
+        address spender;
+        uint256 sigDeadline;
+    }
+
+    #[sol(rpc)]
+    interface IERC2612 {
+        function name() external view returns (string);
+        function version() external view returns (string);
+        function nonces(address owner) external view returns (uint256);
+        function DOMAIN_SEPARATOR() external view returns (bytes32);
+        function eip712Domain() external view returns (bytes1 fields, string name, string version, uint256 chainId, address verifyingContract, bytes32 salt, uint256[] extensions);
+        function permit(address owner, address spender, uint256 value, uint256 deadline, uint8 v, bytes32 r, bytes32 s) external;
+    }
+
+    #[sol(rpc)]
+    interface IPermit2 {
+        function DOMAIN_SEPARATOR() external view returns (bytes32);
+        function allowance(address user, address token, address spender) external view returns (uint160 amount, uint48 expiration, uint48 nonce);
+        function permit(address owner, PermitSingle permitSingle, bytes signature) external;
+    }
+}
+
+/// CLI arguments for `cast permit`.
+#[derive(Debug, Parser)]
+pub struct PermitArgs {
+    /// The token contract address.
+    #[arg(value_parser = NameOrAddress::from_str)]
+    token: NameOrAddress,
+
+    /// The spender address.
+    #[arg(value_parser = NameOrAddress::from_str)]
+    spender: NameOrAddress,
+
+    /// The amount to permit, in the smallest unit of the token.
+    amount: U256,
+
+    /// Sign a Permit2 `PermitSingle` instead of an EIP-2612 permit.
+    #[arg(long)]
+    permit2: bool,
+
+    /// The timestamp until which the signature is valid. Defaults to one hour from now.
+    #[arg(long, value_name = "TIMESTAMP")]
+    deadline: Option<u64>,
+
+    /// The timestamp at which the Permit2 allowance expires. Defaults to the deadline.
+    #[arg(long, value_name = "TIMESTAMP", requires = "permit2")]
+    expiration: Option<u64>,
+
+    /// The nonce of the permit. Defaults to the next nonce of the owner.
+    #[arg(long)]
+    nonce: Option<U256>,
+
+    /// Submit the `permit` transaction after signing it.
+    #[arg(long)]
+    send: bool,
+
+    #[command(flatten)]
+    send_tx: SendTxOpts,
+}
+
+impl PermitArgs {
+    pub async fn run(self) -> Result<()> {
+        let Self { token, spender, amount, permit2, deadline, expiration, nonce, send, send_tx } =
+            self;
+        let config = send_tx.eth.load_config()?;
+        let provider = get_provider(&config)?;
+        let token = token.resolve(&provider).await?;
+        let spender = spender.resolve(&provider).await?;
+        let chain_id = provider.get_chain_id().await?;
+
+        let signer = send_tx.eth.wallet.signer().await?;
+        let owner = signer.address();
+        let deadline = match deadline {
+            Some(deadline) => deadline,
+            None => SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() + 60 * 60,
+        };
+
+        let (signing_hash, nonce, permit_single) = if permit2 {
+            let permit2 = IPermit2::new(PERMIT2, &provider);
+            let nonce = match nonce {
+                Some(nonce) => nonce,
+                None => U256::from(permit2.allowance(owner, token, spender).call().await?.nonce),
+            };
+            if amount > U256::from(U160::MAX) {
+                bail!("Permit2 amounts are at most 160 bits");
+            }
+            if nonce > U256::from(U48::MAX) {
+                bail!("Permit2 nonces are at most 48 bits");
+            }
+            let permit = PermitSingle {
+                details: PermitDetails {
+                    token,
+                    amount: amount.to(),
+                    expiration: U48::from(expiration.unwrap_or(deadline)),
+                    nonce: nonce.to(),
+                },
+                spender,
+                sigDeadline: U256::from(deadline),
+            };
+            let domain = Eip712Domain::new(
+                Some(Cow::Borrowed("Permit2")),
+                None,
+                Some(U256::from(chain_id)),
+                Some(PERMIT2),
+                None,
+            );
+            check_domain_separator(&domain, permit2.DOMAIN_SEPARATOR().call().await.ok())?;
+            (permit.eip712_signing_hash(&domain), nonce, Some(permit))
+        } else {
+            let erc2612 = IERC2612::new(token, &provider);
+            let nonce = match nonce {
+                Some(nonce) => nonce,
+                None => erc2612.nonces(owner).call().await.wrap_err_with(|| {
+                    format!("{token} doesn't support EIP-2612 permits, use --permit2")
+                })?,
+            };
+            let permit =
+                Permit { owner, spender, value: amount, nonce, deadline: U256::from(deadline) };
+            let domain = token_domain(&provider, token, chain_id).await?;
+            (permit.eip712_signing_hash(&domain), nonce, None)
+        };
+
+        let signature = signer.sign_hash(&signing_hash).await?;
+        print_signature(&signature, owner, nonce, deadline)?;
+
+        if !send {
+            return Ok(());
+        }
+
+        let timeout = send_tx.timeout.unwrap_or(config.transaction_timeout);
+        let provider = build_provider_with_signer::<Ethereum>(&send_tx, signer)?;
+        let tx = match permit_single {
+            Some(permit_single) => IPermit2::new(PERMIT2, &provider)
+                .permit(owner, permit_single, Bytes::from(signature.as_bytes()))
+                .into_transaction_request(),
+            None => IERC2612::new(token, &provider)
+                .permit(
+                    owner,
+                    spender,
+                    amount,
+                    U256::from(deadline),
+                    27 + signature.v() as u8,
+                    B256::from(signature.r()),
+                    B256::from(signature.s()),
+                )
+                .into_transaction_request(),
+        };
+        cast_send(provider, tx, send_tx.cast_async, send_tx.sync, send_tx.confirmations, timeout)
+            .await
+    }
+}
+
+/// Discovers the EIP-712 domain of a token, using EIP-5267 if supported, and checks it against
+/// the domain separator of the token.
+async fn token_domain(
+    provider: &RootProvider<AnyNetwork>,
+    token: Address,
+    chain_id: u64,
+) -> Result<Eip712Domain> {
+    let erc2612 = IERC2612::new(token, provider);
+    let domain = if let Ok(domain) = erc2612.eip712Domain().call().await {
+        let fields = domain.fields[0];
+        let field = |bit: u8| fields & (1 << bit) != 0;
+        Eip712Domain::new(
+            field(0).then_some(Cow::Owned(domain.name)),
+            field(1).then_some(Cow::Owned(domain.version)),
+            field(2).then_some(domain.chainId),
+            field(3).then_some(domain.verifyingContract),
+            field(4).then_some(domain.salt),
+        )
+    } else {
+        let name = erc2612.name().call().await?;
+        // Most tokens predating EIP-5267 use version "1" without exposing it.
+        let version = erc2612.version().call().await.unwrap_or_else(|_| "1".to_string());
+        Eip712Domain::new(
+            Some(Cow::Owned(name)),
+            Some(Cow::Owned(version)),
+            Some(U256::from(chain_id)),
+            Some(token),
+            None,
+        )
+    };
+    check_domain_separator(&domain, erc2612.DOMAIN_SEPARATOR().call().await.ok())?;
+    Ok(domain)
+}
+
+fn check_domain_separator(domain: &Eip712Domain, expected: Option<B256>) -> Result<()> {
+    if let Some(expected) = expected
+        && expected != domain.separator()
+    {
+        bail!(
+            "the discovered EIP-712 domain doesn't match the domain separator {expected} of the contract"
+        );
+    }
+    Ok(())
+}
+
+fn print_signature(
+    signature: &Signature,
+    owner: Address,
+    nonce: U256,
+    deadline: u64,
+) -> Result<()> {
+    let v = 27 + signature.v() as u8;
+    let r = B256::from(signature.r());
+    let s = B256::from(signature.s());
+    if shell::is_json() {
+        let output = json!({
+            "owner": owner,
+            "nonce": nonce.to_string(),
+            "deadline": deadline,
+            "signature": hex::encode_prefixed(signature.as_bytes()),
+            "v": v,
+            "r": r,
+            "s": s,
+        });
+        sh_println!("{}", serde_json::to_string_pretty(&output)?)?;
+    } else {
+        sh_println!("{:<11}{owner}", "owner")?;
+        sh_println!("{:<11}{nonce}", "nonce")?;
+        sh_println!("{:<11}{deadline}", "deadline")?;
+        sh_println!("{:<11}{}", "signature", hex::encode_prefixed(signature.as_bytes()))?;
+        sh_println!("{:<11}{v}", "v")?;
+        sh_println!("{:<11}{r}", "r")?;
+        sh_println!("{:<11}{s}", "s")?;
+    }
+    Ok(())
+}
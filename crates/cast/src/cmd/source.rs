@@ -0,0 +1,298 @@
+use crate::explorer_client;
+use alloy_primitives::Address;
+use clap::{Parser, ValueHint};
+use eyre::{OptionExt, Result};
+use foundry_block_explorers::{
+    Client,
+    contract::{ContractMetadata, Metadata, SourceCodeEntry, SourceCodeMetadata},
+    errors::EtherscanError,
+};
+use foundry_cli::{opts::EtherscanOpts, utils::LoadConfig};
+use foundry_common::{compile::etherscan_project, etherscan::EtherscanThrottle, flatten, fs};
+use foundry_config::{Chain, EtherscanRequestLimits, NamedChain};
+use serde::Deserialize;
+use std::{collections::HashMap, path::PathBuf};
+
+/// The default Sourcify API URL used as fallback source.
+const SOURCIFY_URL: &str = "https://sourcify.dev/server";
+
+/// CLI arguments for `cast source`.
+#[derive(Debug, Parser)]
+pub struct SourceArgs {
+    /// The contract's address.
+    pub address: Address,
+
+    /// Whether to flatten the source code.
+    #[arg(long, short)]
+    pub flatten: bool,
+
+    /// The output directory/file to expand source tree into.
+    ///
+    /// The original multi-file layout is preserved. When flattening into an existing directory,
+    /// the flattened source is written to `<PATH>/<CONTRACT_NAME>.sol`.
+    #[arg(
+        short = 'd',
+        long = "output",
+        value_hint = ValueHint::AnyPath,
+        alias = "path",
+        value_name = "PATH"
+    )]
+    pub directory: Option<PathBuf>,
+
+    /// Print the compiler version and settings the contract was verified with.
+    #[arg(long)]
+    pub settings: bool,
+
+    /// Do not fall back to Blockscout or Sourcify if the source can't be fetched from the
+    /// explorer.
+    #[arg(long)]
+    pub no_fallback: bool,
+
+    /// Custom Sourcify API URL used as fallback.
+    #[arg(long, value_name = "URL", env = "SOURCIFY_URL")]
+    pub sourcify_url: Option<String>,
+
+    #[command(flatten)]
+    pub etherscan: EtherscanOpts,
+
+    /// Alternative explorer API URL to use that adheres to the Etherscan API (e.g. Blockscout).
+    /// If not provided, defaults to Etherscan.
+    #[arg(long, env = "EXPLORER_API_URL")]
+    pub explorer_api_url: Option<String>,
+
+    /// Alternative explorer browser URL.
+    #[arg(long, env = "EXPLORER_URL")]
+    pub explorer_url: Option<String>,
+}
+
+/// The verified source of a contract.
+struct FetchedSource {
+    metadata: Metadata,
+    /// The standard JSON compiler settings, if known.
+    settings: Option<serde_json::Value>,
+}
+
+impl SourceArgs {
+    pub async fn run(self) -> Result<()> {
+        let config = self.etherscan.load_config()?;
+        let chain = config.chain.unwrap_or_default();
+        let api_key = config.get_etherscan_api_key(Some(chain));
+        let limits = config
+            .get_etherscan_config_with_chain(Some(chain))
+            .ok()
+            .flatten()
+            .map(|config| config.limits)
+            .unwrap_or_default();
+
+        let FetchedSource { metadata, settings } = self.fetch(chain, api_key, limits).await?;
+
+        if self.settings {
+            sh_println!("Compiler version: {}", metadata.compiler_version)?;
+            if let Some(settings) = &settings {
+                sh_println!("Settings:\n{}", serde_json::to_string_pretty(settings)?)?;
+            }
+        }
+
+        match (self.directory, self.flatten) {
+            (Some(dir), false) => {
+                metadata.source_tree().write_to(&dir)?;
+                sh_println!("Source tree written at {}", dir.display())?;
+            }
+            (None, false) => {
+                // With `--settings` only the settings are printed unless an output is given.
+                if !self.settings {
+                    let source = ContractMetadata { items: vec![metadata] }.source_code();
+                    sh_println!("{source}")?;
+                }
+            }
+            (dir, true) => {
+                let tmp = tempfile::tempdir()?;
+                let project = etherscan_project(&metadata, tmp.path())?;
+                let target_path = project.find_contract_path(&metadata.contract_name)?;
+                let flattened = flatten(project, &target_path)?;
+
+                if let Some(mut path) = dir {
+                    if path.is_dir() {
+                        path.push(format!("{}.sol", metadata.contract_name));
+                    } else if let Some(parent) = path.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+                    fs::write(&path, flattened)?;
+                    sh_println!("Flattened file written at {}", path.display())?
+                } else {
+                    sh_println!("{flattened}")?
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fetches the verified source, trying the providers in the same order as verification:
+    /// the configured explorer (Etherscan, Blockscout or any Etherscan compatible API) first,
+    /// then the Blockscout instance of the chain, if known, and finally Sourcify.
+    async fn fetch(
+        &self,
+        chain: Chain,
+        api_key: Option<String>,
+        limits: EtherscanRequestLimits,
+    ) -> Result<FetchedSource> {
+        let has_explorer = api_key.is_some() || self.explorer_api_url.is_some();
+        if has_explorer || self.no_fallback {
+            let client = explorer_client(
+                chain,
+                api_key,
+                self.explorer_api_url.clone(),
+                self.explorer_url.clone(),
+            )?;
+            match self.fetch_explorer(&client, EtherscanThrottle::new(chain, limits)).await {
+                Ok(source) => return Ok(source),
+                Err(err) if self.no_fallback => return Err(err),
+                Err(err) => {
+                    sh_warn!("Failed to fetch source from the explorer, trying fallbacks: {err}")?;
+                }
+            }
+        }
+
+        // Skip Blockscout if it is the configured explorer, which already failed.
+        if let Some(url) = blockscout_url(chain)
+            && self.explorer_api_url.as_deref().is_none_or(|api_url| !api_url.starts_with(url))
+        {
+            let client =
+                explorer_client(chain, None, Some(format!("{url}/api")), Some(url.to_string()))?;
+            // Blockscout has its own rate limits, so it doesn't share the chain's request budget.
+            match self
+                .fetch_explorer(&client, EtherscanThrottle::unshared(Default::default()))
+                .await
+            {
+                Ok(source) => return Ok(source),
+                Err(err) => {
+                    sh_warn!("Failed to fetch source from Blockscout, trying Sourcify: {err}")?;
+                }
+            }
+        }
+
+        self.fetch_sourcify(chain).await
+    }
+
+    /// Fetches the verified source from an Etherscan compatible explorer.
+    async fn fetch_explorer(
+        &self,
+        client: &Client,
+        throttle: EtherscanThrottle,
+    ) -> Result<FetchedSource> {
+        let mut meta = throttle.run(|| client.contract_source_code(self.address)).await?;
+        eyre::ensure!(!meta.items.is_empty(), "Empty contract source code");
+        let metadata = meta.items.remove(0);
+        let settings = metadata.settings().ok().map(serde_json::to_value).transpose()?;
+        Ok(FetchedSource { metadata, settings })
+    }
+
+    /// Fetches the verified source from Sourcify.
+    async fn fetch_sourcify(&self, chain: Chain) -> Result<FetchedSource> {
+        let url = format!(
+            "{}/v2/contract/{}/{}?fields=sources,compilation",
+            self.sourcify_url.as_deref().unwrap_or(SOURCIFY_URL).trim_end_matches('/'),
+            chain.id(),
+            self.address
+        );
+        let response = reqwest::Client::new().get(&url).send().await?;
+        match response.status().as_u16() {
+            404 => return Err(EtherscanError::ContractCodeNotVerified(self.address).into()),
+            429 => return Err(EtherscanError::RateLimitExceeded.into()),
+            _ => {}
+        }
+        let SourcifyContract { sources, compilation } = response.error_for_status()?.json().await?;
+        let compilation = compilation.ok_or_eyre("Sourcify response missing compilation")?;
+
+        let metadata = Metadata {
+            source_code: SourceCodeMetadata::Sources(
+                sources
+                    .into_iter()
+                    .map(|(path, file)| (path, SourceCodeEntry { content: file.content }))
+                    .collect(),
+            ),
+            abi: String::new(),
+            contract_name: compilation.name,
+            compiler_version: compilation.compiler_version,
+            optimization_used: 0,
+            runs: 0,
+            constructor_arguments: Default::default(),
+            evm_version: String::new(),
+            library: String::new(),
+            license_type: String::new(),
+            proxy: 0,
+            implementation: None,
+            swarm_source: String::new(),
+        };
+        Ok(FetchedSource { metadata, settings: compilation.compiler_settings })
+    }
+}
+
+/// Returns the URL of the public Blockscout instance of the chain, if any.
+fn blockscout_url(chain: Chain) -> Option<&'static str> {
+    Some(match chain.named()? {
+        NamedChain::Mainnet => "https://eth.blockscout.com",
+        NamedChain::Sepolia => "https://eth-sepolia.blockscout.com",
+        NamedChain::Holesky => "https://eth-holesky.blockscout.com",
+        NamedChain::Optimism => "https://optimism.blockscout.com",
+        NamedChain::OptimismSepolia => "https://optimism-sepolia.blockscout.com",
+        NamedChain::Base => "https://base.blockscout.com",
+        NamedChain::BaseSepolia => "https://base-sepolia.blockscout.com",
+        NamedChain::Arbitrum => "https://arbitrum.blockscout.com",
+        NamedChain::Gnosis => "https://gnosis.blockscout.com",
+        NamedChain::Polygon => "https://polygon.blockscout.com",
+        _ => return None,
+    })
+}
+
+/// Sourcify API response for `/v2/contract/{chainId}/{address}`.
+#[derive(Deserialize)]
+struct SourcifyContract {
+    #[serde(default)]
+    sources: HashMap<String, SourcifySource>,
+    #[serde(default)]
+    compilation: Option<SourcifyCompilation>,
+}
+
+#[derive(Deserialize)]
+struct SourcifySource {
+    #[serde(default)]
+    content: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SourcifyCompilation {
+    #[serde(default)]
+    compiler_version: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    compiler_settings: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_output_aliases() {
+        let address = "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2";
+        let args = SourceArgs::parse_from(["source", address, "--output", "out"]);
+        assert_eq!(args.directory, Some(PathBuf::from("out")));
+
+        let args = SourceArgs::parse_from(["source", address, "-d", "out", "--flatten"]);
+        assert_eq!(args.directory, Some(PathBuf::from("out")));
+        assert!(args.flatten);
+
+        let args = SourceArgs::parse_from(["source", address, "--path", "out"]);
+        assert_eq!(args.directory, Some(PathBuf::from("out")));
+    }
+
+    #[test]
+    fn blockscout_fallback_urls() {
+        assert_eq!(blockscout_url(NamedChain::Mainnet.into()), Some("https://eth.blockscout.com"));
+        assert_eq!(blockscout_url(Chain::from_id(999999)), None);
+    }
+}
@@ -0,0 +1,407 @@
+use crate::cmd::erc20::IERC20;
+use alloy_eips::BlockId;
+use alloy_ens::NameOrAddress;
+use alloy_network::AnyNetwork;
+use alloy_primitives::{
+    Address, Bytes, FixedBytes, U256,
+    utils::{format_units, parse_units},
+};
+use alloy_provider::{Provider, RootProvider};
+use alloy_sol_types::{SolCall, sol};
+use clap::Parser;
+use eyre::{Result, bail};
+use foundry_cli::{
+    opts::RpcOpts,
+    utils::{LoadConfig, get_provider},
+};
+use foundry_common::shell;
+use serde_json::json;
+use std::{fmt, str::FromStr};
+
+sol! {
+    #[sol(rpc)]
+    interface IERC165 {
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+    }
+
+    #[sol(rpc)]
+    interface IERC721 {
+        function name() external view returns (string);
+        function symbol() external view returns (string);
+        function totalSupply() external view returns (uint256);
+        function balanceOf(address owner) external view returns (uint256);
+        function isApprovedForAll(address owner, address operator) external view returns (bool);
+        function safeTransferFrom(address from, address to, uint256 tokenId) external;
+    }
+
+    #[sol(rpc)]
+    interface IERC1155 {
+        function balanceOf(address account, uint256 id) external view returns (uint256);
+        function isApprovedForAll(address account, address operator) external view returns (bool);
+        function safeTransferFrom(address from, address to, uint256 id, uint256 value, bytes data) external;
+    }
+}
+
+/// The ERC-165 interface id of ERC-721.
+const ERC721_INTERFACE_ID: FixedBytes<4> = FixedBytes([0x80, 0xac, 0x58, 0xcd]);
+/// The ERC-165 interface id of ERC-1155.
+const ERC1155_INTERFACE_ID: FixedBytes<4> = FixedBytes([0xd9, 0xb6, 0x7a, 0x26]);
+
+/// Query tokens of any standard, detecting whether they are ERC-20, ERC-721 or ERC-1155.
+#[derive(Debug, Parser, Clone)]
+pub enum TokenSubcommand {
+    /// Print the standard, name, symbol, decimals and total supply of a token.
+    #[command(visible_alias = "i")]
+    Info {
+        /// The token contract address.
+        #[arg(value_parser = NameOrAddress::from_str)]
+        token: NameOrAddress,
+
+        /// The block height to query at.
+        #[arg(long, short = 'B')]
+        block: Option<BlockId>,
+
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+
+    /// Print the balance of an account, in token units.
+    #[command(visible_alias = "b")]
+    Balance {
+        /// The token contract address.
+        #[arg(value_parser = NameOrAddress::from_str)]
+        token: NameOrAddress,
+
+        /// The owner to query the balance of.
+        #[arg(value_parser = NameOrAddress::from_str)]
+        owner: NameOrAddress,
+
+        /// The token id, for ERC-1155 tokens.
+        #[arg(long)]
+        id: Option<U256>,
+
+        /// The block height to query at.
+        #[arg(long, short = 'B')]
+        block: Option<BlockId>,
+
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+
+    /// Print the allowance of a spender, or whether an operator is approved for all the tokens
+    /// of ERC-721 and ERC-1155 tokens.
+    #[command(visible_alias = "al")]
+    Allowance {
+        /// The token contract address.
+        #[arg(value_parser = NameOrAddress::from_str)]
+        token: NameOrAddress,
+
+        /// The owner address.
+        #[arg(value_parser = NameOrAddress::from_str)]
+        owner: NameOrAddress,
+
+        /// The spender or operator address.
+        #[arg(value_parser = NameOrAddress::from_str)]
+        spender: NameOrAddress,
+
+        /// The block height to query at.
+        #[arg(long, short = 'B')]
+        block: Option<BlockId>,
+
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+
+    /// Print the calldata transferring tokens, to be sent to the token contract.
+    #[command(visible_alias = "tc")]
+    TransferCalldata {
+        /// The token contract address.
+        #[arg(value_parser = NameOrAddress::from_str)]
+        token: NameOrAddress,
+
+        /// The recipient address.
+        #[arg(value_parser = NameOrAddress::from_str)]
+        to: NameOrAddress,
+
+        /// The amount to transfer, in token units (e.g. `1.5`), or the token id for ERC-721
+        /// tokens.
+        amount: String,
+
+        /// The owner of the transferred tokens, for ERC-721 and ERC-1155 tokens.
+        #[arg(long, value_parser = NameOrAddress::from_str)]
+        from: Option<NameOrAddress>,
+
+        /// The token id, for ERC-1155 tokens.
+        #[arg(long)]
+        id: Option<U256>,
+
+        /// Interpret the amount in the smallest unit of the token instead.
+        #[arg(long)]
+        raw: bool,
+
+        #[command(flatten)]
+        rpc: RpcOpts,
+    },
+}
+
+/// The standard implemented by a token contract.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenStandard {
+    Erc20,
+    Erc721,
+    Erc1155,
+}
+
+impl fmt::Display for TokenStandard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Erc20 => "ERC-20",
+            Self::Erc721 => "ERC-721",
+            Self::Erc1155 => "ERC-1155",
+        })
+    }
+}
+
+impl TokenStandard {
+    /// Detects the standard of a token, using ERC-165 for ERC-721 and ERC-1155 tokens.
+    pub async fn detect(
+        provider: &RootProvider<AnyNetwork>,
+        token: Address,
+        block: BlockId,
+    ) -> Result<Self> {
+        if provider.get_code_at(token).block_id(block).await?.is_empty() {
+            bail!("no contract deployed at {token}");
+        }
+
+        let erc165 = IERC165::new(token, provider);
+        for (interface_id, standard) in
+            [(ERC1155_INTERFACE_ID, Self::Erc1155), (ERC721_INTERFACE_ID, Self::Erc721)]
+        {
+            if erc165.supportsInterface(interface_id).block(block).call().await.unwrap_or(false) {
+                return Ok(standard);
+            }
+        }
+
+        if IERC20::new(token, provider).totalSupply().block(block).call().await.is_ok() {
+            return Ok(Self::Erc20);
+        }
+        bail!("{token} is not an ERC-20, ERC-721 or ERC-1155 token")
+    }
+}
+
+impl TokenSubcommand {
+    const fn rpc_opts(&self) -> &RpcOpts {
+        match self {
+            Self::Info { rpc, .. }
+            | Self::Balance { rpc, .. }
+            | Self::Allowance { rpc, .. }
+            | Self::TransferCalldata { rpc, .. } => rpc,
+        }
+    }
+
+    pub async fn run(self) -> Result<()> {
+        let config = self.rpc_opts().load_config()?;
+        let provider = get_provider(&config)?;
+
+        match self {
+            Self::Info { token, block, .. } => {
+                let block = block.unwrap_or_default();
+                let token = token.resolve(&provider).await?;
+                let standard = TokenStandard::detect(&provider, token, block).await?;
+
+                let erc721 = IERC721::new(token, &provider);
+                let name = erc721.name().block(block).call().await.ok();
+                let symbol = erc721.symbol().block(block).call().await.ok();
+                let total_supply = erc721.totalSupply().block(block).call().await.ok();
+                let decimals = match standard {
+                    TokenStandard::Erc20 => {
+                        IERC20::new(token, &provider).decimals().block(block).call().await.ok()
+                    }
+                    _ => None,
+                };
+
+                if shell::is_json() {
+                    let info = json!({
+                        "address": token,
+                        "standard": standard.to_string(),
+                        "name": name,
+                        "symbol": symbol,
+                        "decimals": decimals,
+                        "totalSupply": total_supply.map(|supply| supply.to_string()),
+                    });
+                    sh_println!("{}", serde_json::to_string_pretty(&info)?)?;
+                    return Ok(());
+                }
+
+                sh_println!("{:<13}{standard}", "standard")?;
+                if let Some(name) = name {
+                    sh_println!("{:<13}{name}", "name")?;
+                }
+                if let Some(symbol) = &symbol {
+                    sh_println!("{:<13}{symbol}", "symbol")?;
+                }
+                if let Some(decimals) = decimals {
+                    sh_println!("{:<13}{decimals}", "decimals")?;
+                }
+                if let Some(total_supply) = total_supply {
+                    let total_supply = format_amount(
+                        total_supply,
+                        decimals.unwrap_or_default(),
+                        symbol.as_deref(),
+                    );
+                    sh_println!("{:<13}{total_supply}", "totalSupply")?;
+                }
+            }
+            Self::Balance { token, owner, id, block, .. } => {
+                let block = block.unwrap_or_default();
+                let token = token.resolve(&provider).await?;
+                let owner = owner.resolve(&provider).await?;
+
+                let (balance, decimals, symbol) =
+                    match TokenStandard::detect(&provider, token, block).await? {
+                        TokenStandard::Erc20 => {
+                            let erc20 = IERC20::new(token, &provider);
+                            let balance = erc20.balanceOf(owner).block(block).call().await?;
+                            let decimals = erc20.decimals().block(block).call().await?;
+                            (balance, decimals, erc20.symbol().block(block).call().await.ok())
+                        }
+                        TokenStandard::Erc721 => {
+                            let erc721 = IERC721::new(token, &provider);
+                            let balance = erc721.balanceOf(owner).block(block).call().await?;
+                            (balance, 0, erc721.symbol().block(block).call().await.ok())
+                        }
+                        TokenStandard::Erc1155 => {
+                            let Some(id) = id else {
+                                bail!("the token id is required for ERC-1155 tokens, use --id");
+                            };
+                            let erc1155 = IERC1155::new(token, &provider);
+                            (erc1155.balanceOf(owner, id).block(block).call().await?, 0, None)
+                        }
+                    };
+
+                if shell::is_json() {
+                    sh_println!("{}", serde_json::to_string(&balance.to_string())?)?
+                } else {
+                    sh_println!("{}", format_amount(balance, decimals, symbol.as_deref()))?
+                }
+            }
+            Self::Allowance { token, owner, spender, block, .. } => {
+                let block = block.unwrap_or_default();
+                let token = token.resolve(&provider).await?;
+                let owner = owner.resolve(&provider).await?;
+                let spender = spender.resolve(&provider).await?;
+
+                match TokenStandard::detect(&provider, token, block).await? {
+                    TokenStandard::Erc20 => {
+                        let erc20 = IERC20::new(token, &provider);
+                        let allowance = erc20.allowance(owner, spender).block(block).call().await?;
+                        if shell::is_json() {
+                            sh_println!("{}", serde_json::to_string(&allowance.to_string())?)?
+                        } else if allowance == U256::MAX {
+                            sh_println!("unlimited")?
+                        } else {
+                            let decimals = erc20.decimals().block(block).call().await?;
+                            let symbol = erc20.symbol().block(block).call().await.ok();
+                            sh_println!(
+                                "{}",
+                                format_amount(allowance, decimals, symbol.as_deref())
+                            )?
+                        }
+                    }
+                    TokenStandard::Erc721 | TokenStandard::Erc1155 => {
+                        // Both standards share the same `isApprovedForAll` function.
+                        let approved = IERC721::new(token, &provider)
+                            .isApprovedForAll(owner, spender)
+                            .block(block)
+                            .call()
+                            .await?;
+                        if shell::is_json() {
+                            sh_println!("{}", serde_json::to_string(&approved)?)?
+                        } else {
+                            sh_println!("{approved}")?
+                        }
+                    }
+                }
+            }
+            Self::TransferCalldata { token, to, amount, from, id, raw, .. } => {
+                let block = BlockId::latest();
+                let token = token.resolve(&provider).await?;
+                let to = to.resolve(&provider).await?;
+                let from = match from {
+                    Some(from) => Some(from.resolve(&provider).await?),
+                    None => None,
+                };
+                let require_from =
+                    || from.ok_or_else(|| eyre::eyre!("the owner is required, use --from"));
+
+                let calldata = match TokenStandard::detect(&provider, token, block).await? {
+                    TokenStandard::Erc20 => {
+                        let decimals = if raw {
+                            0
+                        } else {
+                            IERC20::new(token, &provider).decimals().call().await?
+                        };
+                        let amount = parse_amount(&amount, decimals)?;
+                        IERC20::transferCall { to, amount }.abi_encode()
+                    }
+                    TokenStandard::Erc721 => {
+                        let token_id = U256::from_str(&amount)?;
+                        IERC721::safeTransferFromCall {
+                            from: require_from()?,
+                            to,
+                            tokenId: token_id,
+                        }
+                        .abi_encode()
+                    }
+                    TokenStandard::Erc1155 => {
+                        let Some(id) = id else {
+                            bail!("the token id is required for ERC-1155 tokens, use --id");
+                        };
+                        IERC1155::safeTransferFromCall {
+                            from: require_from()?,
+                            to,
+                            id,
+                            value: parse_amount(&amount, 0)?,
+                            data: Bytes::new(),
+                        }
+                        .abi_encode()
+                    }
+                };
+                sh_println!("{}", Bytes::from(calldata))?
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats an amount in token units, with the token symbol if known.
+fn format_amount(amount: U256, decimals: u8, symbol: Option<&str>) -> String {
+    let mut formatted = format_units(amount, decimals).unwrap_or_else(|_| amount.to_string());
+    if formatted.contains('.') {
+        formatted = formatted.trim_end_matches('0').trim_end_matches('.').to_string();
+    }
+    match symbol {
+        Some(symbol) if !symbol.is_empty() => format!("{formatted} {symbol}"),
+        _ => formatted,
+    }
+}
+
+/// Parses an amount in token units into the smallest unit of the token.
+fn parse_amount(amount: &str, decimals: u8) -> Result<U256> {
+    Ok(parse_units(amount, decimals)?.get_absolute())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_and_parses_amounts() {
+        let amount = U256::from(1_500_000u64);
+        assert_eq!(format_amount(amount, 6, Some("USDC")), "1.5 USDC");
+        assert_eq!(format_amount(U256::from(3), 0, None), "3");
+        assert_eq!(format_amount(U256::from(10).pow(U256::from(18)), 18, Some("")), "1");
+        assert_eq!(parse_amount("1.5", 6).unwrap(), amount);
+        assert_eq!(parse_amount("42", 0).unwrap(), U256::from(42));
+    }
+}
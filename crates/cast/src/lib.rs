@@ -8,10 +8,7 @@
 #[macro_use]
 extern crate tracing;
 
-use alloy_consensus::{
-    BlockHeader,
-    transaction::{Recovered, SignerRecoverable},
-};
+use alloy_consensus::{BlockHeader, transaction::SignerRecoverable};
 use alloy_dyn_abi::{DynSolType, DynSolValue, FunctionExt};
 use alloy_eips::Encodable2718;
 use alloy_ens::NameOrAddress;
@@ -21,7 +18,7 @@
     Address, B256, I256, Keccak256, LogData, Selector, TxHash, U64, U256, hex,
     utils::{ParseUnits, Unit, keccak256},
 };
-use alloy_provider::{PendingTransactionBuilder, Provider, network::eip2718::Decodable2718};
+use alloy_provider::{PendingTransactionBuilder, Provider};
 use alloy_rlp::{Decodable, Encodable};
 use alloy_rpc_types::{
     BlockId, BlockNumberOrTag, BlockOverrides, Filter, FilterBlockOption, Log, state::StateOverride,
@@ -31,11 +28,9 @@
 use eyre::{Context, ContextCompat, OptionExt, Result};
 use foundry_block_explorers::Client;
 use foundry_common::{
-    abi::{coerce_value, encode_function_args, encode_function_args_packed, get_event, get_func},
-    compile::etherscan_project,
-    flatten,
+    abi::{coerce_value, get_event, get_func},
     fmt::*,
-    fs, shell,
+    shell,
 };
 use foundry_config::Chain;
 use foundry_evm::core::bytecode::InstIter;
@@ -49,7 +44,6 @@
     fmt::Write,
     io,
     marker::PhantomData,
-    path::PathBuf,
     str::FromStr,
     sync::atomic::{AtomicBool, Ordering},
 };
@@ -62,6 +56,7 @@
 pub mod opts;
 
 pub mod base;
+pub mod bundle;
 pub mod call_spec;
 pub(crate) mod debug;
 pub mod errors;
@@ -487,7 +482,7 @@ pub async fn admin(&self, who: Address, block: Option<BlockId>) -> Result<String
     /// ```
     pub async fn compute_address(&self, address: Address, nonce: Option<u64>) -> Result<Address> {
         let unpacked = if let Some(n) = nonce { n } else { self.nonce(address, None).await? };
-        Ok(address.create(unpacked))
+        Ok(cast_core::address::compute_address(address, unpacked))
     }
 
     /// # Example
@@ -1482,15 +1477,7 @@ pub fn format_units(value: &str, unit: u8) -> Result<String> {
 
     // Helper function to format units as a string
     fn format_unit_as_string(value: U256, unit: Unit) -> String {
-        let mut formatted = ParseUnits::U256(value).format_units(unit);
-        // Trim empty fractional part.
-        if let Some(dot) = formatted.find('.') {
-            let fractional = &formatted[dot + 1..];
-            if fractional.chars().all(|c: char| c == '0') {
-                formatted = formatted[..dot].to_string();
-            }
-        }
-        formatted
+        cast_core::units::format_units(value, unit)
     }
 
     /// Converts wei into an eth amount
@@ -1764,7 +1751,7 @@ pub fn parse_bytes32_address(s: &str) -> Result<String> {
     /// # Ok::<_, eyre::Report>(())
     /// ```
     pub fn abi_decode(sig: &str, calldata: &str, input: bool) -> Result<Vec<DynSolValue>> {
-        foundry_common::abi::abi_decode_calldata(sig, calldata, input, false)
+        Ok(cast_core::abi::abi_decode(sig, calldata, input, false)?)
     }
 
     /// Decodes calldata-encoded hex input or output
@@ -1805,7 +1792,7 @@ pub fn abi_decode(sig: &str, calldata: &str, input: bool) -> Result<Vec<DynSolVa
     /// # Ok::<_, eyre::Report>(())
     /// ```
     pub fn calldata_decode(sig: &str, calldata: &str, input: bool) -> Result<Vec<DynSolValue>> {
-        foundry_common::abi::abi_decode_calldata(sig, calldata, input, true)
+        Ok(cast_core::abi::abi_decode(sig, calldata, input, true)?)
     }
 
     /// Performs ABI encoding based off of the function signature. Does not include
@@ -1827,9 +1814,8 @@ pub fn calldata_decode(sig: &str, calldata: &str, input: bool) -> Result<Vec<Dyn
     /// # Ok::<_, eyre::Report>(())
     /// ```
     pub fn abi_encode(sig: &str, args: &[impl AsRef<str>]) -> Result<String> {
-        let func = get_func(sig)?;
-        match encode_function_args(&func, args) {
-            Ok(res) => Ok(hex::encode_prefixed(&res[4..])),
+        match cast_core::abi::abi_encode(sig, args) {
+            Ok(res) => Ok(hex::encode_prefixed(res)),
             Err(e) => eyre::bail!("Could not ABI encode the function and arguments: {e}"),
         }
     }
@@ -1853,16 +1839,10 @@ pub fn abi_encode(sig: &str, args: &[impl AsRef<str>]) -> Result<String> {
     /// # Ok::<_, eyre::Report>(())
     /// ```
     pub fn abi_encode_packed(sig: &str, args: &[impl AsRef<str>]) -> Result<String> {
-        // If the signature is a tuple, we need to prefix it to make it a function
-        let sig =
-            if sig.trim_start().starts_with('(') { format!("foo{sig}") } else { sig.to_string() };
-
-        let func = get_func(sig.as_str())?;
-        let encoded = match encode_function_args_packed(&func, args) {
-            Ok(res) => hex::encode(res),
+        match cast_core::abi::abi_encode_packed(sig, args) {
+            Ok(res) => Ok(hex::encode_prefixed(res)),
             Err(e) => eyre::bail!("Could not ABI encode the function and arguments: {e}"),
-        };
-        Ok(format!("0x{encoded}"))
+        }
     }
 
     /// Performs ABI encoding of an event to produce the topics and data.
@@ -1959,9 +1939,7 @@ pub fn abi_encode_event(sig: &str, args: &[impl AsRef<str>]) -> Result<LogData>
     /// # Ok::<_, eyre::Report>(())
     /// ```
     pub fn calldata_encode(sig: impl AsRef<str>, args: &[impl AsRef<str>]) -> Result<String> {
-        let func = get_func(sig.as_ref())?;
-        let calldata = encode_function_args(&func, args)?;
-        Ok(hex::encode_prefixed(calldata))
+        Ok(hex::encode_prefixed(cast_core::abi::calldata_encode(sig.as_ref(), args)?))
     }
 
     /// Returns the slot number for a given mapping key and slot.
@@ -2057,13 +2035,7 @@ pub fn index(key_type: &str, key: &str, slot_number: &str) -> Result<String> {
     /// # Ok::<_, eyre::Report>(())
     /// ```
     pub fn keccak(data: &str) -> Result<String> {
-        // Hex-decode if data starts with 0x.
-        let hash = if data.starts_with("0x") {
-            keccak256(hex::decode(data.trim_end())?)
-        } else {
-            keccak256(data)
-        };
-        Ok(hash.to_string())
+        Ok(cast_core::hash::keccak(data)?.to_string())
     }
 
     /// Performs the left shift operation (<<) on a number
@@ -2120,114 +2092,6 @@ pub fn right_shift(
         Ok(res.to_base(base_out, true)?)
     }
 
-    /// Fetches source code of verified contracts from etherscan.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use cast::SimpleCast as Cast;
-    /// # use foundry_config::NamedChain;
-    /// # async fn foo() -> eyre::Result<()> {
-    /// assert_eq!(
-    ///     "/*
-    ///             - Bytecode Verification performed was compared on second iteration -
-    ///             This file is part of the DAO.....",
-    ///     Cast::etherscan_source(
-    ///         NamedChain::Mainnet.into(),
-    ///         "0xBB9bc244D798123fDe783fCc1C72d3Bb8C189413".to_string(),
-    ///         Some("<etherscan_api_key>".to_string()),
-    ///         None,
-    ///         None
-    ///     )
-    ///     .await
-    ///     .unwrap()
-    ///     .as_str()
-    /// );
-    /// # Ok(())
-    /// # }
-    /// ```
-    pub async fn etherscan_source(
-        chain: Chain,
-        contract_address: String,
-        etherscan_api_key: Option<String>,
-        explorer_api_url: Option<String>,
-        explorer_url: Option<String>,
-    ) -> Result<String> {
-        let client = explorer_client(chain, etherscan_api_key, explorer_api_url, explorer_url)?;
-        let metadata = client.contract_source_code(contract_address.parse()?).await?;
-        Ok(metadata.source_code())
-    }
-
-    /// Fetches the source code of verified contracts from etherscan and expands the resulting
-    /// files to a directory for easy perusal.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use cast::SimpleCast as Cast;
-    /// # use foundry_config::NamedChain;
-    /// # use std::path::PathBuf;
-    /// # async fn expand() -> eyre::Result<()> {
-    /// Cast::expand_etherscan_source_to_directory(
-    ///     NamedChain::Mainnet.into(),
-    ///     "0xBB9bc244D798123fDe783fCc1C72d3Bb8C189413".to_string(),
-    ///     Some("<etherscan_api_key>".to_string()),
-    ///     PathBuf::from("output_dir"),
-    ///     None,
-    ///     None,
-    /// )
-    /// .await?;
-    /// # Ok(())
-    /// # }
-    /// ```
-    pub async fn expand_etherscan_source_to_directory(
-        chain: Chain,
-        contract_address: String,
-        etherscan_api_key: Option<String>,
-        output_directory: PathBuf,
-        explorer_api_url: Option<String>,
-        explorer_url: Option<String>,
-    ) -> eyre::Result<()> {
-        let client = explorer_client(chain, etherscan_api_key, explorer_api_url, explorer_url)?;
-        let meta = client.contract_source_code(contract_address.parse()?).await?;
-        let source_tree = meta.source_tree();
-        source_tree.write_to(&output_directory)?;
-        Ok(())
-    }
-
-    /// Fetches the source code of verified contracts from etherscan, flattens it and writes it to
-    /// the given path or stdout.
-    pub async fn etherscan_source_flatten(
-        chain: Chain,
-        contract_address: String,
-        etherscan_api_key: Option<String>,
-        output_path: Option<PathBuf>,
-        explorer_api_url: Option<String>,
-        explorer_url: Option<String>,
-    ) -> Result<()> {
-        let client = explorer_client(chain, etherscan_api_key, explorer_api_url, explorer_url)?;
-        let metadata = client.contract_source_code(contract_address.parse()?).await?;
-        let Some(metadata) = metadata.items.first() else {
-            eyre::bail!("Empty contract source code")
-        };
-
-        let tmp = tempfile::tempdir()?;
-        let project = etherscan_project(metadata, tmp.path())?;
-        let target_path = project.find_contract_path(&metadata.contract_name)?;
-
-        let flattened = flatten(project, &target_path)?;
-
-        if let Some(path) = output_path {
-            fs::create_dir_all(path.parent().unwrap())?;
-            fs::write(&path, flattened)?;
-            sh_println!("Flattened file written at {}", path.display())?
-        } else {
-            sh_println!("{flattened}")?
-        }
-
-        Ok(())
-    }
-
     /// Disassembles hex encoded bytecode into individual / human readable opcodes
     ///
     /// # Example
@@ -2361,13 +2225,7 @@ pub fn extract_functions(bytecode: &str) -> Result<Vec<(Selector, String, &str)>
     pub fn decode_raw_transaction<N: Network<TxEnvelope: SignerRecoverable + Serialize>>(
         tx: &str,
     ) -> Result<String> {
-        let tx_hex = hex::decode(tx)?;
-        let tx: N::TxEnvelope = Decodable2718::decode_2718(&mut tx_hex.as_slice())?;
-        if let Ok(signer) = tx.recover_signer() {
-            Ok(serde_json::to_string_pretty(&Recovered::new_unchecked(tx, signer))?)
-        } else {
-            Ok(serde_json::to_string_pretty(&tx)?)
-        }
+        Ok(cast_core::tx::decode_raw_transaction::<N::TxEnvelope>(tx)?)
     }
 }
 
@@ -2375,7 +2233,7 @@ pub(crate) fn strip_0x(s: &str) -> &str {
     s.strip_prefix("0x").unwrap_or(s)
 }
 
-fn explorer_client(
+pub(crate) fn explorer_client(
     chain: Chain,
     api_key: Option<String>,
     api_url: Option<String>,
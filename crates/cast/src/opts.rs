@@ -1,12 +1,14 @@
 use crate::cmd::{
     access_list::AccessListArgs, artifact::ArtifactArgs, b2e_payload::B2EPayloadArgs,
-    batch_mktx::BatchMakeTxArgs, batch_send::BatchSendArgs, bind::BindArgs, call::CallArgs,
+    balance_snapshot::BalanceSnapshotArgs, batch_mktx::BatchMakeTxArgs, batch_send::BatchSendArgs,
+    bind::BindArgs, call::CallArgs, chain::ChainSubcommand, compare_rpc::CompareRpcArgs,
     constructor_args::ConstructorArgsArgs, create2::Create2Args, creation_code::CreationCodeArgs,
     da_estimate::DAEstimateArgs, erc20::Erc20Subcommand, estimate::EstimateArgs,
-    find_block::FindBlockArgs, interface::InterfaceArgs, keychain::KeychainSubcommand,
-    logs::LogsArgs, mktx::MakeTxArgs, rpc::RpcArgs, run::RunArgs, send::SendTxArgs,
-    storage::StorageArgs, tip20::Tip20Subcommand, trace::TraceArgs, txpool::TxPoolSubcommands,
-    wallet::WalletSubcommands,
+    find_block::FindBlockArgs, gas::GasArgs, gov::GovSubcommand, interface::InterfaceArgs,
+    keychain::KeychainSubcommand, logs::LogsArgs, merkle::MerkleSubcommand, mktx::MakeTxArgs,
+    permit::PermitArgs, rpc::RpcArgs, run::RunArgs, send::SendTxArgs, source::SourceArgs,
+    storage::StorageArgs, tip20::Tip20Subcommand, token::TokenSubcommand, trace::TraceArgs,
+    txpool::TxPoolSubcommands, wallet::WalletSubcommands,
 };
 use alloy_ens::NameOrAddress;
 use alloy_primitives::{Address, B256, Selector, U256};
@@ -429,6 +431,9 @@ pub enum CastSubcommand {
 
     /// Get the symbolic name of the current chain.
     Chain {
+        #[command(subcommand)]
+        command: Option<ChainSubcommand>,
+
         #[command(flatten)]
         rpc: RpcOpts,
     },
@@ -440,6 +445,11 @@ pub enum CastSubcommand {
         rpc: RpcOpts,
     },
 
+    /// Compare the responses of several RPC endpoints to the same query, flagging the lagging
+    /// and inconsistent ones.
+    #[command(name = "compare-rpc")]
+    CompareRpc(CompareRpcArgs),
+
     /// Get the current client version.
     #[command(visible_alias = "cl")]
     Client {
@@ -494,6 +504,10 @@ pub enum CastSubcommand {
     #[command(name = "mktx", visible_alias = "m")]
     MakeTx(MakeTxArgs),
 
+    /// Build and sign an EIP-2612 or Permit2 permit, and optionally submit it.
+    #[command(visible_alias = "pm")]
+    Permit(PermitArgs),
+
     /// Calculate the ENS namehash of a name.
     #[command(visible_aliases = &["na", "nh"])]
     Namehash { name: Option<String> },
@@ -530,6 +544,22 @@ pub enum CastSubcommand {
         /// Specify the Network for correct encoding.
         #[arg(long, short, num_args = 1, value_name = "NETWORK")]
         network: Option<NetworkVariant>,
+
+        /// Wait for the transaction to be confirmed before printing it.
+        ///
+        /// The transaction is re-broadcast if a reorg drops it before it is confirmed.
+        #[arg(long, requires = "tx_hash")]
+        wait: bool,
+
+        /// The number of confirmations to wait for.
+        #[arg(long, default_value = "1", requires = "wait")]
+        confirmations: u64,
+
+        /// Timeout for the confirmations, in seconds.
+        ///
+        /// Ignored without `--wait`, so that `ETH_TIMEOUT` can be set for other commands.
+        #[arg(long, env = "ETH_TIMEOUT")]
+        timeout: Option<u64>,
     },
 
     /// Get the transaction receipt for a transaction.
@@ -549,6 +579,13 @@ pub enum CastSubcommand {
         #[arg(id = "async", long = "async", env = "CAST_ASYNC", alias = "cast-async")]
         cast_async: bool,
 
+        /// Specify the Network to format the chain-specific fields of the receipt.
+        ///
+        /// Defaults to the network of the chain ID of the RPC endpoint. `foundry` formats the
+        /// fields of every transaction type supported by Foundry, including OP stack deposits.
+        #[arg(long, short, num_args = 1, value_name = "NETWORK")]
+        network: Option<TxNetwork>,
+
         #[command(flatten)]
         rpc: RpcOpts,
     },
@@ -566,10 +603,17 @@ pub enum CastSubcommand {
     BatchSend(BatchSendArgs),
 
     /// Publish a raw transaction to the network.
-    #[command(name = "publish", visible_alias = "p")]
+    #[command(name = "publish", visible_aliases = &["p", "sendraw"])]
     PublishTx {
         /// The raw transaction
-        raw_tx: String,
+        #[arg(required_unless_present = "bundle")]
+        raw_tx: Option<String>,
+
+        /// Publish the transactions of a bundle signed with `cast mktx --offline`, in order.
+        ///
+        /// The bundle is checked against the chain before any transaction is sent.
+        #[arg(long, value_name = "PATH", conflicts_with = "raw_tx")]
+        bundle: Option<PathBuf>,
 
         /// Only print the transaction hash and exit immediately.
         #[arg(id = "async", long = "async", env = "CAST_ASYNC", alias = "cast-async")]
@@ -827,6 +871,10 @@ pub enum CastSubcommand {
         erc20: Option<Address>,
     },
 
+    /// Snapshot the token balances of holders at a block into a report, optionally signed.
+    #[command(visible_aliases = &["proof-of-reserve", "por"])]
+    BalanceSnapshot(BalanceSnapshotArgs),
+
     /// Get the basefee of a block.
     #[command(visible_aliases = &["ba", "fee", "basefee"])]
     BaseFee {
@@ -884,6 +932,9 @@ pub enum CastSubcommand {
         rpc: RpcOpts,
     },
 
+    /// Analyze the fees of recent blocks and recommend fees by target inclusion time.
+    Gas(GasArgs),
+
     /// Generate event signatures from event string.
     #[command(visible_alias = "se")]
     SigEvent {
@@ -1019,30 +1070,7 @@ pub enum CastSubcommand {
 
     /// Get the source code of a contract from a block explorer.
     #[command(visible_aliases = &["et", "src"])]
-    Source {
-        /// The contract's address.
-        address: String,
-
-        /// Whether to flatten the source code.
-        #[arg(long, short)]
-        flatten: bool,
-
-        /// The output directory/file to expand source tree into.
-        #[arg(short, value_hint = ValueHint::DirPath, alias = "path")]
-        directory: Option<PathBuf>,
-
-        #[command(flatten)]
-        etherscan: EtherscanOpts,
-
-        /// Alternative explorer API URL to use that adheres to the Etherscan API. If not provided,
-        /// defaults to Etherscan.
-        #[arg(long, env = "EXPLORER_API_URL")]
-        explorer_api_url: Option<String>,
-
-        /// Alternative explorer browser URL.
-        #[arg(long, env = "EXPLORER_URL")]
-        explorer_url: Option<String>,
-    },
+    Source(SourceArgs),
 
     /// Wallet management utilities.
     #[command(visible_alias = "w")]
@@ -1137,8 +1165,11 @@ pub enum CastSubcommand {
         tx: Option<String>,
 
         /// Specify the Network for correct encoding.
+        ///
+        /// `foundry` decodes every transaction type supported by Foundry, including OP stack
+        /// deposits and Tempo transactions.
         #[arg(long, short, num_args = 1, value_name = "NETWORK")]
-        network: Option<NetworkVariant>,
+        network: Option<TxNetwork>,
     },
 
     /// Recovery an EIP-7702 authority from a Authorization JSON string.
@@ -1162,7 +1193,8 @@ pub enum CastSubcommand {
         #[command(subcommand)]
         command: TxPoolSubcommands,
     },
-    /// Estimates the data availability size of a given opstack block.
+    /// Estimates the data availability size of a given opstack block, or the L1 data fee of a
+    /// pending transaction.
     #[command(name = "da-estimate")]
     DAEstimate(DAEstimateArgs),
 
@@ -1173,6 +1205,24 @@ pub enum CastSubcommand {
         command: Erc20Subcommand,
     },
 
+    /// Token helpers detecting the ERC-20, ERC-721 or ERC-1155 standard.
+    Token {
+        #[command(subcommand)]
+        command: TokenSubcommand,
+    },
+
+    /// OpenZeppelin-compatible merkle tree utilities.
+    Merkle {
+        #[command(subcommand)]
+        command: MerkleSubcommand,
+    },
+
+    /// Governance proposal helpers.
+    Gov {
+        #[command(subcommand)]
+        command: GovSubcommand,
+    },
+
     /// TIP-20 token operations (Tempo).
     #[command(visible_alias = "tip20")]
     Tip20Token {
@@ -1190,6 +1240,15 @@ pub enum CastSubcommand {
     Trace(TraceArgs),
 }
 
+/// The network to decode transactions and format receipts with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum TxNetwork {
+    Ethereum,
+    Optimism,
+    Tempo,
+    Foundry,
+}
+
 /// CLI arguments for `cast --to-base`.
 #[derive(Debug, Parser)]
 pub struct ToBaseArgs {
@@ -1281,6 +1340,19 @@ fn parse_call_data_with_file() {
         };
     }
 
+    #[test]
+    fn parse_tx_timeout_without_wait() {
+        let hash = "0x56462c47c03df160f66819f0a79ea07def1569f8aac0fe91bb3a081159b61b4a";
+        let args: Cast = Cast::parse_from(["foundry-cli", "tx", hash, "--timeout", "5"]);
+        match args.cmd {
+            CastSubcommand::Tx { wait, timeout, .. } => {
+                assert!(!wait);
+                assert_eq!(timeout, Some(5));
+            }
+            _ => unreachable!(),
+        };
+    }
+
     // <https://github.com/foundry-rs/book/issues/1019>
     #[test]
     fn parse_signature() {
@@ -16,7 +16,7 @@
     utils::{self, parse_function_args},
 };
 use foundry_common::{
-    FoundryTransactionBuilder, TransactionReceiptWithRevertReason, fmt::*,
+    ConfirmationWaiter, FoundryTransactionBuilder, TransactionReceiptWithRevertReason, fmt::*,
     get_pretty_receipt_w_reason_attr, shell,
 };
 use foundry_config::{Chain, Config};
@@ -37,6 +37,8 @@ pub struct SendTxOpts {
     pub sync: bool,
 
     /// The number of confirmations until the receipt is fetched.
+    ///
+    /// The transaction is re-broadcast if a reorg drops it before it is confirmed.
     #[arg(long, default_value = "1")]
     pub confirmations: u64,
 
@@ -287,6 +289,8 @@ pub async fn send_raw(&self, raw_tx: &[u8]) -> Result<PendingTransactionBuilder<
 
     /// Prints the transaction hash (if async) or waits for the receipt and prints it.
     ///
+    /// Transactions reorged out while waiting for confirmations are re-broadcast.
+    ///
     /// This is the shared "output" path used by both the normal send flow and the browser wallet
     /// flow (which sends the transaction out-of-band and only has a tx hash).
     pub async fn print_tx_result(
@@ -299,9 +303,14 @@ pub async fn print_tx_result(
         if cast_async {
             sh_println!("{tx_hash:#x}")?;
         } else {
-            let receipt =
-                self.receipt(format!("{tx_hash:#x}"), None, confs, Some(timeout), false).await?;
-            sh_println!("{receipt}")?;
+            let receipt = ConfirmationWaiter::new(confs, Duration::from_secs(timeout))
+                .wait(&self.provider, tx_hash)
+                .await?;
+            let mut receipt =
+                TransactionReceiptWithRevertReason::<N> { receipt, revert_reason: None };
+            // Allow to fail silently
+            let _ = receipt.update_revert_reason(&self.provider).await;
+            sh_println!("{}", self.format_receipt(receipt, None)?)?;
         }
         Ok(())
     }
@@ -606,3 +606,111 @@ async fn setup_token_test(
     let total_supply: U256 = total_supply_str.parse().unwrap();
     assert_eq!(total_supply, U256::from(1_000_000_000_000_000_000_000u128));
 });
+
+// tests that `cast token` detects ERC20 tokens and formats amounts in token units
+forgetest_async!(token_info_balance_transfer_calldata, |prj, cmd| {
+    let (rpc, token) = setup_token_test(&prj, &mut cmd).await;
+
+    cmd.cast_fuse().args(["token", "info", &token, "--rpc-url", &rpc]).assert_success().stdout_eq(
+        str![[r#"
+standard     ERC-20
+name         Test Token
+symbol       TEST
+decimals     18
+totalSupply  1000 TEST
+
+"#]],
+    );
+
+    cmd.cast_fuse()
+        .args(["token", "balance", &token, anvil_const::ADDR1, "--rpc-url", &rpc])
+        .assert_success()
+        .stdout_eq(str![[r#"
+1000 TEST
+
+"#]]);
+
+    cmd.cast_fuse()
+        .args([
+            "token",
+            "allowance",
+            &token,
+            anvil_const::ADDR1,
+            anvil_const::ADDR2,
+            "--rpc-url",
+            &rpc,
+        ])
+        .assert_success()
+        .stdout_eq(str![[r#"
+0 TEST
+
+"#]]);
+
+    cmd.cast_fuse()
+        .args(["token", "transfer-calldata", &token, anvil_const::ADDR2, "1.5", "--rpc-url", &rpc])
+        .assert_success()
+        .stdout_eq(str![[r#"
+0xa9059cbb00000000000000000000000070997970c51812dc3a010c7d01b50e0d17dc79c800000000000000000000000000000000000000000000000014d1120d7b160000
+
+"#]]);
+
+    // Accounts are not tokens.
+    cmd.cast_fuse()
+        .args(["token", "info", anvil_const::ADDR2, "--rpc-url", &rpc])
+        .assert_failure()
+        .stderr_eq(str![[r#"
+Error: no contract deployed at 0x70997970C51812dc3A010C7d01b50e0d17dc79C8
+
+"#]]);
+});
+
+// tests that `cast permit` signs Permit2 permits and requires EIP-2612 support otherwise
+forgetest_async!(permit_signatures, |prj, cmd| {
+    let (rpc, token) = setup_token_test(&prj, &mut cmd).await;
+
+    cmd.cast_fuse()
+        .args([
+            "permit",
+            &token,
+            anvil_const::ADDR2,
+            "100",
+            "--permit2",
+            "--nonce",
+            "0",
+            "--deadline",
+            "2000000000",
+            "--private-key",
+            anvil_const::PK1,
+            "--rpc-url",
+            &rpc,
+        ])
+        .assert_success()
+        .stdout_eq(str![[r#"
+owner      0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266
+nonce      0
+deadline   2000000000
+signature  0x[..]
+v          [..]
+r          0x[..]
+s          0x[..]
+
+"#]]);
+
+    // The test token doesn't implement EIP-2612.
+    cmd.cast_fuse()
+        .args([
+            "permit",
+            &token,
+            anvil_const::ADDR2,
+            "100",
+            "--private-key",
+            anvil_const::PK1,
+            "--rpc-url",
+            &rpc,
+        ])
+        .assert_failure()
+        .stderr_eq(str![[r#"
+Error: 0x5FbDB2315678afecb367f032d93F642f64180aa3 doesn't support EIP-2612 permits, use --permit2
+...
+"#]]);
+});
@@ -2968,6 +2968,128 @@ struct TestStruct {
     assert!(output.contains("gasUsed"));
 });
 
+casttest!(tx_wait_confirmations, async |_prj, cmd| {
+    let (api, handle) = anvil::spawn(NodeConfig::test()).await;
+    let endpoint = handle.http_endpoint();
+
+    let tx_hash = cmd
+        .args([
+            "send",
+            "0x70997970C51812dc3A010C7d01b50e0d17dc79C8",
+            "--value",
+            "1",
+            "--private-key",
+            "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+            "--rpc-url",
+            &endpoint,
+            "--async",
+        ])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let tx_hash = tx_hash.trim();
+
+    // The transaction is only in the latest block.
+    cmd.cast_fuse()
+        .args([
+            "tx",
+            tx_hash,
+            "--wait",
+            "--confirmations",
+            "3",
+            "--timeout",
+            "1",
+            "--rpc-url",
+            &endpoint,
+        ])
+        .assert_failure()
+        .stderr_eq(str![[r#"
+Error: timed out waiting for 3 confirmations of transaction [..]
+
+"#]]);
+
+    api.anvil_mine(Some(U256::from(2)), None).await.unwrap();
+    let output = cmd
+        .cast_fuse()
+        .args([
+            "tx",
+            tx_hash,
+            "--wait",
+            "--confirmations",
+            "3",
+            "--timeout",
+            "1",
+            "--rpc-url",
+            &endpoint,
+        ])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    assert!(output.contains(tx_hash));
+});
+
+casttest!(offline_signing_bundle, async |prj, cmd| {
+    let (_api, handle) = anvil::spawn(NodeConfig::test()).await;
+    let endpoint = handle.http_endpoint();
+    let bundle = prj.root().join("bundle.json");
+    let bundle = bundle.to_str().unwrap();
+
+    for value in ["1", "2"] {
+        cmd.cast_fuse()
+            .args([
+                "mktx",
+                "0x70997970C51812dc3A010C7d01b50e0d17dc79C8",
+                "--value",
+                value,
+                "--from",
+                "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
+                "--bundle",
+                bundle,
+                "--rpc-url",
+                &endpoint,
+            ])
+            .assert_success();
+    }
+
+    // Unsigned bundles can't be published.
+    cmd.cast_fuse()
+        .args(["publish", "--bundle", bundle, "--rpc-url", &endpoint])
+        .assert_failure()
+        .stderr_eq(str![[r#"
+Error: transaction 0 of 0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266 is not signed, sign the bundle with `cast mktx --offline`
+
+"#]]);
+
+    // No RPC is needed to sign.
+    cmd.cast_fuse()
+        .args([
+            "mktx",
+            "--offline",
+            "--bundle",
+            bundle,
+            "--private-key",
+            "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+        ])
+        .assert_success()
+        .stdout_eq(str![[r#"
+Signed 2 transactions of 0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266 on chain 31337
+
+"#]]);
+
+    cmd.cast_fuse()
+        .args(["sendraw", "--bundle", bundle, "--async", "--rpc-url", &endpoint])
+        .assert_success();
+
+    // The nonces are now used.
+    cmd.cast_fuse()
+        .args(["publish", "--bundle", bundle, "--rpc-url", &endpoint])
+        .assert_failure()
+        .stderr_eq(str![[r#"
+Error: nonce 0 of 0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266 was already used, the bundle is stale
+
+"#]]);
+});
+
 casttest!(hash_message, |_prj, cmd| {
     cmd.args(["hash-message", "hello"]).assert_success().stdout_eq(str![[r#"
 0x50b2c43fd39106bafbba0da34fc430e1f91e3c96ea2acee2bc34119f92b37750
@@ -4990,6 +5112,30 @@ struct Info {
     assert_eq!(decoded["feeToken"], "0x20c000000000000000000000b9537d11c60e8b50");
 });
 
+// Test that `--network foundry` decodes every transaction type of the golden vectors.
+casttest!(cast_decode_tx_foundry, |_prj, cmd| {
+    let vectors: Vec<serde_json::Value> =
+        serde_json::from_str(include_str!("../../../primitives/test-data/tx_envelopes.json"))
+            .unwrap();
+    for vector in vectors {
+        let output = cmd
+            .cast_fuse()
+            .args(["decode-tx", "--network", "foundry", vector["raw"].as_str().unwrap()])
+            .assert_success()
+            .get_output()
+            .stdout
+            .clone();
+        let output: String = serde_json::from_slice(&output).unwrap();
+        let decoded: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(
+            decoded["type"],
+            format!("{:#x}", vector["type"].as_u64().unwrap()),
+            "{}",
+            vector["name"]
+        );
+    }
+});
+
 // Test decode-tx with invalid hex input
 casttest!(cast_decode_tx_invalid, |_prj, cmd| {
     cmd.args(["decode-tx", "0xinvalid"]).assert_failure();
@@ -5076,3 +5222,225 @@ struct Info {
         "expected Spurious Dragon gas (177241), got: {sd_output}"
     );
 });
+
+casttest!(balance_snapshot_signed, async |_prj, cmd| {
+    let (_, handle) = anvil::spawn(NodeConfig::test()).await;
+    let rpc = handle.http_endpoint();
+    let holder = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+
+    let output = cmd
+        .args([
+            "balance-snapshot",
+            &format!("ETH:{holder}"),
+            "--sign",
+            "--private-key",
+            "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+            "--rpc-url",
+            &rpc,
+        ])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let report: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let snapshot = &report["snapshot"];
+    assert_eq!(snapshot["chainId"], 31337);
+    assert_eq!(snapshot["balances"][0]["token"], serde_json::Value::Null);
+    assert_eq!(snapshot["balances"][0]["balance"], "0x21e19e0c9bab2400000");
+    assert_eq!(report["signer"], holder);
+    assert_eq!(report["message"], serde_json::to_string(snapshot).unwrap());
+});
+
+casttest!(chain_detect, async |_prj, cmd| {
+    let (_, handle) = anvil::spawn(NodeConfig::test()).await;
+
+    let output = cmd
+        .args(["chain", "detect", "--json", "--rpc-url", &handle.http_endpoint()])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let report: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    assert_eq!(report["chainId"], 31337);
+    assert_eq!(report["namespaces"]["ots"], true);
+    assert_eq!(report["oldestStateBlock"], 0);
+    assert_eq!(report["eips"]["EIP-1559 (base fee)"], true);
+    assert_eq!(report["eips"]["EIP-3855 (PUSH0)"], true);
+});
+
+casttest!(compare_rpc_balance, async |_prj, cmd| {
+    let (_, first) = anvil::spawn(NodeConfig::test()).await;
+    let (api, second) = anvil::spawn(NodeConfig::test()).await;
+    let holder = address!("0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+    let (first, second, who) = (first.http_endpoint(), second.http_endpoint(), holder.to_string());
+    let args = ["compare-rpc", "-e", &first, "-e", &second, "balance", &who, "-B", "latest"];
+
+    let output = cmd.args(args).arg("--json").assert_success().get_output().stdout_lossy();
+    let report: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(report["endpoints"][0]["response"], "10000000000000000000000");
+    assert_eq!(report["endpoints"][1]["inconsistent"], false);
+
+    api.anvil_set_balance(holder, U256::from(1)).await.unwrap();
+    cmd.cast_fuse().args(args).assert_failure().stderr_eq(str![[r#"
+Error: the endpoints returned inconsistent responses
+
+"#]]);
+});
+
+casttest!(receipt_network_foundry, async |_prj, cmd| {
+    let (_, handle) = anvil::spawn(NodeConfig::test()).await;
+    let rpc = handle.http_endpoint();
+
+    let output = cmd
+        .args([
+            "send",
+            "0x70997970C51812dc3A010C7d01b50e0d17dc79C8",
+            "--value",
+            "1",
+            "--private-key",
+            "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+            "--async",
+            "--rpc-url",
+            &rpc,
+        ])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let tx_hash = output.trim();
+
+    let mut receipt = |network: Option<&str>, field: &str| {
+        let mut args = vec!["receipt", tx_hash, field, "--rpc-url", rpc.as_str()];
+        if let Some(network) = network {
+            args.extend(["--network", network]);
+        }
+        cmd.cast_fuse().args(args).assert_success().get_output().stdout_lossy()
+    };
+    assert_eq!(receipt(Some("foundry"), "type"), "2\n");
+    assert_eq!(receipt(Some("foundry"), "gasUsed"), receipt(None, "gasUsed"));
+
+    // Deposit fields are only set for OP stack deposits.
+    cmd.cast_fuse()
+        .args(["receipt", tx_hash, "depositNonce", "--network", "foundry", "--rpc-url", &rpc])
+        .assert_failure();
+});
+
+// tests that `cast gov propose` encodes a proposal without an RPC endpoint
+casttest!(gov_propose_encode, |_prj, cmd| {
+    let output = cmd
+        .args([
+            "gov",
+            "propose",
+            "0x0000000000000000000000000000000000000001",
+            "--kind",
+            "bravo",
+            "--target",
+            "0x0000000000000000000000000000000000000002",
+            "--calldata",
+            "0x",
+            "--description",
+            "",
+        ])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    assert!(output.contains("Proposal id: assigned when proposed"));
+    assert!(output.contains(
+        "Description hash: 0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    ));
+
+    // Every action needs a target and a calldata.
+    cmd.cast_fuse()
+        .args([
+            "gov",
+            "propose",
+            "0x0000000000000000000000000000000000000001",
+            "--target",
+            "0x0000000000000000000000000000000000000002",
+            "--target",
+            "0x0000000000000000000000000000000000000003",
+            "--calldata",
+            "0x",
+            "--description",
+            "",
+        ])
+        .assert_failure();
+});
+
+// <https://github.com/OpenZeppelin/merkle-tree#building-a-tree>
+casttest!(merkle_build_proof_verify, |prj, cmd| {
+    let leaves = prj.root().join("leaves.csv");
+    fs::write(
+        &leaves,
+        "account,amount\n\
+         0x1111111111111111111111111111111111111111,5000000000000000000\n\
+         0x2222222222222222222222222222222222222222,2500000000000000000\n",
+    )
+    .unwrap();
+    let tree = prj.root().join("tree.json");
+
+    cmd.args([
+        "merkle",
+        "build",
+        leaves.to_str().unwrap(),
+        "--types",
+        "address,uint256",
+        "--out",
+        tree.to_str().unwrap(),
+    ])
+    .assert_success()
+    .stdout_eq(str![[r#"
+0xd4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77
+
+"#]]);
+
+    cmd.cast_fuse()
+        .args([
+            "merkle",
+            "proof",
+            tree.to_str().unwrap(),
+            "0x2222222222222222222222222222222222222222",
+            "2500000000000000000",
+        ])
+        .assert_success()
+        .stdout_eq(str![[r#"
+0xeb02c421cfa48976e66dfb29120745909ea3a0f843456c263cf8f1253483e283
+
+"#]]);
+
+    cmd.cast_fuse()
+        .args([
+            "merkle",
+            "verify",
+            "0xd4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77",
+            "0x2222222222222222222222222222222222222222",
+            "2500000000000000000",
+            "--types",
+            "address,uint256",
+            "--proof",
+            "0xeb02c421cfa48976e66dfb29120745909ea3a0f843456c263cf8f1253483e283",
+        ])
+        .assert_success()
+        .stdout_eq(str![[r#"
+valid
+
+"#]]);
+
+    // A proof of another amount is rejected.
+    cmd.cast_fuse()
+        .args([
+            "merkle",
+            "verify",
+            "0xd4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77",
+            "0x2222222222222222222222222222222222222222",
+            "5000000000000000000",
+            "--types",
+            "address,uint256",
+            "--proof",
+            "0xeb02c421cfa48976e66dfb29120745909ea3a0f843456c263cf8f1253483e283",
+        ])
+        .assert_failure()
+        .stderr_eq(str![[r#"
+Error: invalid proof
+
+"#]]);
+});
@@ -385,6 +385,16 @@ struct PotentialRevert {
     #[cheatcode(group = Evm, safety = Unsafe)]
     function loadAllocs(string calldata pathToAllocsJson) external;
 
+    /// Loads the named accounts of an `accounts.toml` file, as used by `anvil --accounts-config`:
+    /// funds them, sets their code or EIP-7702 delegation, labels them and remembers their private
+    /// keys. Returns their addresses, sorted by account name.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function loadNamedAccounts(string calldata path) external returns (address[] memory accounts);
+
+    /// Returns the address of a named account loaded with `loadNamedAccounts`.
+    #[cheatcode(group = Evm, safety = Safe)]
+    function namedAccount(string calldata name) external view returns (address account);
+
     // -------- Record Debug Traces --------
 
     /// Records the debug trace during the run.
@@ -563,6 +573,26 @@ struct PotentialRevert {
     #[cheatcode(group = Evm, safety = Unsafe)]
     function setBlockhash(uint256 blockNumber, bytes32 blockHash) external;
 
+    /// Sets the blockhashes of the consecutive blocks starting at `fromBlock`, e.g. to seed the
+    /// last 256 blockhashes of a fork.
+    /// All the blocks must be lower than or equal to the current block number.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function setBlockhashes(uint256 fromBlock, bytes32[] calldata blockHashes) external;
+
+    /// Sets `block.prevrandao` for the given block, applied when `vm.roll` moves to it, or right away
+    /// if it is the current block.
+    /// Not available on EVM versions before Paris.
+    /// If used on unsupported EVM versions it will revert.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function setPrevrandao(uint256 blockNumber, bytes32 newPrevrandao) external;
+
+    /// Sets `block.blobbasefee` to the given value, keeping the excess blob gas.
+    /// Unlike `blobBaseFee`, which sets the excess blob gas the blob base fee is derived from.
+    /// Not available on EVM versions before Cancun.
+    /// If used on unsupported EVM versions it will revert.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function setBlobBaseFee(uint256 newBlobBaseFee) external;
+
     /// Executes an RLP-encoded signed transaction with full EVM semantics (like `--isolate` mode).
     /// The transaction is decoded from EIP-2718 format (type byte prefix + RLP payload) or legacy RLP.
     /// Returns the execution output bytes.
@@ -577,6 +607,19 @@ struct PotentialRevert {
     #[cheatcode(group = Evm, safety = Unsafe)]
     function deal(address account, uint256 newBalance) external;
 
+    /// Sets an address' balance of an ERC-20 token by writing to the token's storage.
+    /// The balance slot is discovered by probing `balanceOf`, including balances packed with other data in the same slot.
+    /// Tokens storing balances as shares (e.g. stETH, Aave aTokens) are supported through their registered strategy;
+    /// common Ethereum mainnet tokens are registered by default. The resulting balance may differ by rounding for such tokens.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function dealToken(address token, address account, uint256 newBalance) external;
+
+    /// Registers a token as storing balances as shares for `dealToken`.
+    /// `sharesOf` is the selector of the `(address) -> uint256` shares getter and `totalShares` the selector of the
+    /// `() -> uint256` total shares getter; the shares backing a balance are derived using the token's `totalSupply()`.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function setTokenDealStrategy(address token, bytes4 sharesOf, bytes4 totalShares) external;
+
     /// Sets an address' code.
     #[cheatcode(group = Evm, safety = Unsafe)]
     function etch(address target, bytes calldata newRuntimeBytecode) external;
@@ -703,6 +746,13 @@ struct PotentialRevert {
     #[cheatcode(group = Evm, safety = Unsafe)]
     function mockFunction(address callee, address target, bytes calldata data) external;
 
+    /// Mocks the EIP-1271 `isValidSignature(bytes32,bytes)` function of `wallet` for the given
+    /// hash, returning the magic value `0x1626ba7e` if `valid` and `0xffffffff` otherwise,
+    /// whatever the signature.
+    /// If `wallet` has no code, a single `STOP` opcode is etched so it passes code size checks.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function setERC1271Result(address wallet, bytes32 hash, bool valid) external;
+
     // --- Impersonation (pranks) ---
 
     /// Sets the *next* call's `msg.sender` to be the input address.
@@ -741,6 +791,20 @@ struct PotentialRevert {
     #[cheatcode(group = Evm, safety = Unsafe)]
     function stopPrank() external;
 
+    /// Sets `msg.sender` of every call made inside the scope, at any call depth, to be the input address until `stopPrankScope` is called.
+    /// Unlike `startPrank`, calls made by the pranked contracts themselves are pranked as well.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function prankScope(address msgSender) external;
+
+    /// Sets `msg.sender` of every call made inside the scope, at any call depth, to be the input address until `stopPrankScope` is called.
+    /// Calls to any of the `excluded` addresses keep their original `msg.sender`.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function prankScope(address msgSender, address[] calldata excluded) external;
+
+    /// Ends the prank scope started with `prankScope`.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function stopPrankScope() external;
+
     /// Reads the current `msg.sender` and `tx.origin` from state and reports if there is any active caller modification.
     #[cheatcode(group = Evm, safety = Unsafe)]
     function readCallers() external view returns (CallerMode callerMode, address msgSender, address txOrigin);
@@ -894,6 +958,12 @@ struct PotentialRevert {
     #[cheatcode(group = Evm, safety = Unsafe)]
     function selectFork(uint256 forkId) external;
 
+    /// Selects the fork of the given chain, creating it on first use, and applies the rules of its
+    /// `[chains.<name>]` config section (fork block, EVM version, gas price and base fee).
+    /// Returns the identifier of the fork.
+    #[cheatcode(group = Evm, safety = Unsafe)]
+    function selectChain(string calldata chain) external returns (uint256 forkId);
+
     /// Fetches the given transaction from the active fork and executes it on the current state.
     #[cheatcode(group = Evm, safety = Unsafe)]
     function transact(bytes32 txHash) external;
@@ -911,6 +981,55 @@ struct PotentialRevert {
         external
         returns (bytes memory data);
 
+    /// Performs an Ethereum JSON-RPC request to the first of the given endpoints that answers it.
+    #[cheatcode(group = Evm, safety = Safe)]
+    function rpc(string[] calldata urlsOrAliases, string calldata method, string calldata params)
+        external
+        returns (bytes memory data);
+
+    /// Performs a batch of Ethereum JSON-RPC requests to the given endpoint in a single round trip.
+    /// Each request is made of a method and its parameters at the same index.
+    #[cheatcode(group = Evm, safety = Safe)]
+    function rpcBatch(string calldata urlOrAlias, string[] calldata methods, string[] calldata params)
+        external
+        returns (bytes[] memory data);
+
+    /// Performs an Ethereum JSON-RPC request to the current fork URL and decodes its hex string result
+    /// as a `bytes32`.
+    #[cheatcode(group = Evm, safety = Safe)]
+    function rpcBytes32(string calldata method, string calldata params) external returns (bytes32 data);
+
+    /// Performs an Ethereum JSON-RPC request to the given endpoint and decodes its hex string result
+    /// as a `bytes32`.
+    #[cheatcode(group = Evm, safety = Safe)]
+    function rpcBytes32(string calldata urlOrAlias, string calldata method, string calldata params)
+        external
+        returns (bytes32 data);
+
+    /// Performs an Ethereum JSON-RPC request to the current fork URL and decodes its quantity result
+    /// as a `uint256`.
+    #[cheatcode(group = Evm, safety = Safe)]
+    function rpcUint(string calldata method, string calldata params) external returns (uint256 data);
+
+    /// Performs an Ethereum JSON-RPC request to the given endpoint and decodes its quantity result
+    /// as a `uint256`.
+    #[cheatcode(group = Evm, safety = Safe)]
+    function rpcUint(string calldata urlOrAlias, string calldata method, string calldata params)
+        external
+        returns (uint256 data);
+
+    /// Performs an Ethereum JSON-RPC request to the current fork URL and decodes its hex string result
+    /// as an `address`.
+    #[cheatcode(group = Evm, safety = Safe)]
+    function rpcAddress(string calldata method, string calldata params) external returns (address data);
+
+    /// Performs an Ethereum JSON-RPC request to the given endpoint and decodes its hex string result
+    /// as an `address`.
+    #[cheatcode(group = Evm, safety = Safe)]
+    function rpcAddress(string calldata urlOrAlias, string calldata method, string calldata params)
+        external
+        returns (address data);
+
     /// Gets all the logs according to specified filter.
     #[cheatcode(group = Evm, safety = Safe)]
     function eth_getLogs(uint256 fromBlock, uint256 toBlock, address target, bytes32[] calldata topics)
@@ -1202,6 +1321,15 @@ struct PotentialRevert {
     #[cheatcode(group = Testing, safety = Unsafe)]
     function expectPartialRevert(bytes4 revertData, address reverter) external;
 
+    /// Expects an error on next call with the selector of the revert data, comparing only the arguments flagged in `checkArgs`.
+    /// Every flag refers to a decoded argument of the error, which must be declared in a contract of the project.
+    #[cheatcode(group = Testing, safety = Unsafe)]
+    function expectPartialRevert(bytes calldata revertData, bool[] calldata checkArgs) external;
+
+    /// Expects an error on next call to reverter address with the selector of the revert data, comparing only the arguments flagged in `checkArgs`.
+    #[cheatcode(group = Testing, safety = Unsafe)]
+    function expectPartialRevert(bytes calldata revertData, bool[] calldata checkArgs, address reverter) external;
+
     /// Expects an error on next cheatcode call with any revert data.
     #[cheatcode(group = Testing, safety = Unsafe, status = Internal)]
     function _expectCheatcodeRevert() external;
@@ -2000,6 +2128,23 @@ struct PotentialRevert {
     #[cheatcode(group = Filesystem)]
     function deployCode(string calldata artifactPath, bytes calldata constructorArgs, uint256 value, bytes32 salt) external returns (address deployedAddress);
 
+    /// Replaces the code of `target` with the runtime code of an artifact, keeping its storage and balance.
+    /// The artifact's constructor is executed in a scratch deployment so immutables are set.
+    /// Takes in the relative path to the json file or the path to the artifact in the form of <path>:<contract>:<version>
+    /// where <contract> and <version> parts are optional.
+    #[cheatcode(group = Filesystem, safety = Unsafe)]
+    function upgradeTo(address target, string calldata artifactPath) external;
+
+    /// Replaces the code of `target` with the runtime code of an artifact, keeping its storage and balance.
+    /// The artifact's constructor is executed with the given ABI-encoded arguments in a scratch deployment so immutables are set.
+    #[cheatcode(group = Filesystem, safety = Unsafe)]
+    function upgradeTo(address target, string calldata artifactPath, bytes calldata constructorArgs) external;
+
+    /// Replaces the code of `target` with the runtime code of an artifact, keeping its storage and balance,
+    /// then calls `target` with `initData`, e.g. to replay an initializer. Reverts if the call reverts.
+    #[cheatcode(group = Filesystem, safety = Unsafe)]
+    function upgradeTo(address target, string calldata artifactPath, bytes calldata constructorArgs, bytes calldata initData) external returns (bytes memory data);
+
     /// Gets the deployed bytecode from an artifact file. Takes in the relative path to the json file or the path to the
     /// artifact in the form of <path>:<contract>:<version> where <contract> and <version> parts are optional.
     #[cheatcode(group = Filesystem)]
@@ -2890,6 +3035,39 @@ struct PotentialRevert {
     #[cheatcode(group = Utilities)]
     function computeCreate2Address(bytes32 salt, bytes32 initCodeHash) external pure returns (address);
 
+    /// Returns the canonical address of a well-known contract on the current chain.
+    /// Supported names are `WETH`, `PERMIT2`, `MULTICALL3` and `CREATE2_DEPLOYER`.
+    /// Reverts if the contract is unknown or has no canonical address on the current chain.
+    #[cheatcode(group = Utilities)]
+    function wellKnown(string calldata name) external view returns (address);
+
+    /// Returns the canonical address of a well-known contract on the given chain.
+    #[cheatcode(group = Utilities)]
+    function wellKnown(string calldata name, uint256 chainId) external pure returns (address);
+
+    /// Returns the address of a contract of a protocol preset on the current chain, e.g.
+    /// `protocolAddress("uniswap-v3", "FACTORY")` or `protocolAddress("stables", "USDC")`.
+    /// Supported presets are `uniswap-v3`, `aave-v3` and `stables`.
+    /// Reverts if the contract is unknown or not deployed on the current chain.
+    #[cheatcode(group = Utilities)]
+    function protocolAddress(string calldata preset, string calldata name) external view returns (address);
+
+    /// Computes the address of the Uniswap v3 pool of the given tokens and fee on the current chain.
+    /// The pool may not be deployed.
+    #[cheatcode(group = Utilities)]
+    function uniswapV3Pool(address tokenA, address tokenB, uint24 fee) external view returns (address pool);
+
+    /// Returns the deployed Uniswap v3 pools of the given tokens on the current chain, in increasing
+    /// fee order.
+    #[cheatcode(group = Utilities)]
+    function uniswapV3Pools(address tokenA, address tokenB) external view returns (address[] memory pools);
+
+    /// Returns the aToken and variable debt token of the given asset in the Aave v3 pool of the
+    /// current chain.
+    /// Reverts if the asset is not listed.
+    #[cheatcode(group = Utilities)]
+    function aaveV3Reserve(address asset) external view returns (address aToken, address variableDebtToken);
+
     /// Encodes a `bytes` value to a base64 string.
     #[cheatcode(group = Utilities)]
     function toBase64(bytes calldata data) external pure returns (string memory);
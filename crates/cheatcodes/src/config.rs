@@ -4,11 +4,12 @@
 use foundry_common::{ContractsByArtifact, fs::normalize_path};
 use foundry_compilers::{ArtifactId, ProjectPathsConfig, utils::canonicalize};
 use foundry_config::{
-    Config, FsPermissions, ResolvedRpcEndpoint, ResolvedRpcEndpoints, RpcEndpoint, RpcEndpointUrl,
-    cache::StorageCachingConfig, fs_permissions::FsAccessKind,
+    ChainSpecConfig, Config, FsPermissions, ResolvedRpcEndpoint, ResolvedRpcEndpoints, RpcEndpoint,
+    RpcEndpointUrl, cache::StorageCachingConfig, fs_permissions::FsAccessKind,
 };
 use foundry_evm_core::opts::EvmOpts;
 use std::{
+    collections::BTreeMap,
     path::{Path, PathBuf},
     time::Duration,
 };
@@ -30,12 +31,18 @@ pub struct CheatsConfig {
     pub no_storage_caching: bool,
     /// All known endpoints and their aliases
     pub rpc_endpoints: ResolvedRpcEndpoints,
+    /// The chains `vm.selectChain` can switch to, keyed by name
+    pub chains: BTreeMap<String, ChainSpecConfig>,
     /// Project's paths as configured
     pub paths: ProjectPathsConfig,
     /// Path to the directory that contains the bindings generated by `forge bind-json`.
     pub bind_json_path: PathBuf,
     /// Filesystem permissions for cheatcodes like `writeFile`, `readFile`
     pub fs_permissions: FsPermissions,
+    /// Absolute paths of the sources allowed to call each restricted cheatcode.
+    pub cheatcode_permissions: BTreeMap<String, Vec<PathBuf>>,
+    /// Whether to record the contracts invoking each cheatcode.
+    pub cheatcode_audit: bool,
     /// Project root
     pub root: PathBuf,
     /// Absolute Path to broadcast dir i.e project_root/broadcast
@@ -52,6 +59,9 @@ pub struct CheatsConfig {
     pub running_artifact: Option<ArtifactId>,
     /// Whether to enable legacy (non-reverting) assertions.
     pub assertions_revert: bool,
+    /// Whether the calls to assertion cheatcodes are not metered. The code of the calling frame,
+    /// e.g. forge-std's assertion wrappers, is still metered.
+    pub gas_free_assertions: bool,
     /// Optional seed for the RNG algorithm.
     pub seed: Option<U256>,
     /// Whether to allow `expectRevert` to work for internal calls.
@@ -83,9 +93,18 @@ pub fn new(
             rpc_storage_caching: config.rpc_storage_caching.clone(),
             no_storage_caching: config.no_storage_caching,
             rpc_endpoints,
+            chains: config.chains.clone(),
             paths: config.project_paths(),
             bind_json_path: config.bind_json.out.clone(),
             fs_permissions: config.fs_permissions.clone().joined(config.root.as_ref()),
+            cheatcode_permissions: config
+                .cheatcode_permissions
+                .iter()
+                .map(|(name, paths)| {
+                    (name.clone(), paths.iter().map(|path| config.root.join(path)).collect())
+                })
+                .collect(),
+            cheatcode_audit: false,
             root: config.root.clone(),
             broadcast: config.root.clone().join(&config.broadcast),
             evm_opts,
@@ -93,6 +112,7 @@ pub fn new(
             available_artifacts,
             running_artifact,
             assertions_revert: config.assertions_revert,
+            gas_free_assertions: config.gas_free_assertions,
             seed: config.fuzz.seed,
             internal_expect_revert: config.allow_internal_expect_revert,
             fee_token,
@@ -101,13 +121,16 @@ pub fn new(
 
     /// Returns a new `CheatsConfig` configured with the given `Config` and `EvmOpts`.
     pub fn clone_with(&self, config: &Config, evm_opts: EvmOpts) -> Self {
-        Self::new(
-            config,
-            evm_opts,
-            self.available_artifacts.clone(),
-            self.running_artifact.clone(),
-            self.fee_token,
-        )
+        Self {
+            cheatcode_audit: self.cheatcode_audit,
+            ..Self::new(
+                config,
+                evm_opts,
+                self.available_artifacts.clone(),
+                self.running_artifact.clone(),
+                self.fee_token,
+            )
+        }
     }
 
     /// Attempts to canonicalize (see [std::fs::canonicalize]) the path.
@@ -150,6 +173,29 @@ pub fn ensure_path_allowed(
         Ok(normalized)
     }
 
+    /// Returns `true` if `cheatcode` may only be called from the configured paths.
+    pub fn is_cheatcode_restricted(&self, cheatcode: &str) -> bool {
+        self.cheatcode_permissions.contains_key(cheatcode)
+    }
+
+    /// Returns an error if `cheatcode` is restricted and `source`, the source file of the calling
+    /// contract, is not inside any of the paths allowed to call it.
+    ///
+    /// Calls from contracts that can't be matched to an artifact are rejected.
+    pub fn ensure_cheatcode_allowed(&self, cheatcode: &str, source: Option<&Path>) -> Result<()> {
+        let Some(allowed) = self.cheatcode_permissions.get(cheatcode) else { return Ok(()) };
+        let Some(source) = source else {
+            bail!("cannot be called from an unknown contract, see `cheatcode_permissions`");
+        };
+        let source = self.root.join(source);
+        ensure!(
+            allowed.iter().any(|path| source.starts_with(path)),
+            "cannot be called from {}, see `cheatcode_permissions`",
+            source.strip_prefix(&self.root).unwrap_or(&source).display()
+        );
+        Ok(())
+    }
+
     /// Returns true if the given `path` is the project's foundry.toml file
     ///
     /// Note: this should be called with normalized path
@@ -223,8 +269,11 @@ fn default() -> Self {
             rpc_storage_caching: Default::default(),
             no_storage_caching: false,
             rpc_endpoints: Default::default(),
+            chains: Default::default(),
             paths: ProjectPathsConfig::builder().build_with_root("./"),
             fs_permissions: Default::default(),
+            cheatcode_permissions: Default::default(),
+            cheatcode_audit: false,
             root: Default::default(),
             bind_json_path: PathBuf::default().join("utils").join("jsonBindings.sol"),
             broadcast: Default::default(),
@@ -233,6 +282,7 @@ fn default() -> Self {
             available_artifacts: Default::default(),
             running_artifact: Default::default(),
             assertions_revert: true,
+            gas_free_assertions: false,
             seed: None,
             internal_expect_revert: false,
             fee_token: None,
@@ -282,4 +332,30 @@ fn test_is_foundry_toml() {
         let f = format!("{root}lib/other/foundry.toml");
         assert!(!config.is_foundry_toml(f));
     }
+
+    #[test]
+    fn test_cheatcode_permissions() {
+        let root = "/my/project/root/";
+        let config = CheatsConfig::new(
+            &Config {
+                root: root.into(),
+                cheatcode_permissions: [("ffi".to_string(), vec![PathBuf::from("test/ffi")])]
+                    .into(),
+                ..Default::default()
+            },
+            Default::default(),
+            None,
+            None,
+            None,
+        );
+
+        assert!(config.is_cheatcode_restricted("ffi"));
+        assert!(!config.is_cheatcode_restricted("etch"));
+        assert!(
+            config.ensure_cheatcode_allowed("ffi", Some(Path::new("test/ffi/Ffi.t.sol"))).is_ok()
+        );
+        assert!(config.ensure_cheatcode_allowed("ffi", Some(Path::new("test/Ffi.t.sol"))).is_err());
+        assert!(config.ensure_cheatcode_allowed("ffi", None).is_err());
+        assert!(config.ensure_cheatcode_allowed("etch", None).is_ok());
+    }
 }
@@ -13,16 +13,19 @@
     map::{B256Map, HashMap},
 };
 use alloy_rlp::Decodable;
+use alloy_signer_local::PrivateKeySigner;
 use alloy_sol_types::SolValue;
 use foundry_common::{
     TransactionMaybeSigned,
     fs::{read_json_file, write_json_file},
+    named_accounts::NamedAccountsConfig,
     slot_identifier::{
         ENCODING_BYTES, ENCODING_DYN_ARRAY, ENCODING_INPLACE, ENCODING_MAPPING, SlotIdentifier,
         SlotInfo,
     },
 };
 use foundry_compilers::artifacts::EvmVersion;
+use foundry_config::fs_permissions::FsAccessKind;
 use foundry_evm_core::{
     FoundryBlock, FoundryTransaction,
     backend::{DatabaseError, DatabaseExt, RevertStateSnapshotAction},
@@ -59,6 +62,8 @@
 pub(crate) mod mapping;
 pub(crate) mod mock;
 pub(crate) mod prank;
+mod presets;
+pub(crate) mod token;
 
 /// JSON-serializable log entry for `getRecordedLogsJson`.
 #[derive(Serialize)]
@@ -335,6 +340,43 @@ fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FE
     }
 }
 
+impl Cheatcode for loadNamedAccountsCall {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self { path } = self;
+        let path = ccx.state.config.ensure_path_allowed(path, FsAccessKind::Read)?;
+        let accounts = NamedAccountsConfig::load(&path)?.resolve()?;
+
+        let mut addresses = Vec::with_capacity(accounts.len());
+        for account in accounts {
+            let address = account.address;
+            ccx.ensure_not_precompile(&address)?;
+            journaled_account(ccx.ecx, address)?.info.balance = account.balance;
+            if let Some(code) = account.code {
+                let bytecode = Bytecode::new_raw_checked(code)
+                    .map_err(|e| fmt_err!("failed to create bytecode: {e}"))?;
+                ccx.ecx.journal_mut().set_code(address, bytecode);
+            }
+            if let Some(private_key) = account.private_key {
+                ccx.state.wallets().add_local_signer(PrivateKeySigner::from_bytes(&private_key)?);
+            }
+            ccx.state.labels.insert(address, account.name.clone());
+            ccx.state.named_accounts.insert(account.name, address);
+            addresses.push(address);
+        }
+        Ok(addresses.abi_encode())
+    }
+}
+
+impl Cheatcode for namedAccountCall {
+    fn apply<FEN: FoundryEvmNetwork>(&self, state: &mut Cheatcodes<FEN>) -> Result {
+        let Self { name } = self;
+        let Some(address) = state.named_accounts.get(name) else {
+            bail!("unknown named account `{name}`, load it with `vm.loadNamedAccounts`");
+        };
+        Ok(address.abi_encode())
+    }
+}
+
 impl Cheatcode for cloneAccountCall {
     fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
         let Self { source, target } = self;
@@ -574,6 +616,9 @@ impl Cheatcode for rollCall {
     fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
         let Self { newHeight } = self;
         ccx.ecx.block_mut().set_number(*newHeight);
+        if let Some(prevrandao) = ccx.state.block_prevrandaos.get(newHeight) {
+            ccx.ecx.block_mut().set_prevrandao(Some(*prevrandao));
+        }
         Ok(Default::default())
     }
 }
@@ -627,6 +672,21 @@ fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FE
     }
 }
 
+impl Cheatcode for setBlobBaseFeeCall {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self { newBlobBaseFee } = self;
+        ensure!(
+            (*ccx.ecx.cfg().spec()).into() >= SpecId::CANCUN,
+            "`setBlobBaseFee` is not supported before the Cancun hard fork; \
+             see EIP-4844: https://eips.ethereum.org/EIPS/eip-4844"
+        );
+        ensure!(*newBlobBaseFee <= U256::from(u128::MAX), "blob base fee must be less than 2^128");
+
+        ccx.ecx.block_mut().set_blob_gasprice(newBlobBaseFee.to());
+        Ok(Default::default())
+    }
+}
+
 impl Cheatcode for getBlobBaseFeeCall {
     fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
         let Self {} = self;
@@ -1093,6 +1153,42 @@ fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FE
     }
 }
 
+impl Cheatcode for setBlockhashesCall {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self { fromBlock, blockHashes } = self;
+        // The block following the last one.
+        let end = fromBlock.saturating_add(U256::from(blockHashes.len()));
+        ensure!(end <= U256::from(u64::MAX), "block numbers must be less than 2^64");
+        ensure!(
+            blockHashes.is_empty() || end <= U256::from(ccx.ecx.block().number()) + U256::ONE,
+            "block numbers must be less than or equal to the current block number"
+        );
+
+        for (number, hash) in (fromBlock.to::<u64>()..).zip(blockHashes) {
+            ccx.ecx.db_mut().set_blockhash(U256::from(number), *hash);
+        }
+
+        Ok(Default::default())
+    }
+}
+
+impl Cheatcode for setPrevrandaoCall {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self { blockNumber, newPrevrandao } = *self;
+        ensure!(
+            (*ccx.ecx.cfg().spec()).into() >= SpecId::MERGE,
+            "`setPrevrandao` is not supported before the Paris hard fork, use `difficulty` instead; \
+             see EIP-4399: https://eips.ethereum.org/EIPS/eip-4399"
+        );
+
+        if blockNumber == U256::from(ccx.ecx.block().number()) {
+            ccx.ecx.block_mut().set_prevrandao(Some(newPrevrandao));
+        }
+        ccx.state.block_prevrandaos.insert(blockNumber, newPrevrandao);
+        Ok(Default::default())
+    }
+}
+
 impl Cheatcode for executeTransactionCall {
     fn apply_full<FEN: FoundryEvmNetwork>(
         &self,
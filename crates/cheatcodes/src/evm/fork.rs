@@ -5,13 +5,15 @@
 use alloy_dyn_abi::DynSolValue;
 use alloy_evm::EvmEnv;
 use alloy_network::AnyNetwork;
-use alloy_primitives::{B256, U256};
+use alloy_primitives::{Address, B256, Bytes, U256};
 use alloy_provider::Provider;
 use alloy_rpc_types::Filter;
 use alloy_sol_types::SolValue;
 use foundry_common::provider::ProviderBuilder;
+use foundry_config::evm_spec_id;
 use foundry_evm_core::{
-    FoundryContextExt, backend::JournaledState, evm::FoundryEvmNetwork, fork::CreateFork,
+    FoundryBlock, FoundryContextExt, FoundryTransaction, backend::JournaledState,
+    evm::FoundryEvmNetwork, fork::CreateFork,
 };
 use revm::context::ContextTr;
 
@@ -119,6 +121,42 @@ fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FE
     }
 }
 
+impl Cheatcode for selectChainCall {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self { chain } = self;
+        check_broadcast(ccx.state)?;
+
+        let spec = ccx.state.config.chains.get(chain).cloned().unwrap_or_default();
+        let fork_id = match ccx.state.chain_forks.get(chain) {
+            Some(&id) => {
+                persist_caller(ccx);
+                id
+            }
+            None => {
+                let fork = create_fork_request(ccx, spec.rpc(chain), spec.fork_block_number)?;
+                let id = ccx.ecx.db_mut().create_fork(fork)?;
+                ccx.state.chain_forks.insert(chain.clone(), id);
+                id
+            }
+        };
+        fork_env_op(ccx.ecx, |db, evm_env, tx_env, inner| {
+            db.select_fork(fork_id, evm_env, tx_env, inner)
+        })?;
+
+        // Rules which aren't configured for the chain are left as is.
+        if let Some(evm_version) = spec.evm_version {
+            ccx.state.execution_evm_version = Some(evm_spec_id(evm_version));
+        }
+        if let Some(gas_price) = spec.gas_price {
+            ccx.ecx.tx_mut().set_gas_price(gas_price.into());
+        }
+        if let Some(base_fee) = spec.block_base_fee_per_gas {
+            ccx.ecx.block_mut().set_basefee(base_fee);
+        }
+        Ok(fork_id.abi_encode())
+    }
+}
+
 impl Cheatcode for transact_0Call {
     fn apply_full<FEN: FoundryEvmNetwork>(
         &self,
@@ -214,8 +252,7 @@ fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FE
 impl Cheatcode for rpc_0Call {
     fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
         let Self { method, params } = self;
-        let url =
-            ccx.ecx.db().active_fork_url().ok_or_else(|| fmt_err!("no active fork URL found"))?;
+        let url = active_fork_url(ccx)?;
         rpc_call(&url, method, params)
     }
 }
@@ -228,6 +265,117 @@ fn apply<FEN: FoundryEvmNetwork>(&self, state: &mut Cheatcodes<FEN>) -> Result {
     }
 }
 
+impl Cheatcode for rpc_2Call {
+    fn apply<FEN: FoundryEvmNetwork>(&self, state: &mut Cheatcodes<FEN>) -> Result {
+        let Self { urlsOrAliases, method, params } = self;
+        if urlsOrAliases.is_empty() {
+            bail!("no endpoint given");
+        }
+        let mut errors = Vec::with_capacity(urlsOrAliases.len());
+        for url_or_alias in urlsOrAliases {
+            let result = state
+                .config
+                .rpc_endpoint(url_or_alias)
+                .and_then(|endpoint| Ok(endpoint.url()?))
+                .and_then(|url| rpc_call(&url, method, params));
+            match result {
+                Ok(data) => return Ok(data),
+                Err(err) => errors.push(format!("{url_or_alias}: {err}")),
+            }
+        }
+        bail!("every endpoint failed:\n{}", errors.join("\n"))
+    }
+}
+
+impl Cheatcode for rpcBatchCall {
+    fn apply<FEN: FoundryEvmNetwork>(&self, state: &mut Cheatcodes<FEN>) -> Result {
+        let Self { urlOrAlias, methods, params } = self;
+        if methods.len() != params.len() {
+            bail!(
+                "methods and params must have the same length: {} != {}",
+                methods.len(),
+                params.len()
+            );
+        }
+        let url = state.config.rpc_endpoint(urlOrAlias)?.url()?;
+        let provider = ProviderBuilder::<AnyNetwork>::new(&url).build()?;
+        let client = provider.client();
+        let mut batch = client.new_batch();
+        let waiters = methods
+            .iter()
+            .zip(params)
+            .map(|(method, params)| {
+                let params: serde_json::Value = serde_json::from_str(params)?;
+                let waiter = batch
+                    .add_call::<_, serde_json::Value>(method.clone(), &params)
+                    .map_err(|err| fmt_err!("{method:?}: {err}"))?;
+                Ok((method, waiter))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let results = foundry_common::block_on(async move {
+            batch.send().await.map_err(|err| fmt_err!("failed to send batch: {err}"))?;
+            let mut results = Vec::with_capacity(waiters.len());
+            for (method, waiter) in waiters {
+                results.push(waiter.await.map_err(|err| fmt_err!("{method:?}: {err}"))?);
+            }
+            Ok::<_, crate::Error>(results)
+        })?;
+        let data = results
+            .iter()
+            .map(|result| encode_rpc_result(result).map(Bytes::from))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(data.abi_encode())
+    }
+}
+
+impl Cheatcode for rpcBytes32_0Call {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self { method, params } = self;
+        let url = active_fork_url(ccx)?;
+        rpc_bytes32(&url, method, params)
+    }
+}
+
+impl Cheatcode for rpcBytes32_1Call {
+    fn apply<FEN: FoundryEvmNetwork>(&self, state: &mut Cheatcodes<FEN>) -> Result {
+        let Self { urlOrAlias, method, params } = self;
+        let url = state.config.rpc_endpoint(urlOrAlias)?.url()?;
+        rpc_bytes32(&url, method, params)
+    }
+}
+
+impl Cheatcode for rpcUint_0Call {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self { method, params } = self;
+        let url = active_fork_url(ccx)?;
+        rpc_uint(&url, method, params)
+    }
+}
+
+impl Cheatcode for rpcUint_1Call {
+    fn apply<FEN: FoundryEvmNetwork>(&self, state: &mut Cheatcodes<FEN>) -> Result {
+        let Self { urlOrAlias, method, params } = self;
+        let url = state.config.rpc_endpoint(urlOrAlias)?.url()?;
+        rpc_uint(&url, method, params)
+    }
+}
+
+impl Cheatcode for rpcAddress_0Call {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self { method, params } = self;
+        let url = active_fork_url(ccx)?;
+        rpc_address(&url, method, params)
+    }
+}
+
+impl Cheatcode for rpcAddress_1Call {
+    fn apply<FEN: FoundryEvmNetwork>(&self, state: &mut Cheatcodes<FEN>) -> Result {
+        let Self { urlOrAlias, method, params } = self;
+        let url = state.config.rpc_endpoint(urlOrAlias)?.url()?;
+        rpc_address(&url, method, params)
+    }
+}
+
 impl Cheatcode for eth_getLogsCall {
     fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
         let Self { fromBlock, toBlock, target, topics } = self;
@@ -415,23 +563,71 @@ fn persist_caller<FEN: FoundryEvmNetwork>(ccx: &mut CheatsCtxt<'_, '_, FEN>) {
     ccx.ecx.db_mut().add_persistent_account(ccx.caller);
 }
 
-/// Performs an Ethereum JSON-RPC request to the given endpoint.
-fn rpc_call(url: &str, method: &str, params: &str) -> Result {
+/// Returns the URL of the active fork.
+fn active_fork_url<FEN: FoundryEvmNetwork>(ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result<String> {
+    ccx.ecx.db().active_fork_url().ok_or_else(|| fmt_err!("no active fork URL found"))
+}
+
+/// Performs an Ethereum JSON-RPC request to the given endpoint and returns its raw JSON result.
+fn rpc_request(url: &str, method: &str, params: &str) -> Result<serde_json::Value> {
     let provider = ProviderBuilder::<AnyNetwork>::new(url).build()?;
     let params_json: serde_json::Value = serde_json::from_str(params)?;
-    let result =
-        foundry_common::block_on(provider.raw_request(method.to_string().into(), params_json))
-            .map_err(|err| fmt_err!("{method:?}: {err}"))?;
+    foundry_common::block_on(provider.raw_request(method.to_string().into(), params_json))
+        .map_err(|err| fmt_err!("{method:?}: {err}"))
+}
+
+/// Performs an Ethereum JSON-RPC request to the given endpoint.
+fn rpc_call(url: &str, method: &str, params: &str) -> Result {
+    let result = rpc_request(url, method, params)?;
+    Ok(DynSolValue::Bytes(encode_rpc_result(&result)?).abi_encode())
+}
+
+/// Performs an Ethereum JSON-RPC request to the given endpoint, decoding its result as a `bytes32`.
+fn rpc_bytes32(url: &str, method: &str, params: &str) -> Result {
+    let result = rpc_request(url, method, params)?;
+    let value = result
+        .as_str()
+        .and_then(|s| s.parse::<B256>().ok())
+        .ok_or_else(|| fmt_err!("{method:?}: result is not a 32 bytes hex string: {result}"))?;
+    Ok(value.abi_encode())
+}
+
+/// Performs an Ethereum JSON-RPC request to the given endpoint, decoding its result as a `uint256`.
+///
+/// Both hex quantities and decimal numbers are accepted.
+fn rpc_uint(url: &str, method: &str, params: &str) -> Result {
+    let result = rpc_request(url, method, params)?;
+    let value = match &result {
+        serde_json::Value::String(s) => s.parse::<U256>().ok(),
+        serde_json::Value::Number(n) => n.as_u64().map(U256::from),
+        _ => None,
+    }
+    .ok_or_else(|| fmt_err!("{method:?}: result is not a quantity: {result}"))?;
+    Ok(value.abi_encode())
+}
+
+/// Performs an Ethereum JSON-RPC request to the given endpoint, decoding its result as an
+/// `address`.
+fn rpc_address(url: &str, method: &str, params: &str) -> Result {
+    let result = rpc_request(url, method, params)?;
+    let value = result
+        .as_str()
+        .and_then(|s| s.parse::<Address>().ok())
+        .ok_or_else(|| fmt_err!("{method:?}: result is not an address: {result}"))?;
+    Ok(value.abi_encode())
+}
+
+/// ABI-encodes the JSON result of an Ethereum JSON-RPC request, returning raw bytes as is.
+fn encode_rpc_result(result: &serde_json::Value) -> Result<Vec<u8>> {
     let result_as_tokens = convert_to_bytes(
-        &json_value_to_token(&result, None)
+        &json_value_to_token(result, None)
             .map_err(|err| fmt_err!("failed to parse result: {err}"))?,
     );
 
-    let payload = match &result_as_tokens {
+    Ok(match &result_as_tokens {
         DynSolValue::Bytes(b) => b.clone(),
         _ => result_as_tokens.abi_encode(),
-    };
-    Ok(DynSolValue::Bytes(payload).abi_encode())
+    })
 }
 
 /// Convert fixed bytes and address values to bytes in order to prevent encoding issues.
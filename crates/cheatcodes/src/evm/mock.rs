@@ -1,5 +1,7 @@
 use crate::{Cheatcode, Cheatcodes, CheatsCtxt, Result, Vm::*};
 use alloy_primitives::{Address, Bytes, U256};
+use alloy_sol_types::SolValue;
+use foundry_common::{ERC1271_INVALID_VALUE, ERC1271_MAGIC_VALUE};
 use foundry_evm_core::evm::FoundryEvmNetwork;
 use revm::{
     bytecode::Bytecode,
@@ -188,6 +190,26 @@ fn apply<FEN: FoundryEvmNetwork>(&self, state: &mut Cheatcodes<FEN>) -> Result {
     }
 }
 
+impl Cheatcode for setERC1271ResultCall {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self { wallet, hash, valid } = self;
+        let _ = make_acc_non_empty(wallet, ccx)?;
+
+        // Match any signature of the hash.
+        let calldata = [ERC1271_MAGIC_VALUE.as_slice(), hash.as_slice()].concat();
+        let result = if *valid { ERC1271_MAGIC_VALUE } else { ERC1271_INVALID_VALUE };
+        mock_call(
+            ccx.state,
+            wallet,
+            &calldata.into(),
+            None,
+            &result.abi_encode().into(),
+            InstructionResult::Return,
+        );
+        Ok(Default::default())
+    }
+}
+
 fn mock_call<FEN: FoundryEvmNetwork>(
     state: &mut Cheatcodes<FEN>,
     callee: &Address,
@@ -54,6 +54,24 @@ pub const fn first_time_applied(&self) -> Option<Self> {
     }
 }
 
+/// Prank scope information, see `vm.prankScope`.
+#[derive(Clone, Debug, Default)]
+pub struct PrankScope {
+    /// The address to assign to `msg.sender`
+    pub new_caller: Address,
+    /// The call targets that keep their original `msg.sender`
+    pub excluded: Vec<Address>,
+    /// The depth at which the scope was started
+    pub depth: usize,
+}
+
+impl PrankScope {
+    /// Returns `true` if a call to `target` at `depth` is pranked by the scope.
+    pub fn applies_to(&self, target: &Address, depth: usize) -> bool {
+        depth >= self.depth && !self.excluded.contains(target)
+    }
+}
+
 impl Cheatcode for prank_0Call {
     fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
         let Self { msgSender } = self;
@@ -118,6 +136,28 @@ fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FE
     }
 }
 
+impl Cheatcode for prankScope_0Call {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self { msgSender } = self;
+        prank_scope(ccx, msgSender, vec![])
+    }
+}
+
+impl Cheatcode for prankScope_1Call {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self { msgSender, excluded } = self;
+        prank_scope(ccx, msgSender, excluded.clone())
+    }
+}
+
+impl Cheatcode for stopPrankScopeCall {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self {} = self;
+        ensure!(ccx.state.prank_scope.take().is_some(), "no prank scope in progress to stop");
+        Ok(Default::default())
+    }
+}
+
 fn prank<FEN: FoundryEvmNetwork>(
     ccx: &mut CheatsCtxt<'_, '_, FEN>,
     new_caller: &Address,
@@ -138,6 +178,11 @@ fn prank<FEN: FoundryEvmNetwork>(
         );
     }
 
+    ensure!(
+        ccx.state.prank_scope.is_none(),
+        "cannot `prank` inside a prank scope; call `stopPrankScope` first"
+    );
+
     let depth = ccx.ecx.journal().depth();
     if let Some(Prank { used, single_call: current_single_call, .. }) = ccx.state.get_prank(depth) {
         ensure!(used, "cannot overwrite a prank until it is applied at least once");
@@ -169,3 +214,26 @@ fn prank<FEN: FoundryEvmNetwork>(
     ccx.state.pranks.insert(prank.depth, prank);
     Ok(Default::default())
 }
+
+fn prank_scope<FEN: FoundryEvmNetwork>(
+    ccx: &mut CheatsCtxt<'_, '_, FEN>,
+    new_caller: &Address,
+    excluded: Vec<Address>,
+) -> Result {
+    ensure!(ccx.state.prank_scope.is_none(), "a prank scope is already in progress");
+    ensure!(
+        ccx.state.pranks.is_empty(),
+        "cannot start a prank scope while a prank is active; call `stopPrank` first"
+    );
+    ensure!(
+        ccx.state.broadcast.is_none(),
+        "cannot start a prank scope for a broadcasted transaction"
+    );
+
+    // Ensure that we load the account of the pranked address and mark it as touched.
+    journaled_account(ccx.ecx, *new_caller)?;
+
+    let depth = ccx.ecx.journal().depth();
+    ccx.state.prank_scope = Some(PrankScope { new_caller: *new_caller, excluded, depth });
+    Ok(Default::default())
+}
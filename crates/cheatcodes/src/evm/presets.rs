@@ -0,0 +1,101 @@
+//! Implementations of the protocol preset cheatcodes, discovering pools and markets of well-known
+//! protocols.
+
+use crate::{
+    Cheatcode, CheatcodesExecutor, CheatsCtxt, Result, Vm::*, evm::ensure_loaded_account,
+    inspector::exec_call,
+};
+use alloy_primitives::{Address, B256, Bytes, Selector, U256, fixed_bytes};
+use alloy_sol_types::SolValue;
+use foundry_common::presets::{self, Preset};
+use foundry_evm_core::evm::FoundryEvmNetwork;
+use revm::{
+    context::{Cfg, ContextTr, JournalTr},
+    interpreter::{CallInput, CallInputs, CallScheme, CallValue},
+};
+
+/// `getReserveData(address)`
+const GET_RESERVE_DATA: Selector = fixed_bytes!("35ea6a75");
+
+impl Cheatcode for protocolAddressCall {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self { preset, name } = self;
+        let chain_id = ccx.ecx.cfg().chain_id();
+        let preset = preset.parse::<Preset>().map_err(|e| fmt_err!("{e}"))?;
+        Ok(preset_address(preset, name, chain_id)?.abi_encode())
+    }
+}
+
+impl Cheatcode for uniswapV3PoolCall {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self { tokenA, tokenB, fee } = *self;
+        let factory = preset_address(Preset::UniswapV3, "FACTORY", ccx.ecx.cfg().chain_id())?;
+        Ok(presets::uniswap_v3_pool(factory, tokenA, tokenB, fee.to()).abi_encode())
+    }
+}
+
+impl Cheatcode for uniswapV3PoolsCall {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self { tokenA, tokenB } = *self;
+        let factory = preset_address(Preset::UniswapV3, "FACTORY", ccx.ecx.cfg().chain_id())?;
+        let mut pools = Vec::new();
+        for fee in presets::UNISWAP_V3_FEES {
+            let pool = presets::uniswap_v3_pool(factory, tokenA, tokenB, fee);
+            let account = ccx.ecx.journal_mut().load_account_with_code(pool)?;
+            if account.data.info.code.as_ref().is_some_and(|code| !code.is_empty()) {
+                pools.push(pool);
+            }
+        }
+        Ok(pools.abi_encode())
+    }
+}
+
+impl Cheatcode for aaveV3ReserveCall {
+    fn apply_full<FEN: FoundryEvmNetwork>(
+        &self,
+        ccx: &mut CheatsCtxt<'_, '_, FEN>,
+        executor: &mut dyn CheatcodesExecutor<FEN>,
+    ) -> Result {
+        let Self { asset } = *self;
+        let pool = preset_address(Preset::AaveV3, "POOL", ccx.ecx.cfg().chain_id())?;
+        ensure_loaded_account(ccx.ecx, pool)?;
+
+        let mut calldata = GET_RESERVE_DATA.to_vec();
+        calldata.extend_from_slice(&asset.abi_encode());
+        let code = ccx.ecx.journal_mut().load_account_with_code(pool)?.data.info.code.clone();
+        let code = code.unwrap_or_default();
+        let outcome = exec_call(
+            executor,
+            CallInputs {
+                caller: ccx.caller,
+                bytecode_address: pool,
+                known_bytecode: (code.hash_slow(), code),
+                target_address: pool,
+                scheme: CallScheme::StaticCall,
+                value: CallValue::Transfer(U256::ZERO),
+                input: CallInput::Bytes(Bytes::from(calldata)),
+                gas_limit: ccx.gas_limit,
+                reservoir: 0,
+                is_static: true,
+                return_memory_offset: 0..0,
+            },
+            ccx,
+        )?;
+        ensure!(outcome.result.result.is_ok(), "failed to read the reserve data of {asset}");
+
+        // The reserve data is a static struct, with the aToken as its 9th field and the variable
+        // debt token as its 11th.
+        let output = &outcome.result.output;
+        ensure!(output.len() >= 11 * 32, "invalid reserve data returned for {asset}");
+        let word = |index: usize| Address::from_word(B256::from_slice(&output[index * 32..][..32]));
+        let (a_token, variable_debt_token) = (word(8), word(10));
+        ensure!(!a_token.is_zero(), "{asset} is not listed in the Aave v3 pool");
+        Ok((a_token, variable_debt_token).abi_encode_params())
+    }
+}
+
+fn preset_address(preset: Preset, name: &str, chain_id: u64) -> Result<Address> {
+    preset
+        .address(name, chain_id)
+        .ok_or_else(|| fmt_err!("no {name} contract for the {preset} preset on chain {chain_id}"))
+}
@@ -0,0 +1,260 @@
+//! Implementations of the `dealToken` cheatcodes, setting ERC-20 balances through storage.
+
+use crate::{
+    Cheatcode, CheatcodesExecutor, CheatsCtxt, Result, Vm::*, evm::ensure_loaded_account,
+    inspector::exec_call,
+};
+use alloy_primitives::{Address, Bytes, Selector, U256, address, fixed_bytes};
+use alloy_sol_types::SolValue;
+use foundry_evm_core::evm::FoundryEvmNetwork;
+use revm::{
+    context::{Cfg, ContextTr, JournalTr},
+    interpreter::{CallInput, CallInputs, CallScheme, CallValue},
+};
+
+/// `balanceOf(address)`
+const BALANCE_OF: Selector = fixed_bytes!("70a08231");
+/// `totalSupply()`
+const TOTAL_SUPPLY: Selector = fixed_bytes!("18160ddd");
+
+/// How a token stores its balances.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenDealStrategy {
+    /// Balances are stored as is, possibly packed with other data in the same slot.
+    ///
+    /// This also covers fee-on-transfer tokens, as dealing never transfers.
+    Balance,
+    /// Balances are stored as shares of the total supply, e.g. stETH or Aave aTokens.
+    Shares {
+        /// Selector of the `(address) -> uint256` shares getter.
+        shares_of: Selector,
+        /// Selector of the `() -> uint256` total shares getter.
+        total_shares: Selector,
+    },
+}
+
+/// stETH: `sharesOf(address)`, `getTotalShares()`.
+const LIDO_SHARES: TokenDealStrategy = TokenDealStrategy::Shares {
+    shares_of: fixed_bytes!("f5eb42dc"),
+    total_shares: fixed_bytes!("d5002f2e"),
+};
+
+/// Aave aTokens: `scaledBalanceOf(address)`, `scaledTotalSupply()`.
+const AAVE_SCALED: TokenDealStrategy = TokenDealStrategy::Shares {
+    shares_of: fixed_bytes!("1da24f3e"),
+    total_shares: fixed_bytes!("b1bf962d"),
+};
+
+/// Ethereum mainnet tokens with a non-standard balance layout.
+const MAINNET_TOKENS: &[(Address, TokenDealStrategy)] = &[
+    // stETH
+    (address!("0xae7ab96520DE3A18E5e111B5EaAb095312D7fE84"), LIDO_SHARES),
+    // aEthWETH
+    (address!("0x4d5F47FA6A74757f35C14fD3a6Ef8E3C9BC514E8"), AAVE_SCALED),
+    // aEthUSDC
+    (address!("0x98C23E9d8f34FEFb1B7BD6a91B7FF122F4e16F5c"), AAVE_SCALED),
+    // aEthUSDT
+    (address!("0x23878914EFE38d27C4D67Ab83ed1b93A74D4086a"), AAVE_SCALED),
+];
+
+impl Cheatcode for dealTokenCall {
+    fn apply_full<FEN: FoundryEvmNetwork>(
+        &self,
+        ccx: &mut CheatsCtxt<'_, '_, FEN>,
+        executor: &mut dyn CheatcodesExecutor<FEN>,
+    ) -> Result {
+        let Self { token, account, newBalance } = *self;
+        ccx.ensure_not_precompile(&token)?;
+        ensure_loaded_account(ccx.ecx, token)?;
+
+        let (getter, value) = match token_strategy(ccx, token) {
+            TokenDealStrategy::Balance => (BALANCE_OF, newBalance),
+            TokenDealStrategy::Shares { shares_of, total_shares } => {
+                let total_shares = call_getter(ccx, executor, token, total_shares, None)?
+                    .ok_or_else(|| fmt_err!("failed to read the total shares of {token}"))?;
+                let total_supply = call_getter(ccx, executor, token, TOTAL_SUPPLY, None)?
+                    .ok_or_else(|| fmt_err!("failed to read the total supply of {token}"))?;
+                let shares = if total_supply.is_zero() {
+                    newBalance
+                } else {
+                    newBalance
+                        .checked_mul(total_shares)
+                        .ok_or_else(|| fmt_err!("balance overflows when converted to shares"))?
+                        / total_supply
+                };
+                (shares_of, shares)
+            }
+        };
+
+        let (slot, mask, offset) = find_balance_slot(ccx, executor, token, getter, account)?
+            .ok_or_else(|| {
+                fmt_err!(
+                    "could not find the balance slot of {account} in {token}; \
+                     if the token stores balances as shares, register it with \
+                     `setTokenDealStrategy`"
+                )
+            })?;
+        ensure!(
+            value <= mask,
+            "balance does not fit in the {} bits of the balance slot",
+            mask.bit_len()
+        );
+
+        let old = sload(ccx, token, slot)?;
+        sstore(ccx, token, slot, (old & !(mask << offset)) | (value << offset))?;
+        Ok(Default::default())
+    }
+}
+
+impl Cheatcode for setTokenDealStrategyCall {
+    fn apply<FEN: FoundryEvmNetwork>(&self, state: &mut crate::Cheatcodes<FEN>) -> Result {
+        let Self { token, sharesOf, totalShares } = *self;
+        state.token_deal_strategies.insert(
+            token,
+            TokenDealStrategy::Shares { shares_of: sharesOf, total_shares: totalShares },
+        );
+        Ok(Default::default())
+    }
+}
+
+/// Returns the registered strategy of `token`, falling back to the built-in mainnet registry.
+fn token_strategy<FEN: FoundryEvmNetwork>(
+    ccx: &CheatsCtxt<'_, '_, FEN>,
+    token: Address,
+) -> TokenDealStrategy {
+    if let Some(strategy) = ccx.state.token_deal_strategies.get(&token) {
+        return *strategy;
+    }
+    if ccx.ecx.cfg().chain_id() == 1
+        && let Some((_, strategy)) = MAINNET_TOKENS.iter().find(|(address, _)| *address == token)
+    {
+        return *strategy;
+    }
+    TokenDealStrategy::Balance
+}
+
+/// Finds the storage slot holding the value returned by `getter(account)`.
+///
+/// Every slot read by the getter is probed, starting with the last one, by filling it with ones
+/// and checking whether the getter returns an all-ones value. The width of that value and its
+/// offset in the slot locate balances packed with other data.
+///
+/// Returns the slot, the mask of the value and its offset in bits.
+fn find_balance_slot<FEN: FoundryEvmNetwork>(
+    ccx: &mut CheatsCtxt<'_, '_, FEN>,
+    executor: &mut dyn CheatcodesExecutor<FEN>,
+    token: Address,
+    getter: Selector,
+    account: Address,
+) -> Result<Option<(U256, U256, usize)>> {
+    let recording = std::mem::replace(&mut ccx.state.recording_accesses, true);
+    let accesses = std::mem::take(&mut ccx.state.accesses);
+    let result = call_getter(ccx, executor, token, getter, Some(account));
+    let mut reads = std::mem::replace(&mut ccx.state.accesses, accesses)
+        .reads
+        .remove(&token)
+        .unwrap_or_default();
+    ccx.state.recording_accesses = recording;
+    result?.ok_or_else(|| fmt_err!("failed to call the balance getter of {token}"))?;
+
+    reads.dedup();
+    for slot in reads.into_iter().rev() {
+        let old = sload(ccx, token, slot)?;
+
+        sstore(ccx, token, slot, U256::MAX)?;
+        let probed = call_getter(ccx, executor, token, getter, Some(account));
+        sstore(ccx, token, slot, old)?;
+        let Some(mask) = probed?.filter(|value| !value.is_zero()) else { continue };
+        // The value must be the unmodified contents of a slot field.
+        if mask != U256::MAX && !(mask + U256::from(1)).is_power_of_two() {
+            continue;
+        }
+
+        let width = mask.bit_len();
+        for offset in (0..=256 - width).step_by(8) {
+            sstore(ccx, token, slot, mask << offset)?;
+            let probed = call_getter(ccx, executor, token, getter, Some(account));
+            sstore(ccx, token, slot, old)?;
+            if probed? == Some(mask) {
+                return Ok(Some((slot, mask, offset)));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Statically calls `token` with the given getter, returning the decoded `uint256` if the call
+/// succeeds.
+fn call_getter<FEN: FoundryEvmNetwork>(
+    ccx: &mut CheatsCtxt<'_, '_, FEN>,
+    executor: &mut dyn CheatcodesExecutor<FEN>,
+    token: Address,
+    getter: Selector,
+    account: Option<Address>,
+) -> Result<Option<U256>> {
+    let mut calldata = getter.to_vec();
+    if let Some(account) = account {
+        calldata.extend_from_slice(&account.abi_encode());
+    }
+    let code = ccx.ecx.journal_mut().load_account_with_code(token)?.data.info.code.clone();
+    let code = code.unwrap_or_default();
+    let outcome = exec_call(
+        executor,
+        CallInputs {
+            caller: ccx.caller,
+            bytecode_address: token,
+            known_bytecode: (code.hash_slow(), code),
+            target_address: token,
+            scheme: CallScheme::StaticCall,
+            value: CallValue::Transfer(U256::ZERO),
+            input: CallInput::Bytes(Bytes::from(calldata)),
+            gas_limit: ccx.gas_limit,
+            reservoir: 0,
+            is_static: true,
+            return_memory_offset: 0..0,
+        },
+        ccx,
+    )?;
+    if !outcome.result.result.is_ok() {
+        return Ok(None);
+    }
+    Ok(U256::abi_decode(&outcome.result.output).ok())
+}
+
+fn sload<FEN: FoundryEvmNetwork>(
+    ccx: &mut CheatsCtxt<'_, '_, FEN>,
+    token: Address,
+    slot: U256,
+) -> Result<U256> {
+    Ok(ccx
+        .ecx
+        .journal_mut()
+        .sload(token, slot)
+        .map_err(|e| fmt_err!("failed to load storage slot: {:?}", e))?
+        .data)
+}
+
+fn sstore<FEN: FoundryEvmNetwork>(
+    ccx: &mut CheatsCtxt<'_, '_, FEN>,
+    token: Address,
+    slot: U256,
+    value: U256,
+) -> Result<()> {
+    ccx.ecx
+        .journal_mut()
+        .sstore(token, slot, value)
+        .map_err(|e| fmt_err!("failed to store storage slot: {:?}", e))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mainnet_tokens_are_unique() {
+        for (i, (token, _)) in MAINNET_TOKENS.iter().enumerate() {
+            assert!(MAINNET_TOKENS[i + 1..].iter().all(|(other, _)| other != token));
+        }
+    }
+}
@@ -2,12 +2,15 @@
 
 use super::string::parse;
 use crate::{
-    Cheatcode, Cheatcodes, CheatcodesExecutor, CheatsCtxt, Result, Vm::*, inspector::exec_create,
+    Cheatcode, Cheatcodes, CheatcodesExecutor, CheatsCtxt, Result,
+    Vm::*,
+    evm::journaled_account,
+    inspector::{exec_call, exec_create},
 };
 use alloy_dyn_abi::DynSolType;
 use alloy_json_abi::ContractObject;
 use alloy_network::{Network, ReceiptResponse};
-use alloy_primitives::{Bytes, U256, hex, map::Entry};
+use alloy_primitives::{Address, Bytes, U256, hex, map::Entry};
 use alloy_sol_types::SolValue;
 use dialoguer::{Input, Password};
 use forge_script_sequence::{BroadcastReader, TransactionWithMetadata};
@@ -15,8 +18,9 @@
 use foundry_config::fs_permissions::FsAccessKind;
 use foundry_evm_core::evm::FoundryEvmNetwork;
 use revm::{
+    bytecode::Bytecode,
     context::{Cfg, ContextTr, CreateScheme, JournalTr},
-    interpreter::CreateInputs,
+    interpreter::{CallInput, CallInputs, CallScheme, CallValue, CreateInputs},
 };
 use revm_inspectors::tracing::types::CallKind;
 use semver::Version;
@@ -398,6 +402,39 @@ fn apply_full<FEN: FoundryEvmNetwork>(
     }
 }
 
+impl Cheatcode for upgradeTo_0Call {
+    fn apply_full<FEN: FoundryEvmNetwork>(
+        &self,
+        ccx: &mut CheatsCtxt<'_, '_, FEN>,
+        executor: &mut dyn CheatcodesExecutor<FEN>,
+    ) -> Result {
+        let Self { target, artifactPath: path } = self;
+        upgrade_to(ccx, executor, *target, path, None, None)
+    }
+}
+
+impl Cheatcode for upgradeTo_1Call {
+    fn apply_full<FEN: FoundryEvmNetwork>(
+        &self,
+        ccx: &mut CheatsCtxt<'_, '_, FEN>,
+        executor: &mut dyn CheatcodesExecutor<FEN>,
+    ) -> Result {
+        let Self { target, artifactPath: path, constructorArgs: args } = self;
+        upgrade_to(ccx, executor, *target, path, Some(args), None)
+    }
+}
+
+impl Cheatcode for upgradeTo_2Call {
+    fn apply_full<FEN: FoundryEvmNetwork>(
+        &self,
+        ccx: &mut CheatsCtxt<'_, '_, FEN>,
+        executor: &mut dyn CheatcodesExecutor<FEN>,
+    ) -> Result {
+        let Self { target, artifactPath: path, constructorArgs: args, initData: data } = self;
+        upgrade_to(ccx, executor, *target, path, Some(args), Some(data))
+    }
+}
+
 /// Helper function to deploy contract from artifact code.
 /// Uses CREATE2 scheme if salt specified.
 fn deploy_code<FEN: FoundryEvmNetwork>(
@@ -448,6 +485,83 @@ fn deploy_code<FEN: FoundryEvmNetwork>(
     Ok(address.abi_encode())
 }
 
+/// Replaces the code of `target` with the runtime code of the artifact at `path`.
+///
+/// The artifact is deployed from the caller to a scratch address first so that its constructor
+/// runs and immutables are set, then the resulting runtime code is copied to `target`. The scratch
+/// deployment is reverted afterwards, so it leaves neither a contract nor a nonce increment behind.
+/// If `init_data` is given, `target` is called with it afterwards.
+fn upgrade_to<FEN: FoundryEvmNetwork>(
+    ccx: &mut CheatsCtxt<'_, '_, FEN>,
+    executor: &mut dyn CheatcodesExecutor<FEN>,
+    target: Address,
+    path: &str,
+    constructor_args: Option<&Bytes>,
+    init_data: Option<&Bytes>,
+) -> Result {
+    ccx.ensure_not_precompile(&target)?;
+    ensure!(ccx.state.broadcast.is_none(), "cannot `upgradeTo` while broadcasting");
+
+    let mut bytecode = get_artifact_code(ccx.state, path, false)?.to_vec();
+    if let Some(args) = constructor_args {
+        bytecode.extend_from_slice(args);
+    }
+
+    // If prank active at current depth, then use it as caller for the deployment and the call.
+    let caller =
+        ccx.state.get_prank(ccx.ecx.journal().depth()).map_or(ccx.caller, |prank| prank.new_caller);
+
+    let checkpoint = ccx.ecx.journal_mut().checkpoint();
+    let code = deployed_code(ccx, executor, caller, bytecode.into());
+    ccx.ecx.journal_mut().checkpoint_revert(checkpoint);
+    let code = code?;
+
+    ccx.ecx.journal_mut().load_account(target)?;
+    ccx.ecx.journal_mut().set_code(target, code.clone());
+
+    let Some(data) = init_data else { return Ok(Default::default()) };
+    let outcome = exec_call(
+        executor,
+        CallInputs {
+            caller,
+            bytecode_address: target,
+            known_bytecode: (code.hash_slow(), code),
+            target_address: target,
+            scheme: CallScheme::Call,
+            value: CallValue::Transfer(U256::ZERO),
+            input: CallInput::Bytes(data.clone()),
+            gas_limit: ccx.gas_limit,
+            reservoir: 0,
+            is_static: false,
+            return_memory_offset: 0..0,
+        },
+        ccx,
+    )?;
+    if !outcome.result.result.is_ok() {
+        return Err(crate::Error::from(outcome.result.output));
+    }
+    Ok(outcome.result.output.abi_encode())
+}
+
+/// Deploys `bytecode` from `caller` and returns the runtime code of the created contract.
+fn deployed_code<FEN: FoundryEvmNetwork>(
+    ccx: &mut CheatsCtxt<'_, '_, FEN>,
+    executor: &mut dyn CheatcodesExecutor<FEN>,
+    caller: Address,
+    bytecode: Bytes,
+) -> Result<Bytecode> {
+    let outcome = exec_create(
+        executor,
+        CreateInputs::new(caller, CreateScheme::Create, U256::ZERO, bytecode, ccx.gas_limit, 0),
+        ccx,
+    )?;
+    if !outcome.result.result.is_ok() {
+        return Err(crate::Error::from(outcome.result.output));
+    }
+    let address = outcome.address.ok_or_else(|| fmt_err!("contract creation failed"))?;
+    Ok(journaled_account(ccx.ecx, address)?.info.code.clone().unwrap_or_default())
+}
+
 /// Returns the bytecode from a JSON artifact file.
 ///
 /// Can parse following input formats:
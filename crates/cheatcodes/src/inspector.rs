@@ -6,7 +6,8 @@
     evm::{
         DealRecord, GasRecord, RecordAccess, journaled_account,
         mock::{MockCallDataContext, MockCallReturnData},
-        prank::Prank,
+        prank::{Prank, PrankScope},
+        token::TokenDealStrategy,
     },
     inspector::utils::CommonCreateInput,
     script::{Broadcast, Wallets},
@@ -32,6 +33,7 @@
     FoundryTransactionBuilder, SELECTOR_LEN, TransactionMaybeSigned,
     mapping_slots::{MappingSlots, step as mapping_step},
 };
+use foundry_compilers::ArtifactId;
 use foundry_evm_core::{
     Breakpoints, EvmEnv, FoundryTransaction, InspectorExt,
     abi::Vm::stopExpectSafeMemoryCall,
@@ -66,13 +68,13 @@
 use serde_json::Value;
 use std::{
     cmp::max,
-    collections::{BTreeMap, VecDeque},
+    collections::{BTreeMap, BTreeSet, VecDeque},
     fmt::Debug,
     fs::File,
     io::BufReader,
     ops::Range,
     path::PathBuf,
-    sync::{Arc, OnceLock},
+    sync::{Arc, LazyLock, OnceLock},
 };
 
 mod utils;
@@ -162,6 +164,33 @@ pub(crate) fn exec_create<FEN: FoundryEvmNetwork>(
     Ok(outcome.unwrap())
 }
 
+/// Builds a sub-EVM from the current context and executes the given CALL frame.
+pub(crate) fn exec_call<FEN: FoundryEvmNetwork>(
+    executor: &mut dyn CheatcodesExecutor<FEN>,
+    inputs: CallInputs,
+    ccx: &mut CheatsCtxt<'_, '_, FEN>,
+) -> std::result::Result<CallOutcome, EVMError<DatabaseError>> {
+    let mut inputs = Some(inputs);
+    let mut outcome = None;
+    executor.with_nested_evm(ccx.state, ccx.ecx, &mut |evm| {
+        let inputs = inputs.take().unwrap();
+        evm.journal_inner_mut().depth += 1;
+
+        let frame = FrameInput::Call(Box::new(inputs));
+
+        let result = match evm.run_execution(frame)? {
+            FrameResult::Call(call) => call,
+            FrameResult::Create(_) => unreachable!(),
+        };
+
+        evm.journal_inner_mut().depth -= 1;
+
+        outcome = Some(result);
+        Ok(())
+    })?;
+    Ok(outcome.unwrap())
+}
+
 /// Basic implementation of [CheatcodesExecutor] that simply returns the [Cheatcodes] instance as an
 /// inspector.
 #[derive(Debug, Default, Clone, Copy)]
@@ -281,6 +310,9 @@ pub struct GasMetering {
     pub reset: bool,
     /// Stores paused gas frames.
     pub paused_frames: Vec<Gas>,
+    /// The gas of the frame calling an assertion cheatcode, restored once the call returns when
+    /// assertions are gas free.
+    pub assertion_gas: Option<Gas>,
 
     /// The group and name of the active snapshot.
     pub active_gas_snapshot: Option<(String, String)>,
@@ -455,12 +487,25 @@ pub struct Cheatcodes<FEN: FoundryEvmNetwork = EthEvmNetwork> {
     /// in the execution environment.
     pub gas_price: Option<u128>,
 
+    /// The `block.prevrandao` of future blocks, set by `vm.setPrevrandao` and applied by
+    /// `vm.roll`.
+    pub block_prevrandaos: BTreeMap<U256, B256>,
+
     /// Address labels
     pub labels: AddressHashMap<String>,
 
+    /// Named accounts loaded with `vm.loadNamedAccounts`, by name.
+    pub named_accounts: HashMap<String, Address>,
+
     /// Prank information, mapped to the call depth where pranks were added.
     pub pranks: BTreeMap<usize, Prank>,
 
+    /// Prank applied to every call inside a `vm.prankScope` block, regardless of depth.
+    pub prank_scope: Option<PrankScope>,
+
+    /// Token balance layouts registered with `vm.setTokenDealStrategy`.
+    pub token_deal_strategies: AddressHashMap<TokenDealStrategy>,
+
     /// Expected revert information
     pub expected_revert: Option<ExpectedRevert>,
 
@@ -563,6 +608,10 @@ pub struct Cheatcodes<FEN: FoundryEvmNetwork = EthEvmNetwork> {
 
     /// Deprecated cheatcodes mapped to the reason. Used to report warnings on test results.
     pub deprecated: HashMap<&'static str, Option<&'static str>>,
+    /// Cheatcodes invoked by each contract, recorded if the cheatcode audit is enabled.
+    pub cheatcode_sites: BTreeMap<String, BTreeSet<&'static str>>,
+    /// Artifacts matched to the code of cheatcode callers, keyed by code hash.
+    caller_artifacts: HashMap<B256, Option<ArtifactId>>,
     /// Unlocked wallets used in scripts and testing of scripts.
     pub wallets: Option<Wallets>,
     /// Signatures identifier for decoding events and functions
@@ -571,6 +620,8 @@ pub struct Cheatcodes<FEN: FoundryEvmNetwork = EthEvmNetwork> {
     pub dynamic_gas_limit: bool,
     // Custom execution evm version.
     pub execution_evm_version: Option<SpecFor<FEN>>,
+    /// The forks created by `vm.selectChain`, keyed by chain name.
+    pub chain_forks: HashMap<String, U256>,
 }
 
 // This is not derived because calling this in `fn new` with `..Default::default()` creates a second
@@ -589,12 +640,16 @@ pub fn new(config: Arc<CheatsConfig>) -> Self {
             analysis: None,
             fs_commit: true,
             labels: config.labels.clone(),
+            named_accounts: Default::default(),
             config,
             block: Default::default(),
             active_delegations: Default::default(),
             active_blob_sidecar: Default::default(),
             gas_price: Default::default(),
+            block_prevrandaos: Default::default(),
             pranks: Default::default(),
+            prank_scope: Default::default(),
+            token_deal_strategies: Default::default(),
             expected_revert: Default::default(),
             assume_no_revert: Default::default(),
             fork_revert_diagnostic: Default::default(),
@@ -625,10 +680,13 @@ pub fn new(config: Arc<CheatsConfig>) -> Self {
             ignored_traces: Default::default(),
             arbitrary_storage: Default::default(),
             deprecated: Default::default(),
+            cheatcode_sites: Default::default(),
+            caller_artifacts: Default::default(),
             wallets: Default::default(),
             signatures_identifier: Default::default(),
             dynamic_gas_limit: Default::default(),
             execution_evm_version: None,
+            chain_forks: Default::default(),
         }
     }
 
@@ -697,6 +755,52 @@ fn apply_cheatcode(
         )
     }
 
+    /// Enforces the configured cheatcode permissions for `caller` and records the invocation if
+    /// the cheatcode audit is enabled.
+    fn check_cheatcode_access(
+        &mut self,
+        ecx: &mut FoundryContextFor<'_, FEN>,
+        caller: Address,
+        cheat: &spec::Cheatcode<'static>,
+    ) -> Result<()> {
+        let name = cheatcode_name(cheat);
+        if !self.config.cheatcode_audit && !self.config.is_cheatcode_restricted(name) {
+            return Ok(());
+        }
+
+        let artifact = self.caller_artifact(ecx, caller);
+        if self.config.cheatcode_audit {
+            let site = artifact.as_ref().map_or_else(|| caller.to_string(), |id| id.identifier());
+            self.cheatcode_sites.entry(site).or_default().insert(cheatcode_signature(cheat));
+        }
+        self.config.ensure_cheatcode_allowed(name, artifact.as_ref().map(|id| id.source.as_path()))
+    }
+
+    /// Returns the artifact matching the code deployed at `caller`, if any.
+    fn caller_artifact(
+        &mut self,
+        ecx: &mut FoundryContextFor<'_, FEN>,
+        caller: Address,
+    ) -> Option<ArtifactId> {
+        let artifacts = self.config.available_artifacts.as_ref()?;
+        let account = ecx.journal_mut().load_account(caller).ok()?;
+        let code_hash = account.data.info.code_hash;
+        if let Some(artifact) = self.caller_artifacts.get(&code_hash) {
+            return artifact.clone();
+        }
+
+        let artifact = account
+            .data
+            .info
+            .code
+            .as_ref()
+            .filter(|code| !code.is_empty())
+            .and_then(|code| artifacts.find_by_deployed_code(&code.original_bytes()))
+            .map(|(id, _)| id.clone());
+        self.caller_artifacts.insert(code_hash, artifact.clone());
+        artifact
+    }
+
     /// Grants cheat code access for new contracts if the caller also has
     /// cheatcode access or the new contract is created in top most call.
     ///
@@ -932,6 +1036,14 @@ pub fn call_with_executor(
             }
         }
 
+        // Apply our prank scope. Delegate calls keep the `msg.sender` of their parent frame.
+        if let Some(scope) = &self.prank_scope
+            && call.scheme != CallScheme::DelegateCall
+            && scope.applies_to(&call.target_address, curr_depth)
+        {
+            call.caller = scope.new_caller;
+        }
+
         // Apply EIP-2930 access list
         self.apply_accesslist(ecx);
 
@@ -1209,6 +1321,11 @@ fn step(&mut self, interpreter: &mut Interpreter, ecx: &mut FoundryContextFor<'_
             self.meter_gas(interpreter);
         }
 
+        // `gas_free_assertions`: do not meter the calls to assertion cheatcodes.
+        if self.config.gas_free_assertions {
+            self.meter_assertion_gas(interpreter);
+        }
+
         // `resetGasMetering`: reset interpreter gas.
         if self.gas_metering.reset {
             self.meter_gas_reset(interpreter);
@@ -2012,6 +2129,41 @@ fn meter_gas(&mut self, interpreter: &mut Interpreter) {
         }
     }
 
+    /// Restores the gas of the frame once a call to an assertion cheatcode returns, so that it is
+    /// not metered.
+    fn meter_assertion_gas(&mut self, interpreter: &mut Interpreter) {
+        // Cheatcode calls do not create a frame, so the next step is the one after the call.
+        if let Some(assertion_gas) = self.gas_metering.assertion_gas.take() {
+            // Keep the memory changes, as for paused gas metering.
+            let memory = *interpreter.gas.memory();
+            interpreter.gas = assertion_gas;
+            interpreter.gas.memory_mut().words_num = memory.words_num;
+            interpreter.gas.memory_mut().expansion_cost = memory.expansion_cost;
+        }
+
+        let args_offset_depth = match interpreter.bytecode.opcode() {
+            op::CALL | op::CALLCODE => 3,
+            op::STATICCALL | op::DELEGATECALL => 2,
+            _ => return,
+        };
+        let to = Address::from_word(try_or_return!(interpreter.stack.peek(1)).into());
+        if to != CHEATCODE_ADDRESS {
+            return;
+        }
+        let args_offset = try_or_return!(interpreter.stack.peek(args_offset_depth));
+        let args_size = try_or_return!(interpreter.stack.peek(args_offset_depth + 1));
+        let args_offset = args_offset.saturating_to::<usize>();
+        if args_size < U256::from(SELECTOR_LEN)
+            || args_offset.saturating_add(SELECTOR_LEN) > interpreter.memory.size()
+        {
+            return;
+        }
+        let selector = interpreter.memory.slice_len(args_offset, SELECTOR_LEN);
+        if is_assertion_selector(&selector) {
+            self.gas_metering.assertion_gas = Some(interpreter.gas);
+        }
+    }
+
     #[cold]
     fn meter_gas_record(
         &mut self,
@@ -2594,6 +2746,18 @@ const fn cheatcode_of<T: spec::CheatcodeDef>(_: &T) -> &'static spec::Cheatcode<
     T::CHEATCODE
 }
 
+/// Returns `true` if `selector` is the selector of an assertion cheatcode, e.g. `assertEq`.
+fn is_assertion_selector(selector: &[u8]) -> bool {
+    static SELECTORS: LazyLock<HashSet<[u8; SELECTOR_LEN]>> = LazyLock::new(|| {
+        Vm::CHEATCODES
+            .iter()
+            .filter(|cheat| cheatcode_name(cheat).starts_with("assert"))
+            .map(|cheat| cheat.func.selector_bytes)
+            .collect()
+    });
+    <[u8; SELECTOR_LEN]>::try_from(selector).is_ok_and(|selector| SELECTORS.contains(&selector))
+}
+
 fn cheatcode_name(cheat: &spec::Cheatcode<'static>) -> &'static str {
     cheat.func.signature.split('(').next().unwrap()
 }
@@ -2637,7 +2801,10 @@ macro_rules! dispatch {
             }
         };
     }
-    let mut result = vm_calls!(dispatch);
+    let mut result = match ccx.state.check_cheatcode_access(ccx.ecx, ccx.caller, cheat) {
+        Ok(()) => vm_calls!(dispatch),
+        Err(e) => Err(e),
+    };
 
     // Format the error message to include the cheatcode name.
     if let Err(e) = &mut result
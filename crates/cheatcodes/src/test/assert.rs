@@ -1,5 +1,5 @@
 use crate::{CheatcodesExecutor, CheatsCtxt, Result, Vm::*};
-use alloy_primitives::{I256, U256, U512};
+use alloy_primitives::{Address, Bytes, I256, U256, U512, hex};
 use foundry_evm_core::{
     abi::console::{format_units_int, format_units_uint},
     backend::GLOBAL_FAIL_SLOT,
@@ -9,10 +9,20 @@
 };
 use itertools::Itertools;
 use revm::context::{ContextTr, JournalTr};
-use std::{borrow::Cow, fmt};
+use std::{
+    borrow::Cow,
+    fmt::{self, Write},
+};
 
 const EQ_REL_DELTA_RESOLUTION: U256 = U256::from_limbs([18, 0, 0, 0]);
 
+/// Failed equality assertions whose values are formatted longer than this are reported as a diff
+/// of the values instead.
+const MAX_COMPACT_EQ_LEN: usize = 128;
+
+/// The maximum number of differences listed in the diff of a failed equality assertion.
+const MAX_DIFF_ENTRIES: usize = 16;
+
 struct ComparisonAssertionError<'a, T> {
     kind: AssertionKind,
     left: &'a T,
@@ -71,6 +81,106 @@ fn format_for_arrays(&self) -> String {
     }
 }
 
+impl<T: fmt::Display + PartialEq> ComparisonAssertionError<'_, Vec<T>> {
+    /// Formats a failed `assertEq` on arrays, listing the differing elements if the arrays are too
+    /// large to be compared at a glance.
+    fn format_array_diff(&self) -> String {
+        let compact = self.format_for_arrays();
+        if compact.len() <= MAX_COMPACT_EQ_LEN {
+            return compact;
+        }
+        let entries = (0..self.left.len().max(self.right.len())).filter_map(|i| {
+            let (left, right) = (self.left.get(i), self.right.get(i));
+            (left != right).then(|| {
+                format!("[{i}]: {} != {}", format_or_missing(left), format_or_missing(right))
+            })
+        });
+        format_diff(self.left.len(), self.right.len(), "elements", entries)
+    }
+}
+
+impl ComparisonAssertionError<'_, Bytes> {
+    /// Formats a failed `assertEq` on bytes, listing the differing 32-byte words along with their
+    /// decoded values if the bytes are too large to be compared at a glance.
+    ///
+    /// ABI-encoded structs are compared field by field, as each static field is encoded in its own
+    /// word.
+    fn format_bytes_diff(&self) -> String {
+        let compact = self.format_for_values();
+        if compact.len() <= MAX_COMPACT_EQ_LEN {
+            return compact;
+        }
+        let left = self.left.chunks(32).collect::<Vec<_>>();
+        let right = self.right.chunks(32).collect::<Vec<_>>();
+        let entries = (0..left.len().max(right.len())).filter_map(|i| {
+            let (left, right) = (left.get(i).copied(), right.get(i).copied());
+            (left != right).then(|| {
+                format!(
+                    "word {i} (offset {:#x}): {} != {}",
+                    i * 32,
+                    format_word(left),
+                    format_word(right)
+                )
+            })
+        });
+        let mut diff = format_diff(self.left.len(), self.right.len(), "bytes", entries);
+        if let (Some(left), Some(right)) = (printable_str(self.left), printable_str(self.right)) {
+            let _ = write!(diff, "\n  as strings: {left:?} != {right:?}");
+        }
+        diff
+    }
+}
+
+/// Formats the lengths and the differences of two values compared by [`assert_eq`].
+fn format_diff(
+    left_len: usize,
+    right_len: usize,
+    unit: &str,
+    entries: impl Iterator<Item = String>,
+) -> String {
+    let mut diff = String::from("left != right");
+    if left_len != right_len {
+        let _ = write!(diff, "\n  length: {left_len} != {right_len} {unit}");
+    }
+    let entries = entries.collect::<Vec<_>>();
+    for entry in entries.iter().take(MAX_DIFF_ENTRIES) {
+        let _ = write!(diff, "\n  {entry}");
+    }
+    if entries.len() > MAX_DIFF_ENTRIES {
+        let _ = write!(diff, "\n  ... and {} more", entries.len() - MAX_DIFF_ENTRIES);
+    }
+    diff
+}
+
+fn format_or_missing<T: fmt::Display>(value: Option<&T>) -> String {
+    value.map_or_else(|| "<missing>".to_string(), ToString::to_string)
+}
+
+/// Formats a word of ABI-encoded data as hex, followed by its value if it looks like a small
+/// integer or an address.
+fn format_word(word: Option<&[u8]>) -> String {
+    let Some(word) = word else { return "<missing>".to_string() };
+    let hex = hex::encode_prefixed(word);
+    if word.len() != 32 {
+        return hex;
+    }
+    let value = U256::from_be_slice(word);
+    if value.bit_len() <= 64 {
+        format!("{hex} ({value})")
+    } else if value.bit_len() <= 160 {
+        format!("{hex} ({})", Address::from_slice(&word[12..]))
+    } else {
+        hex
+    }
+}
+
+/// Returns the bytes as a string if they are printable UTF-8.
+fn printable_str(bytes: &[u8]) -> Option<&str> {
+    std::str::from_utf8(bytes)
+        .ok()
+        .filter(|s| !s.is_empty() && s.chars().all(|c| !c.is_control() || c.is_whitespace()))
+}
+
 impl ComparisonAssertionError<'_, U256> {
     fn format_with_decimals(&self, decimals: &U256) -> String {
         self.format_values(|v| format_units_uint(v, decimals))
@@ -300,12 +410,17 @@ fn apply_full<FEN: FoundryEvmNetwork>(
     (assertEq_6Call, assertEq_7Call),
     (assertEq_8Call, assertEq_9Call),
     (assertEq_10Call, assertEq_11Call),
+}
+
+impl_assertions! {
+    |left, right| assert_eq(left, right),
+    ComparisonAssertionError::format_bytes_diff,
     (assertEq_12Call, assertEq_13Call),
 }
 
 impl_assertions! {
     |left, right| assert_eq(left, right),
-    ComparisonAssertionError::format_for_arrays,
+    ComparisonAssertionError::format_array_diff,
     (assertEq_14Call, assertEq_15Call),
     (assertEq_16Call, assertEq_17Call),
     (assertEq_18Call, assertEq_19Call),
@@ -618,3 +733,61 @@ fn int_assert_approx_eq_rel(
         })))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+    use alloy_sol_types::SolValue;
+
+    fn eq_error<'a, T>(left: &'a T, right: &'a T) -> ComparisonAssertionError<'a, T> {
+        ComparisonAssertionError { kind: AssertionKind::Eq, left, right }
+    }
+
+    #[test]
+    fn short_values_are_compact() {
+        let (left, right) = (Bytes::from_static(&[0x11]), Bytes::from_static(&[0x12]));
+        assert_eq!(eq_error(&left, &right).format_bytes_diff(), "0x11 != 0x12");
+        assert_eq!(eq_error(&vec![1, 2], &vec![1, 3]).format_array_diff(), "[1, 2] != [1, 3]");
+    }
+
+    #[test]
+    fn diffs_abi_encoded_structs_by_word() {
+        let owner = address!("0x5FbDB2315678afecb367f032d93F642f64180aa3");
+        let left = Bytes::from((U256::from(1), owner, U256::from(5)).abi_encode());
+        let right = Bytes::from((U256::from(1), Address::ZERO, U256::from(6)).abi_encode());
+        assert_eq!(
+            eq_error(&left, &right).format_bytes_diff(),
+            "left != right
+  word 1 (offset 0x20): 0x0000000000000000000000005fbdb2315678afecb367f032d93f642f64180aa3 (0x5FbDB2315678afecb367f032d93F642f64180aa3) != 0x0000000000000000000000000000000000000000000000000000000000000000 (0)
+  word 2 (offset 0x40): 0x0000000000000000000000000000000000000000000000000000000000000005 (5) != 0x0000000000000000000000000000000000000000000000000000000000000006 (6)"
+        );
+    }
+
+    #[test]
+    fn diffs_printable_bytes_as_strings() {
+        let left = Bytes::from("a".repeat(40));
+        let right = Bytes::from("a".repeat(33));
+        let diff = eq_error(&left, &right).format_bytes_diff();
+        assert!(
+            diff.starts_with("left != right\n  length: 40 != 33 bytes\n  word 1 (offset 0x20)")
+        );
+        assert!(diff.ends_with(&format!(
+            "as strings: {:?} != {:?}",
+            "a".repeat(40),
+            "a".repeat(33)
+        )));
+    }
+
+    #[test]
+    fn diffs_arrays_by_element() {
+        let left = (0..40u64).map(U256::from).collect::<Vec<_>>();
+        let mut right = left.clone();
+        right[3] = U256::from(100);
+        right.push(U256::from(40));
+        assert_eq!(
+            eq_error(&left, &right).format_array_diff(),
+            "left != right\n  length: 40 != 41 elements\n  [3]: 3 != 100\n  [40]: <missing> != 40"
+        );
+    }
+}
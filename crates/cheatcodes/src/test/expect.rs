@@ -81,6 +81,8 @@ pub struct ExpectedRevert {
     pub kind: ExpectedRevertKind,
     /// If true then only the first 4 bytes of expected data returned by the revert are checked.
     pub partial_match: bool,
+    /// If set, only the selector and the decoded arguments flagged here are checked.
+    pub checked_args: Option<Vec<bool>>,
     /// Contract expected to revert next call.
     pub reverter: Option<Address>,
     /// Address that reverted the call.
@@ -582,6 +584,20 @@ fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FE
     }
 }
 
+impl Cheatcode for expectPartialRevert_2Call {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self { revertData, checkArgs } = self;
+        expect_revert_with_checked_args(ccx, revertData, checkArgs, None)
+    }
+}
+
+impl Cheatcode for expectPartialRevert_3Call {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self { revertData, checkArgs, reverter } = self;
+        expect_revert_with_checked_args(ccx, revertData, checkArgs, Some(*reverter))
+    }
+}
+
 impl Cheatcode for _expectCheatcodeRevert_0Call {
     fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
         expect_revert(ccx.state, None, ccx.ecx.journal().depth(), true, false, None, 1)
@@ -644,6 +660,10 @@ fn reason(&self) -> Option<&[u8]> {
     fn partial_match(&self) -> bool {
         self.partial_match
     }
+
+    fn checked_args(&self) -> Option<&[bool]> {
+        self.checked_args.as_deref()
+    }
 }
 
 /// Handles expected calls specified by the `expectCall` cheatcodes.
@@ -1033,6 +1053,7 @@ fn expect_revert<FEN: FoundryEvmNetwork>(
             ExpectedRevertKind::Default
         },
         partial_match,
+        checked_args: None,
         reverter,
         reverted_by: None,
         max_depth: depth,
@@ -1042,6 +1063,20 @@ fn expect_revert<FEN: FoundryEvmNetwork>(
     Ok(Default::default())
 }
 
+fn expect_revert_with_checked_args<FEN: FoundryEvmNetwork>(
+    ccx: &mut CheatsCtxt<'_, '_, FEN>,
+    reason: &[u8],
+    checked_args: &[bool],
+    reverter: Option<Address>,
+) -> Result {
+    ensure!(reason.len() >= 4, "revert data must contain at least a selector");
+    expect_revert(ccx.state, Some(reason), ccx.ecx.journal().depth(), false, false, reverter, 1)?;
+    if let Some(expected_revert) = &mut ccx.state.expected_revert {
+        expected_revert.checked_args = Some(checked_args.to_vec());
+    }
+    Ok(Default::default())
+}
+
 fn checks_topics_and_data(checks: [bool; 5], expected: &RawLog, log: &RawLog) -> bool {
     if log.topics().len() != expected.topics().len() {
         return false;
@@ -38,6 +38,11 @@ pub(crate) trait RevertParameters {
     fn reverter(&self) -> Option<Address>;
     fn reason(&self) -> Option<&[u8]>;
     fn partial_match(&self) -> bool;
+
+    /// The error arguments to compare, if only some of them should be checked.
+    fn checked_args(&self) -> Option<&[bool]> {
+        None
+    }
 }
 
 impl RevertParameters for AcceptableRevertParameters {
@@ -91,6 +96,13 @@ fn handle_revert(
         return Ok(());
     }
 
+    // Compare the selector and the flagged decoded arguments only.
+    if let Some(checked_args) = revert_params.checked_args()
+        && checked_args_match(checked_args, &actual_revert, expected_reason, known_contracts)?
+    {
+        return Ok(());
+    }
+
     // Try decoding as known errors.
     actual_revert = decode_revert(actual_revert);
 
@@ -128,6 +140,49 @@ fn handle_revert(
     Err(fmt_err!("Error != expected error: {} != {}", actual, expected))
 }
 
+/// Returns `true` if both revert data are the same custom error and their decoded arguments are
+/// equal wherever `checked_args` is set.
+///
+/// The error definition is looked up by selector in the ABIs of the known contracts.
+fn checked_args_match(
+    checked_args: &[bool],
+    actual: &[u8],
+    expected: &[u8],
+    known_contracts: &Option<ContractsByArtifact>,
+) -> Result<bool> {
+    if actual.get(..4) != expected.get(..4) {
+        return Ok(false);
+    }
+    let selector = &expected[..4];
+    let Some(error) = known_contracts
+        .iter()
+        .flat_map(|contracts| contracts.values())
+        .flat_map(|contract| contract.abi.errors())
+        .find(|error| error.selector().as_slice() == selector)
+    else {
+        bail!(
+            "cannot check the arguments of custom error {}: the error is not declared in any known \
+             contract",
+            hex::encode_prefixed(selector)
+        );
+    };
+    let expected = error
+        .decode_error(expected)
+        .map_err(|e| fmt_err!("failed to decode the expected {} error: {e}", error.name))?;
+    ensure!(
+        checked_args.len() <= expected.body.len(),
+        "`checkArgs` has {} flags but {} only has {} arguments",
+        checked_args.len(),
+        error.name,
+        expected.body.len()
+    );
+    let Ok(actual) = error.decode_error(actual) else { return Ok(false) };
+    Ok(checked_args
+        .iter()
+        .zip(actual.body.iter().zip(&expected.body))
+        .all(|(check, (actual, expected))| !check || actual == expected))
+}
+
 pub(crate) fn handle_assume_no_revert(
     assume_no_revert: &AssumeNoRevert,
     status: InstructionResult,
@@ -6,14 +6,14 @@
 use alloy_primitives::{B64, Bytes, I256, U256, aliases::B32, keccak256, map::HashMap};
 use alloy_rlp::{Decodable, Encodable};
 use alloy_sol_types::SolValue;
-use foundry_common::{TYPE_BINDING_PREFIX, fs};
+use foundry_common::{TYPE_BINDING_PREFIX, addresses, fs};
 use foundry_config::fs_permissions::FsAccessKind;
 use foundry_evm_core::{constants::DEFAULT_CREATE2_DEPLOYER, evm::FoundryEvmNetwork};
 use foundry_evm_fuzz::strategies::BoundMutator;
 use proptest::prelude::Strategy;
 use rand::{Rng, RngCore, seq::SliceRandom};
 use revm::{
-    context::{ContextTr, JournalTr},
+    context::{Cfg, ContextTr, JournalTr},
     inspector::JournalExt,
 };
 use std::path::PathBuf;
@@ -72,6 +72,28 @@ fn apply<FEN: FoundryEvmNetwork>(&self, _state: &mut Cheatcodes<FEN>) -> Result
     }
 }
 
+impl Cheatcode for wellKnown_0Call {
+    fn apply_stateful<FEN: FoundryEvmNetwork>(&self, ccx: &mut CheatsCtxt<'_, '_, FEN>) -> Result {
+        let Self { name } = self;
+        well_known(name, ccx.ecx.cfg().chain_id())
+    }
+}
+
+impl Cheatcode for wellKnown_1Call {
+    fn apply<FEN: FoundryEvmNetwork>(&self, _state: &mut Cheatcodes<FEN>) -> Result {
+        let Self { name, chainId } = self;
+        ensure!(*chainId <= U256::from(u64::MAX), "chain ID must be less than 2^64");
+        well_known(name, chainId.to())
+    }
+}
+
+fn well_known(name: &str, chain_id: u64) -> Result {
+    let address = addresses::well_known(name, chain_id)
+        .map_err(|e| fmt_err!("{e}"))?
+        .ok_or_else(|| fmt_err!("no canonical address for {name} on chain {chain_id}"))?;
+    Ok(address.abi_encode())
+}
+
 impl Cheatcode for ensNamehashCall {
     fn apply<FEN: FoundryEvmNetwork>(&self, _state: &mut Cheatcodes<FEN>) -> Result {
         let Self { name } = self;
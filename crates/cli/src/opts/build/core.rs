@@ -94,6 +94,18 @@ pub struct BuildOpts {
     #[serde(skip)]
     pub offline: bool,
 
+    /// The maximum number of solc processes to run in parallel.
+    ///
+    /// Defaults to the number of available CPUs.
+    #[arg(long, help_heading = "Compiler options", value_name = "JOBS")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solc_jobs: Option<usize>,
+
+    /// The memory budget, in MiB, shared by the solc processes running in parallel.
+    #[arg(long, help_heading = "Compiler options", value_name = "MIB")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solc_memory_budget: Option<u64>,
+
     /// Use the Yul intermediate representation compilation pipeline.
     #[arg(long, help_heading = "Compiler options")]
     #[serde(skip)]
@@ -146,6 +158,11 @@ pub struct BuildOpts {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub build_info_path: Option<PathBuf>,
 
+    /// Write a provenance file with the compiler, input, git commit and lockfile digests.
+    #[arg(long, help_heading = "Project options")]
+    #[serde(skip)]
+    pub provenance: bool,
+
     /// Skip building files whose names contain the given filter.
     ///
     /// `test` and `script` are aliases for `.t.sol` and `.s.sol`.
@@ -276,6 +293,10 @@ fn data(&self) -> Result<Map<Profile, Dict>, figment::Error> {
             dict.insert("build_info".to_string(), self.build_info.into());
         }
 
+        if self.provenance {
+            dict.insert("provenance".to_string(), true.into());
+        }
+
         if self.compiler.ast {
             dict.insert("ast".to_string(), true.into());
         }
@@ -18,7 +18,9 @@
     AccessListItem, Block, BlockTransactions, Header, Log, Transaction, TransactionReceipt,
 };
 use alloy_serde::{OtherFields, WithOtherFields};
+use foundry_primitives::FoundryTxReceipt;
 use op_alloy_consensus::{OpTxEnvelope, TxDeposit, TxPostExec};
+use op_alloy_rpc_types::OpTransactionReceipt;
 use revm::context_interface::transaction::SignedAuthorization;
 use serde::Deserialize;
 use tempo_alloy::{
@@ -827,6 +829,11 @@ pub trait UIfmtReceiptExt {
     fn logs_pretty(&self) -> String;
     fn logs_bloom_pretty(&self) -> String;
     fn tx_type_pretty(&self) -> String;
+
+    /// Returns the names and formatted values of the chain-specific fields of the receipt.
+    fn extra_fields_pretty(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
 }
 
 fn receipt_logs_pretty<T: TxReceipt<Log = Log>>(receipt: &TransactionReceipt<T>) -> String {
@@ -925,6 +932,101 @@ fn logs_bloom_pretty(&self) -> String {
     fn tx_type_pretty(&self) -> String {
         (self.inner.inner.receipt.tx_type as u8).to_string()
     }
+
+    fn extra_fields_pretty(&self) -> Vec<(&'static str, String)> {
+        vec![("feePayer", self.fee_payer.pretty()), ("feeToken", self.fee_token.pretty())]
+    }
+}
+
+/// Appends the chain-specific fields to a pretty-printed receipt.
+fn push_receipt_fields(pretty: &mut String, fields: Vec<(&'static str, String)>) {
+    for (name, value) in fields {
+        pretty.push_str(&format!("\n{name:<NAME_COLUMN_LEN$} {value}"));
+    }
+}
+
+/// Returns the fields of OP stack deposit receipts, which are only set for deposits.
+fn deposit_receipt_fields(
+    deposit_nonce: Option<u64>,
+    deposit_receipt_version: Option<u64>,
+) -> Vec<(&'static str, String)> {
+    [("depositNonce", deposit_nonce), ("depositReceiptVersion", deposit_receipt_version)]
+        .into_iter()
+        .filter_map(|(name, value)| Some((name, value?.pretty())))
+        .collect()
+}
+
+impl UIfmt for OpTransactionReceipt {
+    fn pretty(&self) -> String {
+        let mut pretty = pretty_receipt(&self.inner, self.inner.inner.ty());
+        push_receipt_fields(&mut pretty, self.extra_fields_pretty());
+        pretty
+    }
+}
+
+impl UIfmtReceiptExt for OpTransactionReceipt {
+    fn logs_pretty(&self) -> String {
+        receipt_logs_pretty(&self.inner)
+    }
+
+    fn logs_bloom_pretty(&self) -> String {
+        receipt_logs_bloom_pretty(&self.inner)
+    }
+
+    fn tx_type_pretty(&self) -> String {
+        self.inner.inner.ty().to_string()
+    }
+
+    fn extra_fields_pretty(&self) -> Vec<(&'static str, String)> {
+        let deposit = self.inner.inner.as_deposit_receipt();
+        let mut fields = deposit_receipt_fields(
+            deposit.and_then(|receipt| receipt.deposit_nonce),
+            deposit.and_then(|receipt| receipt.deposit_receipt_version),
+        );
+
+        // Deposits don't pay for their L1 data.
+        let info = &self.l1_block_info;
+        fields.extend(
+            [
+                ("l1GasPrice", info.l1_gas_price),
+                ("l1GasUsed", info.l1_gas_used),
+                ("l1Fee", info.l1_fee),
+                ("l1BaseFeeScalar", info.l1_base_fee_scalar),
+                ("l1BlobBaseFee", info.l1_blob_base_fee),
+                ("l1BlobBaseFeeScalar", info.l1_blob_base_fee_scalar),
+            ]
+            .into_iter()
+            .filter_map(|(name, value)| Some((name, value?.pretty()))),
+        );
+        fields
+    }
+}
+
+impl UIfmt for FoundryTxReceipt {
+    fn pretty(&self) -> String {
+        let mut pretty = pretty_receipt(&self.0.inner, u8::from(self.0.inner.inner.tx_type()));
+        push_receipt_fields(&mut pretty, self.extra_fields_pretty());
+        pretty.push_str(&self.0.other.pretty());
+        pretty
+    }
+}
+
+impl UIfmtReceiptExt for FoundryTxReceipt {
+    fn logs_pretty(&self) -> String {
+        receipt_logs_pretty(&self.0.inner)
+    }
+
+    fn logs_bloom_pretty(&self) -> String {
+        receipt_logs_bloom_pretty(&self.0.inner)
+    }
+
+    fn tx_type_pretty(&self) -> String {
+        u8::from(self.0.inner.inner.tx_type()).to_string()
+    }
+
+    fn extra_fields_pretty(&self) -> Vec<(&'static str, String)> {
+        deposit_receipt_fields(self.deposit_nonce(), self.deposit_receipt_version())
+    }
 }
 
 /// Returns the `UiFmt::pretty()` formatted attribute of the transactions
@@ -1023,7 +1125,10 @@ pub fn get_pretty_receipt_attr<N>(receipt: &N::ReceiptResponse, attr: &str) -> O
         "type" | "transaction_type" => Some(receipt.tx_type_pretty()),
         "blobGasPrice" | "blob_gas_price" => Some(receipt.blob_gas_price().pretty()),
         "blobGasUsed" | "blob_gas_used" => Some(receipt.blob_gas_used().pretty()),
-        _ => None,
+        other => receipt
+            .extra_fields_pretty()
+            .into_iter()
+            .find_map(|(name, value)| (name == other).then_some(value)),
     }
 }
 
@@ -1908,4 +2013,70 @@ fn test_get_pretty_receipt_attr() {
         assert_eq!(Some("[]".to_string()), get_pretty_receipt_attr::<Ethereum>(&receipt, "logs"));
         assert!(get_pretty_receipt_attr::<Ethereum>(&receipt, "logsBloom").is_some());
     }
+
+    #[test]
+    fn can_pretty_print_op_receipts() {
+        let mut receipt_json = serde_json::json!({
+            "type": "0x2",
+            "status": "0x1",
+            "cumulativeGasUsed": "0x5208",
+            "logs": [],
+            "logsBloom": format!("0x{}", "0".repeat(512)),
+            "transactionHash": "0x1234567890123456789012345678901234567890123456789012345678901234",
+            "transactionIndex": "0x1",
+            "blockHash": "0xabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcdefabcd",
+            "blockNumber": "0x1",
+            "gasUsed": "0x5208",
+            "effectiveGasPrice": "0x3b9aca00",
+            "from": "0x1234567890123456789012345678901234567890",
+            "to": "0x0987654321098765432109876543210987654321",
+            "contractAddress": null,
+            "l1GasPrice": "0x3b9aca00",
+            "l1GasUsed": "0x640",
+            "l1Fee": "0x5af3107a4000",
+            "l1BaseFeeScalar": "0x558",
+            "l1BlobBaseFee": "0x1",
+            "l1BlobBaseFeeScalar": "0xc5fc5"
+        });
+        let receipt: OpTransactionReceipt = serde_json::from_value(receipt_json.clone()).unwrap();
+        assert!(receipt.pretty().ends_with(
+            "
+l1GasPrice           1000000000
+l1GasUsed            1600
+l1Fee                100000000000000
+l1BaseFeeScalar      1368
+l1BlobBaseFee        1
+l1BlobBaseFeeScalar  810949"
+        ));
+        assert_eq!(receipt.tx_type_pretty(), "2");
+
+        // Deposits have no L1 fee but a deposit nonce, which is also formatted by the Foundry
+        // receipt.
+        let fields = receipt_json.as_object_mut().unwrap();
+        fields.retain(|key, _| !key.starts_with("l1"));
+        fields.insert("type".to_string(), "0x7e".into());
+        fields.insert("depositNonce".to_string(), "0x2a".into());
+        fields.insert("depositReceiptVersion".to_string(), "0x1".into());
+        let receipt: OpTransactionReceipt = serde_json::from_value(receipt_json.clone()).unwrap();
+        assert_eq!(
+            receipt.extra_fields_pretty(),
+            [("depositNonce", "42".to_string()), ("depositReceiptVersion", "1".to_string())]
+        );
+
+        let receipt: FoundryTxReceipt = serde_json::from_value(receipt_json).unwrap();
+        assert!(receipt.pretty().contains(
+            "
+to                   0x0987654321098765432109876543210987654321
+depositNonce         42
+depositReceiptVersion 1"
+        ));
+        assert_eq!(
+            get_pretty_receipt_attr::<foundry_primitives::FoundryNetwork>(&receipt, "depositNonce"),
+            Some("42".to_string())
+        );
+        assert_eq!(
+            get_pretty_receipt_attr::<foundry_primitives::FoundryNetwork>(&receipt, "type"),
+            Some("126".to_string())
+        );
+    }
 }
@@ -0,0 +1,127 @@
+//! Canonical addresses of well-known contracts per chain.
+
+use alloy_chains::NamedChain;
+use alloy_primitives::{Address, address};
+use std::{fmt, str::FromStr};
+
+/// The CREATE2 deployer (Arachnid's deterministic deployment proxy).
+pub const CREATE2_DEPLOYER: Address = address!("0x4e59b44847b379578588920ca78fbf26c0b4956c");
+
+/// Uniswap's Permit2.
+pub const PERMIT2: Address = address!("0x000000000022D473030F116dDEE9F6B43aC78BA3");
+
+/// Multicall3.
+pub const MULTICALL3: Address = address!("0xcA11bde05977b3631167028862bE2a173976CA11");
+
+/// The predeployed WETH of OP Stack chains.
+const OP_STACK_WETH: Address = address!("0x4200000000000000000000000000000000000006");
+
+/// A well-known contract.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WellKnown {
+    /// Wrapped ether.
+    Weth,
+    /// Uniswap's Permit2.
+    Permit2,
+    /// Multicall3.
+    Multicall3,
+    /// The CREATE2 deployer.
+    Create2Deployer,
+}
+
+impl WellKnown {
+    /// All well-known contracts.
+    pub const ALL: [Self; 4] = [Self::Weth, Self::Permit2, Self::Multicall3, Self::Create2Deployer];
+
+    /// Returns the name of the contract.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Weth => "WETH",
+            Self::Permit2 => "PERMIT2",
+            Self::Multicall3 => "MULTICALL3",
+            Self::Create2Deployer => "CREATE2_DEPLOYER",
+        }
+    }
+
+    /// Returns the address of the contract on the given chain, if known.
+    pub fn address(&self, chain_id: u64) -> Option<Address> {
+        match self {
+            Self::Weth => weth(chain_id),
+            // Deployed at the same address on every chain.
+            Self::Permit2 => Some(PERMIT2),
+            Self::Multicall3 => Some(MULTICALL3),
+            Self::Create2Deployer => Some(CREATE2_DEPLOYER),
+        }
+    }
+}
+
+impl fmt::Display for WellKnown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for WellKnown {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.to_ascii_uppercase().replace('-', "_");
+        Self::ALL.into_iter().find(|known| known.as_str() == name).ok_or_else(|| {
+            let names = Self::ALL.map(|known| known.as_str()).join(", ");
+            format!("unknown well-known contract `{s}`, expected one of: {names}")
+        })
+    }
+}
+
+/// Returns the address of the given well-known contract on the given chain, if known.
+pub fn well_known(name: &str, chain_id: u64) -> Result<Option<Address>, String> {
+    Ok(name.parse::<WellKnown>()?.address(chain_id))
+}
+
+/// Returns the canonical WETH of the given chain, if known.
+fn weth(chain_id: u64) -> Option<Address> {
+    let chain = NamedChain::try_from(chain_id).ok()?;
+    Some(match chain {
+        NamedChain::Mainnet => address!("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"),
+        NamedChain::Sepolia => address!("0xfFf9976782d46CC05630D1f6eBAb18b2324d6B14"),
+        NamedChain::Holesky => address!("0x94373a4919B3240D86eA41593D5eBa789FEF3848"),
+        NamedChain::Optimism
+        | NamedChain::OptimismSepolia
+        | NamedChain::Base
+        | NamedChain::BaseSepolia => OP_STACK_WETH,
+        NamedChain::Arbitrum => address!("0x82aF49447D8a07e3bd95BD0d56f35241523fBab1"),
+        NamedChain::ArbitrumSepolia => address!("0x980B62Da83eFf3D4576C647993b0c1D7faf17c73"),
+        NamedChain::Polygon => address!("0x7ceB23fD6bC0adD59E62ac25578270cFf1b9f619"),
+        NamedChain::Gnosis => address!("0x6A023CCd1ff6F2045C3309768eAd9E68F978f6e1"),
+        NamedChain::Scroll => address!("0x5300000000000000000000000000000000000004"),
+        NamedChain::Linea => address!("0xe5D7C2a44FfDDf6b295A15c148167daaAf5Cf34f"),
+        NamedChain::Blast => address!("0x4300000000000000000000000000000000000004"),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_well_known() {
+        for known in WellKnown::ALL {
+            assert_eq!(known.as_str().parse::<WellKnown>().unwrap(), known);
+        }
+        assert_eq!("weth".parse::<WellKnown>().unwrap(), WellKnown::Weth);
+        assert_eq!("create2-deployer".parse::<WellKnown>().unwrap(), WellKnown::Create2Deployer);
+        assert!("USDC".parse::<WellKnown>().is_err());
+    }
+
+    #[test]
+    fn well_known_addresses() {
+        assert_eq!(
+            well_known("WETH", 1).unwrap(),
+            Some(address!("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"))
+        );
+        assert_eq!(well_known("WETH", 8453).unwrap(), Some(OP_STACK_WETH));
+        assert_eq!(well_known("WETH", 31337).unwrap(), None);
+        assert_eq!(well_known("MULTICALL3", 31337).unwrap(), Some(MULTICALL3));
+    }
+}
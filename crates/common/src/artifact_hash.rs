@@ -0,0 +1,260 @@
+//! Deterministic artifact hashing, and comparison of committed artifacts against a fresh build.
+
+use alloy_primitives::{B256, keccak256};
+use eyre::Result;
+use foundry_compilers::{ArtifactId, artifacts::ConfigurableContractArtifact};
+use serde::Serialize;
+use serde_json::Value;
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+/// Artifact fields which depend on the other sources of the build, and not on the contract.
+const VOLATILE_FIELDS: &[&str] = &["id"];
+
+/// Returns the content hash of an artifact.
+///
+/// The hash is computed over the canonical JSON of the artifact, with object keys sorted and
+/// without whitespace, so that it doesn't depend on how the artifact was written.
+pub fn artifact_hash<T: Serialize>(artifact: &T) -> Result<B256> {
+    Ok(json_artifact_hash(&serde_json::to_value(artifact)?))
+}
+
+/// Returns the content hash of an artifact in its JSON representation.
+///
+/// See [`artifact_hash`].
+pub fn json_artifact_hash(artifact: &Value) -> B256 {
+    let mut canonical = String::new();
+    match artifact {
+        Value::Object(fields) => {
+            let fields = fields
+                .iter()
+                .filter(|(key, _)| !VOLATILE_FIELDS.contains(&key.as_str()))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+            write_canonical(&Value::Object(fields), &mut canonical);
+        }
+        _ => write_canonical(artifact, &mut canonical),
+    }
+    keccak256(canonical)
+}
+
+/// Writes the canonical JSON of `value`: object keys sorted, no whitespace.
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(fields) => {
+            out.push('{');
+            let mut keys = fields.keys().collect::<Vec<_>>();
+            keys.sort();
+            for (i, key) in keys.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(&fields[key], out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        _ => out.push_str(&value.to_string()),
+    }
+}
+
+/// How a committed artifact differs from a fresh build.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum ArtifactChange {
+    /// The committed artifact has different contents.
+    Changed {
+        /// The top-level fields that differ.
+        fields: Vec<String>,
+        /// The hash of the committed artifact.
+        committed: B256,
+        /// The hash of the fresh artifact.
+        fresh: B256,
+    },
+    /// The artifact is produced by the build, but isn't committed.
+    Missing,
+    /// The committed artifact isn't produced by the build anymore.
+    Stale,
+}
+
+/// A committed artifact which differs from a fresh build.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ArtifactDiff {
+    /// The contract identifier, or the artifact path for stale artifacts.
+    pub contract: String,
+    /// The path of the artifact.
+    pub path: PathBuf,
+    /// How the artifact differs.
+    #[serde(flatten)]
+    pub change: ArtifactChange,
+}
+
+/// Compares the artifacts committed in `artifacts_dir` with the artifacts of a fresh build.
+///
+/// If `partial` is set, the fresh build only compiled a subset of the sources, and only the
+/// committed artifacts of the compiled sources can be stale.
+///
+/// Returns the artifacts that differ, sorted by path.
+pub fn diff_artifacts<'a>(
+    artifacts_dir: &Path,
+    fresh: impl IntoIterator<Item = (ArtifactId, &'a ConfigurableContractArtifact)>,
+    partial: bool,
+) -> Result<Vec<ArtifactDiff>> {
+    let mut diffs = Vec::new();
+    let mut produced = BTreeSet::new();
+    for (id, artifact) in fresh {
+        let path = artifacts_dir.join(&id.path);
+        let contract = id.identifier();
+        produced.insert(path.clone());
+
+        let Ok(committed) = std::fs::read_to_string(&path) else {
+            diffs.push(ArtifactDiff { contract, path, change: ArtifactChange::Missing });
+            continue;
+        };
+        let committed: Value = serde_json::from_str(&committed)?;
+        let fresh = serde_json::to_value(artifact)?;
+        let (committed_hash, fresh_hash) =
+            (json_artifact_hash(&committed), json_artifact_hash(&fresh));
+        if committed_hash != fresh_hash {
+            let change = ArtifactChange::Changed {
+                fields: changed_fields(&committed, &fresh),
+                committed: committed_hash,
+                fresh: fresh_hash,
+            };
+            diffs.push(ArtifactDiff { contract, path, change });
+        }
+    }
+
+    let compiled_sources =
+        produced.iter().filter_map(|path| path.parent()).collect::<BTreeSet<_>>();
+    for path in committed_artifacts(artifacts_dir) {
+        if produced.contains(&path)
+            || (partial && !path.parent().is_some_and(|dir| compiled_sources.contains(dir)))
+        {
+            continue;
+        }
+        let contract = path.strip_prefix(artifacts_dir).unwrap_or(&path).display().to_string();
+        diffs.push(ArtifactDiff { contract, path, change: ArtifactChange::Stale });
+    }
+
+    diffs.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(diffs)
+}
+
+/// Rewrites the absolute paths of the artifact at `path` relative to the project `root`.
+///
+/// Returns whether the artifact was changed.
+pub fn normalize_artifact_paths(path: &Path, root: &Path) -> Result<bool> {
+    let artifact = std::fs::read_to_string(path)?;
+    // The root as written in JSON strings.
+    let root = serde_json::to_string(&root.to_string_lossy())?;
+    let root = &root[1..root.len() - 1];
+    let normalized = artifact
+        .replace(&format!("{root}/"), "")
+        .replace(&format!("{root}\\\\"), "")
+        .replace(root, ".");
+    if normalized == artifact {
+        return Ok(false);
+    }
+    std::fs::write(path, normalized)?;
+    Ok(true)
+}
+
+/// Returns the top-level fields which differ between two artifacts.
+fn changed_fields(committed: &Value, fresh: &Value) -> Vec<String> {
+    let empty = serde_json::Map::new();
+    let committed = committed.as_object().unwrap_or(&empty);
+    let fresh = fresh.as_object().unwrap_or(&empty);
+    committed
+        .keys()
+        .chain(fresh.keys())
+        .filter(|key| !VOLATILE_FIELDS.contains(&key.as_str()))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter(|key| committed.get(*key) != fresh.get(*key))
+        .cloned()
+        .collect()
+}
+
+/// Returns the contract artifacts in `artifacts_dir`: the JSON files in `<source file>/`
+/// directories, excluding metadata files.
+fn committed_artifacts(artifacts_dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(artifacts_dir) else { return Vec::new() };
+    entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|dir| {
+            dir.is_dir()
+                && dir.extension().is_some_and(|ext| ext == "sol" || ext == "vy" || ext == "vyi")
+        })
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == "json")
+                && !path.to_string_lossy().ends_with(".metadata.json")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn hash_is_independent_of_formatting() {
+        let a: Value =
+            serde_json::from_str(r#"{"abi":[],"bytecode":{"object":"0x00"},"id":1}"#).unwrap();
+        let b: Value = serde_json::from_str(
+            r#"{
+                "id": 7,
+                "bytecode": { "object": "0x00" },
+                "abi": []
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(json_artifact_hash(&a), json_artifact_hash(&b));
+
+        let c = json!({ "abi": [], "bytecode": { "object": "0x01" } });
+        assert_ne!(json_artifact_hash(&a), json_artifact_hash(&c));
+    }
+
+    #[test]
+    fn normalizes_artifact_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("Counter.json");
+        let root = Path::new("/home/user/project");
+        std::fs::write(
+            &path,
+            r#"{"ast":{"absolutePath":"/home/user/project/src/Counter.sol"},"root":"/home/user/project"}"#,
+        )
+        .unwrap();
+
+        assert!(normalize_artifact_paths(&path, root).unwrap());
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            r#"{"ast":{"absolutePath":"src/Counter.sol"},"root":"."}"#
+        );
+        assert!(!normalize_artifact_paths(&path, root).unwrap());
+    }
+
+    #[test]
+    fn lists_changed_fields() {
+        let committed = json!({ "abi": [], "bytecode": { "object": "0x00" }, "id": 1 });
+        let fresh = json!({ "abi": [], "bytecode": { "object": "0x01" }, "id": 2, "ast": {} });
+        assert_eq!(changed_fields(&committed, &fresh), ["ast", "bytecode"]);
+    }
+}
@@ -16,18 +16,20 @@
     info::ContractInfo as CompilerContractInfo,
     multi::{MultiCompiler, MultiCompilerSettings},
     project::Preprocessor,
-    report::{BasicStdoutReporter, NoReporter, Report},
+    report::{BasicStdoutReporter, NoReporter, Report, Reporter},
     solc::SolcSettings,
 };
 use num_format::{Locale, ToFormattedString};
+use semver::Version;
+use serde::Serialize;
 use std::{
     collections::BTreeMap,
     fmt::Display,
     io::IsTerminal,
     path::{Path, PathBuf},
     str::FromStr,
-    sync::Arc,
-    time::Instant,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 
 /// A Solar compiler instance, to grant syntactic and semantic analysis capabilities.
@@ -62,6 +64,9 @@ pub struct ProjectCompiler {
 
     /// Whether to compile with dynamic linking tests and scripts.
     dynamic_test_linking: bool,
+
+    /// Whether to print the time spent compiling each compilation unit.
+    timings: bool,
 }
 
 impl Default for ProjectCompiler {
@@ -84,6 +89,7 @@ pub fn new() -> Self {
             ignore_eip_3860: false,
             files: Vec::new(),
             dynamic_test_linking: false,
+            timings: false,
         }
     }
 
@@ -137,6 +143,13 @@ pub const fn dynamic_test_linking(mut self, preprocess: bool) -> Self {
         self
     }
 
+    /// Sets whether to print the time spent compiling each compilation unit.
+    #[inline]
+    pub const fn timings(mut self, yes: bool) -> Self {
+        self.timings = yes;
+        self
+    }
+
     /// Compiles the project.
     #[instrument(target = "forge::compile", skip_all)]
     pub fn compile<C: Compiler<CompilerContract = Contract>>(
@@ -189,7 +202,8 @@ fn compile_with<C: Compiler<CompilerContract = Contract>, F>(
         let quiet = self.quiet.unwrap_or(false);
         let bail = self.bail.unwrap_or(true);
 
-        let output = with_compilation_reporter(quiet, Some(self.project_root.clone()), || {
+        let timings = self.timings.then(CompilationTimings::default);
+        let compile = || {
             tracing::debug!("compiling project");
 
             let timer = Instant::now();
@@ -198,7 +212,18 @@ fn compile_with<C: Compiler<CompilerContract = Contract>, F>(
 
             tracing::debug!("finished compiling in {:.3}s", elapsed.as_secs_f64());
             r
-        })?;
+        };
+        let root = Some(self.project_root.clone());
+        let output = match &timings {
+            Some(timings) => with_timed_compilation_reporter(quiet, root, timings, compile),
+            None => with_compilation_reporter(quiet, root, compile),
+        }?;
+
+        if let Some(timings) = &timings
+            && !timings.is_empty()
+        {
+            timings.print()?;
+        }
 
         if bail && output.has_compiler_errors() {
             eyre::bail!("{output}")
@@ -573,6 +598,165 @@ pub fn with_compilation_reporter<O>(
     foundry_compilers::report::with_scoped(&reporter, f)
 }
 
+/// Configures the reporter, additionally recording the compilation units into `timings`, and runs
+/// the given closure.
+pub fn with_timed_compilation_reporter<O>(
+    quiet: bool,
+    project_root: Option<PathBuf>,
+    timings: &CompilationTimings,
+    f: impl FnOnce() -> O,
+) -> O {
+    let timed = |inner| TimingsReporter { inner, timings: timings.clone() };
+    #[expect(clippy::collapsible_else_if)]
+    let reporter = if quiet || shell::is_json() {
+        Report::new(timed(Box::new(NoReporter::default())))
+    } else {
+        if std::io::stdout().is_terminal() {
+            Report::new(timed(Box::new(SpinnerReporter::spawn(project_root))))
+        } else {
+            Report::new(timed(Box::new(BasicStdoutReporter::default())))
+        }
+    };
+
+    foundry_compilers::report::with_scoped(&reporter, f)
+}
+
+/// A compilation unit, i.e. a single compiler invocation.
+#[derive(Clone, Debug, Serialize)]
+pub struct CompilationUnit {
+    /// The name of the compiler.
+    pub compiler: String,
+    /// The version of the compiler.
+    pub version: Version,
+    /// The number of files compiled.
+    pub files: usize,
+    /// The time at which the compiler was spawned, relative to the start of the compilation.
+    pub started: Duration,
+    /// The time spent compiling, if the compiler succeeded.
+    pub duration: Option<Duration>,
+}
+
+/// The compilation units of a project compilation, recorded as the compilers run.
+#[derive(Clone, Debug)]
+pub struct CompilationTimings {
+    start: Instant,
+    units: Arc<Mutex<Vec<CompilationUnit>>>,
+}
+
+impl Default for CompilationTimings {
+    fn default() -> Self {
+        Self { start: Instant::now(), units: Default::default() }
+    }
+}
+
+impl CompilationTimings {
+    /// Returns the recorded compilation units, in the order they were spawned.
+    pub fn units(&self) -> Vec<CompilationUnit> {
+        self.units.lock().unwrap().clone()
+    }
+
+    /// Returns `true` if no compilation unit was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.units.lock().unwrap().is_empty()
+    }
+
+    /// Prints the recorded compilation units as a table, or as JSON.
+    pub fn print(&self) -> Result<()> {
+        let units = self.units();
+        if shell::is_json() {
+            sh_println!("{}", serde_json::to_string(&units)?)?;
+            return Ok(());
+        }
+
+        let mut table = Table::new();
+        if shell::is_markdown() {
+            table.load_preset(ASCII_MARKDOWN);
+        } else {
+            table.apply_modifier(UTF8_ROUND_CORNERS);
+        }
+        table.set_header(["Compiler", "Version", "Files", "Started", "Duration"]);
+        for unit in &units {
+            table.add_row([
+                Cell::new(&unit.compiler),
+                Cell::new(&unit.version),
+                Cell::new(unit.files),
+                Cell::new(format!("{:.2?}", unit.started)),
+                match unit.duration {
+                    Some(duration) => Cell::new(format!("{duration:.2?}")),
+                    None => Cell::new("failed").fg(Color::Red),
+                },
+            ]);
+        }
+        sh_println!("\n{table}\n")?;
+
+        let busy: Duration = units.iter().filter_map(|unit| unit.duration).sum();
+        let wall = units
+            .iter()
+            .filter_map(|unit| Some(unit.started + unit.duration?))
+            .max()
+            .unwrap_or_default();
+        sh_println!("Compiled {} units in {wall:.2?} ({busy:.2?} of compiler time)", units.len())?;
+        Ok(())
+    }
+
+    fn spawn(&self, compiler: &str, version: &Version, files: usize) {
+        self.units.lock().unwrap().push(CompilationUnit {
+            compiler: compiler.to_string(),
+            version: version.clone(),
+            files,
+            started: self.start.elapsed(),
+            duration: None,
+        });
+    }
+
+    fn finish(&self, compiler: &str, version: &Version, duration: Duration) {
+        // Units of the same compiler version may run in parallel: attribute the duration to the
+        // earliest spawned unit that has not finished yet.
+        let mut units = self.units.lock().unwrap();
+        if let Some(unit) = units.iter_mut().find(|unit| {
+            unit.duration.is_none() && unit.compiler == compiler && unit.version == *version
+        }) {
+            unit.duration = Some(duration);
+        }
+    }
+}
+
+/// A [`Reporter`] which records compilation units into [`CompilationTimings`], and forwards all
+/// events to an inner reporter.
+#[derive(Debug)]
+struct TimingsReporter {
+    inner: Box<dyn Reporter + Send + Sync>,
+    timings: CompilationTimings,
+}
+
+impl Reporter for TimingsReporter {
+    fn on_compiler_spawn(&self, compiler_name: &str, version: &Version, dirty_files: &[PathBuf]) {
+        self.timings.spawn(compiler_name, version, dirty_files.len());
+        self.inner.on_compiler_spawn(compiler_name, version, dirty_files);
+    }
+
+    fn on_compiler_success(&self, compiler_name: &str, version: &Version, duration: &Duration) {
+        self.timings.finish(compiler_name, version, *duration);
+        self.inner.on_compiler_success(compiler_name, version, duration);
+    }
+
+    fn on_solc_installation_start(&self, version: &Version) {
+        self.inner.on_solc_installation_start(version);
+    }
+
+    fn on_solc_installation_success(&self, version: &Version) {
+        self.inner.on_solc_installation_success(version);
+    }
+
+    fn on_solc_installation_error(&self, version: &Version, error: &str) {
+        self.inner.on_solc_installation_error(version, error);
+    }
+
+    fn on_unresolved_imports(&self, imports: &[(&Path, &Path)], remappings: &[Remapping]) {
+        self.inner.on_unresolved_imports(imports, remappings);
+    }
+}
+
 /// Container type for parsing contract identifiers from CLI.
 ///
 /// Passed string can be of the following forms:
@@ -660,4 +844,24 @@ fn parse_contract_identifiers() {
             })
         );
     }
+
+    #[test]
+    fn records_parallel_compilation_units() {
+        let timings = CompilationTimings::default();
+        let reporter =
+            TimingsReporter { inner: Box::new(NoReporter::default()), timings: timings.clone() };
+        let v1 = Version::new(0, 8, 30);
+        let v2 = Version::new(0, 7, 6);
+        reporter.on_compiler_spawn("Solc", &v1, &[PathBuf::from("A.sol"), PathBuf::from("B.sol")]);
+        reporter.on_compiler_spawn("Solc", &v1, &[PathBuf::from("C.sol")]);
+        reporter.on_compiler_spawn("Solc", &v2, &[PathBuf::from("D.sol")]);
+        reporter.on_compiler_success("Solc", &v1, &Duration::from_secs(2));
+        reporter.on_compiler_success("Solc", &v2, &Duration::from_secs(1));
+
+        let units = timings.units();
+        assert_eq!(units.len(), 3);
+        assert_eq!((units[0].files, units[0].duration), (2, Some(Duration::from_secs(2))));
+        assert_eq!((units[1].files, units[1].duration), (1, None));
+        assert_eq!((&units[2].version, units[2].duration), (&v2, Some(Duration::from_secs(1))));
+    }
 }
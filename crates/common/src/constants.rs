@@ -2,7 +2,7 @@
 
 use alloy_eips::Typed2718;
 use alloy_network::AnyTxEnvelope;
-use alloy_primitives::{Address, B256, Signature, address};
+use alloy_primitives::{Address, B256, FixedBytes, Signature, address, fixed_bytes};
 use std::time::Duration;
 
 /// The dev chain-id, inherited from hardhat
@@ -52,6 +52,12 @@
 /// Transaction identifier of System transaction types
 pub const SYSTEM_TRANSACTION_TYPE: u8 = 126;
 
+/// The EIP-1271 `isValidSignature(bytes32,bytes)` selector, returned by valid signatures.
+pub const ERC1271_MAGIC_VALUE: FixedBytes<4> = fixed_bytes!("0x1626ba7e");
+
+/// The value returned by EIP-1271 `isValidSignature` for invalid signatures.
+pub const ERC1271_INVALID_VALUE: FixedBytes<4> = fixed_bytes!("0xffffffff");
+
 /// Default user agent set as the header for requests that don't specify one.
 pub const DEFAULT_USER_AGENT: &str = concat!("foundry/", env!("CARGO_PKG_VERSION"));
 
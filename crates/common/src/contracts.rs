@@ -1,6 +1,9 @@
 //! Commonly used contract types and functions.
 
-use crate::{compile::PathOrContractInfo, find_metadata_start, strip_bytecode_placeholders};
+use crate::{
+    compile::PathOrContractInfo, find_metadata_start, normalize::NormalizeArtifact,
+    strip_bytecode_placeholders,
+};
 use alloy_dyn_abi::JsonAbiExt;
 use alloy_json_abi::{Event, Function, JsonAbi};
 use alloy_primitives::{Address, B256, Bytes, Selector, hex};
@@ -14,6 +17,7 @@
     },
     utils::canonicalized,
 };
+use semver::Version;
 use std::{
     collections::BTreeMap,
     ops::Deref,
@@ -586,16 +590,33 @@ pub fn find_target_path(project: &Project, identifier: &PathOrContractInfo) -> R
     }
 }
 
-/// Returns the target artifact given the path and name.
+/// Returns the target artifact given the path and name, normalized to the latest solc schema.
 pub fn find_matching_contract_artifact(
     output: &mut ProjectCompileOutput,
     target_path: &Path,
     target_name: Option<&str>,
 ) -> eyre::Result<ConfigurableContractArtifact> {
+    let (mut artifact, version) = find_contract_artifact(output, target_path, target_name)?;
+    if let Some(version) = version {
+        artifact.normalize(&version);
+    }
+    Ok(artifact)
+}
+
+fn find_contract_artifact(
+    output: &mut ProjectCompileOutput,
+    target_path: &Path,
+    target_name: Option<&str>,
+) -> eyre::Result<(ConfigurableContractArtifact, Option<Version>)> {
     if let Some(name) = target_name {
-        output
+        let version = output
+            .artifact_ids()
+            .find(|(id, _)| id.source == target_path && id.name == name)
+            .map(|(id, _)| id.version);
+        let artifact = output
             .remove(target_path, name)
-            .ok_or_eyre(format!("Could not find artifact `{name}` in the compiled artifacts"))
+            .ok_or_eyre(format!("Could not find artifact `{name}` in the compiled artifacts"))?;
+        Ok((artifact, version))
     } else {
         let possible_targets = output
             .artifact_ids()
@@ -610,7 +631,7 @@ pub fn find_matching_contract_artifact(
 
         let (target_id, target_artifact) = possible_targets[0].clone();
         if possible_targets.len() == 1 {
-            return Ok(target_artifact.clone());
+            return Ok((target_artifact.clone(), Some(target_id.version)));
         }
 
         // If all artifact_ids in `possible_targets` have the same name (without ".", indicates
@@ -626,12 +647,13 @@ pub fn find_matching_contract_artifact(
 
         // Otherwise, we're dealing with additional compiler profiles wherein `id.source` is the
         // same but `id.path` is different.
-        let artifact = possible_targets
+        let (id, artifact) = possible_targets
             .iter()
-            .find_map(|(id, artifact)| (id.profile == "default").then_some(*artifact))
-            .unwrap_or(target_artifact);
+            .find(|(id, _)| id.profile == "default")
+            .cloned()
+            .unwrap_or((target_id, target_artifact));
 
-        Ok(artifact.clone())
+        Ok((artifact.clone(), Some(id.version)))
     }
 }
 
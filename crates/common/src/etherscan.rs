@@ -0,0 +1,203 @@
+//! Request budgeting and backoff for Etherscan-compatible explorer APIs.
+
+use foundry_block_explorers::errors::EtherscanError;
+use foundry_config::{Chain, EtherscanRequestLimits};
+use reqwest::StatusCode;
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, Mutex},
+    time::Duration,
+};
+use tokio::time::Instant;
+
+/// The default number of retries when the API is rate limited or unavailable.
+pub const DEFAULT_ETHERSCAN_RETRIES: u32 = 5;
+
+/// The default initial backoff when the API is rate limited or unavailable.
+pub const DEFAULT_ETHERSCAN_RETRY_BACKOFF: Duration = Duration::from_secs(1);
+
+/// The next free request slot for every chain, shared by all clients in the process.
+static NEXT_SLOTS: LazyLock<Mutex<HashMap<u64, Arc<Mutex<Instant>>>>> =
+    LazyLock::new(Default::default);
+
+/// Spaces out requests to an explorer API according to the per-chain request budget and retries
+/// requests rejected with `429 Too Many Requests` or `503 Service Unavailable` with exponential
+/// backoff.
+///
+/// The budget is shared by every throttle created for the same chain, so separate commands or
+/// clients running in the same process don't exceed it together.
+#[derive(Clone, Debug)]
+pub struct EtherscanThrottle {
+    interval: Option<Duration>,
+    retries: u32,
+    backoff: Duration,
+    next_slot: Arc<Mutex<Instant>>,
+}
+
+impl EtherscanThrottle {
+    /// Creates a new throttle for `chain` with the given limits.
+    pub fn new(chain: Chain, limits: EtherscanRequestLimits) -> Self {
+        let next_slot = NEXT_SLOTS
+            .lock()
+            .unwrap()
+            .entry(chain.id())
+            .or_insert_with(|| Arc::new(Mutex::new(Instant::now())))
+            .clone();
+        Self::with_slot(limits, next_slot)
+    }
+
+    /// Creates a new throttle with the given limits whose budget is not shared with any other
+    /// throttle, e.g. for an explorer of an unknown chain.
+    pub fn unshared(limits: EtherscanRequestLimits) -> Self {
+        Self::with_slot(limits, Arc::new(Mutex::new(Instant::now())))
+    }
+
+    fn with_slot(limits: EtherscanRequestLimits, next_slot: Arc<Mutex<Instant>>) -> Self {
+        Self {
+            interval: limits
+                .requests_per_second
+                .filter(|rps| *rps > 0)
+                .map(|rps| Duration::from_secs(1) / rps),
+            retries: limits.retries.unwrap_or(DEFAULT_ETHERSCAN_RETRIES),
+            backoff: limits
+                .retry_backoff
+                .map(Duration::from_millis)
+                .unwrap_or(DEFAULT_ETHERSCAN_RETRY_BACKOFF),
+            next_slot,
+        }
+    }
+
+    /// Runs the given request, waiting for a free slot in the chain's request budget first and
+    /// retrying it while the API is rate limited or unavailable.
+    pub async fn run<F, Fut, T>(&self, mut request: F) -> Result<T, EtherscanError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, EtherscanError>>,
+    {
+        let mut retries = self.retries;
+        let mut backoff = self.backoff;
+        loop {
+            self.wait().await;
+
+            match request().await {
+                Err(err) if retries > 0 && is_retryable(&err) => {
+                    retries -= 1;
+                    let _ = sh_warn!(
+                        "{err}; waiting {}ms before trying again ({retries} tries remaining)",
+                        backoff.as_millis()
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff = backoff.saturating_mul(2);
+                }
+                res => return res,
+            }
+        }
+    }
+
+    /// Waits for a free slot in the chain's request budget.
+    ///
+    /// Used for requests that must not be retried, e.g. because they are not idempotent.
+    pub async fn wait(&self) {
+        if let Some(slot) = self.reserve_slot() {
+            tokio::time::sleep_until(slot).await;
+        }
+    }
+
+    /// Reserves the next free request slot, if the chain has a request budget.
+    fn reserve_slot(&self) -> Option<Instant> {
+        let interval = self.interval?;
+        let mut next = self.next_slot.lock().unwrap();
+        let slot = (*next).max(Instant::now());
+        *next = slot + interval;
+        Some(slot)
+    }
+}
+
+/// Returns `true` if the request failed because the API is rate limited or temporarily
+/// unavailable.
+fn is_retryable(err: &EtherscanError) -> bool {
+    match err {
+        EtherscanError::RateLimitExceeded => true,
+        EtherscanError::Reqwest(err) => matches!(
+            err.status(),
+            Some(StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE)
+        ),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn no_backoff(retries: u32) -> EtherscanRequestLimits {
+        EtherscanRequestLimits {
+            retries: Some(retries),
+            retry_backoff: Some(0),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_rate_limited_requests() {
+        let throttle = EtherscanThrottle::new(Chain::from_id(31337), no_backoff(3));
+        let attempts = AtomicU32::new(0);
+        let res = throttle
+            .run(|| async {
+                if attempts.fetch_add(1, Ordering::Relaxed) < 2 {
+                    Err(EtherscanError::RateLimitExceeded)
+                } else {
+                    Ok(42)
+                }
+            })
+            .await
+            .unwrap();
+        assert_eq!(res, 42);
+        assert_eq!(attempts.load(Ordering::Relaxed), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_retries() {
+        let throttle = EtherscanThrottle::new(Chain::from_id(31337), no_backoff(1));
+        let attempts = AtomicU32::new(0);
+        let res = throttle
+            .run(|| async {
+                attempts.fetch_add(1, Ordering::Relaxed);
+                Err::<(), _>(EtherscanError::RateLimitExceeded)
+            })
+            .await;
+        assert!(matches!(res, Err(EtherscanError::RateLimitExceeded)));
+        assert_eq!(attempts.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_other_errors() {
+        let throttle = EtherscanThrottle::new(Chain::from_id(31337), no_backoff(3));
+        let attempts = AtomicU32::new(0);
+        let res = throttle
+            .run(|| async {
+                attempts.fetch_add(1, Ordering::Relaxed);
+                Err::<(), _>(EtherscanError::InvalidApiKey)
+            })
+            .await;
+        assert!(matches!(res, Err(EtherscanError::InvalidApiKey)));
+        assert_eq!(attempts.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn budget_is_shared_per_chain() {
+        let limits = EtherscanRequestLimits { requests_per_second: Some(4), ..Default::default() };
+        let a = EtherscanThrottle::new(Chain::from_id(1337), limits);
+        let b = EtherscanThrottle::new(Chain::from_id(1337), limits);
+        let first = a.reserve_slot().unwrap();
+        let second = b.reserve_slot().unwrap();
+        assert!(second >= first + Duration::from_millis(250));
+
+        let unlimited = EtherscanThrottle::new(Chain::from_id(1337), Default::default());
+        assert!(unlimited.reserve_slot().is_none());
+
+        let unshared = EtherscanThrottle::unshared(limits);
+        assert!(unshared.reserve_slot().unwrap() < second);
+    }
+}
@@ -17,16 +17,23 @@
 pub use foundry_common_fmt as fmt;
 
 pub mod abi;
+pub mod addresses;
+pub mod artifact_hash;
 pub mod calc;
 pub mod comments;
 pub mod compile;
 pub mod constants;
 pub mod contracts;
 pub mod errors;
+pub mod etherscan;
 pub mod fs;
 pub mod iter;
 pub mod mapping_slots;
+pub mod named_accounts;
+pub mod normalize;
 mod preprocessor;
+pub mod presets;
+pub mod provenance;
 pub mod provider;
 pub mod retry;
 pub mod selectors;
@@ -38,6 +45,7 @@
 pub mod transactions;
 mod utils;
 pub mod version;
+pub mod wallet;
 
 pub use compile::Analysis;
 pub use constants::*;
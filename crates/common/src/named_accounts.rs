@@ -0,0 +1,258 @@
+//! Named accounts: accounts with a role, a balance, and optionally code or an EIP-7702
+//! delegation, declared in a TOML file.
+//!
+//! They are set up at genesis by `anvil --accounts-config`, and in tests with
+//! `vm.loadNamedAccounts`.
+//!
+//! ```toml
+//! # Roles are funding profiles shared by accounts, in addition to the built-in `deployer`,
+//! # `treasury` and `user` roles.
+//! [roles.whale]
+//! balance = "1000000"
+//!
+//! [accounts.deployer]
+//! role = "deployer"
+//!
+//! [accounts.treasury]
+//! role = "treasury"
+//! code = "0x00"
+//!
+//! [accounts.user1]
+//! role = "user"
+//! delegate = "0x63c0c19a282a1B52b07dD5a65b58948A07DAE32B"
+//! ```
+//!
+//! Accounts without an `address` or a `private-key` use `keccak256(name)` as private key, like
+//! `vm.makeAddr(name)`.
+
+use alloy_primitives::{Address, B256, Bytes, U256, keccak256, utils::parse_ether};
+use alloy_signer::{k256::ecdsa::SigningKey, utils::secret_key_to_address};
+use eyre::{Result, WrapErr, bail};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, path::Path};
+
+/// The built-in roles, with their balance in ether.
+pub const BUILTIN_ROLES: [(&str, u64); 3] =
+    [("deployer", 10_000), ("treasury", 1_000_000), ("user", 100)];
+
+/// The prefix of EIP-7702 delegation designators, followed by the address of the delegate.
+const DELEGATION_DESIGNATOR: [u8; 3] = [0xef, 0x01, 0x00];
+
+/// A named accounts file.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NamedAccountsConfig {
+    /// The custom roles, by name.
+    #[serde(default)]
+    pub roles: BTreeMap<String, RoleConfig>,
+    /// The accounts, by name.
+    #[serde(default)]
+    pub accounts: BTreeMap<String, AccountConfig>,
+}
+
+/// A role of a [`NamedAccountsConfig`]: the default settings of its accounts.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoleConfig {
+    /// The balance, in ether.
+    pub balance: Option<Ether>,
+    /// The code.
+    pub code: Option<Bytes>,
+    /// The EIP-7702 delegate.
+    pub delegate: Option<Address>,
+}
+
+/// An account of a [`NamedAccountsConfig`], overriding the settings of its role.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct AccountConfig {
+    /// The role.
+    pub role: Option<String>,
+    /// The address, for accounts which can't sign.
+    pub address: Option<Address>,
+    /// The private key.
+    pub private_key: Option<B256>,
+    /// The balance, in ether.
+    pub balance: Option<Ether>,
+    /// The code.
+    pub code: Option<Bytes>,
+    /// The EIP-7702 delegate.
+    pub delegate: Option<Address>,
+}
+
+/// An amount of ether, either an integer or a decimal string.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Ether {
+    Integer(u64),
+    Decimal(String),
+}
+
+impl Ether {
+    /// Returns the amount in wei.
+    pub fn to_wei(&self) -> Result<U256> {
+        match self {
+            Self::Integer(ether) => Ok(parse_ether(&ether.to_string())?),
+            Self::Decimal(ether) => {
+                parse_ether(ether.trim()).wrap_err_with(|| format!("invalid balance `{ether}`"))
+            }
+        }
+    }
+}
+
+/// A named account, resolved from a [`NamedAccountsConfig`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamedAccount {
+    /// The name of the account.
+    pub name: String,
+    /// The role of the account, if any.
+    pub role: Option<String>,
+    /// The address of the account.
+    pub address: Address,
+    /// The private key of the account, unless only its address is known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<B256>,
+    /// The balance of the account, in wei.
+    pub balance: U256,
+    /// The code of the account: its code, or the delegation designator of its delegate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<Bytes>,
+    /// The EIP-7702 delegate of the account, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegate: Option<Address>,
+}
+
+impl NamedAccountsConfig {
+    /// Reads a named accounts file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = crate::fs::read_to_string(path)?;
+        toml::from_str(&content)
+            .wrap_err_with(|| format!("invalid named accounts file {}", path.display()))
+    }
+
+    /// Returns the role with the given name, custom or built-in.
+    pub fn role(&self, name: &str) -> Option<RoleConfig> {
+        if let Some(role) = self.roles.get(name) {
+            return Some(role.clone());
+        }
+        BUILTIN_ROLES.iter().find(|(role, _)| *role == name).map(|(_, balance)| RoleConfig {
+            balance: Some(Ether::Integer(*balance)),
+            ..Default::default()
+        })
+    }
+
+    /// Resolves the accounts, sorted by name.
+    pub fn resolve(&self) -> Result<Vec<NamedAccount>> {
+        self.accounts
+            .iter()
+            .map(|(name, account)| {
+                self.resolve_account(name, account)
+                    .wrap_err_with(|| format!("invalid named account `{name}`"))
+            })
+            .collect()
+    }
+
+    fn resolve_account(&self, name: &str, account: &AccountConfig) -> Result<NamedAccount> {
+        let role = match &account.role {
+            Some(role) => match self.role(role) {
+                Some(role) => role,
+                None => bail!("unknown role `{role}`"),
+            },
+            None => RoleConfig::default(),
+        };
+
+        let (address, private_key) = match (account.address, account.private_key) {
+            (Some(_), Some(_)) => bail!("`address` and `private-key` are mutually exclusive"),
+            (Some(address), None) => (address, None),
+            (None, private_key) => {
+                let private_key = private_key.unwrap_or_else(|| keccak256(name));
+                let key = SigningKey::from_slice(private_key.as_slice())?;
+                (secret_key_to_address(&key), Some(private_key))
+            }
+        };
+
+        let balance = match account.balance.as_ref().or(role.balance.as_ref()) {
+            Some(balance) => balance.to_wei()?,
+            None => U256::ZERO,
+        };
+
+        // The settings of the account override the ones of its role as a whole.
+        let (code, delegate) = if account.code.is_some() || account.delegate.is_some() {
+            (account.code.clone(), account.delegate)
+        } else {
+            (role.code, role.delegate)
+        };
+        let code = match (code, delegate) {
+            (Some(_), Some(_)) => bail!("`code` and `delegate` are mutually exclusive"),
+            (code, None) => code,
+            (None, Some(delegate)) => {
+                Some([DELEGATION_DESIGNATOR.as_slice(), delegate.as_slice()].concat().into())
+            }
+        };
+
+        Ok(NamedAccount {
+            name: name.to_string(),
+            role: account.role.clone(),
+            address,
+            private_key,
+            balance,
+            code,
+            delegate,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    #[test]
+    fn resolves_named_accounts() {
+        let config: NamedAccountsConfig = toml::from_str(
+            r#"
+            [roles.whale]
+            balance = "1000000.5"
+
+            [accounts.deployer]
+            role = "deployer"
+
+            [accounts.alice]
+            role = "whale"
+            delegate = "0x63c0c19a282a1B52b07dD5a65b58948A07DAE32B"
+
+            [accounts.vault]
+            address = "0x000000000000000000000000000000000000dEaD"
+            code = "0x00"
+            balance = 1
+            "#,
+        )
+        .unwrap();
+        let accounts = config.resolve().unwrap();
+        let names = accounts.iter().map(|account| account.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["alice", "deployer", "vault"]);
+
+        // Same as `vm.makeAddr("alice")`.
+        assert_eq!(accounts[0].address, address!("0x328809Bc894f92807417D2dAD6b7C998c1aFdac6"));
+        assert_eq!(accounts[0].balance, parse_ether("1000000.5").unwrap());
+        assert_eq!(
+            accounts[0].code.as_deref(),
+            Some(
+                &[
+                    DELEGATION_DESIGNATOR.as_slice(),
+                    address!("0x63c0c19a282a1B52b07dD5a65b58948A07DAE32B").as_slice()
+                ]
+                .concat()[..]
+            )
+        );
+        assert_eq!(accounts[1].balance, parse_ether("10000").unwrap());
+        assert!(accounts[1].private_key.is_some());
+        assert_eq!(accounts[2].private_key, None);
+        assert_eq!(accounts[2].balance, parse_ether("1").unwrap());
+
+        let config: NamedAccountsConfig =
+            toml::from_str("[accounts.bob]\nrole = \"admin\"").unwrap();
+        assert!(config.resolve().is_err());
+    }
+}
@@ -0,0 +1,215 @@
+//! Normalization of compiler output across solc versions.
+//!
+//! Older solc versions omit fields that later versions emit, or emit them in a legacy shape.
+//! Normalizing artifacts to the schema of the latest solc output lets consumers handle artifacts
+//! of all versions the same way.
+
+use alloy_json_abi::JsonAbi;
+use alloy_primitives::hex;
+use foundry_compilers::artifacts::ConfigurableContractArtifact;
+use semver::Version;
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+/// The first solc version whose AST has all the fields normalized by [`normalize_ast`].
+const LATEST_AST_VERSION: Version = Version::new(0, 8, 20);
+
+/// The source location of nodes whose location is unknown.
+const UNKNOWN_SRC: &str = "-1:-1:-1";
+
+/// AST node types that declare a name, and have a `nameLocation` since solc 0.8.2.
+const NAMED_DECLARATIONS: &[&str] = &[
+    "ContractDefinition",
+    "EnumDefinition",
+    "EnumValue",
+    "ErrorDefinition",
+    "EventDefinition",
+    "FunctionDefinition",
+    "ModifierDefinition",
+    "StructDefinition",
+    "UserDefinedValueTypeDefinition",
+    "VariableDeclaration",
+];
+
+/// Fields added to AST nodes after solc 0.6, with the value they take in older outputs.
+const AST_DEFAULTS: &[(&str, &str, fn() -> Value)] = &[
+    // solc 0.6.2
+    ("FunctionCall", "tryCall", || Value::Bool(false)),
+    // solc 0.8.4
+    ("ContractDefinition", "usedErrors", || Value::Array(Vec::new())),
+    // solc 0.8.20
+    ("ContractDefinition", "usedEvents", || Value::Array(Vec::new())),
+];
+
+/// Normalizes compiler output to the schema of the latest solc version.
+pub trait NormalizeArtifact {
+    /// Normalizes `self`, compiled with the given solc version.
+    fn normalize(&mut self, version: &Version);
+}
+
+impl NormalizeArtifact for ConfigurableContractArtifact {
+    fn normalize(&mut self, version: &Version) {
+        if self.method_identifiers.is_none()
+            && let Some(abi) = &self.abi
+        {
+            self.method_identifiers = Some(method_identifiers(abi));
+        }
+        normalize_as_json(&mut self.devdoc, |doc| normalize_natspec(doc, "dev"));
+        normalize_as_json(&mut self.userdoc, |doc| normalize_natspec(doc, "user"));
+        if *version < LATEST_AST_VERSION {
+            normalize_as_json(&mut self.ast, normalize_ast);
+        }
+    }
+}
+
+/// Returns the method identifiers of the functions of `abi`, as emitted by solc.
+pub fn method_identifiers(abi: &JsonAbi) -> BTreeMap<String, String> {
+    abi.functions().map(|func| (func.signature(), hex::encode(func.selector()))).collect()
+}
+
+/// Sets the `kind` and `version` of a devdoc or userdoc, which solc emits since 0.6.11.
+pub fn normalize_natspec(doc: &mut Value, kind: &str) {
+    let Value::Object(doc) = doc else { return };
+    doc.entry("kind").or_insert_with(|| kind.into());
+    doc.entry("version").or_insert_with(|| 1.into());
+}
+
+/// Upgrades a JSON AST emitted by an older solc version to the latest schema.
+///
+/// - plain string `documentation` fields, emitted before solc 0.6.3, become
+///   `StructuredDocumentation` nodes;
+/// - named declarations get an unknown `nameLocation`, emitted since solc 0.8.2;
+/// - fields added since solc 0.6 get the value they implicitly had before.
+pub fn normalize_ast(node: &mut Value) {
+    match node {
+        Value::Object(node) => {
+            if let Some(Value::String(node_type)) = node.get("nodeType") {
+                let node_type = node_type.clone();
+                normalize_node(&node_type, node);
+            }
+            node.values_mut().for_each(normalize_ast);
+        }
+        Value::Array(nodes) => nodes.iter_mut().for_each(normalize_ast),
+        _ => {}
+    }
+}
+
+fn normalize_node(node_type: &str, node: &mut Map<String, Value>) {
+    if let Some(Value::String(text)) = node.get("documentation") {
+        let documentation = Value::Object(Map::from_iter([
+            ("nodeType".to_string(), "StructuredDocumentation".into()),
+            ("src".to_string(), UNKNOWN_SRC.into()),
+            ("text".to_string(), text.clone().into()),
+        ]));
+        node.insert("documentation".to_string(), documentation);
+    }
+
+    if NAMED_DECLARATIONS.contains(&node_type) && node.contains_key("name") {
+        node.entry("nameLocation").or_insert_with(|| UNKNOWN_SRC.into());
+    }
+
+    for &(ty, field, default) in AST_DEFAULTS {
+        if ty == node_type {
+            node.entry(field).or_insert_with(default);
+        }
+    }
+}
+
+/// Applies `f` to the JSON representation of `value`.
+///
+/// `value` is left unchanged if the normalized JSON doesn't deserialize.
+fn normalize_as_json<T: Serialize + DeserializeOwned>(
+    value: &mut Option<T>,
+    f: impl FnOnce(&mut Value),
+) {
+    let Some(inner) = value else { return };
+    let Ok(mut json) = serde_json::to_value(&*inner) else { return };
+    f(&mut json);
+    match serde_json::from_value(json) {
+        Ok(normalized) => *inner = normalized,
+        Err(err) => trace!(%err, "failed to normalize artifact field"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn computes_method_identifiers() {
+        let abi: JsonAbi = serde_json::from_value(json!([{
+            "type": "function",
+            "name": "transfer",
+            "inputs": [{ "name": "to", "type": "address" }, { "name": "amount", "type": "uint256" }],
+            "outputs": [{ "name": "", "type": "bool" }],
+            "stateMutability": "nonpayable"
+        }]))
+        .unwrap();
+        assert_eq!(
+            method_identifiers(&abi),
+            BTreeMap::from([("transfer(address,uint256)".to_string(), "a9059cbb".to_string())])
+        );
+    }
+
+    #[test]
+    fn defaults_natspec_kind_and_version() {
+        let mut doc = json!({ "methods": {} });
+        normalize_natspec(&mut doc, "dev");
+        assert_eq!(doc, json!({ "kind": "dev", "methods": {}, "version": 1 }));
+
+        let mut doc = json!({ "kind": "user", "version": 2 });
+        normalize_natspec(&mut doc, "user");
+        assert_eq!(doc, json!({ "kind": "user", "version": 2 }));
+    }
+
+    #[test]
+    fn upgrades_legacy_ast() {
+        // Shaped after solc 0.6.2 output.
+        let mut ast = json!({
+            "nodeType": "SourceUnit",
+            "nodes": [{
+                "nodeType": "ContractDefinition",
+                "name": "Counter",
+                "documentation": "@notice A counter.",
+                "nodes": [{
+                    "nodeType": "FunctionDefinition",
+                    "name": "increment",
+                    "documentation": null,
+                    "body": {
+                        "nodeType": "Block",
+                        "statements": [{
+                            "nodeType": "ExpressionStatement",
+                            "expression": { "nodeType": "FunctionCall", "tryCall": true }
+                        }, {
+                            "nodeType": "ExpressionStatement",
+                            "expression": { "nodeType": "FunctionCall" }
+                        }]
+                    }
+                }]
+            }]
+        });
+        normalize_ast(&mut ast);
+
+        let contract = &ast["nodes"][0];
+        assert_eq!(
+            contract["documentation"],
+            json!({
+                "nodeType": "StructuredDocumentation",
+                "src": "-1:-1:-1",
+                "text": "@notice A counter."
+            })
+        );
+        assert_eq!(contract["nameLocation"], "-1:-1:-1");
+        assert_eq!(contract["usedErrors"], json!([]));
+        assert_eq!(contract["usedEvents"], json!([]));
+
+        let function = &contract["nodes"][0];
+        assert_eq!(function["documentation"], Value::Null);
+        assert_eq!(function["nameLocation"], "-1:-1:-1");
+        let statements = &function["body"]["statements"];
+        assert_eq!(statements[0]["expression"]["tryCall"], true);
+        assert_eq!(statements[1]["expression"]["tryCall"], false);
+    }
+}
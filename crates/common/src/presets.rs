@@ -0,0 +1,202 @@
+//! Protocol presets: the canonical deployments of widely used protocols per chain.
+//!
+//! Presets are used to pre-warm fork caches and to discover pools and markets in tests.
+
+use alloy_chains::NamedChain;
+use alloy_primitives::{Address, B256, address, b256, keccak256};
+use alloy_sol_types::SolValue;
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// The init code hash of Uniswap v3 pools, used to compute their addresses.
+pub const UNISWAP_V3_POOL_INIT_CODE_HASH: B256 =
+    b256!("0xe34f199b19b2b4f47f68442619d555527d244f78a3297ea89325f843f87b8b54");
+
+/// The fee tiers of Uniswap v3 pools, in hundredths of a bip.
+pub const UNISWAP_V3_FEES: [u32; 4] = [100, 500, 3000, 10000];
+
+/// A protocol preset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Preset {
+    /// Uniswap v3: `FACTORY`, `SWAP_ROUTER` and `QUOTER`.
+    UniswapV3,
+    /// Aave v3: `POOL` and `POOL_ADDRESSES_PROVIDER`.
+    AaveV3,
+    /// Major stablecoins: `USDC`, `USDT` and `DAI`.
+    Stables,
+}
+
+impl Preset {
+    /// All presets.
+    pub const ALL: [Self; 3] = [Self::UniswapV3, Self::AaveV3, Self::Stables];
+
+    /// Returns the name of the preset.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::UniswapV3 => "uniswap-v3",
+            Self::AaveV3 => "aave-v3",
+            Self::Stables => "stables",
+        }
+    }
+
+    /// Returns the named contracts of the preset deployed on the given chain.
+    pub fn contracts(&self, chain_id: u64) -> Vec<(&'static str, Address)> {
+        let Ok(chain) = NamedChain::try_from(chain_id) else { return Vec::new() };
+        match self {
+            Self::UniswapV3 => uniswap_v3(chain),
+            Self::AaveV3 => aave_v3(chain),
+            Self::Stables => stables(chain),
+        }
+    }
+
+    /// Returns the address of the named contract of the preset on the given chain, if deployed.
+    pub fn address(&self, name: &str, chain_id: u64) -> Option<Address> {
+        self.contracts(chain_id)
+            .into_iter()
+            .find(|(contract, _)| contract.eq_ignore_ascii_case(name))
+            .map(|(_, address)| address)
+    }
+}
+
+impl fmt::Display for Preset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Preset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.to_ascii_lowercase().replace('_', "-");
+        Self::ALL.into_iter().find(|preset| preset.as_str() == name).ok_or_else(|| {
+            let names = Self::ALL.map(|preset| preset.as_str()).join(", ");
+            format!("unknown protocol preset `{s}`, expected one of: {names}")
+        })
+    }
+}
+
+impl TryFrom<String> for Preset {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<Preset> for String {
+    fn from(preset: Preset) -> Self {
+        preset.as_str().to_string()
+    }
+}
+
+/// Computes the address of the Uniswap v3 pool of the given tokens and fee, deployed by `factory`.
+///
+/// The pool may not be deployed.
+pub fn uniswap_v3_pool(factory: Address, token_a: Address, token_b: Address, fee: u32) -> Address {
+    let (token0, token1) = if token_a < token_b { (token_a, token_b) } else { (token_b, token_a) };
+    // `uint24` and `uint32` have the same ABI encoding.
+    let salt = keccak256((token0, token1, fee).abi_encode());
+    factory.create2(salt, UNISWAP_V3_POOL_INIT_CODE_HASH)
+}
+
+fn uniswap_v3(chain: NamedChain) -> Vec<(&'static str, Address)> {
+    const FACTORY: Address = address!("0x1F98431c8aD98523631AE4a59f267346ea31F984");
+    const SWAP_ROUTER: Address = address!("0x68b3465833fb72A70ecDF485E0e4C7bD8665Fc45");
+    const QUOTER: Address = address!("0x61fFE014bA17989E743c5F6cB21bF9697530B21e");
+    match chain {
+        NamedChain::Mainnet | NamedChain::Optimism | NamedChain::Arbitrum | NamedChain::Polygon => {
+            vec![("FACTORY", FACTORY), ("SWAP_ROUTER", SWAP_ROUTER), ("QUOTER", QUOTER)]
+        }
+        NamedChain::Base => vec![
+            ("FACTORY", address!("0x33128a8fC17869897dcE68Ed026d694621f6FDfD")),
+            ("SWAP_ROUTER", address!("0x2626664c2603336E57B271c5C0b26F421741e481")),
+            ("QUOTER", address!("0x3d4e44Eb1374240CE5F1B871ab261CD16335B76a")),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+fn aave_v3(chain: NamedChain) -> Vec<(&'static str, Address)> {
+    match chain {
+        NamedChain::Mainnet => vec![
+            ("POOL", address!("0x87870Bca3F3fD6335C3F4ce8392D69350B4fA4E2")),
+            ("POOL_ADDRESSES_PROVIDER", address!("0x2f39d218133AFaB8F2B819B1066c7E434Ad94E9e")),
+        ],
+        NamedChain::Optimism | NamedChain::Arbitrum | NamedChain::Polygon => vec![
+            ("POOL", address!("0x794a61358D6845594F94dc1DB02A252b5b4814aD")),
+            ("POOL_ADDRESSES_PROVIDER", address!("0xa97684ead0e402dC232d5A977953DF7ECBaB3CDb")),
+        ],
+        NamedChain::Base => vec![
+            ("POOL", address!("0xA238Dd80C259a72e81d7e4664a9801593F98d1c5")),
+            ("POOL_ADDRESSES_PROVIDER", address!("0xe20fCBdBfFC4Dd138cE8b2E6FBb6CB49777ad64D")),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+fn stables(chain: NamedChain) -> Vec<(&'static str, Address)> {
+    match chain {
+        NamedChain::Mainnet => vec![
+            ("USDC", address!("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")),
+            ("USDT", address!("0xdAC17F958D2ee523a2206206994597C13D831ec7")),
+            ("DAI", address!("0x6B175474E89094C44Da98b954EedeAC495271d0F")),
+        ],
+        NamedChain::Optimism => vec![
+            ("USDC", address!("0x0b2C639c533813f4Aa9D7837CAf62653d097Ff85")),
+            ("USDT", address!("0x94b008aA00579c1307B0EF2c499aD98a8ce58e58")),
+            ("DAI", address!("0xDA10009cBd5D07dd0CeCc66161FC93D7c9000da1")),
+        ],
+        NamedChain::Arbitrum => vec![
+            ("USDC", address!("0xaf88d065e77c8cC2239327C5EDb3A432268e5831")),
+            ("USDT", address!("0xFd086bC7CD5C481DCC9C85ebE478A1C0b69FCbb9")),
+            ("DAI", address!("0xDA10009cBd5D07dd0CeCc66161FC93D7c9000da1")),
+        ],
+        NamedChain::Polygon => vec![
+            ("USDC", address!("0x3c499c542cEF5E3811e1192ce70d8cC03d5c3359")),
+            ("USDT", address!("0xc2132D05D31c914a87C6611C10748AEb04B58e8F")),
+            ("DAI", address!("0x8f3Cf7ad23Cd3CaDbD9735AFf958023239c6A063")),
+        ],
+        NamedChain::Base => vec![
+            ("USDC", address!("0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913")),
+            ("DAI", address!("0x50c5725949A6F0c72E6C4a641F24049A917DB0Cb")),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_presets() {
+        for preset in Preset::ALL {
+            assert_eq!(preset.as_str().parse::<Preset>().unwrap(), preset);
+        }
+        assert_eq!("UNISWAP_V3".parse::<Preset>().unwrap(), Preset::UniswapV3);
+        assert!("curve".parse::<Preset>().is_err());
+    }
+
+    #[test]
+    fn preset_addresses() {
+        assert_eq!(
+            Preset::Stables.address("usdc", 1),
+            Some(address!("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"))
+        );
+        assert_eq!(Preset::Stables.address("USDT", 8453), None);
+        assert!(Preset::AaveV3.contracts(31337).is_empty());
+    }
+
+    #[test]
+    fn computes_uniswap_v3_pool() {
+        // USDC/WETH 0.05%
+        let factory = Preset::UniswapV3.address("FACTORY", 1).unwrap();
+        let usdc = address!("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+        let weth = address!("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+        let pool = address!("0x88e6A0c2dDD26FEEb64F039a2c41296FcB3f5640");
+        assert_eq!(uniswap_v3_pool(factory, usdc, weth, 500), pool);
+        assert_eq!(uniswap_v3_pool(factory, weth, usdc, 500), pool);
+    }
+}
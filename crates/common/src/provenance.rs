@@ -0,0 +1,177 @@
+//! Build provenance, recording the inputs a project was compiled from so deployments can be
+//! audited against a reproducible build.
+
+use crate::{fs, version::SHORT_VERSION};
+use alloy_primitives::{B256, keccak256};
+use eyre::Result;
+use foundry_compilers::{ProjectCompileOutput, solc::Solc};
+use foundry_config::Config;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+
+/// The file name of the provenance file written to the artifacts directory.
+pub const PROVENANCE_FILE: &str = "provenance.json";
+
+/// The predicate type identifying the provenance format.
+pub const PROVENANCE_PREDICATE_TYPE: &str = "https://getfoundry.sh/provenance/v1";
+
+/// The dependency lockfiles included in the provenance, relative to the project root.
+const LOCKFILES: &[&str] = &["foundry.lock", "soldeer.lock"];
+
+/// SLSA-style provenance of a build.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildProvenance {
+    /// The provenance format, see [`PROVENANCE_PREDICATE_TYPE`].
+    pub predicate_type: String,
+    /// The version of forge that produced the build.
+    pub builder: String,
+    /// The git commit of the project, if it's a git repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_commit: Option<String>,
+    /// The compilers used for the build.
+    pub compilers: Vec<CompilerProvenance>,
+    /// Digest of the compiler input: the sources and the compiler settings.
+    pub input_digest: B256,
+    /// Digest of the dependency lockfiles, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lockfile_digest: Option<B256>,
+}
+
+/// A compiler used for a build.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilerProvenance {
+    /// The compiler name, e.g. `solc`.
+    pub name: String,
+    /// The compiler version.
+    pub version: Version,
+    /// Digest of the compiler binary, if it could be located.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digest: Option<B256>,
+}
+
+impl BuildProvenance {
+    /// Creates the provenance of the given compiler output.
+    pub fn new(
+        config: &Config,
+        output: &ProjectCompileOutput,
+        git_commit: Option<String>,
+    ) -> Result<Self> {
+        let mut sources = BTreeSet::new();
+        let mut versions = BTreeSet::new();
+        for id in output.artifact_ids().map(|(id, _)| id) {
+            let is_vyper = id.source.extension().is_some_and(|ext| ext == "vy" || ext == "vyi");
+            let version = Version::new(id.version.major, id.version.minor, id.version.patch);
+            versions.insert((if is_vyper { "vyper" } else { "solc" }, version));
+            sources.insert(id.source);
+        }
+
+        let compilers = versions
+            .into_iter()
+            .map(|(name, version)| {
+                let digest = (name == "solc")
+                    .then(|| Solc::find_svm_installed_version(&version).ok().flatten())
+                    .flatten()
+                    .and_then(|solc| std::fs::read(solc.solc).ok())
+                    .map(keccak256);
+                CompilerProvenance { name: name.to_string(), version, digest }
+            })
+            .collect();
+
+        Ok(Self {
+            predicate_type: PROVENANCE_PREDICATE_TYPE.to_string(),
+            builder: format!("forge {SHORT_VERSION}"),
+            git_commit,
+            compilers,
+            input_digest: input_digest(config, &sources)?,
+            lockfile_digest: lockfile_digest(&config.root)?,
+        })
+    }
+
+    /// Returns the path of the provenance file for the given config.
+    pub fn path(config: &Config) -> PathBuf {
+        config.out.join(PROVENANCE_FILE)
+    }
+
+    /// Writes the provenance file to the artifacts directory.
+    pub fn write(&self, config: &Config) -> Result<PathBuf> {
+        let path = Self::path(config);
+        fs::create_dir_all(&config.out)?;
+        fs::write_pretty_json_file(&path, self)?;
+        Ok(path)
+    }
+}
+
+/// Hashes the sources, keyed by their path relative to the project root, together with the
+/// compiler settings.
+fn input_digest(config: &Config, sources: &BTreeSet<PathBuf>) -> Result<B256> {
+    let mut input = BTreeMap::new();
+    for source in sources {
+        let path = config.root.join(source);
+        let content = fs::read_to_string(&path)?;
+        let name = path.strip_prefix(&config.root).unwrap_or(&path).to_path_buf();
+        input.insert(name, content);
+    }
+    let settings = config.solc_settings()?;
+    let preimage = serde_json::to_vec(&serde_json::json!({
+        "sources": input,
+        "settings": settings,
+    }))?;
+    Ok(keccak256(preimage))
+}
+
+/// Hashes the dependency lockfiles present in the project root.
+fn lockfile_digest(root: &Path) -> Result<Option<B256>> {
+    let mut preimage = Vec::new();
+    for name in LOCKFILES {
+        let path = root.join(name);
+        if path.exists() {
+            preimage.extend_from_slice(name.as_bytes());
+            preimage.extend(fs::read(&path)?);
+        }
+    }
+    Ok((!preimage.is_empty()).then(|| keccak256(preimage)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lockfile_digest_changes_with_lockfiles() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(lockfile_digest(dir.path()).unwrap(), None);
+
+        std::fs::write(dir.path().join("foundry.lock"), "{}").unwrap();
+        let first = lockfile_digest(dir.path()).unwrap().unwrap();
+
+        std::fs::write(dir.path().join("soldeer.lock"), "[[dependencies]]").unwrap();
+        let second = lockfile_digest(dir.path()).unwrap().unwrap();
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn provenance_roundtrip() {
+        let provenance = BuildProvenance {
+            predicate_type: PROVENANCE_PREDICATE_TYPE.to_string(),
+            builder: "forge 1.0.0".to_string(),
+            git_commit: None,
+            compilers: vec![CompilerProvenance {
+                name: "solc".to_string(),
+                version: Version::new(0, 8, 30),
+                digest: Some(B256::repeat_byte(1)),
+            }],
+            input_digest: B256::repeat_byte(2),
+            lockfile_digest: None,
+        };
+        let json = serde_json::to_value(&provenance).unwrap();
+        assert!(json.get("gitCommit").is_none());
+        assert_eq!(json["compilers"][0]["version"], "0.8.30");
+        assert_eq!(serde_json::from_value::<BuildProvenance>(json).unwrap(), provenance);
+    }
+}
@@ -0,0 +1,256 @@
+//! Transport layers to test and survive failures of the remote endpoint.
+
+use alloy_json_rpc::{RequestPacket, Response, ResponsePacket};
+use alloy_transport::{TransportError, TransportErrorKind, TransportFut};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+use tower::{Layer, Service};
+
+/// The HTTP status of injected failures if none is given: rate limited, which is retried.
+const DEFAULT_FAILURE_STATUS: u16 = 429;
+
+/// The maximum number of responses kept by the [`StaleCacheService`].
+const MAX_STALE_RESPONSES: usize = 10_000;
+
+/// Failures to inject into the requests sent to the remote endpoint.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcFailure {
+    /// The number of requests to fail. If `None`, requests fail until the failure is cleared.
+    #[serde(default)]
+    pub count: Option<u64>,
+    /// The methods whose requests fail. If empty, requests of every method fail.
+    #[serde(default)]
+    pub methods: Vec<String>,
+    /// The HTTP status of the failed requests, defaults to 429.
+    #[serde(default)]
+    pub status: Option<u16>,
+}
+
+impl RpcFailure {
+    fn matches(&self, req: &RequestPacket) -> bool {
+        self.methods.is_empty()
+            || req.method_names().any(|method| self.methods.iter().any(|m| m == method))
+    }
+}
+
+/// Shared handle to inject failures into every provider built with it.
+#[derive(Clone, Debug, Default)]
+pub struct RpcFailureInjector {
+    failure: Arc<Mutex<Option<RpcFailure>>>,
+}
+
+impl RpcFailureInjector {
+    /// Fails the next requests as described by `failure`, replacing any previous failure.
+    ///
+    /// A failure with a count of zero clears the injected failure.
+    pub fn inject(&self, failure: RpcFailure) {
+        let failure = (failure.count != Some(0)).then_some(failure);
+        *self.failure.lock().unwrap() = failure;
+    }
+
+    /// Clears the injected failure.
+    pub fn clear(&self) {
+        *self.failure.lock().unwrap() = None;
+    }
+
+    /// Returns the error to fail `req` with, if any.
+    fn take(&self, req: &RequestPacket) -> Option<TransportError> {
+        let mut guard = self.failure.lock().unwrap();
+        let failure = guard.as_mut().filter(|failure| failure.matches(req))?;
+        let status = failure.status.unwrap_or(DEFAULT_FAILURE_STATUS);
+        if let Some(count) = &mut failure.count {
+            *count -= 1;
+            if *count == 0 {
+                *guard = None;
+            }
+        }
+        Some(TransportErrorKind::http_error(status, "injected RPC failure".to_string()))
+    }
+}
+
+/// Layer failing requests with the failures of a [`RpcFailureInjector`].
+///
+/// It must be below the retry layer so injected failures are retried like real ones.
+#[derive(Clone, Debug, Default)]
+pub struct FailureInjectionLayer {
+    injector: Option<RpcFailureInjector>,
+}
+
+impl FailureInjectionLayer {
+    /// Creates a new layer, forwarding every request if `injector` is `None`.
+    pub fn new(injector: Option<RpcFailureInjector>) -> Self {
+        Self { injector }
+    }
+}
+
+impl<S> Layer<S> for FailureInjectionLayer {
+    type Service = FailureInjectionService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        FailureInjectionService { inner, injector: self.injector.clone() }
+    }
+}
+
+/// Service created by the [`FailureInjectionLayer`].
+#[derive(Clone, Debug)]
+pub struct FailureInjectionService<S> {
+    inner: S,
+    injector: Option<RpcFailureInjector>,
+}
+
+impl<S> Service<RequestPacket> for FailureInjectionService<S>
+where
+    S: Service<
+            RequestPacket,
+            Response = ResponsePacket,
+            Error = TransportError,
+            Future = TransportFut<'static>,
+        > + Send
+        + 'static,
+{
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: RequestPacket) -> Self::Future {
+        if let Some(err) = self.injector.as_ref().and_then(|injector| injector.take(&req)) {
+            return Box::pin(async move { Err(err) });
+        }
+        self.inner.call(req)
+    }
+}
+
+/// Layer answering failed requests with the last successful response to the same request.
+///
+/// It must be above the retry layer so only requests that failed every retry fall back to stale
+/// responses.
+#[derive(Clone, Debug, Default)]
+pub struct StaleCacheLayer {
+    enabled: bool,
+}
+
+impl StaleCacheLayer {
+    /// Creates a new layer, forwarding every request as is if not `enabled`.
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+}
+
+impl<S> Layer<S> for StaleCacheLayer {
+    type Service = StaleCacheService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        StaleCacheService { inner, cache: self.enabled.then(Default::default) }
+    }
+}
+
+/// Responses keyed by the method and the parameters of their request.
+type ResponseCache = Arc<Mutex<HashMap<(String, String), Response>>>;
+
+/// Service created by the [`StaleCacheLayer`].
+#[derive(Clone, Debug)]
+pub struct StaleCacheService<S> {
+    inner: S,
+    cache: Option<ResponseCache>,
+}
+
+impl<S> Service<RequestPacket> for StaleCacheService<S>
+where
+    S: Service<
+            RequestPacket,
+            Response = ResponsePacket,
+            Error = TransportError,
+            Future = TransportFut<'static>,
+        > + Send
+        + 'static,
+{
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: RequestPacket) -> Self::Future {
+        // Batches are forwarded as is.
+        let (Some(cache), RequestPacket::Single(single)) = (self.cache.clone(), &req) else {
+            return self.inner.call(req);
+        };
+        let id = single.id().clone();
+        let key = (
+            single.method().to_string(),
+            single.params().map(|params| params.get().to_string()).unwrap_or_default(),
+        );
+        let fut = self.inner.call(req);
+        Box::pin(async move {
+            match fut.await {
+                Ok(ResponsePacket::Single(response)) => {
+                    if response.payload.is_success() {
+                        let mut cache = cache.lock().unwrap();
+                        if cache.len() < MAX_STALE_RESPONSES || cache.contains_key(&key) {
+                            cache.insert(key, response.clone());
+                        }
+                    }
+                    Ok(ResponsePacket::Single(response))
+                }
+                Ok(response) => Ok(response),
+                Err(err) => {
+                    let Some(mut stale) = cache.lock().unwrap().get(&key).cloned() else {
+                        return Err(err);
+                    };
+                    warn!(target: "provider", method = %key.0, %err, "serving stale response");
+                    stale.id = id;
+                    Ok(ResponsePacket::Single(stale))
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_rpc::{Id, Request};
+
+    fn request(method: &'static str) -> RequestPacket {
+        let req: Request<Vec<()>> = Request::new(method, Id::Number(1), vec![]);
+        RequestPacket::Single(req.serialize().unwrap())
+    }
+
+    #[test]
+    fn injects_counted_failures() {
+        let injector = RpcFailureInjector::default();
+        injector.inject(RpcFailure {
+            count: Some(2),
+            methods: vec!["eth_getBalance".to_string()],
+            status: None,
+        });
+
+        assert!(injector.take(&request("eth_blockNumber")).is_none());
+        assert!(injector.take(&request("eth_getBalance")).is_some());
+        assert!(injector.take(&request("eth_getBalance")).is_some());
+        assert!(injector.take(&request("eth_getBalance")).is_none());
+    }
+
+    #[test]
+    fn injects_failures_until_cleared() {
+        let injector = RpcFailureInjector::default();
+        injector.inject(RpcFailure::default());
+        for _ in 0..10 {
+            assert!(injector.take(&request("eth_chainId")).is_some());
+        }
+
+        injector.inject(RpcFailure { count: Some(0), ..Default::default() });
+        assert!(injector.take(&request("eth_chainId")).is_none());
+    }
+}
@@ -1,12 +1,17 @@
 //! Provider-related instantiation and usage utilities.
 
 pub mod curl_transport;
+pub mod failure;
 pub mod mpp;
 pub mod runtime_transport;
 
 use crate::{
     ALCHEMY_FREE_TIER_CUPS, REQUEST_TIMEOUT,
-    provider::{curl_transport::CurlTransport, runtime_transport::RuntimeTransportBuilder},
+    provider::{
+        curl_transport::CurlTransport,
+        failure::{FailureInjectionLayer, RpcFailureInjector, StaleCacheLayer},
+        runtime_transport::RuntimeTransportBuilder,
+    },
 };
 use alloy_chains::NamedChain;
 use alloy_json_rpc::{RequestPacket, ResponsePacket};
@@ -157,6 +162,10 @@ pub struct ProviderBuilder<N: Network = AnyNetwork> {
     no_proxy: bool,
     /// Whether to output curl commands instead of making requests.
     curl_mode: bool,
+    /// Injects failures into the requests, for testing.
+    failure_injector: Option<RpcFailureInjector>,
+    /// Whether to answer requests that failed every retry with a previous response.
+    stale_cache: bool,
     /// Phantom data for the network type.
     _network: PhantomData<N>,
 }
@@ -211,6 +220,8 @@ pub fn new(url_str: &str) -> Self {
             accept_invalid_certs: false,
             no_proxy: false,
             curl_mode: false,
+            failure_injector: None,
+            stale_cache: false,
             _network: PhantomData,
         }
     }
@@ -365,6 +376,19 @@ pub const fn curl_mode(mut self, curl_mode: bool) -> Self {
         self
     }
 
+    /// Sets the handle injecting failures into the requests sent by the provider.
+    pub fn failure_injector(mut self, injector: RpcFailureInjector) -> Self {
+        self.failure_injector = Some(injector);
+        self
+    }
+
+    /// Sets whether requests that failed every retry are answered with the last successful
+    /// response to the same request, if any.
+    pub const fn stale_cache(mut self, stale_cache: bool) -> Self {
+        self.stale_cache = stale_cache;
+        self
+    }
+
     /// Constructs the `RetryProvider` taking all configs into account.
     pub fn build(self) -> Result<RetryProvider<N>> {
         let Self {
@@ -380,6 +404,8 @@ pub fn build(self) -> Result<RetryProvider<N>> {
             accept_invalid_certs,
             no_proxy,
             curl_mode,
+            failure_injector,
+            stale_cache,
             ..
         } = self;
         let url = url?;
@@ -405,7 +431,11 @@ pub fn build(self) -> Result<RetryProvider<N>> {
             .accept_invalid_certs(accept_invalid_certs)
             .no_proxy(no_proxy)
             .build();
-        let client = ClientBuilder::default().layer(retry_layer).transport(transport, is_local);
+        let client = ClientBuilder::default()
+            .layer(StaleCacheLayer::new(stale_cache))
+            .layer(retry_layer)
+            .layer(FailureInjectionLayer::new(failure_injector))
+            .transport(transport, is_local);
 
         if !is_local {
             client.set_poll_interval(
@@ -445,6 +475,8 @@ pub fn build_fallback(self, urls: Vec<String>) -> Result<RetryProvider<N>> {
             accept_invalid_certs,
             no_proxy,
             curl_mode,
+            failure_injector,
+            stale_cache,
             ..
         } = self;
 
@@ -476,7 +508,11 @@ pub fn build_fallback(self, urls: Vec<String>) -> Result<RetryProvider<N>> {
             RetryBackoffLayer::new(max_retry, initial_backoff, compute_units_per_second);
         // Use normalized/parsed URLs for local detection, consistent with build()
         let is_local = parsed_urls.iter().all(|url| guess_local_url(url.as_str()));
-        let client = ClientBuilder::default().layer(retry_layer).transport(round_robin, is_local);
+        let client = ClientBuilder::default()
+            .layer(StaleCacheLayer::new(stale_cache))
+            .layer(retry_layer)
+            .layer(FailureInjectionLayer::new(failure_injector))
+            .transport(round_robin, is_local);
 
         if !is_local {
             client.set_poll_interval(
@@ -9,6 +9,7 @@
 use alloy_provider::Provider;
 use alloy_signer::Signer;
 use eyre::Result;
+use foundry_primitives::{FoundryNetwork, FoundryTransactionRequest};
 use op_alloy_network::Optimism;
 use op_alloy_rpc_types::OpTransactionRequest;
 use tempo_alloy::{TempoNetwork, provider::TempoProviderExt};
@@ -369,6 +370,46 @@ fn set_authorization_list(&mut self, authorization_list: Vec<SignedAuthorization
     }
 }
 
+impl FoundryTransactionBuilder<FoundryNetwork> for FoundryTransactionRequest {
+    fn reset_gas_limit(&mut self) {
+        self.as_mut().gas = None;
+    }
+
+    fn max_fee_per_blob_gas(&self) -> Option<u128> {
+        self.as_ref().max_fee_per_blob_gas
+    }
+
+    fn set_max_fee_per_blob_gas(&mut self, max_fee_per_blob_gas: u128) {
+        self.as_mut().max_fee_per_blob_gas = Some(max_fee_per_blob_gas);
+    }
+
+    fn blob_versioned_hashes(&self) -> Option<&[B256]> {
+        self.as_ref().blob_versioned_hashes.as_deref()
+    }
+
+    fn set_blob_versioned_hashes(&mut self, hashes: Vec<B256>) {
+        self.as_mut().blob_versioned_hashes = Some(hashes);
+    }
+
+    fn blob_sidecar(&self) -> Option<&BlobTransactionSidecarVariant> {
+        self.as_ref().sidecar.as_ref()
+    }
+
+    fn set_blob_sidecar(&mut self, sidecar: BlobTransactionSidecarVariant) {
+        let tx = self.as_mut();
+        tx.sidecar = Some(sidecar);
+        tx.populate_blob_hashes();
+    }
+
+    fn authorization_list(&self) -> Option<&Vec<SignedAuthorization>> {
+        self.as_ref().authorization_list.as_ref()
+    }
+
+    fn set_authorization_list(&mut self, authorization_list: Vec<SignedAuthorization>) {
+        self.as_mut().authorization_list = Some(authorization_list);
+    }
+}
+
 impl FoundryTransactionBuilder<TempoNetwork> for <TempoNetwork as Network>::TransactionRequest {
     fn reset_gas_limit(&mut self) {
         self.gas = None;
@@ -0,0 +1,144 @@
+use alloy_network::{Network, ReceiptResponse};
+use alloy_primitives::{B256, Bytes, TxHash};
+use alloy_provider::Provider;
+use eyre::Result;
+use std::time::{Duration, Instant};
+
+/// Waits for transactions to reach a number of confirmations.
+///
+/// Reorgs are detected by tracking the block of each receipt: a transaction moved to another
+/// block has its confirmations counted again, and a transaction whose receipt disappears is
+/// re-broadcast if the node no longer knows it.
+#[derive(Clone, Copy, Debug)]
+pub struct ConfirmationWaiter {
+    confirmations: u64,
+    timeout: Duration,
+    poll_interval: Option<Duration>,
+}
+
+impl ConfirmationWaiter {
+    /// Creates a waiter for the given number of confirmations, where `1` means that the
+    /// transaction is included in the latest block.
+    pub fn new(confirmations: u64, timeout: Duration) -> Self {
+        Self { confirmations: confirmations.max(1), timeout, poll_interval: None }
+    }
+
+    /// Sets the interval between two polls. Defaults to the poll interval of the provider.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = Some(poll_interval);
+        self
+    }
+
+    /// Waits for a transaction to be confirmed and returns its receipt.
+    pub async fn wait<N: Network, P: Provider<N>>(
+        &self,
+        provider: &P,
+        tx_hash: TxHash,
+    ) -> Result<N::ReceiptResponse> {
+        let mut receipts = self.wait_all(provider, &[tx_hash]).await?;
+        Ok(receipts.pop().expect("one receipt per transaction"))
+    }
+
+    /// Waits for all the given transactions to be confirmed and returns their receipts, in the
+    /// same order.
+    pub async fn wait_all<N: Network, P: Provider<N>>(
+        &self,
+        provider: &P,
+        tx_hashes: &[TxHash],
+    ) -> Result<Vec<N::ReceiptResponse>> {
+        let deadline = Instant::now() + self.timeout;
+        let poll_interval = self.poll_interval.unwrap_or_else(|| provider.client().poll_interval());
+
+        // Fetch the raw transactions while the node knows them, to re-broadcast them on reorgs.
+        let mut txs = Vec::with_capacity(tx_hashes.len());
+        for &hash in tx_hashes {
+            let raw = provider
+                .raw_request::<_, Option<Bytes>>("eth_getRawTransactionByHash".into(), (hash,))
+                .await
+                .ok()
+                .flatten();
+            txs.push(WatchedTx::<N> { hash, raw, block: None, receipt: None });
+        }
+
+        loop {
+            let head = provider.get_block_number().await?;
+            let mut confirmed = true;
+            for tx in &mut txs {
+                let receipt = provider
+                    .get_transaction_receipt(tx.hash)
+                    .await?
+                    .filter(|receipt| receipt.block_number().is_some());
+                match receipt {
+                    Some(receipt) => {
+                        let block = (
+                            receipt.block_number().unwrap_or_default(),
+                            receipt.block_hash().unwrap_or_default(),
+                        );
+                        if tx.block.is_some_and(|previous| previous != block) {
+                            warn!(tx_hash=?tx.hash, block=block.0, "transaction reorged into another block");
+                        }
+                        tx.block = Some(block);
+                        tx.receipt = Some(receipt);
+                        confirmed &= block.0 + self.confirmations <= head + 1;
+                    }
+                    None => {
+                        confirmed = false;
+                        if tx.block.take().is_some() {
+                            tx.receipt = None;
+                            tx.rebroadcast(provider).await?;
+                        }
+                    }
+                }
+            }
+
+            if confirmed {
+                return Ok(txs.into_iter().filter_map(|tx| tx.receipt).collect());
+            }
+            if Instant::now() >= deadline {
+                let pending = txs
+                    .iter()
+                    .find(|tx| {
+                        tx.block.is_none_or(|(number, _)| number + self.confirmations > head + 1)
+                    })
+                    .map_or(B256::ZERO, |tx| tx.hash);
+                eyre::bail!(
+                    "timed out waiting for {} confirmations of transaction {pending}",
+                    self.confirmations
+                );
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+}
+
+/// A transaction being waited for.
+struct WatchedTx<N: Network> {
+    hash: TxHash,
+    /// The RLP encoded transaction, if the node returned it.
+    raw: Option<Bytes>,
+    /// The number and hash of the block the transaction was last seen in.
+    block: Option<(u64, B256)>,
+    receipt: Option<N::ReceiptResponse>,
+}
+
+impl<N: Network> WatchedTx<N> {
+    /// Re-broadcasts a transaction which was removed from the chain by a reorg, unless it is back
+    /// in the mempool of the node.
+    async fn rebroadcast<P: Provider<N>>(&self, provider: &P) -> Result<()> {
+        if provider.get_transaction_by_hash(self.hash).await?.is_some() {
+            warn!(tx_hash=?self.hash, "transaction reorged out, waiting for it to be included again");
+            return Ok(());
+        }
+        let Some(raw) = &self.raw else {
+            eyre::bail!(
+                "transaction {} was reorged out and the node doesn't return raw transactions, it must be re-sent",
+                self.hash
+            );
+        };
+        warn!(tx_hash=?self.hash, "transaction reorged out and dropped, re-broadcasting it");
+        if let Err(err) = provider.send_raw_transaction(raw).await {
+            warn!(tx_hash=?self.hash, %err, "failed to re-broadcast transaction");
+        }
+        Ok(())
+    }
+}
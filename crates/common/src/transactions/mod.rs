@@ -2,8 +2,10 @@
 
 mod broadcast;
 mod builder;
+mod confirmations;
 mod receipt;
 
 pub use broadcast::*;
 pub use builder::*;
+pub use confirmations::*;
 pub use receipt::*;
@@ -0,0 +1,200 @@
+//! A [`NetworkWallet`] backed by [`WalletSigner`]s.
+
+use alloy_consensus::{SignableTransaction, Signed};
+use alloy_eips::Typed2718;
+use alloy_network::{Network, NetworkWallet, TxSigner, TxSignerSync};
+use alloy_primitives::{Address, Signature, map::AddressHashMap};
+use alloy_signer::{Error, Result, Signer};
+use foundry_wallets::WalletSigner;
+use rayon::prelude::*;
+use std::sync::Arc;
+use tempo_primitives::TEMPO_TX_TYPE_ID;
+
+/// A wallet signing the transactions of the Ethereum, Optimism and Tempo networks.
+///
+/// Any [`WalletSigner`] can be registered, including hardware wallets which can only sign the
+/// Ethereum transaction types.
+#[derive(Clone, Debug, Default)]
+pub struct FoundryWallet {
+    default: Address,
+    signers: AddressHashMap<Arc<WalletSigner>>,
+}
+
+impl From<WalletSigner> for FoundryWallet {
+    fn from(signer: WalletSigner) -> Self {
+        Self::new(signer)
+    }
+}
+
+impl FoundryWallet {
+    /// Creates a new wallet with the given signer as the default signer.
+    pub fn new(signer: WalletSigner) -> Self {
+        let mut this = Self::default();
+        this.register_default_signer(signer);
+        this
+    }
+
+    /// Registers a signer, keeping the current default signer.
+    pub fn register_signer(&mut self, signer: WalletSigner) {
+        self.signers.insert(Signer::address(&signer), Arc::new(signer));
+    }
+
+    /// Registers a signer and sets it as the default signer.
+    pub fn register_default_signer(&mut self, signer: WalletSigner) {
+        self.default = Signer::address(&signer);
+        self.register_signer(signer);
+    }
+
+    /// Signs many transactions from the same sender.
+    ///
+    /// The envelopes are returned in the order of `txs`. The transactions of a local key are
+    /// signed concurrently, the others one by one since each of them may have to be confirmed on
+    /// a device.
+    pub async fn sign_transactions_from<N>(
+        &self,
+        sender: Address,
+        txs: Vec<N::UnsignedTx>,
+    ) -> Result<Vec<N::TxEnvelope>>
+    where
+        N: Network,
+        N::UnsignedTx: SignableTransaction<Signature>,
+        N::TxEnvelope: From<Signed<N::UnsignedTx>>,
+    {
+        if let Some(signer) = self.signers.get(&sender)
+            && let WalletSigner::Local(signer) = &**signer
+        {
+            let signed = txs
+                .into_par_iter()
+                .map(|mut tx| {
+                    let signature = signer.sign_transaction_sync(&mut tx)?;
+                    Ok(tx.into_signed(signature))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(signed.into_iter().map(Into::into).collect());
+        }
+
+        let mut envelopes = Vec::with_capacity(txs.len());
+        for tx in txs {
+            envelopes.push(NetworkWallet::<N>::sign_transaction_from(self, sender, tx).await?);
+        }
+        Ok(envelopes)
+    }
+}
+
+impl<N> NetworkWallet<N> for FoundryWallet
+where
+    N: Network,
+    N::UnsignedTx: SignableTransaction<Signature>,
+    N::TxEnvelope: From<Signed<N::UnsignedTx>>,
+{
+    fn default_signer_address(&self) -> Address {
+        self.default
+    }
+
+    fn has_signer_for(&self, address: &Address) -> bool {
+        self.signers.contains_key(address)
+    }
+
+    fn signer_addresses(&self) -> impl Iterator<Item = Address> {
+        self.signers.keys().copied()
+    }
+
+    async fn sign_transaction_from(
+        &self,
+        sender: Address,
+        mut tx: N::UnsignedTx,
+    ) -> Result<N::TxEnvelope> {
+        let signer = self
+            .signers
+            .get(&sender)
+            .ok_or_else(|| Error::other(format!("Missing signing credential for {sender}")))?;
+        // Tempo transactions are signed over their signature hash, which hardware wallets can't
+        // sign.
+        if tx.ty() == TEMPO_TX_TYPE_ID
+            && let Some(device) = hardware_wallet(signer)
+        {
+            return Err(Error::other(format!(
+                "{device} wallets can't sign Tempo transactions (type 0x76), \
+                 send an EIP-1559 transaction instead"
+            )));
+        }
+        let signature = signer.sign_transaction(&mut tx).await?;
+        Ok(tx.into_signed(signature).into())
+    }
+}
+
+/// Returns the name of the device backing `signer`, if it is a hardware wallet.
+fn hardware_wallet(signer: &WalletSigner) -> Option<&'static str> {
+    match signer {
+        WalletSigner::Ledger(_) => Some("Ledger"),
+        WalletSigner::Trezor(_) => Some("Trezor"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{
+        Transaction, TxEip1559, TypedTransaction, transaction::SignerRecoverable,
+    };
+    use alloy_network::Ethereum;
+    use alloy_primitives::TxKind;
+    use alloy_signer_local::PrivateKeySigner;
+
+    #[tokio::test]
+    async fn signs_from_registered_signers() {
+        let signer = PrivateKeySigner::random();
+        let sender = signer.address();
+        let other = PrivateKeySigner::random();
+        let other_sender = other.address();
+        let mut wallet = FoundryWallet::new(WalletSigner::Local(signer));
+        wallet.register_signer(WalletSigner::Local(other));
+        assert_eq!(NetworkWallet::<Ethereum>::default_signer_address(&wallet), sender);
+
+        for from in [sender, other_sender] {
+            let tx = TxEip1559 { chain_id: 1, to: TxKind::Call(from), ..Default::default() };
+            let envelope = NetworkWallet::<Ethereum>::sign_transaction_from(
+                &wallet,
+                from,
+                TypedTransaction::Eip1559(tx),
+            )
+            .await;
+            assert_eq!(envelope.unwrap().recover_signer().unwrap(), from);
+        }
+
+        let tx = TypedTransaction::Eip1559(TxEip1559::default());
+        let err = NetworkWallet::<Ethereum>::sign_transaction_from(&wallet, Address::ZERO, tx)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Missing signing credential"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn signs_many_transactions_in_order() {
+        let signer = PrivateKeySigner::random();
+        let sender = signer.address();
+        let wallet = FoundryWallet::new(WalletSigner::Local(signer));
+
+        let txs = (0..8)
+            .map(|nonce| {
+                TypedTransaction::Eip1559(TxEip1559 {
+                    chain_id: 1,
+                    nonce,
+                    to: TxKind::Call(sender),
+                    ..Default::default()
+                })
+            })
+            .collect::<Vec<_>>();
+        let envelopes =
+            wallet.sign_transactions_from::<Ethereum>(sender, txs.clone()).await.unwrap();
+        assert_eq!(envelopes.len(), txs.len());
+        for (nonce, envelope) in envelopes.iter().enumerate() {
+            assert_eq!(envelope.nonce(), nonce as u64);
+            assert_eq!(envelope.recover_signer().unwrap(), sender);
+        }
+
+        let err = wallet.sign_transactions_from::<Ethereum>(Address::ZERO, txs).await.unwrap_err();
+        assert!(err.to_string().contains("Missing signing credential"), "{err}");
+    }
+}
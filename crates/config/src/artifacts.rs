@@ -0,0 +1,220 @@
+//! Configuration of the contract outputs written to artifacts.
+
+use foundry_compilers::artifacts::output_selection::OutputSelection;
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// Configuration for the `[artifacts]` section.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArtifactsConfig {
+    /// Contract outputs left out of the artifacts, e.g. `strip = ["opcodes",
+    /// "generated_sources"]`.
+    ///
+    /// Stripped outputs are not requested from the compiler, which cuts the size of the artifacts
+    /// and the time spent writing and reading them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub strip: Vec<ArtifactField>,
+}
+
+impl ArtifactsConfig {
+    /// Returns `true` if no output is stripped.
+    pub fn is_empty(&self) -> bool {
+        self.strip.is_empty()
+    }
+}
+
+/// An optional contract output, which can be left out of the artifacts.
+///
+/// The ABI, the bytecode objects and their link references are always requested.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtifactField {
+    /// The contract metadata.
+    Metadata,
+    /// The function selectors.
+    MethodIdentifiers,
+    /// The source maps of the creation and runtime bytecode.
+    SourceMaps,
+    /// The opcodes of the creation and runtime bytecode.
+    Opcodes,
+    /// The compiler-generated Yul sources.
+    GeneratedSources,
+    /// The debug data of internal functions.
+    FunctionDebugData,
+    /// The storage layout.
+    StorageLayout,
+    /// The gas estimates.
+    GasEstimates,
+}
+
+impl ArtifactField {
+    /// All fields.
+    pub const ALL: [Self; 8] = [
+        Self::Metadata,
+        Self::MethodIdentifiers,
+        Self::SourceMaps,
+        Self::Opcodes,
+        Self::GeneratedSources,
+        Self::FunctionDebugData,
+        Self::StorageLayout,
+        Self::GasEstimates,
+    ];
+
+    /// Fields which are not needed to execute contracts, as in tests and scripts.
+    ///
+    /// Source maps are used by backtraces and the debugger, and storage layouts seed the fuzz
+    /// dictionary, so they are kept.
+    pub const UNUSED_BY_EXECUTION: [Self; 4] =
+        [Self::Opcodes, Self::GeneratedSources, Self::FunctionDebugData, Self::GasEstimates];
+
+    /// Returns the name of the field.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Metadata => "metadata",
+            Self::MethodIdentifiers => "method_identifiers",
+            Self::SourceMaps => "source_maps",
+            Self::Opcodes => "opcodes",
+            Self::GeneratedSources => "generated_sources",
+            Self::FunctionDebugData => "function_debug_data",
+            Self::StorageLayout => "storage_layout",
+            Self::GasEstimates => "gas_estimates",
+        }
+    }
+
+    /// Returns the solc output selections of the field.
+    pub const fn output_selections(&self) -> &'static [&'static str] {
+        match self {
+            Self::Metadata => &["metadata"],
+            Self::MethodIdentifiers => &["evm.methodIdentifiers"],
+            Self::SourceMaps => &["evm.bytecode.sourceMap", "evm.deployedBytecode.sourceMap"],
+            Self::Opcodes => &["evm.bytecode.opcodes", "evm.deployedBytecode.opcodes"],
+            Self::GeneratedSources => {
+                &["evm.bytecode.generatedSources", "evm.deployedBytecode.generatedSources"]
+            }
+            Self::FunctionDebugData => {
+                &["evm.bytecode.functionDebugData", "evm.deployedBytecode.functionDebugData"]
+            }
+            Self::StorageLayout => &["storageLayout"],
+            Self::GasEstimates => &["evm.gasEstimates"],
+        }
+    }
+}
+
+impl fmt::Display for ArtifactField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ArtifactField {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.to_ascii_lowercase().replace('-', "_");
+        Self::ALL.into_iter().find(|field| field.as_str() == name).ok_or_else(|| {
+            let names = Self::ALL.map(|field| field.as_str()).join(", ");
+            format!("unknown artifact field `{s}`, expected one of: {names}")
+        })
+    }
+}
+
+/// The sub-selections of the `evm.bytecode` output selection.
+const BYTECODE_SELECTIONS: &[&str] = &[
+    "evm.bytecode.object",
+    "evm.bytecode.opcodes",
+    "evm.bytecode.sourceMap",
+    "evm.bytecode.linkReferences",
+    "evm.bytecode.generatedSources",
+    "evm.bytecode.functionDebugData",
+];
+
+/// The sub-selections of the `evm.deployedBytecode` output selection.
+const DEPLOYED_BYTECODE_SELECTIONS: &[&str] = &[
+    "evm.deployedBytecode.object",
+    "evm.deployedBytecode.opcodes",
+    "evm.deployedBytecode.sourceMap",
+    "evm.deployedBytecode.linkReferences",
+    "evm.deployedBytecode.generatedSources",
+    "evm.deployedBytecode.functionDebugData",
+    "evm.deployedBytecode.immutableReferences",
+];
+
+/// Removes the given fields from the contract outputs of `selection`.
+///
+/// Whole bytecode selections are expanded into their sub-selections when one of them is removed.
+pub fn strip_output_selection(selection: &mut OutputSelection, fields: &[ArtifactField]) {
+    let stripped: Vec<&str> =
+        fields.iter().flat_map(|field| field.output_selections()).copied().collect();
+    if stripped.is_empty() {
+        return;
+    }
+
+    for contracts in selection.0.values_mut() {
+        for (contract, outputs) in contracts.iter_mut() {
+            // File-level outputs, such as the AST.
+            if contract.is_empty() {
+                continue;
+            }
+
+            let mut slim = Vec::with_capacity(outputs.len());
+            for output in outputs.drain(..) {
+                let expanded = match output.as_str() {
+                    "evm.bytecode" => BYTECODE_SELECTIONS,
+                    "evm.deployedBytecode" => DEPLOYED_BYTECODE_SELECTIONS,
+                    _ => {
+                        if !stripped.contains(&output.as_str()) {
+                            slim.push(output);
+                        }
+                        continue;
+                    }
+                };
+                if expanded.iter().any(|sub| stripped.contains(sub)) {
+                    slim.extend(
+                        expanded
+                            .iter()
+                            .filter(|sub| !stripped.contains(sub))
+                            .map(|s| s.to_string()),
+                    );
+                } else {
+                    slim.push(output);
+                }
+            }
+            *outputs = slim;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_artifact_fields() {
+        for field in ArtifactField::ALL {
+            assert_eq!(field.as_str().parse::<ArtifactField>().unwrap(), field);
+        }
+        assert_eq!("source-maps".parse::<ArtifactField>().unwrap(), ArtifactField::SourceMaps);
+        assert!("abi".parse::<ArtifactField>().is_err());
+    }
+
+    #[test]
+    fn strips_output_selection() {
+        let mut selection = OutputSelection::common_output_selection([
+            "abi".to_string(),
+            "evm.bytecode".to_string(),
+            "evm.deployedBytecode".to_string(),
+            "evm.methodIdentifiers".to_string(),
+            "metadata".to_string(),
+        ]);
+        strip_output_selection(&mut selection, &[ArtifactField::Opcodes, ArtifactField::Metadata]);
+
+        let outputs = &selection.0["*"]["*"];
+        assert!(outputs.contains(&"abi".to_string()));
+        assert!(outputs.contains(&"evm.methodIdentifiers".to_string()));
+        assert!(outputs.contains(&"evm.bytecode.object".to_string()));
+        assert!(outputs.contains(&"evm.deployedBytecode.immutableReferences".to_string()));
+        assert!(!outputs.iter().any(|output| output == "metadata"
+            || output == "evm.bytecode"
+            || output.ends_with(".opcodes")));
+    }
+}
@@ -2,7 +2,13 @@
 
 use crate::Chain;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::{fmt, fmt::Formatter, str::FromStr};
+use std::{
+    fmt,
+    fmt::Formatter,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 use unit_prefix::NumberPrefix;
 
 /// Settings to configure caching of remote.
@@ -225,6 +231,72 @@ pub struct ChainCache {
     pub block_explorer: u64,
 }
 
+/// A category of data removed by `forge clean`, e.g. build artifacts or the RPC cache.
+#[derive(Debug)]
+pub struct CleanCategory {
+    /// The name of the category.
+    pub name: &'static str,
+    /// The files and directories belonging to the category.
+    pub paths: Vec<PathBuf>,
+    /// The total size of `paths` in bytes.
+    pub size: u64,
+}
+
+impl CleanCategory {
+    /// Creates a new category, measuring the disk usage of the existing `paths`.
+    pub fn new(name: &'static str, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        let paths: Vec<_> = paths.into_iter().filter(|path| path.exists()).collect();
+        let size = paths.iter().map(|path| disk_usage(path)).sum();
+        Self { name, paths, size }
+    }
+}
+
+/// Disk usage report of the categories removed by `forge clean`.
+#[derive(Debug, Default)]
+pub struct DiskUsage {
+    /// The categories to report.
+    pub categories: Vec<CleanCategory>,
+}
+
+impl DiskUsage {
+    /// Returns the total size of all categories in bytes.
+    pub fn total(&self) -> u64 {
+        self.categories.iter().map(|category| category.size).sum()
+    }
+}
+
+impl fmt::Display for DiskUsage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for category in &self.categories {
+            writeln!(f, "- {} ({})", category.name, format_size(category.size))?;
+            for path in &category.paths {
+                writeln!(f, "\t- {}", path.display())?;
+            }
+        }
+        writeln!(f, "Total: {}", format_size(self.total()))
+    }
+}
+
+/// Returns the size of the file or the recursive size of the directory at `path` in bytes.
+///
+/// Entries that can't be read are ignored.
+pub fn disk_usage(path: &Path) -> u64 {
+    let Ok(metadata) = fs::symlink_metadata(path) else { return 0 };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+    fs::read_dir(path)
+        .map(|entries| entries.flatten().map(|entry| disk_usage(&entry.path())).sum())
+        .unwrap_or_default()
+}
+
+fn format_size(size: u64) -> String {
+    match NumberPrefix::decimal(size as f32) {
+        NumberPrefix::Standalone(size) => format!("{size:.1} B"),
+        NumberPrefix::Prefixed(prefix, size) => format!("{size:.1} {prefix}B"),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -307,4 +379,30 @@ fn cache_to_string() {
                 - Block 2 (2.0 B)\n";
         assert_eq!(format!("{cache}"), expected);
     }
+
+    #[test]
+    fn disk_usage_to_string() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("out/nested")).unwrap();
+        fs::write(dir.path().join("out/a.json"), [0u8; 1000]).unwrap();
+        fs::write(dir.path().join("out/nested/b.json"), [0u8; 500]).unwrap();
+        assert_eq!(disk_usage(&dir.path().join("out")), 1500);
+        assert_eq!(disk_usage(&dir.path().join("missing")), 0);
+
+        let usage = DiskUsage {
+            categories: vec![
+                CleanCategory::new("Build artifacts", [dir.path().join("out")]),
+                CleanCategory::new("RPC cache", [dir.path().join("missing")]),
+            ],
+        };
+        let expected = format!(
+            "\
+            - Build artifacts (1.5 kB)\n\t\
+                - {}\n\
+            - RPC cache (0.0 B)\n\
+            Total: 1.5 kB\n",
+            dir.path().join("out").display()
+        );
+        assert_eq!(usage.to_string(), expected);
+    }
 }
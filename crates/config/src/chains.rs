@@ -0,0 +1,41 @@
+//! Configuration of the chains tests can switch to with `vm.selectChain`.
+
+use foundry_compilers::artifacts::EvmVersion;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for a `[chains.<name>]` section.
+///
+/// ```toml
+/// [chains.base]
+/// rpc = "base"
+/// evm_version = "cancun"
+/// gas_price = 1000000
+/// block_base_fee_per_gas = 1000000
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainSpecConfig {
+    /// The RPC endpoint of the chain, as an URL or an alias of `[rpc_endpoints]`.
+    ///
+    /// Defaults to the name of the chain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rpc: Option<String>,
+    /// The block to fork the chain at, the latest block if unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fork_block_number: Option<u64>,
+    /// The EVM version defining the opcodes available on the chain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evm_version: Option<EvmVersion>,
+    /// The gas price of the transactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_price: Option<u64>,
+    /// The base fee of the blocks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_base_fee_per_gas: Option<u64>,
+}
+
+impl ChainSpecConfig {
+    /// Returns the RPC endpoint of the chain named `name`.
+    pub fn rpc<'a>(&'a self, name: &'a str) -> &'a str {
+        self.rpc.as_deref().unwrap_or(name)
+    }
+}
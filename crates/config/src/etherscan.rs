@@ -78,9 +78,15 @@ pub fn is_empty(&self) -> bool {
         self.configs.is_empty()
     }
 
-    /// Returns the first config that matches the chain
+    /// Returns the first config that matches the chain.
+    ///
+    /// Configs that explicitly target the chain take precedence over multi-chain configs that
+    /// only list it in `chains`.
     pub fn find_chain(&self, chain: Chain) -> Option<&EtherscanConfig> {
-        self.configs.values().find(|config| config.chain == Some(chain))
+        self.configs
+            .values()
+            .find(|config| config.chain == Some(chain))
+            .or_else(|| self.configs.values().find(|config| config.chains.contains(&chain)))
     }
 
     /// Returns all (alias -> url) pairs
@@ -131,18 +137,25 @@ pub fn new(
     }
 
     /// Returns the first config that matches the chain
+    ///
+    /// If no config explicitly targets the chain, the first multi-chain config listing it in
+    /// `chains` is returned, updated to point at the chain's API.
     pub fn find_chain(
         self,
         chain: Chain,
     ) -> Option<Result<ResolvedEtherscanConfig, EtherscanConfigError>> {
+        let mut multi_chain = None;
         for (_, config) in self.configs {
             match config {
                 Ok(c) if c.chain == Some(chain) => return Some(Ok(c)),
+                Ok(c) if multi_chain.is_none() && c.chains.contains(&chain) => {
+                    multi_chain = Some(c)
+                }
                 Err(e) => return Some(Err(e)),
                 _ => {}
             }
         }
-        None
+        multi_chain.map(|c| Ok(c.with_chain(chain)))
     }
 
     /// Returns true if there's a config that couldn't be resolved
@@ -176,6 +189,15 @@ pub struct EtherscanConfig {
     pub url: Option<String>,
     /// The etherscan API KEY that's required to make requests
     pub key: EtherscanApiKey,
+    /// Additional chains covered by the same key.
+    ///
+    /// Etherscan API v2 keys are valid for every supported chain, so a single entry can serve
+    /// many chains instead of repeating the key for each of them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chains: Vec<Chain>,
+    /// Request budget and backoff settings.
+    #[serde(flatten)]
+    pub limits: EtherscanRequestLimits,
 }
 
 impl EtherscanConfig {
@@ -189,7 +211,7 @@ pub fn resolve(
         self,
         alias: Option<&str>,
     ) -> Result<ResolvedEtherscanConfig, EtherscanConfigError> {
-        let Self { chain, mut url, key } = self;
+        let Self { chain, mut url, key, chains, limits } = self;
 
         if let Some(url) = &mut url {
             *url = interpolate(url)?;
@@ -223,14 +245,23 @@ pub fn resolve(
                 browser_url: chain.etherscan_urls().map(|(_, url)| url.to_string()),
                 key,
                 chain: Some(chain),
+                chains,
+                limits,
             }),
-            (Some(chain), None) => ResolvedEtherscanConfig::create(key, chain).ok_or_else(|| {
-                let msg = alias.map(|a| format!("for `{a}`")).unwrap_or_default();
-                EtherscanConfigError::UnknownChain(msg, chain)
+            (Some(chain), None) => ResolvedEtherscanConfig::create(key, chain)
+                .map(|config| ResolvedEtherscanConfig { chains, limits, ..config })
+                .ok_or_else(|| {
+                    let msg = alias.map(|a| format!("for `{a}`")).unwrap_or_default();
+                    EtherscanConfigError::UnknownChain(msg, chain)
+                }),
+            (None, Some(api_url)) => Ok(ResolvedEtherscanConfig {
+                api_url,
+                browser_url: None,
+                key,
+                chain: None,
+                chains,
+                limits,
             }),
-            (None, Some(api_url)) => {
-                Ok(ResolvedEtherscanConfig { api_url, browser_url: None, key, chain: None })
-            }
             (None, None) => {
                 let msg = alias
                     .map(|a| format!(" for Etherscan config with unknown alias `{a}`"))
@@ -255,6 +286,12 @@ pub struct ResolvedEtherscanConfig {
     /// The chain name or EIP-155 chain ID.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub chain: Option<Chain>,
+    /// Additional chains covered by the same key.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chains: Vec<Chain>,
+    /// Request budget and backoff settings.
+    #[serde(flatten)]
+    pub limits: EtherscanRequestLimits,
 }
 
 impl ResolvedEtherscanConfig {
@@ -267,6 +304,8 @@ pub fn create(api_key: impl Into<String>, chain: impl Into<Chain>) -> Option<Sel
             browser_url: Some(browser_url.to_string()),
             key: api_key.into(),
             chain: Some(chain),
+            chains: Vec::new(),
+            limits: EtherscanRequestLimits::default(),
         })
     }
 
@@ -295,7 +334,7 @@ pub fn into_client(
         self,
     ) -> Result<foundry_block_explorers::Client, foundry_block_explorers::errors::EtherscanError>
     {
-        let Self { api_url, browser_url, key: api_key, chain } = self;
+        let Self { api_url, browser_url, key: api_key, chain, .. } = self;
 
         let chain = chain.unwrap_or_default();
         let cache = Config::foundry_etherscan_chain_cache_dir(chain);
@@ -324,6 +363,25 @@ pub fn into_client(
     }
 }
 
+/// Request budget and backoff settings for an Etherscan (or compatible) API.
+///
+/// The budget applies per chain and is shared by every client talking to that chain, see
+/// `foundry_common::etherscan::EtherscanThrottle`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EtherscanRequestLimits {
+    /// The maximum number of requests per second sent to a single chain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requests_per_second: Option<u32>,
+    /// The number of retries when the API responds with `429` or `503`.
+    ///
+    /// Verification submissions are never retried, only the requests reading from the API.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u32>,
+    /// Initial retry backoff in milliseconds, doubled after every attempt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_backoff: Option<u64>,
+}
+
 /// Represents a single etherscan API key
 ///
 /// This type preserves the value as it's stored in the config. If the value is a reference to an
@@ -398,6 +456,8 @@ fn can_create_client_via_chain() {
                 chain: Some(Mainnet.into()),
                 url: None,
                 key: EtherscanApiKey::Key("ABCDEFG".to_string()),
+                chains: vec![],
+                limits: Default::default(),
             },
         );
 
@@ -420,6 +480,8 @@ fn can_create_client_via_url_and_chain() {
                 chain: Some(Mainnet.into()),
                 url: Some("https://api.etherscan.io/api".to_string()),
                 key: EtherscanApiKey::Key("ABCDEFG".to_string()),
+                chains: vec![],
+                limits: Default::default(),
             },
         );
 
@@ -438,6 +500,8 @@ fn can_create_client_via_url_and_chain_env_var() {
                 chain: Some(Mainnet.into()),
                 url: Some("https://api.etherscan.io/api".to_string()),
                 key: EtherscanApiKey::Env(format!("${{{env}}}")),
+                chains: vec![],
+                limits: Default::default(),
             },
         );
 
@@ -470,6 +534,8 @@ fn resolve_etherscan_alias_config() {
                 chain: None,
                 url: Some("https://api.etherscan.io/api".to_string()),
                 key: EtherscanApiKey::Key("ABCDEFG".to_string()),
+                chains: vec![],
+                limits: Default::default(),
             },
         );
 
@@ -484,6 +550,8 @@ fn resolve_etherscan_alias() {
             chain: None,
             url: Some("https://api.etherscan.io/api".to_string()),
             key: EtherscanApiKey::Key("ABCDEFG".to_string()),
+            chains: vec![],
+            limits: Default::default(),
         };
         let resolved = config.clone().resolve(Some("base_sepolia")).unwrap();
         assert_eq!(resolved.chain, Some(Chain::base_sepolia()));
@@ -503,6 +571,8 @@ fn can_create_client_with_custom_url_for_chain_without_default_url() {
                 chain: Some(Chain::dev()),
                 url: Some("https://custom.api.url/verify/etherscan".to_string()),
                 key: EtherscanApiKey::Key("test_key".to_string()),
+                chains: vec![],
+                limits: Default::default(),
             },
         );
 
@@ -526,6 +596,8 @@ fn fails_without_custom_url_for_chain_without_default_url() {
                 chain: Some(Chain::dev()),
                 url: None,
                 key: EtherscanApiKey::Key("test_key".to_string()),
+                chains: vec![],
+                limits: Default::default(),
             },
         );
 
@@ -537,4 +609,85 @@ fn fails_without_custom_url_for_chain_without_default_url() {
             "Should fail: chains without default Etherscan URLs require custom URL"
         );
     }
+
+    #[test]
+    fn resolve_multi_chain_key() {
+        let mut configs = EtherscanConfigs::default();
+        configs.insert(
+            "v2".to_string(),
+            EtherscanConfig {
+                chain: Some(Mainnet.into()),
+                url: None,
+                key: EtherscanApiKey::Key("ABCDEFG".to_string()),
+                chains: vec![Chain::base(), Chain::optimism_mainnet()],
+                limits: EtherscanRequestLimits {
+                    requests_per_second: Some(3),
+                    ..Default::default()
+                },
+            },
+        );
+
+        assert!(configs.find_chain(Chain::base()).is_some());
+        assert!(configs.find_chain(Chain::arbitrum_mainnet()).is_none());
+
+        let config = configs.clone().resolved().find_chain(Chain::base()).unwrap().unwrap();
+        assert_eq!(config.chain, Some(Chain::base()));
+        assert_eq!(config.key, "ABCDEFG");
+        assert_eq!(config.api_url, Chain::base().etherscan_urls().unwrap().0);
+        assert_eq!(config.limits.requests_per_second, Some(3));
+
+        let config = configs.resolved().find_chain(Mainnet.into()).unwrap().unwrap();
+        assert_eq!(config.chain, Some(Mainnet.into()));
+    }
+
+    #[test]
+    fn explicit_chain_takes_precedence_over_multi_chain() {
+        let mut configs = EtherscanConfigs::default();
+        configs.insert(
+            "a_v2".to_string(),
+            EtherscanConfig {
+                chain: Some(Mainnet.into()),
+                url: None,
+                key: EtherscanApiKey::Key("MULTI".to_string()),
+                chains: vec![Chain::base()],
+                limits: Default::default(),
+            },
+        );
+        configs.insert(
+            "base".to_string(),
+            EtherscanConfig {
+                chain: Some(Chain::base()),
+                url: None,
+                key: EtherscanApiKey::Key("BASE".to_string()),
+                chains: vec![],
+                limits: Default::default(),
+            },
+        );
+
+        let config = configs.resolved().find_chain(Chain::base()).unwrap().unwrap();
+        assert_eq!(config.key, "BASE");
+    }
+
+    #[test]
+    fn deserialize_request_limits() {
+        let config: EtherscanConfig = toml::from_str(
+            r#"
+            key = "ABCDEFG"
+            chains = ["base", 10]
+            requests_per_second = 5
+            retries = 3
+            retry_backoff = 500
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.chains, vec![Chain::base(), Chain::optimism_mainnet()]);
+        assert_eq!(
+            config.limits,
+            EtherscanRequestLimits {
+                requests_per_second: Some(5),
+                retries: Some(3),
+                retry_backoff: Some(500),
+            }
+        );
+    }
 }
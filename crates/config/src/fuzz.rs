@@ -27,6 +27,9 @@ pub struct FuzzConfig {
     pub corpus: FuzzCorpusConfig,
     /// Path where fuzz failures are recorded and replayed.
     pub failure_persist_dir: Option<PathBuf>,
+    /// Directory of the regression files, meant to be checked in to source control, where the
+    /// failing inputs of each test contract are appended and replayed first in the next runs.
+    pub regressions_dir: Option<PathBuf>,
     /// show `console.log` in fuzz test, defaults to `false`
     pub show_logs: bool,
     /// Optional timeout (in seconds) for each property test
@@ -44,6 +47,7 @@ fn default() -> Self {
             gas_report_samples: 256,
             corpus: FuzzCorpusConfig::default(),
             failure_persist_dir: None,
+            regressions_dir: None,
             show_logs: false,
             timeout: None,
         }
@@ -51,9 +55,14 @@ fn default() -> Self {
 }
 
 impl FuzzConfig {
-    /// Creates fuzz configuration to write failures in `{PROJECT_ROOT}/cache/fuzz` dir.
+    /// Creates fuzz configuration to write failures in `{PROJECT_ROOT}/cache/fuzz` dir, and
+    /// regressions in `{PROJECT_ROOT}/testdata/fuzz-regressions` dir.
     pub fn new(cache_dir: PathBuf) -> Self {
-        Self { failure_persist_dir: Some(cache_dir), ..Default::default() }
+        Self {
+            failure_persist_dir: Some(cache_dir),
+            regressions_dir: Some("testdata/fuzz-regressions".into()),
+            ..Default::default()
+        }
     }
 }
 
@@ -0,0 +1,84 @@
+//! Configuration of the codegen hooks run around builds.
+
+use serde::{Deserialize, Serialize};
+use std::{fmt, path::PathBuf};
+
+/// Configuration for the `[hooks]` section.
+///
+/// Hooks run shell commands, so they are only run by `forge build` and `forge test` with the
+/// `--run-hooks` flag.
+///
+/// ```toml
+/// [[hooks.pre_build]]
+/// name = "bindings"
+/// run = "forge bind-json"
+/// inputs = ["src/**/*.sol"]
+/// outputs = ["utils/JsonBindings.sol"]
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HooksConfig {
+    /// Hooks run before compiling the project.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pre_build: Vec<Hook>,
+    /// Hooks run after compiling the project successfully.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub post_build: Vec<Hook>,
+}
+
+impl HooksConfig {
+    /// Returns `true` if no hook is configured.
+    pub fn is_empty(&self) -> bool {
+        self.pre_build.is_empty() && self.post_build.is_empty()
+    }
+
+    /// Returns the hooks of the given stage.
+    pub fn hooks(&self, stage: HookStage) -> &[Hook] {
+        match stage {
+            HookStage::PreBuild => &self.pre_build,
+            HookStage::PostBuild => &self.post_build,
+        }
+    }
+}
+
+/// A codegen step run before or after builds.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hook {
+    /// The name of the hook.
+    pub name: String,
+    /// The shell command to run, from the project root.
+    pub run: String,
+    /// Globs of the files the hook reads, relative to the project root.
+    ///
+    /// The hook only runs when one of them changed since its last run, or when one of its
+    /// `outputs` is missing. Without inputs, the hook runs on every build.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inputs: Vec<String>,
+    /// The files the hook generates, relative to the project root.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub outputs: Vec<PathBuf>,
+}
+
+/// When a hook runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HookStage {
+    /// Before compiling the project.
+    PreBuild,
+    /// After compiling the project successfully.
+    PostBuild,
+}
+
+impl HookStage {
+    /// Returns the name of the stage.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::PreBuild => "pre-build",
+            Self::PostBuild => "post-build",
+        }
+    }
+}
+
+impl fmt::Display for HookStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -63,7 +63,7 @@
 };
 
 mod etherscan;
-pub use etherscan::EtherscanConfigError;
+pub use etherscan::{EtherscanConfigError, EtherscanRequestLimits};
 use etherscan::{EtherscanConfigs, EtherscanEnvProvider, ResolvedEtherscanConfig};
 
 pub mod resolve;
@@ -123,6 +123,18 @@
 mod bind_json;
 use bind_json::BindJsonConfig;
 
+mod artifacts;
+pub use artifacts::{ArtifactField, ArtifactsConfig, strip_output_selection};
+
+mod hooks;
+pub use hooks::{Hook, HookStage, HooksConfig};
+
+mod chains;
+pub use chains::ChainSpecConfig;
+
+mod script_exports;
+pub use script_exports::{ScriptExportFormat, ScriptExportsConfig};
+
 mod compilation;
 pub use compilation::{CompilationRestrictions, SettingsOverrides};
 
@@ -264,6 +276,17 @@ pub struct Config {
     ///      be auto detected but if the solc version is not installed, it will _not_ try to
     ///      install it
     pub offline: bool,
+    /// The maximum number of solc processes to run in parallel.
+    ///
+    /// Defaults to the number of available CPUs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solc_jobs: Option<usize>,
+    /// The memory budget, in MiB, shared by the solc processes running in parallel.
+    ///
+    /// Limits the number of parallel solc jobs to what fits in the budget, see
+    /// [`Self::parallel_solc_jobs`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solc_memory_budget: Option<u64>,
     /// Whether to activate optimizer
     pub optimizer: Option<bool>,
     /// The number of runs specifies roughly how often each opcode of the deployed code will be
@@ -461,6 +484,13 @@ pub struct Config {
     /// Disables rate limiting entirely. This overrides any settings made in
     /// `compute_units_per_second`
     pub no_rpc_rate_limit: bool,
+    /// Protocol presets whose contracts are fetched when creating a fork, e.g.
+    /// `["uniswap-v3", "aave-v3", "stables"]`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fork_presets: Vec<String>,
+    /// Chains tests can switch to with `vm.selectChain`, keyed by name, e.g. `[chains.base]`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub chains: BTreeMap<String, ChainSpecConfig>,
     /// Multiple rpc endpoints and their aliases
     #[serde(default, skip_serializing_if = "RpcEndpoints::is_empty")]
     pub rpc_endpoints: RpcEndpoints,
@@ -489,6 +519,8 @@ pub struct Config {
     pub build_info: bool,
     /// The path to the `build-info` directory that contains the build info json files.
     pub build_info_path: Option<PathBuf>,
+    /// Whether to write a provenance file for every build and attach it to broadcast artifacts.
+    pub provenance: bool,
     /// Configuration for `forge fmt`
     pub fmt: FormatterConfig,
     /// Configuration for `forge lint`
@@ -497,6 +529,18 @@ pub struct Config {
     pub doc: DocConfig,
     /// Configuration for `forge bind-json`
     pub bind_json: BindJsonConfig,
+    /// Configuration of the contract outputs written to artifacts.
+    #[serde(default, skip_serializing_if = "ArtifactsConfig::is_empty")]
+    pub artifacts: ArtifactsConfig,
+    /// Codegen hooks run before and after builds.
+    ///
+    /// Hooks only run with the `--run-hooks` flag, which can't be set from the config.
+    #[serde(default, skip_serializing_if = "HooksConfig::is_empty")]
+    pub hooks: HooksConfig,
+    /// Files written with the deployed addresses after a script broadcast, configured in the
+    /// `[script.exports]` section.
+    #[serde(default, skip_serializing_if = "ScriptExportsConfig::is_empty")]
+    pub script_exports: ScriptExportsConfig,
     /// Configures the permissions of cheat codes that touch the file system.
     ///
     /// This includes what operations can be executed (read, write)
@@ -516,6 +560,11 @@ pub struct Config {
     /// Address labels
     pub labels: AddressHashMap<String>,
 
+    /// Restricts cheatcodes to the contracts defined under the given paths, keyed by cheatcode
+    /// name, e.g. `ffi = ["test/ffi"]`. Cheatcodes without an entry are not restricted.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub cheatcode_permissions: BTreeMap<String, Vec<PathBuf>>,
+
     /// Whether to enable safety checks for `vm.getCode` and `vm.getDeployedCode` invocations.
     /// If disabled, it is possible to access artifacts which were not recompiled or cached.
     pub unchecked_cheatcode_artifacts: bool,
@@ -540,6 +589,13 @@ pub struct Config {
     /// Note that this only applies to native (cheatcode) assertions, invoked on Vm contract.
     pub assertions_revert: bool,
 
+    /// Whether the calls to native (cheatcode) assertions, e.g. `vm.assertEq`, are not metered.
+    ///
+    /// Only the gas of the call to the assertion cheatcode is removed from the gas used by tests.
+    /// forge-std assertions, e.g. `assertEq`, forward to these cheatcodes, but the Solidity code
+    /// around the call, such as encoding the arguments, is still metered.
+    pub gas_free_assertions: bool,
+
     /// Whether `failed()` should be invoked to check if the test have failed.
     pub legacy_assertions: bool,
 
@@ -680,12 +736,23 @@ pub const fn never(&self) -> bool {
 /// Mapping of fallback standalone sections. See [`FallbackProfileProvider`].
 pub const STANDALONE_FALLBACK_SECTIONS: &[(&str, &str)] = &[("invariant", "fuzz")];
 
+/// Tables nested in a standalone section whose name is already a key of the config, as
+/// `(section, key)`: `[<section>.<key>]` is the `<section>_<key>` key of the config. See
+/// [`UnnestProvider`].
+pub const NESTED_STANDALONE_SECTIONS: &[(&str, &str)] = &[("script", "exports")];
+
 /// Deprecated keys and their replacements.
 ///
 /// See [Warning::DeprecatedKey]
 pub const DEPRECATIONS: &[(&str, &str)] =
     &[("cancun", "evm_version = Cancun"), ("deny_warnings", "deny = warnings")];
 
+/// The estimated peak memory of a solc process using the legacy pipeline, in MiB.
+const SOLC_JOB_MEMORY_MIB: u64 = 512;
+
+/// The estimated peak memory of a solc process using the IR pipeline, in MiB.
+const SOLC_IR_JOB_MEMORY_MIB: u64 = 2048;
+
 impl Config {
     /// The default profile: "default"
     pub const DEFAULT_PROFILE: Profile = Profile::Default;
@@ -713,12 +780,16 @@ impl Config {
         "soldeer",
         "vyper",
         "bind_json",
+        "artifacts",
+        "hooks",
+        "chains",
     ];
 
     pub(crate) fn is_standalone_section<T: ?Sized + PartialEq<str>>(section: &T) -> bool {
         section == Self::PROFILE_SECTION
             || section == Self::EXTERNAL_SECTION
             || Self::STANDALONE_SECTIONS.iter().any(|s| section == *s)
+            || NESTED_STANDALONE_SECTIONS.iter().any(|(s, _)| section == *s)
     }
 
     /// File name of config toml file
@@ -1146,6 +1217,35 @@ pub fn project(&self) -> Result<Project<MultiCompiler>, SolcError> {
         self.create_project(self.cache, false)
     }
 
+    /// Same as [`Self::project()`], without the optional contract outputs in `unneeded`.
+    ///
+    /// Commands use this to only request the outputs they need. Outputs written to separate files
+    /// with `extra_output_files` are kept.
+    pub fn slim_project(
+        &self,
+        unneeded: &[ArtifactField],
+    ) -> Result<Project<MultiCompiler>, SolcError> {
+        let mut project = self.project()?;
+        let unneeded = unneeded
+            .iter()
+            .copied()
+            .filter(|field| {
+                !self.extra_output_files.iter().any(|output| {
+                    matches!(
+                        (field, output),
+                        (ArtifactField::Metadata, ContractOutputSelection::Metadata)
+                            | (
+                                ArtifactField::StorageLayout,
+                                ContractOutputSelection::StorageLayout
+                            )
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        project.update_output_selection(|selection| strip_output_selection(selection, &unneeded));
+        Ok(project)
+    }
+
     /// Same as [`Self::project()`] but sets configures the project to not emit artifacts and ignore
     /// cache.
     pub fn ephemeral_project(&self) -> Result<Project<MultiCompiler>, SolcError> {
@@ -1263,14 +1363,21 @@ pub fn create_project(&self, cached: bool, no_artifacts: bool) -> Result<Project
             .set_offline(self.offline)
             .set_cached(cached)
             .set_build_info(!no_artifacts && self.build_info)
-            .set_no_artifacts(no_artifacts);
+            .set_no_artifacts(no_artifacts)
+            .solc_jobs(self.parallel_solc_jobs());
 
         if !self.skip.is_empty() {
             let filter = SkipBuildFilters::new(self.skip.clone(), self.root.clone());
             builder = builder.sparse_output(filter);
         }
 
-        let project = builder.build(self.compiler()?)?;
+        let mut project = builder.build(self.compiler()?)?;
+
+        if !self.artifacts.is_empty() {
+            project.update_output_selection(|selection| {
+                strip_output_selection(selection, &self.artifacts.strip);
+            });
+        }
 
         if self.force {
             // Warnings are intentionally dropped here because `sh_warn!` is a circular
@@ -1281,6 +1388,25 @@ pub fn create_project(&self, cached: bool, no_artifacts: bool) -> Result<Project
         Ok(project)
     }
 
+    /// Returns the number of solc processes to run in parallel.
+    ///
+    /// This is `solc_jobs`, or the number of available CPUs, capped by the number of solc
+    /// processes that fit in `solc_memory_budget`. At least one job is always allowed.
+    pub fn parallel_solc_jobs(&self) -> usize {
+        let jobs = self
+            .solc_jobs
+            .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get()));
+        let jobs = match self.solc_memory_budget {
+            Some(budget) => {
+                let per_job =
+                    if self.via_ir { SOLC_IR_JOB_MEMORY_MIB } else { SOLC_JOB_MEMORY_MIB };
+                jobs.min(usize::try_from(budget / per_job).unwrap_or(usize::MAX))
+            }
+            None => jobs,
+        };
+        jobs.max(1)
+    }
+
     /// Disables optimizations and enables viaIR with minimum optimization if `ir_minimum` is true.
     pub fn disable_optimizations(&self, project: &mut Project, ir_minimum: bool) {
         if ir_minimum {
@@ -1303,6 +1429,11 @@ pub fn disable_optimizations(&self, project: &mut Project, ir_minimum: bool) {
         }
     }
 
+    /// Returns the directory of the `forge lint` cache.
+    pub fn lint_cache_dir(&self) -> PathBuf {
+        self.cache_path.join("lint")
+    }
+
     /// Cleans the project.
     ///
     /// Returns a list of warning messages for any non-fatal cleanup failures. Cleanup is
@@ -1346,6 +1477,17 @@ pub fn cleanup<C: Compiler, T: ArtifactOutput<CompilerContract = C::CompilerCont
         remove_test_dir(&self.invariant.corpus.corpus_dir);
         remove_test_dir(&self.invariant.failure_persist_dir);
 
+        // Remove the lint cache.
+        let lint_cache_dir = self.lint_cache_dir();
+        if let Err(err) = fs::remove_dir_all(&lint_cache_dir)
+            && err.kind() != io::ErrorKind::NotFound
+        {
+            warnings.push(format!(
+                "failed to remove lint cache directory {}: {err}",
+                lint_cache_dir.display()
+            ));
+        }
+
         Ok(warnings)
     }
 
@@ -2045,6 +2187,13 @@ pub fn to_string_pretty(&self) -> Result<String, toml::ser::Error> {
                 value_table.remove(&section).map(|value| (section, value))
             })
             .collect::<Vec<_>>();
+        let nested_sections = NESTED_STANDALONE_SECTIONS
+            .iter()
+            .filter_map(|(section, key)| {
+                let value = value_table.remove(&format!("{section}_{key}"))?;
+                Some((section, key, value))
+            })
+            .collect::<Vec<_>>();
         // wrap inner table in [profile.<profile>]
         let mut wrapping_table = [(
             Self::PROFILE_SECTION.into(),
@@ -2056,6 +2205,15 @@ pub fn to_string_pretty(&self) -> Result<String, toml::ser::Error> {
         for (section, value) in standalone_sections {
             wrapping_table.insert(section, value);
         }
+        // insert nested standalone sections, e.g. `script_exports` as `[script.exports]`
+        for (section, key, value) in nested_sections {
+            let table = wrapping_table
+                .entry(section.to_string())
+                .or_insert_with(|| toml::Value::Table(Default::default()));
+            if let Some(table) = table.as_table_mut() {
+                table.insert(key.to_string(), value);
+            }
+        }
         // stringify
         toml::to_string_pretty(&toml::Value::Table(wrapping_table))
     }
@@ -2411,6 +2569,9 @@ fn merge_toml_provider(
                 figment = figment.merge(provider.wrap(profile.clone(), standalone_key));
             }
         }
+        for (section, key) in NESTED_STANDALONE_SECTIONS {
+            figment = figment.merge(provider.unnest(profile.clone(), *section, key));
+        }
         // merge the profile
         figment = figment.merge(provider);
         figment
@@ -2657,6 +2818,8 @@ fn default() -> Self {
             vyper: Default::default(),
             auto_detect_solc: true,
             offline: false,
+            solc_jobs: None,
+            solc_memory_budget: None,
             optimizer: None,
             optimizer_runs: None,
             optimizer_details: None,
@@ -2731,6 +2894,8 @@ fn default() -> Self {
             etherscan: Default::default(),
             no_storage_caching: false,
             no_rpc_rate_limit: false,
+            fork_presets: Default::default(),
+            chains: Default::default(),
             use_literal_content: false,
             bytecode_hash: BytecodeHash::Ipfs,
             cbor_metadata: true,
@@ -2738,11 +2903,16 @@ fn default() -> Self {
             sparse_mode: false,
             build_info: false,
             build_info_path: None,
+            provenance: false,
             fmt: Default::default(),
             lint: Default::default(),
             doc: Default::default(),
             bind_json: Default::default(),
+            artifacts: Default::default(),
+            hooks: Default::default(),
+            script_exports: Default::default(),
             labels: Default::default(),
+            cheatcode_permissions: Default::default(),
             unchecked_cheatcode_artifacts: false,
             create2_library_salt: Self::DEFAULT_CREATE2_LIBRARY_SALT,
             create2_deployer: Self::DEFAULT_CREATE2_DEPLOYER,
@@ -2750,6 +2920,7 @@ fn default() -> Self {
             dependencies: Default::default(),
             soldeer: Default::default(),
             assertions_revert: true,
+            gas_free_assertions: false,
             legacy_assertions: false,
             warnings: vec![],
             extra_args: vec![],
@@ -3453,6 +3624,8 @@ fn test_resolve_etherscan() {
                             chain: Some(NamedChain::Mainnet.into()),
                             browser_url: Some(mainnet_urls.1.to_string()),
                             key: "FX42Z3BBJJEWXWGYV2X1CIPRSCN".to_string(),
+                            chains: vec![],
+                            limits: Default::default(),
                         }
                     ),
                     (
@@ -3462,6 +3635,8 @@ fn test_resolve_etherscan() {
                             chain: Some(Moonbeam.into()),
                             browser_url: Some(mb_urls.1.to_string()),
                             key: "123456789".to_string(),
+                            chains: vec![],
+                            limits: Default::default(),
                         }
                     ),
                 ])
@@ -3506,6 +3681,8 @@ fn test_resolve_etherscan_with_versions() {
                             chain: Some(NamedChain::Mainnet.into()),
                             browser_url: Some(mainnet_urls.1.to_string()),
                             key: "FX42Z3BBJJEWXWGYV2X1CIPRSCN".to_string(),
+                            chains: vec![],
+                            limits: Default::default(),
                         }
                     ),
                     (
@@ -3515,6 +3692,8 @@ fn test_resolve_etherscan_with_versions() {
                             chain: Some(Moonbeam.into()),
                             browser_url: Some(mb_urls.1.to_string()),
                             key: "123456789".to_string(),
+                            chains: vec![],
+                            limits: Default::default(),
                         }
                     ),
                 ])
@@ -4948,6 +5127,11 @@ fn test_lint_config() {
                 [lint]
                 severity = ['high', 'medium']
                 exclude_lints = ['incorrect-shift']
+                run_on_build = 'deny'
+                baseline = 'lint-baseline.json'
+
+                [lint.severity_overrides]
+                mixed-case-function = 'high'
                 ",
             )?;
             let loaded = Config::load().unwrap().sanitized();
@@ -4956,6 +5140,9 @@ fn test_lint_config() {
                 LinterConfig {
                     severity: vec![LintSeverity::High, LintSeverity::Med],
                     exclude_lints: vec!["incorrect-shift".into()],
+                    severity_overrides: [("mixed-case-function".into(), LintSeverity::High)].into(),
+                    run_on_build: Some(lint::LintOnBuild::Deny),
+                    baseline: Some("lint-baseline.json".into()),
                     ..Default::default()
                 }
             );
@@ -5441,6 +5628,68 @@ fn test_parse_vyper() {
         });
     }
 
+    #[test]
+    fn test_parse_chains() {
+        figment::Jail::expect_with(|jail| {
+            jail.create_file(
+                "foundry.toml",
+                r#"
+                [chains.base]
+                evm_version = "cancun"
+                gas_price = 1000000
+
+                [chains.arbitrum]
+                rpc = "https://arb1.arbitrum.io/rpc"
+                fork_block_number = 1
+            "#,
+            )?;
+
+            let config = Config::load().unwrap();
+            let base = &config.chains["base"];
+            assert_eq!(base.rpc("base"), "base");
+            assert_eq!(base.evm_version, Some(EvmVersion::Cancun));
+            assert_eq!(base.gas_price, Some(1000000));
+            let arbitrum = &config.chains["arbitrum"];
+            assert_eq!(arbitrum.rpc("arbitrum"), "https://arb1.arbitrum.io/rpc");
+            assert_eq!(arbitrum.fork_block_number, Some(1));
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_parse_script_exports() {
+        figment::Jail::expect_with(|jail| {
+            jail.create_file(
+                "foundry.toml",
+                r#"
+                [profile.default]
+                script = "scripts"
+
+                [script.exports]
+                formats = ["typescript", "json"]
+            "#,
+            )?;
+
+            let config = Config::load().unwrap();
+            assert_eq!(config.script, PathBuf::from("scripts"));
+            assert_eq!(
+                config.script_exports,
+                ScriptExportsConfig {
+                    out: "deployments".into(),
+                    formats: vec![ScriptExportFormat::Typescript, ScriptExportFormat::Json],
+                }
+            );
+            assert!(config.warnings.is_empty(), "{:?}", config.warnings);
+
+            let toml = config.to_string_pretty().unwrap();
+            assert!(toml.contains("[script.exports]"), "{toml}");
+            assert!(!toml.contains("script_exports"), "{toml}");
+
+            Ok(())
+        });
+    }
+
     #[test]
     fn test_parse_soldeer() {
         figment::Jail::expect_with(|jail| {
@@ -7441,4 +7690,32 @@ fn hyphenated_profile_with_nested_sections() {
             Ok(())
         });
     }
+
+    #[test]
+    fn parallel_solc_jobs_within_memory_budget() {
+        figment::Jail::expect_with(|jail| {
+            jail.create_file(
+                "foundry.toml",
+                r#"
+                [profile.default]
+                solc_jobs = 8
+                solc_memory_budget = 2048
+                "#,
+            )?;
+            let mut config = Config::load().unwrap();
+            assert_eq!(config.solc_jobs, Some(8));
+            assert_eq!(config.parallel_solc_jobs(), 4);
+
+            config.via_ir = true;
+            assert_eq!(config.parallel_solc_jobs(), 1);
+
+            config.solc_memory_budget = Some(0);
+            assert_eq!(config.parallel_solc_jobs(), 1);
+
+            config.solc_memory_budget = None;
+            assert_eq!(config.parallel_solc_jobs(), 8);
+
+            Ok(())
+        });
+    }
 }
@@ -7,7 +7,7 @@
     ast::{self as ast},
     interface::diagnostics::Level,
 };
-use std::str::FromStr;
+use std::{collections::BTreeMap, str::FromStr};
 use yansi::Paint;
 
 /// Contains the config and rule set.
@@ -21,14 +21,41 @@ pub struct LinterConfig {
     /// Deny specific lints based on their ID (e.g. "mixed-case-function").
     pub exclude_lints: Vec<String>,
 
+    /// Overrides the severity of specific lints, by ID (e.g. `mixed-case-function = "low"`).
+    ///
+    /// The overridden severity is the one filtered by `severity`, and the one reported.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub severity_overrides: BTreeMap<String, Severity>,
+
     /// Globs to ignore.
     pub ignore: Vec<String>,
 
     /// Whether to run linting during `forge build`.
     ///
     /// Defaults to true. Set to false to disable automatic linting during builds.
+    /// Superseded by `run_on_build` when set.
     pub lint_on_build: bool,
 
+    /// How to run linting after a successful compilation in `forge build`: `off`, `warn` to report
+    /// the lints, or `deny` to also fail the build on any lint.
+    ///
+    /// Defaults to `warn`, or to `off` if `lint_on_build` is false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_on_build: Option<LintOnBuild>,
+
+    /// The baseline written by `forge lint --write-baseline`, relative to the project root.
+    ///
+    /// The lints of the baseline are not reported, so that only the new ones are.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<PathBuf>,
+
+    /// Paths of the lint plugins, relative to the project root: either plugin libraries, or
+    /// directories whose libraries are all loaded.
+    ///
+    /// Plugins run native code when linting, including during `forge build`, so none are loaded
+    /// by default.
+    pub plugins: Vec<PathBuf>,
+
     /// Configuration specific to individual lints.
     pub lint_specific: LintSpecificConfig,
 }
@@ -37,14 +64,42 @@ impl Default for LinterConfig {
     fn default() -> Self {
         Self {
             lint_on_build: true,
+            run_on_build: None,
+            baseline: None,
+            plugins: Vec::new(),
             severity: vec![Severity::High, Severity::Med, Severity::Low],
             exclude_lints: Vec::new(),
+            severity_overrides: BTreeMap::new(),
             ignore: Vec::new(),
             lint_specific: LintSpecificConfig::default(),
         }
     }
 }
 
+impl LinterConfig {
+    /// Returns how to run linting during `forge build`.
+    pub fn on_build(&self) -> LintOnBuild {
+        self.run_on_build.unwrap_or(if self.lint_on_build {
+            LintOnBuild::Warn
+        } else {
+            LintOnBuild::Off
+        })
+    }
+}
+
+/// How to run linting during `forge build`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintOnBuild {
+    /// Don't lint.
+    Off,
+    /// Report the lints, failing only as configured by `deny`.
+    #[default]
+    Warn,
+    /// Report the lints, and fail the build on any of them.
+    Deny,
+}
+
 /// Contract types that can be exempted from the multi-contract-file lint.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -71,6 +126,12 @@ pub struct LintSpecificConfig {
     /// Defaults to an empty array (all contract types are flagged when multiple exist).
     /// Note: Regular contracts cannot be exempted and will always be flagged when multiple exist.
     pub multi_contract_file_exceptions: Vec<ContractException>,
+
+    /// Signatures of the functions whose boolean return value must be checked by the
+    /// `erc20-unchecked-transfer` lint (e.g. "approve(address,uint256)").
+    ///
+    /// Defaults to the ERC20 `transfer` and `transferFrom` functions.
+    pub erc20_unchecked_transfer_functions: Vec<String>,
 }
 
 impl Default for LintSpecificConfig {
@@ -89,6 +150,10 @@ fn default() -> Self {
                 "HTTPS".to_string(),
             ],
             multi_contract_file_exceptions: Vec::new(),
+            erc20_unchecked_transfer_functions: vec![
+                "transfer(address,uint256)".to_string(),
+                "transferFrom(address,address,uint256)".to_string(),
+            ],
         }
     }
 }
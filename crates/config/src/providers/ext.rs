@@ -42,6 +42,15 @@ fn fallback(
     ) -> FallbackProfileProvider<Self> {
         FallbackProfileProvider::new(self, profile, fallback)
     }
+
+    fn unnest(
+        self,
+        profile: impl Into<Profile>,
+        section: impl Into<Profile>,
+        key: &'static str,
+    ) -> UnnestProvider<Self> {
+        UnnestProvider::new(self, profile, section, key)
+    }
 }
 
 impl<P: Provider> ProviderExt for P {}
@@ -644,6 +653,50 @@ fn profile(&self) -> Option<Profile> {
     }
 }
 
+/// Moves the `[<section>.<key>]` table into the `<section>_<key>` key of the profile.
+///
+/// For the tables nested in a section whose name is already a key of the config, e.g.
+/// `[script.exports]` while `script` is the path of the scripts.
+pub(crate) struct UnnestProvider<P> {
+    provider: P,
+    profile: Profile,
+    section: Profile,
+    key: &'static str,
+}
+
+impl<P> UnnestProvider<P> {
+    pub fn new(
+        provider: P,
+        profile: impl Into<Profile>,
+        section: impl Into<Profile>,
+        key: &'static str,
+    ) -> Self {
+        Self { provider, profile: profile.into(), section: section.into(), key }
+    }
+}
+
+impl<P: Provider> Provider for UnnestProvider<P> {
+    fn metadata(&self) -> Metadata {
+        self.provider.metadata()
+    }
+
+    fn data(&self) -> Result<Map<Profile, Dict>, Error> {
+        let value = self
+            .provider
+            .data()?
+            .remove(&self.section)
+            .and_then(|mut section| section.remove(self.key));
+        let Some(value) = value else { return Ok(Default::default()) };
+        let mut dict = Dict::new();
+        dict.insert(format!("{}_{}", self.section, self.key), value);
+        Ok(self.profile.collect(dict))
+    }
+
+    fn profile(&self) -> Option<Profile> {
+        Some(self.profile.clone())
+    }
+}
+
 /// Extracts the profile from the `profile` key and using the original key as backup, merging
 /// values where necessary
 ///
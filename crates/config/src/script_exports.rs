@@ -0,0 +1,48 @@
+//! Configuration of the address files written after a script deployment.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Configuration for the `[script.exports]` section.
+///
+/// ```toml
+/// [script.exports]
+/// out = "deployments"
+/// formats = ["json", "typescript", "solidity"]
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScriptExportsConfig {
+    /// The directory the files are written to, relative to the project root.
+    pub out: PathBuf,
+    /// The files to write after each broadcast. Nothing is written if empty.
+    ///
+    /// The JSON manifest is always written, as it records the deployments of previous runs the
+    /// other files are generated from.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub formats: Vec<ScriptExportFormat>,
+}
+
+impl Default for ScriptExportsConfig {
+    fn default() -> Self {
+        Self { out: "deployments".into(), formats: Vec::new() }
+    }
+}
+
+impl ScriptExportsConfig {
+    /// Returns `true` if no file is exported.
+    pub fn is_empty(&self) -> bool {
+        self.formats.is_empty()
+    }
+}
+
+/// A file written with the addresses deployed by scripts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScriptExportFormat {
+    /// An `addresses.json` manifest of the addresses by chain id, along with its JSON schema.
+    Json,
+    /// An `addresses.ts` module of constants.
+    Typescript,
+    /// An `Addresses.sol` library.
+    Solidity,
+}
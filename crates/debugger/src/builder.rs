@@ -5,6 +5,7 @@
 use foundry_common::get_contract_name;
 use foundry_evm_core::Breakpoints;
 use foundry_evm_traces::{CallTraceArena, CallTraceDecoder, Traces, debug::ContractSources};
+use std::path::PathBuf;
 
 /// Debugger builder.
 #[derive(Debug, Default)]
@@ -18,6 +19,8 @@ pub struct DebuggerBuilder {
     sources: ContractSources,
     /// Map of the debugger breakpoints.
     breakpoints: Breakpoints,
+    /// The file to persist the debugger session to.
+    session_file: Option<PathBuf>,
 }
 
 impl DebuggerBuilder {
@@ -83,10 +86,22 @@ pub fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
         self
     }
 
+    /// Sets the file to restore the debugger session (breakpoints, bookmarks and notes) from,
+    /// and to save it to on exit.
+    #[inline]
+    pub fn session_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.session_file = Some(path.into());
+        self
+    }
+
     /// Builds the debugger.
     #[inline]
     pub fn build(self) -> Debugger {
-        let Self { debug_arena, identified_contracts, sources, breakpoints } = self;
-        Debugger::new(debug_arena, identified_contracts, sources, breakpoints)
+        let Self { debug_arena, identified_contracts, sources, breakpoints, session_file } = self;
+        let debugger = Debugger::new(debug_arena, identified_contracts, sources, breakpoints);
+        match session_file {
+            Some(path) => debugger.with_session_file(path),
+            None => debugger,
+        }
     }
 }
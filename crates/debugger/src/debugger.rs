@@ -1,11 +1,11 @@
 //! Debugger implementation.
 
-use crate::{DebugNode, DebuggerBuilder, ExitReason, tui::TUI};
+use crate::{DebugNode, DebuggerBuilder, ExitReason, session::DebugSession, tui::TUI};
 use alloy_primitives::map::AddressHashMap;
 use eyre::Result;
 use foundry_evm_core::Breakpoints;
 use foundry_evm_traces::debug::ContractSources;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 pub struct DebuggerContext {
     pub debug_arena: Vec<DebugNode>,
@@ -13,10 +13,14 @@ pub struct DebuggerContext {
     /// Source map of contract sources
     pub contracts_sources: ContractSources,
     pub breakpoints: Breakpoints,
+    /// The breakpoints, bookmarks and notes set in the debugger.
+    pub(crate) session: DebugSession,
 }
 
 pub struct Debugger {
     context: DebuggerContext,
+    /// The file the session is restored from and saved to.
+    session_file: Option<PathBuf>,
 }
 
 impl Debugger {
@@ -27,7 +31,7 @@ pub fn builder() -> DebuggerBuilder {
     }
 
     /// Creates a new debugger.
-    pub const fn new(
+    pub fn new(
         debug_arena: Vec<DebugNode>,
         identified_contracts: AddressHashMap<String>,
         contracts_sources: ContractSources,
@@ -39,10 +43,22 @@ pub const fn new(
                 identified_contracts,
                 contracts_sources,
                 breakpoints,
+                session: DebugSession::default(),
             },
+            session_file: None,
         }
     }
 
+    /// Restores the debugger session from `path` when starting the TUI, and saves it there on
+    /// exit.
+    ///
+    /// Locations of the session whose code changed are remapped to the same source code on a best
+    /// effort basis.
+    pub fn with_session_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.session_file = Some(path.into());
+        self
+    }
+
     /// Starts the debugger TUI. Terminates the current process on failure or user exit.
     pub fn run_tui_exit(mut self) -> ! {
         let code = match self.try_run_tui() {
@@ -59,8 +75,42 @@ pub fn run_tui_exit(mut self) -> ! {
     pub fn try_run_tui(&mut self) -> Result<ExitReason> {
         eyre::ensure!(!self.context.debug_arena.is_empty(), "debug arena is empty");
 
+        if let Some(path) = &self.session_file {
+            self.restore_session(path.clone());
+        }
+
         let mut tui = TUI::new(&mut self.context);
-        tui.try_run()
+        let reason = tui.try_run();
+
+        if let Some(path) = &self.session_file {
+            self.context.session.save(path)?;
+        }
+        reason
+    }
+
+    /// Restores the session saved at `path`, if any.
+    fn restore_session(&mut self, path: PathBuf) {
+        let mut session = match DebugSession::load(&path) {
+            Ok(session) => session,
+            Err(err) => {
+                let _ = sh_warn!("failed to load debugger session {}: {err}", path.display());
+                return;
+            }
+        };
+
+        let dropped = session.remap(&self.context);
+        if dropped > 0 {
+            let _ = sh_warn!(
+                "{dropped} breakpoint(s), bookmark(s) or note(s) of the debugger session no longer match the code and were dropped"
+            );
+        }
+
+        // Breakpoints set with cheatcodes take precedence.
+        let breakpoints = session.resolved_breakpoints(&self.context).collect::<Vec<_>>();
+        for (key, breakpoint) in breakpoints {
+            self.context.breakpoints.entry(key).or_insert(breakpoint);
+        }
+        self.context.session = session;
     }
 
     /// Dumps debugger data to file.
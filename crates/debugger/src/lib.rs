@@ -16,6 +16,7 @@
 mod builder;
 mod debugger;
 mod dump;
+mod session;
 mod tui;
 
 mod node;
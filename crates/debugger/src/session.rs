@@ -0,0 +1,263 @@
+//! Debugger sessions persisted across runs.
+
+use crate::debugger::DebuggerContext;
+use alloy_primitives::Address;
+use eyre::Result;
+use foundry_common::fs;
+use foundry_evm_traces::debug::ContractSources;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+/// The breakpoints, bookmarks and notes of a debugger session.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct DebugSession {
+    /// Breakpoints set in the debugger, by key.
+    #[serde(default)]
+    pub(crate) breakpoints: BTreeMap<char, SessionLocation>,
+    /// Bookmarked locations.
+    #[serde(default)]
+    pub(crate) bookmarks: Vec<SessionLocation>,
+    /// Notes on locations.
+    #[serde(default)]
+    pub(crate) notes: Vec<SessionNote>,
+}
+
+/// A note on a location.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SessionNote {
+    pub(crate) location: SessionLocation,
+    pub(crate) text: String,
+}
+
+/// A program counter in the code of a contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SessionLocation {
+    /// The name of the contract.
+    pub(crate) contract: String,
+    /// Whether the program counter is in the creation code.
+    pub(crate) init_code: bool,
+    pub(crate) pc: usize,
+    /// The source code the program counter maps to, used to remap the location after the code
+    /// changed.
+    pub(crate) source: Option<SourceLocation>,
+}
+
+/// A location in a source file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SourceLocation {
+    pub(crate) path: PathBuf,
+    /// The 1-based line of the start of the source element.
+    pub(crate) line: usize,
+    /// The source code of the source element.
+    pub(crate) snippet: String,
+}
+
+impl SessionLocation {
+    /// Creates the location of `pc` in `contract`, recording the source code it maps to.
+    pub(crate) fn new(
+        sources: &ContractSources,
+        contract: &str,
+        init_code: bool,
+        pc: usize,
+    ) -> Self {
+        let source = source_location(sources, contract, init_code, pc);
+        Self { contract: contract.to_string(), init_code, pc, source }
+    }
+
+    /// Returns `true` if both locations are the same program counter of the same code.
+    pub(crate) fn same_pc(&self, contract: &str, init_code: bool, pc: usize) -> bool {
+        self.contract == contract && self.init_code == init_code && self.pc == pc
+    }
+}
+
+impl DebugSession {
+    /// Loads the session at `path`, or returns an empty session if there is none.
+    pub(crate) fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(fs::read_json_file(path)?)
+    }
+
+    /// Saves the session to `path`, removing the file if the session is empty.
+    pub(crate) fn save(&self, path: &Path) -> Result<()> {
+        if self.is_empty() {
+            if path.exists() {
+                fs::remove_file(path)?;
+            }
+            return Ok(());
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write_pretty_json_file(path, self)?;
+        Ok(())
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.breakpoints.is_empty() && self.bookmarks.is_empty() && self.notes.is_empty()
+    }
+
+    /// Remaps the locations of the session to the code executed in `context`, dropping the ones
+    /// that can not be found anymore.
+    ///
+    /// Returns the number of dropped locations.
+    pub(crate) fn remap(&mut self, context: &DebuggerContext) -> usize {
+        let mut executed = BTreeMap::<(&str, bool), Vec<(usize, Option<SourceLocation>)>>::new();
+        let mut seen = HashSet::new();
+        for node in &context.debug_arena {
+            let Some(contract) = context.identified_contracts.get(&node.address) else { continue };
+            let init_code = node.kind.is_any_create();
+            for step in &node.steps {
+                if seen.insert((contract.as_str(), init_code, step.pc)) {
+                    let source =
+                        source_location(&context.contracts_sources, contract, init_code, step.pc);
+                    executed.entry((contract, init_code)).or_default().push((step.pc, source));
+                }
+            }
+        }
+
+        let mut dropped = 0;
+        let mut remap = |location: &SessionLocation| {
+            let candidates = executed
+                .get(&(location.contract.as_str(), location.init_code))
+                .map_or(&[][..], Vec::as_slice);
+            let pc = remap_pc(location, candidates);
+            dropped += pc.is_none() as usize;
+            pc.map(|(pc, source)| SessionLocation { pc, source, ..location.clone() })
+        };
+
+        self.breakpoints = std::mem::take(&mut self.breakpoints)
+            .into_iter()
+            .filter_map(|(key, location)| Some((key, remap(&location)?)))
+            .collect();
+        self.bookmarks =
+            std::mem::take(&mut self.bookmarks).iter().filter_map(&mut remap).collect();
+        self.notes = std::mem::take(&mut self.notes)
+            .into_iter()
+            .filter_map(|note| {
+                Some(SessionNote { location: remap(&note.location)?, text: note.text })
+            })
+            .collect();
+        dropped
+    }
+
+    /// Returns the breakpoints of the session at the address of the first call executing them.
+    pub(crate) fn resolved_breakpoints(
+        &self,
+        context: &DebuggerContext,
+    ) -> impl Iterator<Item = (char, (Address, usize))> {
+        self.breakpoints.iter().filter_map(|(&key, location)| {
+            let node = context.debug_arena.iter().find(|node| {
+                node.kind.is_any_create() == location.init_code
+                    && context.identified_contracts.get(&node.address) == Some(&location.contract)
+                    && node.steps.iter().any(|step| step.pc == location.pc)
+            })?;
+            Some((key, (node.address, location.pc)))
+        })
+    }
+
+    /// Returns the note on the program counter, if any.
+    pub(crate) fn note(&self, contract: &str, init_code: bool, pc: usize) -> Option<&str> {
+        self.notes
+            .iter()
+            .find(|note| note.location.same_pc(contract, init_code, pc))
+            .map(|note| note.text.as_str())
+    }
+
+    /// Returns `true` if the program counter is bookmarked.
+    pub(crate) fn is_bookmarked(&self, contract: &str, init_code: bool, pc: usize) -> bool {
+        self.bookmarks.iter().any(|location| location.same_pc(contract, init_code, pc))
+    }
+}
+
+/// Remaps `location` to one of the `executed` program counters of its contract and their source
+/// locations.
+///
+/// The program counter is kept if it still maps to the same source code. Otherwise, it is moved to
+/// the program counter mapping to the same source code closest to its previous line.
+fn remap_pc(
+    location: &SessionLocation,
+    executed: &[(usize, Option<SourceLocation>)],
+) -> Option<(usize, Option<SourceLocation>)> {
+    let current = executed.iter().find(|(pc, _)| *pc == location.pc);
+    let Some(source) = &location.source else {
+        // Without source code to compare, the program counter can only be kept as is.
+        return current.cloned();
+    };
+    if let Some((_, Some(current_source))) = current
+        && current_source == source
+    {
+        return current.cloned();
+    }
+
+    executed
+        .iter()
+        .filter_map(|(pc, candidate)| {
+            let candidate = candidate.as_ref()?;
+            (candidate.path == source.path && candidate.snippet == source.snippet)
+                .then(|| (candidate.line.abs_diff(source.line), *pc))
+        })
+        .min()
+        .and_then(|(_, pc)| executed.iter().find(|(candidate, _)| *candidate == pc).cloned())
+}
+
+/// Returns the source location `pc` of `contract` maps to.
+fn source_location(
+    sources: &ContractSources,
+    contract: &str,
+    init_code: bool,
+    pc: usize,
+) -> Option<SourceLocation> {
+    let (element, source) = sources.find_source_mapping(contract, pc as u32, init_code)?;
+    let start = (element.offset() as usize).min(source.source.len());
+    let end = (start + element.length() as usize).min(source.source.len());
+    let snippet = source.source.get(start..end)?;
+    let line = source.source[..start].matches('\n').count() + 1;
+    Some(SourceLocation { path: source.path.clone(), line, snippet: snippet.to_string() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(line: usize, snippet: &str) -> Option<SourceLocation> {
+        Some(SourceLocation {
+            path: PathBuf::from("src/Counter.sol"),
+            line,
+            snippet: snippet.to_string(),
+        })
+    }
+
+    fn location(pc: usize, source: Option<SourceLocation>) -> SessionLocation {
+        SessionLocation { contract: "Counter".to_string(), init_code: false, pc, source }
+    }
+
+    #[test]
+    fn remaps_moved_code() {
+        let executed = [
+            (10, source(4, "number = 1")),
+            (20, source(8, "number++")),
+            (30, source(20, "number++")),
+        ];
+
+        // Unchanged.
+        let unchanged = location(20, source(8, "number++"));
+        assert_eq!(remap_pc(&unchanged, &executed), Some((20, source(8, "number++"))));
+
+        // Moved to the closest matching source code.
+        let moved = location(10, source(10, "number++"));
+        assert_eq!(remap_pc(&moved, &executed), Some((20, source(8, "number++"))));
+
+        // Removed.
+        let removed = location(10, source(4, "number = 2"));
+        assert_eq!(remap_pc(&removed, &executed), None);
+
+        // Without source code.
+        assert_eq!(remap_pc(&location(30, None), &executed), Some((30, source(20, "number++"))));
+        assert_eq!(remap_pc(&location(40, None), &executed), None);
+    }
+}
@@ -1,6 +1,10 @@
 //! Debugger context and event handler implementation.
 
-use crate::{DebugNode, ExitReason, debugger::DebuggerContext};
+use crate::{
+    DebugNode, ExitReason,
+    debugger::DebuggerContext,
+    session::{SessionLocation, SessionNote},
+};
 use alloy_primitives::{Address, hex};
 use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
 use foundry_evm_core::buffer::BufferKind;
@@ -33,6 +37,8 @@ pub(crate) struct TUIContext<'a> {
     pub(crate) show_shortcuts: bool,
     /// The currently active buffer (memory, calldata, returndata) to be drawn.
     pub(crate) active_buffer: BufferKind,
+    /// The note being edited on the current step, if any.
+    pub(crate) note_input: Option<String>,
 }
 
 impl<'a> TUIContext<'a> {
@@ -50,6 +56,7 @@ pub(crate) fn new(debugger_context: &'a mut DebuggerContext) -> Self {
             buf_utf: false,
             show_shortcuts: true,
             active_buffer: BufferKind::Memory,
+            note_input: None,
         }
     }
 
@@ -85,6 +92,32 @@ pub(crate) fn current_step(&self) -> &CallTraceStep {
         &self.debug_steps()[self.current_step]
     }
 
+    /// Returns the name of the contract of the given call, if identified.
+    pub(crate) fn contract_name(&self, node: &DebugNode) -> Option<&str> {
+        self.debugger_context.identified_contracts.get(&node.address).map(String::as_str)
+    }
+
+    /// Returns the session location of the current step, if its contract is identified.
+    fn current_location(&self) -> Option<SessionLocation> {
+        let contract = self.contract_name(self.debug_call())?;
+        Some(SessionLocation::new(
+            &self.debugger_context.contracts_sources,
+            contract,
+            self.call_kind().is_any_create(),
+            self.current_step().pc,
+        ))
+    }
+
+    /// Returns the note on the current step, if any.
+    pub(crate) fn current_note(&self) -> Option<&str> {
+        let contract = self.contract_name(self.debug_call())?;
+        self.debugger_context.session.note(
+            contract,
+            self.call_kind().is_any_create(),
+            self.current_step().pc,
+        )
+    }
+
     fn gen_opcode_list(&mut self) {
         self.opcode_list.clear();
         let debug_steps =
@@ -123,6 +156,12 @@ pub(crate) fn handle_event(&mut self, event: Event) -> ControlFlow<ExitReason> {
     }
 
     fn handle_key_event(&mut self, event: KeyEvent) -> ControlFlow<ExitReason> {
+        // Notes
+        if self.note_input.is_some() {
+            self.handle_note_input(event);
+            return ControlFlow::Continue(());
+        }
+
         // Breakpoints
         if let KeyCode::Char(c) = event.code
             && c.is_alphabetic()
@@ -132,6 +171,15 @@ fn handle_key_event(&mut self, event: KeyEvent) -> ControlFlow<ExitReason> {
             return ControlFlow::Continue(());
         }
 
+        // Set breakpoints
+        if let KeyCode::Char(c) = event.code
+            && c.is_alphabetic()
+            && self.key_buffer.starts_with('B')
+        {
+            self.set_breakpoint(c);
+            return ControlFlow::Continue(());
+        }
+
         let control = event.modifiers.contains(KeyModifiers::CONTROL);
 
         match event.code {
@@ -241,6 +289,28 @@ fn handle_key_event(&mut self, event: KeyEvent) -> ControlFlow<ExitReason> {
             // Toggle help notice
             KeyCode::Char('h') => self.show_shortcuts = !self.show_shortcuts,
 
+            // Toggle bookmark
+            KeyCode::Char('v') => self.toggle_bookmark(),
+
+            // Go to next bookmark
+            KeyCode::Char('n') => self.repeat(|this| this.goto_bookmark(true)),
+
+            // Go to previous bookmark
+            KeyCode::Char('N') => self.repeat(|this| this.goto_bookmark(false)),
+
+            // Edit note
+            KeyCode::Char('i') => {
+                self.note_input = Some(self.current_note().unwrap_or_default().to_string());
+            }
+
+            // Set breakpoint
+            KeyCode::Char('B') => {
+                self.key_buffer.clear();
+                // Early return to not clear the buffer.
+                self.key_buffer.push('B');
+                return ControlFlow::Continue(());
+            }
+
             // Numbers for repeating commands or breakpoints
             KeyCode::Char(
                 other @ ('0' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' | '\''),
@@ -275,6 +345,77 @@ fn handle_breakpoint(&mut self, c: char) {
         self.key_buffer.clear();
     }
 
+    fn set_breakpoint(&mut self, c: char) {
+        if let Some(location) = self.current_location() {
+            let address = *self.address();
+            self.debugger_context.breakpoints.insert(c, (address, location.pc));
+            self.debugger_context.session.breakpoints.insert(c, location);
+        }
+        self.key_buffer.clear();
+    }
+
+    fn toggle_bookmark(&mut self) {
+        let Some(location) = self.current_location() else { return };
+        let bookmarks = &mut self.debugger_context.session.bookmarks;
+        if let Some(i) = bookmarks.iter().position(|bookmark| {
+            bookmark.same_pc(&location.contract, location.init_code, location.pc)
+        }) {
+            bookmarks.remove(i);
+        } else {
+            bookmarks.push(location);
+        }
+    }
+
+    /// Moves to the next or previous step at a bookmarked program counter, in execution order.
+    fn goto_bookmark(&mut self, forward: bool) {
+        let current = (self.draw_memory.inner_call_index, self.current_step);
+        let target = {
+            let session = &self.debugger_context.session;
+            let mut bookmarked = self.debug_arena().iter().enumerate().flat_map(|(i, node)| {
+                let contract = self.contract_name(node);
+                let init_code = node.kind.is_any_create();
+                node.steps.iter().enumerate().filter_map(move |(j, step)| {
+                    contract
+                        .is_some_and(|contract| session.is_bookmarked(contract, init_code, step.pc))
+                        .then_some((i, j))
+                })
+            });
+            if forward {
+                bookmarked.find(|position| *position > current)
+            } else {
+                bookmarked.take_while(|position| *position < current).last()
+            }
+        };
+        if let Some((call_index, step)) = target {
+            self.draw_memory.inner_call_index = call_index;
+            self.current_step = step;
+        }
+    }
+
+    fn handle_note_input(&mut self, event: KeyEvent) {
+        let Some(input) = &mut self.note_input else { return };
+        match event.code {
+            KeyCode::Char(c) => input.push(c),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Esc => self.note_input = None,
+            KeyCode::Enter => {
+                let text = self.note_input.take().unwrap_or_default();
+                let Some(location) = self.current_location() else { return };
+                let notes = &mut self.debugger_context.session.notes;
+                notes.retain(|note| {
+                    !note.location.same_pc(&location.contract, location.init_code, location.pc)
+                });
+                let text = text.trim();
+                if !text.is_empty() {
+                    notes.push(SessionNote { location, text: text.to_string() });
+                }
+            }
+            _ => {}
+        }
+    }
+
     fn handle_mouse_event(&mut self, event: MouseEvent) -> ControlFlow<ExitReason> {
         match event.kind {
             MouseEventKind::ScrollUp => self.step_back(),
@@ -84,7 +84,7 @@ fn size_too_small(&self, f: &mut Frame<'_>, min_width: u16, min_height: u16) {
     /// ```
     fn vertical_layout(&self, f: &mut Frame<'_>) {
         let area = f.area();
-        let h_height = if self.show_shortcuts { 4 } else { 0 };
+        let h_height = if self.show_shortcuts { 6 } else { 0 };
 
         // NOTE: `Layout::split` always returns a slice of the same length as the number of
         // constraints, so the `else` branch is unreachable.
@@ -134,7 +134,7 @@ fn vertical_layout(&self, f: &mut Frame<'_>) {
     /// ```
     fn horizontal_layout(&self, f: &mut Frame<'_>) {
         let area = f.area();
-        let h_height = if self.show_shortcuts { 4 } else { 0 };
+        let h_height = if self.show_shortcuts { 6 } else { 0 };
 
         // Split off footer.
         let [app, footer] = Layout::new(
@@ -181,9 +181,13 @@ fn horizontal_layout(&self, f: &mut Frame<'_>) {
     fn draw_footer(&self, f: &mut Frame<'_>, area: Rect) {
         let l1 = "[q]: quit | [k/j]: prev/next op | [a/s]: prev/next jump | [c/C]: prev/next call | [g/G]: start/end | [b]: cycle memory/calldata/returndata buffers";
         let l2 = "[t]: stack labels | [m]: buffer decoding | [shift + j/k]: scroll stack | [ctrl + j/k]: scroll buffer | ['<char>]: goto breakpoint | [h] toggle help";
+        let l3 = "[B<char>]: set breakpoint | [v]: toggle bookmark | [n/N]: next/prev bookmark | [i]: edit note";
         let dimmed = Style::new().add_modifier(Modifier::DIM);
-        let lines =
-            vec![Line::from(Span::styled(l1, dimmed)), Line::from(Span::styled(l2, dimmed))];
+        let lines = vec![
+            Line::from(Span::styled(l1, dimmed)),
+            Line::from(Span::styled(l2, dimmed)),
+            Line::from(Span::styled(l3, dimmed)),
+        ];
         let paragraph =
             Paragraph::new(lines).alignment(Alignment::Center).wrap(Wrap { trim: false });
         f.render_widget(paragraph, area);
@@ -358,12 +362,22 @@ fn draw_op_list(&self, f: &mut Frame<'_>, area: Rect) {
         let max_pc = debug_steps.iter().map(|step| step.pc).max().unwrap_or(0);
         let max_pc_len = hex_digits(max_pc);
 
+        let session = &self.debugger_context.session;
+        let contract = self.contract_name(self.debug_call());
+        let init_code = self.call_kind().is_any_create();
+
         let items = debug_steps
             .iter()
             .enumerate()
             .map(|(i, step)| {
                 let mut content = String::with_capacity(64);
-                write!(content, "{:0>max_pc_len$x}|", step.pc).unwrap();
+                // Mark bookmarked and annotated program counters.
+                let mark = match contract {
+                    Some(c) if session.is_bookmarked(c, init_code, step.pc) => '*',
+                    Some(c) if session.note(c, init_code, step.pc).is_some() => '#',
+                    _ => ' ',
+                };
+                write!(content, "{mark}{:0>max_pc_len$x}|", step.pc).unwrap();
                 if let Some(op) = self.opcode_list.get(i) {
                     content.push_str(op);
                 }
@@ -371,13 +385,18 @@ fn draw_op_list(&self, f: &mut Frame<'_>, area: Rect) {
             })
             .collect::<Vec<_>>();
 
-        let title = format!(
+        let mut title = format!(
             "Address: {} | PC: {} | Gas used: {} | Gas refund: {}",
             self.address(),
             self.current_step().pc,
             self.debug_call().gas_limit - self.current_step().gas_remaining,
             self.current_step().gas_refund_counter
         );
+        if let Some(input) = &self.note_input {
+            write!(title, " | Note: {input}_").unwrap();
+        } else if let Some(note) = self.current_note() {
+            write!(title, " | Note: {note}").unwrap();
+        }
         let block = Block::default().title(title).borders(Borders::ALL);
         let list = List::new(items)
             .block(block)
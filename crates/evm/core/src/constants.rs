@@ -43,8 +43,7 @@
 pub const DEFAULT_CREATE2_DEPLOYER_DEPLOYER: Address =
     address!("0x3fAB184622Dc19b6109349B94811493BF2a45362");
 /// The default CREATE2 deployer.
-pub const DEFAULT_CREATE2_DEPLOYER: Address =
-    address!("0x4e59b44847b379578588920ca78fbf26c0b4956c");
+pub const DEFAULT_CREATE2_DEPLOYER: Address = foundry_common::addresses::CREATE2_DEPLOYER;
 /// The initcode of the default CREATE2 deployer.
 pub const DEFAULT_CREATE2_DEPLOYER_CODE: &[u8] = &hex!(
     "604580600e600039806000f350fe7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe03601600081602082378035828234f58015156039578182fd5b8082525050506014600cf3"
@@ -16,6 +16,7 @@
     context::{Block, BlockEnv, Cfg, CfgEnv, Transaction, TxEnv},
     context_interface::{
         ContextTr,
+        block::BlobExcessGasAndPrice,
         either::Either,
         transaction::{AccessList, RecoveredAuthorization, SignedAuthorization},
     },
@@ -56,6 +57,9 @@ fn set_blob_excess_gas_and_price(
         _base_fee_update_fraction: u64,
     );
 
+    /// Sets the blob gasprice, keeping the excess blob gas.
+    fn set_blob_gasprice(&mut self, _blob_gasprice: u128) {}
+
     // Tempo methods
 
     /// Returns the milliseconds portion of the block timestamp.
@@ -103,6 +107,12 @@ fn set_blob_excess_gas_and_price(
     ) {
         self.set_blob_excess_gas_and_price(excess_blob_gas, base_fee_update_fraction);
     }
+
+    fn set_blob_gasprice(&mut self, blob_gasprice: u128) {
+        let excess_blob_gas = self.blob_excess_gas().unwrap_or_default();
+        self.blob_excess_gas_and_price =
+            Some(BlobExcessGasAndPrice { excess_blob_gas, blob_gasprice });
+    }
 }
 
 impl FoundryBlock for TempoBlockEnv {
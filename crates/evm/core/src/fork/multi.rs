@@ -7,7 +7,13 @@
 use crate::FoundryBlock;
 use alloy_evm::EvmEnv;
 use alloy_network::{AnyNetwork, Network};
-use alloy_primitives::{U256, map::HashMap};
+use alloy_primitives::{
+    U256,
+    map::{AddressHashSet, HashMap},
+};
+use alloy_provider::Provider;
+use alloy_rpc_types::BlockId;
+use foundry_common::presets::Preset;
 use foundry_config::Config;
 use foundry_fork_db::{
     BackendHandler, BlockchainDb, ForkBlockEnv, SharedBackend, cache::BlockchainDbMeta,
@@ -18,9 +24,13 @@
     stream::Fuse,
     task::{Context, Poll},
 };
-use revm::primitives::hardfork::SpecId;
+use revm::{
+    primitives::hardfork::SpecId,
+    state::{AccountInfo, Bytecode},
+};
 use std::{
     fmt::{self, Write},
+    future::IntoFuture,
     pin::Pin,
     sync::{
         Arc,
@@ -609,9 +619,73 @@ async fn create_fork<
 
     let provider = fork.evm_opts.fork_provider_with_url::<N>(&fork.url)?;
     let db = BlockchainDb::new(meta, cache_path);
+    if !fork.evm_opts.fork_presets.is_empty() {
+        prewarm_presets(
+            &provider,
+            &db,
+            &fork.evm_opts.fork_presets,
+            evm_env.cfg_env.chain_id,
+            number,
+        )
+        .await;
+    }
     let (backend, handler) = SharedBackend::new(provider, db, Some(number.into()));
     let fork_id = ForkId::new(&fork.url, Some(number));
     let fork = CreatedFork::new(fork, evm_env, backend);
 
     Ok((fork_id, fork, handler))
 }
+
+/// Fetches the accounts of the contracts of the given presets into `db`, so that tests using them
+/// don't fetch them one by one.
+///
+/// Accounts already in `db`, e.g. loaded from the fork cache, are skipped.
+async fn prewarm_presets<N: Network, P: Provider<N>>(
+    provider: &P,
+    db: &BlockchainDb,
+    presets: &[Preset],
+    chain_id: u64,
+    number: u64,
+) {
+    let addresses = {
+        let accounts = db.accounts().read();
+        presets
+            .iter()
+            .flat_map(|preset| preset.contracts(chain_id))
+            .map(|(_, address)| address)
+            .filter(|address| !accounts.contains_key(address))
+            .collect::<AddressHashSet>()
+    };
+    if addresses.is_empty() {
+        return;
+    }
+
+    let block = BlockId::number(number);
+    let accounts = futures::future::join_all(addresses.into_iter().map(|address| async move {
+        let (balance, nonce, code) = futures::try_join!(
+            provider.get_balance(address).block_id(block).into_future(),
+            provider.get_transaction_count(address).block_id(block).into_future(),
+            provider.get_code_at(address).block_id(block).into_future(),
+        )?;
+        Ok::<_, eyre::Report>((address, balance, nonce, code))
+    }))
+    .await;
+
+    let mut cache = db.accounts().write();
+    for account in accounts {
+        match account {
+            Ok((address, balance, nonce, code)) => {
+                let code = Bytecode::new_raw(code);
+                let info = AccountInfo {
+                    balance,
+                    nonce,
+                    code_hash: code.hash_slow(),
+                    code: Some(code),
+                    ..Default::default()
+                };
+                cache.insert(address, info);
+            }
+            Err(err) => warn!(%err, "failed to prewarm a preset account"),
+        }
+    }
+}
@@ -11,7 +11,9 @@
 use alloy_provider::{Provider, RootProvider};
 use alloy_rpc_types::{BlockNumberOrTag, anvil::NodeInfo};
 use eyre::WrapErr;
-use foundry_common::{ALCHEMY_FREE_TIER_CUPS, NON_ARCHIVE_NODE_WARNING, provider::ProviderBuilder};
+use foundry_common::{
+    ALCHEMY_FREE_TIER_CUPS, NON_ARCHIVE_NODE_WARNING, presets::Preset, provider::ProviderBuilder,
+};
 use foundry_config::{Chain, Config, GasLimit};
 use foundry_evm_networks::NetworkConfigs;
 use revm::{context::CfgEnv, primitives::hardfork::SpecId};
@@ -52,6 +54,10 @@ pub struct EvmOpts {
     /// Disables storage caching entirely.
     pub no_storage_caching: bool,
 
+    /// Protocol presets whose contracts are fetched when creating a fork.
+    #[serde(default)]
+    pub fork_presets: Vec<Preset>,
+
     /// The initial balance of each deployed test contract.
     pub initial_balance: U256,
 
@@ -100,6 +106,7 @@ fn default() -> Self {
             compute_units_per_second: None,
             no_rpc_rate_limit: false,
             no_storage_caching: false,
+            fork_presets: Vec::new(),
             initial_balance: U256::default(),
             sender: Address::default(),
             ffi: false,
@@ -29,6 +29,7 @@
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use serde_json::json;
 use std::{
+    collections::{BTreeMap, BTreeSet},
     sync::{
         Arc, OnceLock,
         atomic::{AtomicU32, Ordering},
@@ -67,6 +68,8 @@ struct WorkerState<FEN: FoundryEvmNetwork> {
     logs: Vec<Log>,
     /// Deprecated cheatcodes seen by this worker
     deprecated_cheatcodes: HashMap<&'static str, Option<&'static str>>,
+    /// Cheatcodes invoked by each contract across the cases run by this worker
+    cheatcode_sites: BTreeMap<String, BTreeSet<&'static str>>,
     /// Number of runs this worker completed
     runs: u32,
     /// Failure reason if this worker failed
@@ -91,6 +94,7 @@ fn new(worker_id: usize) -> Self {
             coverage: None,
             logs: Vec::new(),
             deprecated_cheatcodes: HashMap::default(),
+            cheatcode_sites: BTreeMap::default(),
             runs: 0,
             failure: None,
             last_run_timestamp: 0,
@@ -181,8 +185,8 @@ pub struct FuzzedExecutor<FEN: FoundryEvmNetwork> {
     sender: Address,
     /// The fuzz configuration.
     config: FuzzConfig,
-    /// The persisted counterexample to be replayed, if any.
-    persisted_failure: Option<BaseCounterExample>,
+    /// The calldata of the persisted failures, replayed before any new input.
+    persisted_failures: Vec<Bytes>,
     /// The number of parallel workers.
     num_workers: usize,
 }
@@ -194,12 +198,12 @@ pub fn new(
         runner: TestRunner,
         sender: Address,
         config: FuzzConfig,
-        persisted_failure: Option<BaseCounterExample>,
+        persisted_failures: Vec<Bytes>,
     ) -> Self {
         let max_workers =
             if config.runs == 0 { 0 } else { Ord::max(1, config.runs / MIN_RUNS_PER_WORKER) };
         let num_workers = Ord::min(rayon::current_num_threads(), max_workers as usize);
-        Self { executor_f: executor, runner, sender, config, persisted_failure, num_workers }
+        Self { executor_f: executor, runner, sender, config, persisted_failures, num_workers }
     }
 
     /// Fuzzes the provided function, assuming it is available at the contract at `address`
@@ -274,9 +278,13 @@ fn single_fuzz(
             return Err(TestCaseError::reject(FuzzError::AssumeReject));
         }
 
-        let (breakpoints, deprecated_cheatcodes) =
+        let (breakpoints, deprecated_cheatcodes, cheatcode_sites) =
             call.cheatcodes.as_ref().map_or_else(Default::default, |cheats| {
-                (cheats.breakpoints.clone(), cheats.deprecated.clone())
+                (
+                    cheats.breakpoints.clone(),
+                    cheats.deprecated.clone(),
+                    cheats.cheatcode_sites.clone(),
+                )
             });
 
         // Consider call success if test should not fail on reverts and reverter is not the
@@ -299,6 +307,7 @@ fn single_fuzz(
                 breakpoints,
                 logs: call.logs,
                 deprecated_cheatcodes,
+                cheatcode_sites,
             }))
         } else {
             Ok(FuzzOutcome::CounterExample(CounterExampleOutcome {
@@ -393,6 +402,7 @@ fn aggregate_results(
             result.gas_report_traces.extend(worker.traces.into_iter().map(|t| t.arena));
             HitMaps::merge_opt(&mut result.line_coverage, worker.coverage);
             result.deprecated_cheatcodes.extend(worker.deprecated_cheatcodes);
+            merge_cheatcode_sites(&mut result.cheatcode_sites, worker.cheatcode_sites);
         }
 
         if let Some(reason) = &result.reason
@@ -470,7 +480,8 @@ fn run_worker(
             TestRunner::new(runner_config)
         };
 
-        let mut persisted_failure = self.persisted_failure.as_ref().filter(|_| worker_id == 0);
+        let mut persisted_failures =
+            if worker_id == 0 { self.persisted_failures.as_slice() } else { &[] }.iter();
 
         // Offset to stagger corpus syncs across workers; so that workers don't sync at the same
         // time.
@@ -482,12 +493,11 @@ fn run_worker(
         // 1. Global state allows (not timed out, not at global limit, no failure found)
         // 2. Worker hasn't reached its specific run limit
         'stop: while shared_state.should_continue() && worker.runs < worker_runs {
-            // If counterexample recorded, replay it first, without incrementing runs.
-            let input = if worker_id == 0
-                && let Some(failure) = persisted_failure.take()
-                && failure.calldata.get(..4).is_some_and(|selector| func.selector() == selector)
-            {
-                failure.calldata.clone()
+            // If failures recorded, replay them first, without incrementing runs.
+            let input = if let Some(calldata) = persisted_failures.find(|calldata| {
+                calldata.get(..4).is_some_and(|selector| func.selector() == selector)
+            }) {
+                calldata.clone()
             } else {
                 runs_since_sync += 1;
                 if runs_since_sync >= sync_threshold {
@@ -588,6 +598,7 @@ fn run_worker(
 
                         HitMaps::merge_opt(&mut worker.coverage, case.coverage);
                         worker.deprecated_cheatcodes = case.deprecated_cheatcodes;
+                        merge_cheatcode_sites(&mut worker.cheatcode_sites, case.cheatcode_sites);
                     }
                     FuzzOutcome::CounterExample(CounterExampleOutcome {
                         exit_reason: status,
@@ -665,3 +676,13 @@ const fn runs_per_worker(&self, worker_id: usize) -> u32 {
         if worker_id < remainder { runs + 1 } else { runs }
     }
 }
+
+/// Merges the cheatcode invocation sites of `other` into `sites`.
+pub(crate) fn merge_cheatcode_sites(
+    sites: &mut BTreeMap<String, BTreeSet<&'static str>>,
+    other: BTreeMap<String, BTreeSet<&'static str>>,
+) {
+    for (contract, cheatcodes) in other {
+        sites.entry(contract).or_default().extend(cheatcodes);
+    }
+}
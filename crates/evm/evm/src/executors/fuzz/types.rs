@@ -5,6 +5,7 @@
 use foundry_evm_fuzz::FuzzCase;
 use foundry_evm_traces::SparsedTraceArena;
 use revm::interpreter::InstructionResult;
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Returned by a single fuzz in the case of a successful run
 #[derive(Debug)]
@@ -21,6 +22,8 @@ pub struct CaseOutcome {
     pub logs: Vec<Log>,
     // Deprecated cheatcodes mapped to their replacements.
     pub deprecated_cheatcodes: HashMap<&'static str, Option<&'static str>>,
+    /// Cheatcodes invoked by each contract, if the cheatcode audit is enabled.
+    pub cheatcode_sites: BTreeMap<String, BTreeSet<&'static str>>,
 }
 
 /// Returned by a single fuzz when a counterexample has been discovered
@@ -1,7 +1,7 @@
 use crate::{
     executors::{
         DURATION_BETWEEN_METRICS_REPORT, EarlyExit, EvmError, Executor, FuzzTestTimer,
-        RawCallResult, corpus::WorkerCorpus,
+        RawCallResult, corpus::WorkerCorpus, fuzz::merge_cheatcode_sites,
     },
     inspectors::Fuzzer,
 };
@@ -39,7 +39,7 @@
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::{
-    collections::{HashMap as Map, HashSet, btree_map::Entry},
+    collections::{BTreeMap, BTreeSet, HashMap as Map, HashSet, btree_map::Entry},
     sync::Arc,
     time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
@@ -245,6 +245,9 @@ struct InvariantTestData<FEN: FoundryEvmNetwork> {
     // Only used when invariant function returns int256.
     optimization_best_value: Option<I256>,
     optimization_best_sequence: Vec<BasicTxDetails>,
+
+    // Cheatcodes invoked by the fuzzed calls, keyed by the calling contract.
+    cheatcode_sites: BTreeMap<String, BTreeSet<&'static str>>,
 }
 
 /// Contains invariant test data.
@@ -281,6 +284,7 @@ fn new(
             branch_runner,
             optimization_best_value: None,
             optimization_best_sequence: vec![],
+            cheatcode_sites: BTreeMap::default(),
         };
         Self { fuzz_state, targeted_contracts, test_data }
     }
@@ -315,6 +319,16 @@ fn merge_line_coverage(&mut self, new_coverage: Option<HitMaps>) {
         HitMaps::merge_opt(&mut self.test_data.line_coverage, new_coverage);
     }
 
+    /// Merge the cheatcode invocation sites recorded by the last fuzzed call.
+    fn merge_cheatcode_sites(&mut self, call_result: &RawCallResult<FEN>) {
+        if let Some(cheats) = call_result.cheatcodes.as_ref() {
+            merge_cheatcode_sites(
+                &mut self.test_data.cheatcode_sites,
+                cheats.cheatcode_sites.clone(),
+            );
+        }
+    }
+
     /// Update metrics for a fuzzed selector, extracted from tx details.
     /// Always increments number of calls; discarded runs (through assume cheatcodes) are tracked
     /// separated from reverts.
@@ -525,6 +539,8 @@ pub fn invariant_fuzz(
 
                 // Collect line coverage from last fuzzed call.
                 invariant_test.merge_line_coverage(call_result.line_coverage.clone());
+                // Collect cheatcode invocation sites from last fuzzed call.
+                invariant_test.merge_cheatcode_sites(&call_result);
                 // Collect edge coverage and set the flag in the current run.
                 if corpus_manager.merge_edge_coverage(&mut call_result) {
                     current_run.new_coverage = true;
@@ -775,6 +791,7 @@ pub fn invariant_fuzz(
             failed_corpus_replays: corpus_manager.failed_replays,
             optimization_best_value: result.optimization_best_value,
             optimization_best_sequence: result.optimization_best_sequence,
+            cheatcode_sites: result.cheatcode_sites,
         })
     }
 
@@ -22,7 +22,10 @@
 };
 use revm::interpreter::InstructionResult;
 use revm_inspectors::tracing::CallTraceArena;
-use std::{borrow::Cow, collections::HashMap};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, HashMap},
+};
 
 /// The outcome of an invariant fuzz test
 #[derive(Debug)]
@@ -48,6 +51,8 @@ pub struct InvariantFuzzTestResult {
     pub optimization_best_value: Option<I256>,
     /// For optimization mode: the call sequence that produced the best value.
     pub optimization_best_sequence: Vec<BasicTxDetails>,
+    /// Cheatcodes invoked by the fuzzed calls, keyed by the calling contract.
+    pub cheatcode_sites: BTreeMap<String, BTreeSet<&'static str>>,
 }
 
 /// Enriched results of an invariant run check.
@@ -7,7 +7,7 @@
 // the concrete `Executor` type.
 
 use crate::inspectors::{
-    Cheatcodes, InspectorData, InspectorStack, cheatcodes::BroadcastableTransactions,
+    Cheatcodes, HostProfiler, InspectorData, InspectorStack, cheatcodes::BroadcastableTransactions,
 };
 use alloy_dyn_abi::{DynSolValue, FunctionExt, JsonAbiExt};
 use alloy_json_abi::Function;
@@ -342,6 +342,12 @@ pub fn set_trace_printer(&mut self, trace_printer: bool) -> &mut Self {
         self
     }
 
+    #[inline]
+    pub fn set_host_profiler(&mut self, profiler: Option<HostProfiler>) -> &mut Self {
+        self.inspector_mut().set_host_profiler(profiler);
+        self
+    }
+
     #[inline]
     pub fn create2_deployer(&self) -> Address {
         self.inspector().create2_deployer
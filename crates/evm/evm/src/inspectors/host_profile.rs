@@ -0,0 +1,116 @@
+//! Host profiler inspector, tracking the peak resource usage of the EVM host.
+
+use revm::{
+    Inspector,
+    context::{ContextTr, JournalTr},
+    database::CacheDB,
+    interpreter::{
+        CallInputs, CallOutcome, CreateInputs, CreateOutcome, Interpreter,
+        interpreter_types::MemoryTr,
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+/// Peak resource usage of the EVM host during a test.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HostProfile {
+    /// The largest EVM memory of a call frame, in bytes.
+    pub peak_memory: usize,
+    /// The deepest call depth.
+    pub peak_depth: usize,
+    /// The most accounts held in the journaled state.
+    pub peak_journal_accounts: usize,
+    /// The most storage slots held in the journaled state.
+    pub peak_journal_slots: usize,
+    /// The accounts cached in the database when the test started.
+    pub db_accounts: usize,
+    /// The storage slots cached in the database when the test started.
+    pub db_slots: usize,
+    /// The contracts cached in the database when the test started.
+    pub db_contracts: usize,
+}
+
+impl HostProfile {
+    /// Adds the sizes of the caches of `db` to the profile.
+    pub fn record_db<DB>(&mut self, db: &CacheDB<DB>) {
+        self.db_accounts += db.cache.accounts.len();
+        self.db_slots +=
+            db.cache.accounts.values().map(|account| account.storage.len()).sum::<usize>();
+        self.db_contracts += db.cache.contracts.len();
+    }
+}
+
+#[derive(Debug, Default)]
+struct SharedHostProfile {
+    peak_memory: AtomicUsize,
+    peak_depth: AtomicUsize,
+    peak_journal_accounts: AtomicUsize,
+    peak_journal_slots: AtomicUsize,
+}
+
+/// An inspector which records the peak EVM memory, call depth and journal size.
+///
+/// Clones share the same profile, so that the profile of a test covers all of its runs, including
+/// the parallel fuzz workers.
+#[derive(Clone, Debug, Default)]
+pub struct HostProfiler {
+    shared: Arc<SharedHostProfile>,
+    /// The peak memory seen by this clone, to avoid touching the shared profile on every step.
+    peak_memory: usize,
+    /// The peak depth seen by this clone.
+    peak_depth: usize,
+}
+
+impl HostProfiler {
+    /// Returns the profile recorded so far, across all clones.
+    pub fn profile(&self) -> HostProfile {
+        HostProfile {
+            peak_memory: self.shared.peak_memory.load(Ordering::Relaxed),
+            peak_depth: self.shared.peak_depth.load(Ordering::Relaxed),
+            peak_journal_accounts: self.shared.peak_journal_accounts.load(Ordering::Relaxed),
+            peak_journal_slots: self.shared.peak_journal_slots.load(Ordering::Relaxed),
+            ..Default::default()
+        }
+    }
+
+    fn record_journal<CTX: ContextTr>(&self, context: &CTX) {
+        let state = context.journal().evm_state();
+        let slots = state.values().map(|account| account.storage.len()).sum();
+        self.shared.peak_journal_accounts.fetch_max(state.len(), Ordering::Relaxed);
+        self.shared.peak_journal_slots.fetch_max(slots, Ordering::Relaxed);
+    }
+}
+
+impl<CTX: ContextTr> Inspector<CTX> for HostProfiler {
+    #[inline]
+    fn step(&mut self, interp: &mut Interpreter, context: &mut CTX) {
+        let memory = interp.memory.size();
+        if memory > self.peak_memory {
+            self.peak_memory = memory;
+            self.shared.peak_memory.fetch_max(memory, Ordering::Relaxed);
+        }
+
+        let depth = context.journal().depth();
+        if depth > self.peak_depth {
+            self.peak_depth = depth;
+            self.shared.peak_depth.fetch_max(depth, Ordering::Relaxed);
+        }
+    }
+
+    fn call_end(&mut self, context: &mut CTX, _inputs: &CallInputs, _outcome: &mut CallOutcome) {
+        self.record_journal(context);
+    }
+
+    fn create_end(
+        &mut self,
+        context: &mut CTX,
+        _inputs: &CreateInputs,
+        _outcome: &mut CreateOutcome,
+    ) {
+        self.record_journal(context);
+    }
+}
@@ -13,6 +13,9 @@
 mod chisel_state;
 pub use chisel_state::ChiselState;
 
+mod host_profile;
+pub use host_profile::{HostProfile, HostProfiler};
+
 mod logs;
 pub use logs::LogCollector;
 
@@ -1,6 +1,7 @@
 use super::{
-    Cheatcodes, CheatsConfig, ChiselState, CustomPrintTracer, Fuzzer, LineCoverageCollector,
-    LogCollector, RevertDiagnostic, ScriptExecutionInspector, TempoLabels, TracingInspector,
+    Cheatcodes, CheatsConfig, ChiselState, CustomPrintTracer, Fuzzer, HostProfiler,
+    LineCoverageCollector, LogCollector, RevertDiagnostic, ScriptExecutionInspector, TempoLabels,
+    TracingInspector,
 };
 use alloy_primitives::{
     Address, B256, Bytes, Log, TxKind, U256,
@@ -74,6 +75,8 @@ pub struct InspectorStackBuilder<BLOCK: Clone> {
     pub line_coverage: Option<bool>,
     /// Whether to print all opcode traces into the console. Useful for debugging the EVM.
     pub print: Option<bool>,
+    /// The host profiler, recording the peak resource usage of the EVM.
+    pub host_profiler: Option<HostProfiler>,
     /// The chisel state inspector.
     pub chisel_state: Option<usize>,
     /// Whether to enable call isolation.
@@ -100,6 +103,7 @@ fn default() -> Self {
             logs: None,
             line_coverage: None,
             print: None,
+            host_profiler: None,
             chisel_state: None,
             enable_isolation: false,
             networks: NetworkConfigs::default(),
@@ -186,6 +190,13 @@ pub const fn print(mut self, yes: bool) -> Self {
         self
     }
 
+    /// Set the host profiler.
+    #[inline]
+    pub fn host_profiler(mut self, profiler: HostProfiler) -> Self {
+        self.host_profiler = Some(profiler);
+        self
+    }
+
     /// Set whether to enable the tracer.
     /// Revert diagnostic inspector is activated when `mode != TraceMode::None`
     #[inline]
@@ -231,6 +242,7 @@ pub fn build<FEN: FoundryEvmNetwork<EvmFactory: FoundryEvmFactory<BlockEnv = BLO
             logs,
             line_coverage,
             print,
+            host_profiler,
             chisel_state,
             enable_isolation,
             networks,
@@ -263,6 +275,7 @@ pub fn build<FEN: FoundryEvmNetwork<EvmFactory: FoundryEvmFactory<BlockEnv = BLO
         stack.collect_line_coverage(line_coverage.unwrap_or(false));
         stack.collect_logs(logs);
         stack.print(print.unwrap_or(false));
+        stack.set_host_profiler(host_profiler);
         stack.tracing(trace_mode);
 
         stack.enable_isolation(enable_isolation);
@@ -363,6 +376,7 @@ pub struct InspectorStackInner {
     pub chisel_state: Option<Box<ChiselState>>,
     pub edge_coverage: Option<Box<EdgeCovInspector>>,
     pub fuzzer: Option<Box<Fuzzer>>,
+    pub host_profiler: Option<Box<HostProfiler>>,
     pub line_coverage: Option<Box<LineCoverageCollector>>,
     pub log_collector: Option<Box<LogCollector>>,
     pub printer: Option<Box<CustomPrintTracer>>,
@@ -597,6 +611,12 @@ pub fn print(&mut self, yes: bool) {
         self.printer = yes.then(Default::default);
     }
 
+    /// Set the host profiler.
+    #[inline]
+    pub fn set_host_profiler(&mut self, profiler: Option<HostProfiler>) {
+        self.host_profiler = profiler.map(Into::into);
+    }
+
     /// Set whether to enable the tracer.
     /// Revert diagnostic inspector is activated when `mode != TraceMode::None`
     #[inline]
@@ -698,6 +718,7 @@ fn do_call_end(
             #[ret]
             [
                 &mut self.fuzzer,
+                &mut self.host_profiler,
                 &mut self.tracer,
                 &mut self.cheatcodes,
                 &mut self.printer,
@@ -731,7 +752,7 @@ fn do_create_end(
         let result = outcome.result.result;
         call_inspectors!(
             #[ret]
-            [&mut self.tracer, &mut self.cheatcodes, &mut self.printer],
+            [&mut self.host_profiler, &mut self.tracer, &mut self.cheatcodes, &mut self.printer],
             |inspector| {
                 let previous_outcome = outcome.clone();
                 inspector.create_end(ecx, call, outcome);
@@ -963,6 +984,7 @@ fn step_inlined(
                 // These are sorted in definition order.
                 &mut self.edge_coverage,
                 &mut self.fuzzer,
+                &mut self.host_profiler,
                 &mut self.line_coverage,
                 &mut self.printer,
                 &mut self.revert_diag,
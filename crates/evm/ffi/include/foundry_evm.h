@@ -0,0 +1,97 @@
+/*
+ * C ABI around the Foundry EVM executor.
+ *
+ * Functions returning an `int` return 0 on success and -1 on failure, in which case the error
+ * message of the calling thread is returned by `foundry_evm_last_error`.
+ *
+ * Addresses are passed as 20 bytes and 256-bit integers as 32 big-endian bytes.
+ *
+ * Panics are reported as errors as well when the library is built with unwinding, i.e. with
+ * `cargo build -p foundry-evm-ffi --profile ffi`.
+ */
+
+#ifndef FOUNDRY_EVM_H
+#define FOUNDRY_EVM_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* An in-memory EVM, with call tracing enabled. */
+typedef struct FoundryEvm FoundryEvm;
+
+/* The result of a call or a deployment. */
+typedef struct FoundryEvmResult FoundryEvmResult;
+
+/* Returns the last error that occurred on the calling thread, or NULL if there is none. */
+const char *foundry_evm_last_error(void);
+
+/* Creates a new in-memory EVM, returning NULL on failure. */
+FoundryEvm *foundry_evm_new(void);
+
+/* Frees an EVM. */
+void foundry_evm_free(FoundryEvm *evm);
+
+/* Sets the balance of an account. */
+int foundry_evm_set_balance(FoundryEvm *evm, const uint8_t address[20], const uint8_t balance[32]);
+
+/* Sets the nonce of an account. */
+int foundry_evm_set_nonce(FoundryEvm *evm, const uint8_t address[20], uint64_t nonce);
+
+/* Sets the code of an account. */
+int foundry_evm_set_code(FoundryEvm *evm, const uint8_t address[20], const uint8_t *code,
+                         size_t code_len);
+
+/* Sets a storage slot of an account. */
+int foundry_evm_set_storage(FoundryEvm *evm, const uint8_t address[20], const uint8_t slot[32],
+                            const uint8_t value[32]);
+
+/*
+ * Deploys a contract with the given init code and commits the new state.
+ *
+ * A reverted deployment is not a failure: its result is written to `out` and must be freed with
+ * `foundry_evm_result_free`.
+ */
+int foundry_evm_deploy(FoundryEvm *evm, const uint8_t from[20], const uint8_t *code,
+                       size_t code_len, const uint8_t value[32], FoundryEvmResult **out);
+
+/*
+ * Calls an account with the given calldata, committing the new state if `commit` is true.
+ *
+ * A reverted call is not a failure: its result is written to `out` and must be freed with
+ * `foundry_evm_result_free`.
+ */
+int foundry_evm_call(FoundryEvm *evm, const uint8_t from[20], const uint8_t to[20],
+                     const uint8_t *data, size_t data_len, const uint8_t value[32], bool commit,
+                     FoundryEvmResult **out);
+
+/* Returns whether the call or deployment succeeded, i.e. did not revert. */
+bool foundry_evm_result_success(const FoundryEvmResult *result);
+
+/* Returns the gas used by the call or deployment. */
+uint64_t foundry_evm_result_gas_used(const FoundryEvmResult *result);
+
+/* Returns the output of the call or deployment, owned by the result, writing its length to `len`. */
+const uint8_t *foundry_evm_result_output(const FoundryEvmResult *result, size_t *len);
+
+/*
+ * Writes the address of the deployed contract to `address`, returning false if the result is not
+ * the one of a successful deployment.
+ */
+bool foundry_evm_result_address(const FoundryEvmResult *result, uint8_t address[20]);
+
+/* Returns the call traces as JSON, owned by the result, or NULL if there are none. */
+const char *foundry_evm_result_traces(const FoundryEvmResult *result);
+
+/* Frees a result. */
+void foundry_evm_result_free(FoundryEvmResult *result);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* FOUNDRY_EVM_H */
@@ -0,0 +1,491 @@
+//! # foundry-evm-ffi
+//!
+//! C ABI around the Foundry EVM [`Executor`], so harnesses written in other languages can drive
+//! it without shelling out to the CLI. The C declarations are in `include/foundry_evm.h`.
+//!
+//! Functions returning an `int` return `0` on success and `-1` on failure, in which case the
+//! error message of the calling thread is returned by [`foundry_evm_last_error`].
+//!
+//! Addresses are passed as 20 bytes and 256-bit integers as 32 big-endian bytes.
+//!
+//! Panics are caught and reported as errors too, which requires unwinding: the library must be
+//! built with the `ffi` profile rather than `release`, which aborts on panics.
+//!
+//! ```sh
+//! cargo build -p foundry-evm-ffi --profile ffi
+//! ```
+
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+use alloy_primitives::{Address, B256, Bytes, U256};
+use foundry_evm::{
+    backend::Backend,
+    core::evm::{EthEvmNetwork, EvmEnvFor, TxEnvFor},
+    executors::{EvmError, Executor, ExecutorBuilder, RawCallResult},
+    revm::state::Bytecode,
+    traces::TraceMode,
+};
+use std::{
+    cell::RefCell,
+    ffi::{CString, c_char, c_int},
+    panic::{AssertUnwindSafe, catch_unwind},
+    ptr, slice,
+};
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// An in-memory EVM, with call tracing enabled.
+pub struct FoundryEvm {
+    executor: Executor<EthEvmNetwork>,
+}
+
+/// The result of a call or a deployment.
+pub struct FoundryEvmResult {
+    success: bool,
+    gas_used: u64,
+    output: Bytes,
+    address: Option<Address>,
+    traces: Option<CString>,
+}
+
+impl FoundryEvmResult {
+    fn new(raw: RawCallResult<EthEvmNetwork>, address: Option<Address>) -> eyre::Result<Self> {
+        let traces = raw.traces.as_ref().map(serde_json::to_string).transpose()?;
+        Ok(Self {
+            success: !raw.reverted,
+            gas_used: raw.gas_used,
+            output: raw.result,
+            address,
+            traces: traces.map(CString::new).transpose()?,
+        })
+    }
+}
+
+/// Runs `f`, recording its error or panic as the last error of the thread.
+///
+/// Panics can only be caught with `panic = "unwind"`, otherwise they abort the process.
+fn ffi_try<T>(f: impl FnOnce() -> eyre::Result<T>) -> Option<T> {
+    let err = match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(value)) => return Some(value),
+        Ok(Err(err)) => format!("{err:#}"),
+        Err(panic) => panic
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panicked".to_string()),
+    };
+    let err = CString::new(err.replace('\0', "")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(err));
+    None
+}
+
+/// Runs `f`, returning `0` on success and `-1` on failure.
+fn ffi_status(f: impl FnOnce() -> eyre::Result<()>) -> c_int {
+    if ffi_try(f).is_some() { 0 } else { -1 }
+}
+
+/// Writes the result of `f` to `out`.
+///
+/// # Safety
+///
+/// `out` must be valid for writes.
+unsafe fn ffi_result(
+    out: *mut *mut FoundryEvmResult,
+    f: impl FnOnce() -> eyre::Result<FoundryEvmResult>,
+) -> c_int {
+    ffi_status(|| {
+        eyre::ensure!(!out.is_null(), "null result pointer");
+        let result = Box::into_raw(Box::new(f()?));
+        // SAFETY: `out` is non-null and valid for writes per the contract of the caller.
+        unsafe { *out = result };
+        Ok(())
+    })
+}
+
+/// # Safety
+///
+/// `evm` must be null or a pointer returned by [`foundry_evm_new`] and not yet freed.
+unsafe fn evm_mut<'a>(evm: *mut FoundryEvm) -> eyre::Result<&'a mut FoundryEvm> {
+    // SAFETY: per the contract of the caller.
+    unsafe { evm.as_mut() }.ok_or_else(|| eyre::eyre!("null EVM pointer"))
+}
+
+/// # Safety
+///
+/// `address` must be null or valid for reads of 20 bytes.
+unsafe fn read_address(address: *const u8) -> eyre::Result<Address> {
+    eyre::ensure!(!address.is_null(), "null address pointer");
+    // SAFETY: per the contract of the caller.
+    Ok(Address::from_slice(unsafe { slice::from_raw_parts(address, 20) }))
+}
+
+/// # Safety
+///
+/// `word` must be null or valid for reads of 32 bytes.
+unsafe fn read_word(word: *const u8) -> eyre::Result<B256> {
+    eyre::ensure!(!word.is_null(), "null word pointer");
+    // SAFETY: per the contract of the caller.
+    Ok(B256::from_slice(unsafe { slice::from_raw_parts(word, 32) }))
+}
+
+/// # Safety
+///
+/// `data` must be valid for reads of `len` bytes, or `len` must be zero.
+unsafe fn read_bytes(data: *const u8, len: usize) -> eyre::Result<Bytes> {
+    if len == 0 {
+        return Ok(Bytes::new());
+    }
+    eyre::ensure!(!data.is_null(), "null data pointer");
+    // SAFETY: per the contract of the caller.
+    Ok(Bytes::copy_from_slice(unsafe { slice::from_raw_parts(data, len) }))
+}
+
+/// Returns the last error that occurred on the calling thread, or null if there is none.
+///
+/// The string is owned by the library and valid until the next failing call on the same thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn foundry_evm_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ref().map_or(ptr::null(), |err| err.as_ptr()))
+}
+
+/// Creates a new in-memory EVM, returning null on failure.
+///
+/// It must be freed with [`foundry_evm_free`].
+#[unsafe(no_mangle)]
+pub extern "C" fn foundry_evm_new() -> *mut FoundryEvm {
+    ffi_try(|| {
+        let backend = Backend::spawn(None)?;
+        let executor =
+            ExecutorBuilder::default().inspectors(|stack| stack.trace_mode(TraceMode::Call)).build(
+                EvmEnvFor::<EthEvmNetwork>::default(),
+                TxEnvFor::<EthEvmNetwork>::default(),
+                backend,
+            );
+        Ok(Box::into_raw(Box::new(FoundryEvm { executor })))
+    })
+    .unwrap_or(ptr::null_mut())
+}
+
+/// Frees an EVM.
+///
+/// # Safety
+///
+/// `evm` must be null or a pointer returned by [`foundry_evm_new`] and not yet freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn foundry_evm_free(evm: *mut FoundryEvm) {
+    if !evm.is_null() {
+        // SAFETY: per the contract of the caller.
+        drop(unsafe { Box::from_raw(evm) });
+    }
+}
+
+/// Sets the balance of an account.
+///
+/// # Safety
+///
+/// `evm` must be a live EVM, `address` valid for reads of 20 bytes and `balance` of 32 bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn foundry_evm_set_balance(
+    evm: *mut FoundryEvm,
+    address: *const u8,
+    balance: *const u8,
+) -> c_int {
+    ffi_status(|| unsafe {
+        let balance = read_word(balance)?.into();
+        evm_mut(evm)?.executor.set_balance(read_address(address)?, balance)?;
+        Ok(())
+    })
+}
+
+/// Sets the nonce of an account.
+///
+/// # Safety
+///
+/// `evm` must be a live EVM and `address` valid for reads of 20 bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn foundry_evm_set_nonce(
+    evm: *mut FoundryEvm,
+    address: *const u8,
+    nonce: u64,
+) -> c_int {
+    ffi_status(|| unsafe {
+        evm_mut(evm)?.executor.set_nonce(read_address(address)?, nonce)?;
+        Ok(())
+    })
+}
+
+/// Sets the code of an account.
+///
+/// # Safety
+///
+/// `evm` must be a live EVM, `address` valid for reads of 20 bytes and `code` of `code_len`
+/// bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn foundry_evm_set_code(
+    evm: *mut FoundryEvm,
+    address: *const u8,
+    code: *const u8,
+    code_len: usize,
+) -> c_int {
+    ffi_status(|| unsafe {
+        let code = Bytecode::new_raw_checked(read_bytes(code, code_len)?)?;
+        evm_mut(evm)?.executor.set_code(read_address(address)?, code)?;
+        Ok(())
+    })
+}
+
+/// Sets a storage slot of an account.
+///
+/// # Safety
+///
+/// `evm` must be a live EVM, `address` valid for reads of 20 bytes, `slot` and `value` of 32
+/// bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn foundry_evm_set_storage(
+    evm: *mut FoundryEvm,
+    address: *const u8,
+    slot: *const u8,
+    value: *const u8,
+) -> c_int {
+    ffi_status(|| unsafe {
+        let (slot, value) = (read_word(slot)?.into(), read_word(value)?.into());
+        evm_mut(evm)?.executor.set_storage_slot(read_address(address)?, slot, value)?;
+        Ok(())
+    })
+}
+
+/// Deploys a contract with the given init code and commits the new state.
+///
+/// A reverted deployment is not a failure: its result is written to `out` and must be freed with
+/// [`foundry_evm_result_free`].
+///
+/// # Safety
+///
+/// `evm` must be a live EVM, `from` valid for reads of 20 bytes, `code` of `code_len` bytes,
+/// `value` of 32 bytes and `out` valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn foundry_evm_deploy(
+    evm: *mut FoundryEvm,
+    from: *const u8,
+    code: *const u8,
+    code_len: usize,
+    value: *const u8,
+    out: *mut *mut FoundryEvmResult,
+) -> c_int {
+    unsafe {
+        ffi_result(out, || {
+            let (from, code) = (read_address(from)?, read_bytes(code, code_len)?);
+            let value = read_word(value)?.into();
+            match evm_mut(evm)?.executor.deploy(from, code, value, None) {
+                Ok(deploy) => FoundryEvmResult::new(deploy.raw, Some(deploy.address)),
+                Err(EvmError::Execution(err)) => FoundryEvmResult::new(err.raw, None),
+                Err(err) => Err(eyre::eyre!("{err}")),
+            }
+        })
+    }
+}
+
+/// Calls an account with the given calldata, committing the new state if `commit` is true.
+///
+/// A reverted call is not a failure: its result is written to `out` and must be freed with
+/// [`foundry_evm_result_free`].
+///
+/// # Safety
+///
+/// `evm` must be a live EVM, `from` and `to` valid for reads of 20 bytes, `data` of `data_len`
+/// bytes, `value` of 32 bytes and `out` valid for writes.
+#[unsafe(no_mangle)]
+#[expect(clippy::too_many_arguments)]
+pub unsafe extern "C" fn foundry_evm_call(
+    evm: *mut FoundryEvm,
+    from: *const u8,
+    to: *const u8,
+    data: *const u8,
+    data_len: usize,
+    value: *const u8,
+    commit: bool,
+    out: *mut *mut FoundryEvmResult,
+) -> c_int {
+    unsafe {
+        ffi_result(out, || {
+            let (from, to) = (read_address(from)?, read_address(to)?);
+            let (data, value): (_, U256) = (read_bytes(data, data_len)?, read_word(value)?.into());
+            let executor = &mut evm_mut(evm)?.executor;
+            let raw = if commit {
+                executor.transact_raw(from, to, data, value)?
+            } else {
+                executor.call_raw(from, to, data, value)?
+            };
+            FoundryEvmResult::new(raw, None)
+        })
+    }
+}
+
+/// Returns whether the call or deployment succeeded, i.e. did not revert.
+///
+/// # Safety
+///
+/// `result` must be a live result.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn foundry_evm_result_success(result: *const FoundryEvmResult) -> bool {
+    // SAFETY: per the contract of the caller.
+    unsafe { result.as_ref() }.is_some_and(|result| result.success)
+}
+
+/// Returns the gas used by the call or deployment.
+///
+/// # Safety
+///
+/// `result` must be a live result.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn foundry_evm_result_gas_used(result: *const FoundryEvmResult) -> u64 {
+    // SAFETY: per the contract of the caller.
+    unsafe { result.as_ref() }.map_or(0, |result| result.gas_used)
+}
+
+/// Returns the output of the call or deployment, writing its length to `len`.
+///
+/// The output is owned by the result and valid until it is freed.
+///
+/// # Safety
+///
+/// `result` must be a live result and `len` valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn foundry_evm_result_output(
+    result: *const FoundryEvmResult,
+    len: *mut usize,
+) -> *const u8 {
+    // SAFETY: per the contract of the caller.
+    let output = unsafe { result.as_ref() }.map_or(&[][..], |result| &result.output[..]);
+    if !len.is_null() {
+        // SAFETY: per the contract of the caller.
+        unsafe { *len = output.len() };
+    }
+    output.as_ptr()
+}
+
+/// Writes the address of the deployed contract to `address`, returning false if the result is
+/// not the one of a successful deployment.
+///
+/// # Safety
+///
+/// `result` must be a live result and `address` valid for writes of 20 bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn foundry_evm_result_address(
+    result: *const FoundryEvmResult,
+    address: *mut u8,
+) -> bool {
+    // SAFETY: per the contract of the caller.
+    let Some(deployed) = unsafe { result.as_ref() }.and_then(|result| result.address) else {
+        return false;
+    };
+    if address.is_null() {
+        return false;
+    }
+    // SAFETY: per the contract of the caller.
+    unsafe { ptr::copy_nonoverlapping(deployed.as_ptr(), address, 20) };
+    true
+}
+
+/// Returns the call traces of the call or deployment as JSON, or null if there are none.
+///
+/// The string is owned by the result and valid until it is freed.
+///
+/// # Safety
+///
+/// `result` must be a live result.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn foundry_evm_result_traces(
+    result: *const FoundryEvmResult,
+) -> *const c_char {
+    // SAFETY: per the contract of the caller.
+    unsafe { result.as_ref() }
+        .and_then(|result| result.traces.as_ref())
+        .map_or(ptr::null(), |traces| traces.as_ptr())
+}
+
+/// Frees a result.
+///
+/// # Safety
+///
+/// `result` must be null or a result returned by this library and not yet freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn foundry_evm_result_free(result: *mut FoundryEvmResult) {
+    if !result.is_null() {
+        // SAFETY: per the contract of the caller.
+        drop(unsafe { Box::from_raw(result) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, hex};
+    use std::ffi::CStr;
+
+    #[test]
+    fn deploy_and_call() {
+        // Returns 42 for any call.
+        let init_code = hex!("600a600c600039600a6000f3602a60005260206000f3");
+        let from = address!("0x1000000000000000000000000000000000000001");
+        let zero = [0u8; 32];
+        let evm = foundry_evm_new();
+        assert!(!evm.is_null());
+
+        unsafe {
+            let balance = U256::from(10).pow(U256::from(18)).to_be_bytes::<32>();
+            assert_eq!(foundry_evm_set_balance(evm, from.as_ptr(), balance.as_ptr()), 0);
+
+            let mut result = ptr::null_mut();
+            let status = foundry_evm_deploy(
+                evm,
+                from.as_ptr(),
+                init_code.as_ptr(),
+                init_code.len(),
+                zero.as_ptr(),
+                &mut result,
+            );
+            assert_eq!(status, 0);
+            assert!(foundry_evm_result_success(result));
+            let mut deployed = [0u8; 20];
+            assert!(foundry_evm_result_address(result, deployed.as_mut_ptr()));
+            foundry_evm_result_free(result);
+
+            let mut result = ptr::null_mut();
+            let status = foundry_evm_call(
+                evm,
+                from.as_ptr(),
+                deployed.as_ptr(),
+                ptr::null(),
+                0,
+                zero.as_ptr(),
+                false,
+                &mut result,
+            );
+            assert_eq!(status, 0);
+            assert!(foundry_evm_result_success(result));
+            assert!(foundry_evm_result_gas_used(result) > 0);
+            let mut len = 0;
+            let output = foundry_evm_result_output(result, &mut len);
+            assert_eq!(U256::from_be_slice(slice::from_raw_parts(output, len)), U256::from(42));
+            let traces = foundry_evm_result_traces(result);
+            assert!(!traces.is_null());
+            assert!(CStr::from_ptr(traces).to_str().unwrap().contains("arena"));
+            foundry_evm_result_free(result);
+
+            foundry_evm_free(evm);
+        }
+    }
+
+    #[test]
+    fn records_last_error() {
+        unsafe {
+            assert_eq!(foundry_evm_set_nonce(ptr::null_mut(), ptr::null(), 1), -1);
+            let err = CStr::from_ptr(foundry_evm_last_error());
+            assert_eq!(err.to_str().unwrap(), "null EVM pointer");
+        }
+    }
+}
@@ -19,7 +19,11 @@
 use foundry_evm_traces::{CallTraceArena, SparsedTraceArena};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
-use std::{fmt, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    sync::Arc,
+};
 
 pub use proptest::test_runner::{Config as FuzzConfig, Reason};
 
@@ -33,6 +37,9 @@
 mod inspector;
 pub use inspector::Fuzzer;
 
+mod regressions;
+pub use regressions::FuzzRegressions;
+
 /// Details of a transaction generated by fuzz strategy for fuzzing a target.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BasicTxDetails {
@@ -287,6 +294,9 @@ pub struct FuzzTestResult {
     // Deprecated cheatcodes mapped to their replacements.
     pub deprecated_cheatcodes: HashMap<&'static str, Option<&'static str>>,
 
+    /// Cheatcodes invoked by each contract, if the cheatcode audit is enabled.
+    pub cheatcode_sites: BTreeMap<String, BTreeSet<&'static str>>,
+
     /// Number of failed replays from persisted corpus.
     pub failed_corpus_replays: usize,
 }
@@ -0,0 +1,165 @@
+//! Regression files of the fuzz failures, following the model of the proptest regression files.
+//!
+//! Each test contract has a regression file, meant to be checked in to source control, listing the
+//! inputs which made its fuzz tests fail. These inputs are replayed before any new input in the
+//! next runs, so that a failure found once keeps being tested by everyone.
+//!
+//! Each failure is recorded on a line, with the name of the test, the seed of the run which found
+//! it and its ABI-encoded calldata:
+//!
+//! ```text
+//! cc testDeposit seed=0x1 calldata=0x6e553f65...01 # args=[1]
+//! ```
+
+use crate::BaseCounterExample;
+use alloy_primitives::{Bytes, U256};
+use std::{
+    fmt::Write,
+    io,
+    path::{Path, PathBuf},
+};
+
+/// The header of a new regression file.
+const HEADER: &str = "\
+# Fuzz inputs which made tests fail in the past. They are replayed by `forge test` before any new
+# input is generated.
+#
+# It is recommended to check this file in to source control so that everyone who runs the tests
+# benefits from these saved cases.
+";
+
+/// The regression file of a test contract.
+#[derive(Clone, Debug)]
+pub struct FuzzRegressions {
+    path: PathBuf,
+}
+
+impl FuzzRegressions {
+    /// Returns the regression file of the contract `contract_name`, in `dir`.
+    pub fn new(dir: &Path, contract_name: &str) -> Self {
+        let contract = contract_name.split(':').next_back().unwrap_or(contract_name);
+        Self { path: dir.join(format!("{contract}.txt")) }
+    }
+
+    /// Returns the path of the regression file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the calldata of the failures recorded for the test `test_name`, in recording order.
+    pub fn load(&self, test_name: &str) -> Vec<Bytes> {
+        std::fs::read_to_string(&self.path)
+            .map(|contents| parse(&contents, test_name))
+            .unwrap_or_default()
+    }
+
+    /// Records the failure of the test `test_name`, found by the run with the given seed.
+    ///
+    /// Does nothing if the failure is already recorded.
+    pub fn record(
+        &self,
+        test_name: &str,
+        seed: Option<U256>,
+        counterexample: &BaseCounterExample,
+    ) -> io::Result<()> {
+        if self.load(test_name).contains(&counterexample.calldata) {
+            return Ok(());
+        }
+        let mut contents = match std::fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(_) => {
+                if let Some(parent) = self.path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                HEADER.to_string()
+            }
+        };
+        contents.push_str(&format_entry(test_name, seed, counterexample));
+        std::fs::write(&self.path, contents)
+    }
+}
+
+/// Returns the calldata of the failures of the test `test_name` in a regression file.
+fn parse(contents: &str, test_name: &str) -> Vec<Bytes> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.strip_prefix("cc ")?.split_whitespace();
+            if fields.next()? != test_name {
+                return None;
+            }
+            fields.find_map(|field| field.strip_prefix("calldata=")?.parse().ok())
+        })
+        .collect()
+}
+
+/// Formats the line recording a failure.
+fn format_entry(
+    test_name: &str,
+    seed: Option<U256>,
+    counterexample: &BaseCounterExample,
+) -> String {
+    let mut entry = format!("cc {test_name} seed=");
+    match seed {
+        Some(seed) => write!(entry, "{seed:#x}").unwrap(),
+        None => entry.push_str("none"),
+    }
+    write!(entry, " calldata={}", counterexample.calldata).unwrap();
+    if let Some(args) = &counterexample.args {
+        write!(entry, " # args=[{}]", args.replace('\n', " ")).unwrap();
+    }
+    entry.push('\n');
+    entry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counterexample(calldata: &str, args: Option<&str>) -> BaseCounterExample {
+        BaseCounterExample {
+            warp: None,
+            roll: None,
+            sender: None,
+            addr: None,
+            calldata: calldata.parse().unwrap(),
+            contract_name: None,
+            func_name: None,
+            signature: None,
+            args: args.map(str::to_string),
+            raw_args: None,
+            traces: None,
+            show_solidity: false,
+        }
+    }
+
+    #[test]
+    fn parses_recorded_failures() {
+        let mut contents = HEADER.to_string();
+        contents.push_str(&format_entry(
+            "testDeposit",
+            Some(U256::from(1)),
+            &counterexample("0x6e553f6501", Some("1")),
+        ));
+        contents.push_str(&format_entry("testWithdraw", None, &counterexample("0x2e1a7d4d", None)));
+        contents.push_str(&format_entry("testDeposit", None, &counterexample("0x6e553f65", None)));
+
+        assert!(contents.contains("cc testDeposit seed=0x1 calldata=0x6e553f6501 # args=[1]\n"));
+        assert!(contents.contains("cc testWithdraw seed=none calldata=0x2e1a7d4d\n"));
+
+        let deposits = parse(&contents, "testDeposit");
+        assert_eq!(deposits.len(), 2);
+        assert_eq!(deposits[1], Bytes::from_static(&[0x6e, 0x55, 0x3f, 0x65]));
+        assert_eq!(parse(&contents, "testWithdraw").len(), 1);
+        assert!(parse(&contents, "testMint").is_empty());
+    }
+
+    #[test]
+    fn names_file_after_contract() {
+        let regressions = FuzzRegressions::new(
+            Path::new("testdata/fuzz-regressions"),
+            "test/Vault.t.sol:VaultTest",
+        );
+        assert_eq!(regressions.path(), Path::new("testdata/fuzz-regressions/VaultTest.txt"));
+    }
+}
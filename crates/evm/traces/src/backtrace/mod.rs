@@ -1,11 +1,13 @@
 //! Solidity stack trace support for test failures.
 
-use crate::{CallTrace, SparsedTraceArena};
+use crate::{CallTrace, CallTraceArena, SparsedTraceArena};
 use alloy_primitives::{Address, Bytes, map::HashMap};
+use alloy_sol_types::{Panic, Revert, SolError};
 use foundry_compilers::{
     Artifact, ArtifactId, ProjectCompileOutput,
     artifacts::{ConfigurableContractArtifact, Libraries, sourcemap::SourceMap},
 };
+use foundry_evm_core::decode::RevertDecoder;
 use std::{fmt, path::PathBuf};
 use yansi::Paint;
 
@@ -96,15 +98,52 @@ pub fn from_traces(&mut self, arena: &SparsedTraceArena) -> Backtrace<'_> {
             }
         }
 
+        let revert_origin = self.revert_origin(arena, &artifacts_by_address);
+
         Backtrace::new(
             artifacts_by_address,
             &self.build_sources_cache,
             self.linked_libraries.clone(),
             self.disable_source_locs,
             arena,
+            revert_origin,
         )
     }
 
+    /// Finds the frame that raised a custom error re-thrown by at least two frames above it, and
+    /// decodes the error against that contract's ABI.
+    ///
+    /// Returns the node indices of the re-throw chain, innermost first, and the decoded error.
+    fn revert_origin(
+        &self,
+        arena: &SparsedTraceArena,
+        artifacts_by_address: &HashMap<Address, (ArtifactId, SourceData)>,
+    ) -> Option<(Vec<usize>, String)> {
+        let chain = bubbled_revert_chain(arena);
+        if chain.len() < 3 {
+            return None;
+        }
+
+        let trace = &arena.nodes()[chain[0]].trace;
+        let selector = trace.output.get(..4)?;
+        if selector == Revert::SELECTOR || selector == Panic::SELECTOR {
+            return None;
+        }
+
+        let abi = artifacts_by_address.get(&trace.address).and_then(|(artifact_id, _)| {
+            self.output
+                .artifact_ids()
+                .find(|(id, _)| id.source == artifact_id.source && id.name == artifact_id.name)
+                .and_then(|(_, artifact)| artifact.abi.clone())
+        });
+        let error = abi
+            .and_then(|abi| RevertDecoder::new().with_abi(&abi).maybe_decode(&trace.output, None))
+            .or_else(|| trace.decoded.as_ref().and_then(|decoded| decoded.return_data.clone()))
+            .unwrap_or_else(|| RevertDecoder::new().decode(&trace.output, None));
+
+        Some((chain, error))
+    }
+
     /// Resolves contract addresses to [`ArtifactId`] and their [`SourceData`] from trace labels and
     /// linked libraries.
     fn resolve_addresses(
@@ -186,6 +225,9 @@ fn resolve_addresses(
 pub struct Backtrace<'a> {
     /// The frames of the backtrace, from innermost (where the revert happened) to outermost.
     frames: Vec<BacktraceFrame>,
+    /// The decoded error and the frame that raised it, followed by the frames that re-threw it,
+    /// if a custom error was bubbled through multiple frames.
+    revert_origin: Option<(String, Vec<BacktraceFrame>)>,
     /// Map from address to PcSourceMapper
     pc_mappers: HashMap<Address, PcSourceMapper<'a>>,
     /// Linked libraries from configuration
@@ -204,6 +246,7 @@ fn new(
         linked_libraries: Vec<LinkedLib>,
         disable_source_locs: bool,
         arena: &SparsedTraceArena,
+        revert_origin: Option<(Vec<usize>, String)>,
     ) -> Self {
         let mut pc_mappers = HashMap::default();
 
@@ -217,11 +260,24 @@ fn new(
             }
         }
 
-        let mut backtrace =
-            Self { frames: Vec::new(), pc_mappers, linked_libraries, disable_source_locs };
+        let mut backtrace = Self {
+            frames: Vec::new(),
+            revert_origin: None,
+            pc_mappers,
+            linked_libraries,
+            disable_source_locs,
+        };
 
         backtrace.extract_frames(arena);
 
+        if let Some((chain, error)) = revert_origin {
+            let frames = chain
+                .into_iter()
+                .filter_map(|idx| backtrace.create_frame(&arena.nodes()[idx].trace))
+                .collect();
+            backtrace.revert_origin = Some((error, frames));
+        }
+
         backtrace
     }
 
@@ -321,10 +377,45 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             writeln!(f, "{frame}")?;
         }
 
+        if let Some((error, frames)) = &self.revert_origin
+            && let Some((origin, rethrows)) = frames.split_first()
+        {
+            writeln!(f, "{}", Paint::yellow("Revert origin:"))?;
+            writeln!(f, "  {error} raised at {origin}")?;
+            for frame in rethrows {
+                writeln!(f, "  re-thrown by {frame}")?;
+            }
+        }
+
         Ok(())
     }
 }
 
+/// Follows a failed call from the root frame down to the frame that raised the revert, as long
+/// as every frame re-throws its failed child's revert data unchanged.
+///
+/// Returns the node indices of the chain, innermost first.
+fn bubbled_revert_chain(arena: &CallTraceArena) -> Vec<usize> {
+    let nodes = arena.nodes();
+    if nodes.first().is_none_or(|root| root.trace.success) {
+        return Vec::new();
+    }
+
+    let mut chain = vec![0];
+    let mut idx = 0;
+    // The last failed child with the same revert data is the one that was re-thrown, earlier ones
+    // may have been caught.
+    while let Some(&child) = nodes[idx].children.iter().rev().find(|&&child| {
+        let trace = &nodes[child].trace;
+        !trace.success && trace.output == nodes[idx].trace.output
+    }) {
+        chain.push(child);
+        idx = child;
+    }
+    chain.reverse();
+    chain
+}
+
 /// A single frame in a backtrace.
 #[derive(Debug, Clone)]
 struct BacktraceFrame {
@@ -0,0 +1,157 @@
+//! Per-frame gas accounting for call traces.
+
+use alloy_primitives::U256;
+use foundry_evm_core::buffer::get_buffer_accesses;
+use revm_inspectors::tracing::{
+    CallTraceArena,
+    types::{CallTraceNode, CallTraceStep, TraceMemberOrder},
+};
+use std::fmt::Write;
+
+/// Gas accounting of a single call frame.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FrameGas {
+    /// The index of the frame's node in the arena.
+    pub idx: usize,
+    /// The call depth of the frame.
+    pub depth: usize,
+    /// The total gas used by the frame, including its children.
+    pub gas_used: u64,
+    /// The cumulative gas used by the frame's direct children.
+    pub child_gas: u64,
+    /// The gas refund counter at the end of the frame.
+    pub refund: u64,
+    /// The memory expansion cost paid by the frame, if steps were recorded.
+    pub memory_expansion: Option<u64>,
+}
+
+impl FrameGas {
+    /// Returns the gas used by the frame itself, excluding its children.
+    pub fn self_gas(&self) -> u64 {
+        self.gas_used.saturating_sub(self.child_gas)
+    }
+}
+
+/// Returns the gas accounting of every call frame reachable in the arena, in call order.
+///
+/// Frames removed from the ordering, e.g. by [`crate::prune_trace_depth`], are skipped.
+pub fn frame_gas(arena: &CallTraceArena) -> Vec<FrameGas> {
+    let mut frames = Vec::new();
+    if !arena.nodes().is_empty() {
+        collect_frames(arena.nodes(), 0, &mut frames);
+    }
+    frames
+}
+
+fn collect_frames(nodes: &[CallTraceNode], idx: usize, frames: &mut Vec<FrameGas>) {
+    let node = &nodes[idx];
+    let memory_size = node.trace.steps.iter().map(post_step_memory_size).max();
+    frames.push(FrameGas {
+        idx,
+        depth: node.trace.depth,
+        gas_used: node.trace.gas_used,
+        child_gas: node.children.iter().map(|child| nodes[*child].trace.gas_used).sum(),
+        refund: node.trace.gas_refund_counter,
+        memory_expansion: memory_size.map(|size| memory_expansion_cost(size as u64)),
+    });
+    for order in &node.ordering {
+        if let TraceMemberOrder::Call(child_idx) = order {
+            collect_frames(nodes, node.children[*child_idx], frames);
+        }
+    }
+}
+
+/// Returns the total cost of expanding memory from zero to `size` bytes.
+pub fn memory_expansion_cost(size: u64) -> u64 {
+    let words = size.div_ceil(32);
+    3 * words + words * words / 512
+}
+
+/// Returns the memory size after executing the step.
+///
+/// Steps record the memory size before their execution, so the size is extended by the memory
+/// region the opcode accesses, if its stack was recorded.
+fn post_step_memory_size(step: &CallTraceStep) -> usize {
+    memory_size_after(step.op.get(), step.stack.as_deref(), step.memory_size)
+}
+
+fn memory_size_after(op: u8, stack: Option<&[U256]>, memory_size: usize) -> usize {
+    let accessed = stack
+        .and_then(|stack| get_buffer_accesses(op, stack))
+        .into_iter()
+        .flat_map(|accesses| [accesses.read.map(|(_, access)| access), accesses.write])
+        .flatten()
+        .filter(|access| access.len > 0)
+        .map(|access| access.offset.saturating_add(access.len).next_multiple_of(32))
+        .max()
+        .unwrap_or_default();
+    memory_size.max(accessed)
+}
+
+/// Annotates the return of every call frame in the arena with its gas breakdown.
+///
+/// The breakdown is appended to the decoded return data, so the arena should be decoded first.
+pub fn attach_gas_breakdown(arena: &mut CallTraceArena) {
+    for frame in frame_gas(arena) {
+        let trace = &mut arena.nodes_mut()[frame.idx].trace;
+        let output = if trace.kind.is_any_create() && trace.success {
+            format!("{} bytes of code", trace.output.len())
+        } else if !trace.output.is_empty() {
+            trace.output.to_string()
+        } else {
+            String::new()
+        };
+        let decoded = trace.decoded.get_or_insert_with(Default::default);
+        let mut s = decoded.return_data.take().unwrap_or(output);
+        if !s.is_empty() {
+            s.push(' ');
+        }
+        let _ = write!(s, "(self: {}, children: {}", frame.self_gas(), frame.child_gas);
+        if frame.refund > 0 {
+            let _ = write!(s, ", refunded: {}", frame.refund);
+        }
+        if let Some(memory_expansion) = frame.memory_expansion {
+            let _ = write!(s, ", memory expansion: {memory_expansion}");
+        }
+        s.push(')');
+        decoded.return_data = Some(s);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_expansion_costs() {
+        assert_eq!(memory_expansion_cost(0), 0);
+        assert_eq!(memory_expansion_cost(1), 3);
+        assert_eq!(memory_expansion_cost(32), 3);
+        assert_eq!(memory_expansion_cost(0x80), 12);
+        // 1024 words: 3 * 1024 + 1024^2 / 512
+        assert_eq!(memory_expansion_cost(32 * 1024), 3072 + 2048);
+    }
+
+    #[test]
+    fn memory_size_includes_accessed_region() {
+        use revm::bytecode::opcode;
+
+        // MSTORE(offset = 0x80, value): memory grows from 0x80 to 0xa0 bytes.
+        let stack = [U256::from(1), U256::from(0x80)];
+        assert_eq!(memory_size_after(opcode::MSTORE, Some(&stack), 0x80), 0xa0);
+        // RETURN(offset = 0x20, size = 0x21): rounded up to whole words.
+        let stack = [U256::from(0x21), U256::from(0x20)];
+        assert_eq!(memory_size_after(opcode::RETURN, Some(&stack), 0), 0x60);
+        // Zero-sized accesses don't expand memory.
+        let stack = [U256::ZERO, U256::from(0x1000)];
+        assert_eq!(memory_size_after(opcode::RETURN, Some(&stack), 0x40), 0x40);
+        // Without a recorded stack, the recorded size is used.
+        assert_eq!(memory_size_after(opcode::MSTORE, None, 0x40), 0x40);
+    }
+
+    #[test]
+    fn self_gas_excludes_children() {
+        let frame = FrameGas { gas_used: 100, child_gas: 60, ..Default::default() };
+        assert_eq!(frame.self_gas(), 40);
+    }
+}
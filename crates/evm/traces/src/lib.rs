@@ -52,6 +52,8 @@
 
 pub mod folded_stack_trace;
 
+pub mod gas;
+
 pub mod backtrace;
 
 pub type Traces = Vec<(TraceKind, SparsedTraceArena)>;
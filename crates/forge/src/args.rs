@@ -1,12 +1,15 @@
 use crate::{
-    cmd::{cache::CacheSubcommands, generate::GenerateSubcommands, watch},
+    cmd::{
+        broadcast::BroadcastSubcommands, cache::CacheSubcommands, generate::GenerateSubcommands,
+        watch,
+    },
     opts::{Forge, ForgeSubcommand},
 };
 use clap::{CommandFactory, Parser};
 use clap_complete::generate;
 use eyre::Result;
 use foundry_cli::utils;
-use foundry_common::{sh_warn, shell};
+use foundry_common::shell;
 use foundry_evm::inspectors::cheatcodes::{ForgeContext, set_execution_context};
 
 /// Run the `forge` command line interface.
@@ -33,7 +36,7 @@ pub fn setup() -> Result<()> {
 pub fn run_command(args: Forge) -> Result<()> {
     // Set the execution context based on the subcommand.
     let context = match &args.cmd {
-        ForgeSubcommand::Test(_) => ForgeContext::Test,
+        ForgeSubcommand::Test(_) | ForgeSubcommand::Daemon(_) => ForgeContext::Test,
         ForgeSubcommand::Coverage(_) => ForgeContext::Coverage,
         ForgeSubcommand::Snapshot(_) => ForgeContext::Snapshot,
         ForgeSubcommand::Script(cmd) => {
@@ -86,6 +89,11 @@ pub fn run_command(args: Forge) -> Result<()> {
             CacheSubcommands::Clean(cmd) => cmd.run(),
             CacheSubcommands::Ls(cmd) => cmd.run(),
         },
+        ForgeSubcommand::Broadcast(cmd) => match cmd.sub {
+            BroadcastSubcommands::Ls(cmd) => cmd.run(),
+            BroadcastSubcommands::Prune(cmd) => cmd.run(),
+            BroadcastSubcommands::Show(cmd) => cmd.run(),
+        },
         ForgeSubcommand::Create(cmd) => global.block_on(cmd.run()),
         ForgeSubcommand::Update(cmd) => cmd.run(),
         ForgeSubcommand::Install(cmd) => global.block_on(cmd.run()),
@@ -96,14 +104,7 @@ pub fn run_command(args: Forge) -> Result<()> {
             generate(shell, &mut Forge::command(), "forge", &mut std::io::stdout());
             Ok(())
         }
-        ForgeSubcommand::Clean { root } => {
-            let config = utils::load_config_with_root(root.as_deref())?;
-            let project = config.project()?;
-            for warning in config.cleanup(&project)? {
-                let _ = sh_warn!("{warning}");
-            }
-            Ok(())
-        }
+        ForgeSubcommand::Clean(cmd) => cmd.run(),
         ForgeSubcommand::Snapshot(cmd) => {
             if cmd.is_watch() {
                 global.block_on(watch::watch_gas_snapshot(cmd))
@@ -139,6 +140,10 @@ pub fn run_command(args: Forge) -> Result<()> {
         ForgeSubcommand::Soldeer(cmd) => global.block_on(cmd.run()),
         ForgeSubcommand::Eip712(cmd) => cmd.run(),
         ForgeSubcommand::BindJson(cmd) => cmd.run(),
+        ForgeSubcommand::BindEvents(cmd) => cmd.run(),
+        ForgeSubcommand::RunEst(cmd) => cmd.run(),
         ForgeSubcommand::Lint(cmd) => cmd.run(),
+        ForgeSubcommand::Lsp(cmd) => cmd.run(),
+        ForgeSubcommand::Daemon(cmd) => cmd.run(global.tokio_runtime()),
     }
 }
@@ -0,0 +1,261 @@
+use alloy_json_abi::Event;
+use alloy_primitives::hex;
+use clap::{Parser, ValueEnum, ValueHint};
+use eyre::Result;
+use foundry_cli::{
+    opts::BuildOpts,
+    utils::{FoundryPathExt, LoadConfig},
+};
+use foundry_common::{compile::ProjectCompiler, fs};
+use foundry_config::impl_figment_convert;
+use regex::Regex;
+use std::{collections::BTreeMap, fmt::Write, path::PathBuf};
+
+impl_figment_convert!(BindEventsArgs, build);
+
+const GENERATED_NOTICE: &str = "This file was generated by `forge bind-events`, do not edit it.";
+
+/// CLI arguments for `forge bind-events`.
+#[derive(Clone, Debug, Parser)]
+pub struct BindEventsArgs {
+    /// The directory to write the decoders to.
+    ///
+    /// Defaults to `<out>/events`.
+    #[arg(long, short, value_hint = ValueHint::DirPath, value_name = "PATH")]
+    pub out: Option<PathBuf>,
+
+    /// The languages to generate the decoders for.
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_values_t = [EventsLang::Rust, EventsLang::Typescript]
+    )]
+    pub lang: Vec<EventsLang>,
+
+    /// Generate decoders only for the events of the contracts whose names match the specified
+    /// filter(s).
+    #[arg(long)]
+    pub select: Vec<Regex>,
+
+    /// Check that the existing decoders are up to date instead of writing them.
+    #[arg(long)]
+    pub check: bool,
+
+    #[command(flatten)]
+    build: BuildOpts,
+}
+
+/// A language to generate event decoders for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum EventsLang {
+    /// A module of `sol!` interfaces, for indexers using alloy.
+    Rust,
+    /// A module of `as const` ABIs and a decoding function, for indexers using viem.
+    Typescript,
+}
+
+impl EventsLang {
+    const fn file_name(self) -> &'static str {
+        match self {
+            Self::Rust => "events.rs",
+            Self::Typescript => "events.ts",
+        }
+    }
+}
+
+impl BindEventsArgs {
+    pub fn run(self) -> Result<()> {
+        let config = self.load_config()?;
+        let project = config.project()?;
+        let outcome = ProjectCompiler::new().quiet(true).compile(&project)?;
+
+        // The events of the project contracts, excluding tests and scripts.
+        let mut events = BTreeMap::<String, Vec<Event>>::new();
+        for (file, contract, artifact) in outcome.into_artifacts_with_files() {
+            if !file.starts_with(&project.paths.sources)
+                || file.is_sol_test()
+                || (!self.select.is_empty() && !self.select.iter().any(|re| re.is_match(&contract)))
+            {
+                continue;
+            }
+            let Some(abi) = artifact.abi else { continue };
+            // Anonymous events have no topic to be identified by.
+            let contract_events =
+                abi.events().filter(|event| !event.anonymous).cloned().collect::<Vec<_>>();
+            if contract_events.is_empty() {
+                continue;
+            }
+            if events.contains_key(&contract) {
+                sh_warn!(
+                    "Skipping events of duplicate contract `{contract}` in {}",
+                    file.display()
+                )?;
+                continue;
+            }
+            events.insert(contract, contract_events);
+        }
+        eyre::ensure!(!events.is_empty(), "No events found in the project contracts");
+
+        let out = self.out.clone().unwrap_or_else(|| config.out.join("events"));
+        let mut outdated = Vec::new();
+        for lang in &self.lang {
+            let contents = match lang {
+                EventsLang::Rust => rust_decoders(&events),
+                EventsLang::Typescript => typescript_decoders(&events),
+            };
+            let path = out.join(lang.file_name());
+            if self.check {
+                if std::fs::read_to_string(&path).ok().as_deref() != Some(contents.as_str()) {
+                    outdated.push(path);
+                }
+            } else {
+                fs::create_dir_all(&out)?;
+                fs::write(&path, contents)?;
+            }
+        }
+
+        if self.check {
+            eyre::ensure!(
+                outdated.is_empty(),
+                "Event decoders are out of date, run `forge bind-events` to update:\n{}",
+                outdated
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            );
+            sh_println!("Event decoders are up to date.")?;
+        } else {
+            let count = events.values().map(Vec::len).sum::<usize>();
+            sh_println!("Generated decoders for {count} events to {}", out.display())?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns a Rust module declaring the events with `sol!`, which generates the topic hashes and
+/// a `<Contract>Events` enum decoding the logs of each contract.
+fn rust_decoders(events: &BTreeMap<String, Vec<Event>>) -> String {
+    let mut out = format!("//! {GENERATED_NOTICE}\n\nalloy::sol! {{");
+    for (contract, events) in events {
+        let _ = write!(out, "\n    #[derive(Debug, PartialEq, Eq)]\n    interface {contract} {{\n");
+        for event in events {
+            let _ = writeln!(out, "        {};", event.full_signature());
+        }
+        out.push_str("    }\n");
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Returns a TypeScript module exporting the event ABIs, their topic hashes and a function
+/// decoding the logs with viem.
+fn typescript_decoders(events: &BTreeMap<String, Vec<Event>>) -> String {
+    let mut out =
+        format!("// {GENERATED_NOTICE}\n\nimport {{ decodeEventLog, type Hex }} from \"viem\";\n");
+
+    let mut topics = BTreeMap::new();
+    let mut abis = Vec::new();
+    for (contract, events) in events {
+        let abi = lower_camel_case(contract) + "EventsAbi";
+        let _ = write!(out, "\n/** The events of `{contract}`. */\nexport const {abi} = [\n");
+        for event in events {
+            let item = serde_json::json!({
+                "type": "event",
+                "name": event.name,
+                "inputs": event.inputs,
+                "anonymous": event.anonymous,
+            });
+            let _ = writeln!(out, "  {item},");
+            topics.insert(event.signature(), hex::encode_prefixed(event.selector()));
+        }
+        out.push_str("] as const;\n");
+        abis.push(abi);
+    }
+
+    let abis = abis.iter().map(|abi| format!("...{abi}")).collect::<Vec<_>>().join(", ");
+    let _ = write!(
+        out,
+        "\n/** The events of all contracts. */\nexport const eventsAbi = [{abis}] as const;\n"
+    );
+
+    out.push_str(
+        "\n/** The topic hash of each event, by signature. */\nexport const eventTopics = {\n",
+    );
+    for (signature, topic) in topics {
+        let _ = writeln!(out, "  \"{signature}\": \"{topic}\",");
+    }
+    out.push_str("} as const;\n");
+
+    out.push_str(
+        "
+/** Decodes a log emitted by one of the contracts, throws if the event is unknown. */
+export function decodeEvent(log: { topics: [Hex, ...Hex[]]; data: Hex }) {
+  return decodeEventLog({ abi: eventsAbi, topics: log.topics, data: log.data });
+}
+",
+    );
+    out
+}
+
+/// Converts a contract name to a lower camel case identifier, e.g. `ERC20Token` to `erc20Token`.
+fn lower_camel_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let upper = chars.iter().take_while(|c| !c.is_lowercase()).count();
+    // Keep the last uppercase letter of an acronym followed by a word, e.g. the `T` of
+    // `ERC20Token`.
+    let lower = if upper > 1 && upper < chars.len() { upper - 1 } else { upper };
+    chars[..lower]
+        .iter()
+        .flat_map(|c| c.to_lowercase())
+        .chain(chars[lower..].iter().copied())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn events() -> BTreeMap<String, Vec<Event>> {
+        BTreeMap::from([(
+            "Counter".to_string(),
+            vec![Event::parse("event NumberSet(address indexed caller, uint256 number)").unwrap()],
+        )])
+    }
+
+    #[test]
+    fn generates_rust_decoders() {
+        assert_eq!(
+            rust_decoders(&events()),
+            "//! This file was generated by `forge bind-events`, do not edit it.
+
+alloy::sol! {
+    #[derive(Debug, PartialEq, Eq)]
+    interface Counter {
+        event NumberSet(address indexed caller, uint256 number);
+    }
+}
+"
+        );
+    }
+
+    #[test]
+    fn generates_typescript_decoders() {
+        let ts = typescript_decoders(&events());
+        assert!(ts.contains("export const counterEventsAbi = [\n  {"), "{ts}");
+        assert!(ts.contains(r#""name":"NumberSet""#), "{ts}");
+        assert!(ts.contains(&format!(
+            "\"NumberSet(address,uint256)\": \"{}\",",
+            hex::encode_prefixed(alloy_primitives::keccak256("NumberSet(address,uint256)"))
+        )));
+        assert!(ts.contains("export const eventsAbi = [...counterEventsAbi] as const;"));
+    }
+
+    #[test]
+    fn converts_to_lower_camel_case() {
+        assert_eq!(lower_camel_case("Counter"), "counter");
+        assert_eq!(lower_camel_case("ERC20Token"), "erc20Token");
+        assert_eq!(lower_camel_case("WETH"), "weth");
+    }
+}
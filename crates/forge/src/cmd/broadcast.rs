@@ -0,0 +1,264 @@
+use alloy_chains::Chain;
+use alloy_primitives::B256;
+use clap::{Parser, Subcommand, ValueHint};
+use comfy_table::{Cell, Table, modifiers::UTF8_ROUND_CORNERS, presets::ASCII_MARKDOWN};
+use eyre::{Result, bail};
+use forge_script_sequence::{BroadcastIndex, RunFile, now, run_files};
+use foundry_cli::utils;
+use foundry_common::{fs, shell};
+use serde_json::{Value, json};
+use std::{path::PathBuf, time::Duration};
+
+/// CLI arguments for `forge broadcast`.
+#[derive(Debug, Parser)]
+pub struct BroadcastArgs {
+    #[command(subcommand)]
+    pub sub: BroadcastSubcommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum BroadcastSubcommands {
+    /// List the latest deployment of each contract, by chain.
+    Ls(BroadcastLsArgs),
+
+    /// Remove old script runs from the broadcast directory.
+    Prune(BroadcastPruneArgs),
+
+    /// Show a broadcast transaction and its receipt.
+    Show(BroadcastShowArgs),
+}
+
+/// CLI arguments for `forge broadcast ls`.
+#[derive(Debug, Parser)]
+pub struct BroadcastLsArgs {
+    /// Only list the deployments on the given chain.
+    #[arg(long)]
+    chain: Option<Chain>,
+
+    /// Rebuild the index from the run files instead of reading it.
+    #[arg(long)]
+    rebuild: bool,
+
+    /// The project's root path.
+    ///
+    /// By default root of the Git repository, if in one,
+    /// or the current working directory.
+    #[arg(long, value_hint = ValueHint::DirPath, value_name = "PATH")]
+    root: Option<PathBuf>,
+}
+
+impl BroadcastLsArgs {
+    pub fn run(self) -> Result<()> {
+        let config = utils::load_config_with_root(self.root.as_deref())?;
+        let index = if self.rebuild {
+            let index = BroadcastIndex::build(&config.broadcast)?;
+            index.save(&config.broadcast)?;
+            index
+        } else {
+            BroadcastIndex::load(&config.broadcast)?
+        };
+
+        let chains = index
+            .chains
+            .iter()
+            .filter(|(chain, _)| self.chain.is_none_or(|filter| filter.id() == **chain));
+
+        if shell::is_json() {
+            let chains = chains.collect::<std::collections::BTreeMap<_, _>>();
+            sh_println!("{}", serde_json::to_string_pretty(&chains)?)?;
+            return Ok(());
+        }
+
+        let mut table = Table::new();
+        if shell::is_markdown() {
+            table.load_preset(ASCII_MARKDOWN);
+        } else {
+            table.apply_modifier(UTF8_ROUND_CORNERS);
+        }
+        table.set_header(["Chain", "Contract", "Address", "Script", "Deployed"]);
+        for (chain, deployments) in chains {
+            for (contract, deployment) in deployments {
+                table.add_row([
+                    Cell::new(Chain::from_id(*chain)),
+                    Cell::new(contract),
+                    Cell::new(deployment.address),
+                    Cell::new(&deployment.script),
+                    Cell::new(format_timestamp(deployment.timestamp)),
+                ]);
+            }
+        }
+        if table.row_count() == 0 {
+            sh_println!("No deployments found in {}", config.broadcast.display())?;
+        } else {
+            sh_println!("{table}")?;
+        }
+        Ok(())
+    }
+}
+
+/// CLI arguments for `forge broadcast prune`.
+#[derive(Debug, Parser)]
+pub struct BroadcastPruneArgs {
+    /// The number of most recent runs to keep for each script and chain.
+    #[arg(long, default_value_t = 10, value_name = "RUNS")]
+    keep: usize,
+
+    /// Only remove the runs older than the given number of days.
+    #[arg(long, value_name = "DAYS")]
+    older_than: Option<u64>,
+
+    /// Only print the runs that would be removed.
+    #[arg(long)]
+    dry_run: bool,
+
+    /// The project's root path.
+    ///
+    /// By default root of the Git repository, if in one,
+    /// or the current working directory.
+    #[arg(long, value_hint = ValueHint::DirPath, value_name = "PATH")]
+    root: Option<PathBuf>,
+}
+
+impl BroadcastPruneArgs {
+    pub fn run(self) -> Result<()> {
+        let config = utils::load_config_with_root(self.root.as_deref())?;
+        let broadcast_dir = &config.broadcast;
+        let index = BroadcastIndex::load(broadcast_dir)?;
+
+        let cutoff = self.older_than.map(|days| {
+            let age = Duration::from_secs(days * 24 * 60 * 60);
+            now().saturating_sub(age).as_millis()
+        });
+
+        let runs = run_files(broadcast_dir);
+        let mut pruned = Vec::new();
+        for dir_runs in runs.chunk_by(|a, b| a.dir == b.dir) {
+            // Runs are sorted by ascending timestamp.
+            let prunable = dir_runs.len().saturating_sub(self.keep);
+            pruned.extend(dir_runs[..prunable].iter().filter(|run| {
+                cutoff.is_none_or(|cutoff| run.timestamp < cutoff)
+                    // Keep the runs of the indexed deployments.
+                    && !index.references(broadcast_dir, &run.path)
+            }));
+        }
+
+        for RunFile { path, .. } in &pruned {
+            let relative = path.strip_prefix(broadcast_dir).unwrap_or(path);
+            if self.dry_run {
+                sh_println!("Would remove {}", relative.display())?;
+                continue;
+            }
+            fs::remove_file(path)?;
+            // The sensitive values of the run, in the cache directory.
+            let sensitive = config.cache_path.join(relative);
+            if sensitive.is_file() {
+                fs::remove_file(sensitive)?;
+            }
+        }
+
+        let verb = if self.dry_run { "Would remove" } else { "Removed" };
+        sh_println!("{verb} {} of {} runs", pruned.len(), runs.len())?;
+        Ok(())
+    }
+}
+
+/// CLI arguments for `forge broadcast show`.
+#[derive(Debug, Parser)]
+pub struct BroadcastShowArgs {
+    /// The hash of the transaction.
+    tx: B256,
+
+    /// The project's root path.
+    ///
+    /// By default root of the Git repository, if in one,
+    /// or the current working directory.
+    #[arg(long, value_hint = ValueHint::DirPath, value_name = "PATH")]
+    root: Option<PathBuf>,
+}
+
+impl BroadcastShowArgs {
+    pub fn run(self) -> Result<()> {
+        let config = utils::load_config_with_root(self.root.as_deref())?;
+        let broadcast_dir = &config.broadcast;
+
+        // Most recent runs first.
+        let mut runs = run_files(broadcast_dir);
+        runs.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        for run in runs {
+            let sequence: Value = fs::read_json_file(&run.path)?;
+            let Some(tx) = find_by_hash(&sequence["transactions"], "hash", self.tx) else {
+                continue;
+            };
+            let receipt = find_by_hash(&sequence["receipts"], "transactionHash", self.tx);
+            let relative = run.path.strip_prefix(broadcast_dir).unwrap_or(&run.path);
+
+            if shell::is_json() {
+                let output = json!({
+                    "run": relative,
+                    "chain": sequence["chain"],
+                    "transaction": tx,
+                    "receipt": receipt,
+                });
+                sh_println!("{}", serde_json::to_string_pretty(&output)?)?;
+                return Ok(());
+            }
+
+            sh_println!("Transaction {}", self.tx)?;
+            sh_println!("  run:      {}", relative.display())?;
+            if let Some(chain) = sequence["chain"].as_u64() {
+                sh_println!("  chain:    {}", Chain::from_id(chain))?;
+            }
+            for (label, key) in [
+                ("type", "transactionType"),
+                ("contract", "contractName"),
+                ("address", "contractAddress"),
+                ("function", "function"),
+            ] {
+                if let Some(value) = tx[key].as_str().filter(|value| !value.is_empty()) {
+                    sh_println!("  {:<9} {value}", format!("{label}:"))?;
+                }
+            }
+            if let Some(arguments) = tx["arguments"].as_array().filter(|args| !args.is_empty()) {
+                let arguments =
+                    arguments.iter().filter_map(Value::as_str).collect::<Vec<_>>().join(", ");
+                sh_println!("  args:     {arguments}")?;
+            }
+            match receipt {
+                Some(receipt) => {
+                    let success = receipt["status"].as_str() == Some("0x1");
+                    sh_println!(
+                        "  status:   {} (block {}, gas used {})",
+                        if success { "success" } else { "failed" },
+                        hex_quantity(&receipt["blockNumber"]),
+                        hex_quantity(&receipt["gasUsed"]),
+                    )?;
+                }
+                None => sh_println!("  status:   pending")?,
+            }
+            return Ok(());
+        }
+
+        bail!("transaction {} not found in {}", self.tx, broadcast_dir.display())
+    }
+}
+
+/// Returns the item of a JSON array whose `key` field is the given hash.
+fn find_by_hash<'a>(items: &'a Value, key: &str, hash: B256) -> Option<&'a Value> {
+    items
+        .as_array()?
+        .iter()
+        .find(|item| item[key].as_str().and_then(|value| value.parse::<B256>().ok()) == Some(hash))
+}
+
+/// Formats a JSON hex quantity as a decimal number.
+fn hex_quantity(value: &Value) -> String {
+    value
+        .as_str()
+        .and_then(|value| u64::from_str_radix(value.trim_start_matches("0x"), 16).ok())
+        .map_or_else(|| "unknown".to_string(), |value| value.to_string())
+}
+
+fn format_timestamp(millis: u128) -> String {
+    chrono::DateTime::from_timestamp_millis(millis as i64)
+        .map_or_else(|| millis.to_string(), |time| time.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+}
@@ -1,29 +1,43 @@
 use super::{install, watch::WatchArgs};
+use crate::hooks::run_hooks;
 use clap::Parser;
 use eyre::{Context, Result};
-use forge_lint::{linter::Linter, sol::SolidityLinter};
+use forge_lint::{fixes::FileFixes, linter::Linter, sol::SolidityLinter};
 use foundry_cli::{
     opts::{BuildOpts, configure_pcx_from_solc, get_solar_sources_from_compile_output},
     utils::{Git, LoadConfig, cache_local_signatures},
 };
-use foundry_common::{compile::ProjectCompiler, shell};
+use foundry_common::{
+    artifact_hash::{ArtifactChange, diff_artifacts, normalize_artifact_paths},
+    compile::ProjectCompiler,
+    provenance::BuildProvenance,
+    shell,
+};
 use foundry_compilers::{
     CompilationError, FileFilter, Project, ProjectCompileOutput,
-    compilers::{Language, multi::MultiCompilerLanguage},
+    artifacts::{BytecodeHash, BytecodeObject},
+    compilers::{
+        Language,
+        multi::{MultiCompilerError, MultiCompilerLanguage},
+    },
     solc::SolcLanguage,
     utils::source_files_iter,
 };
 use foundry_config::{
-    Config, SkipBuildFilters,
+    Config, DenyLevel, HookStage, SkipBuildFilters,
     figment::{
         self, Metadata, Profile, Provider,
         error::Kind::InvalidType,
         value::{Dict, Map, Value},
     },
     filter::expand_globs,
+    lint::LintOnBuild,
 };
 use serde::Serialize;
-use std::path::PathBuf;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
 
 foundry_config::merge_impl_figment_convert!(BuildArgs, build);
 
@@ -61,6 +75,43 @@ pub struct BuildArgs {
     #[serde(skip)]
     pub ignore_eip_3860: bool,
 
+    /// Print the time spent compiling each compilation unit.
+    #[arg(long)]
+    #[serde(skip)]
+    pub timings: bool,
+
+    /// Build without writing artifacts, and fail if the artifacts in the output directory differ
+    /// from the fresh build.
+    ///
+    /// Meant for CI in repositories which commit their artifacts.
+    #[arg(long, conflicts_with_all = ["force", "apply_fixes"])]
+    #[serde(skip)]
+    pub assert_unchanged: bool,
+
+    /// Apply the quick-fixes of compiler diagnostics, then build.
+    ///
+    /// Fixes missing SPDX license identifiers and version pragmas, mismatching version pragmas,
+    /// and unused variables. Applied fixes are printed as patches.
+    #[arg(long)]
+    #[serde(skip)]
+    pub apply_fixes: bool,
+
+    /// Build reproducible bytecode, and verify that it is.
+    ///
+    /// Pins the metadata hash to `none` (or keeps `ipfs`), rebuilds without cache, checks that a
+    /// second build produces the same bytecode and rewrites the absolute paths of the artifacts
+    /// relative to the project root.
+    #[arg(long, conflicts_with = "assert_unchanged")]
+    #[serde(skip)]
+    pub deterministic: bool,
+
+    /// Run the hooks configured in the `[hooks]` section of the config.
+    ///
+    /// Hooks run arbitrary shell commands, so they are skipped unless this flag is set.
+    #[arg(long)]
+    #[serde(skip)]
+    pub run_hooks: bool,
+
     #[command(flatten)]
     #[serde(flatten)]
     pub build: BuildOpts,
@@ -80,9 +131,15 @@ pub async fn run(self) -> Result<ProjectCompileOutput> {
             config = self.load_config()?;
         }
 
+        if self.deterministic {
+            pin_deterministic_settings(&mut config);
+        }
+
         self.check_soldeer_lock_consistency(&config).await;
         self.check_foundry_lock_consistency(&config);
 
+        run_hooks(&config, HookStage::PreBuild, self.run_hooks)?;
+
         let project = config.project()?;
 
         // Collect sources to compile if build subdirectories specified.
@@ -99,41 +156,107 @@ pub async fn run(self) -> Result<ProjectCompileOutput> {
         }
 
         let format_json = shell::is_json();
+        if self.apply_fixes {
+            let output = ProjectCompiler::new()
+                .files(files.clone())
+                .dynamic_test_linking(config.dynamic_test_linking)
+                .quiet(true)
+                .bail(false)
+                .compile(&project)?;
+            self.apply_fixes(&project, &output)?;
+        }
+
+        if self.assert_unchanged {
+            let project = config.ephemeral_project()?;
+            let output = ProjectCompiler::new()
+                .files(files)
+                .dynamic_test_linking(config.dynamic_test_linking)
+                .quiet(format_json)
+                .compile(&project)?;
+            assert_artifacts_unchanged(&config, &output, self.paths.is_some())?;
+            return Ok(output);
+        }
+
+        // Build once without writing artifacts, to compare with the actual build.
+        let reference = if self.deterministic {
+            let output = ProjectCompiler::new()
+                .files(files.clone())
+                .dynamic_test_linking(config.dynamic_test_linking)
+                .quiet(true)
+                .compile(&config.ephemeral_project()?)?;
+            Some(output)
+        } else {
+            None
+        };
+
         let compiler = ProjectCompiler::new()
             .files(files)
             .dynamic_test_linking(config.dynamic_test_linking)
             .print_names(self.names)
             .print_sizes(self.sizes)
             .ignore_eip_3860(self.ignore_eip_3860)
+            .timings(self.timings)
             .bail(!format_json);
 
         let mut output = compiler.compile(&project)?;
+        if !output.has_compiler_errors() {
+            run_hooks(&config, HookStage::PostBuild, self.run_hooks)?;
+        }
 
         // Cache project selectors.
         cache_local_signatures(&output)?;
 
-        if format_json && !self.names && !self.sizes {
+        if config.provenance {
+            let git_commit = Git::new(&config.root).commit_hash(false, "HEAD").ok();
+            let path = BuildProvenance::new(&config, &output, git_commit)?.write(&config)?;
+            if !format_json {
+                sh_println!("Provenance written to {}", path.display())?;
+            }
+        }
+
+        if let Some(reference) = &reference {
+            let report = ReproducibilityReport::new(&config, reference, &output)?;
+            report.print()?;
+            if !report.mismatches.is_empty() {
+                eyre::bail!("{} contracts are not reproducible", report.mismatches.len());
+            }
+        } else if format_json && !self.names && !self.sizes {
             sh_println!("{}", serde_json::to_string_pretty(&output.output())?)?;
         }
 
         // Only run the `SolidityLinter` if lint on build and no compilation errors.
-        if config.lint.lint_on_build && !output.output().errors.iter().any(|e| e.is_error()) {
-            self.lint(&project, &config, self.paths.as_deref(), &mut output)
+        let on_build = config.lint.on_build();
+        if on_build != LintOnBuild::Off && !output.output().errors.iter().any(|e| e.is_error()) {
+            self.lint(&project, &config, self.paths.as_deref(), &mut output, on_build)
                 .wrap_err("Lint failed")?;
         }
 
         Ok(output)
     }
 
+    /// Applies the quick-fixes of the solc diagnostics of `output`, printing them as patches.
+    fn apply_fixes(&self, project: &Project, output: &ProjectCompileOutput) -> Result<()> {
+        let errors = output.output().errors.iter().filter_map(|error| match error {
+            MultiCompilerError::Solc(error) => Some(error),
+            _ => None,
+        });
+        let files = FileFixes::collect(project.root(), errors)?;
+        write_fixes(project.root(), &files)
+    }
+
     fn lint(
         &self,
         project: &Project,
         config: &Config,
         files: Option<&[PathBuf]>,
         output: &mut ProjectCompileOutput,
+        on_build: LintOnBuild,
     ) -> Result<()> {
         let format_json = shell::is_json();
-        if project.compiler.solc.is_some() && !shell::is_quiet() {
+        // Denied lints fail the build even when quiet.
+        let deny = on_build == LintOnBuild::Deny;
+        if project.compiler.solc.is_some() && (!shell::is_quiet() || deny) {
+            forge_lint::plugin::load(&config.root, &config.lint.plugins)?;
             let linter = SolidityLinter::new(config.project_paths())
                 .with_json_emitter(format_json)
                 .with_description(!format_json)
@@ -154,7 +277,22 @@ fn lint(
                             .collect(),
                     )
                 })
-                .with_lint_specific(&config.lint.lint_specific);
+                .with_severity_overrides(config.lint.severity_overrides.iter().filter_map(
+                    |(id, severity)| {
+                        let lint = forge_lint::sol::SolLint::try_from(id.as_str()).ok()?;
+                        Some((lint, *severity))
+                    },
+                ))
+                .with_lint_specific(&config.lint.lint_specific)
+                .with_cache((config.cache && !config.force).then(|| config.lint_cache_dir()))
+                .with_baseline(
+                    config
+                        .lint
+                        .baseline
+                        .as_ref()
+                        .map(|path| forge_lint::baseline::Baseline::read(&config.root.join(path)))
+                        .transpose()?,
+                );
 
             // Expand ignore globs and canonicalize from the get go
             let ignored = expand_globs(&config.root, config.lint.ignore.iter())?
@@ -199,7 +337,8 @@ fn lint(
                 pcx.set_resolve_imports(true);
                 pcx.parse();
             });
-            linter.lint(&input_files, config.deny, &mut compiler)?;
+            let deny = if deny { DenyLevel::Notes } else { config.deny };
+            linter.lint(&input_files, deny, &mut compiler)?;
         }
 
         Ok(())
@@ -322,6 +461,161 @@ fn check_foundry_lock_consistency(&self, config: &Config) {
     }
 }
 
+/// Applies the machine-applicable fixes of `files`, printing all the fixes as patches relative to
+/// `root`.
+pub(crate) fn write_fixes(root: &Path, files: &[FileFixes]) -> Result<()> {
+    let (mut applied, mut fixed_files) = (0, 0);
+    for file in files {
+        if !shell::is_json() {
+            let path = file.path.strip_prefix(root).unwrap_or(&file.path);
+            sh_println!("{}", file.patch(path))?;
+        }
+        if file.applicable() > 0 {
+            foundry_common::fs::write(&file.path, file.apply())?;
+            applied += file.applicable();
+            fixed_files += 1;
+        }
+    }
+
+    if !shell::is_json() {
+        sh_println!("Applied {applied} fixes to {fixed_files} files")?;
+    }
+    Ok(())
+}
+
+/// Pins the settings which make the bytecode depend on the machine it was built on.
+fn pin_deterministic_settings(config: &mut Config) {
+    // The IPFS hash of the metadata only depends on the sources and on their paths relative to
+    // the root, any other hash is dropped.
+    if config.bytecode_hash != BytecodeHash::Ipfs {
+        config.bytecode_hash = BytecodeHash::None;
+    }
+    // Cached artifacts may come from a build with other settings.
+    config.cache = false;
+}
+
+/// The outcome of a `--deterministic` build.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReproducibilityReport {
+    bytecode_hash: BytecodeHash,
+    contracts: usize,
+    /// The number of artifacts whose absolute paths were rewritten.
+    normalized_artifacts: usize,
+    /// The contracts whose bytecode differs between the two builds.
+    mismatches: Vec<String>,
+}
+
+impl ReproducibilityReport {
+    /// Compares the bytecode of two builds, and normalizes the paths of the written artifacts.
+    fn new(
+        config: &Config,
+        reference: &ProjectCompileOutput,
+        output: &ProjectCompileOutput,
+    ) -> Result<Self> {
+        let (reference, built) = (contract_bytecodes(reference), contract_bytecodes(output));
+        let mismatches = reference
+            .keys()
+            .chain(built.keys())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .filter(|contract| reference.get(*contract) != built.get(*contract))
+            .cloned()
+            .collect();
+
+        let artifacts_dir = config.root.join(&config.out);
+        let mut normalized_artifacts = 0;
+        for (id, _) in output.artifact_ids() {
+            if normalize_artifact_paths(&artifacts_dir.join(&id.path), &config.root)? {
+                normalized_artifacts += 1;
+            }
+        }
+
+        Ok(Self {
+            bytecode_hash: config.bytecode_hash,
+            contracts: built.len(),
+            normalized_artifacts,
+            mismatches,
+        })
+    }
+
+    fn print(&self) -> Result<()> {
+        if shell::is_json() {
+            sh_println!("{}", serde_json::to_string_pretty(self)?)?;
+            return Ok(());
+        }
+
+        sh_println!("Reproducibility report:")?;
+        sh_println!("  bytecode hash: {}", self.bytecode_hash)?;
+        sh_println!("  contracts: {}", self.contracts)?;
+        sh_println!("  normalized artifacts: {}", self.normalized_artifacts)?;
+        if self.mismatches.is_empty() {
+            sh_println!("Bytecode is reproducible")?;
+        } else {
+            sh_println!("Bytecode differs between two builds:")?;
+            for contract in &self.mismatches {
+                sh_println!("  {contract}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the creation and runtime bytecode of the contracts of a build.
+fn contract_bytecodes(
+    output: &ProjectCompileOutput,
+) -> BTreeMap<String, (Option<BytecodeObject>, Option<BytecodeObject>)> {
+    output
+        .artifact_ids()
+        .map(|(id, artifact)| {
+            let bytecode = artifact.bytecode.as_ref().map(|code| code.object.clone());
+            let deployed_bytecode = artifact
+                .deployed_bytecode
+                .as_ref()
+                .and_then(|code| code.bytecode.as_ref())
+                .map(|code| code.object.clone());
+            (id.identifier(), (bytecode, deployed_bytecode))
+        })
+        .collect()
+}
+
+/// Fails if the committed artifacts of the project differ from the freshly built `output`.
+///
+/// If `partial` is set, only the given paths were compiled, so the artifacts of the other sources
+/// are not stale.
+fn assert_artifacts_unchanged(
+    config: &Config,
+    output: &ProjectCompileOutput,
+    partial: bool,
+) -> Result<()> {
+    let diffs = diff_artifacts(&config.root.join(&config.out), output.artifact_ids(), partial)?;
+    if shell::is_json() {
+        sh_println!("{}", serde_json::to_string_pretty(&diffs)?)?;
+    } else if !diffs.is_empty() {
+        sh_println!("Artifacts differ from a fresh build:")?;
+        for diff in &diffs {
+            let change = match &diff.change {
+                ArtifactChange::Changed { fields, committed, fresh } => format!(
+                    "changed {} ({} -> {})",
+                    fields.join(", "),
+                    &committed.to_string()[..10],
+                    &fresh.to_string()[..10]
+                ),
+                ArtifactChange::Missing => "missing".to_string(),
+                ArtifactChange::Stale => "stale".to_string(),
+            };
+            sh_println!("  {}: {change}", diff.contract)?;
+        }
+    } else {
+        sh_println!("Artifacts match a fresh build")?;
+    }
+
+    if !diffs.is_empty() {
+        eyre::bail!("{} artifacts differ from a fresh build", diffs.len());
+    }
+    Ok(())
+}
+
 // Make this args a `figment::Provider` so that it can be merged into the `Config`
 impl Provider for BuildArgs {
     fn metadata(&self) -> Metadata {
@@ -0,0 +1,106 @@
+use clap::{Parser, ValueHint};
+use eyre::Result;
+use foundry_cli::utils;
+use foundry_config::{
+    Config,
+    cache::{CleanCategory, DiskUsage},
+};
+use std::{io, path::PathBuf};
+
+/// CLI arguments for `forge clean`.
+#[derive(Clone, Debug, Parser)]
+pub struct CleanArgs {
+    /// The project's root path.
+    ///
+    /// By default root of the Git repository, if in one,
+    /// or the current working directory.
+    #[arg(long, value_hint = ValueHint::DirPath, value_name = "PATH")]
+    pub root: Option<PathBuf>,
+
+    /// Also remove the global RPC and Etherscan caches and all solc installations.
+    #[arg(long)]
+    pub deep: bool,
+
+    /// Only report the disk usage of every category that would be removed.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+impl CleanArgs {
+    pub fn run(self) -> Result<()> {
+        let config = utils::load_config_with_root(self.root.as_deref())?;
+        let project = config.project()?;
+        let root = project.root();
+
+        let mut usage = DiskUsage::default();
+        usage.categories.push(CleanCategory::new(
+            "Build artifacts",
+            [
+                project.paths.artifacts.clone(),
+                project.paths.cache.clone(),
+                project.paths.build_infos.clone(),
+                config.lint_cache_dir(),
+            ],
+        ));
+        usage.categories.push(CleanCategory::new(
+            "Test failures and fuzz corpora",
+            std::iter::once(config.test_failures_file.clone()).chain(
+                [
+                    &config.fuzz.failure_persist_dir,
+                    &config.fuzz.corpus.corpus_dir,
+                    &config.invariant.corpus.corpus_dir,
+                    &config.invariant.failure_persist_dir,
+                ]
+                .into_iter()
+                .flatten()
+                .map(|dir| root.join(dir)),
+            ),
+        ));
+        if self.deep {
+            usage
+                .categories
+                .push(CleanCategory::new("RPC and Etherscan cache", Config::foundry_cache_dir()));
+            usage
+                .categories
+                .push(CleanCategory::new("Solc installations", [svm::data_dir().to_path_buf()]));
+        }
+
+        if self.dry_run {
+            sh_println!("Would remove:\n{usage}")?;
+            return Ok(());
+        }
+
+        let mut warnings = config.cleanup(&project)?;
+        if self.deep {
+            warnings.extend(Config::clean_foundry_cache()?);
+            let svm_dir = svm::data_dir();
+            if let Err(err) = std::fs::remove_dir_all(svm_dir)
+                && err.kind() != io::ErrorKind::NotFound
+            {
+                warnings.push(format!(
+                    "failed to remove solc installations {}: {err}",
+                    svm_dir.display()
+                ));
+            }
+            sh_println!("Removed:\n{usage}")?;
+        }
+        for warning in warnings {
+            let _ = sh_warn!("{warning}");
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_clean_args() {
+        let args = CleanArgs::parse_from(["clean", "--deep", "--dry-run"]);
+        assert!(args.deep);
+        assert!(args.dry_run);
+        assert!(args.root.is_none());
+    }
+}
@@ -1,5 +1,7 @@
 use super::{init::InitArgs, install::DependencyInstallOpts};
-use alloy_primitives::{Address, Bytes, ChainId, TxHash};
+use alloy_network::{AnyNetwork, Network, TransactionBuilder};
+use alloy_primitives::{Address, B256, Bytes, ChainId, TxHash, U256, b256, hex};
+use alloy_provider::Provider;
 use clap::{Parser, ValueEnum, ValueHint};
 use eyre::Result;
 use forge_verify::sourcify::SOURCIFY_URL;
@@ -12,13 +14,13 @@
 };
 use foundry_cli::{
     opts::EtherscanOpts,
-    utils::{Git, LoadConfig},
+    utils::{Git, LoadConfig, get_provider},
 };
-use foundry_common::{compile::ProjectCompiler, fs};
+use foundry_common::{compile::ProjectCompiler, etherscan::EtherscanThrottle, fs, shell};
 use foundry_compilers::{
     ProjectCompileOutput, ProjectPathsConfig,
     artifacts::{
-        ConfigurableContractArtifact, Settings, StorageLayout,
+        ConfigurableContractArtifact, Settings, Storage, StorageLayout,
         output_selection::ContractOutputSelection,
         remappings::{RelativeRemapping, Remapping},
     },
@@ -28,9 +30,10 @@
 use reqwest::StatusCode;
 use serde::Deserialize;
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, HashMap, HashSet},
     fs::read_dir,
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
+    process::Command,
     time::Duration,
 };
 use tracing::trace;
@@ -38,7 +41,7 @@
 
 /// CloneMetadata stores the metadata that are not included by `foundry.toml` but necessary for a
 /// cloned contract. The metadata can be serialized to a metadata file in the cloned project root.
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CloneMetadata {
     /// The path to the source file that contains the contract declaration.
@@ -56,8 +59,43 @@ pub struct CloneMetadata {
     pub deployer: Address,
     /// The constructor arguments of the contract on chain.
     pub constructor_arguments: Bytes,
-    /// The storage layout of the contract on chain.
+    /// The storage layout of the contract on chain, or of its implementation if the contract is
+    /// a proxy cloned with `--follow-proxy`.
     pub storage_layout: StorageLayout,
+    /// The implementation of the contract, if it is a proxy cloned with `--follow-proxy`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub implementation: Option<CloneImplementation>,
+    /// The directory the sources of the contract were dumped to, relative to the root directory
+    /// of the project, if it was cloned along with other contracts and is not the first one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_dir: Option<PathBuf>,
+}
+
+/// CloneImplementation stores the metadata of the implementation of a cloned proxy.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloneImplementation {
+    /// The path to the source file that contains the implementation contract declaration.
+    /// The path is relative to the root directory of the project.
+    pub path: PathBuf,
+    /// The name of the implementation contract in the file.
+    pub target_contract: String,
+    /// The address of the implementation contract on the blockchain.
+    pub address: Address,
+    /// The constructor arguments of the implementation contract on chain.
+    pub constructor_arguments: Bytes,
+}
+
+/// A contract cloned along with the main one, whose sources are dumped to their own directory of
+/// `src`, with remappings scoped to this directory: the implementation of a proxy, or the other
+/// contracts of a batch.
+pub(crate) struct ScopedContract {
+    /// The address of the contract on the blockchain.
+    pub address: Address,
+    /// The metadata of the contract, from the block explorer.
+    pub meta: Metadata,
+    /// The directory the sources are dumped to, relative to the root directory of the project.
+    pub dir: PathBuf,
 }
 
 /// Source explorer type for `forge clone`.
@@ -68,12 +106,25 @@ pub enum SourceExplorer {
     Etherscan,
     /// Use Sourcify API.
     Sourcify,
+    /// Use the Etherscan compatible API of a Blockscout instance, set with `--explorer-url`.
+    Blockscout,
+}
+
+impl SourceExplorer {
+    /// The name of the explorer, as printed to the user.
+    fn name(self) -> &'static str {
+        match self {
+            Self::Etherscan => "Etherscan",
+            Self::Sourcify => "Sourcify",
+            Self::Blockscout => "Blockscout",
+        }
+    }
 }
 
 /// CLI arguments for `forge clone`.
 ///
-/// `forge clone` clones an on-chain contract from block explorers (e.g., Etherscan, Sourcify) in
-/// the following steps:
+/// `forge clone` clones an on-chain contract from block explorers (e.g., Etherscan, Sourcify,
+/// Blockscout) in the following steps:
 /// 1. Fetch the contract source code from the block explorer.
 /// 2. Initialize a empty foundry project at the `root` directory specified in `CloneArgs`.
 /// 3. Dump the contract sources to the source directory.
@@ -84,14 +135,36 @@ pub enum SourceExplorer {
 ///    modifies the contract, it is possible to quickly check the storage layout compatibility with
 ///    the original on-chain contract.
 /// 6. Dump the `CloneMetadata` to the root directory of the cloned project as `.clone.meta` file.
+///
+/// Several contracts can be cloned into the same project: the first one is cloned as above, and
+/// the sources of the others are dumped to `src/<contract name>` with remappings scoped to this
+/// directory, so that they don't collide. The `.clone.meta` file is an array with an entry per
+/// contract.
+///
+/// Vyper contracts are dumped to the `src` directory as is, and their storage layout is
+/// collected with `vyper -f layout`.
+///
+/// With `--follow-proxy`, the implementation of an EIP-1967 proxy is cloned along with it: its
+/// sources are dumped to `src/<implementation name>`, and its storage layout is the one preserved
+/// in the `CloneMetadata`.
+///
+/// With `--diff`, an already cloned project is compared with the verified source of the contract
+/// instead: the local modifications are printed as a unified diff, and the storage layout of the
+/// local contract is checked against the one preserved in the `CloneMetadata`.
 #[derive(Clone, Debug, Parser)]
 pub struct CloneArgs {
-    /// The contract address to clone.
-    pub address: Address,
+    /// The addresses of the contracts to clone, e.g. the facets of a diamond.
+    ///
+    /// The last argument is the root directory of the cloned project if it is not an address and
+    /// `--root` is not set.
+    #[arg(value_name = "ADDRESSES", required = true, num_args = 1..)]
+    pub addresses: Vec<String>,
 
     /// The root directory of the cloned project.
-    #[arg(value_hint = ValueHint::DirPath, default_value = ".", value_name = "PATH")]
-    pub root: PathBuf,
+    ///
+    /// Defaults to the current directory.
+    #[arg(long, value_hint = ValueHint::DirPath, value_name = "PATH")]
+    pub root: Option<PathBuf>,
 
     /// Do not generate the remappings.txt file. Instead, keep the remappings in the configuration.
     #[arg(long)]
@@ -107,16 +180,44 @@ pub struct CloneArgs {
 
     /// Source explorer to use for fetching contract data.
     ///
-    /// Can be either "etherscan" (default) or "sourcify".
-    #[arg(long, default_value = "etherscan", value_name = "EXPLORER")]
+    /// Can be either "etherscan" (default), "sourcify" or "blockscout".
+    #[arg(long, visible_alias = "explorer", default_value = "etherscan", value_name = "EXPLORER")]
     pub source: SourceExplorer,
 
+    /// The URL of the explorer, for chains without Etherscan coverage.
+    ///
+    /// With `--explorer etherscan` or `--explorer blockscout`, the URL of any Etherscan compatible
+    /// API, e.g. `https://eth.blockscout.com` (required for Blockscout). With `--explorer
+    /// sourcify`, the same as `--sourcify-url`.
+    #[arg(long, value_name = "URL")]
+    pub explorer_url: Option<String>,
+
     /// Custom Sourcify API URL.
     ///
     /// Implies `--source sourcify`.
     #[arg(long, value_name = "URL")]
     pub sourcify_url: Option<String>,
 
+    /// Also clone the implementation of the contract, if it is a proxy.
+    ///
+    /// The implementation is read from the EIP-1967 implementation slot of the proxy (transparent
+    /// and UUPS proxies), or from the beacon in its EIP-1967 beacon slot.
+    #[arg(long)]
+    pub follow_proxy: bool,
+
+    /// The RPC endpoint used to read the implementation of the proxy.
+    #[arg(long, short, env = "ETH_RPC_URL", value_name = "URL")]
+    pub rpc_url: Option<String>,
+
+    /// Compare the project cloned at the root directory with the verified source of the contract,
+    /// instead of cloning it.
+    ///
+    /// Prints the local modifications as a unified diff, and fails if the storage layout of the
+    /// local contract is incompatible with the one of the contract on chain.
+    /// Pass `--keep-directory-structure` if the project was cloned with it.
+    #[arg(long, conflicts_with_all = ["follow_proxy", "no_remappings_txt"])]
+    pub diff: bool,
+
     #[command(flatten)]
     pub etherscan: EtherscanOpts,
 
@@ -127,44 +228,78 @@ pub struct CloneArgs {
 impl CloneArgs {
     pub async fn run(self) -> Result<()> {
         let Self {
-            address,
+            addresses,
             root,
             install,
             etherscan,
             no_remappings_txt,
             keep_directory_structure,
             source,
+            explorer_url,
             sourcify_url,
+            follow_proxy,
+            rpc_url,
+            diff,
         } = self;
+        let (addresses, root) = parse_targets(&addresses, root)?;
+        let address = addresses[0];
 
         // step 0. get the chain and api key from the config
-        let config = etherscan.load_config()?;
+        let mut config = etherscan.load_config()?;
         let chain = config.chain.unwrap_or_default();
 
         // If sourcify_url is specified, use Sourcify as the source
         let source = if sourcify_url.is_some() { SourceExplorer::Sourcify } else { source };
+        let sourcify_url = sourcify_url.or_else(|| {
+            explorer_url.clone().filter(|_| matches!(source, SourceExplorer::Sourcify))
+        });
 
-        // step 1. get the metadata from client based on source type
-        let (meta, explorer_name, sourcify_client) = match source {
-            SourceExplorer::Etherscan => {
-                let client = config
-                    .get_etherscan_config_with_chain(Some(chain))?
-                    .ok_or_else(|| {
-                        eyre::eyre!("No Etherscan API key configured for chain {chain}")
-                    })?
-                    .into_client()?;
-                sh_println!("Downloading the source code of {address} from Etherscan...")?;
-                let meta = Self::collect_metadata_from_client(address, &client).await?;
-                (meta, "Etherscan", None)
-            }
-            SourceExplorer::Sourcify => {
-                let client = SourcifyClient::with_url(chain, sourcify_url.as_deref());
-                sh_println!("Downloading the source code of {address} from Sourcify...")?;
-                let meta = Self::collect_metadata_from_client(address, &client).await?;
-                (meta, "Sourcify", Some(client))
+        if diff {
+            eyre::ensure!(addresses.len() == 1, "--diff compares a single contract");
+            let root = dunce::canonicalize(&root)?;
+            let provider =
+                MetadataProvider::new(&config, chain, source, explorer_url, sourcify_url)?;
+            return Self::diff(address, &root, keep_directory_structure, &provider).await;
+        }
+
+        // read the implementation of the proxy, if requested
+        eyre::ensure!(
+            !follow_proxy || addresses.len() == 1,
+            "--follow-proxy is not supported when cloning several contracts"
+        );
+        let implementation = if follow_proxy {
+            if rpc_url.is_some() {
+                config.eth_rpc_url = rpc_url;
             }
+            let provider = get_provider(&config)?;
+            let implementation = proxy_implementation(&provider, address).await?;
+            sh_println!("{address} is a proxy of {implementation}")?;
+            Some(implementation)
+        } else {
+            None
         };
 
+        // step 1. get the metadata from the provider of the source type
+        let explorer_name = source.name();
+        let provider = MetadataProvider::new(&config, chain, source, explorer_url, sourcify_url)?;
+        let (meta, implementation, others) =
+            Self::collect_all_metadata(&addresses, implementation, &provider, explorer_name)
+                .await
+                .map_err(|err| suggest_sourcify(err, source))?;
+        if let Some(implementation) = &implementation {
+            eyre::ensure!(
+                !meta.is_vyper() && !implementation.meta.is_vyper(),
+                "--follow-proxy is not supported for Vyper contracts"
+            );
+        }
+        if !others.is_empty() {
+            eyre::ensure!(
+                !meta.is_vyper() && others.iter().all(|other| !other.meta.is_vyper()),
+                "cloning several contracts is not supported for Vyper contracts"
+            );
+        }
+        let scoped = implementation.iter().chain(&others).collect::<Vec<_>>();
+
         // step 2. initialize an empty project
         Self::init_an_empty_project(&root, install).await?;
         // canonicalize the root path
@@ -172,37 +307,43 @@ pub async fn run(self) -> Result<()> {
         let root = dunce::canonicalize(&root)?;
 
         // step 3. parse the metadata
-        Self::parse_metadata(&meta, chain, &root, no_remappings_txt, keep_directory_structure)
-            .await?;
+        Self::parse_metadata(
+            &meta,
+            &scoped,
+            chain,
+            &root,
+            no_remappings_txt,
+            keep_directory_structure,
+        )
+        .await?;
 
         // step 4. collect the compilation metadata
-        sh_println!("Collecting the creation information of {address} from {explorer_name}...")?;
-
-        match source {
-            SourceExplorer::Etherscan => {
-                let etherscan_config =
-                    config.get_etherscan_config_with_chain(Some(chain))?.ok_or_else(|| {
-                        eyre::eyre!("No Etherscan API key configured for chain {chain}")
-                    })?;
-                if etherscan_config.key.is_empty() {
-                    sh_warn!("Waiting for 5 seconds to avoid rate limit...")?;
-                    tokio::time::sleep(Duration::from_secs(5)).await;
-                }
-                let client = etherscan_config.into_client()?;
-                Self::collect_compilation_metadata(&meta, chain, address, &root, &client).await?;
-            }
-            SourceExplorer::Sourcify => {
-                // Reuse the client from step 1 to benefit from cached creation data
-                let client = sourcify_client.expect("Sourcify client should exist");
-                Self::collect_compilation_metadata(&meta, chain, address, &root, &client).await?;
-            }
+        let targets = addresses.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+        sh_println!("Collecting the creation information of {targets} from {explorer_name}...")?;
+
+        if provider.is_keyless() {
+            sh_warn!("Waiting for 5 seconds to avoid rate limit...")?;
+            tokio::time::sleep(Duration::from_secs(5)).await;
         }
 
+        // Reuse the provider from step 1, so that the Etherscan requests share the chain's request
+        // budget and the Sourcify creation data is cached.
+        Self::collect_compilation_metadata(
+            &meta,
+            implementation.as_ref(),
+            &others,
+            chain,
+            address,
+            &root,
+            &provider,
+        )
+        .await?;
+
         // step 5. git add and commit the changes if needed
         if install.commit {
             let git = Git::new(&root);
             git.add(Some("--all"))?;
-            let msg = format!("chore: forge clone {address} from {explorer_name}");
+            let msg = format!("chore: forge clone {targets} from {explorer_name}");
             git.commit(&msg)?;
         }
 
@@ -219,9 +360,64 @@ pub(crate) async fn collect_metadata_from_client<C: ExplorerClient>(
     ) -> Result<Metadata> {
         let mut meta = client.contract_source_code(address).await?;
         eyre::ensure!(meta.items.len() == 1, "contract not found or ill-formed");
-        let meta = meta.items.remove(0);
-        eyre::ensure!(!meta.is_vyper(), "Vyper contracts are not supported");
-        Ok(meta)
+        Ok(meta.items.remove(0))
+    }
+
+    /// Collect the metadata of the implementation of a proxy from the block explorer, if any.
+    ///
+    /// * `implementation` - the address of the implementation.
+    /// * `client` - the client of the block explorer.
+    pub(crate) async fn collect_implementation_metadata<C: ExplorerClient>(
+        implementation: Option<Address>,
+        client: &C,
+    ) -> Result<Option<ScopedContract>> {
+        let Some(implementation) = implementation else { return Ok(None) };
+        sh_println!("Downloading the source code of the implementation {implementation}...")?;
+        let meta = Self::collect_metadata_from_client(implementation, client).await?;
+        let dir = Path::new("src").join(&meta.contract_name);
+        Ok(Some(ScopedContract { address: implementation, meta, dir }))
+    }
+
+    /// Collect the metadata of the contracts to clone from the block explorer.
+    ///
+    /// Returns the metadata of the first contract, the implementation of the first contract if it
+    /// is a proxy cloned with `--follow-proxy`, and the other contracts.
+    ///
+    /// * `addresses` - the addresses of the contracts to be cloned.
+    /// * `implementation` - the address of the implementation, if any.
+    /// * `client` - the client of the block explorer.
+    /// * `explorer_name` - the name of the block explorer.
+    pub(crate) async fn collect_all_metadata<C: ExplorerClient>(
+        addresses: &[Address],
+        implementation: Option<Address>,
+        client: &C,
+        explorer_name: &str,
+    ) -> Result<(Metadata, Option<ScopedContract>, Vec<ScopedContract>)> {
+        let mut metas = Vec::with_capacity(addresses.len());
+        for &address in addresses {
+            sh_println!("Downloading the source code of {address} from {explorer_name}...")?;
+            metas.push(Self::collect_metadata_from_client(address, client).await?);
+        }
+        let implementation = Self::collect_implementation_metadata(implementation, client).await?;
+
+        // the other contracts are dumped to `src/<contract name>`, suffixed with their address if
+        // several contracts have the same name
+        let mut dirs = implementation.iter().map(|c| c.dir.clone()).collect::<HashSet<_>>();
+        let mut metas = metas.into_iter();
+        let meta = metas.next().expect("at least one address");
+        let others = addresses[1..]
+            .iter()
+            .zip(metas)
+            .map(|(&address, meta)| {
+                let mut dir = Path::new("src").join(&meta.contract_name);
+                if !dirs.insert(dir.clone()) {
+                    dir = Path::new("src").join(format!("{}_{address}", meta.contract_name));
+                    dirs.insert(dir.clone());
+                }
+                ScopedContract { address, meta, dir }
+            })
+            .collect();
+        Ok((meta, implementation, others))
     }
 
     /// Initialize an empty project at the root directory.
@@ -245,12 +441,17 @@ pub(crate) async fn init_an_empty_project(
     /// This function compiles the cloned contract and collects the compilation metadata.
     ///
     /// * `meta` - the metadata of the contract (from block explorer).
+    /// * `implementation` - the implementation, if the contract is a proxy cloned with
+    ///   `--follow-proxy`.
+    /// * `others` - the other contracts cloned into the project.
     /// * `chain` - the chain where the contract to be cloned locates.
     /// * `address` - the address of the contract to be cloned.
     /// * `root` - the root directory of the cloned project.
     /// * `client` - the client of the block explorer.
     pub(crate) async fn collect_compilation_metadata<C: ExplorerClient>(
         meta: &Metadata,
+        implementation: Option<&ScopedContract>,
+        others: &[ScopedContract],
         chain: Chain,
         address: Address,
         root: &PathBuf,
@@ -260,12 +461,35 @@ pub(crate) async fn collect_compilation_metadata<C: ExplorerClient>(
         let compile_output = compile_project(root)?;
         let (main_file, main_artifact) = find_main_contract(&compile_output, &meta.contract_name)?;
         let main_file = main_file.strip_prefix(root)?.to_path_buf();
-        let storage_layout =
-            main_artifact.storage_layout.clone().expect("storage layout not found");
+        let mut storage_layout = if meta.is_vyper() {
+            vyper_storage_layout(root, &main_file, &meta.contract_name)?
+        } else {
+            main_artifact.storage_layout.clone().expect("storage layout not found")
+        };
+
+        // the storage of a proxy is laid out by its implementation
+        let implementation = match implementation {
+            Some(implementation) => {
+                let (impl_file, impl_artifact) = find_contract_in_dir(
+                    &compile_output,
+                    &implementation.meta.contract_name,
+                    &root.join(&implementation.dir),
+                )?;
+                storage_layout =
+                    impl_artifact.storage_layout.clone().expect("storage layout not found");
+                Some(CloneImplementation {
+                    path: impl_file.strip_prefix(root)?.to_path_buf(),
+                    target_contract: implementation.meta.contract_name.clone(),
+                    address: implementation.address,
+                    constructor_arguments: implementation.meta.constructor_arguments.clone(),
+                })
+            }
+            None => None,
+        };
 
         // dump the metadata to the root directory
         let creation_tx = client.contract_creation_data(address).await?;
-        let clone_meta = CloneMetadata {
+        let mut clone_metas = vec![CloneMetadata {
             path: main_file,
             target_contract: meta.contract_name.clone(),
             address,
@@ -274,8 +498,30 @@ pub(crate) async fn collect_compilation_metadata<C: ExplorerClient>(
             deployer: creation_tx.contract_creator,
             constructor_arguments: meta.constructor_arguments.clone(),
             storage_layout,
-        };
-        let metadata_content = serde_json::to_string(&clone_meta)?;
+            implementation,
+            source_dir: None,
+        }];
+        for other in others {
+            let (file, artifact) = find_contract_in_dir(
+                &compile_output,
+                &other.meta.contract_name,
+                &root.join(&other.dir),
+            )?;
+            let creation_tx = client.contract_creation_data(other.address).await?;
+            clone_metas.push(CloneMetadata {
+                path: file.strip_prefix(root)?.to_path_buf(),
+                target_contract: other.meta.contract_name.clone(),
+                address: other.address,
+                chain_id: chain.id(),
+                creation_transaction: creation_tx.transaction_hash,
+                deployer: creation_tx.contract_creator,
+                constructor_arguments: other.meta.constructor_arguments.clone(),
+                storage_layout: artifact.storage_layout.clone().expect("storage layout not found"),
+                implementation: None,
+                source_dir: Some(other.dir.clone()),
+            });
+        }
+        let metadata_content = serde_json::to_string(&clone_metas)?;
         let metadata_file = root.join(".clone.meta");
         fs::write(&metadata_file, metadata_content)?;
         let mut perms = std::fs::metadata(&metadata_file)?.permissions();
@@ -287,6 +533,8 @@ pub(crate) async fn collect_compilation_metadata<C: ExplorerClient>(
 
     /// Download and parse the source code from Etherscan.
     ///
+    /// * `scoped` - the contracts cloned along with the contract: its implementation, if it is a
+    ///   proxy cloned with `--follow-proxy`, and the other contracts cloned into the project.
     /// * `chain` - the chain where the contract to be cloned locates.
     /// * `address` - the address of the contract to be cloned.
     /// * `root` - the root directory to clone the contract into as a foundry project.
@@ -294,13 +542,30 @@ pub(crate) async fn collect_compilation_metadata<C: ExplorerClient>(
     /// * `no_remappings_txt` - whether to generate the remappings.txt file.
     pub(crate) async fn parse_metadata(
         meta: &Metadata,
+        scoped: &[&ScopedContract],
         chain: Chain,
         root: &PathBuf,
         no_remappings_txt: bool,
         keep_directory_structure: bool,
     ) -> Result<()> {
+        if meta.is_vyper() {
+            dump_vyper_sources(meta, root)?;
+            Config::update_at(root, |config, doc| {
+                update_config_by_vyper_metadata(config, doc, meta, chain).is_ok()
+            })?;
+            // The Vyper version can't be pinned in the configuration.
+            sh_warn!(
+                "The contract was compiled with Vyper {}, make sure that this version is installed",
+                meta.compiler_version()?
+            )?;
+            return Ok(());
+        }
+
         // dump sources and update the remapping in configuration
-        let remappings = dump_sources(meta, root, keep_directory_structure)?;
+        let mut remappings = dump_sources(meta, root, keep_directory_structure)?;
+        for contract in scoped {
+            remappings.extend(dump_scoped_sources(contract, root)?);
+        }
         Config::update_at(root, |config, doc| {
             let profile = config.profile.as_str().as_str();
 
@@ -323,6 +588,31 @@ pub(crate) async fn parse_metadata(
             update_config_by_metadata(config, doc, meta, chain).is_ok()
         })?;
 
+        // the cloned contracts are compiled together with the settings of the first one, let the
+        // compiler version be detected if they were compiled with different versions
+        let mut auto_detect_solc = false;
+        for contract in scoped {
+            if contract.meta.compiler_version()? != meta.compiler_version()? {
+                sh_warn!(
+                    "{} was compiled with solc {}, and {} with solc {}; the solc version will be auto-detected",
+                    contract.meta.contract_name,
+                    contract.meta.compiler_version()?,
+                    meta.contract_name,
+                    meta.compiler_version()?
+                )?;
+                auto_detect_solc = true;
+            }
+        }
+        if auto_detect_solc {
+            Config::update_at(root, |config, doc| {
+                let profile = config.profile.as_str().as_str();
+                doc[Config::PROFILE_SECTION][profile]["auto_detect_solc"] = toml_edit::value(true);
+                doc[Config::PROFILE_SECTION][profile]
+                    .as_table_mut()
+                    .is_some_and(|profile| profile.remove("solc_version").is_some())
+            })?;
+        }
+
         // write remappings to remappings.txt if necessary
         if !no_remappings_txt {
             let remappings_txt = root.join("remappings.txt");
@@ -350,6 +640,380 @@ pub(crate) async fn parse_metadata(
 
         Ok(())
     }
+
+    /// Compare a cloned project with the verified source of the contract.
+    ///
+    /// The verified sources are dumped to a temporary directory the same way as when cloning, and
+    /// compared with the local ones. The storage layout of the local contract, or of its
+    /// implementation if it is a proxy, is compared with the one preserved in `.clone.meta`.
+    ///
+    /// * `address` - the address of the cloned contract.
+    /// * `root` - the root directory of the cloned project.
+    /// * `keep_directory_structure` - whether the project was cloned with
+    ///   `--keep-directory-structure`.
+    /// * `client` - the client of the block explorer.
+    pub(crate) async fn diff<C: ExplorerClient>(
+        address: Address,
+        root: &Path,
+        keep_directory_structure: bool,
+        client: &C,
+    ) -> Result<()> {
+        let clone_metas = read_clone_metadata(root)
+            .map_err(|e| eyre::eyre!("{} is not a cloned project: {e}", root.display()))?;
+        let clone_meta = clone_metas
+            .iter()
+            .find(|clone_meta| clone_meta.address == address)
+            .ok_or_else(|| eyre::eyre!("{} is not a clone of {address}", root.display()))?;
+
+        sh_println!("Downloading the source code of {address}...")?;
+        let meta = Self::collect_metadata_from_client(address, client).await?;
+        let implementation = Self::collect_implementation_metadata(
+            clone_meta.implementation.as_ref().map(|implementation| implementation.address),
+            client,
+        )
+        .await?;
+
+        // dump the verified sources the same way as when cloning
+        let verified = tempfile::tempdir()?;
+        let verified_root = verified.path().to_path_buf();
+        let is_vyper = meta.is_vyper();
+        if is_vyper {
+            dump_vyper_sources(&meta, &verified_root)?;
+        } else if let Some(dir) = clone_meta.source_dir.clone() {
+            std::fs::create_dir_all(verified_root.join("src"))?;
+            dump_scoped_sources(&ScopedContract { address, meta, dir }, &verified_root)?;
+        } else {
+            // the installed forge-std is replaced by the verified one, if any
+            std::fs::create_dir_all(verified_root.join("src"))?;
+            std::fs::create_dir_all(verified_root.join("lib").join("forge-std"))?;
+            dump_sources(&meta, &verified_root, keep_directory_structure)?;
+            if let Some(implementation) = &implementation {
+                dump_scoped_sources(implementation, &verified_root)?;
+            }
+        }
+        // the sources of the other contracts cloned into the project are not compared
+        let dir = clone_meta.source_dir.as_deref().unwrap_or(Path::new("src"));
+        let excluded = clone_metas
+            .iter()
+            .filter_map(|clone_meta| clone_meta.source_dir.as_deref())
+            .filter(|other| *other != dir)
+            .collect::<Vec<_>>();
+        let diffs = source_diffs(&verified_root, root, dir, &excluded)?;
+
+        let mut compile_output = None;
+        let (contract, storage_layout) =
+            local_storage_layout(root, clone_meta, &mut compile_output)?;
+        let changes = storage_layout_changes(&clone_meta.storage_layout, &storage_layout);
+        let compatible = changes.iter().all(|change| change.kind.is_compatible());
+
+        if shell::is_json() {
+            sh_println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "files": diffs,
+                    "storageLayout": {
+                        "contract": contract,
+                        "compatible": compatible,
+                        "changes": changes,
+                    },
+                }))?
+            )?;
+        } else {
+            if diffs.is_empty() {
+                sh_println!("No changes to the verified sources")?;
+            }
+            for diff in &diffs {
+                sh_print!("{}", diff.diff)?;
+            }
+            sh_println!()?;
+            if changes.is_empty() {
+                sh_println!("The storage layout of {contract} is unchanged")?;
+            } else {
+                sh_println!("Storage layout changes of {contract}:")?;
+                for change in &changes {
+                    sh_println!("  {change}")?;
+                }
+            }
+        }
+        eyre::ensure!(
+            compatible,
+            "the storage layout of {contract} is incompatible with the contract on chain"
+        );
+        Ok(())
+    }
+}
+
+/// Check that the storage layouts of the contracts of a cloned project are still compatible with
+/// the ones preserved in `.clone.meta`, i.e. that upgrading the contracts on chain to the local
+/// ones would not corrupt their storage.
+///
+/// The storage layout of a proxy cloned with `--follow-proxy` is the one of its implementation.
+pub fn check_clone_layout(root: &Path) -> Result<()> {
+    let clone_metas = read_clone_metadata(root)
+        .map_err(|e| eyre::eyre!("{} is not a cloned project: {e}", root.display()))?;
+
+    let mut compile_output = None;
+    let mut reports = Vec::with_capacity(clone_metas.len());
+    for clone_meta in &clone_metas {
+        let (contract, storage_layout) =
+            local_storage_layout(root, clone_meta, &mut compile_output)?;
+        let changes = storage_layout_changes(&clone_meta.storage_layout, &storage_layout);
+        let compatible = changes.iter().all(|change| change.kind.is_compatible());
+        reports.push((clone_meta.address, contract, compatible, changes));
+    }
+
+    if shell::is_json() {
+        let reports = reports
+            .iter()
+            .map(|(address, contract, compatible, changes)| {
+                serde_json::json!({
+                    "address": address,
+                    "contract": contract,
+                    "compatible": compatible,
+                    "changes": changes,
+                })
+            })
+            .collect::<Vec<_>>();
+        sh_println!("{}", serde_json::to_string_pretty(&reports)?)?;
+    } else {
+        for (address, contract, compatible, changes) in &reports {
+            let status = if *compatible { "compatible" } else { "incompatible" };
+            if changes.is_empty() {
+                sh_println!("{contract} ({address}): storage layout unchanged")?;
+                continue;
+            }
+            sh_println!("{contract} ({address}): storage layout {status}")?;
+            for change in changes {
+                sh_println!("  {change}")?;
+            }
+        }
+    }
+
+    let incompatible = reports
+        .iter()
+        .filter(|(_, _, compatible, _)| !compatible)
+        .map(|(_, contract, _, _)| contract.as_str())
+        .collect::<Vec<_>>();
+    eyre::ensure!(
+        incompatible.is_empty(),
+        "upgrading {} would corrupt the storage of the contract on chain",
+        incompatible.join(", ")
+    );
+    Ok(())
+}
+
+/// Get the storage layout of a cloned contract from the local sources, or of its implementation
+/// if it is a proxy cloned with `--follow-proxy`, along with the name of the contract.
+///
+/// Solidity projects are compiled once, the first time `compile_output` is needed.
+fn local_storage_layout(
+    root: &Path,
+    clone_meta: &CloneMetadata,
+    compile_output: &mut Option<ProjectCompileOutput>,
+) -> Result<(String, StorageLayout)> {
+    // the storage of a proxy is laid out by its implementation
+    let (path, contract) = match &clone_meta.implementation {
+        Some(implementation) => (&implementation.path, &implementation.target_contract),
+        None => (&clone_meta.path, &clone_meta.target_contract),
+    };
+    if path.extension().is_some_and(|ext| ext == "vy") {
+        return Ok((contract.clone(), vyper_storage_layout(root, path, contract)?));
+    }
+
+    let compile_output = match compile_output {
+        Some(compile_output) => compile_output,
+        None => compile_output.insert(compile_project(root)?),
+    };
+    let file = root.join(path);
+    let (_, artifact) = compile_output
+        .artifacts_with_files()
+        .find(|(f, c, _)| *c == contract && Path::new(f) == file)
+        .ok_or_else(|| eyre::eyre!("contract {contract} not found in {}", path.display()))?;
+    let storage_layout = artifact
+        .storage_layout
+        .clone()
+        .ok_or_else(|| eyre::eyre!("storage layout of {contract} not found"))?;
+    Ok((contract.clone(), storage_layout))
+}
+
+/// The status of a source file of a cloned project, compared with its verified version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+enum SourceStatus {
+    Modified,
+    Removed,
+    Added,
+}
+
+/// A source file of a cloned project which differs from its verified version.
+#[derive(Debug, serde::Serialize)]
+struct SourceDiff {
+    /// The path to the source file, relative to the root directory of the project.
+    path: PathBuf,
+    status: SourceStatus,
+    /// The unified diff from the verified version to the local one.
+    diff: String,
+}
+
+/// Compare the local sources of a cloned project with the verified ones.
+///
+/// All the verified sources are compared, and the sources added to the `dir` directory are
+/// reported as well, except for the ones in the `excluded` directories.
+fn source_diffs(
+    verified_root: &Path,
+    root: &Path,
+    dir: &Path,
+    excluded: &[&Path],
+) -> Result<Vec<SourceDiff>> {
+    let mut verified_files = Vec::new();
+    collect_files(verified_root, verified_root, &mut verified_files)?;
+    let mut local_files = Vec::new();
+    if root.join(dir).is_dir() {
+        collect_files(root, &root.join(dir), &mut local_files)?;
+    }
+    local_files.retain(|file| {
+        !verified_files.contains(file)
+            && !excluded.iter().any(|dir| file.starts_with(dir))
+            && file.extension().is_some_and(|ext| ext == "sol" || ext == "vy" || ext == "vyi")
+    });
+
+    let mut diffs = Vec::new();
+    for (file, status) in verified_files
+        .iter()
+        .map(|file| (file, SourceStatus::Modified))
+        .chain(local_files.iter().map(|file| (file, SourceStatus::Added)))
+    {
+        let verified = std::fs::read_to_string(verified_root.join(file)).unwrap_or_default();
+        let local = std::fs::read_to_string(root.join(file));
+        let status = if local.is_err() { SourceStatus::Removed } else { status };
+        let local = local.unwrap_or_default();
+        if status == SourceStatus::Modified && verified == local {
+            continue;
+        }
+        let name = file.to_string_lossy();
+        let diff = similar::TextDiff::from_lines(&verified, &local)
+            .unified_diff()
+            .header(&format!("a/{name}"), &format!("b/{name}"))
+            .to_string();
+        diffs.push(SourceDiff { path: file.clone(), status, diff });
+    }
+    diffs.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(diffs)
+}
+
+/// Collect the files of the `dir` directory recursively, relative to `base`.
+fn collect_files(base: &Path, dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(base, &path, files)?;
+        } else {
+            files.push(path.strip_prefix(base)?.to_path_buf());
+        }
+    }
+    Ok(())
+}
+
+/// The kind of a change of a storage layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+enum StorageChangeKind {
+    /// A variable was added after the original variables.
+    Appended,
+    /// An original variable was renamed.
+    Renamed,
+    /// A variable was added among the original variables.
+    Inserted,
+    /// An original variable was removed, or moved to another slot.
+    Removed,
+    /// The type of an original variable was changed.
+    Retyped,
+}
+
+impl StorageChangeKind {
+    /// Whether the change keeps the storage layout compatible with the original one.
+    fn is_compatible(self) -> bool {
+        matches!(self, Self::Appended | Self::Renamed)
+    }
+}
+
+/// A change of the storage layout of a cloned contract.
+#[derive(Debug, PartialEq, Eq, serde::Serialize)]
+struct StorageChange {
+    kind: StorageChangeKind,
+    slot: String,
+    offset: i64,
+    /// The original variable, as `<type> <label>`.
+    original: Option<String>,
+    /// The local variable, as `<type> <label>`.
+    local: Option<String>,
+}
+
+impl std::fmt::Display for StorageChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = format!("{:?}", self.kind).to_lowercase();
+        write!(f, "{kind:<8} slot {}, offset {}: ", self.slot, self.offset)?;
+        match (&self.original, &self.local) {
+            (Some(original), Some(local)) => write!(f, "{original} -> {local}"),
+            (Some(var), None) | (None, Some(var)) => write!(f, "{var}"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// Compare a storage layout with the original one.
+///
+/// Variables are matched by their slot and offset, and their types by their label and size, as
+/// the type identifiers depend on the compilation.
+fn storage_layout_changes(original: &StorageLayout, local: &StorageLayout) -> Vec<StorageChange> {
+    let position = |storage: &Storage| {
+        (U256::from_str_radix(&storage.slot, 10).unwrap_or_default(), storage.offset)
+    };
+    let ty = |layout: &StorageLayout, storage: &Storage| {
+        layout.types.get(&storage.storage_type).map_or_else(
+            || (storage.storage_type.clone(), String::new()),
+            |ty| (ty.label.clone(), ty.number_of_bytes.clone()),
+        )
+    };
+    let var = |layout: &StorageLayout, storage: &Storage| {
+        format!("{} {}", ty(layout, storage).0, storage.label)
+    };
+    let change = |kind, storage: &Storage, original: Option<String>, local: Option<String>| {
+        StorageChange { kind, slot: storage.slot.clone(), offset: storage.offset, original, local }
+    };
+
+    let mut changes = Vec::new();
+    for o in &original.storage {
+        let Some(l) = local.storage.iter().find(|l| position(l) == position(o)) else {
+            changes.push(change(StorageChangeKind::Removed, o, Some(var(original, o)), None));
+            continue;
+        };
+        let kind = if ty(original, o) != ty(local, l) {
+            StorageChangeKind::Retyped
+        } else if o.label != l.label {
+            StorageChangeKind::Renamed
+        } else {
+            continue;
+        };
+        changes.push(change(kind, o, Some(var(original, o)), Some(var(local, l))));
+    }
+
+    let end = original.storage.iter().map(position).max();
+    for l in &local.storage {
+        if original.storage.iter().any(|o| position(o) == position(l)) {
+            continue;
+        }
+        let kind = if end.is_none_or(|end| position(l) > end) {
+            StorageChangeKind::Appended
+        } else {
+            StorageChangeKind::Inserted
+        };
+        changes.push(change(kind, l, None, Some(var(local, l))));
+    }
+    changes.sort_by_key(|change| {
+        (U256::from_str_radix(&change.slot, 10).unwrap_or_default(), change.offset)
+    });
+    changes
 }
 
 /// Update the configuration file with the metadata.
@@ -479,6 +1143,140 @@ macro_rules! update_if_needed {
     Ok(())
 }
 
+/// Update the configuration file with the metadata of a Vyper contract.
+/// It will update the following fields:
+/// - `chain_id` to the chain of the contract
+/// - `evm_version` to the value from the metadata, unless it is "Default"
+/// - `vyper.optimize` to `gas` if the optimizer was used, `none` otherwise
+fn update_config_by_vyper_metadata(
+    config: &Config,
+    doc: &mut toml_edit::DocumentMut,
+    meta: &Metadata,
+    chain: Chain,
+) -> Result<()> {
+    let profile = config.profile.as_str().as_str();
+
+    doc[Config::PROFILE_SECTION][profile]["chain_id"] = toml_edit::value(chain.id() as i64);
+    if !meta.evm_version.is_empty() && !meta.evm_version.eq_ignore_ascii_case("default") {
+        doc[Config::PROFILE_SECTION][profile]["evm_version"] =
+            toml_edit::value(meta.evm_version.to_lowercase());
+    }
+
+    let optimize = if meta.optimization_used == 1 { "gas" } else { "none" };
+    doc[Config::PROFILE_SECTION][profile]["vyper"] = toml_edit::table();
+    doc[Config::PROFILE_SECTION][profile]["vyper"]["optimize"] = toml_edit::value(optimize);
+
+    Ok(())
+}
+
+/// Dump the sources of a Vyper contract to the `src` directory, keeping their directory
+/// structure.
+/// Single-file sources have no file name: they are dumped to `src/<contract name>.vy`.
+fn dump_vyper_sources(meta: &Metadata, root: &Path) -> Result<()> {
+    let src_dir = root.join("src");
+    let contract_dir = Path::new(&meta.contract_name);
+    for entry in meta.source_tree().entries {
+        let path = entry.path.strip_prefix(contract_dir).unwrap_or(&entry.path);
+        let is_vyper = path.extension().is_some_and(|ext| ext == "vy" || ext == "vyi");
+        let path = if is_vyper {
+            // drop the root and parent components, to stay in the `src` directory
+            path.components().filter(|c| matches!(c, Component::Normal(_))).collect()
+        } else {
+            PathBuf::from(format!("{}.vy", meta.contract_name))
+        };
+        let dest = src_dir.join(path);
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&dest, entry.contents)?;
+    }
+    Ok(())
+}
+
+/// Get the storage layout of a Vyper contract with `vyper -f layout`, converted to the storage
+/// layout format of solc.
+fn vyper_storage_layout(root: &Path, file: &Path, contract: &str) -> Result<StorageLayout> {
+    let config = Config::load_with_root(root)?;
+    let vyper = config.vyper.path.unwrap_or_else(|| PathBuf::from("vyper"));
+    let output = Command::new(&vyper)
+        .current_dir(root)
+        .args(["-f", "layout"])
+        .arg(file)
+        .output()
+        .map_err(|e| eyre::eyre!("failed to run {}: {e}", vyper.display()))?;
+    eyre::ensure!(
+        output.status.success(),
+        "failed to get the storage layout of {}: {}",
+        file.display(),
+        String::from_utf8_lossy(&output.stderr).trim()
+    );
+    let layout: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    convert_vyper_storage_layout(&layout, &format!("{}:{contract}", file.display()))
+}
+
+/// Convert a Vyper storage layout to the storage layout format of solc.
+///
+/// Vyper reports the slot and type of each variable, nesting the variables of imported modules.
+/// Nested variables are labeled `<module>.<variable>`.
+fn convert_vyper_storage_layout(
+    layout: &serde_json::Value,
+    contract: &str,
+) -> Result<StorageLayout> {
+    let layout = layout.get("storage_layout").unwrap_or(layout);
+    let mut storage = Vec::new();
+    let mut types = serde_json::Map::new();
+    collect_vyper_storage(layout, "", contract, &mut storage, &mut types);
+    storage.sort_by_key(|(slot, _)| *slot);
+    let storage = storage.into_iter().map(|(_, var)| var).collect::<Vec<_>>();
+    Ok(serde_json::from_value(serde_json::json!({ "storage": storage, "types": types }))?)
+}
+
+fn collect_vyper_storage(
+    layout: &serde_json::Value,
+    prefix: &str,
+    contract: &str,
+    storage: &mut Vec<(u64, serde_json::Value)>,
+    types: &mut serde_json::Map<String, serde_json::Value>,
+) {
+    let Some(vars) = layout.as_object() else { return };
+    for (name, var) in vars {
+        let label = format!("{prefix}{name}");
+        let slot = var.get("slot").and_then(|slot| slot.as_u64());
+        let Some((slot, ty)) = slot.zip(var.get("type").and_then(|ty| ty.as_str())) else {
+            collect_vyper_storage(var, &format!("{label}."), contract, storage, types);
+            continue;
+        };
+        // Vyper < 0.3 also lists the code variables.
+        if var.get("location").and_then(|l| l.as_str()).is_some_and(|l| l != "storage") {
+            continue;
+        }
+
+        let n_slots = var.get("n_slots").and_then(|n| n.as_u64()).unwrap_or(1);
+        let type_id = format!(
+            "t_{}",
+            ty.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect::<String>()
+        );
+        types.entry(type_id.clone()).or_insert_with(|| {
+            serde_json::json!({
+                "encoding": "inplace",
+                "label": ty,
+                "numberOfBytes": (n_slots * 32).to_string(),
+            })
+        });
+        storage.push((
+            slot,
+            serde_json::json!({
+                "astId": 0,
+                "contract": contract,
+                "label": label,
+                "offset": 0,
+                "slot": slot.to_string(),
+                "type": type_id,
+            }),
+        ));
+    }
+}
+
 /// Dump the contract sources to the root directory.
 /// The sources are dumped to the `src` directory.
 /// IO errors may be returned.
@@ -627,6 +1425,122 @@ fn dump_sources(meta: &Metadata, root: &PathBuf, no_reorg: bool) -> Result<Vec<R
     Ok(remappings.into_iter().map(|r| r.into_relative(root)).collect())
 }
 
+/// Dump the sources of a contract cloned along with the main one to its own directory, keeping
+/// their directory structure.
+/// The returned remappings are scoped to this directory, so that the imports of the contract are
+/// not resolved to the sources of the other cloned contracts.
+fn dump_scoped_sources(contract: &ScopedContract, root: &Path) -> Result<Vec<RelativeRemapping>> {
+    let meta = &contract.meta;
+    let contract_name = &meta.contract_name;
+    let dest = root.join(&contract.dir);
+    eyre::ensure!(!Path::exists(&dest), "destination already exists: {:?}", dest);
+
+    let tmp_dump_dir = root.join("raw_scoped_sources");
+    meta.source_tree()
+        .write_to(&tmp_dump_dir)
+        .map_err(|e| eyre::eyre!("failed to dump sources: {}", e))?;
+    std::fs::rename(tmp_dump_dir.join(contract_name), &dest)?;
+    std::fs::remove_dir_all(tmp_dump_dir)?;
+
+    let context = format!("{}/", dest.to_string_lossy());
+    let mut remappings = Vec::new();
+    for entry in read_dir(&dest)? {
+        let folder_name = entry?.file_name();
+        remappings.push(Remapping {
+            context: Some(context.clone()),
+            name: folder_name.to_string_lossy().to_string(),
+            path: dest.join(&folder_name).to_string_lossy().to_string(),
+        });
+    }
+    for mut r in meta.settings()?.remappings {
+        r.context = Some(context.clone());
+        r.path = dest.join(&r.path).to_string_lossy().to_string();
+        remappings.push(r);
+    }
+
+    Ok(remappings.into_iter().map(|r| r.into_relative(root)).collect())
+}
+
+/// The EIP-1967 implementation slot: `keccak256('eip1967.proxy.implementation') - 1`.
+const EIP1967_IMPLEMENTATION_SLOT: B256 =
+    b256!("0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc");
+
+/// The EIP-1967 beacon slot: `keccak256('eip1967.proxy.beacon') - 1`.
+const EIP1967_BEACON_SLOT: B256 =
+    b256!("0xa3f0ad74e5423aebfd80d3ef4346578335a9a72aeaee59ff6cb3582b35133d50");
+
+/// Read the implementation of an EIP-1967 proxy, either from its implementation slot or from the
+/// `implementation()` function of its beacon.
+async fn proxy_implementation<P: Provider<AnyNetwork>>(
+    provider: &P,
+    proxy: Address,
+) -> Result<Address> {
+    let read_slot = async |slot: B256| -> Result<Address> {
+        let value = provider.get_storage_at(proxy, slot.into()).await?;
+        Ok(Address::from_word(value.into()))
+    };
+
+    let implementation = read_slot(EIP1967_IMPLEMENTATION_SLOT).await?;
+    if !implementation.is_zero() {
+        return Ok(implementation);
+    }
+
+    let beacon = read_slot(EIP1967_BEACON_SLOT).await?;
+    eyre::ensure!(
+        !beacon.is_zero(),
+        "{proxy} is not an EIP-1967 proxy: its implementation and beacon slots are empty"
+    );
+    // `implementation()`
+    let tx = <AnyNetwork as Network>::TransactionRequest::default()
+        .with_to(beacon)
+        .with_input(hex!("5c60da1b"));
+    let output = provider.call(tx).await?;
+    eyre::ensure!(
+        output.len() == 32,
+        "the beacon {beacon} of {proxy} returned an invalid implementation"
+    );
+    Ok(Address::from_word(B256::from_slice(&output)))
+}
+
+/// Read the `.clone.meta` file of a cloned project, with an entry per cloned contract.
+///
+/// Projects cloned before several contracts could be cloned together have a single entry, which
+/// is not in an array.
+pub fn read_clone_metadata(root: &Path) -> Result<Vec<CloneMetadata>> {
+    let content: serde_json::Value = fs::read_json_file(&root.join(".clone.meta"))?;
+    Ok(if content.is_array() {
+        serde_json::from_value(content)?
+    } else {
+        vec![serde_json::from_value(content)?]
+    })
+}
+
+/// Split the positional arguments of `forge clone` into the addresses to clone and the root
+/// directory of the project.
+///
+/// Without `--root`, the last argument is the root directory if it is not an address.
+fn parse_targets(args: &[String], root: Option<PathBuf>) -> Result<(Vec<Address>, PathBuf)> {
+    let mut args = args;
+    let root = match root {
+        Some(root) => root,
+        None => match args.split_last() {
+            Some((last, rest)) if !rest.is_empty() && last.parse::<Address>().is_err() => {
+                args = rest;
+                PathBuf::from(last)
+            }
+            _ => PathBuf::from("."),
+        },
+    };
+    let mut addresses = Vec::with_capacity(args.len());
+    for arg in args {
+        let address =
+            arg.parse::<Address>().map_err(|e| eyre::eyre!("invalid address `{arg}`: {e}"))?;
+        eyre::ensure!(!addresses.contains(&address), "{address} is cloned more than once");
+        addresses.push(address);
+    }
+    Ok((addresses, root))
+}
+
 /// Compile the project in the root directory, and return the compilation result.
 pub fn compile_project(root: &Path) -> Result<ProjectCompileOutput> {
     let mut config = Config::load_with_root(root)?.sanitized();
@@ -657,6 +1571,34 @@ pub fn find_main_contract<'a>(
     rv.ok_or_else(|| eyre::eyre!("contract not found"))
 }
 
+/// Suggests cloning from Sourcify if the contract is not verified on Etherscan.
+fn suggest_sourcify(err: eyre::Report, source: SourceExplorer) -> eyre::Report {
+    if matches!(source, SourceExplorer::Etherscan)
+        && matches!(err.downcast_ref(), Some(EtherscanError::ContractCodeNotVerified(_)))
+    {
+        err.wrap_err(
+            "The contract is not verified on Etherscan, pass `--source sourcify` to clone it from \
+             Sourcify instead",
+        )
+    } else {
+        err
+    }
+}
+
+/// Find the artifact of the contract with the specified name, declared in a file of the `dir`
+/// directory.
+fn find_contract_in_dir<'a>(
+    compile_output: &'a ProjectCompileOutput,
+    contract: &str,
+    dir: &Path,
+) -> Result<(PathBuf, &'a ConfigurableContractArtifact)> {
+    compile_output
+        .artifacts_with_files()
+        .find(|(f, c, _)| *c == contract && Path::new(f).starts_with(dir))
+        .map(|(f, _, a)| (PathBuf::from(f), a))
+        .ok_or_else(|| eyre::eyre!("contract {contract} not found in {}", dir.display()))
+}
+
 /// ExplorerClient is a trait that defines the methods to interact with block explorers.
 /// It is defined as a wrapper of the `foundry_block_explorers::Client` to allow mocking.
 #[cfg_attr(test, mockall::automock)]
@@ -671,19 +1613,142 @@ async fn contract_creation_data(
     ) -> std::result::Result<ContractCreationData, EtherscanError>;
 }
 
-impl ExplorerClient for Client {
+/// MetadataProvider is the source of the verified metadata of the cloned contracts, selected with
+/// `--source`.
+pub(crate) enum MetadataProvider {
+    /// Etherscan, or the Etherscan compatible API of a Blockscout instance.
+    Etherscan(EtherscanClient),
+    /// The full and partial matches of the Sourcify repository.
+    Sourcify(SourcifyClient),
+}
+
+impl MetadataProvider {
+    /// Create the provider of `source`, at `explorer_url` or `sourcify_url` if set.
+    pub fn new(
+        config: &Config,
+        chain: Chain,
+        source: SourceExplorer,
+        explorer_url: Option<String>,
+        sourcify_url: Option<String>,
+    ) -> Result<Self> {
+        Ok(match source {
+            SourceExplorer::Etherscan | SourceExplorer::Blockscout => {
+                Self::Etherscan(EtherscanClient::for_explorer(config, chain, source, explorer_url)?)
+            }
+            SourceExplorer::Sourcify => {
+                Self::Sourcify(SourcifyClient::with_url(chain, sourcify_url.as_deref()))
+            }
+        })
+    }
+}
+
+impl MetadataProvider {
+    /// Returns `true` if the provider is an Etherscan compatible API used without an API key, and
+    /// is therefore subject to stricter rate limits.
+    pub fn is_keyless(&self) -> bool {
+        matches!(self, Self::Etherscan(client) if client.keyless)
+    }
+}
+
+impl ExplorerClient for MetadataProvider {
     async fn contract_source_code(
         &self,
         address: Address,
     ) -> std::result::Result<ContractMetadata, EtherscanError> {
-        self.contract_source_code(address).await
+        match self {
+            Self::Etherscan(client) => client.contract_source_code(address).await,
+            Self::Sourcify(client) => client.contract_source_code(address).await,
+        }
     }
 
     async fn contract_creation_data(
         &self,
         address: Address,
     ) -> std::result::Result<ContractCreationData, EtherscanError> {
-        self.contract_creation_data(address).await
+        match self {
+            Self::Etherscan(client) => client.contract_creation_data(address).await,
+            Self::Sourcify(client) => client.contract_creation_data(address).await,
+        }
+    }
+}
+
+/// EtherscanClient wraps the `foundry_block_explorers::Client` so that every request respects the
+/// request budget and backoff settings configured for the chain.
+pub(crate) struct EtherscanClient {
+    client: Client,
+    throttle: EtherscanThrottle,
+    /// Whether the client has no API key.
+    keyless: bool,
+}
+
+impl EtherscanClient {
+    pub fn new(config: &Config, chain: Chain) -> Result<Self> {
+        let etherscan_config =
+            config.get_etherscan_config_with_chain(Some(chain))?.ok_or_else(|| {
+                eyre::eyre!(
+                    "No Etherscan API key configured for chain {chain}, pass `--source sourcify` \
+                     to clone from Sourcify instead"
+                )
+            })?;
+        let throttle = EtherscanThrottle::new(chain, etherscan_config.limits);
+        let keyless = etherscan_config.key.is_empty();
+        Ok(Self { client: etherscan_config.into_client()?, throttle, keyless })
+    }
+
+    /// Create a client for the Etherscan compatible API at `url`, e.g. the one of a Blockscout
+    /// instance, with the API key configured for the chain, if any.
+    ///
+    /// The `/api` path is appended to the URL if missing.
+    pub fn with_url(config: &Config, chain: Chain, url: &str) -> Result<Self> {
+        // an API key is optional for most Etherscan compatible explorers
+        let etherscan_config = config.get_etherscan_config_with_chain(Some(chain)).ok().flatten();
+        let url = url.trim_end_matches('/');
+        let (api_url, browser_url) = match url.strip_suffix("/api") {
+            Some(browser_url) => (url.to_string(), browser_url),
+            None => (format!("{url}/api"), url),
+        };
+        let key = etherscan_config.as_ref().map(|c| c.key.clone()).unwrap_or_default();
+        let keyless = key.is_empty();
+        let client = Client::builder()
+            .with_api_url(api_url.as_str())?
+            .with_url(browser_url)?
+            .with_api_key(key)
+            .build()?;
+        let limits = etherscan_config.map(|c| c.limits).unwrap_or_default();
+        Ok(Self { client, throttle: EtherscanThrottle::new(chain, limits), keyless })
+    }
+
+    /// Create a client for an Etherscan compatible `explorer`, at `explorer_url` if set.
+    pub fn for_explorer(
+        config: &Config,
+        chain: Chain,
+        explorer: SourceExplorer,
+        explorer_url: Option<String>,
+    ) -> Result<Self> {
+        match (explorer, explorer_url) {
+            (_, Some(url)) => Self::with_url(config, chain, &url),
+            (SourceExplorer::Blockscout, None) => eyre::bail!(
+                "Please provide the URL of the Blockscout instance using `--explorer-url`, e.g. \
+                 https://eth.blockscout.com"
+            ),
+            _ => Self::new(config, chain),
+        }
+    }
+}
+
+impl ExplorerClient for EtherscanClient {
+    async fn contract_source_code(
+        &self,
+        address: Address,
+    ) -> std::result::Result<ContractMetadata, EtherscanError> {
+        self.throttle.run(|| self.client.contract_source_code(address)).await
+    }
+
+    async fn contract_creation_data(
+        &self,
+        address: Address,
+    ) -> std::result::Result<ContractCreationData, EtherscanError> {
+        self.throttle.run(|| self.client.contract_creation_data(address)).await
     }
 }
 
@@ -1036,8 +2101,7 @@ fn assert_compilation_result(
         }
     }
 
-    fn mock_etherscan(address: Address) -> impl super::ExplorerClient {
-        // load mock data
+    fn mock_data() -> BTreeMap<Address, (ContractMetadata, ContractCreationData)> {
         let mut mocked_data = BTreeMap::new();
         let data_folder =
             PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../testdata/etherscan");
@@ -1059,7 +2123,11 @@ fn mock_etherscan(address: Address) -> impl super::ExplorerClient {
             // insert the data to the map
             mocked_data.insert(addr, (metadata, creation_data));
         }
+        mocked_data
+    }
 
+    fn mock_etherscan(address: Address) -> impl super::ExplorerClient {
+        let mocked_data = mock_data();
         let (metadata, creation_data) = mocked_data.get(&address).unwrap();
         let metadata = metadata.clone();
         let creation_data = *creation_data;
@@ -1075,6 +2143,25 @@ fn mock_etherscan(address: Address) -> impl super::ExplorerClient {
         mocked_client
     }
 
+    /// Mocks a block explorer serving each of `addresses` once.
+    fn mock_etherscan_contracts(addresses: &[Address]) -> impl super::ExplorerClient {
+        let mocked_data = mock_data();
+        let mocked_data = addresses
+            .iter()
+            .map(|address| (*address, mocked_data[address].clone()))
+            .collect::<BTreeMap<_, _>>();
+        let mut mocked_client = super::MockExplorerClient::new();
+        let metadata = mocked_data.clone();
+        mocked_client
+            .expect_contract_source_code()
+            .times(addresses.len())
+            .returning(move |address| Ok(metadata[&address].0.clone()));
+        mocked_client
+            .expect_contract_creation_data()
+            .returning(move |address| Ok(mocked_data[&address].1));
+        mocked_client
+    }
+
     /// Fetch the metadata and creation data from Etherscan and dump them to the testdata folder.
     #[tokio::test(flavor = "multi_thread")]
     #[ignore = "this test is used to dump mock data from Etherscan"]
@@ -1109,11 +2196,13 @@ async fn one_test_case(address: Address, check_compilation_result: bool) {
             .await
             .unwrap();
         project_root = dunce::canonicalize(&project_root).unwrap();
-        CloneArgs::parse_metadata(&meta, Chain::mainnet(), &project_root, false, false)
+        CloneArgs::parse_metadata(&meta, &[], Chain::mainnet(), &project_root, false, false)
             .await
             .unwrap();
         CloneArgs::collect_compilation_metadata(
             &meta,
+            None,
+            &[],
             Chain::mainnet(),
             address,
             &project_root,
@@ -1129,6 +2218,176 @@ async fn one_test_case(address: Address, check_compilation_result: bool) {
         }
     }
 
+    #[test]
+    fn test_convert_vyper_storage_layout() {
+        let layout = serde_json::json!({
+            "storage_layout": {
+                "owner": { "type": "address", "n_slots": 1, "slot": 1 },
+                "balances": { "type": "HashMap[address, uint256]", "n_slots": 1, "slot": 2 },
+                "ownable": {
+                    "pending_owner": { "type": "address", "n_slots": 1, "slot": 0 }
+                }
+            }
+        });
+        let layout = convert_vyper_storage_layout(&layout, "src/Token.vy:Token").unwrap();
+        let labels = layout.storage.iter().map(|s| s.label.as_str()).collect::<Vec<_>>();
+        assert_eq!(labels, ["ownable.pending_owner", "owner", "balances"]);
+        assert_eq!(layout.storage[2].storage_type, "t_HashMap_address__uint256_");
+        assert_eq!(layout.types["t_address"].label, "address");
+    }
+
+    #[test]
+    fn test_parse_targets() {
+        let a = "0x35Fb958109b70799a8f9Bc2a8b1Ee4cC62034193";
+        let b = "0x9d27527Ada2CF29fBDAB2973cfa243845a08Bd3F";
+        let args = |args: &[&str]| args.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        let (addresses, root) = parse_targets(&args(&[a, "project"]), None).unwrap();
+        assert_eq!(addresses, [a.parse::<Address>().unwrap()]);
+        assert_eq!(root, PathBuf::from("project"));
+
+        let (addresses, root) =
+            parse_targets(&args(&[a, b]), Some(PathBuf::from("project"))).unwrap();
+        assert_eq!(addresses.len(), 2);
+        assert_eq!(root, PathBuf::from("project"));
+
+        let (addresses, root) = parse_targets(&args(&[a, b]), None).unwrap();
+        assert_eq!(addresses.len(), 2);
+        assert_eq!(root, PathBuf::from("."));
+
+        assert!(parse_targets(&args(&["project"]), None).is_err());
+        assert!(parse_targets(&args(&[a, "project"]), Some(PathBuf::from("root"))).is_err());
+        assert!(parse_targets(&args(&[a, a]), None).is_err());
+    }
+
+    #[test]
+    fn test_explorer_client_url() {
+        let config = Config::default();
+        let chain = Chain::from_id(100);
+        for url in ["https://gnosis.blockscout.com", "https://gnosis.blockscout.com/api/"] {
+            let client = EtherscanClient::with_url(&config, chain, url).unwrap();
+            assert_eq!(
+                client.client.etherscan_api_url().as_str(),
+                "https://gnosis.blockscout.com/api"
+            );
+        }
+        assert!(
+            EtherscanClient::for_explorer(&config, chain, SourceExplorer::Blockscout, None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_metadata_provider() {
+        let config = Config::default();
+        let chain = Chain::from_id(100);
+        let provider = MetadataProvider::new(
+            &config,
+            chain,
+            SourceExplorer::Sourcify,
+            None,
+            Some("https://sourcify.example/server".to_string()),
+        )
+        .unwrap();
+        assert!(matches!(provider, MetadataProvider::Sourcify(_)));
+        let provider = MetadataProvider::new(
+            &config,
+            chain,
+            SourceExplorer::Blockscout,
+            Some("https://gnosis.blockscout.com".to_string()),
+            None,
+        )
+        .unwrap();
+        assert!(matches!(provider, MetadataProvider::Etherscan(_)));
+        assert!(
+            MetadataProvider::new(&config, chain, SourceExplorer::Blockscout, None, None).is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_suggest_sourcify() {
+        let address = Address::repeat_byte(0x11);
+        let mut client = super::MockExplorerClient::new();
+        client
+            .expect_contract_source_code()
+            .returning(|address| Err(EtherscanError::ContractCodeNotVerified(address)));
+
+        for (source, hinted) in [
+            (SourceExplorer::Etherscan, true),
+            (SourceExplorer::Blockscout, false),
+            (SourceExplorer::Sourcify, false),
+        ] {
+            let err = CloneArgs::collect_all_metadata(&[address], None, &client, source.name())
+                .await
+                .unwrap_err();
+            let err = suggest_sourcify(err, source);
+            assert_eq!(err.to_string().contains("--source sourcify"), hinted, "{source:?}");
+            assert!(matches!(
+                err.downcast_ref(),
+                Some(EtherscanError::ContractCodeNotVerified(a)) if *a == address
+            ));
+        }
+
+        let err = suggest_sourcify(eyre::eyre!("rate limited"), SourceExplorer::Etherscan);
+        assert_eq!(err.to_string(), "rate limited");
+    }
+
+    #[test]
+    fn test_storage_layout_changes() {
+        let layout = |vars: &[(&str, u64, i64, &str)]| -> StorageLayout {
+            let storage = vars
+                .iter()
+                .map(|(label, slot, offset, ty)| {
+                    serde_json::json!({
+                        "astId": 0,
+                        "contract": "src/Vault.sol:Vault",
+                        "label": label,
+                        "offset": offset,
+                        "slot": slot.to_string(),
+                        "type": ty,
+                    })
+                })
+                .collect::<Vec<_>>();
+            serde_json::from_value(serde_json::json!({
+                "storage": storage,
+                "types": {
+                    "t_address": { "encoding": "inplace", "label": "address", "numberOfBytes": "20" },
+                    "t_uint256": { "encoding": "inplace", "label": "uint256", "numberOfBytes": "32" },
+                },
+            }))
+            .unwrap()
+        };
+        let original = layout(&[
+            ("owner", 0, 0, "t_address"),
+            ("total", 1, 0, "t_uint256"),
+            ("cap", 2, 0, "t_uint256"),
+        ]);
+        let local = layout(&[
+            ("admin", 0, 0, "t_address"),
+            ("paused", 0, 20, "t_address"),
+            ("total", 1, 0, "t_address"),
+            ("extra", 3, 0, "t_uint256"),
+        ]);
+
+        let changes = storage_layout_changes(&original, &local);
+        let kinds = changes.iter().map(|change| change.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                StorageChangeKind::Renamed,
+                StorageChangeKind::Inserted,
+                StorageChangeKind::Retyped,
+                StorageChangeKind::Removed,
+                StorageChangeKind::Appended,
+            ]
+        );
+        assert_eq!(
+            changes[2].to_string(),
+            "retyped  slot 1, offset 0: uint256 total -> address total"
+        );
+        assert!(storage_layout_changes(&original, &original).is_empty());
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_clone_single_file_contract() {
         let address = "0x35Fb958109b70799a8f9Bc2a8b1Ee4cC62034193".parse().unwrap();
@@ -1177,6 +2436,85 @@ async fn test_clone_contract_with_nested_src() {
         one_test_case(address, false).await
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_clone_follow_proxy() {
+        let proxy: Address = "0x9d27527Ada2CF29fBDAB2973cfa243845a08Bd3F".parse().unwrap();
+        let implementation: Address = "0x71356E37e0368Bd10bFDbF41dC052fE5FA24cD05".parse().unwrap();
+
+        // the proxy stores its implementation in the EIP-1967 implementation slot
+        let (api, handle) = anvil::spawn(anvil::NodeConfig::test()).await;
+        api.anvil_set_storage_at(
+            proxy,
+            EIP1967_IMPLEMENTATION_SLOT.into(),
+            implementation.into_word(),
+        )
+        .await
+        .unwrap();
+        let resolved = proxy_implementation(&handle.http_provider(), proxy).await.unwrap();
+        assert_eq!(resolved, implementation);
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut project_root = temp_dir.path().to_path_buf();
+        let client = mock_etherscan_contracts(&[proxy, implementation]);
+        let (meta, implementation, others) =
+            CloneArgs::collect_all_metadata(&[proxy], Some(resolved), &client, "Etherscan")
+                .await
+                .unwrap();
+        let implementation = implementation.expect("implementation not collected");
+        assert!(others.is_empty());
+        assert_eq!(implementation.dir, Path::new("src").join(&implementation.meta.contract_name));
+
+        CloneArgs::init_an_empty_project(&project_root, DependencyInstallOpts::default())
+            .await
+            .unwrap();
+        project_root = dunce::canonicalize(&project_root).unwrap();
+        CloneArgs::parse_metadata(
+            &meta,
+            &[&implementation],
+            Chain::mainnet(),
+            &project_root,
+            false,
+            false,
+        )
+        .await
+        .unwrap();
+        CloneArgs::collect_compilation_metadata(
+            &meta,
+            Some(&implementation),
+            &[],
+            Chain::mainnet(),
+            proxy,
+            &project_root,
+            &client,
+        )
+        .await
+        .unwrap();
+
+        // the sources of the implementation are dumped into their own directory
+        let impl_dir = project_root.join(&implementation.dir);
+        let contract_dir = Path::new(&implementation.meta.contract_name);
+        for entry in implementation.meta.source_tree().entries {
+            let path = impl_dir.join(entry.path.strip_prefix(contract_dir).unwrap());
+            assert_eq!(std::fs::read_to_string(&path).unwrap(), entry.contents);
+        }
+
+        // the metadata of the proxy records its implementation and its storage layout
+        let clone_metas = read_clone_metadata(&project_root).unwrap();
+        assert_eq!(clone_metas.len(), 1);
+        let clone_meta = &clone_metas[0];
+        assert_eq!(clone_meta.address, proxy);
+        assert_eq!(clone_meta.target_contract, meta.contract_name);
+        let cloned = clone_meta.implementation.as_ref().expect("implementation not recorded");
+        assert_eq!(cloned.address, resolved);
+        assert_eq!(cloned.target_contract, implementation.meta.contract_name);
+        assert!(cloned.path.starts_with(&implementation.dir));
+
+        let output = assert_successful_compilation(&project_root);
+        let (_, artifact) =
+            find_contract_in_dir(&output, &cloned.target_contract, &impl_dir).unwrap();
+        assert_eq!(artifact.storage_layout.as_ref(), Some(&clone_meta.storage_layout));
+    }
+
     fn pick_creation_info(address: &str) -> Option<(&'static str, &'static str)> {
         for (addr, contract_name, creation_code) in &CREATION_ARRAY {
             if address == *addr {
@@ -0,0 +1,307 @@
+//! `forge daemon`: a long-running server for editors, compiling the project and running single
+//! tests on demand.
+//!
+//! Clients connect to a Unix socket and exchange JSON-RPC 2.0 messages, one per line:
+//! - `compile`: compiles the project, reusing the compilation cache, and returns the compiler
+//!   errors.
+//! - `listTests`: returns the test inventory, as printed by `forge test --list --json`.
+//! - `runTest`: runs a single test, given its `test` name and optionally its `contract` and `path`,
+//!   and returns its result along with its decoded traces.
+//! - `shutdown`: stops the daemon.
+//!
+//! Connections are served one at a time.
+
+use crate::{
+    cmd::test::{self, TestArgs, strip_ansi},
+    decode::decode_console_logs,
+    traces::{TraceKind, decode_trace_arena, render_trace_arena_inner},
+};
+use clap::{Parser, ValueHint};
+use eyre::Result;
+use foundry_common::EmptyTestFilter;
+use foundry_compilers::ProjectCompileOutput;
+use foundry_config::{Config, find_project_root};
+use serde_json::{Value, json};
+use std::{
+    io::{BufRead, Write},
+    path::{Path, PathBuf},
+};
+use tokio::runtime::Runtime;
+
+/// The name of the default socket, in the cache directory of the project.
+const SOCKET_NAME: &str = "forge-daemon.sock";
+
+/// JSON-RPC error codes.
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+
+/// CLI arguments for `forge daemon`.
+#[derive(Clone, Debug, Parser)]
+pub struct DaemonArgs {
+    /// The path of the socket to listen on.
+    ///
+    /// Defaults to `forge-daemon.sock` in the cache directory of the project.
+    #[arg(long, value_hint = ValueHint::FilePath, value_name = "PATH")]
+    pub socket: Option<PathBuf>,
+
+    /// The project's root path.
+    ///
+    /// By default root of the Git repository, if in one,
+    /// or the current working directory.
+    #[arg(long, value_hint = ValueHint::DirPath, value_name = "PATH")]
+    pub root: Option<PathBuf>,
+}
+
+impl DaemonArgs {
+    pub fn run(self, runtime: Runtime) -> Result<()> {
+        let root = match self.root {
+            Some(root) => dunce::canonicalize(root)?,
+            None => find_project_root(None)?,
+        };
+        let socket = match self.socket {
+            Some(socket) => socket,
+            None => root.join(Config::load_with_root(&root)?.cache_path).join(SOCKET_NAME),
+        };
+        Daemon { root, runtime }.serve(&socket)
+    }
+}
+
+/// An error response to a request.
+struct ResponseError {
+    code: i64,
+    message: String,
+}
+
+impl ResponseError {
+    fn invalid_params(message: &str) -> Self {
+        Self { code: INVALID_PARAMS, message: format!("invalid params: {message}") }
+    }
+}
+
+impl From<eyre::Report> for ResponseError {
+    fn from(err: eyre::Report) -> Self {
+        Self { code: INTERNAL_ERROR, message: format!("{err:#}") }
+    }
+}
+
+struct Daemon {
+    root: PathBuf,
+    runtime: Runtime,
+}
+
+impl Daemon {
+    #[cfg(unix)]
+    fn serve(&self, socket: &Path) -> Result<()> {
+        use std::os::unix::net::{UnixListener, UnixStream};
+
+        if socket.exists() {
+            eyre::ensure!(
+                UnixStream::connect(socket).is_err(),
+                "a daemon is already listening on {}",
+                socket.display()
+            );
+            // Left over by a daemon which didn't shut down.
+            std::fs::remove_file(socket)?;
+        }
+        if let Some(parent) = socket.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let listener = UnixListener::bind(socket)?;
+        sh_println!("Listening on {}", socket.display())?;
+
+        let result = (|| -> Result<()> {
+            for stream in listener.incoming() {
+                let stream = stream?;
+                let reader = std::io::BufReader::new(stream.try_clone()?);
+                match self.handle_connection(reader, stream) {
+                    Ok(true) => break,
+                    Ok(false) => {}
+                    Err(err) => sh_warn!("connection closed: {err}")?,
+                }
+            }
+            Ok(())
+        })();
+        let _ = std::fs::remove_file(socket);
+        result
+    }
+
+    #[cfg(not(unix))]
+    fn serve(&self, _socket: &Path) -> Result<()> {
+        eyre::bail!("`forge daemon` is only supported on Unix platforms")
+    }
+
+    /// Serves the requests of a connection, returning whether the daemon was shut down.
+    fn handle_connection(&self, reader: impl BufRead, mut writer: impl Write) -> Result<bool> {
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (id, method, result) = match serde_json::from_str::<Value>(&line) {
+                Ok(request) => {
+                    let id = request.get("id").cloned().unwrap_or(Value::Null);
+                    let method = request.get("method").and_then(Value::as_str).unwrap_or_default();
+                    let params = request.get("params").cloned().unwrap_or(Value::Null);
+                    (id, method.to_string(), self.handle_request(method, &params))
+                }
+                Err(err) => (
+                    Value::Null,
+                    String::new(),
+                    Err(ResponseError { code: PARSE_ERROR, message: err.to_string() }),
+                ),
+            };
+            let response = match result {
+                Ok(result) => json!({"jsonrpc": "2.0", "id": id, "result": result}),
+                Err(err) => json!({
+                    "jsonrpc": "2.0",
+                    "id": id,
+                    "error": {"code": err.code, "message": err.message},
+                }),
+            };
+            writeln!(writer, "{}", serde_json::to_string(&response)?)?;
+            writer.flush()?;
+            if method == "shutdown" {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn handle_request(&self, method: &str, params: &Value) -> Result<Value, ResponseError> {
+        match method {
+            "compile" => self.compile(),
+            "listTests" => self.list_tests(),
+            "runTest" => self.run_test(params),
+            "shutdown" => Ok(Value::Null),
+            _ => Err(ResponseError {
+                code: METHOD_NOT_FOUND,
+                message: format!("unsupported method: {method}"),
+            }),
+        }
+    }
+
+    /// Compiles the project, reloading its configuration to pick up the changes made since the
+    /// daemon started.
+    fn compile_project(&self) -> Result<ProjectCompileOutput> {
+        let config = Config::load_with_root(&self.root)?.sanitized();
+        Ok(config.project()?.compile()?)
+    }
+
+    fn compile(&self) -> Result<Value, ResponseError> {
+        let output = self.compile_project()?;
+        Ok(json!({
+            "success": !output.has_compiler_errors(),
+            "errors": output.output().errors,
+        }))
+    }
+
+    fn list_tests(&self) -> Result<Value, ResponseError> {
+        let output = self.compile_project()?;
+        if output.has_compiler_errors() {
+            return Err(eyre::eyre!("compilation failed:\n{output}").into());
+        }
+        let suites = test::inventory(&output, &self.root, &EmptyTestFilter::default());
+        Ok(serde_json::to_value(suites).map_err(eyre::Report::from)?)
+    }
+
+    fn run_test(&self, params: &Value) -> Result<Value, ResponseError> {
+        let param = |name| params.get(name).and_then(Value::as_str);
+        let test = param("test").ok_or_else(|| ResponseError::invalid_params("missing `test`"))?;
+        // Accept signatures, as listed in the inventory.
+        let test = test.split('(').next().unwrap_or(test);
+        let verbosity = params.get("verbosity").and_then(Value::as_u64).unwrap_or(4).min(5);
+
+        let mut args = vec![
+            "test".to_string(),
+            "--root".to_string(),
+            self.root.display().to_string(),
+            "--match-test".to_string(),
+            format!("^{}$", regex::escape(test)),
+        ];
+        if let Some(contract) = param("contract") {
+            args.extend(["--match-contract".to_string(), format!("^{}$", regex::escape(contract))]);
+        }
+        if let Some(path) = param("path") {
+            args.extend(["--match-path".to_string(), path.to_string()]);
+        }
+        if verbosity > 0 {
+            args.push(format!("-{}", "v".repeat(verbosity as usize)));
+        }
+        let args = TestArgs::try_parse_from(args)
+            .map_err(|err| ResponseError::invalid_params(&err.to_string()))?;
+        let mut outcome = self.runtime.block_on(args.run())?;
+
+        let mut results = Vec::new();
+        for (suite, suite_result) in &mut outcome.results {
+            for (signature, result) in &mut suite_result.test_results {
+                let mut traces = Vec::new();
+                if let Some(decoder) = &outcome.last_run_decoder {
+                    for (kind, arena) in &mut result.traces {
+                        let kind = match kind {
+                            TraceKind::Deployment => continue,
+                            TraceKind::Setup => "setup",
+                            TraceKind::Execution => "execution",
+                        };
+                        self.runtime.block_on(decode_trace_arena(arena, decoder));
+                        let rendered = render_trace_arena_inner(arena, false, verbosity > 4);
+                        traces.push(json!({"kind": kind, "trace": strip_ansi(&rendered)}));
+                    }
+                }
+                results.push(json!({
+                    "suite": suite,
+                    "test": signature,
+                    "status": result.status,
+                    "reason": result.reason,
+                    "kind": result.kind,
+                    "counterexample": result.counterexample,
+                    "durationMs": result.duration.as_millis() as u64,
+                    "logs": decode_console_logs(&result.logs),
+                    "traces": traces,
+                }));
+            }
+        }
+        if results.is_empty() {
+            return Err(eyre::eyre!("no test matches `{test}`").into());
+        }
+        Ok(Value::Array(results))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serves_requests_until_shutdown() {
+        let daemon = Daemon {
+            root: PathBuf::from("."),
+            runtime: tokio::runtime::Builder::new_current_thread().build().unwrap(),
+        };
+        let requests = concat!(
+            r#"{"jsonrpc":"2.0","id":1,"method":"unknown"}"#,
+            "\n",
+            r#"{"jsonrpc":"2.0","id":2,"method":"runTest","params":{}}"#,
+            "\n",
+            "not json\n",
+            r#"{"jsonrpc":"2.0","id":3,"method":"shutdown"}"#,
+            "\n",
+            r#"{"jsonrpc":"2.0","id":4,"method":"unknown"}"#,
+            "\n",
+        );
+        let mut output = Vec::new();
+        assert!(daemon.handle_connection(requests.as_bytes(), &mut output).unwrap());
+
+        let responses = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(responses.len(), 4);
+        assert_eq!(responses[0]["error"]["code"], METHOD_NOT_FOUND);
+        assert_eq!(responses[1]["error"]["code"], INVALID_PARAMS);
+        assert_eq!(responses[2]["error"]["code"], PARSE_ERROR);
+        assert_eq!(responses[3], json!({"jsonrpc": "2.0", "id": 3, "result": null}));
+    }
+}
@@ -49,6 +49,8 @@ pub fn run(self) -> Result<()> {
             paths: self.paths,
             severity: None,
             lint: Some(vec!["unsafe-cheatcode".to_string()]),
+            fix: false,
+            format: crate::cmd::lint::LintFormat::Text,
             build: self.build,
         };
         lint_args.build.deny = Some(DenyLevel::Notes);
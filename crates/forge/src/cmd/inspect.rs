@@ -29,12 +29,20 @@
 #[derive(Clone, Debug, Parser)]
 pub struct InspectArgs {
     /// The identifier of the contract to inspect in the form `(<path>:)?<contractname>`.
-    #[arg(value_parser = PathOrContractInfo::from_str)]
-    pub contract: PathOrContractInfo,
+    #[arg(
+        value_parser = PathOrContractInfo::from_str,
+        required_unless_present = "check_clone_layout"
+    )]
+    pub contract: Option<PathOrContractInfo>,
 
     /// The contract artifact field to inspect.
-    #[arg(value_enum)]
-    pub field: ContractArtifactField,
+    #[arg(value_enum, required_unless_present = "check_clone_layout")]
+    pub field: Option<ContractArtifactField>,
+
+    /// Check that the storage layouts of the cloned contracts are compatible with the ones saved
+    /// in `.clone.meta` by `forge clone`, and fail with a slot-by-slot report otherwise.
+    #[arg(long, conflicts_with_all = ["contract", "field"])]
+    pub check_clone_layout: bool,
 
     /// All build arguments are supported
     #[command(flatten)]
@@ -51,7 +59,14 @@ pub struct InspectArgs {
 
 impl InspectArgs {
     pub fn run(self) -> Result<()> {
-        let Self { contract, field, build, strip_yul_comments, wrap } = self;
+        let Self { contract, field, check_clone_layout, build, strip_yul_comments, wrap } = self;
+
+        if check_clone_layout {
+            return super::clone::check_clone_layout(&build.project_paths.project_root());
+        }
+        let (Some(contract), Some(field)) = (contract, field) else {
+            eyre::bail!("a contract and a field to inspect are required");
+        };
 
         trace!(target: "forge", ?field, ?contract, "running forge inspect");
 
@@ -1,17 +1,27 @@
-use clap::{Parser, ValueHint};
+use crate::cmd::build::write_fixes;
+use clap::{Parser, ValueEnum, ValueHint};
 use eyre::{Result, eyre};
 use forge_lint::{
-    linter::Linter,
-    sol::{SolLint, SolLintError, SolidityLinter},
+    baseline::Baseline,
+    linter::{Lint, Linter},
+    plugin,
+    report::FileReport,
+    slither::{self, Correlation},
+    sol::{SolLint, SolLintError, SolidityLinter, registered_lints},
 };
 use foundry_cli::{
     opts::{BuildOpts, configure_pcx_from_solc, get_solar_sources_from_compile_output},
     utils::{FoundryPathExt, LoadConfig},
 };
-use foundry_common::{compile::ProjectCompiler, shell};
+use foundry_common::{compile::ProjectCompiler, shell, version::SHORT_VERSION};
 use foundry_compilers::{solc::SolcLanguage, utils::SOLC_EXTENSIONS};
 use foundry_config::{filter::expand_globs, lint::Severity};
-use std::path::PathBuf;
+use serde_json::{Value, json};
+use solar::interface::diagnostics::Applicability;
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
 
 /// CLI arguments for `forge lint`.
 #[derive(Clone, Debug, Parser)]
@@ -22,8 +32,9 @@ pub struct LintArgs {
 
     /// Specifies which lints to run based on severity. Overrides the `severity` project config.
     ///
-    /// Supported values: `high`, `med`, `low`, `info`, `gas`.
-    #[arg(long, value_name = "SEVERITY", num_args(1..))]
+    /// Supported values: `high`, `med`, `low`, `info`, `gas`, e.g. `--only gas` to run the gas
+    /// optimization lints.
+    #[arg(long, visible_alias = "only", value_name = "SEVERITY", num_args(1..))]
     pub(crate) severity: Option<Vec<Severity>>,
 
     /// Specifies which lints to run based on their ID (e.g., "incorrect-shift"). Overrides the
@@ -31,15 +42,98 @@ pub struct LintArgs {
     #[arg(long = "only-lint", value_name = "LINT_ID", num_args(1..))]
     pub(crate) lint: Option<Vec<String>>,
 
+    /// Apply the fixes suggested by the lints.
+    ///
+    /// Only the fixes which are safe to apply without review are applied, skipping the ones
+    /// overlapping another fix. All fixes are printed as patches.
+    #[arg(long)]
+    pub(crate) fix: bool,
+
+    /// The format of the emitted lints.
+    ///
+    /// The `json` and `sarif` formats are printed to stdout, the diagnostics are still printed to
+    /// stderr.
+    #[arg(long, value_enum, default_value_t = LintFormat::Text)]
+    pub(crate) format: LintFormat,
+
+    /// Correlate the emitted lints with the findings of a Slither JSON output, as written by
+    /// `slither . --json <PATH>`, and report the ones found by a single tool.
+    #[arg(long, value_hint = ValueHint::FilePath, value_name = "PATH")]
+    pub(crate) compare_slither: Option<PathBuf>,
+
+    /// Write the emitted lints to a baseline file, e.g. `lint-baseline.json`, and exit
+    /// successfully.
+    ///
+    /// The lints of a baseline are not reported by the runs using it, so that only the new ones
+    /// are.
+    #[arg(
+        long,
+        value_hint = ValueHint::FilePath,
+        value_name = "PATH",
+        conflicts_with_all = ["fix", "baseline"],
+    )]
+    pub(crate) write_baseline: Option<PathBuf>,
+
+    /// Only report the lints which are not in the given baseline, as written by
+    /// `--write-baseline`. Overrides the `baseline` project config.
+    #[arg(long, value_hint = ValueHint::FilePath, value_name = "PATH")]
+    pub(crate) baseline: Option<PathBuf>,
+
+    /// Print the documentation of a lint, by ID (e.g., "incorrect-shift"), and exit.
+    #[arg(long, value_name = "LINT_ID", conflicts_with = "dump_rules")]
+    pub(crate) explain: Option<String>,
+
+    /// Print the metadata of all the lints in the given format and exit, e.g. to generate their
+    /// documentation.
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    pub(crate) dump_rules: Option<RulesFormat>,
+
     #[command(flatten)]
     pub(crate) build: BuildOpts,
 }
 
 foundry_config::impl_figment_convert!(LintArgs, build);
 
+/// The output format of `forge lint`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum LintFormat {
+    /// Human-readable diagnostics.
+    #[default]
+    Text,
+    /// A JSON array of the emitted lints.
+    Json,
+    /// A SARIF 2.1.0 log, e.g. for GitHub code scanning.
+    Sarif,
+}
+
+/// The output format of `forge lint --dump-rules`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum RulesFormat {
+    /// A JSON array of the lints.
+    Json,
+}
+
 impl LintArgs {
+    /// Whether the lints are reported on stdout rather than only as diagnostics.
+    fn with_reports(&self) -> bool {
+        self.format != LintFormat::Text
+            || self.compare_slither.is_some()
+            || self.write_baseline.is_some()
+    }
+
     pub fn run(self) -> Result<()> {
         let config = self.load_config()?;
+        plugin::load(&config.root, &config.lint.plugins)?;
+
+        if let Some(id) = &self.explain {
+            return explain(SolLint::try_from(id.as_str())?);
+        }
+        if let Some(RulesFormat::Json) = self.dump_rules {
+            let rules = registered_lints().map(|lint| rule_to_json(&lint)).collect();
+            sh_println!("{:#}", Value::Array(rules))?;
+            return Ok(());
+        }
+
         let project = config.solar_project()?;
         let path_config = config.project_paths();
 
@@ -95,19 +189,42 @@ pub fn run(self) -> Result<()> {
             }
         };
 
+        let severity_overrides = config
+            .lint
+            .severity_overrides
+            .iter()
+            .map(|(id, severity)| Ok((SolLint::try_from(id.as_str())?, *severity)))
+            .collect::<Result<Vec<_>, SolLintError>>()?;
+
         if project.compiler.solc.is_none() {
             return Err(eyre!("linting not supported for this language"));
         }
 
+        // All the lints are reported when writing a new baseline.
+        let baseline = match (&self.baseline, &config.lint.baseline) {
+            _ if self.write_baseline.is_some() => None,
+            (Some(path), _) => Some(Baseline::read(path)?),
+            (None, Some(path)) => Some(Baseline::read(&config.root.join(path))?),
+            (None, None) => None,
+        };
+
         let linter = SolidityLinter::new(path_config)
             .with_json_emitter(shell::is_json())
             .with_description(true)
             .with_lints(include)
             .without_lints(exclude)
             .with_severity(if severity.is_empty() { None } else { Some(severity) })
-            .with_lint_specific(&config.lint.lint_specific);
+            .with_severity_overrides(severity_overrides)
+            .with_lint_specific(&config.lint.lint_specific)
+            .with_fixes(self.fix)
+            .with_reports(self.with_reports())
+            .with_cache((config.cache && !config.force).then(|| config.lint_cache_dir()))
+            .with_baseline(baseline);
 
-        let output = ProjectCompiler::new().files(input.iter().cloned()).compile(&project)?;
+        let output = ProjectCompiler::new()
+            .quiet(shell::is_quiet() || self.with_reports())
+            .files(input.iter().cloned())
+            .compile(&project)?;
         let solar_sources =
             get_solar_sources_from_compile_output(&config, &output, Some(&input), Some(&ignored))?;
         if solar_sources.input.sources.is_empty() {
@@ -127,8 +244,247 @@ pub fn run(self) -> Result<()> {
             configure_pcx_from_solc(&mut pcx, &config.project_paths(), &solar_sources, true);
             pcx.parse();
         });
-        linter.lint(&input, config.deny, &mut compiler)?;
+        let result = linter.lint(&input, config.deny, &mut compiler);
+
+        // Apply the fixes even if the lints are denied, since they fix some of them.
+        if self.fix {
+            write_fixes(&config.root, &linter.take_fixes())?;
+        }
+
+        let reports = linter.take_reports();
+        if let Some(path) = &self.write_baseline {
+            let baseline = Baseline::from_reports(&config.root, &reports)?;
+            baseline.write(path)?;
+            sh_println!("Wrote {} lints to {}", baseline.len(), path.display())?;
+            return Ok(());
+        }
+        if let Some(path) = &self.compare_slither {
+            let findings = slither::read_findings(path)?;
+            let correlation = slither::correlate(&config.root, &reports, findings);
+            match self.format {
+                LintFormat::Json | LintFormat::Sarif => {
+                    sh_println!("{}", correlation_to_json(&correlation))?
+                }
+                LintFormat::Text => print_correlation(&correlation)?,
+            }
+            return result;
+        }
+        match self.format {
+            LintFormat::Text => {}
+            LintFormat::Json => sh_println!("{}", to_json(&config.root, &reports))?,
+            LintFormat::Sarif => sh_println!("{:#}", to_sarif(&config.root, &reports))?,
+        }
+
+        result
+    }
+}
+
+/// Prints the documentation of a lint.
+fn explain(lint: SolLint) -> Result<()> {
+    sh_println!("{} ({})\n", lint.id(), lint.severity())?;
+    sh_println!("{}", lint.description())?;
+    if !lint.examples().is_empty() {
+        sh_println!("\nExamples:")?;
+        for example in lint.examples() {
+            sh_println!()?;
+            for line in example.lines() {
+                sh_println!("    {line}")?;
+            }
+        }
+    }
+    if !lint.config_keys().is_empty() {
+        sh_println!("\nConfiguration:")?;
+        for key in lint.config_keys() {
+            sh_println!("    {key}")?;
+        }
+    }
+    sh_println!("\nSee {}", lint.help())?;
+    Ok(())
+}
+
+/// Serializes the metadata of a lint.
+fn rule_to_json(lint: &SolLint) -> Value {
+    json!({
+        "id": lint.id(),
+        "severity": lint.severity(),
+        "description": lint.description(),
+        "help": lint.help(),
+        "examples": lint.examples(),
+        "config": lint.config_keys(),
+    })
+}
+
+/// Prints the lints and Slither findings found by a single tool.
+fn print_correlation(correlation: &Correlation) -> Result<()> {
+    let Correlation { matched, forge_only, slither_only } = correlation;
+    sh_println!("Found by both forge lint and Slither: {}", matched.len())?;
+    sh_println!("\nOnly found by forge lint: {}", forge_only.len())?;
+    for lint in forge_only {
+        let path = lint.path.display();
+        let (line, column) = (lint.lint.start.line, lint.lint.start.column);
+        sh_println!("  {path}:{line}:{column} [{}] {}", lint.lint.id, lint.lint.message)?;
+    }
+    sh_println!("\nOnly found by Slither: {}", slither_only.len())?;
+    for finding in slither_only {
+        let location = finding
+            .location()
+            .map(|(path, line)| format!("{}:{line}", path.display()))
+            .unwrap_or_else(|| "<unknown>".to_string());
+        sh_println!("  {location} [{}] {}", finding.check, finding.summary())?;
+    }
+    Ok(())
+}
+
+/// Serializes the lints and Slither findings, correlated.
+fn correlation_to_json(correlation: &Correlation) -> Value {
+    let lint = |lint: &slither::FileLint| {
+        json!({
+            "id": lint.lint.id,
+            "severity": lint.lint.severity,
+            "message": lint.lint.message,
+            "file": lint.path.to_string_lossy().replace('\\', "/"),
+            "line": lint.lint.start.line,
+            "column": lint.lint.start.column,
+        })
+    };
+    let finding = |finding: &slither::SlitherFinding| {
+        let (file, line) = finding.location().unzip();
+        json!({
+            "check": finding.check,
+            "impact": finding.impact,
+            "description": finding.summary(),
+            "file": file.map(|file| file.to_string_lossy().replace('\\', "/")),
+            "line": line,
+        })
+    };
+    json!({
+        "matched": correlation
+            .matched
+            .iter()
+            .map(|(l, f)| json!({ "lint": lint(l), "slither": finding(f) }))
+            .collect::<Vec<_>>(),
+        "forgeOnly": correlation.forge_only.iter().map(lint).collect::<Vec<_>>(),
+        "slitherOnly": correlation.slither_only.iter().map(finding).collect::<Vec<_>>(),
+    })
+}
+
+/// Returns the path of a linted file, relative to the project root.
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root).unwrap_or(path).to_string_lossy().replace('\\', "/")
+}
+
+/// Serializes the emitted lints as a JSON array.
+fn to_json(root: &Path, reports: &[FileReport]) -> Value {
+    let lints = reports.iter().flat_map(|file| {
+        let path = relative_path(root, &file.path);
+        file.lints.iter().map(move |lint| {
+            json!({
+                "id": lint.id,
+                "severity": lint.severity,
+                "message": lint.message,
+                "help": lint.help,
+                "file": path,
+                "span": {
+                    "start": { "line": lint.start.line, "column": lint.start.column },
+                    "end": { "line": lint.end.line, "column": lint.end.column },
+                    "offset": lint.range.start,
+                    "length": lint.range.len(),
+                },
+                "fix": lint.fix.as_ref().map(|fix| json!({
+                    "description": fix.desc,
+                    "replacement": fix.replacement,
+                    "offset": fix.range.start,
+                    "length": fix.range.len(),
+                    "machineApplicable": fix.applicability == Applicability::MachineApplicable,
+                })),
+            })
+        })
+    });
+    Value::Array(lints.collect())
+}
+
+/// Serializes the emitted lints as a SARIF 2.1.0 log.
+fn to_sarif(root: &Path, reports: &[FileReport]) -> Value {
+    let ids = reports.iter().flat_map(|file| file.lints.iter().map(|lint| lint.id));
+    let rules = ids
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter_map(|id| SolLint::try_from(id).ok())
+        .map(|lint| {
+            json!({
+                "id": lint.id(),
+                "shortDescription": { "text": lint.description() },
+                "helpUri": lint.help(),
+                "defaultConfiguration": { "level": sarif_level(lint.severity()) },
+                "properties": { "severity": lint.severity() },
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let results = reports.iter().flat_map(|file| {
+        let uri = relative_path(root, &file.path);
+        file.lints.iter().map(move |lint| {
+            let artifact = json!({ "uri": uri, "uriBaseId": "%SRCROOT%" });
+            let mut result = json!({
+                "ruleId": lint.id,
+                "level": sarif_level(lint.severity),
+                "message": { "text": lint.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": artifact,
+                        "region": {
+                            "startLine": lint.start.line,
+                            "startColumn": lint.start.column,
+                            "endLine": lint.end.line,
+                            "endColumn": lint.end.column,
+                        },
+                    },
+                }],
+            });
+            if let Some(fix) = &lint.fix {
+                result["fixes"] = json!([{
+                    "description": { "text": fix.desc.unwrap_or(lint.message.as_str()) },
+                    "artifactChanges": [{
+                        "artifactLocation": artifact,
+                        "replacements": [{
+                            "deletedRegion": {
+                                "byteOffset": fix.range.start,
+                                "byteLength": fix.range.len(),
+                            },
+                            "insertedContent": { "text": fix.replacement },
+                        }],
+                    }],
+                }]);
+            }
+            result
+        })
+    });
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "forge-lint",
+                    "version": SHORT_VERSION,
+                    "informationUri": "https://book.getfoundry.sh/reference/forge/forge-lint",
+                    "rules": rules,
+                },
+            },
+            "originalUriBaseIds": {
+                "%SRCROOT%": { "uri": format!("file://{}/", root.display()) },
+            },
+            "results": results.collect::<Vec<_>>(),
+        }],
+    })
+}
 
-        Ok(())
+/// Returns the SARIF level of a lint severity.
+const fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::High => "error",
+        Severity::Med | Severity::Low => "warning",
+        Severity::Info | Severity::Gas | Severity::CodeSize => "note",
     }
 }
@@ -0,0 +1,242 @@
+//! Semantic analysis of a document and its imports with solar, the frontend used by `forge lint`.
+
+use super::document::{LspRange, range_at};
+use eyre::{Result, WrapErr};
+use foundry_compilers::solc::SolcLanguage;
+use foundry_config::Config;
+use solar::{
+    ast,
+    interface::{
+        BytePos, Session, Span,
+        diagnostics::JsonEmitter,
+        source_map::{FileName, FileResolver, SourceFile},
+    },
+    sema::{
+        Compiler,
+        hir::{self, Visit},
+        interface::config::ImportRemapping,
+    },
+};
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+    ops::ControlFlow,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+/// A document parsed and lowered, with its imports, by solar.
+pub(super) struct Analysis {
+    pub(super) compiler: Compiler,
+    /// The diagnostics emitted by solar, in the rustc JSON diagnostic format.
+    emitted: SharedBuffer,
+}
+
+impl Analysis {
+    /// Analyzes `file` and the files it imports, preferring the unsaved text of the open
+    /// `documents`.
+    pub(super) fn new(
+        config: &Config,
+        file: &Path,
+        documents: &HashMap<PathBuf, String>,
+    ) -> Result<Self> {
+        let emitted = SharedBuffer::default();
+        let mut compiler =
+            Compiler::new(Session::builder().with_buffer_emitter(Default::default()).build());
+        let sm = compiler.sess().clone_source_map();
+        let emitter = JsonEmitter::new(Box::new(emitted.clone()), sm.clone()).rustc_like(true);
+        compiler.dcx().set_emitter(Box::new(emitter));
+
+        // Loaded first, so that the files resolved while parsing are the open documents.
+        for (path, text) in documents {
+            let _ = sm.new_source_file(path.clone(), text.as_str());
+        }
+
+        compiler.enter_mut(|compiler| -> Result<()> {
+            let mut pcx = compiler.parse();
+            configure_resolver(&mut pcx.file_resolver, config);
+            pcx.set_resolve_imports(true);
+            let source = pcx
+                .sess
+                .source_map()
+                .load_file(file)
+                .wrap_err_with(|| format!("failed to read {}", file.display()))?;
+            pcx.add_file(source);
+            pcx.parse();
+            let _ = compiler.lower_asts();
+            Ok(())
+        })?;
+
+        Ok(Self { compiler, emitted })
+    }
+
+    /// Returns the diagnostics emitted by solar, in the rustc JSON diagnostic format.
+    pub(super) fn emitted_diagnostics(&self) -> Vec<u8> {
+        self.emitted.0.lock().unwrap().clone()
+    }
+
+    /// Returns the file imported by the import path under the byte `offset` of `file`, if any.
+    pub(super) fn import_at(&self, config: &Config, file: &Path, offset: usize) -> Option<PathBuf> {
+        self.compiler.enter_sequential(|compiler| {
+            let gcx = compiler.gcx();
+            let (_, source) = gcx.get_ast_source(file)?;
+            let pos = byte_pos(&source.file, offset);
+            let import = source.ast.as_ref()?.items.iter().find_map(|item| match &item.kind {
+                ast::ItemKind::Import(import) if contains(import.path.span, pos) => Some(import),
+                _ => None,
+            })?;
+
+            let mut resolver = FileResolver::new(gcx.sess.source_map());
+            configure_resolver(&mut resolver, config);
+            let imported =
+                resolver.resolve_file(Path::new(import.path.value.as_str()), file.parent()).ok()?;
+            match &imported.name {
+                FileName::Real(path) => Some(path.clone()),
+                _ => None,
+            }
+        })
+    }
+
+    /// Returns the location of the definition of the item referenced at the byte `offset` of
+    /// `file`.
+    pub(super) fn definition(&self, file: &Path, offset: usize) -> Option<(PathBuf, LspRange)> {
+        self.compiler.enter_sequential(|compiler| {
+            let gcx = compiler.gcx();
+            let (source_id, source) = gcx.get_hir_source(file)?;
+            let pos = byte_pos(&source.file, offset);
+            let mut finder = ReferenceFinder { hir: &gcx.hir, pos };
+            let id = finder.visit_nested_source(source_id).break_value()?;
+
+            let item = gcx.hir.item(id);
+            let span = item.name().map_or(item.span(), |name| name.span);
+            let sm = gcx.sess.source_map();
+            let file = sm.lookup_source_file(span.lo());
+            let FileName::Real(path) = &file.name else { return None };
+            let range = sm.span_to_range(span).ok()?;
+            Some((path.clone(), range_at(&file.src, range)))
+        })
+    }
+
+    /// Returns the name of the contract enclosing the byte `offset` of `file`.
+    pub(super) fn enclosing_contract(&self, file: &Path, offset: usize) -> Option<String> {
+        self.compiler.enter_sequential(|compiler| {
+            let gcx = compiler.gcx();
+            let (source_id, source) = gcx.get_hir_source(file)?;
+            let pos = byte_pos(&source.file, offset);
+            gcx.hir
+                .contract_ids()
+                .map(|id| gcx.hir.contract(id))
+                .find(|contract| contract.source == source_id && contains(contract.span, pos))
+                .map(|contract| contract.name.to_string())
+        })
+    }
+}
+
+/// Finds the item referenced by the identifier or the type name at a position.
+struct ReferenceFinder<'hir> {
+    hir: &'hir hir::Hir<'hir>,
+    pos: BytePos,
+}
+
+impl<'hir> Visit<'hir> for ReferenceFinder<'hir> {
+    type BreakValue = hir::ItemId;
+
+    fn hir(&self) -> &'hir hir::Hir<'hir> {
+        self.hir
+    }
+
+    fn visit_expr(&mut self, expr: &'hir hir::Expr<'hir>) -> ControlFlow<Self::BreakValue> {
+        if let hir::ExprKind::Ident(resolutions) = &expr.kind
+            && contains(expr.span, self.pos)
+            && let Some(hir::Res::Item(id)) = resolutions.first()
+        {
+            return ControlFlow::Break(*id);
+        }
+        self.walk_expr(expr)
+    }
+
+    fn visit_ty(&mut self, ty: &'hir hir::Type<'hir>) -> ControlFlow<Self::BreakValue> {
+        if let hir::TypeKind::Custom(id) = ty.kind
+            && contains(ty.span, self.pos)
+        {
+            return ControlFlow::Break(id);
+        }
+        self.walk_ty(ty)
+    }
+}
+
+/// Configures `resolver` with the remappings and include paths of the project.
+fn configure_resolver(resolver: &mut FileResolver<'_>, config: &Config) {
+    let paths = config.project_paths::<SolcLanguage>();
+    resolver.set_current_dir(&paths.root);
+    for remapping in &paths.remappings {
+        resolver.add_import_remapping(ImportRemapping {
+            context: remapping.context.clone().unwrap_or_default(),
+            prefix: remapping.name.clone(),
+            path: remapping.path.clone(),
+        });
+    }
+    resolver.add_include_paths(config.include_paths.iter().cloned());
+}
+
+/// Returns the position of the byte `offset` of `file` in the source map.
+fn byte_pos(file: &SourceFile, offset: usize) -> BytePos {
+    BytePos(file.start_pos.0 + offset as u32)
+}
+
+/// Returns whether `span` contains `pos`, including its end, where the cursor lies after a word.
+fn contains(span: Span, pos: BytePos) -> bool {
+    span.lo() <= pos && pos <= span.hi()
+}
+
+/// A writer whose content is shared, to read what an emitter wrote.
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cmd::lsp::document::Position;
+    use std::fs;
+
+    #[test]
+    fn resolves_across_remappings() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("lib/forge-std/src")).unwrap();
+        fs::create_dir_all(root.join("test")).unwrap();
+        fs::write(root.join("lib/forge-std/src/Test.sol"), "contract Test {}\n").unwrap();
+        fs::write(root.join("remappings.txt"), "forge-std/=lib/forge-std/src/\n").unwrap();
+        let config = Config::load_with_root(root).unwrap().sanitized();
+
+        // Only open in the editor, never saved.
+        let file = root.join("test/Counter.t.sol");
+        let text = "import {Test} from \"forge-std/Test.sol\";\n\
+                    contract CounterTest is Test {\n    Test t;\n}\n";
+        let documents = HashMap::from([(file.clone(), text.to_string())]);
+        let analysis = Analysis::new(&config, &file, &documents).unwrap();
+        let test = root.join("lib/forge-std/src/Test.sol");
+
+        let offset = text.find("forge-std").unwrap();
+        assert_eq!(analysis.import_at(&config, &file, offset), Some(test.clone()));
+        assert_eq!(analysis.import_at(&config, &file, text.find("contract").unwrap()), None);
+
+        let (path, range) = analysis.definition(&file, text.find("Test t").unwrap()).unwrap();
+        assert_eq!(path, test);
+        assert_eq!(range.start, Position { line: 0, character: 9 });
+
+        let offset = text.find("t;").unwrap();
+        assert_eq!(analysis.enclosing_contract(&file, offset).as_deref(), Some("CounterTest"));
+        assert!(analysis.emitted_diagnostics().is_empty());
+    }
+}
@@ -0,0 +1,158 @@
+//! Document diagnostics: the errors reported by solar and the lints of `forge lint`.
+
+use super::{
+    analysis::Analysis,
+    document::{LspRange, Position, range_at},
+};
+use eyre::Result;
+use forge_lint::{
+    linter::Linter,
+    sol::{SolLint, SolidityLinter},
+};
+use foundry_config::{
+    Config, DenyLevel,
+    lint::{LintOnBuild, Severity},
+};
+use serde_json::{Value, json};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+/// LSP diagnostic severities.
+const ERROR: u8 = 1;
+const WARNING: u8 = 2;
+const INFORMATION: u8 = 3;
+const HINT: u8 = 4;
+
+/// Diagnostics keyed by the absolute path of their file.
+pub(super) type Diagnostics = BTreeMap<PathBuf, Vec<Value>>;
+
+/// Returns the diagnostics of `file` and of the files it imports.
+///
+/// `file` is linted, as configured for `forge build`, when solar reports no errors. `read` returns
+/// the text of a file, preferring the unsaved content of open documents.
+pub(super) fn collect(
+    analysis: &mut Analysis,
+    config: &Config,
+    file: &Path,
+    read: impl Fn(&Path) -> Option<String>,
+) -> Result<Diagnostics> {
+    let mut diagnostics = Diagnostics::new();
+    let emitted = analysis.emitted_diagnostics();
+    for diagnostic in serde_json::Deserializer::from_slice(&emitted).into_iter::<Value>() {
+        if let Some((path, diagnostic)) = solar_diagnostic(&config.root, &diagnostic?) {
+            diagnostics.entry(path).or_default().push(diagnostic);
+        }
+    }
+
+    let compiler = &mut analysis.compiler;
+    if config.lint.on_build() == LintOnBuild::Off || compiler.dcx().has_errors().is_err() {
+        return Ok(diagnostics);
+    }
+    let linter =
+        SolidityLinter::new(config.project_paths())
+            .with_json_emitter(true)
+            .with_description(false)
+            .with_severity((!config.lint.severity.is_empty()).then(|| config.lint.severity.clone()))
+            .without_lints((!config.lint.exclude_lints.is_empty()).then(|| {
+                config
+                    .lint
+                    .exclude_lints
+                    .iter()
+                    .filter_map(|s| SolLint::try_from(s.as_str()).ok())
+                    .collect()
+            }))
+            .with_severity_overrides(config.lint.severity_overrides.iter().filter_map(
+                |(id, severity)| Some((SolLint::try_from(id.as_str()).ok()?, *severity)),
+            ))
+            .with_lint_specific(&config.lint.lint_specific)
+            .with_reports(true);
+    linter.lint(&[file.to_path_buf()], DenyLevel::Never, compiler)?;
+
+    for report in linter.take_reports() {
+        let Some(text) = read(&report.path) else { continue };
+        let entry = diagnostics.entry(report.path).or_default();
+        entry.extend(report.lints.into_iter().map(|lint| {
+            json!({
+                "range": range_at(&text, lint.range),
+                "severity": lint_severity(lint.severity),
+                "code": lint.id,
+                "codeDescription": {"href": lint.help},
+                "source": "forge-lint",
+                "message": lint.message,
+            })
+        }));
+    }
+    Ok(diagnostics)
+}
+
+/// Returns the diagnostic severity of a lint, matching the level it is emitted with.
+const fn lint_severity(severity: Severity) -> u8 {
+    match severity {
+        Severity::High | Severity::Med | Severity::Low => WARNING,
+        Severity::Info | Severity::Gas | Severity::CodeSize => INFORMATION,
+    }
+}
+
+/// Converts a solar diagnostic, in the rustc JSON diagnostic format, into a diagnostic.
+fn solar_diagnostic(root: &Path, diagnostic: &Value) -> Option<(PathBuf, Value)> {
+    let spans = diagnostic.get("spans")?.as_array()?;
+    let span = spans
+        .iter()
+        .find(|span| span.get("is_primary").and_then(Value::as_bool) == Some(true))
+        .or_else(|| spans.first())?;
+    let path = root.join(span.get("file_name")?.as_str()?);
+    // Lines and columns are one-based.
+    let position = |line, column| {
+        let get = |key| span.get(key)?.as_u64()?.checked_sub(1).map(|n| n as u32);
+        Some(Position { line: get(line)?, character: get(column)? })
+    };
+    let range = LspRange {
+        start: position("line_start", "column_start")?,
+        end: position("line_end", "column_end")?,
+    };
+    let severity = match diagnostic.get("level").and_then(Value::as_str) {
+        Some("error") => ERROR,
+        Some("warning") => WARNING,
+        Some("note") => INFORMATION,
+        _ => HINT,
+    };
+    let diagnostic = json!({
+        "range": range,
+        "severity": severity,
+        "code": diagnostic.get("code").and_then(|code| code.get("code")),
+        "source": "solar",
+        "message": diagnostic.get("message")?,
+    });
+    Some((path, diagnostic))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_solar_diagnostics() {
+        let diagnostic = json!({
+            "$message_type": "diagnostic",
+            "message": "unresolved symbol `Counter`",
+            "code": null,
+            "level": "error",
+            "spans": [{
+                "file_name": "src/Counter.t.sol",
+                "line_start": 3,
+                "line_end": 3,
+                "column_start": 5,
+                "column_end": 12,
+                "is_primary": true
+            }]
+        });
+        let (path, diagnostic) = solar_diagnostic(Path::new("/project"), &diagnostic).unwrap();
+        assert_eq!(path, Path::new("/project/src/Counter.t.sol"));
+        assert_eq!(diagnostic["severity"], ERROR);
+        assert_eq!(diagnostic["source"], "solar");
+        assert_eq!(diagnostic["range"]["start"], json!({"line": 2, "character": 4}));
+        assert_eq!(diagnostic["range"]["end"], json!({"line": 2, "character": 11}));
+    }
+}
@@ -0,0 +1,86 @@
+//! Text documents and conversions between LSP positions and byte offsets.
+
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// A position in a document: a zero-based line and UTF-16 character offset.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct Position {
+    pub line: u32,
+    pub character: u32,
+}
+
+/// A range in a document.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct LspRange {
+    pub start: Position,
+    pub end: Position,
+}
+
+/// Returns the byte offset of `position` in `text`, clamped to the end of its line.
+pub(super) fn offset_at(text: &str, position: Position) -> Option<usize> {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        line_start += text[line_start..].find('\n')? + 1;
+    }
+    let line = text[line_start..].split('\n').next().unwrap_or_default();
+    let mut units = 0;
+    for (offset, c) in line.char_indices() {
+        if units >= position.character as usize {
+            return Some(line_start + offset);
+        }
+        units += c.len_utf16();
+    }
+    Some(line_start + line.trim_end_matches('\r').len())
+}
+
+/// Returns the position of the byte `offset` in `text`.
+pub(super) fn position_at(text: &str, offset: usize) -> Position {
+    let before = &text[..offset.min(text.len())];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Position {
+        line: before.matches('\n').count() as u32,
+        character: before[line_start..].encode_utf16().count() as u32,
+    }
+}
+
+/// Returns the range of the bytes `range` in `text`.
+pub(super) fn range_at(text: &str, range: Range<usize>) -> LspRange {
+    LspRange { start: position_at(text, range.start), end: position_at(text, range.end) }
+}
+
+/// Returns the identifier at the byte `offset` in `text`.
+pub(super) fn word_at(text: &str, offset: usize) -> Option<&str> {
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$';
+    let start = text[..offset].rfind(|c| !is_ident(c)).map_or(0, |i| i + 1);
+    let end = text[offset..].find(|c| !is_ident(c)).map_or(text.len(), |i| offset + i);
+    let word = &text[start..end];
+    (!word.is_empty() && !word.starts_with(|c: char| c.is_ascii_digit())).then_some(word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_positions() {
+        let text = "contract A {\n    // é\n    uint x;\n}\n";
+        let position = Position { line: 2, character: 9 };
+        let offset = offset_at(text, position).unwrap();
+        assert_eq!(&text[offset..offset + 1], "x");
+        assert_eq!(position_at(text, offset), position);
+        assert_eq!(word_at(text, offset), Some("x"));
+
+        let end_of_comment = offset_at(text, Position { line: 1, character: 100 }).unwrap();
+        assert_eq!(&text[end_of_comment..end_of_comment + 1], "\n");
+        assert_eq!(offset_at(text, Position { line: 10, character: 0 }), None);
+    }
+
+    #[test]
+    fn finds_words() {
+        let text = "Counter counter = new Counter(1);";
+        assert_eq!(word_at(text, 3), Some("Counter"));
+        assert_eq!(word_at(text, 7), Some("Counter"));
+        assert_eq!(word_at(text, 30), None);
+    }
+}
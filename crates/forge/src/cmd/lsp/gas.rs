@@ -0,0 +1,52 @@
+//! Gas hovers, from the gas snapshot and the build artifacts.
+
+use serde_json::Value;
+use std::{fs, path::Path};
+
+/// Returns the gas information known for `function` of `contract`, from the gas snapshot and the
+/// gas estimates of the build artifact.
+pub(super) fn gas_info(
+    root: &Path,
+    out: &Path,
+    file: &Path,
+    contract: &str,
+    function: &str,
+) -> Vec<String> {
+    let prefix = format!("{function}(");
+    let mut info = Vec::new();
+
+    if let Ok(snapshot) = fs::read_to_string(root.join(".gas-snapshot")) {
+        let contract_prefix = format!("{contract}:");
+        info.extend(
+            snapshot
+                .lines()
+                .filter_map(|line| line.strip_prefix(&contract_prefix))
+                .filter(|entry| entry.starts_with(&prefix))
+                .map(|entry| format!("`{entry}` (snapshot)")),
+        );
+    }
+
+    let artifact = file
+        .file_name()
+        .map(|file_name| out.join(file_name).join(format!("{contract}.json")))
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|artifact| serde_json::from_str::<Value>(&artifact).ok());
+    if let Some(artifact) = artifact {
+        let estimates = artifact.pointer("/gasEstimates/external");
+        let signatures = artifact.get("methodIdentifiers").and_then(Value::as_object);
+        for signature in signatures.into_iter().flat_map(|s| s.keys()) {
+            if !signature.starts_with(&prefix) {
+                continue;
+            }
+            let estimate = estimates.and_then(|estimates| estimates.get(signature));
+            match estimate.and_then(Value::as_str) {
+                Some(estimate) => info.push(format!("`{signature}`: {estimate} (estimate)")),
+                None => info.push(format!(
+                    "`{signature}`: no estimate, enable the `gasEstimates` extra output"
+                )),
+            }
+        }
+    }
+
+    info
+}
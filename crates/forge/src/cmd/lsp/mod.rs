@@ -0,0 +1,373 @@
+//! `forge lsp`: a Language Server Protocol server for the project.
+//!
+//! It provides diagnostics from the compiler and the linter, go-to-definition across imports and
+//! remappings, gas hovers and formatting with the project configuration.
+
+use analysis::Analysis;
+use clap::Parser;
+use document::{LspRange, Position, offset_at, position_at, word_at};
+use eyre::{Result, eyre};
+use foundry_common::version::SHORT_VERSION;
+use foundry_config::Config;
+use serde_json::{Value, json};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    io::{BufRead, Write},
+    path::{Path, PathBuf},
+};
+use url::Url;
+
+mod analysis;
+mod diagnostics;
+mod document;
+mod gas;
+mod transport;
+
+/// CLI arguments for `forge lsp`.
+#[derive(Clone, Debug, Parser)]
+pub struct LspArgs {
+    /// Communicate over stdin and stdout.
+    ///
+    /// This is the only supported transport, the flag is accepted for compatibility with editors.
+    #[arg(long)]
+    pub stdio: bool,
+}
+
+impl LspArgs {
+    pub fn run(self) -> Result<()> {
+        let stdin = std::io::stdin().lock();
+        let stdout = std::io::stdout().lock();
+        Server::default().serve(stdin, stdout)
+    }
+}
+
+/// JSON-RPC error codes.
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+
+/// An error response to a request.
+struct ResponseError {
+    code: i64,
+    message: String,
+}
+
+impl ResponseError {
+    fn invalid_params() -> Self {
+        Self { code: INVALID_PARAMS, message: "invalid params".to_string() }
+    }
+}
+
+impl From<eyre::Report> for ResponseError {
+    fn from(err: eyre::Report) -> Self {
+        Self { code: INTERNAL_ERROR, message: format!("{err:#}") }
+    }
+}
+
+#[derive(Default)]
+struct Server {
+    /// Configuration of the project opened in the editor.
+    config: Option<Config>,
+    /// Text of the open documents.
+    documents: HashMap<PathBuf, String>,
+    /// Files with published diagnostics, to clear them once fixed.
+    published: HashSet<PathBuf>,
+    shutdown: bool,
+}
+
+impl Server {
+    fn serve(&mut self, mut reader: impl BufRead, mut writer: impl Write) -> Result<()> {
+        while let Some(message) = transport::read_message(&mut reader)? {
+            let Some(method) = message.get("method").and_then(Value::as_str) else {
+                // Responses to requests of the server, which sends none.
+                continue;
+            };
+            let params = message.get("params").cloned().unwrap_or(Value::Null);
+            if method == "exit" {
+                return if self.shutdown { Ok(()) } else { Err(eyre!("exit before shutdown")) };
+            }
+            if let Some(id) = message.get("id") {
+                let response = match self.handle_request(method, params) {
+                    Ok(result) => json!({"jsonrpc": "2.0", "id": id, "result": result}),
+                    Err(err) => json!({
+                        "jsonrpc": "2.0",
+                        "id": id,
+                        "error": {"code": err.code, "message": err.message},
+                    }),
+                };
+                transport::write_message(&mut writer, &response)?;
+            } else {
+                for notification in self.handle_notification(method, params) {
+                    transport::write_message(&mut writer, &notification)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_request(&mut self, method: &str, params: Value) -> Result<Value, ResponseError> {
+        match method {
+            "initialize" => self.initialize(&params),
+            "shutdown" => {
+                self.shutdown = true;
+                Ok(Value::Null)
+            }
+            "textDocument/definition" => self.definition(&params),
+            "textDocument/hover" => self.hover(&params),
+            "textDocument/formatting" => self.formatting(&params),
+            _ => Err(ResponseError {
+                code: METHOD_NOT_FOUND,
+                message: format!("unsupported method: {method}"),
+            }),
+        }
+    }
+
+    fn handle_notification(&mut self, method: &str, params: Value) -> Vec<Value> {
+        let Some(path) = params.pointer("/textDocument/uri").and_then(uri_to_path) else {
+            return vec![];
+        };
+        match method {
+            "textDocument/didOpen" => {
+                if let Some(text) = params.pointer("/textDocument/text").and_then(Value::as_str) {
+                    self.documents.insert(path.clone(), text.to_string());
+                }
+                self.publish_diagnostics(&path)
+            }
+            "textDocument/didChange" => {
+                // Documents are fully synchronized: the last change holds the whole text.
+                if let Some(text) = params
+                    .get("contentChanges")
+                    .and_then(Value::as_array)
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change.get("text"))
+                    .and_then(Value::as_str)
+                {
+                    self.documents.insert(path, text.to_string());
+                }
+                vec![]
+            }
+            "textDocument/didSave" => self.publish_diagnostics(&path),
+            "textDocument/didClose" => {
+                self.documents.remove(&path);
+                vec![]
+            }
+            _ => vec![],
+        }
+    }
+
+    fn initialize(&mut self, params: &Value) -> Result<Value, ResponseError> {
+        let root = params
+            .get("rootUri")
+            .and_then(uri_to_path)
+            .or_else(|| params.pointer("/workspaceFolders/0/uri").and_then(uri_to_path))
+            .or_else(|| params.get("rootPath").and_then(Value::as_str).map(PathBuf::from))
+            .map_or_else(std::env::current_dir, Ok)
+            .map_err(eyre::Report::from)?;
+        self.config = Some(Config::load_with_root(&root).map_err(eyre::Report::from)?.sanitized());
+
+        Ok(json!({
+            "capabilities": {
+                "textDocumentSync": {"openClose": true, "change": 1, "save": {"includeText": false}},
+                "definitionProvider": true,
+                "hoverProvider": true,
+                "documentFormattingProvider": true,
+            },
+            "serverInfo": {"name": "forge", "version": SHORT_VERSION},
+        }))
+    }
+
+    fn definition(&self, params: &Value) -> Result<Value, ResponseError> {
+        let config = self.config()?;
+        let (path, _, offset) = self.document_position(params)?;
+        let analysis = Analysis::new(config, &path, &self.documents)?;
+        if let Some(file) = analysis.import_at(config, &path, offset) {
+            return Ok(location(&file, LspRange::default()));
+        }
+        Ok(analysis
+            .definition(&path, offset)
+            .map(|(file, range)| location(&file, range))
+            .unwrap_or(Value::Null))
+    }
+
+    fn hover(&self, params: &Value) -> Result<Value, ResponseError> {
+        let config = self.config()?;
+        let (path, text, offset) = self.document_position(params)?;
+        let Some(function) = word_at(&text, offset) else { return Ok(Value::Null) };
+        let analysis = Analysis::new(config, &path, &self.documents)?;
+        let Some(contract) = analysis.enclosing_contract(&path, offset) else {
+            return Ok(Value::Null);
+        };
+        let out = config.root.join(&config.out);
+        let info = gas::gas_info(&config.root, &out, &path, &contract, function);
+        if info.is_empty() {
+            return Ok(Value::Null);
+        }
+        let value = format!(
+            "**Gas**\n\n{}",
+            info.iter().map(|line| format!("- {line}\n")).collect::<String>()
+        );
+        Ok(json!({"contents": {"kind": "markdown", "value": value}}))
+    }
+
+    fn formatting(&self, params: &Value) -> Result<Value, ResponseError> {
+        let config = self.config()?;
+        let path = params
+            .pointer("/textDocument/uri")
+            .and_then(uri_to_path)
+            .ok_or_else(ResponseError::invalid_params)?;
+        let text = self.text(&path)?;
+        // Sources with syntax errors are left as is, diagnostics report the errors.
+        let Ok(formatted) = forge_fmt::format(&text, config.fmt.clone()).into_result() else {
+            return Ok(json!([]));
+        };
+        if formatted == text {
+            return Ok(json!([]));
+        }
+        let range = LspRange { start: Position::default(), end: position_at(&text, text.len()) };
+        Ok(json!([{"range": range, "newText": formatted}]))
+    }
+
+    /// Analyzes `path` and returns the notifications publishing its diagnostics and those of the
+    /// files it imports, and clearing the fixed ones.
+    fn publish_diagnostics(&mut self, path: &Path) -> Vec<Value> {
+        let Some(config) = &self.config else { return vec![] };
+        let read = |path: &Path| {
+            self.documents.get(path).cloned().or_else(|| fs::read_to_string(path).ok())
+        };
+        let collected = Analysis::new(config, path, &self.documents)
+            .and_then(|mut analysis| diagnostics::collect(&mut analysis, config, path, read));
+        let mut diagnostics = match collected {
+            Ok(diagnostics) => diagnostics,
+            Err(err) => {
+                return vec![json!({
+                    "jsonrpc": "2.0",
+                    "method": "window/logMessage",
+                    "params": {"type": 1, "message": format!("{err:#}")},
+                })];
+            }
+        };
+        diagnostics.entry(path.to_path_buf()).or_default();
+        for fixed in self.published.drain() {
+            diagnostics.entry(fixed).or_default();
+        }
+
+        let mut notifications = Vec::with_capacity(diagnostics.len());
+        for (file, diagnostics) in diagnostics {
+            let Ok(uri) = Url::from_file_path(&file) else { continue };
+            if !diagnostics.is_empty() {
+                self.published.insert(file);
+            }
+            notifications.push(json!({
+                "jsonrpc": "2.0",
+                "method": "textDocument/publishDiagnostics",
+                "params": {"uri": uri, "diagnostics": diagnostics},
+            }));
+        }
+        notifications
+    }
+
+    fn config(&self) -> Result<&Config, ResponseError> {
+        self.config.as_ref().ok_or_else(|| ResponseError {
+            code: INTERNAL_ERROR,
+            message: "server not initialized".to_string(),
+        })
+    }
+
+    /// Returns the text of `path`, preferring the unsaved content of the open document.
+    fn text(&self, path: &Path) -> Result<String, ResponseError> {
+        match self.documents.get(path) {
+            Some(text) => Ok(text.clone()),
+            None => Ok(fs::read_to_string(path).map_err(eyre::Report::from)?),
+        }
+    }
+
+    /// Returns the path, text and byte offset of a `TextDocumentPositionParams`.
+    fn document_position(&self, params: &Value) -> Result<(PathBuf, String, usize), ResponseError> {
+        let path = params
+            .pointer("/textDocument/uri")
+            .and_then(uri_to_path)
+            .ok_or_else(ResponseError::invalid_params)?;
+        let position = params
+            .get("position")
+            .and_then(|position| serde_json::from_value::<Position>(position.clone()).ok())
+            .ok_or_else(ResponseError::invalid_params)?;
+        let text = self.text(&path)?;
+        let offset = offset_at(&text, position).ok_or_else(ResponseError::invalid_params)?;
+        Ok((path, text, offset))
+    }
+}
+
+fn uri_to_path(uri: &Value) -> Option<PathBuf> {
+    Url::parse(uri.as_str()?).ok()?.to_file_path().ok()
+}
+
+fn location(path: &Path, range: LspRange) -> Value {
+    match Url::from_file_path(path) {
+        Ok(uri) => json!({"uri": uri, "range": range}),
+        Err(()) => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(value: Value) -> Vec<u8> {
+        let mut buf = Vec::new();
+        transport::write_message(&mut buf, &value).unwrap();
+        buf
+    }
+
+    fn responses(mut output: &[u8]) -> Vec<Value> {
+        std::iter::from_fn(|| transport::read_message(&mut output).unwrap()).collect()
+    }
+
+    #[test]
+    fn serves_requests() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("src")).unwrap();
+        let counter = root.join("src/Counter.sol");
+        fs::write(&counter, "contract Counter {\n    function increment() public {}\n}\n").unwrap();
+        let test = root.join("src/Counter.t.sol");
+        let test_text = "import {Counter} from \"./Counter.sol\";\ncontract T { Counter c; }\n";
+        fs::write(&test, test_text).unwrap();
+        let root_uri = Url::from_directory_path(root).unwrap();
+        let test_uri = Url::from_file_path(&test).unwrap();
+
+        let mut input = Vec::new();
+        input.extend(message(json!({
+            "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {"rootUri": root_uri},
+        })));
+        input.extend(message(json!({
+            "jsonrpc": "2.0", "id": 2, "method": "textDocument/definition",
+            "params": {"textDocument": {"uri": test_uri}, "position": {"line": 1, "character": 15}},
+        })));
+        input.extend(message(json!({
+            "jsonrpc": "2.0", "id": 3, "method": "textDocument/formatting",
+            "params": {"textDocument": {"uri": test_uri}, "options": {}},
+        })));
+        input.extend(message(json!({"jsonrpc": "2.0", "id": 4, "method": "unknown"})));
+        input.extend(message(json!({"jsonrpc": "2.0", "id": 5, "method": "shutdown"})));
+        input.extend(message(json!({"jsonrpc": "2.0", "method": "exit"})));
+
+        let mut output = Vec::new();
+        Server::default().serve(input.as_slice(), &mut output).unwrap();
+        let responses = responses(&output);
+        assert_eq!(responses.len(), 5);
+
+        assert_eq!(responses[0]["result"]["capabilities"]["definitionProvider"], true);
+
+        let definition = &responses[1]["result"];
+        assert_eq!(definition["uri"], json!(Url::from_file_path(&counter).unwrap()));
+        assert_eq!(definition["range"]["start"], json!({"line": 0, "character": 9}));
+
+        let edits = responses[2]["result"].as_array().unwrap();
+        assert_eq!(edits.len(), 1);
+        assert!(edits[0]["newText"].as_str().unwrap().contains("contract T {\n    Counter c;\n}"));
+
+        assert_eq!(responses[3]["error"]["code"], METHOD_NOT_FOUND);
+        assert_eq!(responses[4]["result"], Value::Null);
+    }
+}
@@ -0,0 +1,58 @@
+//! Base protocol of the Language Server Protocol: JSON-RPC messages with a `Content-Length`
+//! header.
+
+use eyre::{Context, Result, eyre};
+use serde_json::Value;
+use std::io::{BufRead, Write};
+
+/// Reads the next message, returning `None` at the end of the input.
+pub(super) fn read_message(reader: &mut impl BufRead) -> Result<Option<Value>> {
+    let mut content_length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.eq_ignore_ascii_case("content-length")
+        {
+            content_length =
+                Some(value.trim().parse::<usize>().wrap_err("invalid content length")?);
+        }
+    }
+    let content_length = content_length.ok_or_else(|| eyre!("missing content length"))?;
+    let mut content = vec![0; content_length];
+    reader.read_exact(&mut content)?;
+    Ok(Some(serde_json::from_slice(&content)?))
+}
+
+/// Writes a message.
+pub(super) fn write_message(writer: &mut impl Write, message: &Value) -> Result<()> {
+    let content = serde_json::to_string(message)?;
+    write!(writer, "Content-Length: {}\r\n\r\n{content}", content.len())?;
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn roundtrip() {
+        let message = json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}});
+        let mut buf = Vec::new();
+        write_message(&mut buf, &message).unwrap();
+        write_message(&mut buf, &message).unwrap();
+
+        let mut reader = buf.as_slice();
+        assert_eq!(read_message(&mut reader).unwrap(), Some(message.clone()));
+        assert_eq!(read_message(&mut reader).unwrap(), Some(message));
+        assert_eq!(read_message(&mut reader).unwrap(), None);
+    }
+}
@@ -6,14 +6,18 @@
 //! [`foundry_config::Config`].
 
 pub mod bind;
+pub mod bind_events;
 pub mod bind_json;
+pub mod broadcast;
 pub mod build;
 pub mod cache;
+pub mod clean;
 pub mod clone;
 pub mod compiler;
 pub mod config;
 pub mod coverage;
 pub mod create;
+pub mod daemon;
 pub mod doc;
 pub mod eip712;
 pub mod flatten;
@@ -24,8 +28,10 @@
 pub mod inspect;
 pub mod install;
 pub mod lint;
+pub mod lsp;
 pub mod remappings;
 pub mod remove;
+pub mod run_est;
 pub mod selectors;
 pub mod snapshot;
 pub mod soldeer;
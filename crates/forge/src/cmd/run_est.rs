@@ -0,0 +1,523 @@
+//! `forge run-est`: runs the state tests of the Ethereum execution-spec-tests.
+//!
+//! The fixtures are the `state_test` JSON files generated by
+//! [execution-spec-tests](https://github.com/ethereum/execution-spec-tests), also used by the
+//! `GeneralStateTests` of [ethereum/tests](https://github.com/ethereum/tests). Each transaction of
+//! a fixture is executed on top of its pre-state with the EVM bundled with forge, and the resulting
+//! state root and logs are compared to the expectations of every fork.
+
+use alloy_consensus::{TxEip1559, TxEip2930, TxEip4844, TxEip7702, TxLegacy};
+use alloy_eips::{eip2930::AccessList, eip7702::SignedAuthorization};
+use alloy_evm::{EthEvmFactory, Evm, EvmEnv, EvmFactory, FromRecoveredTx};
+use alloy_primitives::{
+    Address, B256, Bytes, Log, TxKind, U256, keccak256,
+    map::{AddressMap, U256Map},
+};
+use alloy_rlp::Encodable;
+use alloy_signer_local::PrivateKeySigner;
+use alloy_trie::{EMPTY_ROOT_HASH, HashBuilder, Nibbles};
+use clap::{Parser, ValueHint};
+use eyre::{Context, OptionExt, Result};
+use foundry_cli::utils;
+use foundry_common::{fs, shell};
+use foundry_evm::utils::get_blob_base_fee_update_fraction_by_spec_id;
+use foundry_evm_networks::NetworkConfigs;
+use rayon::prelude::*;
+use revm::{
+    context::{BlockEnv, CfgEnv, TxEnv},
+    context_interface::block::BlobExcessGasAndPrice,
+    database::{AccountState, CacheDB, DbAccount, EmptyDB},
+    primitives::hardfork::SpecId,
+    state::{AccountInfo, Bytecode},
+};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+/// CLI arguments for `forge run-est`.
+#[derive(Clone, Debug, Parser)]
+pub struct RunEstArgs {
+    /// The state test fixtures to run, as JSON files or directories containing them.
+    #[arg(required = true, value_hint = ValueHint::AnyPath, value_name = "FIXTURES")]
+    pub paths: Vec<PathBuf>,
+
+    /// Only run the expectations of the given forks, e.g. `Cancun`.
+    #[arg(long = "fork", value_name = "FORK")]
+    pub forks: Vec<String>,
+
+    /// Only run the tests whose name matches the specified regex pattern.
+    #[arg(long = "match", value_name = "REGEX")]
+    pub pattern: Option<regex::Regex>,
+
+    /// The project's root path.
+    ///
+    /// The precompiles of the network configured by the project are added to the EVM.
+    ///
+    /// By default root of the Git repository, if in one,
+    /// or the current working directory.
+    #[arg(long, value_hint = ValueHint::DirPath, value_name = "PATH")]
+    pub root: Option<PathBuf>,
+}
+
+impl RunEstArgs {
+    pub fn run(self) -> Result<()> {
+        let config = utils::load_config_with_root(self.root.as_deref())?;
+
+        let mut files = Vec::new();
+        for path in &self.paths {
+            if path.is_dir() {
+                files.extend(fs::json_files(path));
+            } else {
+                files.push(path.clone());
+            }
+        }
+
+        let results = files
+            .par_iter()
+            .map(|file| self.run_file(file, config.networks))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        let report = EstReport::new(results);
+
+        if shell::is_json() {
+            sh_println!("{}", serde_json::to_string_pretty(&report)?)?;
+        } else {
+            report.print()?;
+        }
+
+        let failed = report.forks.values().map(|summary| summary.failed).sum::<usize>();
+        if failed > 0 {
+            eyre::bail!("{failed} state test cases failed");
+        }
+        Ok(())
+    }
+
+    /// Runs the state tests of a fixture file.
+    fn run_file(&self, file: &Path, networks: NetworkConfigs) -> Result<Vec<CaseResult>> {
+        let tests: BTreeMap<String, StateTest> = fs::read_json_file(file)
+            .wrap_err_with(|| format!("failed to read state test fixture {}", file.display()))?;
+
+        let mut results = Vec::new();
+        for (name, test) in &tests {
+            if self.pattern.as_ref().is_some_and(|pattern| !pattern.is_match(name)) {
+                continue;
+            }
+            for (fork, expectations) in &test.post {
+                if !self.forks.is_empty() && !self.forks.contains(fork) {
+                    continue;
+                }
+                for expected in expectations {
+                    let status = match fork_spec(fork) {
+                        Some(spec) => test.run_case(spec, expected, networks),
+                        None => CaseStatus::Skipped(format!("unsupported fork {fork}")),
+                    };
+                    results.push(CaseResult {
+                        name: name.clone(),
+                        fork: fork.clone(),
+                        indexes: expected.indexes,
+                        status,
+                    });
+                }
+            }
+        }
+        Ok(results)
+    }
+}
+
+/// Returns the spec of a fork, as named by the fixtures.
+fn fork_spec(fork: &str) -> Option<SpecId> {
+    Some(match fork {
+        "Frontier" => SpecId::FRONTIER,
+        "Homestead" => SpecId::HOMESTEAD,
+        "EIP150" | "Tangerine" => SpecId::TANGERINE,
+        "EIP158" | "SpuriousDragon" => SpecId::SPURIOUS_DRAGON,
+        "Byzantium" => SpecId::BYZANTIUM,
+        "Constantinople" => SpecId::CONSTANTINOPLE,
+        "ConstantinopleFix" | "Petersburg" => SpecId::PETERSBURG,
+        "Istanbul" => SpecId::ISTANBUL,
+        "Berlin" => SpecId::BERLIN,
+        "London" => SpecId::LONDON,
+        "Merge" | "Paris" => SpecId::MERGE,
+        "Shanghai" => SpecId::SHANGHAI,
+        "Cancun" => SpecId::CANCUN,
+        "Prague" => SpecId::PRAGUE,
+        "Osaka" => SpecId::OSAKA,
+        _ => return None,
+    })
+}
+
+/// A state test, with a transaction whose data, gas limit and value vary across test cases.
+#[derive(Debug, Deserialize)]
+struct StateTest {
+    env: TestEnv,
+    pre: BTreeMap<Address, TestAccount>,
+    transaction: TestTransaction,
+    /// The expected post-state of each test case, by fork.
+    post: BTreeMap<String, Vec<PostState>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TestEnv {
+    current_coinbase: Address,
+    current_difficulty: U256,
+    current_gas_limit: U256,
+    current_number: U256,
+    current_timestamp: U256,
+    current_base_fee: Option<U256>,
+    current_random: Option<B256>,
+    current_excess_blob_gas: Option<U256>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TestAccount {
+    balance: U256,
+    #[serde(default)]
+    code: Bytes,
+    nonce: U256,
+    #[serde(default)]
+    storage: BTreeMap<U256, U256>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TestTransaction {
+    data: Vec<Bytes>,
+    gas_limit: Vec<U256>,
+    value: Vec<U256>,
+    nonce: U256,
+    secret_key: B256,
+    sender: Option<Address>,
+    #[serde(default, deserialize_with = "deserialize_to")]
+    to: Option<Address>,
+    gas_price: Option<U256>,
+    max_fee_per_gas: Option<U256>,
+    max_priority_fee_per_gas: Option<U256>,
+    max_fee_per_blob_gas: Option<U256>,
+    blob_versioned_hashes: Option<Vec<B256>>,
+    access_lists: Option<Vec<Option<AccessList>>>,
+    authorization_list: Option<Vec<SignedAuthorization>>,
+}
+
+/// Deserializes the recipient of a transaction, which is empty for contract creations.
+fn deserialize_to<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Address>, D::Error> {
+    let to = Option::<String>::deserialize(deserializer)?.unwrap_or_default();
+    if to.is_empty() { Ok(None) } else { to.parse().map(Some).map_err(serde::de::Error::custom) }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PostState {
+    /// The expected state root.
+    hash: B256,
+    /// The expected hash of the RLP encoded logs.
+    logs: B256,
+    indexes: CaseIndexes,
+    /// The reason the transaction is expected to be invalid, if any.
+    expect_exception: Option<String>,
+}
+
+/// The indexes of the data, gas limit and value of the transaction of a test case.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+struct CaseIndexes {
+    data: usize,
+    gas: usize,
+    value: usize,
+}
+
+impl StateTest {
+    /// Runs a test case and compares its outcome with the expected post-state.
+    fn run_case(&self, spec: SpecId, expected: &PostState, networks: NetworkConfigs) -> CaseStatus {
+        let tx = match self.transaction.tx_env(expected.indexes) {
+            Ok(tx) => tx,
+            Err(err) => return CaseStatus::Skipped(format!("{err:#}")),
+        };
+
+        let mut db = CacheDB::new(EmptyDB::default());
+        for (address, account) in &self.pre {
+            let code = Bytecode::new_raw(account.code.clone());
+            let info = AccountInfo {
+                balance: account.balance,
+                nonce: account.nonce.saturating_to(),
+                code_hash: code.hash_slow(),
+                code: Some(code),
+                ..Default::default()
+            };
+            db.insert_account_info(*address, info);
+            for (slot, value) in &account.storage {
+                db.insert_account_storage(*address, *slot, *value).expect("infallible");
+            }
+        }
+
+        let mut evm = EthEvmFactory::default().create_evm(db, self.evm_env(spec));
+        networks.inject_precompiles(evm.precompiles_mut());
+        let (logs, error) = match evm.transact_commit(tx) {
+            Ok(result) => (result.into_logs(), None),
+            Err(err) => (Vec::new(), Some(err.to_string())),
+        };
+        let db = evm.into_db();
+
+        match (&expected.expect_exception, error) {
+            (Some(exception), None) => {
+                return CaseStatus::Failed(format!("expected exception {exception}"));
+            }
+            (None, Some(error)) => {
+                return CaseStatus::Failed(format!("unexpected exception: {error}"));
+            }
+            _ => {}
+        }
+        let logs_hash = logs_hash(&logs);
+        if logs_hash != expected.logs {
+            return CaseStatus::Failed(format!(
+                "logs hash mismatch: expected {}, got {logs_hash}",
+                expected.logs
+            ));
+        }
+        let state_root = state_root(&db.cache.accounts, spec);
+        if state_root != expected.hash {
+            return CaseStatus::Failed(format!(
+                "state root mismatch: expected {}, got {state_root}",
+                expected.hash
+            ));
+        }
+        CaseStatus::Passed
+    }
+
+    fn evm_env(&self, spec: SpecId) -> EvmEnv {
+        let env = &self.env;
+        let block = BlockEnv {
+            number: env.current_number,
+            beneficiary: env.current_coinbase,
+            timestamp: env.current_timestamp,
+            gas_limit: env.current_gas_limit.saturating_to(),
+            basefee: env.current_base_fee.unwrap_or_default().saturating_to(),
+            difficulty: env.current_difficulty,
+            prevrandao: if spec >= SpecId::MERGE { env.current_random } else { None },
+            blob_excess_gas_and_price: env
+                .current_excess_blob_gas
+                .filter(|_| spec >= SpecId::CANCUN)
+                .map(|excess| {
+                    BlobExcessGasAndPrice::new(
+                        excess.saturating_to(),
+                        get_blob_base_fee_update_fraction_by_spec_id(spec),
+                    )
+                }),
+            ..Default::default()
+        };
+        EvmEnv::new(CfgEnv::new_with_spec(spec), block)
+    }
+}
+
+impl TestTransaction {
+    /// Returns the transaction of a test case.
+    fn tx_env(&self, indexes: CaseIndexes) -> Result<TxEnv> {
+        let sender = match self.sender {
+            Some(sender) => sender,
+            None => PrivateKeySigner::from_bytes(&self.secret_key)?.address(),
+        };
+        let input = self.data.get(indexes.data).cloned().ok_or_eyre("data index out of bounds")?;
+        let gas_limit = self.gas_limit.get(indexes.gas).ok_or_eyre("gas index out of bounds")?;
+        let gas_limit = gas_limit.saturating_to();
+        let value = *self.value.get(indexes.value).ok_or_eyre("value index out of bounds")?;
+        let nonce = self.nonce.saturating_to();
+        let to = self.to.map_or(TxKind::Create, TxKind::Call);
+        let access_list = self
+            .access_lists
+            .as_ref()
+            .and_then(|access_lists| access_lists.get(indexes.data).cloned().flatten());
+        let max_fee_per_gas = self.max_fee_per_gas.unwrap_or_default().saturating_to();
+        let max_priority_fee_per_gas =
+            self.max_priority_fee_per_gas.unwrap_or_default().saturating_to();
+        // Every typed transaction of the fixtures is signed for mainnet.
+        let chain_id = 1;
+
+        Ok(if let Some(authorization_list) = &self.authorization_list {
+            let tx = TxEip7702 {
+                chain_id,
+                nonce,
+                gas_limit,
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                to: self.to.ok_or_eyre("EIP-7702 transactions cannot create contracts")?,
+                value,
+                access_list: access_list.unwrap_or_default(),
+                authorization_list: authorization_list.clone(),
+                input,
+            };
+            TxEnv::from_recovered_tx(&tx, sender)
+        } else if let Some(blob_versioned_hashes) = &self.blob_versioned_hashes {
+            let tx = TxEip4844 {
+                chain_id,
+                nonce,
+                gas_limit,
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                to: self.to.ok_or_eyre("blob transactions cannot create contracts")?,
+                value,
+                access_list: access_list.unwrap_or_default(),
+                blob_versioned_hashes: blob_versioned_hashes.clone(),
+                max_fee_per_blob_gas: self.max_fee_per_blob_gas.unwrap_or_default().saturating_to(),
+                input,
+            };
+            TxEnv::from_recovered_tx(&tx, sender)
+        } else if self.max_fee_per_gas.is_some() {
+            let tx = TxEip1559 {
+                chain_id,
+                nonce,
+                gas_limit,
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                to,
+                value,
+                access_list: access_list.unwrap_or_default(),
+                input,
+            };
+            TxEnv::from_recovered_tx(&tx, sender)
+        } else {
+            let gas_price = self.gas_price.unwrap_or_default().saturating_to();
+            if let Some(access_list) = access_list {
+                let tx = TxEip2930 {
+                    chain_id,
+                    nonce,
+                    gas_price,
+                    gas_limit,
+                    to,
+                    value,
+                    access_list,
+                    input,
+                };
+                TxEnv::from_recovered_tx(&tx, sender)
+            } else {
+                let tx = TxLegacy { chain_id: None, nonce, gas_price, gas_limit, to, value, input };
+                TxEnv::from_recovered_tx(&tx, sender)
+            }
+        })
+    }
+}
+
+/// Returns the hash of the RLP encoded logs.
+fn logs_hash(logs: &[Log]) -> B256 {
+    let mut out = Vec::new();
+    logs.encode(&mut out);
+    keccak256(out)
+}
+
+/// Returns the state root of the accounts, excluding the empty accounts removed by EIP-161.
+fn state_root(accounts: &AddressMap<DbAccount>, spec: SpecId) -> B256 {
+    let mut leaves = accounts
+        .iter()
+        .filter(|(_, account)| {
+            account.account_state != AccountState::NotExisting
+                && !(spec >= SpecId::SPURIOUS_DRAGON && account.info.is_empty())
+        })
+        .map(|(address, account)| {
+            let mut rlp = Vec::new();
+            let storage_root = storage_root(&account.storage);
+            let fields: [&dyn Encodable; 4] = [
+                &account.info.nonce,
+                &account.info.balance,
+                &storage_root,
+                &account.info.code_hash,
+            ];
+            alloy_rlp::encode_list::<_, dyn Encodable>(&fields, &mut rlp);
+            (Nibbles::unpack(keccak256(address)), rlp)
+        })
+        .collect::<Vec<_>>();
+    leaves.sort_by_key(|(key, _)| *key);
+    build_root(leaves)
+}
+
+fn storage_root(storage: &U256Map<U256>) -> B256 {
+    let mut leaves = storage
+        .iter()
+        .filter(|(_, value)| !value.is_zero())
+        .map(|(slot, value)| {
+            (Nibbles::unpack(keccak256(slot.to_be_bytes::<32>())), alloy_rlp::encode(value))
+        })
+        .collect::<Vec<_>>();
+    if leaves.is_empty() {
+        return EMPTY_ROOT_HASH;
+    }
+    leaves.sort_by_key(|(key, _)| *key);
+    build_root(leaves)
+}
+
+fn build_root(leaves: Vec<(Nibbles, Vec<u8>)>) -> B256 {
+    let mut builder = HashBuilder::default();
+    for (key, value) in leaves {
+        builder.add_leaf(key, &value);
+    }
+    builder.root()
+}
+
+/// The outcome of a test case.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum CaseStatus {
+    Passed,
+    Failed(String),
+    Skipped(String),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CaseResult {
+    name: String,
+    fork: String,
+    indexes: CaseIndexes,
+    status: CaseStatus,
+}
+
+/// The number of test cases of a fork by outcome.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ForkSummary {
+    passed: usize,
+    failed: usize,
+    skipped: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EstReport {
+    forks: BTreeMap<String, ForkSummary>,
+    /// The test cases that did not pass.
+    cases: Vec<CaseResult>,
+}
+
+impl EstReport {
+    fn new(results: Vec<CaseResult>) -> Self {
+        let mut forks = BTreeMap::<String, ForkSummary>::new();
+        for result in &results {
+            let summary = forks.entry(result.fork.clone()).or_default();
+            match result.status {
+                CaseStatus::Passed => summary.passed += 1,
+                CaseStatus::Failed(_) => summary.failed += 1,
+                CaseStatus::Skipped(_) => summary.skipped += 1,
+            }
+        }
+        let cases = results.into_iter().filter(|result| result.status != CaseStatus::Passed);
+        Self { forks, cases: cases.collect() }
+    }
+
+    fn print(&self) -> Result<()> {
+        for case in &self.cases {
+            let CaseIndexes { data, gas, value } = case.indexes;
+            let id = format!("{} [{}] d{data}g{gas}v{value}", case.name, case.fork);
+            match &case.status {
+                CaseStatus::Failed(reason) => sh_println!("FAIL {id}: {reason}")?,
+                CaseStatus::Skipped(reason) => sh_println!("SKIP {id}: {reason}")?,
+                CaseStatus::Passed => {}
+            }
+        }
+        for (fork, summary) in &self.forks {
+            let ForkSummary { passed, failed, skipped } = summary;
+            sh_println!("{fork}: {passed} passed, {failed} failed, {skipped} skipped")?;
+        }
+        Ok(())
+    }
+}
@@ -6,12 +6,12 @@
     Cell, Color, Row, Table, modifiers::UTF8_ROUND_CORNERS, presets::ASCII_MARKDOWN,
 };
 use eyre::{Context, Result};
-use foundry_cli::utils::STATIC_FUZZ_SEED;
-use foundry_common::shell;
+use foundry_cli::utils::{LoadConfig, STATIC_FUZZ_SEED};
+use foundry_common::{ContractsByArtifact, TestFunctionExt, shell};
 use regex::Regex;
 use std::{
     cmp::Ordering,
-    fs,
+    fmt, fs,
     io::{self, BufRead},
     path::{Path, PathBuf},
     str::FromStr,
@@ -25,6 +25,11 @@
     Regex::new(r"(?P<file>(.*?)):(?P<sig>(\w+)\s*\((.*?)\))\s*\(((gas:)?\s*(?P<gas>\d+)|(runs:\s*(?P<runs>\d+),\s*μ:\s*(?P<avg>\d+),\s*~:\s*(?P<med>\d+))|(runs:\s*(?P<invruns>\d+),\s*calls:\s*(?P<calls>\d+),\s*reverts:\s*(?P<reverts>\d+)))\)").unwrap()
 });
 
+/// A regex that matches a contract size snapshot entry like
+/// `src/Counter.sol:Counter (size: 1234)`
+pub static RE_SIZE_SNAPSHOT_ENTRY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?P<contract>.+:\w+)\s*\(size:\s*(?P<size>\d+)\)$").unwrap());
+
 /// CLI arguments for `forge snapshot`.
 #[derive(Clone, Debug, Parser)]
 pub struct GasSnapshotArgs {
@@ -78,6 +83,12 @@ pub struct GasSnapshotArgs {
     #[arg(long, value_name = "ORDER")]
     diff_sort: Option<DiffSortOrder>,
 
+    /// Also track the deployed bytecode size of each contract of the project.
+    ///
+    /// Sizes are diffed and checked like gas, and the `--tolerance` applies to both.
+    #[arg(long)]
+    size: bool,
+
     /// All test arguments are supported
     #[command(flatten)]
     pub(crate) test: test::TestArgs,
@@ -104,26 +115,34 @@ pub async fn run(mut self) -> Result<()> {
 
         let outcome = self.test.compile_and_run().await?;
         outcome.ensure_ok(false)?;
+        let sizes = match (self.size, &outcome.known_contracts) {
+            (true, Some(known_contracts)) => {
+                let config = self.test.load_config()?;
+                contract_sizes(known_contracts, &config.root, &config.src)
+            }
+            _ => Vec::new(),
+        };
         let tests = self.config.apply(outcome);
 
         if let Some(path) = self.diff {
             let snap = path.as_ref().unwrap_or(&self.snap);
-            let snaps = read_gas_snapshot(snap)?;
+            let (snaps, size_snaps) = read_gas_snapshot(snap)?;
             diff(tests, snaps, self.diff_sort.unwrap_or_default())?;
+            if self.size {
+                diff_sizes(sizes, size_snaps)?;
+            }
         } else if let Some(path) = self.check {
             let snap = path.as_ref().unwrap_or(&self.snap);
-            let snaps = read_gas_snapshot(snap)?;
-            if check(tests, snaps, self.tolerance) {
-                std::process::exit(0)
-            } else {
-                std::process::exit(1)
-            }
+            let (snaps, size_snaps) = read_gas_snapshot(snap)?;
+            let gas_ok = check(tests, snaps, self.tolerance);
+            let sizes_ok = !self.size || check_sizes(sizes, size_snaps, self.tolerance);
+            if gas_ok && sizes_ok { std::process::exit(0) } else { std::process::exit(1) }
         } else {
             if matches!(self.format, Some(Format::Table)) {
                 let table = build_gas_snapshot_table(&tests);
                 sh_println!("\n{}", table)?;
             }
-            write_to_gas_snapshot_file(&tests, self.snap, self.format)?;
+            write_to_gas_snapshot_file(&tests, &sizes, self.snap, self.format)?;
         }
         Ok(())
     }
@@ -281,25 +300,95 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
     }
 }
 
-/// Reads a list of gas snapshot entries from a gas snapshot file.
-fn read_gas_snapshot(path: impl AsRef<Path>) -> Result<Vec<GasSnapshotEntry>> {
+/// A contract size entry in a snapshot file
+///
+/// Has the form `<source>:<contract> (size: 1234)`, the size being the deployed bytecode size in
+/// bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SizeSnapshotEntry {
+    pub contract: String,
+    pub size: usize,
+}
+
+impl fmt::Display for SizeSnapshotEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (size: {})", self.contract, self.size)
+    }
+}
+
+impl FromStr for SizeSnapshotEntry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RE_SIZE_SNAPSHOT_ENTRY
+            .captures(s)
+            .and_then(|cap| {
+                Some(Self {
+                    contract: cap.name("contract")?.as_str().to_string(),
+                    size: cap.name("size")?.as_str().parse().ok()?,
+                })
+            })
+            .ok_or_else(|| format!("Could not extract Size Snapshot Entry for {s}"))
+    }
+}
+
+/// Returns the deployed bytecode size of each contract in the `src` directory, sorted by contract.
+///
+/// Test and script contracts, and contracts without deployed bytecode, are skipped.
+fn contract_sizes(
+    known_contracts: &ContractsByArtifact,
+    root: &Path,
+    src: &Path,
+) -> Vec<SizeSnapshotEntry> {
+    let mut sizes = known_contracts
+        .iter()
+        .filter(|(id, _)| id.source.starts_with(src))
+        .filter(|(_, contract)| {
+            !contract.abi.functions().any(|f| {
+                f.test_function_kind().is_known()
+                    || matches!(f.name.as_str(), "IS_TEST" | "IS_SCRIPT")
+            })
+        })
+        .filter_map(|(id, contract)| {
+            let source = id.source.strip_prefix(root).unwrap_or(&id.source);
+            Some(SizeSnapshotEntry {
+                contract: format!("{}:{}", source.display(), id.name),
+                size: contract.deployed_bytecode()?.len(),
+            })
+        })
+        .collect::<Vec<_>>();
+    sizes.sort_by(|a, b| a.contract.cmp(&b.contract));
+    sizes
+}
+
+/// Reads the gas and contract size entries from a snapshot file.
+fn read_gas_snapshot(
+    path: impl AsRef<Path>,
+) -> Result<(Vec<GasSnapshotEntry>, Vec<SizeSnapshotEntry>)> {
     let path = path.as_ref();
     let mut entries = Vec::new();
+    let mut sizes = Vec::new();
     for line in io::BufReader::new(
         fs::File::open(path)
             .wrap_err(format!("failed to read snapshot file \"{}\"", path.display()))?,
     )
     .lines()
     {
-        entries
-            .push(GasSnapshotEntry::from_str(line?.as_str()).map_err(|err| eyre::eyre!("{err}"))?);
+        let line = line?;
+        if let Ok(size) = SizeSnapshotEntry::from_str(&line) {
+            sizes.push(size);
+            continue;
+        }
+        entries.push(GasSnapshotEntry::from_str(&line).map_err(|err| eyre::eyre!("{err}"))?);
     }
-    Ok(entries)
+    Ok((entries, sizes))
 }
 
-/// Writes a series of tests to a gas snapshot file after sorting them.
+/// Writes a series of tests to a gas snapshot file after sorting them, followed by the contract
+/// sizes, if any.
 fn write_to_gas_snapshot_file(
     tests: &[SuiteTestResult],
+    sizes: &[SizeSnapshotEntry],
     path: impl AsRef<Path>,
     _format: Option<Format>,
 ) -> Result<()> {
@@ -313,6 +402,8 @@ fn write_to_gas_snapshot_file(
     // sort all reports
     reports.sort();
 
+    reports.extend(sizes.iter().map(ToString::to_string));
+
     let content = reports.join("\n");
     Ok(fs::write(path, content)?)
 }
@@ -405,6 +496,89 @@ fn check(
     !has_diff
 }
 
+/// Compares the contract sizes with an existing snapshot.
+///
+/// Returns true if all sizes match.
+fn check_sizes(
+    sizes: Vec<SizeSnapshotEntry>,
+    snaps: Vec<SizeSnapshotEntry>,
+    tolerance: Option<u32>,
+) -> bool {
+    let snaps = snaps.into_iter().map(|s| (s.contract, s.size)).collect::<HashMap<_, _>>();
+    let mut has_diff = false;
+    for entry in sizes {
+        if let Some(&target_size) = snaps.get(&entry.contract) {
+            if !within_tolerance(entry.size as u64, target_size as u64, tolerance) {
+                let _ = sh_println!(
+                    "Diff in size of \"{}\": {} bytes, expected {} bytes",
+                    entry.contract,
+                    entry.size,
+                    target_size
+                );
+                has_diff = true;
+            }
+        } else {
+            let _ = sh_println!(
+                "No matching size snapshot entry found for \"{}\" in snapshot file",
+                entry.contract
+            );
+            has_diff = true;
+        }
+    }
+    !has_diff
+}
+
+/// Compares the contract sizes with an existing snapshot.
+fn diff_sizes(sizes: Vec<SizeSnapshotEntry>, snaps: Vec<SizeSnapshotEntry>) -> Result<()> {
+    let snaps = snaps.into_iter().map(|s| (s.contract, s.size)).collect::<HashMap<_, _>>();
+    let mut new_contracts = Vec::new();
+    let mut overall_size_change = 0i128;
+    let mut overall_size = 0i128;
+
+    sh_println!("\n{}", "Contract sizes:".yellow())?;
+    for entry in sizes {
+        let Some(&target_size) = snaps.get(&entry.contract) else {
+            new_contracts.push(entry.contract);
+            continue;
+        };
+        let size_change = entry.size as i128 - target_size as i128;
+        overall_size_change += size_change;
+        overall_size += target_size as i128;
+
+        let icon = match size_change.cmp(&0) {
+            Ordering::Greater => "↑".red().to_string(),
+            Ordering::Less => "↓".green().to_string(),
+            Ordering::Equal => "━".to_string(),
+        };
+        let size_diff = if target_size > 0 { size_change as f64 / target_size as f64 } else { 0.0 };
+        sh_println!(
+            "{} {} (size: {} → {} | {} {})",
+            icon,
+            entry.contract,
+            target_size,
+            entry.size,
+            fmt_change(size_change),
+            fmt_pct_change(size_diff)
+        )?;
+    }
+
+    if !new_contracts.is_empty() {
+        sh_println!("\n{}", "New contracts:".yellow())?;
+        for contract in new_contracts {
+            sh_println!("  {} {}", "+".green(), contract)?;
+        }
+    }
+
+    let overall_size_diff =
+        if overall_size > 0 { overall_size_change as f64 / overall_size as f64 } else { 0.0 };
+    sh_println!(
+        "Overall size change: {} ({})",
+        fmt_change(overall_size_change),
+        fmt_pct_change(overall_size_diff)
+    )?;
+    Ok(())
+}
+
 /// Compare the set of tests with an existing gas snapshot.
 fn diff(
     tests: Vec<SuiteTestResult>,
@@ -578,6 +752,20 @@ fn test_tolerance() {
         assert!(within_tolerance(100, 100, None));
     }
 
+    #[test]
+    fn can_parse_size_snapshot_entry() {
+        let s = "src/Counter.sol:Counter (size: 1234)";
+        let entry = SizeSnapshotEntry::from_str(s).unwrap();
+        assert_eq!(
+            entry,
+            SizeSnapshotEntry { contract: "src/Counter.sol:Counter".to_string(), size: 1234 }
+        );
+        assert_eq!(entry.to_string(), s);
+
+        // Gas entries are not size entries.
+        assert!(SizeSnapshotEntry::from_str("Test:deposit() (gas: 7222)").is_err());
+    }
+
     #[test]
     fn can_parse_basic_gas_snapshot_entry() {
         let s = "Test:deposit() (gas: 7222)";
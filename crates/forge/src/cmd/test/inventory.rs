@@ -0,0 +1,256 @@
+//! Test inventory: the test suites of a project and their tests, collected from the compiler
+//! output without executing anything.
+
+use crate::multi_runner::matches_artifact;
+use foundry_common::{TestFilter, TestFunctionExt};
+use foundry_compilers::ProjectCompileOutput;
+use regex::Regex;
+use serde::Serialize;
+use solar::ast;
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::LazyLock,
+};
+
+/// Matches the fork aliases passed as string literals to the fork cheatcodes.
+static FORK_ALIAS_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"\b(?:createSelectFork|createFork|rpcUrl)\(\s*"([^"]+)""#).unwrap()
+});
+
+/// The NatSpec tag declaring the tags of a test contract or function, e.g.
+/// `/// @custom:tags slow, integration`.
+const TAGS_NATSPEC: &str = "@custom:tags";
+
+/// A test suite of the inventory.
+#[derive(Clone, Debug, Serialize)]
+pub struct SuiteInventory {
+    /// The path of the source file, relative to the project root.
+    pub file: String,
+    /// The name of the test contract.
+    pub contract: String,
+    /// The line the contract is declared at.
+    pub line: Option<usize>,
+    /// The fork alias selected by `setUp`, if any.
+    pub fork: Option<String>,
+    /// The tags of the contract.
+    pub tags: Vec<String>,
+    /// The tests of the contract.
+    pub tests: Vec<TestInventory>,
+}
+
+/// A test of a [`SuiteInventory`].
+#[derive(Clone, Debug, Serialize)]
+pub struct TestInventory {
+    /// The name of the test function.
+    pub name: String,
+    /// The signature of the test function.
+    pub signature: String,
+    /// The kind of test, e.g. `test`, `fuzz` or `invariant`.
+    pub kind: &'static str,
+    /// The line the test function is declared at, in the file declaring it.
+    pub line: Option<usize>,
+    /// The file declaring the test function, if inherited from another file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub declared_in: Option<String>,
+    /// The fork alias required by the test, selected by the test itself or by `setUp`.
+    pub fork: Option<String>,
+    /// The tags of the test, including the tags of its contract.
+    pub tags: Vec<String>,
+}
+
+/// Lists the test suites matching the filter, with the location, fork alias and tags of their
+/// tests.
+pub fn inventory(
+    output: &ProjectCompileOutput,
+    root: &Path,
+    filter: &dyn TestFilter,
+) -> Vec<SuiteInventory> {
+    let decls = Declarations::parse(output, root);
+
+    let mut suites = Vec::new();
+    for (id, artifact) in output.artifact_ids() {
+        let id = id.with_stripped_file_prefixes(root);
+        let Some(abi) = artifact.abi.as_ref() else { continue };
+        // Same as the test contracts deployed by the runner.
+        let is_test_contract = abi.constructor.as_ref().is_none_or(|c| c.inputs.is_empty())
+            && abi.functions().any(|func| func.name.is_any_test())
+            && artifact.bytecode.as_ref().is_some_and(|b| b.object.is_non_empty_bytecode());
+        if !is_test_contract || !matches_artifact(filter, &id, abi) {
+            continue;
+        }
+
+        let contract = decls.get(&id.source, &id.name);
+        let tags = contract.map(|c| c.tags.clone()).unwrap_or_default();
+        let fork = decls
+            .find_function(&id.source, &id.name, "setUp")
+            .and_then(|(_, setup)| setup.fork.clone());
+
+        let tests = abi
+            .functions()
+            .filter(|func| filter.matches_test_function(func))
+            .map(|func| {
+                let decl = decls.find_function(&id.source, &id.name, &func.name);
+                let mut test_tags = tags.clone();
+                if let Some((_, decl)) = decl {
+                    test_tags.extend(decl.tags.iter().filter(|t| !tags.contains(t)).cloned());
+                }
+                TestInventory {
+                    name: func.name.clone(),
+                    signature: func.signature(),
+                    kind: func.test_function_kind().name(),
+                    line: decl.map(|(_, decl)| decl.line),
+                    declared_in: decl
+                        .map(|(file, _)| file)
+                        .filter(|file| *file != id.source)
+                        .map(|file| file.display().to_string()),
+                    fork: decl.and_then(|(_, decl)| decl.fork.clone()).or_else(|| fork.clone()),
+                    tags: test_tags,
+                }
+            })
+            .collect();
+
+        suites.push(SuiteInventory {
+            file: id.source.display().to_string(),
+            contract: id.name.clone(),
+            line: contract.map(|c| c.line),
+            fork,
+            tags,
+            tests,
+        });
+    }
+    suites.sort_by(|a, b| (&a.file, &a.contract).cmp(&(&b.file, &b.contract)));
+    suites
+}
+
+/// The contracts declared in the sources of a project, indexed by file and name.
+#[derive(Default)]
+struct Declarations {
+    contracts: HashMap<(PathBuf, String), ContractDecl>,
+}
+
+/// A contract declaration.
+struct ContractDecl {
+    line: usize,
+    tags: Vec<String>,
+    /// The names of the direct bases, as written in the declaration.
+    bases: Vec<String>,
+    functions: HashMap<String, FunctionDecl>,
+}
+
+/// A function declaration.
+struct FunctionDecl {
+    line: usize,
+    tags: Vec<String>,
+    /// The first fork alias used by the function.
+    fork: Option<String>,
+}
+
+impl Declarations {
+    /// Collects the declarations from the ASTs of the compiled sources.
+    fn parse(output: &ProjectCompileOutput, root: &Path) -> Self {
+        let files: Vec<_> = output.output().sources.as_ref().keys().cloned().collect();
+        output.parser().solc().compiler().enter(|compiler| {
+            let sm = compiler.sess().source_map();
+            let mut decls = Self::default();
+            for file in &files {
+                let Some((_, source)) = compiler.gcx().get_ast_source(root.join(file)) else {
+                    continue;
+                };
+                let Some(ast) = &source.ast else { continue };
+                let file = file.strip_prefix(root).unwrap_or(file);
+                for item in ast.items.iter() {
+                    let ast::ItemKind::Contract(contract) = &item.kind else { continue };
+                    let mut functions = HashMap::new();
+                    for item in contract.body.iter() {
+                        let ast::ItemKind::Function(function) = &item.kind else { continue };
+                        let Some(name) = function.header.name else { continue };
+                        let fork = sm.span_to_snippet(item.span).ok().and_then(|body| {
+                            FORK_ALIAS_RE
+                                .captures_iter(&body)
+                                .map(|c| c[1].to_string())
+                                .find(|alias| !alias.contains("://"))
+                        });
+                        functions.entry(name.to_string()).or_insert_with(|| FunctionDecl {
+                            line: sm.lookup_char_pos(item.span.lo()).line,
+                            tags: natspec_tags(item),
+                            fork,
+                        });
+                    }
+                    decls.contracts.insert(
+                        (file.to_path_buf(), contract.name.to_string()),
+                        ContractDecl {
+                            line: sm.lookup_char_pos(item.span.lo()).line,
+                            tags: natspec_tags(item),
+                            bases: contract
+                                .bases
+                                .iter()
+                                .map(|base| base.name.last().name.to_string())
+                                .collect(),
+                            functions,
+                        },
+                    );
+                }
+            }
+            decls
+        })
+    }
+
+    /// Returns the declaration of a contract, preferring the given file.
+    fn get(&self, file: &Path, name: &str) -> Option<&ContractDecl> {
+        self.get_with_file(file, name).map(|(_, decl)| decl)
+    }
+
+    fn get_with_file(&self, file: &Path, name: &str) -> Option<(&Path, &ContractDecl)> {
+        if let Some((key, decl)) = self.contracts.get_key_value(&(file.to_path_buf(), name.into()))
+        {
+            return Some((&key.0, decl));
+        }
+        self.contracts
+            .iter()
+            .find(|((_, contract), _)| contract == name)
+            .map(|((file, _), decl)| (file.as_path(), decl))
+    }
+
+    /// Finds the declaration of a function of a contract, looking up its bases from the most
+    /// derived one.
+    ///
+    /// Returns the file declaring the function along with its declaration.
+    fn find_function(
+        &self,
+        file: &Path,
+        contract: &str,
+        function: &str,
+    ) -> Option<(&Path, &FunctionDecl)> {
+        let mut stack = vec![(file, contract.to_string())];
+        let mut visited = HashSet::new();
+        while let Some((file, contract)) = stack.pop() {
+            if !visited.insert(contract.clone()) {
+                continue;
+            }
+            let Some((file, decl)) = self.get_with_file(file, &contract) else { continue };
+            if let Some(function) = decl.functions.get(function) {
+                return Some((file, function));
+            }
+            // The last base is the most derived one, so it's visited first.
+            stack.extend(decl.bases.iter().map(|base| (file, base.clone())));
+        }
+        None
+    }
+}
+
+/// Returns the tags declared with `@custom:tags` in the NatSpec of an item.
+fn natspec_tags(item: &ast::Item<'_>) -> Vec<String> {
+    let mut tags = Vec::new();
+    for doc in item.docs.iter() {
+        for line in doc.symbol.as_str().lines() {
+            let Some((_, line_tags)) = line.split_once(TAGS_NATSPEC) else { continue };
+            for tag in line_tags.split([',', ' ']).map(str::trim).filter(|tag| !tag.is_empty()) {
+                if !tags.iter().any(|t| t == tag) {
+                    tags.push(tag.to_string());
+                }
+            }
+        }
+    }
+    tags
+}
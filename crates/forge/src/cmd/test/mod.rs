@@ -3,6 +3,7 @@
     MultiContractRunner, MultiContractRunnerBuilder,
     decode::decode_console_logs,
     gas_report::GasReport,
+    hooks::run_hooks,
     multi_runner::matches_artifact,
     result::{SuiteResult, TestOutcome, TestStatus},
     traces::{
@@ -31,7 +32,7 @@
     utils::source_files_iter,
 };
 use foundry_config::{
-    Config, figment,
+    ArtifactField, Config, HookStage, figment,
     figment::{
         Metadata, Profile, Provider,
         value::{Dict, Map},
@@ -45,7 +46,10 @@
         TxEnvFor,
     },
     opts::EvmOpts,
-    traces::{backtrace::BacktraceBuilder, identifier::TraceIdentifiers, prune_trace_depth},
+    traces::{
+        backtrace::BacktraceBuilder, gas::attach_gas_breakdown, identifier::TraceIdentifiers,
+        prune_trace_depth,
+    },
 };
 use rand::Rng;
 use regex::Regex;
@@ -53,18 +57,23 @@
 use std::{
     collections::{BTreeMap, BTreeSet},
     fmt::Write,
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
     sync::{Arc, mpsc::channel},
     time::{Duration, Instant},
 };
 use yansi::Paint;
 
 mod filter;
+mod inventory;
 mod summary;
+mod ui;
 use crate::{result::TestKind, traces::render_trace_arena_inner};
 pub use filter::FilterArgs;
+pub use inventory::{SuiteInventory, TestInventory, inventory};
 use quick_junit::{NonSuccessKind, Report, TestCase, TestCaseStatus, TestSuite};
 use summary::{TestSummaryReport, format_invariant_metrics_table};
+pub(crate) use ui::strip_ansi;
+use ui::{TestUi, UiAction, UiTest};
 
 // Loads project's figment and merges the build cli arguments into it
 foundry_config::merge_impl_figment_convert!(TestArgs, build, evm);
@@ -87,6 +96,10 @@ pub struct TestArgs {
     ///
     /// If the matching test is a fuzz test, then it will open the debugger on the first failure
     /// case. If the fuzz test does not fail, it will open the debugger on the last fuzz case.
+    ///
+    /// Breakpoints, bookmarks and notes set in the debugger are saved per test in
+    /// `.forge/debug/<source path>/<contract>/` and restored the next time the test is debugged,
+    /// even after the code changed.
     #[arg(long, conflicts_with_all = ["flamegraph", "flamechart", "decode_internal", "rerun"])]
     debug: bool,
 
@@ -146,6 +159,14 @@ pub struct TestArgs {
     #[arg(long)]
     trace_depth: Option<usize>,
 
+    /// Annotate every call frame of the traces with its gas breakdown.
+    ///
+    /// Shows the gas used by every call frame on its return, split into its own and its
+    /// children's gas, and the gas refunded. Memory expansion costs are included when all
+    /// opcode steps are recorded, e.g. with `-vvvvv`.
+    #[arg(long, help_heading = "Display options")]
+    trace_gas: bool,
+
     /// Output test results as JUnit XML report.
     #[arg(long, conflicts_with_all = ["quiet", "json", "gas_report", "summary", "list", "show_progress"], help_heading = "Display options")]
     pub junit: bool,
@@ -186,6 +207,12 @@ pub struct TestArgs {
     #[arg(long)]
     pub rerun: bool,
 
+    /// Run the hooks configured in the `[hooks]` section of the config.
+    ///
+    /// Hooks run arbitrary shell commands, so they are skipped unless this flag is set.
+    #[arg(long)]
+    pub run_hooks: bool,
+
     /// Print test summary table.
     #[arg(long, help_heading = "Display options")]
     pub summary: bool,
@@ -194,10 +221,33 @@ pub struct TestArgs {
     #[arg(long, help_heading = "Display options", requires = "summary")]
     pub detailed: bool,
 
+    /// Report every contract that invoked a cheatcode, grouped by cheatcode.
+    #[arg(long, help_heading = "Display options")]
+    pub cheatcode_audit: bool,
+
+    /// Report the peak EVM memory, call depth, journal size and database cache size of each test.
+    #[arg(long, help_heading = "Display options")]
+    pub profile_host: bool,
+
     /// Disables the labels in the traces.
     #[arg(long, help_heading = "Display options")]
     pub disable_labels: bool,
 
+    /// Show the test progress and results in an interactive terminal UI.
+    ///
+    /// Results can be filtered and their traces expanded, and the selected test can be re-run or
+    /// opened in the debugger with a single key.
+    #[arg(
+        long,
+        conflicts_with_all = ["debug", "flamegraph", "flamechart", "list", "junit", "show_progress", "summary", "gas_report", "watch"],
+        help_heading = "Display options"
+    )]
+    pub ui: bool,
+
+    /// The action selected when exiting the UI.
+    #[arg(skip)]
+    ui_action: Option<UiAction>,
+
     #[command(flatten)]
     filter: FilterArgs,
 
@@ -214,9 +264,57 @@ pub struct TestArgs {
 impl TestArgs {
     pub async fn run(mut self) -> Result<TestOutcome> {
         trace!(target: "forge::test", "executing test command");
+        if self.ui {
+            return self.run_ui().await;
+        }
         self.compile_and_run().await
     }
 
+    /// Runs the tests in the interactive UI, re-running or debugging tests as selected in the UI
+    /// until the user quits it.
+    async fn run_ui(mut self) -> Result<TestOutcome> {
+        if shell::is_json() {
+            bail!("`--ui` can not be used with `--json`");
+        }
+        TestUi::check_terminal()?;
+
+        let (filter, path, rerun) = (self.filter.clone(), self.path.clone(), self.rerun);
+        loop {
+            let outcome = self.compile_and_run().await?;
+            match self.ui_action.take() {
+                None | Some(UiAction::Quit) => return Ok(outcome),
+                Some(UiAction::RerunAll) => {
+                    self.filter = filter.clone();
+                    self.path = path.clone();
+                    self.rerun = rerun;
+                }
+                Some(UiAction::Rerun { suite, signature }) => self.focus(&suite, &signature)?,
+                Some(UiAction::Debug { suite, signature }) => {
+                    self.focus(&suite, &signature)?;
+                    self.ui = false;
+                    self.debug = true;
+                    let result = self.compile_and_run().await;
+                    self.ui = true;
+                    self.debug = false;
+                    result?;
+                }
+            }
+        }
+    }
+
+    /// Restricts the filter to the test function `signature` of the test suite `suite`.
+    fn focus(&mut self, suite: &str, signature: &str) -> Result<()> {
+        let (path, contract) =
+            suite.rsplit_once(':').ok_or_else(|| eyre::eyre!("invalid test suite: {suite}"))?;
+        let name = signature.split('(').next().unwrap_or(signature);
+        self.path = None;
+        self.rerun = false;
+        self.filter.path_pattern = Some(path.parse()?);
+        self.filter.contract_pattern = Some(Regex::new(&format!("^{}$", regex::escape(contract)))?);
+        self.filter.test_pattern = Some(Regex::new(&format!("^{}$", regex::escape(name)))?);
+        Ok(())
+    }
+
     /// Returns a list of files that need to be compiled in order to run all the tests that match
     /// the given filter.
     ///
@@ -274,8 +372,10 @@ pub async fn compile_and_run(&mut self) -> Result<TestOutcome> {
             config = self.load_config()?;
         }
 
-        // Set up the project.
-        let project = config.project()?;
+        run_hooks(&config, HookStage::PreBuild, self.run_hooks)?;
+
+        // Set up the project, without the outputs that aren't needed to run tests.
+        let project = config.slim_project(&ArtifactField::UNUSED_BY_EXECUTION)?;
 
         let filter = self.filter(&config)?;
         trace!(target: "forge::test", ?filter, "using filter");
@@ -285,6 +385,7 @@ pub async fn compile_and_run(&mut self) -> Result<TestOutcome> {
             .quiet(shell::is_json() || self.junit)
             .files(self.get_sources_to_compile(&config, &filter)?);
         let output = compiler.compile(&project)?;
+        run_hooks(&config, HookStage::PostBuild, self.run_hooks)?;
 
         self.run_tests(&project.paths.root, config, evm_opts, &output, &filter, false).await
     }
@@ -316,12 +417,20 @@ pub async fn run_tests(
             .seed
             .or_else(|| Some(U256::from_be_bytes(rand::rng().random::<[u8; 32]>())));
 
+        // Listing tests doesn't execute anything, so it needs neither an EVM nor a fork.
+        if self.list {
+            return list(project_root, output, filter);
+        }
+
         // Create test options from general project settings and compiler output.
         let should_debug = self.debug;
         let should_draw = self.flamegraph || self.flamechart;
 
         // Determine executor verbosity.
-        if (self.gas_report && evm_opts.verbosity < 3) || self.flamegraph || self.flamechart {
+        if ((self.gas_report || self.ui) && evm_opts.verbosity < 3)
+            || self.flamegraph
+            || self.flamechart
+        {
             evm_opts.verbosity = 3;
         }
 
@@ -421,7 +530,7 @@ pub async fn run_tests(
 
         if should_debug {
             // Get first non-empty suite result. We will have only one such entry.
-            let (_, _, test_result) =
+            let (suite_name, test_name, test_result) =
                 outcome.remove_first().ok_or_eyre("no tests were executed")?;
 
             let sources =
@@ -443,7 +552,8 @@ pub async fn run_tests(
             if let Some(dump_path) = &self.dump {
                 debugger.dump_to_file(dump_path)?;
             } else {
-                debugger.try_run_tui()?;
+                let session = debug_session_file(project_root, &suite_name, &test_name);
+                debugger.with_session_file(session).try_run_tui()?;
             }
         }
 
@@ -453,7 +563,7 @@ pub async fn run_tests(
     /// Build the test runner and execute tests for a specific network type.
     #[allow(clippy::too_many_arguments)]
     async fn build_and_run_tests<FEN: FoundryEvmNetwork>(
-        &self,
+        &mut self,
         config: Config,
         evm_opts: EvmOpts,
         output: &ProjectCompileOutput,
@@ -474,6 +584,8 @@ async fn build_and_run_tests<FEN: FoundryEvmNetwork>(
             .sender(evm_opts.sender)
             .with_fork(evm_opts.get_fork(&config, evm_env.cfg_env.chain_id, fork_block))
             .enable_isolation(evm_opts.isolate)
+            .set_cheatcode_audit(self.cheatcode_audit)
+            .set_profile_host(self.profile_host)
             .fail_fast(self.fail_fast)
             .set_coverage(coverage)
             .build::<FEN, MultiCompiler>(output, evm_env, tx_env, evm_opts)?;
@@ -485,7 +597,7 @@ async fn build_and_run_tests<FEN: FoundryEvmNetwork>(
 
     /// Run all tests that matches the filter predicate from a test runner
     async fn run_tests_inner<FEN: FoundryEvmNetwork>(
-        &self,
+        &mut self,
         mut runner: MultiContractRunner<FEN>,
         config: Arc<Config>,
         verbosity: u8,
@@ -493,14 +605,13 @@ async fn run_tests_inner<FEN: FoundryEvmNetwork>(
         output: &ProjectCompileOutput,
     ) -> eyre::Result<TestOutcome> {
         let fuzz_seed = config.fuzz.seed;
-        if self.list {
-            return list(runner, filter);
-        }
 
         trace!(target: "forge::test", "running all tests");
 
-        // If we need to render to a serialized format, we should not print anything else to stdout.
-        let silent = self.gas_report && shell::is_json() || self.summary && shell::is_json();
+        // If we need to render to a serialized format, or to the UI, we should not print anything
+        // else to stdout.
+        let silent =
+            self.ui || self.gas_report && shell::is_json() || self.summary && shell::is_json();
 
         let num_filtered = runner.matching_test_functions(filter).count();
 
@@ -639,6 +750,8 @@ async fn run_tests_inner<FEN: FoundryEvmNetwork>(
 
         let mut any_test_failed = false;
         let mut backtrace_builder = None;
+        let ui = self.ui.then(|| TestUi::spawn(num_filtered));
+        let mut ui_closed = false;
         for (contract_name, mut suite_result) in rx {
             let tests = &mut suite_result.test_results;
             let has_tests = !tests.is_empty();
@@ -715,11 +828,16 @@ async fn run_tests_inner<FEN: FoundryEvmNetwork>(
                     // - 4: also display the setup trace for failed tests
                     // - 5..: display all traces for all tests, including storage changes
                     let should_include = match kind {
+                        // The UI displays the traces of every test, and the setup trace of failed
+                        // tests.
                         TraceKind::Execution => {
-                            (verbosity == 3 && result.status.is_failure()) || verbosity >= 4
+                            self.ui
+                                || (verbosity == 3 && result.status.is_failure())
+                                || verbosity >= 4
                         }
                         TraceKind::Setup => {
-                            (verbosity == 4 && result.status.is_failure()) || verbosity >= 5
+                            ((self.ui || verbosity == 4) && result.status.is_failure())
+                                || verbosity >= 5
                         }
                         TraceKind::Deployment => false,
                     };
@@ -731,10 +849,25 @@ async fn run_tests_inner<FEN: FoundryEvmNetwork>(
                             prune_trace_depth(arena, trace_depth);
                         }
 
+                        if self.trace_gas && !shell::is_json() {
+                            attach_gas_breakdown(arena);
+                        }
+
                         decoded_traces.push(render_trace_arena_inner(arena, false, verbosity > 4));
                     }
                 }
 
+                if let Some(ui) = &ui {
+                    ui_closed |= !ui.push(UiTest {
+                        suite: contract_name.clone(),
+                        signature: name.clone(),
+                        status: result.status,
+                        result: ui::strip_ansi(&result.short_result(name)),
+                        logs: decode_console_logs(&result.logs),
+                        traces: decoded_traces.iter().map(|trace| ui::strip_ansi(trace)).collect(),
+                    });
+                }
+
                 if !silent && show_traces && !decoded_traces.is_empty() {
                     sh_println!("Traces:")?;
                     for trace in &decoded_traces {
@@ -891,14 +1024,19 @@ async fn run_tests_inner<FEN: FoundryEvmNetwork>(
             // Add the suite result to the outcome.
             outcome.results.insert(contract_name, suite_result);
 
-            // Stop processing the remaining suites if any test failed and `fail_fast` is set.
-            if self.fail_fast && any_test_failed {
+            // Stop processing the remaining suites if any test failed and `fail_fast` is set, or
+            // if the UI was closed.
+            if (self.fail_fast && any_test_failed) || ui_closed {
                 break;
             }
         }
         outcome.last_run_decoder = Some(decoder);
         let duration = timer.elapsed();
 
+        if let Some(ui) = ui {
+            self.ui_action = Some(ui.finish(duration)?);
+        }
+
         trace!(target: "forge::test", len=outcome.results.len(), %any_test_failed, "done with results");
 
         if let Some(gas_report) = gas_report {
@@ -907,6 +1045,14 @@ async fn run_tests_inner<FEN: FoundryEvmNetwork>(
             outcome.gas_report = Some(finalized);
         }
 
+        if self.cheatcode_audit && !shell::is_json() {
+            sh_println!("\n{}", outcome.cheatcode_audit())?;
+        }
+
+        if self.profile_host && !shell::is_json() {
+            sh_println!("\n{}", outcome.host_profiles())?;
+        }
+
         if !self.summary && !shell::is_json() {
             sh_println!("{}", outcome.summary(duration))?;
         }
@@ -1002,25 +1148,32 @@ fn data(&self) -> Result<Map<Profile, Dict>, figment::Error> {
     }
 }
 
-/// Lists all matching tests
-fn list<FEN: FoundryEvmNetwork>(
-    runner: MultiContractRunner<FEN>,
+/// Lists all matching tests.
+///
+/// In JSON mode, prints the test inventory: every suite and test with its location, required
+/// fork alias and tags.
+fn list(
+    root: &Path,
+    output: &ProjectCompileOutput,
     filter: &ProjectPathsAwareFilter,
 ) -> Result<TestOutcome> {
-    let results = runner.list(filter);
+    let suites = inventory::inventory(output, root, filter);
 
     if shell::is_json() {
-        sh_println!("{}", serde_json::to_string(&results)?)?;
+        sh_println!("{}", serde_json::to_string(&suites)?)?;
     } else {
-        for (file, contracts) in &results {
-            sh_println!("{file}")?;
-            for (contract, tests) in contracts {
-                sh_println!("  {contract}")?;
-                sh_println!("    {}\n", tests.join("\n    "))?;
+        let mut last_file = None;
+        for suite in &suites {
+            if last_file != Some(&suite.file) {
+                sh_println!("{}", suite.file)?;
+                last_file = Some(&suite.file);
             }
+            sh_println!("  {}", suite.contract)?;
+            let tests = suite.tests.iter().map(|test| test.name.as_str()).collect::<Vec<_>>();
+            sh_println!("    {}\n", tests.join("\n    "))?;
         }
     }
-    Ok(TestOutcome::empty(Some(runner.known_contracts), false))
+    Ok(TestOutcome::empty(None, false))
 }
 
 /// Load persisted filter (with last test run failures) from file.
@@ -1057,6 +1210,19 @@ fn persist_run_failures(config: &Config, outcome: &TestOutcome) {
     }
 }
 
+/// Returns the file persisting the debugger session of a test.
+///
+/// Sessions are stored under the source path of the test contract, so that contracts with the same
+/// name don't share sessions.
+fn debug_session_file(root: &Path, suite_name: &str, test_name: &str) -> PathBuf {
+    let (source, contract) = suite_name.rsplit_once(':').unwrap_or(("", suite_name));
+    let test = test_name.split('(').next().unwrap_or(test_name);
+    let mut path = root.join(".forge").join("debug");
+    // Keep the session inside the debug directory, even for absolute source paths.
+    path.extend(Path::new(source).components().filter(|c| matches!(c, Component::Normal(_))));
+    path.join(contract).join(format!("{test}.json"))
+}
+
 /// Generate test report in JUnit XML report format.
 fn junit_xml_report(results: &BTreeMap<String, SuiteResult>, verbosity: u8) -> Report {
     let mut total_duration = Duration::default();
@@ -1123,6 +1289,43 @@ fn depth_trace() {
         assert!(args.trace_depth.is_some());
     }
 
+    #[test]
+    fn trace_gas() {
+        let args: TestArgs = TestArgs::parse_from(["foundry-cli", "-vvvv", "--trace-gas"]);
+        assert!(args.trace_gas);
+    }
+
+    #[test]
+    fn debug_session_path() {
+        let path = debug_session_file(
+            Path::new("/project"),
+            "test/Counter.t.sol:CounterTest",
+            "testFuzz_SetNumber(uint256)",
+        );
+        assert_eq!(
+            path,
+            Path::new(
+                "/project/.forge/debug/test/Counter.t.sol/CounterTest/testFuzz_SetNumber.json"
+            )
+        );
+    }
+
+    #[test]
+    fn ui_parse() {
+        let args: TestArgs = TestArgs::parse_from(["foundry-cli", "--ui"]);
+        assert!(args.ui);
+        assert!(TestArgs::try_parse_from(["foundry-cli", "--ui", "--debug"]).is_err());
+    }
+
+    #[test]
+    fn ui_focus() {
+        let mut args: TestArgs = TestArgs::parse_from(["foundry-cli", "--ui", "--mt", "test"]);
+        args.focus("test/Counter.t.sol:CounterTest", "testFuzz_SetNumber(uint256)").unwrap();
+        assert_eq!(args.filter.test_pattern.unwrap().as_str(), "^testFuzz_SetNumber$");
+        assert_eq!(args.filter.contract_pattern.unwrap().as_str(), "^CounterTest$");
+        assert_eq!(args.filter.path_pattern.unwrap().as_str(), "test/Counter.t.sol");
+    }
+
     // <https://github.com/foundry-rs/foundry/issues/5913>
     #[test]
     fn fuzz_seed_exists() {
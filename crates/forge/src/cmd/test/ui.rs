@@ -0,0 +1,527 @@
+//! Interactive terminal UI for `forge test --ui`.
+
+use crate::result::TestStatus;
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+use eyre::{Result, eyre};
+use ratatui::{
+    Frame, Terminal,
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Gauge, List, ListItem, ListState, Paragraph, Wrap},
+};
+use std::{
+    io::{self, IsTerminal},
+    sync::mpsc::{self, Receiver, Sender, TryRecvError},
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+
+/// How often the UI checks for new results while waiting for input.
+const TICK: Duration = Duration::from_millis(100);
+
+/// The result of a test, as displayed by the UI.
+#[derive(Clone, Debug)]
+pub(crate) struct UiTest {
+    /// The identifier of the suite, `path:contract_name`.
+    pub suite: String,
+    /// The signature of the test function.
+    pub signature: String,
+    pub status: TestStatus,
+    /// The result line, e.g. `[PASS] test() (gas: 1234)`.
+    pub result: String,
+    /// The decoded console logs.
+    pub logs: Vec<String>,
+    /// The rendered traces.
+    pub traces: Vec<String>,
+}
+
+/// What to do once the UI exits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum UiAction {
+    /// Exit `forge test`.
+    Quit,
+    /// Run all the tests matched by the original filter again.
+    RerunAll,
+    /// Run a single test again.
+    Rerun { suite: String, signature: String },
+    /// Run a single test in the debugger.
+    Debug { suite: String, signature: String },
+}
+
+enum UiEvent {
+    Test(Box<UiTest>),
+    Finished(Duration),
+}
+
+/// Handle to the UI running on its own thread.
+///
+/// Dropping it before [`finish`](Self::finish) closes the UI and restores the terminal.
+pub(crate) struct TestUi {
+    tx: Option<Sender<UiEvent>>,
+    handle: Option<JoinHandle<Result<UiAction>>>,
+}
+
+impl TestUi {
+    /// Returns an error if the UI can't be displayed.
+    pub(crate) fn check_terminal() -> Result<()> {
+        if io::stdout().is_terminal() {
+            Ok(())
+        } else {
+            Err(eyre!("`--ui` requires an interactive terminal"))
+        }
+    }
+
+    /// Starts the UI for a run of `total` tests.
+    pub(crate) fn spawn(total: usize) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let handle = std::thread::spawn(move || {
+            let mut terminal = TerminalGuard::new()?;
+            App::new(total).run(&mut terminal.terminal, &rx)
+        });
+        Self { tx: Some(tx), handle: Some(handle) }
+    }
+
+    /// Displays a test result. Returns `false` if the UI was closed.
+    pub(crate) fn push(&self, test: UiTest) -> bool {
+        self.tx.as_ref().is_some_and(|tx| tx.send(UiEvent::Test(Box::new(test))).is_ok())
+    }
+
+    /// Marks the run as finished and waits for the user to exit the UI.
+    pub(crate) fn finish(mut self, duration: Duration) -> Result<UiAction> {
+        let tx = self.tx.take().expect("UI already finished");
+        let _ = tx.send(UiEvent::Finished(duration));
+        match self.handle.take().expect("UI already finished").join() {
+            Ok(result) => result,
+            Err(payload) => std::panic::resume_unwind(payload),
+        }
+    }
+}
+
+impl Drop for TestUi {
+    fn drop(&mut self) {
+        // Disconnecting the channel exits the UI.
+        drop(self.tx.take());
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+type UiTerminal = Terminal<CrosstermBackend<io::Stdout>>;
+
+/// Sets the terminal up for the UI and restores it when dropped.
+struct TerminalGuard {
+    terminal: UiTerminal,
+}
+
+impl TerminalGuard {
+    fn new() -> Result<Self> {
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        if let Err(err) = execute!(stdout, EnterAlternateScreen) {
+            let _ = disable_raw_mode();
+            return Err(err.into());
+        }
+        let guard = Self { terminal: Terminal::new(CrosstermBackend::new(stdout))? };
+        Ok(guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = execute!(self.terminal.backend_mut(), LeaveAlternateScreen);
+        let _ = self.terminal.show_cursor();
+    }
+}
+
+/// The state of the UI.
+struct App {
+    tests: Vec<UiTest>,
+    total: usize,
+    started: Instant,
+    finished: Option<Duration>,
+    /// Only tests whose suite or signature contain this string are listed.
+    filter: String,
+    editing_filter: bool,
+    failures_only: bool,
+    /// Index of the selected test in the listed tests.
+    selected: usize,
+    /// Whether the traces of the selected test are expanded.
+    expanded: bool,
+    /// Scroll offset of the expanded traces.
+    scroll: u16,
+}
+
+impl App {
+    fn new(total: usize) -> Self {
+        Self {
+            tests: Vec::with_capacity(total),
+            total,
+            started: Instant::now(),
+            finished: None,
+            filter: String::new(),
+            editing_filter: false,
+            failures_only: false,
+            selected: 0,
+            expanded: false,
+            scroll: 0,
+        }
+    }
+
+    fn run(mut self, terminal: &mut UiTerminal, rx: &Receiver<UiEvent>) -> Result<UiAction> {
+        loop {
+            loop {
+                match rx.try_recv() {
+                    Ok(UiEvent::Test(test)) => self.tests.push(*test),
+                    Ok(UiEvent::Finished(duration)) => self.finished = Some(duration),
+                    Err(TryRecvError::Empty) => break,
+                    // The run was aborted before finishing.
+                    Err(TryRecvError::Disconnected) if self.finished.is_none() => {
+                        return Ok(UiAction::Quit);
+                    }
+                    Err(TryRecvError::Disconnected) => break,
+                }
+            }
+            terminal.draw(|f| self.draw(f))?;
+            if event::poll(TICK)?
+                && let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+                && let Some(action) = self.handle_key(key)
+            {
+                return Ok(action);
+            }
+        }
+    }
+
+    /// Returns the indices of the listed tests.
+    fn listed(&self) -> Vec<usize> {
+        let filter = self.filter.to_lowercase();
+        self.tests
+            .iter()
+            .enumerate()
+            .filter(|(_, test)| !self.failures_only || test.status.is_failure())
+            .filter(|(_, test)| {
+                filter.is_empty()
+                    || test.signature.to_lowercase().contains(&filter)
+                    || test.suite.to_lowercase().contains(&filter)
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    fn selected_test(&self) -> Option<&UiTest> {
+        self.listed().get(self.selected).map(|&i| &self.tests[i])
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> Option<UiAction> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Some(UiAction::Quit);
+        }
+
+        if self.editing_filter {
+            match key.code {
+                KeyCode::Char(c) => self.filter.push(c),
+                KeyCode::Backspace => {
+                    self.filter.pop();
+                }
+                KeyCode::Esc => {
+                    self.filter.clear();
+                    self.editing_filter = false;
+                }
+                KeyCode::Enter => self.editing_filter = false,
+                _ => {}
+            }
+            self.selected = 0;
+            return None;
+        }
+
+        if self.expanded {
+            match key.code {
+                KeyCode::Char('j') | KeyCode::Down => self.scroll = self.scroll.saturating_add(1),
+                KeyCode::Char('k') | KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+                KeyCode::PageDown => self.scroll = self.scroll.saturating_add(20),
+                KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(20),
+                KeyCode::Char('g') | KeyCode::Home => self.scroll = 0,
+                KeyCode::Enter | KeyCode::Esc | KeyCode::Char('q') => self.expanded = false,
+                _ => return self.handle_action_key(key),
+            }
+            return None;
+        }
+
+        let len = self.listed().len();
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Some(UiAction::Quit),
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.selected = (self.selected + 1).min(len.saturating_sub(1));
+            }
+            KeyCode::Char('k') | KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Char('g') | KeyCode::Home => self.selected = 0,
+            KeyCode::Char('G') | KeyCode::End => self.selected = len.saturating_sub(1),
+            KeyCode::Char('/') => self.editing_filter = true,
+            KeyCode::Char('f') => {
+                self.failures_only = !self.failures_only;
+                self.selected = 0;
+            }
+            KeyCode::Enter if len > 0 => {
+                self.expanded = true;
+                self.scroll = 0;
+            }
+            _ => return self.handle_action_key(key),
+        }
+        None
+    }
+
+    /// Handles the keys exiting the UI to re-run or debug tests.
+    fn handle_action_key(&self, key: KeyEvent) -> Option<UiAction> {
+        match key.code {
+            KeyCode::Char('R') => Some(UiAction::RerunAll),
+            KeyCode::Char('r') => self.selected_test().map(|test| UiAction::Rerun {
+                suite: test.suite.clone(),
+                signature: test.signature.clone(),
+            }),
+            KeyCode::Char('d') => self.selected_test().map(|test| UiAction::Debug {
+                suite: test.suite.clone(),
+                signature: test.signature.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    fn draw(&self, f: &mut Frame<'_>) {
+        let [header, body, footer] = Layout::new(
+            Direction::Vertical,
+            [Constraint::Length(3), Constraint::Min(3), Constraint::Length(1)],
+        )
+        .split(f.area())[..] else {
+            unreachable!()
+        };
+
+        self.draw_progress(f, header);
+        if self.expanded {
+            self.draw_traces(f, body);
+        } else {
+            let [list, details] = Layout::new(
+                Direction::Horizontal,
+                [Constraint::Percentage(50), Constraint::Percentage(50)],
+            )
+            .split(body)[..] else {
+                unreachable!()
+            };
+            self.draw_list(f, list);
+            self.draw_details(f, details);
+        }
+        self.draw_footer(f, footer);
+    }
+
+    fn draw_progress(&self, f: &mut Frame<'_>, area: Rect) {
+        let count = |status: TestStatus| self.tests.iter().filter(|t| t.status == status).count();
+        let elapsed = self.finished.unwrap_or_else(|| self.started.elapsed());
+        let state = if self.finished.is_some() { "finished" } else { "running" };
+        let label = format!(
+            "{}/{} tests {state} in {elapsed:.2?}: {} passed, {} failed, {} skipped",
+            self.tests.len(),
+            self.total,
+            count(TestStatus::Success),
+            count(TestStatus::Failure),
+            count(TestStatus::Skipped),
+        );
+        let ratio = if self.total == 0 { 1.0 } else { self.tests.len() as f64 / self.total as f64 };
+        let color = if count(TestStatus::Failure) > 0 { Color::Red } else { Color::Green };
+        let gauge = Gauge::default()
+            .block(Block::default().title(" forge test ").borders(Borders::ALL))
+            .gauge_style(Style::new().fg(color))
+            .ratio(ratio.min(1.0))
+            .label(label);
+        f.render_widget(gauge, area);
+    }
+
+    fn draw_list(&self, f: &mut Frame<'_>, area: Rect) {
+        let listed = self.listed();
+        let items = listed
+            .iter()
+            .map(|&i| {
+                let test = &self.tests[i];
+                let contract = test.suite.rsplit(':').next().unwrap_or(&test.suite);
+                ListItem::new(Line::from(vec![
+                    Span::styled(status_label(test.status), status_style(test.status)),
+                    Span::raw(format!(" {contract}::{}", test.signature)),
+                ]))
+            })
+            .collect::<Vec<_>>();
+
+        let mut title = format!(" Tests ({}) ", listed.len());
+        if self.failures_only {
+            title.push_str("[failures] ");
+        }
+        if !self.filter.is_empty() {
+            title.push_str(&format!("[/{}] ", self.filter));
+        }
+        let list = List::new(items)
+            .block(Block::default().title(title).borders(Borders::ALL))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        f.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn draw_details(&self, f: &mut Frame<'_>, area: Rect) {
+        let block = Block::default().title(" Details ").borders(Borders::ALL);
+        let Some(test) = self.selected_test() else {
+            let waiting =
+                if self.finished.is_some() { "No tests." } else { "Waiting for results..." };
+            f.render_widget(Paragraph::new(waiting).block(block), area);
+            return;
+        };
+
+        let bold = Style::new().add_modifier(Modifier::BOLD);
+        let mut lines = vec![
+            Line::from(Span::styled(test.suite.clone(), bold)),
+            Line::from(Span::styled(test.result.clone(), status_style(test.status))),
+            Line::default(),
+        ];
+        if !test.logs.is_empty() {
+            lines.push(Line::from(Span::styled("Logs:", bold)));
+            lines.extend(test.logs.iter().map(|log| Line::from(format!("  {log}"))));
+            lines.push(Line::default());
+        }
+        let traces = if test.traces.is_empty() {
+            "No traces recorded.".to_string()
+        } else {
+            format!("{} trace(s), press [enter] to expand.", test.traces.len())
+        };
+        lines.push(Line::from(Span::styled(traces, Style::new().add_modifier(Modifier::DIM))));
+
+        f.render_widget(Paragraph::new(lines).block(block).wrap(Wrap { trim: false }), area);
+    }
+
+    fn draw_traces(&self, f: &mut Frame<'_>, area: Rect) {
+        let Some(test) = self.selected_test() else { return };
+        let lines = test
+            .traces
+            .iter()
+            .flat_map(|trace| trace.lines().map(|line| Line::from(line.to_string())))
+            .collect::<Vec<_>>();
+        let title = format!(" Traces: {} ", test.signature);
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default().title(title).borders(Borders::ALL))
+            .scroll((self.scroll, 0));
+        f.render_widget(paragraph, area);
+    }
+
+    fn draw_footer(&self, f: &mut Frame<'_>, area: Rect) {
+        let help = if self.editing_filter {
+            format!("filter: {}_ | [enter]: apply | [esc]: clear", self.filter)
+        } else if self.expanded {
+            "[k/j]: scroll | [enter/esc]: collapse | [r]: re-run | [d]: debug".to_string()
+        } else {
+            "[q]: quit | [k/j]: prev/next | [enter]: traces | [/]: filter | [f]: failures only | [r]: re-run | [R]: re-run all | [d]: debug".to_string()
+        };
+        let paragraph =
+            Paragraph::new(Span::styled(help, Style::new().add_modifier(Modifier::DIM)));
+        f.render_widget(paragraph, area);
+    }
+}
+
+const fn status_label(status: TestStatus) -> &'static str {
+    match status {
+        TestStatus::Success => "[PASS]",
+        TestStatus::Failure => "[FAIL]",
+        TestStatus::Skipped => "[SKIP]",
+    }
+}
+
+fn status_style(status: TestStatus) -> Style {
+    match status {
+        TestStatus::Success => Style::new().fg(Color::Green),
+        TestStatus::Failure => Style::new().fg(Color::Red),
+        TestStatus::Skipped => Style::new().fg(Color::Yellow),
+    }
+}
+
+/// Removes the ANSI escape sequences of text rendered for the terminal.
+pub(crate) fn strip_ansi(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            if chars.next() == Some('[') {
+                // Skip the parameters up to the final byte of the sequence.
+                for c in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            continue;
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test(signature: &str, status: TestStatus) -> UiTest {
+        UiTest {
+            suite: "test/Counter.t.sol:CounterTest".to_string(),
+            signature: signature.to_string(),
+            status,
+            result: String::new(),
+            logs: vec![],
+            traces: vec![],
+        }
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn filters_and_selects_tests() {
+        let mut app = App::new(3);
+        app.tests.push(test("test_Increment()", TestStatus::Success));
+        app.tests.push(test("test_Revert()", TestStatus::Failure));
+        app.tests.push(test("testFuzz_SetNumber(uint256)", TestStatus::Success));
+
+        app.handle_key(key(KeyCode::Char('f')));
+        assert_eq!(app.listed(), [1]);
+        assert_eq!(
+            app.handle_key(key(KeyCode::Char('r'))),
+            Some(UiAction::Rerun {
+                suite: "test/Counter.t.sol:CounterTest".to_string(),
+                signature: "test_Revert()".to_string(),
+            })
+        );
+        app.handle_key(key(KeyCode::Char('f')));
+
+        app.handle_key(key(KeyCode::Char('/')));
+        for c in "fuzz".chars() {
+            app.handle_key(key(KeyCode::Char(c)));
+        }
+        app.handle_key(key(KeyCode::Enter));
+        assert_eq!(app.listed(), [2]);
+        assert!(matches!(
+            app.handle_key(key(KeyCode::Char('d'))),
+            Some(UiAction::Debug { signature, .. }) if signature == "testFuzz_SetNumber(uint256)"
+        ));
+
+        app.handle_key(key(KeyCode::Char('/')));
+        app.handle_key(key(KeyCode::Esc));
+        app.handle_key(key(KeyCode::Char('G')));
+        assert_eq!(app.selected, 2);
+        assert_eq!(app.handle_key(key(KeyCode::Char('q'))), Some(UiAction::Quit));
+    }
+
+    #[test]
+    fn strips_ansi_codes() {
+        assert_eq!(strip_ansi("\x1b[32m[PASS]\x1b[0m test()"), "[PASS] test()");
+    }
+}
@@ -0,0 +1,105 @@
+//! Runs the codegen hooks configured in `[hooks]` around builds.
+
+use alloy_primitives::{B256, keccak256};
+use eyre::{Result, WrapErr};
+use foundry_common::{fs, shell};
+use foundry_config::{Config, Hook, HookStage, filter::expand_globs};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+    process::Command,
+};
+
+/// The file name of the hooks cache, in the cache directory.
+const HOOKS_CACHE_FILE: &str = "hooks.json";
+
+/// The digests of the inputs of each hook at its last successful run.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct HooksCache {
+    hooks: BTreeMap<String, B256>,
+}
+
+/// Runs the hooks of the given stage whose inputs changed since their last run.
+///
+/// Hooks run arbitrary shell commands from the project configuration, so they only run if
+/// `allowed` is set by the `--run-hooks` flag. Otherwise they are skipped with a warning.
+pub fn run_hooks(config: &Config, stage: HookStage, allowed: bool) -> Result<()> {
+    // Hooks commonly run forge themselves: don't run hooks recursively.
+    let hooks = config.hooks.hooks(stage);
+    if hooks.is_empty() || std::env::var_os("FOUNDRY_HOOK").is_some() {
+        return Ok(());
+    }
+    if !allowed {
+        let names = hooks.iter().map(|hook| format!("`{}`", hook.name)).collect::<Vec<_>>();
+        sh_warn!(
+            "skipping {stage} hooks {}: hooks run shell commands and require `--run-hooks`",
+            names.join(", ")
+        )?;
+        return Ok(());
+    }
+
+    let cache_path = config.cache_path.join(HOOKS_CACHE_FILE);
+    let mut cache: HooksCache = fs::read_json_file(&cache_path).unwrap_or_default();
+    for hook in hooks {
+        let key = format!("{stage}:{}", hook.name);
+        let digest = inputs_digest(config, hook)?;
+        let outputs_exist = hook.outputs.iter().all(|output| config.root.join(output).exists());
+        if !hook.inputs.is_empty() && outputs_exist && cache.hooks.get(&key) == Some(&digest) {
+            trace!(target: "forge::hooks", %key, "hook is up to date");
+            continue;
+        }
+
+        if !shell::is_quiet() && !shell::is_json() {
+            sh_println!("Running {stage} hook `{}`: {}", hook.name, hook.run)?;
+        }
+        run_hook(config, hook, stage)?;
+
+        // Record the inputs as they are after the run, in case the hook generates some of them.
+        cache.hooks.insert(key, inputs_digest(config, hook)?);
+        if config.cache {
+            fs::create_dir_all(&config.cache_path)?;
+            fs::write_json_file(&cache_path, &cache)?;
+        }
+    }
+    Ok(())
+}
+
+fn run_hook(config: &Config, hook: &Hook, stage: HookStage) -> Result<()> {
+    let mut cmd = if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C");
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c");
+        cmd
+    };
+    let status = cmd
+        .arg(&hook.run)
+        .current_dir(&config.root)
+        .env("FOUNDRY_HOOK", &hook.name)
+        .env("FOUNDRY_HOOK_STAGE", stage.as_str())
+        .status()
+        .wrap_err_with(|| format!("failed to run {stage} hook `{}`", hook.name))?;
+    if !status.success() {
+        eyre::bail!("{stage} hook `{}` failed: {status}", hook.name);
+    }
+    Ok(())
+}
+
+/// Returns the digest of the command and the input files of a hook.
+fn inputs_digest(config: &Config, hook: &Hook) -> Result<B256> {
+    let files: BTreeSet<PathBuf> = expand_globs(&config.root, &hook.inputs)?
+        .into_iter()
+        .filter(|path| path.is_file())
+        .collect();
+
+    let mut preimage = hook.run.as_bytes().to_vec();
+    for file in files {
+        let path = file.strip_prefix(&config.root).unwrap_or(&file);
+        preimage.extend_from_slice(path.to_string_lossy().as_bytes());
+        preimage.extend_from_slice(keccak256(fs::read(&file)?).as_slice());
+    }
+    Ok(keccak256(preimage))
+}
@@ -21,6 +21,8 @@
 
 pub mod gas_report;
 
+pub mod hooks;
+
 pub mod multi_runner;
 pub use multi_runner::{MultiContractRunner, MultiContractRunnerBuilder};
 
@@ -309,6 +309,10 @@ pub struct TestRunnerConfig<FEN: FoundryEvmNetwork> {
     pub decode_internal: InternalTraceMode,
     /// Whether to enable call isolation.
     pub isolation: bool,
+    /// Whether to record the contracts invoking each cheatcode.
+    pub cheatcode_audit: bool,
+    /// Whether to record the peak resource usage of the EVM host of each test.
+    pub profile_host: bool,
     /// Whether to exit early on test failure or if test run interrupted.
     pub early_exit: EarlyExit,
 }
@@ -367,13 +371,15 @@ pub fn executor(
         artifact_id: &ArtifactId,
         db: Backend<FEN>,
     ) -> Executor<FEN> {
-        let cheats_config = Arc::new(CheatsConfig::new(
+        let mut cheats_config = CheatsConfig::new(
             &self.config,
             self.evm_opts.clone(),
             Some(known_contracts),
             Some(artifact_id.clone()),
             None,
-        ));
+        );
+        cheats_config.cheatcode_audit = self.cheatcode_audit;
+        let cheats_config = Arc::new(cheats_config);
         ExecutorBuilder::default()
             .inspectors(|stack| {
                 stack
@@ -421,6 +427,10 @@ pub struct MultiContractRunnerBuilder {
     pub decode_internal: InternalTraceMode,
     /// Whether to enable call isolation
     pub isolation: bool,
+    /// Whether to record the contracts invoking each cheatcode.
+    pub cheatcode_audit: bool,
+    /// Whether to record the peak resource usage of the EVM host of each test.
+    pub profile_host: bool,
     /// Whether to exit early on test failure.
     pub fail_fast: bool,
 }
@@ -436,6 +446,8 @@ pub fn new(config: Arc<Config>) -> Self {
             debug: Default::default(),
             isolation: Default::default(),
             decode_internal: Default::default(),
+            cheatcode_audit: false,
+            profile_host: false,
             fail_fast: false,
         }
     }
@@ -480,6 +492,16 @@ pub const fn enable_isolation(mut self, enable: bool) -> Self {
         self
     }
 
+    pub const fn set_cheatcode_audit(mut self, enable: bool) -> Self {
+        self.cheatcode_audit = enable;
+        self
+    }
+
+    pub const fn set_profile_host(mut self, enable: bool) -> Self {
+        self.profile_host = enable;
+        self
+    }
+
     /// Given an EVM, proceeds to return a runner which is able to execute all tests
     /// against that evm
     pub fn build<FEN: FoundryEvmNetwork, C: Compiler<CompilerContract = Contract>>(
@@ -593,6 +615,8 @@ pub fn build<FEN: FoundryEvmNetwork, C: Compiler<CompilerContract = Contract>>(
                 decode_internal: self.decode_internal,
                 inline_config: Arc::new(InlineConfig::new_parsed(output, &self.config)?),
                 isolation: self.isolation,
+                cheatcode_audit: self.cheatcode_audit,
+                profile_host: self.profile_host,
                 early_exit: EarlyExit::new(self.fail_fast),
                 config: self.config,
             },
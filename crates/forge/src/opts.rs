@@ -1,16 +1,16 @@
 use crate::cmd::{
-    bind::BindArgs, bind_json, build::BuildArgs, cache::CacheArgs, clone::CloneArgs,
-    compiler::CompilerArgs, config, coverage, create::CreateArgs, doc::DocArgs, eip712, flatten,
-    fmt::FmtArgs, geiger, generate, init::InitArgs, inspect, install::InstallArgs, lint::LintArgs,
-    remappings::RemappingArgs, remove::RemoveArgs, selectors::SelectorsSubcommands, snapshot,
-    soldeer, test, tree, update,
+    bind::BindArgs, bind_events, bind_json, broadcast::BroadcastArgs, build::BuildArgs,
+    cache::CacheArgs, clean::CleanArgs, clone::CloneArgs, compiler::CompilerArgs, config, coverage,
+    create::CreateArgs, daemon::DaemonArgs, doc::DocArgs, eip712, flatten, fmt::FmtArgs, geiger,
+    generate, init::InitArgs, inspect, install::InstallArgs, lint::LintArgs, lsp::LspArgs,
+    remappings::RemappingArgs, remove::RemoveArgs, run_est::RunEstArgs,
+    selectors::SelectorsSubcommands, snapshot, soldeer, test, tree, update,
 };
-use clap::{Parser, Subcommand, ValueHint};
+use clap::{Parser, Subcommand};
 use forge_script::ScriptArgs;
 use forge_verify::{VerifyArgs, VerifyBytecodeArgs, VerifyCheckArgs};
 use foundry_cli::opts::GlobalArgs;
 use foundry_common::version::{LONG_VERSION, SHORT_VERSION};
-use std::path::PathBuf;
 
 /// Build, test, fuzz, debug and deploy Solidity contracts.
 #[derive(Parser)]
@@ -101,18 +101,14 @@ pub enum ForgeSubcommand {
 
     /// Remove the build artifacts and cache directories.
     #[command(visible_alias = "cl")]
-    Clean {
-        /// The project's root path.
-        ///
-        /// By default root of the Git repository, if in one,
-        /// or the current working directory.
-        #[arg(long, value_hint = ValueHint::DirPath, value_name = "PATH")]
-        root: Option<PathBuf>,
-    },
+    Clean(CleanArgs),
 
     /// Manage the Foundry cache.
     Cache(CacheArgs),
 
+    /// Query and prune the script broadcasts.
+    Broadcast(BroadcastArgs),
+
     /// Create a gas snapshot of each test's gas usage.
     #[command(visible_alias = "s")]
     Snapshot(snapshot::GasSnapshotArgs),
@@ -132,6 +128,12 @@ pub enum ForgeSubcommand {
     #[command(visible_alias = "l")]
     Lint(LintArgs),
 
+    /// Start a Language Server Protocol server for Solidity sources.
+    Lsp(LspArgs),
+
+    /// Start a daemon compiling the project and running single tests on demand, for editors.
+    Daemon(DaemonArgs),
+
     /// Get specialized information about a smart contract.
     #[command(visible_alias = "in")]
     Inspect(inspect::InspectArgs),
@@ -170,6 +172,13 @@ pub enum ForgeSubcommand {
 
     /// Generate bindings for serialization/deserialization of project structs via JSON cheatcodes.
     BindJson(bind_json::BindJsonArgs),
+
+    /// Generate decoders of the project events for indexers, in Rust (alloy) and TypeScript (viem).
+    BindEvents(bind_events::BindEventsArgs),
+
+    /// Run execution-spec-tests state test fixtures against the bundled EVM.
+    #[command(name = "run-est")]
+    RunEst(RunEstArgs),
 }
 
 #[cfg(test)]
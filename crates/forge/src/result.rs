@@ -8,6 +8,7 @@
     Address, I256, Log, U256,
     map::{AddressHashMap, HashMap},
 };
+use comfy_table::{Cell, Table, modifiers::UTF8_ROUND_CORNERS, presets::ASCII_MARKDOWN};
 use eyre::Report;
 use foundry_common::{ContractsByArtifact, get_contract_name, get_file_name, shell};
 use foundry_evm::{
@@ -16,11 +17,12 @@
     decode::SkipReason,
     executors::{RawCallResult, invariant::InvariantMetrics},
     fuzz::{CounterExample, FuzzCase, FuzzFixtures, FuzzTestResult},
+    inspectors::HostProfile,
     traces::{CallTraceArena, CallTraceDecoder, TraceKind, Traces},
 };
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{BTreeMap, HashMap as Map},
+    collections::{BTreeMap, BTreeSet, HashMap as Map},
     fmt::{self, Write},
     time::Duration,
 };
@@ -239,6 +241,105 @@ pub fn remove_first(&mut self) -> Option<(String, String, TestResult)> {
             }
         })
     }
+
+    /// Returns the contracts which invoked each cheatcode, across all tests.
+    pub fn cheatcode_audit(&self) -> CheatcodeAudit {
+        let mut audit = CheatcodeAudit::default();
+        for (_, result) in self.tests() {
+            for (contract, cheatcodes) in &result.cheatcode_sites {
+                for cheatcode in cheatcodes {
+                    audit.sites.entry(cheatcode).or_default().insert(contract.clone());
+                }
+            }
+        }
+        audit
+    }
+
+    /// Returns the host profile of each test, if host profiling is enabled.
+    pub fn host_profiles(&self) -> HostProfileReport {
+        let mut tests = self
+            .results
+            .iter()
+            .flat_map(|(suite, result)| {
+                let contract = get_contract_name(suite);
+                result.test_results.iter().filter_map(move |(test, result)| {
+                    Some((
+                        format!("{contract}::{}", test.split('(').next().unwrap()),
+                        result.host_profile?,
+                    ))
+                })
+            })
+            .collect::<Vec<_>>();
+        tests.sort_by(|(a_name, a), (b_name, b)| {
+            b.peak_memory.cmp(&a.peak_memory).then_with(|| a_name.cmp(b_name))
+        });
+        HostProfileReport { tests }
+    }
+}
+
+/// Report of the peak resource usage of the EVM host of each test, see
+/// `forge test --profile-host`.
+#[derive(Clone, Debug, Default)]
+pub struct HostProfileReport {
+    /// The profile of each test, sorted by decreasing peak memory.
+    pub tests: Vec<(String, HostProfile)>,
+}
+
+impl fmt::Display for HostProfileReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut table = Table::new();
+        if shell::is_markdown() {
+            table.load_preset(ASCII_MARKDOWN);
+        } else {
+            table.apply_modifier(UTF8_ROUND_CORNERS);
+        }
+        table.set_header([
+            "Test",
+            "Peak Memory (B)",
+            "Peak Depth",
+            "Journal Accounts",
+            "Journal Slots",
+            "DB Accounts",
+            "DB Slots",
+            "DB Contracts",
+        ]);
+        for (test, profile) in &self.tests {
+            table.add_row([
+                Cell::new(test),
+                Cell::new(profile.peak_memory),
+                Cell::new(profile.peak_depth),
+                Cell::new(profile.peak_journal_accounts),
+                Cell::new(profile.peak_journal_slots),
+                Cell::new(profile.db_accounts),
+                Cell::new(profile.db_slots),
+                Cell::new(profile.db_contracts),
+            ]);
+        }
+        write!(f, "Host profile:\n{table}")
+    }
+}
+
+/// Report of the cheatcodes invoked during a test run, see `forge test --cheatcode-audit`.
+#[derive(Clone, Debug, Default)]
+pub struct CheatcodeAudit {
+    /// The contracts which invoked each cheatcode, keyed by cheatcode signature.
+    pub sites: BTreeMap<&'static str, BTreeSet<String>>,
+}
+
+impl fmt::Display for CheatcodeAudit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.sites.is_empty() {
+            return write!(f, "No cheatcodes invoked.");
+        }
+        write!(f, "Cheatcode audit:")?;
+        for (cheatcode, contracts) in &self.sites {
+            write!(f, "\n  {}", cheatcode.yellow())?;
+            for contract in contracts {
+                write!(f, "\n    {contract}")?;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// A set of test results for a single test suite, which is all the tests in a single contract.
@@ -464,6 +565,14 @@ pub struct TestResult {
     /// Deprecated cheatcodes (mapped to their replacements, if any) used in current test.
     #[serde(skip)]
     pub deprecated_cheatcodes: HashMap<&'static str, Option<&'static str>>,
+
+    /// Cheatcodes invoked by each contract in current test, if the cheatcode audit is enabled.
+    #[serde(skip)]
+    pub cheatcode_sites: BTreeMap<String, BTreeSet<&'static str>>,
+
+    /// Peak resource usage of the EVM host, if host profiling is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host_profile: Option<HostProfile>,
 }
 
 impl fmt::Display for TestResult {
@@ -622,6 +731,7 @@ pub fn single_result<FEN: FoundryEvmNetwork>(
             self.breakpoints = cheatcodes.breakpoints;
             self.gas_snapshots = cheatcodes.gas_snapshots;
             self.deprecated_cheatcodes = cheatcodes.deprecated;
+            self.cheatcode_sites = cheatcodes.cheatcode_sites;
         }
     }
 
@@ -652,6 +762,7 @@ pub fn fuzz_result(&mut self, result: FuzzTestResult) {
         self.gas_report_traces = result.gas_report_traces.into_iter().map(|t| vec![t]).collect();
         self.breakpoints = result.breakpoints.unwrap_or_default();
         self.deprecated_cheatcodes = result.deprecated_cheatcodes;
+        self.cheatcode_sites = result.cheatcode_sites;
     }
 
     /// Returns the fail result for fuzz test setup.
@@ -736,6 +847,7 @@ pub fn invariant_result(
         metrics: Map<String, InvariantMetrics>,
         failed_corpus_replays: usize,
         optimization_best_value: Option<I256>,
+        cheatcode_sites: BTreeMap<String, BTreeSet<&'static str>>,
     ) {
         self.kind = TestKind::Invariant {
             runs: cases.len(),
@@ -754,6 +866,7 @@ pub fn invariant_result(
         self.reason = reason;
         self.counterexample = counterexample;
         self.gas_report_traces = gas_report_traces;
+        self.cheatcode_sites = cheatcode_sites;
     }
 
     /// Returns the result for a table test. Merges table test execution results (logs, labeled
@@ -781,6 +894,7 @@ pub fn table_result(&mut self, result: FuzzTestResult) {
         self.gas_report_traces = result.gas_report_traces.into_iter().map(|t| vec![t]).collect();
         self.breakpoints = result.breakpoints.unwrap_or_default();
         self.deprecated_cheatcodes = result.deprecated_cheatcodes;
+        self.cheatcode_sites = result.cheatcode_sites;
     }
 
     /// Returns `true` if this is the result of a fuzz test
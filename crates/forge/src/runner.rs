@@ -28,10 +28,11 @@
         },
     },
     fuzz::{
-        BasicTxDetails, CallDetails, CounterExample, FuzzFixtures, fixture_name,
+        BasicTxDetails, CallDetails, CounterExample, FuzzFixtures, FuzzRegressions, fixture_name,
         invariant::{InvariantContract, InvariantSettings},
         strategies::EvmFuzzState,
     },
+    inspectors::{HostProfile, HostProfiler},
     revm::primitives::hardfork::SpecId,
     traces::{TraceKind, TraceMode, load_contracts},
 };
@@ -528,7 +529,20 @@ fn run(
             return self.result;
         }
 
-        match kind {
+        // The profiler is shared by all the executors cloned from this one.
+        let host_profile = self.profile_host.then(|| {
+            let profiler = HostProfiler::default();
+            self.executor.to_mut().set_host_profiler(Some(profiler.clone()));
+            let mut profile = HostProfile::default();
+            let backend = self.executor.backend();
+            profile.record_db(backend.mem_db());
+            if let Some(db) = backend.active_fork_db() {
+                profile.record_db(db);
+            }
+            (profiler, profile)
+        });
+
+        let mut result = match kind {
             TestFunctionKind::UnitTest { .. } => self.run_unit_test(func),
             TestFunctionKind::FuzzTest { .. } => self.run_fuzz_test(func),
             TestFunctionKind::TableTest => self.run_table_test(func),
@@ -536,7 +550,17 @@ fn run(
                 self.run_invariant_test(func, call_after_invariant, identified_contracts.unwrap())
             }
             _ => unreachable!(),
+        };
+
+        if let Some((profiler, db)) = host_profile {
+            result.host_profile = Some(HostProfile {
+                db_accounts: db.db_accounts,
+                db_slots: db.db_slots,
+                db_contracts: db.db_contracts,
+                ..profiler.profile()
+            });
         }
+        result
     }
 
     /// Runs a single unit test.
@@ -1019,6 +1043,7 @@ fn run_invariant_test(
             invariant_result.metrics,
             invariant_result.failed_corpus_replays,
             invariant_result.optimization_best_value,
+            invariant_result.cheatcode_sites,
         );
         self.result
     }
@@ -1066,12 +1091,28 @@ fn run_fuzz_test(mut self, func: &Function) -> TestResult {
         executor
             .inspector_mut()
             .collect_sancov_trace_cmp(fuzz_config.corpus.collect_sancov_trace_cmp());
-        // Load persisted counterexample, if any.
-        let persisted_failure =
-            foundry_common::fs::read_json_file::<BaseCounterExample>(failure_file.as_path()).ok();
+        // Load regressions and persisted counterexample, if any.
+        let regressions =
+            fuzz_config.regressions_dir.as_ref().map(|dir| FuzzRegressions::new(dir, self.cr.name));
+        let mut persisted_failures = regressions
+            .as_ref()
+            .map(|regressions| regressions.load(&func.name))
+            .unwrap_or_default();
+        if let Ok(failure) =
+            foundry_common::fs::read_json_file::<BaseCounterExample>(failure_file.as_path())
+            && !persisted_failures.contains(&failure.calldata)
+        {
+            persisted_failures.push(failure.calldata);
+        }
+        let seed = fuzz_config.seed;
         // Run fuzz test.
-        let mut fuzzed_executor =
-            FuzzedExecutor::new(executor, runner, self.tcfg.sender, fuzz_config, persisted_failure);
+        let mut fuzzed_executor = FuzzedExecutor::new(
+            executor,
+            runner,
+            self.tcfg.sender,
+            fuzz_config,
+            persisted_failures,
+        );
         let result = match fuzzed_executor.fuzz(
             func,
             &self.setup.fuzz_fixtures,
@@ -1098,6 +1139,11 @@ fn run_fuzz_test(mut self, func: &Function) -> TestResult {
             {
                 error!(%err, "Failed to record call sequence");
             }
+            if let Some(regressions) = &regressions
+                && let Err(err) = regressions.record(&func.name, seed, counterexample)
+            {
+                error!(%err, "Failed to record fuzz regression");
+            }
         }
 
         self.result.fuzz_result(result);
@@ -686,3 +686,66 @@
 ...
 "#]]);
 });
+
+forgetest!(test_backtrace_revert_origin, |prj, cmd| {
+    prj.insert_ds_test();
+
+    prj.add_source(
+        "Vault.sol",
+        r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Vault {
+    error InsufficientBalance(uint256 requested, uint256 available);
+
+    function withdraw(uint256 amount) public pure {
+        revert InsufficientBalance(amount, 0);
+    }
+}
+
+contract Router {
+    Vault vault = new Vault();
+
+    function withdraw(uint256 amount) public {
+        try vault.withdraw(amount) {} catch (bytes memory reason) {
+            assembly {
+                revert(add(reason, 32), mload(reason))
+            }
+        }
+    }
+}
+"#,
+    );
+
+    prj.add_test(
+        "RevertOrigin.t.sol",
+        r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+import "../src/test.sol";
+import "../src/Vault.sol";
+
+contract RevertOriginTest is DSTest {
+    function testBubbledCustomError() public {
+        Router router = new Router();
+        router.withdraw(1);
+    }
+}
+"#,
+    );
+
+    cmd.args(["test", "-vvv"]).assert_failure().stdout_eq(str![[r#"
+...
+Backtrace:
+  at Vault.withdraw
+  at Router.withdraw
+  at RevertOriginTest.testBubbledCustomError
+Revert origin:
+  InsufficientBalance(1, 0) raised at Vault.withdraw
+  re-thrown by Router.withdraw
+  re-thrown by RevertOriginTest.testBubbledCustomError
+...
+"#]]);
+});
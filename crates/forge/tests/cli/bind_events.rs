@@ -0,0 +1,61 @@
+use foundry_test_utils::snapbox;
+
+forgetest_init!(test_bind_events, |prj, cmd| {
+    prj.add_source(
+        "Vault",
+        r#"
+contract Vault {
+    event Deposit(address indexed owner, uint256 amount);
+    event Anonymous(uint256 amount) anonymous;
+}
+"#,
+    );
+
+    cmd.args(["bind-events", "--select", "Vault"]).assert_success().stdout_eq(str![[r#"
+Generated decoders for 1 events to [..]
+
+"#]]);
+
+    snapbox::assert_data_eq!(
+        snapbox::Data::read_from(&prj.root().join("out/events/events.rs"), None),
+        snapbox::str![[r#"
+//! This file was generated by `forge bind-events`, do not edit it.
+
+alloy::sol! {
+    #[derive(Debug, PartialEq, Eq)]
+    interface Vault {
+        event Deposit(address indexed owner, uint256 amount);
+    }
+}
+
+"#]]
+    );
+
+    cmd.forge_fuse()
+        .args(["bind-events", "--select", "Vault", "--check"])
+        .assert_success()
+        .stdout_eq(str![[r#"
+Event decoders are up to date.
+
+"#]]);
+
+    prj.add_source(
+        "Vault",
+        r#"
+contract Vault {
+    event Deposit(address indexed owner, uint256 amount);
+    event Withdraw(address indexed owner, uint256 amount);
+}
+"#,
+    );
+
+    cmd.forge_fuse()
+        .args(["bind-events", "--select", "Vault", "--check"])
+        .assert_failure()
+        .stderr_eq(str![[r#"
+Error: Event decoders are out of date, run `forge bind-events` to update:
+[..]events.rs
+[..]events.ts
+
+"#]]);
+});
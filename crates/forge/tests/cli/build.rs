@@ -1,4 +1,6 @@
 use crate::utils::generate_large_init_contract;
+use foundry_compilers::artifacts::BytecodeHash;
+use foundry_config::ArtifactField;
 use foundry_test_utils::{forgetest, forgetest_init, snapbox::IntoData, str};
 use globset::Glob;
 use std::fs;
@@ -510,3 +512,215 @@
 
 "#]]);
 });
+
+// tests that `--provenance` writes the provenance file to the artifacts directory
+forgetest_init!(build_writes_provenance, |prj, cmd| {
+    prj.initialize_default_contracts();
+
+    cmd.args(["build", "--provenance"]).assert_success().stdout_eq(str![[r#"
+...
+Provenance written to [..]provenance.json
+
+"#]]);
+
+    let provenance: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(prj.artifacts().join("provenance.json")).unwrap())
+            .unwrap();
+    assert_eq!(provenance["predicateType"], "https://getfoundry.sh/provenance/v1");
+    assert_eq!(provenance["compilers"][0]["name"], "solc");
+    assert!(provenance["inputDigest"].is_string());
+});
+
+// tests that `--timings` prints the compilation units
+forgetest_init!(build_timings, |prj, cmd| {
+    prj.initialize_default_contracts();
+
+    cmd.args(["build", "--force", "--timings", "--solc-jobs", "2", "--solc-memory-budget", "1024"])
+        .assert_success()
+        .stdout_eq(str![[r#"
+...
+╭[..]╮
+| Compiler | Version | Files | Started[..] | Duration[..] |
++[..]+
+| Solc     | [..]
+╰[..]╯
+
+Compiled 1 units in [..] ([..] of compiler time)
+
+"#]]);
+});
+
+// tests that `--apply-fixes` applies the quick-fixes of compiler diagnostics
+forgetest!(build_apply_fixes, |prj, cmd| {
+    let path = prj.add_raw_source(
+        "Fix",
+        r"pragma solidity ^0.8.0;
+contract Fix {
+    function f(uint256 a) external pure {}
+}
+",
+    );
+
+    cmd.args(["build", "--apply-fixes"]).assert_success().stdout_eq(str![[r#"
+--- a/src/Fix.sol
++++ b/src/Fix.sol
+@@ -1,1 +1,2 @@ add an SPDX license identifier
+-pragma solidity ^0.8.0;
++// SPDX-License-Identifier: UNLICENSED
++pragma solidity ^0.8.0;
+@@ -3,1 +4,1 @@ comment out the parameter name
+-    function f(uint256 a) external pure {}
++    function f(uint256 /* a */) external pure {}
+
+Applied 2 fixes to 1 files
+[COMPILING_FILES] with [SOLC_VERSION]
+[SOLC_VERSION] [ELAPSED]
+Compiler run successful!
+
+"#]]);
+
+    assert_eq!(
+        fs::read_to_string(path).unwrap(),
+        r"// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.0;
+contract Fix {
+    function f(uint256 /* a */) external pure {}
+}
+"
+    );
+});
+
+// tests that outputs listed in `[artifacts] strip` are not requested from the compiler
+forgetest_init!(build_strips_artifact_fields, |prj, cmd| {
+    prj.initialize_default_contracts();
+    prj.update_config(|config| {
+        config.artifacts.strip = vec![ArtifactField::Opcodes, ArtifactField::GeneratedSources];
+    });
+
+    cmd.args(["build", "--build-info"]).assert_success();
+
+    let build_info = fs::read_dir(prj.artifacts().join("build-info"))
+        .unwrap()
+        .map(|entry| fs::read_to_string(entry.unwrap().path()).unwrap())
+        .collect::<String>();
+    assert!(build_info.contains("\"sourceMap\""));
+    assert!(!build_info.contains("\"opcodes\""));
+    assert!(!build_info.contains("\"generatedSources\""));
+});
+
+// tests that `--assert-unchanged` compares the committed artifacts with a fresh build
+forgetest_init!(build_assert_unchanged, |prj, cmd| {
+    prj.initialize_default_contracts();
+    cmd.args(["build"]).assert_success();
+
+    cmd.forge_fuse().args(["build", "--assert-unchanged"]).assert_success().stdout_eq(str![[r#"
+...
+Artifacts match a fresh build
+
+"#]]);
+
+    let path = prj.artifacts().join("Counter.sol/Counter.json");
+    let mut artifact: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    artifact["deployedBytecode"]["object"] = "0x00".into();
+    fs::write(&path, serde_json::to_string(&artifact).unwrap()).unwrap();
+    fs::create_dir_all(prj.artifacts().join("Old.sol")).unwrap();
+    fs::write(prj.artifacts().join("Old.sol/Old.json"), "{}").unwrap();
+
+    cmd.forge_fuse()
+        .args(["build", "--assert-unchanged"])
+        .assert_failure()
+        .stdout_eq(str![[r#"
+...
+Artifacts differ from a fresh build:
+  src/Counter.sol:Counter: changed deployedBytecode ([..] -> [..])
+  Old.sol/Old.json: stale
+
+"#]])
+        .stderr_eq(str![[r#"
+Error: 2 artifacts differ from a fresh build
+
+"#]]);
+
+    // When building a subset, the artifacts of the other sources are not stale.
+    cmd.forge_fuse()
+        .args(["build", "src/Counter.sol", "--assert-unchanged"])
+        .assert_failure()
+        .stdout_eq(str![[r#"
+...
+Artifacts differ from a fresh build:
+  src/Counter.sol:Counter: changed deployedBytecode ([..] -> [..])
+
+"#]])
+        .stderr_eq(str![[r#"
+Error: 1 artifacts differ from a fresh build
+
+"#]]);
+});
+
+// tests that `--deterministic` verifies the bytecode and writes artifacts without absolute paths
+forgetest_init!(build_deterministic, |prj, cmd| {
+    prj.initialize_default_contracts();
+    prj.update_config(|config| config.bytecode_hash = BytecodeHash::Bzzr1);
+
+    cmd.args(["build", "--deterministic"]).assert_success().stdout_eq(str![[r#"
+...
+Reproducibility report:
+  bytecode hash: none
+  contracts: [..]
+  normalized artifacts: [..]
+Bytecode is reproducible
+
+"#]]);
+
+    let root = prj.root().to_string_lossy().into_owned();
+    let artifact = fs::read_to_string(prj.artifacts().join("Counter.sol/Counter.json")).unwrap();
+    assert!(!artifact.contains(&root));
+});
+
+// tests that pre-build hooks run before compiling with `--run-hooks`, and only when their inputs
+// changed
+#[cfg(unix)]
+forgetest!(build_runs_hooks, |prj, cmd| {
+    fs::create_dir_all(prj.root().join("gen")).unwrap();
+    fs::write(
+        prj.root().join("gen/Gen.sol.in"),
+        "// SPDX-License-Identifier: MIT\npragma solidity ^0.8.0;\ncontract Gen {}\n",
+    )
+    .unwrap();
+    prj.update_config(|config| {
+        config.hooks.pre_build = vec![foundry_config::Hook {
+            name: "gen".to_string(),
+            run: "cp gen/Gen.sol.in src/Gen.sol".to_string(),
+            inputs: vec!["gen/*.in".to_string()],
+            outputs: vec!["src/Gen.sol".into()],
+        }];
+    });
+
+    // Hooks are never run without an explicit opt-in.
+    cmd.args(["build"]).assert_success().stderr_eq(str![[r#"
+Warning: skipping pre-build hooks `gen`: hooks run shell commands and require `--run-hooks`
+
+"#]]);
+    assert!(!prj.root().join("src/Gen.sol").exists());
+
+    cmd.forge_fuse().args(["build", "--run-hooks"]).assert_success().stdout_eq(str![[r#"
+Running pre-build hook `gen`: cp gen/Gen.sol.in src/Gen.sol
+[COMPILING_FILES] with [SOLC_VERSION]
+[SOLC_VERSION] [ELAPSED]
+Compiler run successful!
+
+"#]]);
+    assert!(prj.root().join("src/Gen.sol").exists());
+
+    cmd.forge_fuse().args(["build", "--run-hooks"]).assert_success().stdout_eq(str![[r#"
+No files changed, compilation skipped
+
+"#]]);
+
+    fs::remove_file(prj.root().join("src/Gen.sol")).unwrap();
+    cmd.forge_fuse().args(["build", "--run-hooks"]).assert_success().stdout_eq(str![[r#"
+Running pre-build hook `gen`: cp gen/Gen.sol.in src/Gen.sol
+...
+"#]]);
+});
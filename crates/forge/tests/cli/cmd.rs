@@ -120,6 +120,24 @@
 "#]]);
 });
 
+// checks that `clean --dry-run` reports disk usage without removing anything
+forgetest_init!(can_clean_dry_run, |prj, cmd| {
+    prj.initialize_default_contracts();
+    cmd.arg("build").assert_success();
+    assert!(prj.artifacts().exists());
+
+    cmd.forge_fuse().args(["clean", "--dry-run"]).assert_success().stdout_eq(str![[r#"
+Would remove:
+- Build artifacts ([..])
+...
+- Test failures and fuzz corpora ([..])
+...
+Total: [..]
+
+"#]]);
+    assert!(prj.artifacts().exists());
+});
+
 // checks that `cache ls` can be invoked and displays the foundry cache
 forgetest!(
     #[ignore]
@@ -1416,6 +1434,49 @@ struct Bar {
 "#]]);
 });
 
+// test that `forge snapshot --size` tracks and checks contract sizes
+forgetest_init!(can_snapshot_contract_sizes, |prj, cmd| {
+    prj.initialize_default_contracts();
+    cmd.args(["snapshot", "--size"]).assert_success();
+
+    let snapshot = fs::read_to_string(prj.root().join(".gas-snapshot")).unwrap();
+    assert!(snapshot.lines().any(|line| line.starts_with("src/Counter.sol:Counter (size: ")));
+    assert!(!snapshot.contains("CounterTest (size:"));
+
+    cmd.forge_fuse().args(["snapshot", "--size", "--check"]).assert_success();
+
+    // Without `--size`, size entries are ignored.
+    cmd.forge_fuse().args(["snapshot", "--check"]).assert_success();
+
+    prj.add_source(
+        "Counter.sol",
+        r#"
+contract Counter {
+    uint256 public number;
+
+    function setNumber(uint256 newNumber) public {
+        number = newNumber;
+    }
+
+    function increment() public {
+        number++;
+    }
+
+    function decrement() public {
+        number--;
+    }
+}
+"#,
+    );
+    cmd.forge_fuse().args(["snapshot", "--size", "--check"]).assert_failure().stdout_eq(str![[
+        r#"
+...
+Diff in size of "src/Counter.sol:Counter": [..] bytes, expected [..] bytes
+...
+"#
+    ]]);
+});
+
 // test that `forge build` does not print `(with warnings)` if file path is ignored
 forgetest!(can_compile_without_warnings_ignored_file_paths, |prj, cmd| {
     // Ignoring path and setting empty error_codes as default would set some error codes
@@ -3644,6 +3705,75 @@ struct ErrWithMsg {
 ╰-------------+-----------------------------------------------+--------------------------------------------------------------------╯
 
 
+"#]]);
+});
+
+// checks that `forge inspect --check-clone-layout` reports the storage layout changes of a clone
+forgetest!(inspect_check_clone_layout, |prj, cmd| {
+    prj.add_source(
+        "Vault.sol",
+        r#"
+contract Vault {
+    address public owner;
+    uint256 public total;
+}
+   "#,
+    );
+    let layout = serde_json::json!({
+        "storage": [
+            {"astId": 3, "contract": "src/Vault.sol:Vault", "label": "owner", "offset": 0, "slot": "0", "type": "t_address"},
+            {"astId": 5, "contract": "src/Vault.sol:Vault", "label": "total", "offset": 0, "slot": "1", "type": "t_uint256"},
+        ],
+        "types": {
+            "t_address": {"encoding": "inplace", "label": "address", "numberOfBytes": "20"},
+            "t_uint256": {"encoding": "inplace", "label": "uint256", "numberOfBytes": "32"},
+        },
+    });
+    let meta = serde_json::json!({
+        "path": "src/Vault.sol",
+        "targetContract": "Vault",
+        "address": "0x0000000000000000000000000000000000000011",
+        "chainId": 1,
+        "creationTransaction": "0x0000000000000000000000000000000000000000000000000000000000000001",
+        "deployer": "0x00000000000000000000000000000000000000bb",
+        "constructorArguments": "0x",
+        "storageLayout": layout,
+    });
+    prj.create_file(".clone.meta", &meta.to_string());
+
+    cmd.args(["inspect", "--check-clone-layout"]).assert_success().stdout_eq(str![[r#"
+[COMPILING_FILES] with [SOLC_VERSION]
+[SOLC_VERSION] [ELAPSED]
+Compiler run successful!
+Vault (0x0000000000000000000000000000000000000011): storage layout unchanged
+
+"#]]);
+
+    prj.add_source(
+        "Vault.sol",
+        r#"
+contract Vault {
+    address public owner;
+    bool public paused;
+    uint128 public total;
+    uint256 public fee;
+}
+   "#,
+    );
+    cmd.assert_failure()
+        .stdout_eq(str![[r#"
+[COMPILING_FILES] with [SOLC_VERSION]
+[SOLC_VERSION] [ELAPSED]
+Compiler run successful!
+Vault (0x0000000000000000000000000000000000000011): storage layout incompatible
+  inserted slot 0, offset 20: bool paused
+  retyped  slot 1, offset 0: uint256 total -> uint128 total
+  appended slot 2, offset 0: uint256 fee
+
+"#]])
+        .stderr_eq(str![[r#"
+Error: upgrading Vault would corrupt the storage of the contract on chain
+
 "#]]);
 });
 
@@ -103,6 +103,7 @@
 cbor_metadata = true
 sparse_mode = false
 build_info = false
+provenance = false
 isolate = false
 disable_block_gas_limit = false
 enable_tx_gas_limit = false
@@ -110,6 +111,7 @@
 create2_library_salt = "0x0000000000000000000000000000000000000000000000000000000000000000"
 create2_deployer = "0x4e59b44847b379578588920ca78fbf26c0b4956c"
 assertions_revert = true
+gas_free_assertions = false
 legacy_assertions = false
 celo = false
 bypass_prevrandao = false
@@ -157,6 +159,7 @@
 exclude_lints = []
 ignore = []
 lint_on_build = true
+plugins = []
 
 [lint.lint_specific]
 mixed_case_exceptions = [
@@ -172,6 +175,10 @@
     "HTTPS",
 ]
 multi_contract_file_exceptions = []
+erc20_unchecked_transfer_functions = [
+    "transfer(address,uint256)",
+    "transferFrom(address,address,uint256)",
+]
 
 [doc]
 out = "docs"
@@ -198,6 +205,7 @@
 sancov_edges = false
 sancov_trace_cmp = false
 failure_persist_dir = "cache/fuzz"
+regressions_dir = "testdata/fuzz-regressions"
 show_logs = false
 
 [invariant]
@@ -267,6 +275,8 @@
         auto_detect_solc: false,
         auto_detect_remappings: true,
         offline: true,
+        solc_jobs: None,
+        solc_memory_budget: None,
         optimizer: Some(false),
         optimizer_runs: Some(1000),
         optimizer_details: Some(OptimizerDetails {
@@ -356,6 +366,8 @@
         },
         no_storage_caching: true,
         no_rpc_rate_limit: true,
+        fork_presets: Default::default(),
+        chains: Default::default(),
         use_literal_content: false,
         bytecode_hash: Default::default(),
         cbor_metadata: true,
@@ -366,22 +378,28 @@
         rpc_endpoints: Default::default(),
         build_info: false,
         build_info_path: None,
+        provenance: false,
         fmt: Default::default(),
         lint: Default::default(),
         doc: Default::default(),
         bind_json: Default::default(),
         fs_permissions: Default::default(),
         labels: Default::default(),
+        cheatcode_permissions: Default::default(),
         isolate: true,
         unchecked_cheatcode_artifacts: false,
         create2_library_salt: Config::DEFAULT_CREATE2_LIBRARY_SALT,
         create2_deployer: Config::DEFAULT_CREATE2_DEPLOYER,
         vyper: Default::default(),
+        artifacts: Default::default(),
+        hooks: Default::default(),
+        script_exports: Default::default(),
         skip: vec![],
         dependencies: Default::default(),
         soldeer: Default::default(),
         warnings: vec![],
         assertions_revert: true,
+        gas_free_assertions: false,
         legacy_assertions: false,
         extra_args: vec![],
         networks: Default::default(),
@@ -1287,6 +1305,7 @@
     "sancov_edges": false,
     "sancov_trace_cmp": false,
     "failure_persist_dir": "cache/fuzz",
+    "regressions_dir": "testdata/fuzz-regressions",
     "show_logs": false,
     "timeout": null
   },
@@ -1359,6 +1378,7 @@
   "sparse_mode": false,
   "build_info": false,
   "build_info_path": null,
+  "provenance": false,
   "fmt": {
     "line_length": 120,
     "tab_width": 4,
@@ -1390,6 +1410,7 @@
     "exclude_lints": [],
     "ignore": [],
     "lint_on_build": true,
+    "plugins": [],
     "lint_specific": {
       "mixed_case_exceptions": [
         "ERC",
@@ -1403,7 +1424,11 @@
         "HTTP",
         "HTTPS"
       ],
-      "multi_contract_file_exceptions": []
+      "multi_contract_file_exceptions": [],
+      "erc20_unchecked_transfer_functions": [
+        "transfer(address,uint256)",
+        "transferFrom(address,address,uint256)"
+      ]
     }
   },
   "doc": {
@@ -1435,6 +1460,7 @@
   "dependencies": null,
   "soldeer": null,
   "assertions_revert": true,
+  "gas_free_assertions": false,
   "legacy_assertions": false,
   "network": null,
   "celo": false,
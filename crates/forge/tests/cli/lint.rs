@@ -1,6 +1,7 @@
 use forge_lint::{linter::Lint, sol::med::REGISTERED_LINTS};
 use foundry_config::{
-    DenyLevel, LintSeverity, LinterConfig, SolidityErrorCode, lint::LintSpecificConfig,
+    DenyLevel, LintSeverity, LinterConfig, SolidityErrorCode,
+    lint::{LintOnBuild, LintSpecificConfig},
 };
 
 mod geiger;
@@ -263,11 +264,129 @@
                 mixed_case_exceptions: vec!["MIXED".to_string()],
                 ..Default::default()
             },
+            ..Default::default()
         };
     });
     cmd.arg("lint").assert_success().stderr_eq(str![[""]]);
 });
 
+forgetest!(can_use_config_severity_overrides, |prj, cmd| {
+    prj.add_source("OtherContract", OTHER_CONTRACT);
+
+    // Only high lints are run, including the overridden ones.
+    prj.update_config(|config| {
+        config.lint = LinterConfig {
+            severity: vec![LintSeverity::High],
+            severity_overrides: [("mixed-case-function".to_string(), LintSeverity::High)].into(),
+            ..Default::default()
+        };
+    });
+    cmd.arg("lint").assert_success().stderr_eq(str![[r#"
+warning[mixed-case-function]: function names should use mixedCase
+  [FILE]:9:14
+  │
+9 │     function functionMIXEDCaseInfo() public {}
+  │              ━━━━━━━━━━━━━━━━━━━━━ help: consider using: `functionMixedCaseInfo`
+  │
+  ╰ help: https://book.getfoundry.sh/reference/forge/forge-lint#mixed-case-function
+
+
+"#]]);
+
+    // Unknown lint ids are rejected.
+    prj.update_config(|config| {
+        config.lint.severity_overrides = [("unknown-lint".to_string(), LintSeverity::Low)].into();
+    });
+    cmd.forge_fuse().arg("lint").assert_failure().stderr_eq(str![[r#"
+Error: Unknown lint ID: unknown-lint
+
+"#]]);
+});
+
+forgetest!(can_use_config_erc20_unchecked_transfer_functions, |prj, cmd| {
+    prj.add_source(
+        "Approver",
+        r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+interface IERC20 {
+    function approve(address spender, uint256 amount) external returns (bool);
+}
+
+contract Approver {
+    function approveAll(IERC20 token, address spender) public {
+        token.approve(spender, 1);
+    }
+}
+"#,
+    );
+
+    cmd.args(["lint", "--only-lint", "erc20-unchecked-transfer"])
+        .assert_success()
+        .stderr_eq(str![[""]]);
+
+    prj.update_config(|config| {
+        config.lint.lint_specific.erc20_unchecked_transfer_functions =
+            vec!["approve(address,uint256)".to_string()];
+    });
+    cmd.assert_success().stderr_eq(str![[r#"
+warning[erc20-unchecked-transfer]: ERC20 'transfer' and 'transferFrom' calls should check the return value
+   [FILE]:11:9
+   │
+11 │         token.approve(spender, 1);
+   │         ━━━━━━━━━━━━━━━━━━━━━━━━━
+   │
+   ╰ help: https://book.getfoundry.sh/reference/forge/forge-lint#erc20-unchecked-transfer
+
+
+"#]]);
+});
+
+// tests that state variables used by derived contracts of other sources are not flagged as unused
+forgetest!(unused_state_variables_used_by_derived_contracts, |prj, cmd| {
+    prj.add_source(
+        "Base",
+        r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Base {
+    uint256 internal inherited;
+    uint256 internal unused;
+}
+"#,
+    );
+    prj.add_source(
+        "Derived",
+        r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+import {Base} from "./Base.sol";
+
+contract Derived is Base {
+    function set(uint256 value) external {
+        inherited = value;
+    }
+}
+"#,
+    );
+
+    cmd.args(["lint", "--only-lint", "unused-state-variables"]);
+    cmd.assert_success().stderr_eq(str![[r#"
+note[unused-state-variables]: state variable is never used
+  [FILE]:7:5
+  │
+7 │     uint256 internal unused;
+  │     ━━━━━━━━━━━━━━━━━━━━━━━━
+  │
+  ╰ help: https://book.getfoundry.sh/reference/forge/forge-lint#unused-state-variables
+
+
+"#]]);
+});
+
 forgetest!(multi_contract_file_no_exceptions, |prj, cmd| {
     prj.add_source("MixedFile", MULTI_CONTRACT_FILE);
 
@@ -834,6 +953,37 @@
 "#]]);
 });
 
+forgetest!(build_respects_run_on_build, |prj, cmd| {
+    prj.add_source("ContractWithLints", CONTRACT);
+
+    prj.update_config(|config| {
+        config.lint = LinterConfig {
+            severity: vec![LintSeverity::Med],
+            exclude_lints: vec!["incorrect-shift".into()],
+            run_on_build: Some(LintOnBuild::Deny),
+            ..Default::default()
+        };
+    });
+
+    // Lints fail the build, even when quiet.
+    cmd.args(["build", "--quiet"]).assert_failure().stderr_eq(str![[r#"
+warning[divide-before-multiply]: multiplication should occur before division to avoid loss of precision
+...
+Error: Lint failed
+
+Context:
+- aborting due to 1 linter warning(s)
+...
+"#]]);
+
+    // `run_on_build` takes precedence over `lint_on_build`.
+    prj.update_config(|config| {
+        config.lint.lint_on_build = true;
+        config.lint.run_on_build = Some(LintOnBuild::Off);
+    });
+    cmd.forge_fuse().args(["build", "--force"]).assert_success().stderr_eq(str![[""]]);
+});
+
 forgetest!(can_process_inline_config_regardless_of_input_order, |prj, cmd| {
     prj.add_source("ContractWithLints", CONTRACT);
     prj.add_source("OtherContractWithLints", OTHER_CONTRACT);
@@ -1265,3 +1415,318 @@ fn ensure_no_privileged_lint_id() {
 "#
     ]]);
 });
+
+forgetest!(can_fix_lints, |prj, cmd| {
+    prj.add_source(
+        "Fixable",
+        r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Fixable {
+    function shift(uint256 value) public pure returns (uint256) {
+        return 2 << value;
+    }
+
+    function check(bool enabled) public pure returns (bool) {
+        return enabled == true;
+    }
+}
+"#,
+    );
+
+    cmd.arg("lint")
+        .args(["--only-lint", "incorrect-shift", "boolean-equal", "--fix"])
+        .assert_success()
+        .stdout_eq(str![[r#"
+...
+--- a/src/Fixable.sol
++++ b/src/Fixable.sol
+@@ -7,1 +7,1 @@ swap the operands (not applied)
+-        return 2 << value;
++        return value << 2;
+@@ -11,1 +11,1 @@ consider simplifying to
+-        return enabled == true;
++        return enabled;
+
+Applied 1 fixes to 1 files
+
+"#]]);
+
+    // Swapping the operands may not be what was intended, so it's only suggested.
+    let fixed = std::fs::read_to_string(prj.root().join("src/Fixable.sol")).unwrap();
+    assert!(fixed.contains("return 2 << value;"));
+    assert!(fixed.contains("return enabled;"));
+
+    // Nothing is left to fix.
+    cmd.forge_fuse().args(["lint", "--only-lint", "incorrect-shift", "boolean-equal", "--fix"]);
+    cmd.assert_success().stdout_eq(str![[r#"
+...
+Applied 0 fixes to 0 files
+
+"#]]);
+});
+
+forgetest!(can_output_lints_as_json_and_sarif, |prj, cmd| {
+    prj.add_source(
+        "Shift",
+        r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Shift {
+    function shift(uint256 value) public pure returns (uint256) {
+        return 2 << value;
+    }
+}
+"#,
+    );
+
+    let output = cmd
+        .args(["lint", "--only-lint", "incorrect-shift", "--format", "json"])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let lints: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let [lint] = lints.as_array().unwrap().as_slice() else { panic!("expected one lint: {lints}") };
+    assert_eq!(lint["id"], "incorrect-shift");
+    assert_eq!(lint["severity"], "high");
+    assert_eq!(lint["file"], "src/Shift.sol");
+    assert_eq!(lint["span"]["start"]["line"], 7);
+    assert_eq!(lint["fix"]["replacement"], "value << 2");
+    assert_eq!(lint["fix"]["machineApplicable"], false);
+
+    cmd.forge_fuse().args(["lint", "--only-lint", "incorrect-shift", "--format", "sarif"]);
+    let output = cmd.assert_success().get_output().stdout_lossy();
+    let sarif: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(sarif["version"], "2.1.0");
+    let run = &sarif["runs"][0];
+    assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "incorrect-shift");
+    let result = &run["results"][0];
+    assert_eq!(result["ruleId"], "incorrect-shift");
+    assert_eq!(result["level"], "error");
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "src/Shift.sol");
+    assert_eq!(location["region"]["startLine"], 7);
+    assert_eq!(
+        result["fixes"][0]["artifactChanges"][0]["replacements"][0]["insertedContent"]["text"],
+        "value << 2"
+    );
+});
+
+forgetest!(can_explain_and_dump_lints, |_prj, cmd| {
+    cmd.args(["lint", "--explain", "mixed-case-variable"]).assert_success().stdout_eq(str![[r#"
+mixed-case-variable (Info)
+
+mutable variables should use mixedCase
+
+Examples:
+
+    uint256 Total_Supply;
+
+Configuration:
+    lint_specific.mixed_case_exceptions
+
+See https://book.getfoundry.sh/reference/forge/forge-lint#mixed-case-variable
+
+"#]]);
+
+    cmd.forge_fuse().args(["lint", "--explain", "unknown-lint"]);
+    cmd.assert_failure().stderr_eq(str![[r#"
+Error: Unknown lint ID: unknown-lint
+
+"#]]);
+
+    let output = cmd
+        .forge_fuse()
+        .args(["lint", "--dump-rules", "json"])
+        .assert_success()
+        .get_output()
+        .stdout_lossy();
+    let rules: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let rules = rules.as_array().unwrap();
+    assert!(rules.iter().all(|rule| !rule["examples"].as_array().unwrap().is_empty()));
+    let rule = rules.iter().find(|rule| rule["id"] == "erc20-unchecked-transfer").unwrap();
+    assert_eq!(rule["severity"], "high");
+    assert_eq!(rule["config"][0], "lint_specific.erc20_unchecked_transfer_functions");
+});
+
+forgetest!(can_replay_cached_lints, |prj, cmd| {
+    prj.add_source(
+        "Shift",
+        r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Shift {
+    function shift(uint256 value) public pure returns (uint256) {
+        return 2 << value;
+    }
+}
+"#,
+    );
+
+    let expected = str![[r#"
+warning[incorrect-shift]: the order of args in a shift operation is incorrect
+ [FILE]:7:16
+  │
+7 │         return 2 << value;
+  │                ━━━━━━━━━━
+  │
+  ╰ help: https://book.getfoundry.sh/reference/forge/forge-lint#incorrect-shift
+
+
+"#]];
+    cmd.args(["lint", "--only-lint", "incorrect-shift"])
+        .assert_success()
+        .stderr_eq(expected.clone());
+    assert!(prj.root().join("cache/lint/lint-cache.json").exists());
+
+    // Replayed from the cache.
+    cmd.assert_success().stderr_eq(expected);
+
+    // Linted again once the file changes.
+    prj.add_source(
+        "Shift",
+        r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Shift {
+    function shift(uint256 value) public pure returns (uint256) {
+        return value << 2;
+    }
+}
+"#,
+    );
+    cmd.assert_success().stderr_eq(str![[""]]);
+});
+
+forgetest!(can_compare_with_slither, |prj, cmd| {
+    prj.add_source(
+        "Shift",
+        r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Shift {
+    function shift(uint256 value) public pure returns (uint256) {
+        return 2 << value;
+    }
+
+    function check(uint256 value) public view returns (bool) {
+        return tx.origin == address(uint160(value));
+    }
+}
+"#,
+    );
+    let slither = serde_json::json!({
+        "success": true,
+        "error": null,
+        "results": {
+            "detectors": [
+                {
+                    "check": "incorrect-shift",
+                    "impact": "High",
+                    "description": "Shift.shift(uint256) contains an incorrect shift operation\n",
+                    "elements": [{
+                        "source_mapping": { "filename_relative": "src/Shift.sol", "lines": [6, 7, 8] },
+                    }],
+                },
+                {
+                    "check": "solc-version",
+                    "impact": "Informational",
+                    "description": "Version constraint ^0.8.0 contains known severe issues\n",
+                    "elements": [{
+                        "source_mapping": { "filename_relative": "src/Shift.sol", "lines": [3] },
+                    }],
+                },
+            ],
+        },
+    });
+    let path = prj.root().join("slither.json");
+    std::fs::write(&path, slither.to_string()).unwrap();
+
+    cmd.args(["lint", "--only-lint", "incorrect-shift", "tx-origin", "--compare-slither"])
+        .arg(&path)
+        .assert_success()
+        .stdout_eq(str![[r#"
+Found by both forge lint and Slither: 1
+
+Only found by forge lint: 1
+  src/Shift.sol:11:16 [tx-origin] `tx.origin` should not be used for authorization
+
+Only found by Slither: 1
+  src/Shift.sol:3 [solc-version] Version constraint ^0.8.0 contains known severe issues
+
+"#]]);
+});
+
+forgetest!(can_only_report_lints_missing_from_baseline, |prj, cmd| {
+    prj.add_source(
+        "Shift",
+        r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Shift {
+    function shift(uint256 value) public pure returns (uint256) {
+        return 2 << value;
+    }
+}
+"#,
+    );
+
+    cmd.args(["lint", "--only-lint", "incorrect-shift", "--write-baseline", "lint-baseline.json"])
+        .assert_success()
+        .stdout_eq(str![[r#"
+Wrote 1 lints to lint-baseline.json
+
+"#]]);
+    assert!(prj.root().join("lint-baseline.json").exists());
+
+    // The baselined lint is not reported, even once the code around it moves.
+    prj.add_source(
+        "Shift",
+        r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Shift {
+    function shift(uint256 value) public pure returns (uint256) {
+        return 2 << value;
+    }
+
+    function shiftAgain(uint256 value) public pure returns (uint256) {
+        return 2 << value;
+    }
+
+    function shiftMore(uint256 value) public pure returns (uint256) {
+        return 8 << value;
+    }
+}
+"#,
+    );
+    cmd.forge_fuse()
+        .args(["lint", "--only-lint", "incorrect-shift", "--baseline", "lint-baseline.json"])
+        .assert_success()
+        .stderr_eq(str![[r#"
+warning[incorrect-shift]: the order of args in a shift operation is incorrect
+   [FILE]:11:16
+   │
+11 │         return 2 << value;
+   │                ━━━━━━━━━━
+   │
+   ╰ help: https://book.getfoundry.sh/reference/forge/forge-lint#incorrect-shift
+
+warning[incorrect-shift]: the order of args in a shift operation is incorrect
+   [FILE]:15:16
+   │
+15 │         return 8 << value;
+   │                ━━━━━━━━━━
+   │
+   ╰ help: https://book.getfoundry.sh/reference/forge/forge-lint#incorrect-shift
+
+
+"#]]);
+});
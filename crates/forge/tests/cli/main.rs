@@ -6,6 +6,7 @@
 
 mod backtrace;
 mod bind;
+mod bind_events;
 mod bind_json;
 mod build;
 mod cache;
@@ -26,6 +27,7 @@
 mod lint;
 mod multi_script;
 mod precompiles;
+mod run_est;
 mod script;
 mod soldeer;
 mod svm;
@@ -0,0 +1,98 @@
+//! Tests for `forge run-est`.
+
+use std::path::{Path, PathBuf};
+
+/// Writes a state test whose transaction has a nonce too high, so the state is left unchanged.
+fn write_fixture(root: &Path, state_root: &str) -> PathBuf {
+    let fixture = format!(
+        r#"{{
+  "nonce_too_high": {{
+    "env": {{
+      "currentCoinbase": "0x2adc25665018aa1fe0e6bc666dac8fc2697ff9ba",
+      "currentDifficulty": "0x00",
+      "currentGasLimit": "0x016345785d8a0000",
+      "currentNumber": "0x01",
+      "currentTimestamp": "0x03e8",
+      "currentRandom": "0x0000000000000000000000000000000000000000000000000000000000000000",
+      "currentBaseFee": "0x07",
+      "currentExcessBlobGas": "0x00"
+    }},
+    "pre": {{
+      "0xa94f5374fce5edbc8e2a8697c15331677e6ebf0b": {{
+        "nonce": "0x00",
+        "balance": "0x0de0b6b3a7640000",
+        "code": "0x",
+        "storage": {{}}
+      }}
+    }},
+    "transaction": {{
+      "nonce": "0x01",
+      "gasPrice": "0x0a",
+      "gasLimit": ["0x5208"],
+      "to": "0x1000000000000000000000000000000000000000",
+      "value": ["0x01"],
+      "data": ["0x"],
+      "sender": "0xa94f5374fce5edbc8e2a8697c15331677e6ebf0b",
+      "secretKey": "0x45a915e4d060149eb4365960e6a7a45f334393093061116b197e3240065ff2d8"
+    }},
+    "post": {{
+      "Cancun": [
+        {{
+          "hash": "{state_root}",
+          "logs": "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
+          "indexes": {{ "data": 0, "gas": 0, "value": 0 }},
+          "expectException": "TransactionException.NONCE_MISMATCH_TOO_HIGH"
+        }}
+      ],
+      "ShanghaiToCancunAtTime15k": [
+        {{
+          "hash": "{state_root}",
+          "logs": "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
+          "indexes": {{ "data": 0, "gas": 0, "value": 0 }},
+          "expectException": "TransactionException.NONCE_MISMATCH_TOO_HIGH"
+        }}
+      ]
+    }}
+  }}
+}}"#
+    );
+    let path = root.join("fixtures").join("nonce_too_high.json");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, fixture).unwrap();
+    path
+}
+
+forgetest!(runs_state_test_fixtures, |prj, cmd| {
+    write_fixture(prj.root(), "0x517f2cdf6adb1a644878c390ffab4e130f1bed4b498ef7ce58c5addd98d61018");
+
+    cmd.args(["run-est", "fixtures"]).assert_success().stdout_eq(str![[r#"
+SKIP nonce_too_high [ShanghaiToCancunAtTime15k] d0g0v0: unsupported fork ShanghaiToCancunAtTime15k
+Cancun: 1 passed, 0 failed, 0 skipped
+ShanghaiToCancunAtTime15k: 0 passed, 0 failed, 1 skipped
+
+"#]]);
+
+    cmd.forge_fuse()
+        .args(["run-est", "fixtures", "--fork", "Cancun", "--match", "nonce"])
+        .assert_success()
+        .stdout_eq(str![[r#"
+Cancun: 1 passed, 0 failed, 0 skipped
+
+"#]]);
+});
+
+forgetest!(reports_state_root_mismatch, |prj, cmd| {
+    write_fixture(prj.root(), "0x0000000000000000000000000000000000000000000000000000000000000000");
+
+    cmd.args(["run-est", "fixtures/nonce_too_high.json", "--fork", "Cancun"])
+        .assert_failure()
+        .stdout_eq(str![[r#"
+FAIL nonce_too_high [Cancun] d0g0v0: state root mismatch: expected 0x0000000000000000000000000000000000000000000000000000000000000000, got 0x517f2cdf6adb1a644878c390ffab4e130f1bed4b498ef7ce58c5addd98d61018
+Cancun: 0 passed, 1 failed, 0 skipped
+
+"#]])
+        .stderr_eq(str![[r#"
+Error: 1 state test cases failed
+
+"#]]);
+});
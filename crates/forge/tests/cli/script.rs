@@ -6,6 +6,7 @@
 use alloy_primitives::{Address, Bytes, address, hex};
 use anvil::{NodeConfig, spawn};
 use forge_script_sequence::ScriptSequence;
+use foundry_config::ScriptExportFormat;
 use foundry_test_utils::{
     ScriptOutcome, ScriptTester,
     rpc::{self, next_http_archive_rpc_url},
@@ -2467,6 +2468,59 @@ struct Point {
     assert_eq!(sequence.transactions[1].additional_contracts.len(), 1);
 });
 
+forgetest_async!(can_verify_broadcast_against_simulation, |prj, cmd| {
+    let (_api, handle) = spawn(NodeConfig::test()).await;
+
+    foundry_test_utils::util::initialize(prj.root());
+    prj.add_source(
+        "Foo",
+        r#"
+import "forge-std/Script.sol";
+
+contract Counter {
+    uint256 public number;
+
+    function increment() public {
+        number++;
+    }
+}
+
+contract ContractScript is Script {
+    function run() public {
+        vm.startBroadcast();
+        Counter counter = new Counter();
+        counter.increment();
+    }
+}
+   "#,
+    );
+    let args = [
+        "script",
+        "ContractScript",
+        "--private-key",
+        "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+        "--rpc-url",
+        &handle.http_endpoint(),
+    ];
+    cmd.args(args).arg("--broadcast").assert_success();
+
+    let run_latest = prj.root().join("broadcast/Foo.sol/31337/run-latest.json");
+    let sequence: ScriptSequence<Ethereum> =
+        foundry_common::fs::read_json_file(&run_latest).unwrap();
+    let simulation = sequence.transactions[1].simulation.as_ref().unwrap();
+    assert!(simulation.gas_used > 0);
+    assert_eq!(simulation.state_diff.len(), 1);
+
+    cmd.forge_fuse().args(args).arg("--verify-broadcast").assert_success().stdout_eq(str![[r#"
+...
+✅  [Match] Chain: 31337 Hash: [..] Counter
+✅  [Match] Chain: 31337 Hash: [..] Counter::increment()
+
+All 2 broadcast transactions match their simulation.
+
+"#]]);
+});
+
 // <https://github.com/foundry-rs/foundry/issues/9661>
 forgetest_async!(should_set_correct_sender_nonce_via_cli, |prj, cmd| {
     foundry_test_utils::util::initialize(prj.root());
@@ -2751,7 +2805,7 @@ struct Point {
             r#"
 import "forge-std/Script.sol";
 import {Vm} from "forge-std/Vm.sol";
-import {Counter} from "../src/Counter.sol";
+import {Counter} from "src/Counter.sol";
 contract EIP7702Script is Script {
     uint256 constant PRIVATE_KEY = uint256(bytes32(0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80));
     address constant SENDER = 0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266;
@@ -3066,7 +3120,7 @@ struct Call {
         r#"
 import "forge-std/Script.sol";
 import {Factory} from "../src/Factory.sol";
-import {Counter} from "../src/Counter.sol";
+import {Counter} from "src/Counter.sol";
 
 contract FactoryScript is Script {
     Factory public factory;
@@ -3145,7 +3199,7 @@ struct Call {
         "Counter.s.sol",
         &r#"
 import "forge-std/Script.sol";
-import {Counter} from "../src/Counter.sol";
+import {Counter} from "src/Counter.sol";
 
 contract CounterScript is Script {
     Counter public counter;
@@ -3257,7 +3311,7 @@ struct Call {
         r#"
 import "forge-std/Script.sol";
 import {Vm} from "forge-std/Vm.sol";
-import {Counter} from "../src/Counter.sol";
+import {Counter} from "src/Counter.sol";
 contract CounterScript is Script {
     function run() public {
         vm.startBroadcast();
@@ -3590,3 +3644,139 @@ struct Call {
         .arg(prj.root())
         .assert_success();
 });
+
+// tests that `forge broadcast` indexes, shows and prunes the runs of the broadcast directory
+forgetest!(can_query_and_prune_broadcasts, |prj, cmd| {
+    let runs_dir = prj.root().join("broadcast/Deploy.s.sol/31337");
+    fs::create_dir_all(&runs_dir).unwrap();
+    let write_run = |timestamp: u64, contract: &str, address: &str, hash: &str| {
+        let run = serde_json::json!({
+            "transactions": [{
+                "hash": hash,
+                "transactionType": "CREATE",
+                "contractName": contract,
+                "contractAddress": address,
+                "function": null,
+                "arguments": null,
+                "additionalContracts": [],
+            }],
+            "receipts": [{
+                "transactionHash": hash,
+                "status": "0x1",
+                "blockNumber": "0x2",
+                "gasUsed": "0x5208",
+            }],
+            "chain": 31337,
+            "timestamp": timestamp,
+        });
+        fs::write(runs_dir.join(format!("run-{timestamp}.json")), run.to_string()).unwrap();
+    };
+    let hash = |byte: u8| format!("0x{}", hex::encode([byte; 32]));
+    write_run(1000, "Counter", "0x5fbdb2315678afecb367f032d93f642f64180aa3", &hash(1));
+    write_run(2000, "Counter", "0xe7f1725e7734ce288f8367e1bb143e90bb3f0512", &hash(2));
+    write_run(3000, "Token", "0x9fe46736679d2d9a65f0992f2272de9f3c7fa6e0", &hash(3));
+
+    cmd.args(["broadcast", "ls"]).assert_success().stdout_eq(str![[r#"
+...
+[..]Counter[..]0xe7f1725E7734CE288F8367e1Bb143E90bb3F0512[..]Deploy.s.sol[..]
+...
+[..]Token[..]0x9fE46736679d2D9a65F0992F2272dE9f3c7fa6e0[..]Deploy.s.sol[..]
+...
+"#]]);
+    assert!(prj.root().join("broadcast/index.json").exists());
+
+    cmd.forge_fuse().args(["broadcast", "show", &hash(1)]).assert_success().stdout_eq(str![[r#"
+Transaction 0x0101010101010101010101010101010101010101010101010101010101010101
+  run:      Deploy.s.sol/31337/run-1000.json
+  chain:    [..]
+  type:     CREATE
+  contract: Counter
+  address:  0x5fbdb2315678afecb367f032d93f642f64180aa3
+  status:   success (block 2, gas used 21000)
+
+"#]]);
+
+    // The runs of the latest deployments are kept.
+    cmd.forge_fuse().args(["broadcast", "prune", "--keep", "0"]).assert_success().stdout_eq(str![
+        [r#"
+Removed 1 of 3 runs
+
+"#]
+    ]);
+    assert!(!runs_dir.join("run-1000.json").exists());
+    assert!(runs_dir.join("run-2000.json").exists());
+
+    cmd.forge_fuse().args(["broadcast", "show", &hash(1)]).assert_failure().stderr_eq(str![[r#"
+Error: transaction 0x0101010101010101010101010101010101010101010101010101010101010101 not found in [..]
+
+"#]]);
+});
+
+// Deployed addresses are exported after broadcasting, as configured in `[script.exports]`.
+forgetest_async!(can_export_deployed_addresses, |prj, cmd| {
+    foundry_test_utils::util::initialize(prj.root());
+    prj.update_config(|config| {
+        config.script_exports.formats =
+            vec![ScriptExportFormat::Typescript, ScriptExportFormat::Solidity];
+    });
+    let deploy_script = prj.add_script(
+        "Deploy.s.sol",
+        r#"
+import "forge-std/Script.sol";
+import {Counter} from "src/Counter.sol";
+
+contract DeployScript is Script {
+    function run() external {
+        vm.broadcast();
+        new Counter();
+    }
+}"#,
+    );
+
+    let (_api, handle) = spawn(NodeConfig::test()).await;
+    cmd.args([
+        "script",
+        &format!("{}:DeployScript", deploy_script.display()),
+        "--rpc-url",
+        &handle.http_endpoint(),
+        "--broadcast",
+        "--private-key",
+        "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+    ])
+    .assert_success()
+    .stdout_eq(str![[r#"
+...
+Deployed addresses exported to:
+[..]addresses.json
+[..]addresses.schema.json
+[..]addresses.ts
+[..]Addresses.sol
+
+"#]]);
+
+    let deployments = prj.root().join("deployments");
+    let manifest: Value =
+        serde_json::from_str(&fs::read_to_string(deployments.join("addresses.json")).unwrap())
+            .unwrap();
+    assert_eq!(
+        manifest,
+        serde_json::json!({
+            "$schema": "./addresses.schema.json",
+            "chains": { "31337": { "Counter": "0x5FbDB2315678afecb367f032d93F642f64180aa3" } }
+        })
+    );
+    assert_eq!(
+        fs::read_to_string(deployments.join("addresses.ts")).unwrap(),
+        r#"// This file was generated by `forge script`, do not edit it.
+
+export const addresses = {
+  31337: {
+    Counter: "0x5FbDB2315678afecb367f032d93F642f64180aa3",
+  },
+} as const;
+
+export type ChainId = keyof typeof addresses;
+"#
+    );
+    assert!(deployments.join("Addresses.sol").exists());
+});
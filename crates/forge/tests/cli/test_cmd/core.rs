@@ -1,6 +1,6 @@
 //! Core test functionality tests
 
-use foundry_test_utils::str;
+use foundry_test_utils::{str, util::OutputExt};
 
 forgetest_init!(failing_test_after_failed_setup, |prj, cmd| {
     prj.add_test(
@@ -96,6 +96,66 @@
 "#]]);
 });
 
+forgetest_init!(gas_free_assertions, |prj, cmd| {
+    prj.add_test(
+        "GasFreeAssertions.t.sol",
+        r#"
+import "forge-std/Test.sol";
+
+contract MeteredAssertionsTest is Test {
+    function testAssertions() public pure {
+        for (uint256 i; i < 10; i++) {
+            vm.assertEq(i, i);
+            vm.assertTrue(i < 10);
+        }
+    }
+}
+
+/// forge-config: default.gas_free_assertions = true
+contract GasFreeAssertionsTest is Test {
+    function testAssertions() public pure {
+        for (uint256 i; i < 10; i++) {
+            vm.assertEq(i, i);
+            vm.assertTrue(i < 10);
+        }
+    }
+}
+
+contract MeteredStdAssertionsTest is Test {
+    function testAssertions() public pure {
+        for (uint256 i; i < 10; i++) {
+            assertEq(i, i);
+            assertTrue(i < 10);
+        }
+    }
+}
+
+/// forge-config: default.gas_free_assertions = true
+contract GasFreeStdAssertionsTest is Test {
+    function testAssertions() public pure {
+        for (uint256 i; i < 10; i++) {
+            assertEq(i, i);
+            assertTrue(i < 10);
+        }
+    }
+}
+"#,
+    );
+
+    let output = cmd.args(["test", "-j1"]).assert_success().get_output().stdout_lossy();
+    let gas = |contract: &str| {
+        let re =
+            regex::Regex::new(&format!(r"{contract}\n\[PASS\] testAssertions\(\) \(gas: (\d+)\)"))
+                .unwrap();
+        let captures = re.captures(&output).unwrap_or_else(|| panic!("{output}"));
+        captures[1].parse::<u64>().unwrap()
+    };
+    assert!(gas("GasFreeAssertionsTest") < gas("MeteredAssertionsTest"), "{output}");
+    // Only the cheatcode calls made by the forge-std assertions are not metered.
+    assert!(gas("GasFreeStdAssertionsTest") < gas("MeteredStdAssertionsTest"), "{output}");
+    assert!(gas("GasFreeStdAssertionsTest") > gas("GasFreeAssertionsTest"), "{output}");
+});
+
 forgetest_init!(payment_failure, |prj, cmd| {
     prj.add_test(
         "PaymentFailure.t.sol",
@@ -137,3 +197,220 @@
 
 "#]]);
 });
+
+forgetest_init!(cheatcode_permissions, |prj, cmd| {
+    prj.wipe_contracts();
+    prj.update_config(|config| {
+        config.cheatcode_permissions =
+            [("etch".to_string(), vec!["test/allowed".into()])].into_iter().collect();
+    });
+    prj.add_test(
+        "allowed/Etch.t.sol",
+        r#"
+import "forge-std/Test.sol";
+
+contract AllowedEtchTest is Test {
+    function testEtch() public {
+        vm.etch(address(1234), hex"00");
+    }
+}
+"#,
+    );
+    prj.add_test(
+        "Etch.t.sol",
+        r#"
+import "forge-std/Test.sol";
+
+contract RestrictedEtchTest is Test {
+    function testEtch() public {
+        vm.etch(address(1234), hex"00");
+    }
+}
+"#,
+    );
+
+    cmd.args(["test", "--cheatcode-audit", "-j1"]).assert_failure().stdout_eq(str![[r#"
+...
+Ran 1 test for test/Etch.t.sol:RestrictedEtchTest
+[FAIL: vm.etch: cannot be called from test/Etch.t.sol, see `cheatcode_permissions`] testEtch() ([GAS])
+Suite result: FAILED. 0 passed; 1 failed; 0 skipped; [ELAPSED]
+
+Ran 1 test for test/allowed/Etch.t.sol:AllowedEtchTest
+[PASS] testEtch() ([GAS])
+Suite result: ok. 1 passed; 0 failed; 0 skipped; [ELAPSED]
+
+Cheatcode audit:
+  etch(address,bytes)
+    test/Etch.t.sol:RestrictedEtchTest
+    test/allowed/Etch.t.sol:AllowedEtchTest
+...
+"#]]);
+});
+
+// tests that `--cheatcode-audit` reports cheatcodes invoked by invariant handlers
+forgetest_init!(cheatcode_audit_invariant, |prj, cmd| {
+    prj.wipe_contracts();
+    prj.add_test(
+        "Handler.t.sol",
+        r#"
+import "forge-std/Test.sol";
+
+contract Handler is Test {
+    function warp(uint256 delta) public {
+        vm.warp(block.timestamp + (delta % 100));
+    }
+}
+
+contract HandlerInvariantTest is Test {
+    function setUp() public {
+        targetContract(address(new Handler()));
+    }
+
+    function invariant_true() public pure {}
+}
+"#,
+    );
+
+    cmd.args(["test", "--cheatcode-audit"]).assert_success().stdout_eq(str![[r#"
+...
+Cheatcode audit:
+...
+  warp(uint256)
+    test/Handler.t.sol:Handler
+...
+"#]]);
+});
+
+// tests that `--profile-host` reports the peak EVM memory of each test
+forgetest_init!(profile_host, |prj, cmd| {
+    prj.wipe_contracts();
+    prj.add_test(
+        "Memory.t.sol",
+        r#"
+import "forge-std/Test.sol";
+
+contract MemoryTest is Test {
+    function testAllocate() public pure {
+        bytes memory data = new bytes(64 * 1024);
+        data[0] = 0x01;
+    }
+}
+"#,
+    );
+
+    cmd.args(["test", "--profile-host"]).assert_success().stdout_eq(str![[r#"
+...
+Host profile:
+[..]
+[..]Test[..]Peak Memory (B)[..]
+...
+[..]MemoryTest::testAllocate[..]
+...
+"#]]);
+
+    let output = cmd.forge_fuse().args(["test", "--profile-host", "--json"]).assert_success();
+    let json: serde_json::Value =
+        serde_json::from_str(&output.get_output().stdout_lossy()).unwrap();
+    let profile =
+        &json["test/Memory.t.sol:MemoryTest"]["test_results"]["testAllocate()"]["host_profile"];
+    assert!(profile["peak_memory"].as_u64().unwrap() >= 64 * 1024);
+    assert!(profile["peak_journal_accounts"].as_u64().unwrap() > 0);
+});
+
+// Lists the test inventory without executing the tests.
+forgetest_init!(list_test_inventory, |prj, cmd| {
+    prj.add_raw_test(
+        "Inventory.t.sol",
+        r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.18;
+
+import "forge-std/Test.sol";
+
+abstract contract ForkBase is Test {
+    function setUp() public virtual {
+        vm.createSelectFork("mainnet");
+    }
+
+    /// @custom:tags inherited
+    function testInherited() public {}
+}
+
+/// @custom:tags slow
+contract InventoryTest is ForkBase {
+    /// @custom:tags fuzz, math
+    function testFuzz_Add(uint256 a) public {}
+
+    function testOptimism() public {
+        vm.createSelectFork("optimism");
+    }
+}
+"#,
+    );
+
+    cmd.args(["test", "--list", "--match-path", "test/Inventory.t.sol"])
+        .assert_success()
+        .stdout_eq(str![[r#"
+...
+test/Inventory.t.sol
+  InventoryTest
+    testFuzz_Add
+    testInherited
+    testOptimism
+
+"#]]);
+
+    cmd.forge_fuse()
+        .args(["test", "--list", "--json", "--match-path", "test/Inventory.t.sol"])
+        .assert_success()
+        .stdout_eq(
+            str![[r#"
+[
+  {
+    "file": "test/Inventory.t.sol",
+    "contract": "InventoryTest",
+    "line": 16,
+    "fork": "mainnet",
+    "tags": [
+      "slow"
+    ],
+    "tests": [
+      {
+        "name": "testFuzz_Add",
+        "signature": "testFuzz_Add(uint256)",
+        "kind": "fuzz",
+        "line": 18,
+        "fork": "mainnet",
+        "tags": [
+          "slow",
+          "fuzz",
+          "math"
+        ]
+      },
+      {
+        "name": "testInherited",
+        "signature": "testInherited()",
+        "kind": "test",
+        "line": 12,
+        "fork": "mainnet",
+        "tags": [
+          "slow",
+          "inherited"
+        ]
+      },
+      {
+        "name": "testOptimism",
+        "signature": "testOptimism()",
+        "kind": "test",
+        "line": 20,
+        "fork": "optimism",
+        "tags": [
+          "slow"
+        ]
+      }
+    ]
+  }
+]
+"#]]
+            .is_json(),
+        );
+});
@@ -1,5 +1,5 @@
 use alloy_primitives::U256;
-use foundry_test_utils::{TestCommand, forgetest_init, str};
+use foundry_test_utils::{TestCommand, forgetest_init, str, util::OutputExt};
 use regex::Regex;
 
 forgetest_init!(test_can_scrape_bytecode, |prj, cmd| {
@@ -476,6 +476,7 @@
     assert!(!persist_dir.exists());
     prj.update_config(|config| {
         config.fuzz.failure_persist_dir = Some(persist_dir.clone());
+        config.fuzz.regressions_dir = None;
     });
 
     prj.add_test(
@@ -545,6 +546,49 @@ struct TestTuple {
     assert!(new_persist_dir.exists());
 });
 
+forgetest_init!(fuzz_failure_regressions, |prj, cmd| {
+    prj.update_config(|config| {
+        config.fuzz.seed = Some(U256::from(100));
+    });
+    prj.add_test(
+        "FuzzRegressions.t.sol",
+        r#"
+import "forge-std/Test.sol";
+
+contract FuzzRegressionsTest is Test {
+    function test_regression(uint256 x) public pure {
+        require(x < 1000);
+    }
+}
+   "#,
+    );
+
+    let calldata = |stdout: &str| {
+        let re = Regex::new(r"calldata=(0x[0-9a-fA-F]+)").unwrap();
+        re.captures(stdout).unwrap().get(1).unwrap().as_str().to_string()
+    };
+    cmd.args(["test", "-j1"]);
+    let output = cmd.assert_failure().get_output().stdout_lossy();
+    let failure = calldata(&output);
+
+    let regressions = prj.root().join("testdata/fuzz-regressions/FuzzRegressionsTest.txt");
+    let contents = std::fs::read_to_string(&regressions).unwrap();
+    assert!(
+        contents.contains(&format!("cc test_regression seed=0x64 calldata={failure}")),
+        "{contents}"
+    );
+
+    // The regression is replayed first, even without the failure persisted in the cache and with
+    // another seed.
+    std::fs::remove_dir_all(prj.root().join("cache/fuzz")).unwrap();
+    prj.update_config(|config| {
+        config.fuzz.seed = Some(U256::from(200));
+    });
+    let output = cmd.assert_failure().get_output().stdout_lossy();
+    assert_eq!(calldata(&output), failure);
+    assert_eq!(std::fs::read_to_string(&regressions).unwrap(), contents);
+});
+
 // https://github.com/foundry-rs/foundry/pull/735 behavior changed with https://github.com/foundry-rs/foundry/issues/3521
 // random values (instead edge cases) are generated if no fixtures defined
 forgetest_init!(fuzz_int, |prj, cmd| {
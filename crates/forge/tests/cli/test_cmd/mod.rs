@@ -2,6 +2,8 @@
 
 use alloy_primitives::U256;
 use anvil::{NodeConfig, spawn};
+use foundry_compilers::artifacts::EvmVersion;
+use foundry_config::ChainSpecConfig;
 use foundry_test_utils::{
     TestCommand,
     rpc::{self, rpc_endpoints},
@@ -553,6 +555,74 @@ fn dummy_test_filter(cmd: &mut TestCommand) {
 "#]]);
 });
 
+forgetest_init!(can_select_chain, |prj, cmd| {
+    let endpoint = rpc::next_http_archive_rpc_url();
+    prj.update_config(|config| {
+        config.chains.insert(
+            "mainnet".to_string(),
+            ChainSpecConfig {
+                rpc: Some(endpoint.clone()),
+                gas_price: Some(7),
+                block_base_fee_per_gas: Some(3),
+                ..Default::default()
+            },
+        );
+        config.chains.insert(
+            "legacy".to_string(),
+            ChainSpecConfig {
+                rpc: Some(endpoint),
+                fork_block_number: Some(15_000_000),
+                evm_version: Some(EvmVersion::London),
+                ..Default::default()
+            },
+        );
+    });
+
+    prj.add_test(
+        "SelectChain.t.sol",
+        r#"
+import "forge-std/Test.sol";
+
+interface Vm {
+    function selectChain(string calldata chain) external returns (uint256 forkId);
+}
+
+contract SelectChainTest is Test {
+    Vm constant chains = Vm(address(uint160(uint256(keccak256("hevm cheat code")))));
+
+    function test_selectChain() public {
+        uint256 mainnet = chains.selectChain("mainnet");
+        assertEq(block.chainid, 1);
+        assertEq(tx.gasprice, 7);
+        assertEq(block.basefee, 3);
+        assertEq(vm.activeFork(), mainnet);
+
+        uint256 legacy = chains.selectChain("legacy");
+        assertTrue(legacy != mainnet);
+        assertEq(block.number, 15_000_000);
+        assertEq(vm.getEvmVersion(), "london");
+
+        assertEq(chains.selectChain("mainnet"), mainnet);
+        assertEq(vm.activeFork(), mainnet);
+    }
+}
+   "#,
+    );
+
+    cmd.args(["test", "--mt", "test_selectChain"]).assert_success().stdout_eq(str![[r#"
+[COMPILING_FILES] with [SOLC_VERSION]
+[SOLC_VERSION] [ELAPSED]
+Compiler run successful!
+
+Ran 1 test for test/SelectChain.t.sol:SelectChainTest
+[PASS] test_selectChain() ([GAS])
+Suite result: ok. 1 passed; 0 failed; 0 skipped; [ELAPSED]
+
+Ran 1 test suite [ELAPSED]: 1 tests passed, 0 failed, 0 skipped (1 total tests)
+
+"#]]);
+});
+
 static FAILING_TEST: &str = r#"
 import "forge-std/Test.sol";
 
@@ -2002,6 +2072,51 @@ fn extract_test_result(out: &str) -> &str {
 "#]]);
 });
 
+// Tests that `expectPartialRevert` with `checkArgs` compares the flagged decoded arguments only.
+forgetest_init!(test_expect_partial_revert_checked_args, |prj, cmd| {
+    prj.insert_ds_test();
+    prj.insert_vm();
+    prj.clear();
+
+    prj.add_source(
+        "Counter.t.sol",
+        r#"
+import {Vm} from "./Vm.sol";
+import {DSTest} from "./test.sol";
+contract Counter {
+    error WrongNumber(string reason, uint256 number);
+    function count(string memory reason, uint256 number) public pure {
+        revert WrongNumber(reason, number);
+    }
+}
+contract CounterTest is DSTest {
+    Vm vm = Vm(HEVM_ADDRESS);
+    function testCheckedArgMatches() public {
+        Counter counter = new Counter();
+        bool[] memory checkArgs = new bool[](2);
+        checkArgs[0] = true;
+        vm.expectPartialRevert(abi.encodeWithSelector(Counter.WrongNumber.selector, "odd", 0), checkArgs);
+        counter.count("odd", 1);
+    }
+    function testCheckedArgDiffers() public {
+        Counter counter = new Counter();
+        bool[] memory checkArgs = new bool[](2);
+        checkArgs[0] = true;
+        vm.expectPartialRevert(abi.encodeWithSelector(Counter.WrongNumber.selector, "even", 1), checkArgs);
+        counter.count("odd", 1);
+    }
+}
+     "#,
+    );
+
+    cmd.args(["test"]).assert_failure().stdout_eq(str![[r#"
+...
+[FAIL: Error != expected error: WrongNumber("odd", 1) != WrongNumber("even", 1)] testCheckedArgDiffers() ([GAS])
+[PASS] testCheckedArgMatches() ([GAS])
+...
+"#]]);
+});
+
 forgetest_init!(test_assume_no_revert, |prj, cmd| {
     prj.insert_ds_test();
     prj.insert_vm();
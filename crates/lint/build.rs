@@ -0,0 +1,17 @@
+#![expect(clippy::disallowed_macros)]
+
+use std::{env, process::Command};
+
+/// Records the version of the compiler, since plugins must be built with the same one.
+fn main() {
+    println!("cargo:rerun-if-env-changed=RUSTC");
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|version| version.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=FORGE_LINT_RUSTC_VERSION={version}");
+}
@@ -0,0 +1,145 @@
+//! Baselines of the lints of a codebase, to only report the new ones.
+//!
+//! A baseline lists the lints emitted in each file, identified by their id and the linted code
+//! rather than their position, so that baselined lints stay suppressed when the code around them
+//! moves. Each entry suppresses a single occurrence of the lint.
+
+use crate::report::FileReport;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::Path, sync::Mutex};
+
+/// The lints of a codebase, written by `forge lint --write-baseline`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    lints: Vec<BaselineLint>,
+}
+
+/// A baselined lint.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct BaselineLint {
+    /// The path of the file, relative to the project root.
+    pub file: String,
+    /// The lint id.
+    pub id: String,
+    /// The linted code, with normalized whitespaces.
+    pub code: String,
+}
+
+impl Baseline {
+    /// Creates the baseline of the lints reported for the files of the project at `root`.
+    pub fn from_reports(root: &Path, reports: &[FileReport]) -> eyre::Result<Self> {
+        let mut lints = Vec::new();
+        for report in reports {
+            let src = foundry_common::fs::read_to_string(&report.path)?;
+            let file = relative_path(root, &report.path);
+            lints.extend(report.lints.iter().map(|lint| BaselineLint {
+                file: file.clone(),
+                id: lint.id.to_string(),
+                code: normalize(src.get(lint.range.clone()).unwrap_or_default()),
+            }));
+        }
+        lints.sort();
+        Ok(Self { lints })
+    }
+
+    /// Reads the baseline at `path`.
+    pub fn read(path: &Path) -> eyre::Result<Self> {
+        Ok(foundry_common::fs::read_json_file(path)?)
+    }
+
+    /// Writes the baseline to `path`.
+    pub fn write(&self, path: &Path) -> eyre::Result<()> {
+        Ok(foundry_common::fs::write_pretty_json_file(path, self)?)
+    }
+
+    /// Returns the number of baselined lints.
+    pub fn len(&self) -> usize {
+        self.lints.len()
+    }
+
+    /// Returns `true` if no lint is baselined.
+    pub fn is_empty(&self) -> bool {
+        self.lints.is_empty()
+    }
+
+    /// Returns the lints baselined in the file at `path` of the project at `root`.
+    pub fn for_file(&self, root: &Path, path: &Path) -> FileBaseline {
+        let file = relative_path(root, path);
+        let mut remaining = HashMap::<_, usize>::new();
+        for lint in self.lints.iter().filter(|lint| lint.file == file) {
+            *remaining.entry((lint.id.clone(), lint.code.clone())).or_default() += 1;
+        }
+        FileBaseline { remaining: Mutex::new(remaining) }
+    }
+}
+
+/// The lints baselined in a file, consumed as they are emitted.
+#[derive(Debug, Default)]
+pub struct FileBaseline {
+    remaining: Mutex<HashMap<(String, String), usize>>,
+}
+
+impl FileBaseline {
+    /// Returns `true` if the lint emitted on `code` is baselined, consuming its baseline entry.
+    pub fn consume(&self, id: &str, code: &str) -> bool {
+        let mut remaining = self.remaining.lock().unwrap();
+        match remaining.get_mut(&(id.to_string(), normalize(code))) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Returns the path of a file, relative to the project root, with forward slashes.
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root).unwrap_or(path).to_string_lossy().replace('\\', "/")
+}
+
+/// Collapses the whitespaces of `code`, so that reformatting does not invalidate the baseline.
+fn normalize(code: &str) -> String {
+    code.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::{LintReport, Position};
+    use foundry_config::lint::Severity;
+
+    #[test]
+    fn suppresses_each_baselined_occurrence_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("src/Shift.sol");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "uint256 a = 2 << x;\nuint256 b = 2 << x;\n").unwrap();
+        let lint = |start| LintReport {
+            id: "incorrect-shift",
+            severity: Severity::High,
+            message: String::new(),
+            help: "",
+            range: start..start + 6,
+            start: Position { line: 1, column: 1 },
+            end: Position { line: 1, column: 1 },
+            fix: None,
+        };
+        let reports = [FileReport::new(path.clone(), vec![lint(12), lint(32)])];
+
+        let baseline = Baseline::from_reports(dir.path(), &reports).unwrap();
+        assert_eq!(baseline.len(), 2);
+        let file = dir.path().join("baseline.json");
+        baseline.write(&file).unwrap();
+        let baseline = Baseline::read(&file).unwrap();
+
+        let remaining = baseline.for_file(dir.path(), &path);
+        assert!(remaining.consume("incorrect-shift", "2  <<  x"));
+        assert!(remaining.consume("incorrect-shift", "2 << x"));
+        assert!(!remaining.consume("incorrect-shift", "2 << x"));
+        assert!(!remaining.consume("incorrect-shift", "2 << y"));
+
+        let other = baseline.for_file(dir.path(), &dir.path().join("src/Other.sol"));
+        assert!(!other.consume("incorrect-shift", "2 << x"));
+    }
+}
@@ -0,0 +1,249 @@
+//! Cache of the emitted lints, to only lint the files which changed since the previous run.
+//!
+//! Each linted file is cached with a hash of its content and of the content of the sources it is
+//! related to through imports, either way, since the late lints can look at the contracts of other
+//! sources, e.g. the ones deriving from the linted contracts. The whole cache is invalidated when
+//! the linter config or the forge version change.
+//!
+//! Cached lints are replayed with the [`LintContext`](crate::linter::LintContext) of the file, so
+//! they are emitted and reported just like fresh ones.
+
+use alloy_primitives::{B256, keccak256};
+use serde::{Deserialize, Serialize};
+use solar::{
+    interface::diagnostics::{Applicability, SuggestionStyle},
+    sema::hir,
+};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    ops::Range,
+    path::{Path, PathBuf},
+    sync::{LazyLock, Mutex},
+};
+
+/// The name of the cache file, in the lint cache directory.
+pub const LINT_CACHE_FILE: &str = "lint-cache.json";
+
+/// The lints emitted by the previous runs, by file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct LintCache {
+    /// The version of forge which emitted the lints.
+    version: String,
+    /// The hash of the linter config.
+    config: B256,
+    /// The cached files, by path.
+    files: BTreeMap<PathBuf, CachedFile>,
+}
+
+/// The lints emitted in a file.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct CachedFile {
+    /// The hash of the file and of its related sources.
+    hash: B256,
+    /// The emitted lints, in emission order.
+    lints: Vec<CachedLint>,
+}
+
+/// An emitted lint.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedLint {
+    /// The lint id.
+    pub id: String,
+    /// The message of the diagnostic, if not the lint description.
+    pub message: Option<String>,
+    /// The byte range of the linted code.
+    pub range: Range<usize>,
+    /// The emitted suggestion, if any.
+    pub suggestion: Option<CachedSuggestion>,
+}
+
+/// A suggestion emitted with a lint.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum CachedSuggestion {
+    /// A code example.
+    Example { desc: Option<String>, content: String },
+    /// A fix, replacing `range` with `content`.
+    Fix {
+        desc: Option<String>,
+        content: String,
+        range: Range<usize>,
+        applicability: String,
+        style: String,
+    },
+}
+
+impl CachedSuggestion {
+    /// Returns the description of the suggestion, interned for the lifetime of the program as
+    /// lint suggestions only accept static descriptions.
+    pub fn desc(&self) -> Option<&'static str> {
+        let (Self::Example { desc, .. } | Self::Fix { desc, .. }) = self;
+        desc.as_deref().map(intern)
+    }
+}
+
+impl LintCache {
+    /// Reads the cache at `path`.
+    ///
+    /// Returns an empty cache if it is missing, unreadable, or was written by another version or
+    /// with another config.
+    pub fn read(path: &Path, version: &str, config: B256) -> Self {
+        foundry_common::fs::read_json_file::<Self>(path)
+            .ok()
+            .filter(|cache| cache.version == version && cache.config == config)
+            .unwrap_or_else(|| Self {
+                version: version.to_string(),
+                config,
+                files: BTreeMap::new(),
+            })
+    }
+
+    /// Writes the cache to `path`.
+    pub fn write(&self, path: &Path) -> eyre::Result<()> {
+        if let Some(parent) = path.parent() {
+            foundry_common::fs::create_dir_all(parent)?;
+        }
+        foundry_common::fs::write_json_file(path, self)?;
+        Ok(())
+    }
+
+    /// Returns the lints cached for the file at `path`, if its hash did not change.
+    pub fn get(&self, path: &Path, hash: B256) -> Option<&[CachedLint]> {
+        self.files.get(path).filter(|file| file.hash == hash).map(|file| file.lints.as_slice())
+    }
+
+    /// Caches the lints emitted in the file at `path`.
+    pub fn insert(&mut self, path: PathBuf, hash: B256, lints: Vec<CachedLint>) {
+        self.files.insert(path, CachedFile { hash, lints });
+    }
+}
+
+/// Returns the hash of the linter config, from its debug representation.
+pub fn config_hash(config: &impl std::fmt::Debug) -> B256 {
+    keccak256(format!("{config:?}"))
+}
+
+/// Returns the hash of a file, from the hashes of its content and of the content of its related
+/// sources.
+pub fn file_hash(mut related: Vec<B256>) -> B256 {
+    related.sort_unstable();
+    related.dedup();
+    keccak256(related.iter().flat_map(|hash| hash.as_slice()).copied().collect::<Vec<_>>())
+}
+
+/// The content hashes and the import graph of the lowered sources, to hash the linted files.
+pub(crate) struct SourceHashes {
+    content: HashMap<hir::SourceId, B256>,
+    imports: HashMap<hir::SourceId, Vec<hir::SourceId>>,
+    importers: HashMap<hir::SourceId, Vec<hir::SourceId>>,
+}
+
+impl SourceHashes {
+    pub(crate) fn new(hir: &hir::Hir<'_>) -> Self {
+        let mut content = HashMap::new();
+        let mut imports = HashMap::<_, Vec<_>>::new();
+        let mut importers = HashMap::<_, Vec<_>>::new();
+        for (id, source) in hir.sources_enumerated() {
+            content.insert(id, keccak256(source.file.src.as_bytes()));
+            for &(_, import) in source.imports {
+                imports.entry(id).or_default().push(import);
+                importers.entry(import).or_default().push(id);
+            }
+        }
+        Self { content, imports, importers }
+    }
+
+    /// Returns the hash of a source, from its content and the content of the sources it imports
+    /// or is imported by, transitively.
+    pub(crate) fn hash(&self, id: hir::SourceId) -> B256 {
+        let mut related = Vec::new();
+        for graph in [&self.imports, &self.importers] {
+            let mut seen = HashSet::from([id]);
+            let mut stack = vec![id];
+            while let Some(id) = stack.pop() {
+                related.extend(self.content.get(&id).copied());
+                let next = graph.get(&id).into_iter().flatten();
+                stack.extend(next.copied().filter(|&next| seen.insert(next)));
+            }
+        }
+        file_hash(related)
+    }
+}
+
+pub(crate) fn applicability_to_str(applicability: Applicability) -> String {
+    format!("{applicability:?}")
+}
+
+pub(crate) fn applicability_from_str(s: &str) -> Applicability {
+    match s {
+        "MachineApplicable" => Applicability::MachineApplicable,
+        "MaybeIncorrect" => Applicability::MaybeIncorrect,
+        "HasPlaceholders" => Applicability::HasPlaceholders,
+        _ => Applicability::Unspecified,
+    }
+}
+
+pub(crate) fn style_to_str(style: SuggestionStyle) -> String {
+    format!("{style:?}")
+}
+
+pub(crate) fn style_from_str(s: &str) -> SuggestionStyle {
+    match s {
+        "CompletelyHidden" => SuggestionStyle::CompletelyHidden,
+        _ => SuggestionStyle::ShowCode,
+    }
+}
+
+/// Interns a string, leaking each distinct string once.
+fn intern(s: &str) -> &'static str {
+    static INTERNED: LazyLock<Mutex<HashSet<&'static str>>> = LazyLock::new(Default::default);
+    let mut interned = INTERNED.lock().unwrap();
+    if let Some(&s) = interned.get(s) {
+        return s;
+    }
+    let s: &'static str = Box::leak(s.into());
+    interned.insert(s);
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalidates_on_version_config_or_hash_change() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("lint").join(LINT_CACHE_FILE);
+        let config = config_hash(&("severity", ["high"]));
+        let hash = file_hash(vec![keccak256("contract A {}"), keccak256("contract B {}")]);
+        let lint = CachedLint {
+            id: "incorrect-shift".to_string(),
+            message: None,
+            range: 10..16,
+            suggestion: Some(CachedSuggestion::Fix {
+                desc: Some("swap the operands".to_string()),
+                content: "x << 2".to_string(),
+                range: 10..16,
+                applicability: applicability_to_str(Applicability::MachineApplicable),
+                style: style_to_str(SuggestionStyle::ShowCode),
+            }),
+        };
+
+        let mut cache = LintCache::read(&path, "1.0.0", config);
+        assert!(cache.get(Path::new("src/A.sol"), hash).is_none());
+        cache.insert("src/A.sol".into(), hash, vec![lint.clone()]);
+        cache.write(&path).unwrap();
+
+        let cache = LintCache::read(&path, "1.0.0", config);
+        assert_eq!(cache.get(Path::new("src/A.sol"), hash), Some(&[lint][..]));
+        assert!(cache.get(Path::new("src/A.sol"), keccak256("contract A {}")).is_none());
+        assert!(
+            LintCache::read(&path, "1.0.1", config).get(Path::new("src/A.sol"), hash).is_none()
+        );
+        let other = config_hash(&("severity", ["med"]));
+        assert!(LintCache::read(&path, "1.0.0", other).get(Path::new("src/A.sol"), hash).is_none());
+
+        // Related sources are hashed regardless of their order.
+        assert_eq!(hash, file_hash(vec![keccak256("contract B {}"), keccak256("contract A {}")]));
+    }
+}
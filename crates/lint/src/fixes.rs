@@ -0,0 +1,456 @@
+//! Quick-fixes for common compiler diagnostics and lints.
+//!
+//! Fixes are text edits of the source file reported by a diagnostic. Like lint suggestions, each
+//! fix has an [`Applicability`]: only [`Applicability::MachineApplicable`] fixes are applied by
+//! [`FileFixes::apply`], the others are only surfaced as patches.
+//!
+//! Lint fixes are the suggestions emitted with
+//! [`LintContext::emit_with_suggestion`](crate::linter::LintContext::emit_with_suggestion), and are
+//! collected by [`SolidityLinter::with_fixes`](crate::sol::SolidityLinter::with_fixes).
+
+use foundry_compilers::artifacts::Error as CompilerError;
+use foundry_config::SolidityErrorCode;
+use solar::interface::diagnostics::Applicability;
+use std::{
+    fmt::Write,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+/// Error: "Source file requires different compiler version".
+const COMPILER_VERSION_MISMATCH: u64 = 5333;
+
+/// The license identifier inserted in files without one.
+const DEFAULT_LICENSE: &str = "// SPDX-License-Identifier: UNLICENSED\n";
+
+/// The diagnostic fixed by a [`Fix`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FixOrigin {
+    /// A solc diagnostic, by error code.
+    Solc(SolidityErrorCode),
+    /// A lint, by id.
+    Lint(&'static str),
+}
+
+/// A text edit fixing a compiler diagnostic or a lint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fix {
+    /// The fixed diagnostic.
+    pub origin: FixOrigin,
+    /// A short description of the edit.
+    pub desc: &'static str,
+    /// The byte range of the source to replace.
+    pub range: Range<usize>,
+    /// The replacement of `range`.
+    pub replacement: String,
+    /// Whether the fix can be applied without review.
+    pub applicability: Applicability,
+}
+
+impl Fix {
+    fn new(
+        code: SolidityErrorCode,
+        desc: &'static str,
+        range: Range<usize>,
+        replacement: impl Into<String>,
+        applicability: Applicability,
+    ) -> Self {
+        Self {
+            origin: FixOrigin::Solc(code),
+            desc,
+            range,
+            replacement: replacement.into(),
+            applicability,
+        }
+    }
+
+    /// Creates the fix of a lint.
+    pub fn lint(
+        id: &'static str,
+        desc: &'static str,
+        range: Range<usize>,
+        replacement: impl Into<String>,
+        applicability: Applicability,
+    ) -> Self {
+        Self {
+            origin: FixOrigin::Lint(id),
+            desc,
+            range,
+            replacement: replacement.into(),
+            applicability,
+        }
+    }
+
+    /// Returns `true` if the fix can be applied without review.
+    pub fn is_machine_applicable(&self) -> bool {
+        matches!(self.applicability, Applicability::MachineApplicable)
+    }
+}
+
+/// Returns the fix of a solc diagnostic in `source`, if there is one.
+///
+/// Handles missing SPDX license identifiers and `pragma solidity` directives, mismatching
+/// `pragma solidity` directives, unused function parameters and unused local variables.
+pub fn solc_fix(error: &CompilerError, source: &str) -> Option<Fix> {
+    let code = error.error_code?;
+    let location = error.source_location.as_ref();
+    let range = location.and_then(|loc| {
+        let range = usize::try_from(loc.start).ok()?..usize::try_from(loc.end).ok()?;
+        source.get(range.clone()).is_some().then_some(range)
+    });
+
+    match SolidityErrorCode::from(code) {
+        SolidityErrorCode::SpdxLicenseNotProvided => Some(Fix::new(
+            SolidityErrorCode::SpdxLicenseNotProvided,
+            "add an SPDX license identifier",
+            0..0,
+            DEFAULT_LICENSE,
+            Applicability::MachineApplicable,
+        )),
+        SolidityErrorCode::PragmaSolidity => {
+            // `Consider adding "pragma solidity ^0.8.30;"`
+            let pragma = error.message.split('"').nth(1)?;
+            let at = license_line_end(source);
+            Some(Fix::new(
+                SolidityErrorCode::PragmaSolidity,
+                "add a version pragma",
+                at..at,
+                format!("{pragma}\n"),
+                Applicability::MachineApplicable,
+            ))
+        }
+        SolidityErrorCode::UnusedFunctionParameter => {
+            let range = range?;
+            let (ty, name) = source[range.clone()].rsplit_once(char::is_whitespace)?;
+            Some(Fix::new(
+                SolidityErrorCode::UnusedFunctionParameter,
+                "comment out the parameter name",
+                range,
+                format!("{} /* {name} */", ty.trim_end()),
+                Applicability::MachineApplicable,
+            ))
+        }
+        SolidityErrorCode::UnusedLocalVariable => unused_variable_fix(source, range?),
+        SolidityErrorCode::Other(COMPILER_VERSION_MISMATCH) => {
+            // `Source file requires different compiler version (current compiler is
+            // 0.8.30+commit.73712a01.Linux.g++) - ...`
+            let current = error.message.split("current compiler is ").nth(1)?;
+            let version = current.split(['+', ')', ' ', '-']).next()?;
+            if version.split('.').count() != 3
+                || version.split('.').any(|n| n.parse::<u64>().is_err())
+            {
+                return None;
+            }
+            Some(Fix::new(
+                code.into(),
+                "use the current compiler version",
+                range?,
+                format!("pragma solidity ^{version};"),
+                Applicability::MachineApplicable,
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Removes an unused local variable declaration.
+///
+/// Declarations in a tuple assignment and declarations without a value are removed. When the
+/// declaration has a value, the value is kept as an expression statement, since it may have side
+/// effects: this is only a suggestion.
+fn unused_variable_fix(source: &str, range: Range<usize>) -> Option<Fix> {
+    let code = SolidityErrorCode::UnusedLocalVariable;
+    let rest = &source[range.end..];
+    let next = rest.trim_start();
+    let after_ws = range.end + rest.len() - next.len();
+    match next.chars().next()? {
+        ',' | ')' => Some(Fix::new(
+            code,
+            "remove the variable from the tuple",
+            range,
+            "",
+            Applicability::MachineApplicable,
+        )),
+        ';' => {
+            // Remove the whole statement, and its line if nothing else is on it.
+            let (start, end) = line_bounds(source, range.start, after_ws + 1);
+            Some(Fix::new(
+                code,
+                "remove the variable",
+                start..end,
+                "",
+                Applicability::MachineApplicable,
+            ))
+        }
+        '=' if !next.starts_with("==") => {
+            let value = next[1..].trim_start();
+            let end = after_ws + next.len() - value.len();
+            Some(Fix::new(
+                code,
+                "remove the variable and keep its value",
+                range.start..end,
+                "",
+                Applicability::MaybeIncorrect,
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Extends `start..end` to the full line if the line only contains whitespace besides it.
+fn line_bounds(source: &str, start: usize, end: usize) -> (usize, usize) {
+    let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = source[end..].find('\n').map_or(source.len(), |i| end + i + 1);
+    if source[line_start..start].trim().is_empty() && source[end..line_end].trim().is_empty() {
+        (line_start, line_end)
+    } else {
+        (start, end)
+    }
+}
+
+/// Returns the offset after the SPDX license identifier line, or `0` if there is none.
+fn license_line_end(source: &str) -> usize {
+    let mut offset = 0;
+    for line in source.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.contains("SPDX-License-Identifier:") {
+            return offset + line.len();
+        }
+        if !trimmed.is_empty() && !trimmed.starts_with("//") {
+            break;
+        }
+        offset += line.len();
+    }
+    0
+}
+
+/// The fixes of a source file.
+#[derive(Clone, Debug)]
+pub struct FileFixes {
+    /// The path of the source file.
+    pub path: PathBuf,
+    /// The content of the source file.
+    pub source: String,
+    /// The fixes, sorted by position and without overlaps.
+    pub fixes: Vec<Fix>,
+}
+
+impl FileFixes {
+    /// Collects the fixes of the given solc diagnostics, grouped by source file.
+    ///
+    /// Diagnostic locations are resolved relative to `root`.
+    pub fn collect<'a>(
+        root: &Path,
+        errors: impl IntoIterator<Item = &'a CompilerError>,
+    ) -> eyre::Result<Vec<Self>> {
+        let mut files = Vec::<Self>::new();
+        for error in errors {
+            let Some(location) = &error.source_location else { continue };
+            let path = root.join(&location.file);
+            let idx = match files.iter().position(|file| file.path == path) {
+                Some(idx) => idx,
+                None => {
+                    let source = std::fs::read_to_string(&path)?;
+                    files.push(Self { path, source, fixes: Vec::new() });
+                    files.len() - 1
+                }
+            };
+            let file = &mut files[idx];
+            if let Some(fix) = solc_fix(error, &file.source) {
+                file.fixes.push(fix);
+            }
+        }
+
+        files.retain_mut(|file| {
+            file.normalize();
+            !file.fixes.is_empty()
+        });
+        Ok(files)
+    }
+
+    /// Creates the fixes of a source file, sorting them and dropping the overlapping ones.
+    pub fn new(path: PathBuf, source: String, fixes: Vec<Fix>) -> Self {
+        let mut file = Self { path, source, fixes };
+        file.normalize();
+        file
+    }
+
+    /// Sorts the fixes by position, and drops duplicates and fixes overlapping a previous one.
+    fn normalize(&mut self) {
+        let key = |fix: &Fix| {
+            let code = match fix.origin {
+                FixOrigin::Solc(code) => u64::from(code),
+                FixOrigin::Lint(_) => u64::MAX,
+            };
+            (fix.range.start, fix.range.end, code, fix.desc)
+        };
+        self.fixes.sort_by(|a, b| key(a).cmp(&key(b)));
+        self.fixes.dedup();
+        let mut end = 0;
+        self.fixes.retain(|fix| {
+            let keep = fix.range.start >= end;
+            end = end.max(fix.range.end);
+            keep
+        });
+    }
+
+    /// Returns the number of machine-applicable fixes.
+    pub fn applicable(&self) -> usize {
+        self.fixes.iter().filter(|fix| fix.is_machine_applicable()).count()
+    }
+
+    /// Returns the source with all machine-applicable fixes applied.
+    pub fn apply(&self) -> String {
+        let mut fixed = String::with_capacity(self.source.len());
+        let mut pos = 0;
+        for fix in self.fixes.iter().filter(|fix| fix.is_machine_applicable()) {
+            fixed.push_str(&self.source[pos..fix.range.start]);
+            fixed.push_str(&fix.replacement);
+            pos = fix.range.end;
+        }
+        fixed.push_str(&self.source[pos..]);
+        fixed
+    }
+
+    /// Renders the fixes as a unified diff, with one hunk per fix.
+    ///
+    /// Hunks of fixes that are not machine-applicable are marked as not applied, and don't shift
+    /// the line numbers of the following hunks.
+    ///
+    /// `display_path` is the path shown in the diff header.
+    pub fn patch(&self, display_path: &Path) -> String {
+        let path = display_path.display();
+        let mut patch = format!("--- a/{path}\n+++ b/{path}\n");
+        let mut line_delta = 0isize;
+        for fix in &self.fixes {
+            let start = self.source[..fix.range.start].rfind('\n').map_or(0, |i| i + 1);
+            let end = self.source[fix.range.end..]
+                .find('\n')
+                .map_or(self.source.len(), |i| fix.range.end + i + 1);
+            let before = &self.source[start..end];
+            let after = format!(
+                "{}{}{}",
+                &self.source[start..fix.range.start],
+                fix.replacement,
+                &self.source[fix.range.end..end]
+            );
+
+            let line = self.source[..start].lines().count() + 1;
+            let (old, new) = (before.lines().count(), after.lines().count());
+            let new_line = line.saturating_add_signed(line_delta);
+            if fix.is_machine_applicable() {
+                line_delta += new as isize - old as isize;
+            }
+
+            let note = if fix.is_machine_applicable() { "" } else { " (not applied)" };
+            let _ = writeln!(patch, "@@ -{line},{old} +{new_line},{new} @@ {}{note}", fix.desc);
+            for line in before.lines() {
+                let _ = writeln!(patch, "-{line}");
+            }
+            for line in after.lines() {
+                let _ = writeln!(patch, "+{line}");
+            }
+        }
+        patch
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(code: u64, source: &str, span: Option<&str>, message: &str) -> CompilerError {
+        let (start, end) = match span {
+            Some(span) => {
+                let start = source.find(span).unwrap() as i32;
+                (start, start + span.len() as i32)
+            }
+            None => (-1, -1),
+        };
+        serde_json::from_value(serde_json::json!({
+            "sourceLocation": { "file": "src/A.sol", "start": start, "end": end },
+            "type": "Warning",
+            "component": "general",
+            "severity": "warning",
+            "errorCode": code.to_string(),
+            "message": message,
+        }))
+        .unwrap()
+    }
+
+    fn fixed(source: &str, errors: &[CompilerError]) -> String {
+        let fixes = errors.iter().filter_map(|error| solc_fix(error, source)).collect();
+        FileFixes { path: PathBuf::from("src/A.sol"), source: source.to_string(), fixes }.apply()
+    }
+
+    #[test]
+    fn fixes_missing_license_and_pragma() {
+        let source = "contract A {}\n";
+        let errors = [
+            error(1878, source, None, "SPDX license identifier not provided in source file."),
+            error(
+                3420,
+                source,
+                None,
+                "Source file does not specify required compiler version! Consider adding \"pragma solidity ^0.8.30;\"",
+            ),
+        ];
+        assert_eq!(
+            fixed(source, &errors),
+            "// SPDX-License-Identifier: UNLICENSED\npragma solidity ^0.8.30;\ncontract A {}\n"
+        );
+    }
+
+    #[test]
+    fn fixes_compiler_version_mismatch() {
+        let source = "pragma solidity 0.7.6;\ncontract A {}\n";
+        let message = "Source file requires different compiler version (current compiler is 0.8.30+commit.73712a01.Linux.g++) - note that nightly builds are considered to be strictly less than the released version";
+        let errors = [error(5333, source, Some("pragma solidity 0.7.6;"), message)];
+        assert_eq!(fixed(source, &errors), "pragma solidity ^0.8.30;\ncontract A {}\n");
+    }
+
+    #[test]
+    fn fixes_unused_variables() {
+        let source = "function f(uint256 a, uint256 b) {\n    uint256 c;\n    (uint256 d, uint256 e) = g();\n    uint256 h = g2();\n}\n";
+        let errors = [
+            error(5667, source, Some("uint256 b"), "Unused function parameter."),
+            error(2072, source, Some("uint256 c"), "Unused local variable."),
+            error(2072, source, Some("uint256 e"), "Unused local variable."),
+            error(2072, source, Some("uint256 h"), "Unused local variable."),
+        ];
+        // Keeping the value of `h` is only a suggestion.
+        assert_eq!(
+            fixed(source, &errors),
+            "function f(uint256 a, uint256 /* b */) {\n    (uint256 d, ) = g();\n    uint256 h = g2();\n}\n"
+        );
+
+        let fix = solc_fix(&errors[3], source).unwrap();
+        assert_eq!(fix.applicability, Applicability::MaybeIncorrect);
+        assert_eq!(&source[fix.range], "uint256 h = ");
+    }
+
+    #[test]
+    fn renders_patch() {
+        let source = "contract A {\n    function f(uint256 a) external {}\n}\n";
+        let fixes = vec![
+            solc_fix(&error(1878, source, None, ""), source).unwrap(),
+            solc_fix(&error(5667, source, Some("uint256 a"), ""), source).unwrap(),
+        ];
+        let file =
+            FileFixes { path: PathBuf::from("src/A.sol"), source: source.to_string(), fixes };
+        assert_eq!(
+            file.patch(Path::new("src/A.sol")),
+            "\
+--- a/src/A.sol
++++ b/src/A.sol
+@@ -1,1 +1,2 @@ add an SPDX license identifier
+-contract A {
++// SPDX-License-Identifier: UNLICENSED
++contract A {
+@@ -2,1 +3,1 @@ comment out the parameter name
+-    function f(uint256 a) external {}
++    function f(uint256 /* a */) external {}
+"
+        );
+    }
+}
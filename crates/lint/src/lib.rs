@@ -3,5 +3,11 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![allow(elided_lifetimes_in_paths)]
 
+pub mod baseline;
+pub mod cache;
+pub mod fixes;
 pub mod linter;
+pub mod plugin;
+pub mod report;
+pub mod slither;
 pub mod sol;
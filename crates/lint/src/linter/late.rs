@@ -1,7 +1,7 @@
 use solar::{interface::data_structures::Never, sema::hir};
 use std::ops::ControlFlow;
 
-use super::LintContext;
+use super::{LintContext, ProjectSymbols};
 
 /// Trait for lints that operate on the HIR (High-level Intermediate Representation).
 /// Its methods mirror `hir::visit::Visit`, with the addition of `LintContext`.
@@ -104,6 +104,26 @@ fn check_ty(
         _ty: &'hir hir::Type<'hir>,
     ) {
     }
+
+    /// Should be called after the source has been visited. Enables lints that require knowledge
+    /// of the whole project, e.g. of the contracts of other sources inheriting from the contracts
+    /// of this one.
+    ///
+    /// Since each source is linted on its own, implementations should only emit diagnostics for
+    /// the items of `source`.
+    ///
+    /// # Performance
+    ///
+    /// As with [`EarlyLintPass::check_full_source_unit`](super::EarlyLintPass), implementations
+    /// should first check that the relevant lint is enabled using
+    /// [`LintContext::is_lint_enabled`].
+    fn check_project(
+        &mut self,
+        _ctx: &LintContext,
+        _project: &ProjectSymbols<'hir>,
+        _source: hir::SourceId,
+    ) {
+    }
 }
 
 /// Visitor struct for `LateLintPass`es
@@ -124,6 +144,14 @@ pub fn new(
     ) -> Self {
         Self { ctx, passes, hir }
     }
+
+    /// Extends the [`hir::Visit`] trait functionality with a hook that can run after the
+    /// traversal of a source, with the symbols of the whole project.
+    pub fn post_source(&mut self, project: &ProjectSymbols<'hir>, source: hir::SourceId) {
+        for pass in self.passes.iter_mut() {
+            pass.check_project(self.ctx, project, source);
+        }
+    }
 }
 
 impl<'s, 'hir> hir::Visit<'hir> for LateLintVisitor<'_, 's, 'hir>
@@ -249,12 +277,16 @@ mod tests {
     use super::*;
     use crate::linter::LinterConfig;
     use foundry_common::comments::inline_config::InlineConfig;
+    use foundry_compilers::ProjectPathsConfig;
     use foundry_config::lint::LintSpecificConfig;
     use solar::{
         interface::{Session, source_map::FileName},
         sema::Compiler,
     };
-    use std::sync::{Arc, Mutex};
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
 
     #[derive(Debug, Default)]
     struct HookCounts {
@@ -337,6 +369,8 @@ fn calls_hooks_for_nested_items_modifiers_and_call_args() {
         let counts = Arc::new(Mutex::new(HookCounts::default()));
         let inline = InlineConfig::default();
         let lint_specific = LintSpecificConfig::default();
+        let severity_overrides = HashMap::new();
+        let path_config = ProjectPathsConfig::builder().build_with_root(".");
         let source = r#"
             pragma solidity ^0.8.20;
 
@@ -383,7 +417,12 @@ fn calls_hooks_for_nested_items_modifiers_and_call_args() {
                     gcx.sess,
                     false,
                     false,
-                    LinterConfig { inline: &inline, lint_specific: &lint_specific },
+                    LinterConfig {
+                        inline: &inline,
+                        lint_specific: &lint_specific,
+                        severity_overrides: &severity_overrides,
+                        path_config: &path_config,
+                    },
                     Vec::new(),
                     None,
                 );
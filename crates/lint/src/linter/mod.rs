@@ -1,18 +1,26 @@
 mod early;
 mod late;
+mod project;
 
 pub use early::{EarlyLintPass, EarlyLintVisitor};
 pub use late::{LateLintPass, LateLintVisitor};
+pub use project::ProjectSymbols;
 
+use crate::{
+    baseline::FileBaseline,
+    cache::{self, CachedLint, CachedSuggestion},
+    fixes::Fix,
+    report::{LintReport, Position, SuggestedFix},
+};
 use foundry_common::comments::inline_config::InlineConfig;
-use foundry_compilers::Language;
+use foundry_compilers::{Language, ProjectPathsConfig};
 use foundry_config::{
     DenyLevel,
     lint::{LintSpecificConfig, Severity},
 };
 use solar::{
     interface::{
-        Session, Span,
+        BytePos, Session, Span,
         diagnostics::{
             Applicability, DiagBuilder, DiagId, DiagMsg, MultiSpan, Style, SuggestionStyle,
         },
@@ -20,7 +28,12 @@
     },
     sema::Compiler,
 };
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    collections::HashMap,
+    ops::Range,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
 
 /// Trait representing a generic linter for analyzing and reporting issues in smart contract source
 /// code files.
@@ -57,11 +70,30 @@ pub struct LintContext<'s, 'c> {
     active_lints: Vec<&'static str>,
     /// The source file currently being linted, when known.
     source_file: Option<Arc<SourceFile>>,
+    /// Collects the fixes of the emitted suggestions, when set.
+    fixes: Option<&'s Mutex<Vec<Fix>>>,
+    /// Collects the reports of the emitted lints, when set.
+    reports: Option<&'s Mutex<Vec<LintReport>>>,
+    /// Collects the emitted lints to be cached, when set.
+    cache: Option<&'s Mutex<Vec<CachedLint>>>,
+    /// The baselined lints, which are not reported, when set.
+    baseline: Option<&'s FileBaseline>,
 }
 
 pub struct LinterConfig<'s> {
     pub inline: &'s InlineConfig<Vec<String>>,
     pub lint_specific: &'s LintSpecificConfig,
+    /// The overridden severities, by lint id.
+    pub severity_overrides: &'s HashMap<&'static str, Severity>,
+    /// The project paths, to scope lints to sources, tests or scripts.
+    pub path_config: &'s ProjectPathsConfig,
+}
+
+impl LinterConfig<'_> {
+    /// Returns the severity of the lint, accounting for the overrides.
+    pub fn severity<L: Lint>(&self, lint: &L) -> Severity {
+        self.severity_overrides.get(lint.id()).copied().unwrap_or_else(|| lint.severity())
+    }
 }
 
 impl<'s, 'c> LintContext<'s, 'c> {
@@ -73,7 +105,55 @@ pub const fn new(
         active_lints: Vec<&'static str>,
         source_file: Option<Arc<SourceFile>>,
     ) -> Self {
-        Self { sess, with_description, with_json_emitter, config, active_lints, source_file }
+        Self {
+            sess,
+            with_description,
+            with_json_emitter,
+            config,
+            active_lints,
+            source_file,
+            fixes: None,
+            reports: None,
+            cache: None,
+            baseline: None,
+        }
+    }
+
+    /// Collects the fixes of the suggestions emitted in the source file into `fixes`.
+    ///
+    /// Requires the source file to be known.
+    pub fn with_fixes(mut self, fixes: &'s Mutex<Vec<Fix>>) -> Self {
+        self.fixes = Some(fixes);
+        self
+    }
+
+    /// Collects the reports of the lints emitted in the source file into `reports`.
+    ///
+    /// Requires the source file to be known.
+    pub fn with_reports(mut self, reports: &'s Mutex<Vec<LintReport>>) -> Self {
+        self.reports = Some(reports);
+        self
+    }
+
+    /// Collects the lints emitted in the source file into `cache`, to be replayed by the next
+    /// runs with [`Self::replay`].
+    ///
+    /// Requires the source file to be known.
+    pub fn with_cache(mut self, cache: &'s Mutex<Vec<CachedLint>>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Does not report the lints of `baseline`.
+    pub fn with_baseline(mut self, baseline: &'s FileBaseline) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
+    /// Returns `true` if the lint emitted at `span` is in the baseline, consuming its entry.
+    fn is_baselined<L: Lint>(&self, lint: &'static L, span: Span) -> bool {
+        let Some(baseline) = self.baseline else { return false };
+        self.span_to_snippet(span).is_some_and(|code| baseline.consume(lint.id(), &code))
     }
 
     fn add_help<'a>(&self, diag: DiagBuilder<'a, ()>, help: &'static str) -> DiagBuilder<'a, ()> {
@@ -103,18 +183,23 @@ pub fn emit<L: Lint>(&self, lint: &'static L, span: Span) {
         if self.config.inline.is_id_disabled(span, lint.id()) || !self.is_lint_enabled(lint.id()) {
             return;
         }
+        self.cache_lint(lint, span, None, None);
+        if self.is_baselined(lint, span) {
+            return;
+        }
 
         let desc = if self.with_description { lint.description() } else { "" };
         let mut diag: DiagBuilder<'_, ()> = self
             .sess
             .dcx
-            .diag(lint.severity().into(), desc)
+            .diag(self.config.severity(lint).into(), desc)
             .code(DiagId::new_str(lint.id()))
             .span(MultiSpan::from_span(span));
 
         diag = self.add_help(diag, lint.help());
 
         diag.emit();
+        self.collect_report(lint, span, lint.description(), None);
     }
 
     /// Emit a diagnostic with a caller-provided message instead of the lint's description.
@@ -126,10 +211,17 @@ pub fn emit_with_msg<L: Lint>(&self, lint: &'static L, span: Span, msg: impl Int
             return;
         }
 
+        let msg = msg.into();
+        self.cache_lint(lint, span, Some(msg.as_str()), None);
+        if self.is_baselined(lint, span) {
+            return;
+        }
+        self.collect_report(lint, span, msg.as_str(), None);
+
         let diag: DiagBuilder<'_, ()> = self
             .sess
             .dcx
-            .diag(lint.severity().into(), msg.into())
+            .diag(self.config.severity(lint).into(), msg)
             .code(DiagId::new_str(lint.id()))
             .span(MultiSpan::from_span(span));
 
@@ -138,7 +230,9 @@ pub fn emit_with_msg<L: Lint>(&self, lint: &'static L, span: Span, msg: impl Int
 
     /// Emit a diagnostic with a code suggestion.
     ///
-    /// If no span is provided for [`SuggestionKind::Fix`], it will use the lint's span.
+    /// If no span is provided for [`SuggestionKind::Fix`], it will use the lint's span. Fixes are
+    /// also collected for `forge lint --fix`, which applies the
+    /// [`Applicability::MachineApplicable`] ones.
     pub fn emit_with_suggestion<L: Lint>(
         &self,
         lint: &'static L,
@@ -149,23 +243,44 @@ pub fn emit_with_suggestion<L: Lint>(
             return;
         }
 
+        self.cache_lint(lint, span, None, Some(&suggestion));
+        if self.is_baselined(lint, span) {
+            return;
+        }
+
         let desc = if self.with_description { lint.description() } else { "" };
         let mut diag: DiagBuilder<'_, ()> = self
             .sess
             .dcx
-            .diag(lint.severity().into(), desc)
+            .diag(self.config.severity(lint).into(), desc)
             .code(DiagId::new_str(lint.id()))
             .span(MultiSpan::from_span(span));
 
+        let mut fix = None;
         diag = match suggestion.kind {
-            SuggestionKind::Fix { span: fix_span, applicability, style } => diag
-                .span_suggestion_with_style(
-                    fix_span.unwrap_or(span),
+            SuggestionKind::Fix { span: fix_span, applicability, style } => {
+                let fix_span = fix_span.unwrap_or(span);
+                fix = self.file_range(fix_span).map(|range| SuggestedFix {
+                    desc: suggestion.desc,
+                    range,
+                    replacement: suggestion.content.clone(),
+                    applicability,
+                });
+                self.collect_fix(
+                    lint,
+                    fix_span,
+                    suggestion.desc,
+                    &suggestion.content,
+                    applicability,
+                );
+                diag.span_suggestion_with_style(
+                    fix_span,
                     suggestion.desc.unwrap_or_default(),
                     suggestion.content,
                     applicability,
                     style,
-                ),
+                )
+            }
             SuggestionKind::Example => {
                 if let Some(note) = suggestion.to_note() {
                     diag.note(note.iter().map(|l| l.0.as_str()).collect::<String>())
@@ -178,6 +293,141 @@ pub fn emit_with_suggestion<L: Lint>(
         diag = self.add_help(diag, lint.help());
 
         diag.emit();
+        self.collect_report(lint, span, lint.description(), fix);
+    }
+
+    /// Emits a lint cached by a previous run on the same source file.
+    pub fn replay<L: Lint>(&self, lint: &'static L, cached: &CachedLint) {
+        let Some(span) = self.file_span(cached.range.clone()) else { return };
+        let Some(suggestion) = &cached.suggestion else {
+            match &cached.message {
+                Some(msg) => self.emit_with_msg(lint, span, msg.clone()),
+                None => self.emit(lint, span),
+            }
+            return;
+        };
+
+        let mut replayed = match suggestion {
+            CachedSuggestion::Example { content, .. } => Suggestion::example(content.clone()),
+            CachedSuggestion::Fix { content, range, applicability, style, .. } => {
+                let Some(fix_span) = self.file_span(range.clone()) else { return };
+                Suggestion::fix(content.clone(), cache::applicability_from_str(applicability))
+                    .with_span(fix_span)
+                    .with_style(cache::style_from_str(style))
+            }
+        };
+        if let Some(desc) = suggestion.desc() {
+            replayed = replayed.with_desc(desc);
+        }
+        self.emit_with_suggestion(lint, span, replayed);
+    }
+
+    /// Returns the span of the byte `range` of the source file, if known.
+    fn file_span(&self, range: Range<usize>) -> Option<Span> {
+        let file = self.source_file.as_ref()?;
+        if range.end > file.src.len() {
+            return None;
+        }
+        Some(Span::new(
+            file.start_pos + BytePos::from_usize(range.start),
+            file.start_pos + BytePos::from_usize(range.end),
+        ))
+    }
+
+    /// Returns the byte range of `span` in the source file, if known.
+    fn file_range(&self, span: Span) -> Option<Range<usize>> {
+        let file = self.source_file.as_ref()?;
+        let start = file.start_pos.to_usize();
+        let (lo, hi) = (span.lo().to_usize(), span.hi().to_usize());
+        (lo >= start && hi <= start + file.src.len()).then(|| lo - start..hi - start)
+    }
+
+    /// Collects the report of the lint emitted at `span`, if reports are collected.
+    fn collect_report<L: Lint>(
+        &self,
+        lint: &'static L,
+        span: Span,
+        message: &str,
+        fix: Option<SuggestedFix>,
+    ) {
+        let (Some(reports), Some(range)) = (self.reports, self.file_range(span)) else { return };
+        let position = |pos| {
+            let loc = self.sess.source_map().lookup_char_pos(pos);
+            Position { line: loc.line, column: loc.col.to_usize() + 1 }
+        };
+        let report = LintReport {
+            id: lint.id(),
+            severity: self.config.severity(lint),
+            message: message.to_string(),
+            help: lint.help(),
+            range,
+            start: position(span.lo()),
+            end: position(span.hi()),
+            fix,
+        };
+        reports.lock().unwrap().push(report);
+    }
+
+    /// Collects the lint emitted at `span` to be cached, if cached lints are collected.
+    fn cache_lint<L: Lint>(
+        &self,
+        lint: &'static L,
+        span: Span,
+        message: Option<&str>,
+        suggestion: Option<&Suggestion>,
+    ) {
+        let (Some(cache), Some(range)) = (self.cache, self.file_range(span)) else { return };
+        let suggestion = match suggestion {
+            None => None,
+            Some(Suggestion { desc, content, kind: SuggestionKind::Example }) => {
+                Some(CachedSuggestion::Example {
+                    desc: desc.map(str::to_string),
+                    content: content.clone(),
+                })
+            }
+            Some(Suggestion {
+                desc,
+                content,
+                kind: SuggestionKind::Fix { span: fix_span, applicability, style },
+            }) => {
+                // The fix cannot be replayed outside of the source file.
+                let Some(fix_range) = self.file_range(fix_span.unwrap_or(span)) else { return };
+                Some(CachedSuggestion::Fix {
+                    desc: desc.map(str::to_string),
+                    content: content.clone(),
+                    range: fix_range,
+                    applicability: cache::applicability_to_str(*applicability),
+                    style: cache::style_to_str(*style),
+                })
+            }
+        };
+        let cached = CachedLint {
+            id: lint.id().to_string(),
+            message: message.map(str::to_string),
+            range,
+            suggestion,
+        };
+        cache.lock().unwrap().push(cached);
+    }
+
+    /// Collects the fix replacing `span` with `replacement`, if fixes are collected.
+    fn collect_fix<L: Lint>(
+        &self,
+        lint: &'static L,
+        span: Span,
+        desc: Option<&'static str>,
+        replacement: &str,
+        applicability: Applicability,
+    ) {
+        let (Some(fixes), Some(range)) = (self.fixes, self.file_range(span)) else { return };
+        let fix = Fix::lint(
+            lint.id(),
+            desc.unwrap_or(lint.description()),
+            range,
+            replacement,
+            applicability,
+        );
+        fixes.lock().unwrap().push(fix);
     }
 
     /// Gets the "raw" source code (snippet) of the given span.
@@ -214,7 +464,7 @@ pub enum SuggestionKind {
     /// to be removed (from `span`) and the code to be added (from `add`).
     Fix {
         /// The `Span` of the source code to be removed. Note that, if uninformed,
-        /// `fn emit_with_fix()` falls back to the lint span.
+        /// `fn emit_with_suggestion()` falls back to the lint span.
         span: Option<Span>,
         /// The applicability of the suggested fix.
         applicability: Applicability,
@@ -0,0 +1,64 @@
+use solar::{interface::Symbol, sema::hir};
+use std::collections::HashMap;
+
+/// A project-wide symbol table, built once from the HIR of all the sources of a lint run.
+///
+/// Used by the [`LateLintPass::check_project`](super::LateLintPass::check_project) hook for the
+/// lints which need to look beyond the linted source, e.g. at the contracts deriving from a
+/// contract of another file.
+pub struct ProjectSymbols<'hir> {
+    hir: &'hir hir::Hir<'hir>,
+    /// The contracts, interfaces and libraries by name.
+    contracts_by_name: HashMap<Symbol, Vec<hir::ContractId>>,
+    /// The contracts inheriting from each contract, directly or not.
+    derived: HashMap<hir::ContractId, Vec<hir::ContractId>>,
+}
+
+impl<'hir> ProjectSymbols<'hir> {
+    pub fn new(hir: &'hir hir::Hir<'hir>) -> Self {
+        let mut contracts_by_name = HashMap::<_, Vec<_>>::new();
+        let mut derived = HashMap::<_, Vec<_>>::new();
+        for (id, contract) in hir.contracts_enumerated() {
+            contracts_by_name.entry(contract.name.name).or_default().push(id);
+            // The first base is the contract itself.
+            for &base in contract.linearized_bases.iter().skip(1) {
+                derived.entry(base).or_default().push(id);
+            }
+        }
+        Self { hir, contracts_by_name, derived }
+    }
+
+    /// Returns the HIR of the project.
+    pub const fn hir(&self) -> &'hir hir::Hir<'hir> {
+        self.hir
+    }
+
+    /// Returns the contracts declared in the given source.
+    pub fn source_contracts(
+        &self,
+        source: hir::SourceId,
+    ) -> impl Iterator<Item = (hir::ContractId, &'hir hir::Contract<'hir>)> + '_ {
+        self.hir.contracts_enumerated().filter(move |(_, contract)| contract.source == source)
+    }
+
+    /// Returns the contracts, interfaces and libraries with the given name, in any source.
+    pub fn contracts_named(&self, name: Symbol) -> &[hir::ContractId] {
+        self.contracts_by_name.get(&name).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the bases of a contract, from the most base-like to the most derived, excluding the
+    /// contract itself.
+    pub fn bases(&self, id: hir::ContractId) -> impl DoubleEndedIterator<Item = hir::ContractId> {
+        self.hir.contract(id).linearized_bases.iter().skip(1).rev().copied()
+    }
+
+    /// Returns the contracts of the project inheriting from a contract, directly or not.
+    pub fn derived(&self, id: hir::ContractId) -> &[hir::ContractId] {
+        self.derived.get(&id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns `true` if no contract of the project inherits from the contract.
+    pub fn is_most_derived(&self, id: hir::ContractId) -> bool {
+        self.derived(id).is_empty()
+    }
+}
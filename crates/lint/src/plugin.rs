@@ -0,0 +1,275 @@
+//! Lints defined outside of forge-lint, e.g. the protocol-specific rules of a project.
+//!
+//! A plugin is a dynamic library exporting a [`LintPlugin`] with [`declare_lint_plugin!`]:
+//!
+//! ```ignore
+//! use forge_lint::{
+//!     linter::{EarlyLintPass, LintContext},
+//!     plugin::LintPlugin,
+//!     sol::{Severity, SolLint},
+//! };
+//!
+//! static NO_SELFDESTRUCT: SolLint = SolLint::new(
+//!     "no-selfdestruct",
+//!     Severity::High,
+//!     "`selfdestruct` must not be used in the protocol contracts",
+//!     "https://example.com/lints#no-selfdestruct",
+//! );
+//!
+//! #[derive(Default)]
+//! struct ProtocolLints;
+//!
+//! impl LintPlugin for ProtocolLints {
+//!     fn name(&self) -> &str {
+//!         "protocol-lints"
+//!     }
+//!
+//!     fn lints(&self) -> &'static [SolLint] {
+//!         std::slice::from_ref(&NO_SELFDESTRUCT)
+//!     }
+//!
+//!     fn early_passes<'ast>(&self) -> Vec<Box<dyn EarlyLintPass<'ast>>> {
+//!         vec![Box::new(NoSelfdestruct)]
+//!     }
+//! }
+//!
+//! forge_lint::declare_lint_plugin!(ProtocolLints);
+//! ```
+//!
+//! Plugins are built as a `cdylib` against the same version of forge-lint, and with the same Rust
+//! toolchain, as the forge binary loading them, since trait objects have no stable ABI: both
+//! versions are checked when loading a plugin. Plugins run native code, so none are loaded unless
+//! their paths are listed in the `lint.plugins` config, which is empty by default.
+
+use crate::{
+    linter::{EarlyLintPass, LateLintPass},
+    sol::SolLint,
+};
+use eyre::{Context, Result};
+use std::{
+    path::{Path, PathBuf},
+    sync::{LazyLock, RwLock},
+};
+
+/// The version of the plugin interface, which must match the one a plugin was built with: the
+/// version of forge-lint, and the version of the compiler.
+pub const PLUGIN_VERSION: &str =
+    concat!(env!("CARGO_PKG_VERSION"), " (", env!("FORGE_LINT_RUSTC_VERSION"), ")");
+
+/// The name of the symbol exporting the version a plugin was built with.
+const VERSION_SYMBOL: &[u8] = b"FORGE_LINT_PLUGIN_VERSION\0";
+
+/// The name of the symbol exporting the constructor of a plugin.
+const CONSTRUCTOR_SYMBOL: &[u8] = b"forge_lint_plugin\0";
+
+/// The registered plugins.
+static PLUGINS: LazyLock<RwLock<Vec<&'static dyn LintPlugin>>> = LazyLock::new(Default::default);
+
+/// A set of lints, with the passes emitting them.
+///
+/// The passes are created for each linted source, and only if at least one of the lints of the
+/// plugin is included by the linter config. Passes must check that a lint is enabled with
+/// [`LintContext::is_lint_enabled`](crate::linter::LintContext::is_lint_enabled) before doing any
+/// expensive work, as for the built-in lints.
+pub trait LintPlugin: Send + Sync {
+    /// Returns the name of the plugin.
+    fn name(&self) -> &str;
+
+    /// Returns the lints emitted by the passes of the plugin.
+    fn lints(&self) -> &'static [SolLint];
+
+    /// Creates the passes run on the AST of a source.
+    fn early_passes<'ast>(&self) -> Vec<Box<dyn EarlyLintPass<'ast>>> {
+        Vec::new()
+    }
+
+    /// Creates the passes run on the HIR of a source.
+    fn late_passes<'hir>(&self) -> Vec<Box<dyn LateLintPass<'hir>>> {
+        Vec::new()
+    }
+}
+
+/// Exports a [`LintPlugin`], implementing [`Default`], from a plugin library.
+#[macro_export]
+macro_rules! declare_lint_plugin {
+    ($plugin:ty) => {
+        #[unsafe(no_mangle)]
+        pub static FORGE_LINT_PLUGIN_VERSION: &str = $crate::plugin::PLUGIN_VERSION;
+
+        #[unsafe(no_mangle)]
+        pub fn forge_lint_plugin() -> Box<dyn $crate::plugin::LintPlugin> {
+            Box::new(<$plugin as Default>::default())
+        }
+    };
+}
+
+/// Registers a plugin, unless a plugin with the same name is already registered.
+///
+/// Returns `false` if the plugin was already registered.
+pub fn register(plugin: Box<dyn LintPlugin>) -> bool {
+    let mut plugins = PLUGINS.write().unwrap();
+    if plugins.iter().any(|registered| registered.name() == plugin.name()) {
+        return false;
+    }
+    plugins.push(Box::leak(plugin));
+    true
+}
+
+/// Returns the registered plugins.
+pub fn plugins() -> Vec<&'static dyn LintPlugin> {
+    PLUGINS.read().unwrap().clone()
+}
+
+/// Loads and registers the plugins at the given paths, relative to the project root.
+///
+/// Each path is either a plugin library or a directory, whose libraries are all loaded. Missing
+/// directories are skipped.
+pub fn load(root: &Path, paths: &[PathBuf]) -> Result<()> {
+    for path in paths {
+        let path = root.join(path);
+        if path.is_dir() {
+            let mut libraries = std::fs::read_dir(&path)?
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<Result<Vec<_>, _>>()?;
+            libraries.retain(|library| {
+                library.extension().is_some_and(|ext| ext == std::env::consts::DLL_EXTENSION)
+            });
+            libraries.sort();
+            for library in libraries {
+                load_library(&library)?;
+            }
+        } else if path.exists() {
+            load_library(&path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Loads and registers the plugin exported by a library.
+fn load_library(path: &Path) -> Result<()> {
+    let err = || format!("failed to load lint plugin {}", path.display());
+    // SAFETY: plugins are only loaded when listed in the config, like FFI calls are only allowed
+    // when enabled.
+    let library = unsafe { libloading::Library::new(path) }.wrap_err_with(err)?;
+    // SAFETY: the symbols are exported with these types by `declare_lint_plugin!`.
+    let plugin = unsafe {
+        let version = library.get::<*const &str>(VERSION_SYMBOL).wrap_err_with(err)?;
+        let version = **version;
+        if version != PLUGIN_VERSION {
+            eyre::bail!(
+                "lint plugin {} was built for forge-lint {version}, expected {PLUGIN_VERSION}; \
+                 rebuild it with the same forge-lint version and Rust toolchain",
+                path.display()
+            );
+        }
+        let constructor =
+            library.get::<fn() -> Box<dyn LintPlugin>>(CONSTRUCTOR_SYMBOL).wrap_err_with(err)?;
+        constructor()
+    };
+    // The lints and passes of the plugin live as long as the program.
+    std::mem::forget(library);
+    register(plugin);
+    Ok(())
+}
+
+/// Creates the early passes of the plugins with at least one lint included by the config, along
+/// with the IDs of their included lints.
+pub(crate) fn create_early_lint_passes<'ast>(
+    include: &dyn Fn(&SolLint) -> bool,
+) -> Vec<(Box<dyn EarlyLintPass<'ast>>, Vec<&'static str>)> {
+    plugins()
+        .into_iter()
+        .flat_map(|plugin| {
+            let lints = included(plugin, include);
+            let passes = if lints.is_empty() { Vec::new() } else { plugin.early_passes() };
+            passes.into_iter().map(move |pass| (pass, lints.clone()))
+        })
+        .collect()
+}
+
+/// Creates the late passes of the plugins with at least one lint included by the config, along
+/// with the IDs of their included lints.
+pub(crate) fn create_late_lint_passes<'hir>(
+    include: &dyn Fn(&SolLint) -> bool,
+) -> Vec<(Box<dyn LateLintPass<'hir>>, Vec<&'static str>)> {
+    plugins()
+        .into_iter()
+        .flat_map(|plugin| {
+            let lints = included(plugin, include);
+            let passes = if lints.is_empty() { Vec::new() } else { plugin.late_passes() };
+            passes.into_iter().map(move |pass| (pass, lints.clone()))
+        })
+        .collect()
+}
+
+fn included(plugin: &dyn LintPlugin, include: &dyn Fn(&SolLint) -> bool) -> Vec<&'static str> {
+    plugin.lints().iter().filter(|&lint| include(lint)).map(|lint| lint.id).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        linter::{EarlyLintPass, Lint, LintContext},
+        sol::Severity,
+    };
+    use solar::ast;
+
+    static NO_EMPTY_CONTRACT: SolLint = SolLint::new(
+        "no-empty-contract",
+        Severity::Low,
+        "contracts must not be empty",
+        "https://example.com/lints#no-empty-contract",
+    );
+
+    struct NoEmptyContract;
+
+    impl<'ast> EarlyLintPass<'ast> for NoEmptyContract {
+        fn check_item_contract(
+            &mut self,
+            ctx: &LintContext<'ast, '_>,
+            contract: &'ast ast::ItemContract<'ast>,
+        ) {
+            if contract.body.is_empty() {
+                ctx.emit(&NO_EMPTY_CONTRACT, contract.name.span);
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct TestPlugin;
+
+    impl LintPlugin for TestPlugin {
+        fn name(&self) -> &str {
+            "test-plugin"
+        }
+
+        fn lints(&self) -> &'static [SolLint] {
+            std::slice::from_ref(&NO_EMPTY_CONTRACT)
+        }
+
+        fn early_passes<'ast>(&self) -> Vec<Box<dyn EarlyLintPass<'ast>>> {
+            vec![Box::new(NoEmptyContract)]
+        }
+    }
+
+    #[test]
+    fn registers_plugin_lints() {
+        assert!(register(Box::new(TestPlugin)));
+        assert!(!register(Box::new(TestPlugin)));
+
+        assert_eq!(SolLint::try_from("no-empty-contract").unwrap(), NO_EMPTY_CONTRACT);
+        assert!(crate::sol::registered_lints().any(|lint| lint == NO_EMPTY_CONTRACT));
+
+        let passes = create_early_lint_passes(&|lint| lint.severity() == Severity::Low);
+        assert!(passes.iter().any(|(_, lints)| lints == &["no-empty-contract"]));
+        let passes = create_early_lint_passes(&|lint| lint.severity() == Severity::High);
+        assert!(passes.iter().all(|(_, lints)| !lints.contains(&"no-empty-contract")));
+    }
+
+    #[test]
+    fn skips_missing_plugin_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        load(dir.path(), &["lints".into()]).unwrap();
+    }
+}
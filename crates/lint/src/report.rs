@@ -0,0 +1,71 @@
+//! Structured reports of the emitted lints, for machine-readable output formats.
+//!
+//! Every lint emitted with a [`LintContext`](crate::linter::LintContext) is also reported, with its
+//! location and suggested fix, when reports are collected by
+//! [`SolidityLinter::with_reports`](crate::sol::SolidityLinter::with_reports).
+
+use foundry_config::lint::Severity;
+use solar::interface::diagnostics::Applicability;
+use std::{ops::Range, path::PathBuf};
+
+/// A position in a source file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Position {
+    /// The line, starting at 1.
+    pub line: usize,
+    /// The column in characters, starting at 1.
+    pub column: usize,
+}
+
+/// The fix suggested by a lint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SuggestedFix {
+    /// A short description of the fix, if any.
+    pub desc: Option<&'static str>,
+    /// The byte range of the source to replace.
+    pub range: Range<usize>,
+    /// The replacement of `range`.
+    pub replacement: String,
+    /// Whether the fix can be applied without review.
+    pub applicability: Applicability,
+}
+
+/// An emitted lint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintReport {
+    /// The lint id.
+    pub id: &'static str,
+    /// The lint severity.
+    pub severity: Severity,
+    /// The message of the diagnostic.
+    pub message: String,
+    /// The link to the lint documentation.
+    pub help: &'static str,
+    /// The byte range of the linted code.
+    pub range: Range<usize>,
+    /// The start of the linted code.
+    pub start: Position,
+    /// The end of the linted code.
+    pub end: Position,
+    /// The suggested fix, if any.
+    pub fix: Option<SuggestedFix>,
+}
+
+/// The lints emitted in a source file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileReport {
+    /// The path of the file.
+    pub path: PathBuf,
+    /// The emitted lints, in source order.
+    pub lints: Vec<LintReport>,
+}
+
+impl FileReport {
+    /// Creates the report of the file at `path`, sorting the lints in source order.
+    pub fn new(path: PathBuf, mut lints: Vec<LintReport>) -> Self {
+        lints.sort_by(|a, b| {
+            (a.range.start, a.range.end, a.id).cmp(&(b.range.start, b.range.end, b.id))
+        });
+        Self { path, lints }
+    }
+}
@@ -0,0 +1,254 @@
+//! Correlation of the emitted lints with the findings of [Slither](https://github.com/crytic/slither).
+//!
+//! Findings are read from the JSON output of `slither . --json <PATH>`, and matched with the lints
+//! of an equivalent rule emitted in the same file, within the lines of one of the finding's
+//! elements.
+
+use crate::report::{FileReport, LintReport};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// The JSON output of Slither.
+#[derive(Debug, Deserialize)]
+struct SlitherOutput {
+    #[serde(default)]
+    error: Option<String>,
+    #[serde(default)]
+    results: Option<SlitherResults>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SlitherResults {
+    #[serde(default)]
+    detectors: Vec<SlitherFinding>,
+}
+
+/// A finding of a Slither detector.
+#[derive(Clone, Debug, Deserialize)]
+pub struct SlitherFinding {
+    /// The detector, e.g. `reentrancy-eth`.
+    pub check: String,
+    /// The impact of the finding, e.g. `High`.
+    #[serde(default)]
+    pub impact: String,
+    /// The description of the finding.
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    elements: Vec<SlitherElement>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct SlitherElement {
+    #[serde(default)]
+    source_mapping: Option<SourceMapping>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct SourceMapping {
+    #[serde(default)]
+    filename_relative: Option<PathBuf>,
+    #[serde(default)]
+    lines: Vec<usize>,
+}
+
+impl SlitherFinding {
+    /// Returns the file of the finding, relative to the project root, and its first line.
+    pub fn location(&self) -> Option<(&Path, usize)> {
+        self.mappings().find_map(|(path, lines)| Some((path, *lines.first()?)))
+    }
+
+    /// Returns the first line of the description.
+    pub fn summary(&self) -> &str {
+        self.description.lines().next().unwrap_or_default().trim()
+    }
+
+    /// Returns the files and lines of the elements of the finding.
+    fn mappings(&self) -> impl Iterator<Item = (&Path, &[usize])> {
+        self.elements.iter().filter_map(|element| {
+            let mapping = element.source_mapping.as_ref()?;
+            Some((mapping.filename_relative.as_deref()?, mapping.lines.as_slice()))
+        })
+    }
+
+    /// Returns `true` if the lint emitted at line `line` of `path` is located in the finding.
+    fn contains(&self, path: &Path, line: usize) -> bool {
+        self.mappings().any(|(file, lines)| {
+            file == path
+                && lines
+                    .first()
+                    .zip(lines.last())
+                    .is_some_and(|(&lo, &hi)| (lo..=hi).contains(&line))
+        })
+    }
+}
+
+/// Reads the findings of a Slither JSON output.
+pub fn read_findings(path: &Path) -> eyre::Result<Vec<SlitherFinding>> {
+    let output: SlitherOutput = foundry_common::fs::read_json_file(path)?;
+    if let Some(error) = output.error {
+        eyre::bail!("Slither failed: {error}");
+    }
+    Ok(output.results.map(|results| results.detectors).unwrap_or_default())
+}
+
+/// Returns the lints equivalent to a Slither detector.
+pub fn equivalent_lints(check: &str) -> &'static [&'static str] {
+    match check {
+        "incorrect-shift" => &["incorrect-shift"],
+        "unchecked-lowlevel" | "unchecked-send" => &["unchecked-call"],
+        "unchecked-transfer" => &["erc20-unchecked-transfer"],
+        "rtlo" => &["rtlo"],
+        "reentrancy-eth" | "reentrancy-no-eth" | "reentrancy-benign" => &["reentrancy"],
+        "divide-before-multiply" => &["divide-before-multiply"],
+        "incorrect-equality" => &["block-strict-equality"],
+        "timestamp" => &["block-timestamp", "block-strict-equality"],
+        "tx-origin" => &["tx-origin"],
+        "erc20-interface" => &["incorrect-erc20-interface"],
+        "erc721-interface" => &["incorrect-erc721-interface"],
+        "unused-return" => &["unused-return"],
+        "missing-zero-check" => &["missing-zero-check"],
+        "boolean-cst" => &["boolean-cst"],
+        "boolean-equal" => &["boolean-equal"],
+        "naming-convention" => &[
+            "mixed-case-function",
+            "mixed-case-variable",
+            "pascal-case-struct",
+            "screaming-snake-case-const",
+            "screaming-snake-case-immutable",
+        ],
+        "dead-code" => &["dead-code"],
+        "unused-state" => &["unused-state-variables"],
+        "immutable-states" => &["could-be-immutable"],
+        "cache-array-length" => &["cache-array-length"],
+        "unused-import" => &["unused-import"],
+        _ => &[],
+    }
+}
+
+/// A lint emitted in a file, relative to the project root.
+#[derive(Clone, Debug)]
+pub struct FileLint {
+    /// The path of the file, relative to the project root.
+    pub path: PathBuf,
+    /// The emitted lint.
+    pub lint: LintReport,
+}
+
+/// The lints and Slither findings, correlated.
+#[derive(Debug, Default)]
+pub struct Correlation {
+    /// The findings of both tools.
+    pub matched: Vec<(FileLint, SlitherFinding)>,
+    /// The lints without an equivalent Slither finding.
+    pub forge_only: Vec<FileLint>,
+    /// The Slither findings without an equivalent lint.
+    pub slither_only: Vec<SlitherFinding>,
+}
+
+/// Correlates the lints reported for the files of the project at `root` with Slither findings.
+///
+/// Each finding is matched with at most one lint.
+pub fn correlate(
+    root: &Path,
+    reports: &[FileReport],
+    findings: Vec<SlitherFinding>,
+) -> Correlation {
+    let mut lints = reports
+        .iter()
+        .flat_map(|file| {
+            let path = file.path.strip_prefix(root).unwrap_or(&file.path);
+            file.lints.iter().map(|lint| FileLint { path: path.to_path_buf(), lint: lint.clone() })
+        })
+        .map(Some)
+        .collect::<Vec<_>>();
+
+    let mut correlation = Correlation::default();
+    for finding in findings {
+        let ids = equivalent_lints(&finding.check);
+        let matched = lints.iter_mut().find(|lint| {
+            lint.as_ref().is_some_and(|lint| {
+                ids.contains(&lint.lint.id) && finding.contains(&lint.path, lint.lint.start.line)
+            })
+        });
+        match matched.and_then(Option::take) {
+            Some(lint) => correlation.matched.push((lint, finding)),
+            None => correlation.slither_only.push(finding),
+        }
+    }
+    correlation.forge_only = lints.into_iter().flatten().collect();
+    correlation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::Position;
+    use foundry_config::lint::Severity;
+
+    fn lint(id: &'static str, line: usize) -> LintReport {
+        LintReport {
+            id,
+            severity: Severity::High,
+            message: String::new(),
+            help: "",
+            range: 0..0,
+            start: Position { line, column: 1 },
+            end: Position { line, column: 2 },
+            fix: None,
+        }
+    }
+
+    #[test]
+    fn correlates_findings_with_lints() {
+        let output = serde_json::json!({
+            "success": true,
+            "error": null,
+            "results": {
+                "detectors": [
+                    {
+                        "check": "reentrancy-eth",
+                        "impact": "High",
+                        "description": "Reentrancy in Vault.withdraw() (src/Vault.sol#10-15):\n",
+                        "elements": [{
+                            "type": "function",
+                            "source_mapping": {
+                                "filename_relative": "src/Vault.sol",
+                                "lines": [10, 11, 12, 13, 14, 15],
+                            },
+                        }],
+                    },
+                    {
+                        "check": "arbitrary-send-eth",
+                        "impact": "High",
+                        "description": "Vault.sweep() sends eth to arbitrary user\n",
+                        "elements": [{
+                            "source_mapping": { "filename_relative": "src/Vault.sol", "lines": [20] },
+                        }],
+                    },
+                ],
+            },
+        });
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("slither.json");
+        std::fs::write(&path, output.to_string()).unwrap();
+        let findings = read_findings(&path).unwrap();
+
+        let root = Path::new("/project");
+        let reports = [FileReport::new(
+            root.join("src/Vault.sol"),
+            vec![lint("reentrancy", 13), lint("incorrect-shift", 30)],
+        )];
+        let correlation = correlate(root, &reports, findings);
+
+        let [(lint, finding)] = correlation.matched.as_slice() else { panic!("{correlation:?}") };
+        assert_eq!((lint.lint.id, finding.check.as_str()), ("reentrancy", "reentrancy-eth"));
+        assert_eq!(lint.path, Path::new("src/Vault.sol"));
+        let [lint] = correlation.forge_only.as_slice() else { panic!("{correlation:?}") };
+        assert_eq!(lint.lint.id, "incorrect-shift");
+        let [finding] = correlation.slither_only.as_slice() else { panic!("{correlation:?}") };
+        assert_eq!(finding.check, "arbitrary-send-eth");
+        assert_eq!(finding.location(), Some((Path::new("src/Vault.sol"), 20)));
+        assert_eq!(finding.summary(), "Vault.sweep() sends eth to arbitrary user");
+    }
+}
@@ -12,7 +12,8 @@
     UNWRAPPED_MODIFIER_LOGIC,
     Severity::CodeSize,
     "unwrapped-modifier-logic",
-    "wrap modifier logic to reduce code size"
+    "wrap modifier logic to reduce code size",
+    examples: ["modifier onlyOwner() { require(msg.sender == owner, \"not owner\"); _; }"]
 );
 
 impl<'hir> LateLintPass<'hir> for UnwrappedModifierLogic {
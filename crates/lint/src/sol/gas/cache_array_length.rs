@@ -0,0 +1,159 @@
+use super::CacheArrayLength;
+use crate::{
+    linter::{EarlyLintPass, LintContext},
+    sol::{Severity, SolLint},
+};
+use solar::{
+    ast::{self as ast, Expr, ExprKind, ItemKind, StmtKind, Symbol, visit::Visit},
+    interface::{SpannedOption, data_structures::Never},
+};
+use std::{collections::HashSet, ops::ControlFlow};
+
+declare_forge_lint!(
+    CACHE_ARRAY_LENGTH,
+    Severity::Gas,
+    "cache-array-length",
+    "cache the length of the storage array outside of the loop",
+    examples: ["for (uint256 i; i < items.length; ++i) { total += items[i]; }"]
+);
+
+impl<'ast> EarlyLintPass<'ast> for CacheArrayLength {
+    fn check_item_contract(&mut self, ctx: &LintContext, contract: &'ast ast::ItemContract<'ast>) {
+        let arrays: HashSet<Symbol> = contract
+            .body
+            .iter()
+            .filter_map(|item| match &item.kind {
+                ItemKind::Variable(var)
+                    if var.mutability.is_none()
+                        && matches!(var.ty.kind, ast::TypeKind::Array(_)) =>
+                {
+                    var.name.map(|name| name.name)
+                }
+                _ => None,
+            })
+            .collect();
+        if arrays.is_empty() {
+            return;
+        }
+
+        for item in contract.body.iter() {
+            let ItemKind::Function(func) = &item.kind else { continue };
+            let Some(body) = &func.body else { continue };
+
+            // Skip the arrays shadowed by a parameter or a local variable.
+            let mut declared = DeclaredNames::default();
+            let _ = declared.visit_item_function(func);
+            let visible: HashSet<Symbol> = arrays.difference(&declared.0).copied().collect();
+            if visible.is_empty() {
+                continue;
+            }
+
+            let mut checker = LoopChecker { ctx, arrays: &visible };
+            let _ = checker.visit_block(body);
+        }
+    }
+}
+
+/// Collects the names of the variables declared in a function.
+#[derive(Default)]
+struct DeclaredNames(HashSet<Symbol>);
+
+impl<'ast> Visit<'ast> for DeclaredNames {
+    type BreakValue = Never;
+
+    fn visit_variable_definition(
+        &mut self,
+        var: &'ast ast::VariableDefinition<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        if let Some(name) = var.name {
+            self.0.insert(name.name);
+        }
+        self.walk_variable_definition(var)
+    }
+}
+
+/// Visitor flagging the `<array>.length` reads in loop conditions.
+struct LoopChecker<'a, 's> {
+    ctx: &'a LintContext<'s, 'a>,
+    arrays: &'a HashSet<Symbol>,
+}
+
+impl<'ast> Visit<'ast> for LoopChecker<'_, '_> {
+    type BreakValue = Never;
+
+    fn visit_stmt(&mut self, stmt: &'ast ast::Stmt<'ast>) -> ControlFlow<Self::BreakValue> {
+        if let StmtKind::For { cond: Some(cond), body, .. } = &stmt.kind {
+            let mut lengths = Vec::new();
+            collect_length_reads(cond, self.arrays, &mut lengths);
+            for (name, span) in lengths {
+                // The length can't be cached if the loop resizes the array.
+                let mut resizes = ResizeChecker { name };
+                if resizes.visit_stmt(body).is_continue() {
+                    self.ctx.emit(&CACHE_ARRAY_LENGTH, span);
+                }
+            }
+        }
+        self.walk_stmt(stmt)
+    }
+}
+
+/// Collects the `<array>.length` expressions of `expr` reading one of `arrays`.
+fn collect_length_reads(
+    expr: &Expr<'_>,
+    arrays: &HashSet<Symbol>,
+    out: &mut Vec<(Symbol, ast::Span)>,
+) {
+    match &expr.kind {
+        ExprKind::Member(base, member) if member.as_str() == "length" => {
+            if let ExprKind::Ident(ident) = &base.kind
+                && arrays.contains(&ident.name)
+            {
+                out.push((ident.name, expr.span));
+            }
+        }
+        ExprKind::Binary(lhs, _, rhs) => {
+            collect_length_reads(lhs, arrays, out);
+            collect_length_reads(rhs, arrays, out);
+        }
+        ExprKind::Unary(_, inner) => collect_length_reads(inner, arrays, out),
+        ExprKind::Tuple(elems) => {
+            for elem in elems.iter() {
+                if let SpannedOption::Some(inner) = elem.as_ref() {
+                    collect_length_reads(inner, arrays, out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Breaks on the statements pushing to, popping from, deleting or reassigning an array.
+struct ResizeChecker {
+    name: Symbol,
+}
+
+impl ResizeChecker {
+    fn is_array(&self, expr: &Expr<'_>) -> bool {
+        matches!(&expr.kind, ExprKind::Ident(ident) if ident.name == self.name)
+    }
+}
+
+impl<'ast> Visit<'ast> for ResizeChecker {
+    type BreakValue = ();
+
+    fn visit_expr(&mut self, expr: &'ast Expr<'ast>) -> ControlFlow<Self::BreakValue> {
+        let resizes = match &expr.kind {
+            ExprKind::Call(callee, _) => matches!(
+                &callee.kind,
+                ExprKind::Member(base, member)
+                    if matches!(member.as_str(), "push" | "pop") && self.is_array(base)
+            ),
+            ExprKind::Assign(lhs, _, _) | ExprKind::Delete(lhs) => self.is_array(lhs),
+            _ => false,
+        };
+        if resizes {
+            return ControlFlow::Break(());
+        }
+        self.walk_expr(expr)
+    }
+}
@@ -0,0 +1,54 @@
+use super::{
+    CouldBeCalldata,
+    immutable::{block_contains_unlowered_stmt, collect_state_writes},
+};
+use crate::{
+    linter::{LateLintPass, LintContext},
+    sol::{Severity, SolLint},
+};
+use solar::{ast, sema::hir};
+use std::collections::HashSet;
+
+declare_forge_lint!(
+    COULD_BE_CALLDATA,
+    Severity::Gas,
+    "could-be-calldata",
+    "read-only parameter of an external function could be declared calldata instead of memory",
+    examples: [
+        "function first(uint256[] memory ids) external pure returns (uint256) { return ids[0]; }",
+    ]
+);
+
+impl<'hir> LateLintPass<'hir> for CouldBeCalldata {
+    fn check_function(
+        &mut self,
+        ctx: &LintContext,
+        hir: &'hir hir::Hir<'hir>,
+        func: &'hir hir::Function<'hir>,
+    ) {
+        // Public functions may be called internally with memory arguments.
+        if !func.kind.is_function() || !matches!(func.visibility, ast::Visibility::External) {
+            return;
+        }
+        let Some(body) = func.body else { return };
+
+        let candidates: HashSet<_> = func
+            .parameters
+            .iter()
+            .copied()
+            .filter(|&id| hir.variable(id).data_location == Some(ast::DataLocation::Memory))
+            .collect();
+        if candidates.is_empty() || block_contains_unlowered_stmt(body) {
+            return;
+        }
+
+        let mut writes = HashSet::new();
+        collect_state_writes(hir, body, &candidates, &mut writes);
+
+        for &id in func.parameters {
+            if candidates.contains(&id) && !writes.contains(&id) {
+                ctx.emit(&COULD_BE_CALLDATA, hir.variable(id).span);
+            }
+        }
+    }
+}
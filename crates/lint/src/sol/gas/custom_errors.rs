@@ -9,7 +9,8 @@
     CUSTOM_ERRORS,
     Severity::Gas,
     "custom-errors",
-    "prefer using custom errors on revert and require calls"
+    "prefer using custom errors on revert and require calls",
+    examples: ["require(msg.sender == owner, \"not owner\");", "revert(\"unauthorized\");"]
 );
 
 impl<'ast> EarlyLintPass<'ast> for CustomErrors {
@@ -14,7 +14,8 @@
     COULD_BE_IMMUTABLE,
     Severity::Gas,
     "could-be-immutable",
-    "state variable could be declared immutable"
+    "state variable could be declared immutable",
+    examples: ["address owner; constructor() { owner = msg.sender; }"]
 );
 
 impl<'hir> LateLintPass<'hir> for CouldBeImmutable {
@@ -165,7 +166,7 @@ fn contract_contains_unlowered_stmt<'hir>(
     })
 }
 
-fn block_contains_unlowered_stmt(block: hir::Block<'_>) -> bool {
+pub(super) fn block_contains_unlowered_stmt(block: hir::Block<'_>) -> bool {
     block.stmts.iter().any(stmt_contains_unlowered_stmt)
 }
 
@@ -194,7 +195,7 @@ fn stmt_contains_unlowered_stmt(stmt: &hir::Stmt<'_>) -> bool {
     }
 }
 
-fn collect_state_writes<'hir>(
+pub(super) fn collect_state_writes<'hir>(
     hir: &'hir hir::Hir<'hir>,
     block: hir::Block<'hir>,
     candidates: &HashSet<hir::VariableId>,
@@ -13,7 +13,8 @@
     ASM_KECCAK256,
     Severity::Gas,
     "asm-keccak256",
-    "use of inefficient hashing mechanism; consider using inline assembly"
+    "use of inefficient hashing mechanism; consider using inline assembly",
+    examples: ["bytes32 hash = keccak256(abi.encode(a, b));"]
 );
 
 impl<'hir> LateLintPass<'hir> for AsmKeccak256 {
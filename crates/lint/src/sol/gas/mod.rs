@@ -1,17 +1,29 @@
 use crate::sol::{EarlyLintPass, LateLintPass, SolLint};
 
+mod cache_array_length;
+mod calldata;
 mod custom_errors;
 mod immutable;
 mod keccak;
+mod pre_increment;
+mod storage_packing;
 mod unused_state_variables;
+use cache_array_length::CACHE_ARRAY_LENGTH;
+use calldata::COULD_BE_CALLDATA;
 use custom_errors::CUSTOM_ERRORS;
 use immutable::COULD_BE_IMMUTABLE;
 use keccak::ASM_KECCAK256;
+use pre_increment::PRE_INCREMENT;
+use storage_packing::STORAGE_PACKING;
 use unused_state_variables::UNUSED_STATE_VARIABLES;
 
 register_lints!(
     (AsmKeccak256, late, (ASM_KECCAK256)),
+    (CacheArrayLength, early, (CACHE_ARRAY_LENGTH)),
     (CustomErrors, early, (CUSTOM_ERRORS)),
+    (CouldBeCalldata, late, (COULD_BE_CALLDATA)),
     (CouldBeImmutable, late, (COULD_BE_IMMUTABLE)),
+    (PreIncrement, early, (PRE_INCREMENT)),
+    (StoragePacking, late, (STORAGE_PACKING)),
     (UnusedStateVariables, late, (UNUSED_STATE_VARIABLES)),
 );
@@ -0,0 +1,40 @@
+use super::PreIncrement;
+use crate::{
+    linter::{EarlyLintPass, LintContext, Suggestion},
+    sol::{Severity, SolLint},
+};
+use solar::{
+    ast::{ExprKind, Stmt, StmtKind, UnOp, UnOpKind},
+    interface::diagnostics::Applicability,
+};
+
+declare_forge_lint!(
+    PRE_INCREMENT,
+    Severity::Gas,
+    "pre-increment",
+    "prefer the prefix increment and decrement in loop updates",
+    examples: ["for (uint256 i; i < n; i++) {}"]
+);
+
+impl<'ast> EarlyLintPass<'ast> for PreIncrement {
+    fn check_stmt(&mut self, ctx: &LintContext, stmt: &'ast Stmt<'ast>) {
+        // The value of the loop update is discarded, so `i++` can be replaced by `++i`.
+        if let StmtKind::For { next: Some(next), .. } = &stmt.kind
+            && let ExprKind::Unary(
+                UnOp { kind: kind @ (UnOpKind::PostInc | UnOpKind::PostDec), .. },
+                inner,
+            ) = &next.kind
+        {
+            let op = if matches!(kind, UnOpKind::PostInc) { "++" } else { "--" };
+            match ctx.span_to_snippet(inner.span) {
+                Some(operand) => ctx.emit_with_suggestion(
+                    &PRE_INCREMENT,
+                    next.span,
+                    Suggestion::fix(format!("{op}{operand}"), Applicability::MachineApplicable)
+                        .with_desc("use the prefix form"),
+                ),
+                None => ctx.emit(&PRE_INCREMENT, next.span),
+            }
+        }
+    }
+}
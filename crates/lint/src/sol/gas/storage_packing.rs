@@ -0,0 +1,110 @@
+use super::StoragePacking;
+use crate::{
+    linter::{LateLintPass, LintContext},
+    sol::{Severity, SolLint},
+};
+use solar::{
+    ast::ContractKind,
+    sema::hir::{self, ElementaryType, ItemId, TypeKind},
+};
+
+declare_forge_lint!(
+    STORAGE_PACKING,
+    Severity::Gas,
+    "storage-packing",
+    "state variables could be reordered to use fewer storage slots",
+    examples: ["uint128 a; uint256 b; uint128 c;"]
+);
+
+/// The size of a storage slot, in bytes.
+const SLOT_SIZE: usize = 32;
+
+impl<'hir> LateLintPass<'hir> for StoragePacking {
+    fn check_contract(
+        &mut self,
+        ctx: &LintContext,
+        hir: &'hir hir::Hir<'hir>,
+        contract: &'hir hir::Contract<'hir>,
+    ) {
+        if contract.kind == ContractKind::Interface {
+            return;
+        }
+
+        // Only the variables declared by the contract are considered, the ones of its bases are
+        // laid out before them in the inheritance order.
+        let sizes: Vec<usize> = contract
+            .variables()
+            .map(|id| hir.variable(id))
+            .filter(|var| !var.is_constant() && !var.is_immutable())
+            .map(|var| packed_size(hir, &var.ty.kind).unwrap_or(SLOT_SIZE))
+            .collect();
+
+        let used = declared_slots(&sizes);
+        let packed = packed_slots(&sizes);
+        if packed < used {
+            ctx.emit_with_msg(
+                &STORAGE_PACKING,
+                contract.name.span,
+                format!(
+                    "state variables could be reordered to use {packed} storage slots instead of \
+                     {used}"
+                ),
+            );
+        }
+    }
+}
+
+/// Returns the size in bytes of a type which can share a storage slot, or `None` for the types
+/// always starting a new slot (structs, arrays, mappings, strings and bytes).
+fn packed_size(hir: &hir::Hir<'_>, ty: &TypeKind<'_>) -> Option<usize> {
+    let size = match ty {
+        TypeKind::Elementary(ty) => match ty {
+            ElementaryType::Address(_) => 20,
+            ElementaryType::Bool => 1,
+            ElementaryType::Int(size)
+            | ElementaryType::UInt(size)
+            | ElementaryType::FixedBytes(size) => size.bytes() as usize,
+            _ => return None,
+        },
+        TypeKind::Custom(ItemId::Contract(_)) => 20,
+        TypeKind::Custom(ItemId::Enum(_)) => 1,
+        TypeKind::Custom(ItemId::Udvt(id)) => return packed_size(hir, &hir.udvt(*id).ty.kind),
+        _ => return None,
+    };
+    (size < SLOT_SIZE).then_some(size)
+}
+
+/// Returns the number of slots used by variables of the given sizes, in declaration order.
+fn declared_slots(sizes: &[usize]) -> usize {
+    let mut slots = 0;
+    let mut offset = 0;
+    for &size in sizes {
+        if offset + size > SLOT_SIZE {
+            slots += 1;
+            offset = 0;
+        }
+        offset += size;
+        // Full-slot variables also close the current slot.
+        if size == SLOT_SIZE {
+            slots += 1;
+            offset = 0;
+        }
+    }
+    slots + usize::from(offset > 0)
+}
+
+/// Returns the number of slots used by variables of the given sizes, packing the smaller ones with
+/// the first-fit decreasing heuristic.
+fn packed_slots(sizes: &[usize]) -> usize {
+    let mut sorted = sizes.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut free: Vec<usize> = Vec::new();
+    for size in sorted {
+        match free.iter_mut().find(|free| **free >= size) {
+            Some(free) => *free -= size,
+            None => free.push(SLOT_SIZE - size),
+        }
+    }
+    free.len()
+}
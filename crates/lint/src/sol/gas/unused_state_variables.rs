@@ -1,6 +1,6 @@
 use super::UnusedStateVariables;
 use crate::{
-    linter::{LateLintPass, LintContext},
+    linter::{LateLintPass, Lint, LintContext, ProjectSymbols},
     sol::{Severity, SolLint},
 };
 use solar::{
@@ -14,39 +14,59 @@
     UNUSED_STATE_VARIABLES,
     Severity::Gas,
     "unused-state-variables",
-    "state variable is never used"
+    "state variable is never used",
+    examples: ["uint256 private unused;"]
 );
 
 impl<'hir> LateLintPass<'hir> for UnusedStateVariables {
-    fn check_contract(
+    fn check_project(
         &mut self,
         ctx: &LintContext,
-        hir: &'hir hir::Hir<'hir>,
-        contract: &'hir hir::Contract<'hir>,
+        project: &ProjectSymbols<'hir>,
+        source: hir::SourceId,
     ) {
-        // Skip interfaces, they cannot have mutable state variables.
-        if contract.kind == ContractKind::Interface {
+        if !ctx.is_lint_enabled(UNUSED_STATE_VARIABLES.id()) {
             return;
         }
+        for (id, contract) in project.source_contracts(source) {
+            check_contract(ctx, project, id, contract);
+        }
+    }
+}
 
-        // Collect state variable IDs, skipping constants and immutables
-        // (those are handled by the compiler and don't occupy storage slots).
-        let state_vars: Vec<hir::VariableId> = contract
-            .variables()
-            .filter(|&var_id| {
-                let var = hir.variable(var_id);
-                !var.is_constant() && !var.is_immutable()
-            })
-            .collect();
+fn check_contract<'hir>(
+    ctx: &LintContext,
+    project: &ProjectSymbols<'hir>,
+    id: hir::ContractId,
+    contract: &'hir hir::Contract<'hir>,
+) {
+    let hir = project.hir();
 
-        if state_vars.is_empty() {
-            return;
-        }
+    // Skip interfaces, they cannot have mutable state variables.
+    if contract.kind == ContractKind::Interface {
+        return;
+    }
 
-        // Walk the full contract — functions (including modifier call args, parameters, returns,
-        // and bodies) and state variable initializers — to collect every variable referenced
-        // anywhere in this contract.
-        let mut collector = UsedVarCollector { hir, used: HashSet::new() };
+    // Collect state variable IDs, skipping constants and immutables
+    // (those are handled by the compiler and don't occupy storage slots).
+    let state_vars: Vec<hir::VariableId> = contract
+        .variables()
+        .filter(|&var_id| {
+            let var = hir.variable(var_id);
+            !var.is_constant() && !var.is_immutable()
+        })
+        .collect();
+
+    if state_vars.is_empty() {
+        return;
+    }
+
+    // Walk the full contract and the contracts deriving from it, in any source — functions
+    // (including modifier call args, parameters, returns, and bodies) and state variable
+    // initializers — to collect every variable referenced anywhere they can be used.
+    let mut collector = UsedVarCollector { hir, used: HashSet::new() };
+    for contract_id in std::iter::once(id).chain(project.derived(id).iter().copied()) {
+        let contract = hir.contract(contract_id);
         for func_id in contract.all_functions() {
             let _ = collector.visit_nested_function(func_id);
         }
@@ -54,13 +74,13 @@ fn check_contract(
         for var_id in contract.variables() {
             let _ = collector.visit_nested_var(var_id);
         }
+    }
 
-        // Report any state variable that was never referenced.
-        for var_id in state_vars {
-            if !collector.used.contains(&var_id) {
-                let var = hir.variable(var_id);
-                ctx.emit(&UNUSED_STATE_VARIABLES, var.span);
-            }
+    // Report any state variable that was never referenced.
+    for var_id in state_vars {
+        if !collector.used.contains(&var_id) {
+            let var = hir.variable(var_id);
+            ctx.emit(&UNUSED_STATE_VARIABLES, var.span);
         }
     }
 }
@@ -1,27 +1,44 @@
 use super::IncorrectShift;
 use crate::{
-    linter::{EarlyLintPass, LintContext},
+    linter::{EarlyLintPass, LintContext, Suggestion},
     sol::{Severity, SolLint},
 };
-use solar::ast::{BinOp, BinOpKind, Expr, ExprKind};
+use solar::{
+    ast::{BinOp, BinOpKind, Expr, ExprKind},
+    interface::diagnostics::{Applicability, SuggestionStyle},
+};
 
 declare_forge_lint!(
     INCORRECT_SHIFT,
     Severity::High,
     "incorrect-shift",
-    "the order of args in a shift operation is incorrect"
+    "the order of args in a shift operation is incorrect",
+    examples: ["uint256 result = 2 << x;"]
 );
 
 impl<'ast> EarlyLintPass<'ast> for IncorrectShift {
     fn check_expr(&mut self, ctx: &LintContext, expr: &'ast Expr<'ast>) {
         if let ExprKind::Binary(
             left_expr,
-            BinOp { kind: BinOpKind::Shl | BinOpKind::Shr, .. },
+            op @ BinOp { kind: BinOpKind::Shl | BinOpKind::Shr, .. },
             right_expr,
         ) = &expr.kind
             && contains_incorrect_shift(left_expr, right_expr)
         {
-            ctx.emit(&INCORRECT_SHIFT, expr.span);
+            // Swap the operands, e.g. `1 << x` to `x << 1`.
+            match (ctx.span_to_snippet(left_expr.span), ctx.span_to_snippet(right_expr.span)) {
+                (Some(left), Some(right)) => ctx.emit_with_suggestion(
+                    &INCORRECT_SHIFT,
+                    expr.span,
+                    Suggestion::fix(
+                        format!("{right} {} {left}", op.kind.to_str()),
+                        Applicability::MaybeIncorrect,
+                    )
+                    .with_desc("swap the operands")
+                    .with_style(SuggestionStyle::CompletelyHidden),
+                ),
+                _ => ctx.emit(&INCORRECT_SHIFT, expr.span),
+            }
         }
     }
 }
@@ -1,10 +1,12 @@
 use crate::sol::{EarlyLintPass, LateLintPass, SolLint};
 
 mod incorrect_shift;
+mod reentrancy;
 mod rtlo;
-mod unchecked_calls;
+pub(crate) mod unchecked_calls;
 
 use incorrect_shift::INCORRECT_SHIFT;
+use reentrancy::REENTRANCY;
 use rtlo::RTLO;
 use unchecked_calls::{ERC20_UNCHECKED_TRANSFER, UNCHECKED_CALL};
 
@@ -12,5 +14,6 @@
     (IncorrectShift, early, (INCORRECT_SHIFT)),
     (UncheckedCall, early, (UNCHECKED_CALL)),
     (UncheckedTransferERC20, late, (ERC20_UNCHECKED_TRANSFER)),
-    (Rtlo, early, (RTLO))
+    (Rtlo, early, (RTLO)),
+    (Reentrancy, late, (REENTRANCY))
 );
@@ -0,0 +1,329 @@
+use super::{Reentrancy, unchecked_calls::is_erc20_transfer_call};
+use crate::{
+    linter::{LateLintPass, LintContext},
+    sol::{Severity, SolLint},
+};
+use solar::{
+    ast::{self, ContractKind},
+    interface::{Span, data_structures::Never, kw},
+    sema::hir::{self, ExprKind, ItemId, Res, StmtKind, TypeKind, Visit},
+};
+use std::{collections::HashMap, ops::ControlFlow};
+
+declare_forge_lint!(
+    REENTRANCY,
+    Severity::High,
+    "reentrancy",
+    "state is written after an external call, which can lead to reentrancy",
+    examples: ["(bool ok,) = msg.sender.call{value: amount}(\"\"); balances[msg.sender] = 0;"]
+);
+
+impl<'hir> LateLintPass<'hir> for Reentrancy {
+    fn check_function(
+        &mut self,
+        ctx: &LintContext,
+        hir: &'hir hir::Hir<'hir>,
+        func: &'hir hir::Function<'hir>,
+    ) {
+        if !is_candidate(func) || is_non_reentrant(hir, func) {
+            return;
+        }
+        let Some(body) = func.body else { return };
+
+        let erc20_transfers = &ctx.config.lint_specific.erc20_unchecked_transfer_functions;
+        let mut summaries = HashMap::new();
+        let mut analyzer = Analyzer::new(hir, erc20_transfers, &mut summaries);
+        for stmt in body.stmts {
+            let _ = analyzer.visit_stmt(stmt);
+        }
+        for span in analyzer.reentrant_writes {
+            ctx.emit(&REENTRANCY, span);
+        }
+    }
+}
+
+/// Functions that can write state, excluding constructors which can't be reentered.
+fn is_candidate(func: &hir::Function<'_>) -> bool {
+    !matches!(func.state_mutability, ast::StateMutability::Pure | ast::StateMutability::View)
+        && !matches!(func.kind, hir::FunctionKind::Constructor | hir::FunctionKind::Modifier)
+}
+
+/// Returns whether the function is protected by a reentrancy guard, based on the modifier names:
+/// `nonReentrant`, `noReentrancy`, `lock`, ...
+fn is_non_reentrant(hir: &hir::Hir<'_>, func: &hir::Function<'_>) -> bool {
+    func.modifiers.iter().filter_map(|modifier| modifier.id.as_function()).any(|id| {
+        hir.function(id).name.is_some_and(|name| {
+            let name = name.as_str().to_lowercase().replace('_', "");
+            name.contains("nonreentrant") || name.contains("noreentran") || name == "lock"
+        })
+    })
+}
+
+/// The effects of an internal function, including the internal functions it calls.
+#[derive(Clone, Copy, Default)]
+struct Summary {
+    external_call: bool,
+    state_write: bool,
+}
+
+/// Walks a function body in execution order, tracking whether an external call may have been
+/// made on the current path.
+struct Analyzer<'a, 'hir> {
+    hir: &'hir hir::Hir<'hir>,
+    /// The signatures of the ERC20 transfer functions, which are not considered external calls.
+    erc20_transfers: &'a [String],
+    /// The summaries of the internal functions, `None` while being computed.
+    summaries: &'a mut HashMap<hir::FunctionId, Option<Summary>>,
+    /// The external call preceding the current statement on some path, if any.
+    call: Option<Span>,
+    /// The effects of the whole body, regardless of the paths.
+    summary: Summary,
+    /// The state writes following an external call, in source order.
+    reentrant_writes: Vec<Span>,
+}
+
+impl<'a, 'hir> Analyzer<'a, 'hir> {
+    fn new(
+        hir: &'hir hir::Hir<'hir>,
+        erc20_transfers: &'a [String],
+        summaries: &'a mut HashMap<hir::FunctionId, Option<Summary>>,
+    ) -> Self {
+        Self {
+            hir,
+            erc20_transfers,
+            summaries,
+            call: None,
+            summary: Summary::default(),
+            reentrant_writes: Vec::new(),
+        }
+    }
+
+    fn external_call(&mut self, span: Span) {
+        self.summary.external_call = true;
+        self.call.get_or_insert(span);
+    }
+
+    /// Records a state write, following the external call `call` if any.
+    fn state_write(&mut self, span: Span, call: Option<Span>) {
+        self.summary.state_write = true;
+        if call.is_some() && !self.reentrant_writes.contains(&span) {
+            self.reentrant_writes.push(span);
+        }
+    }
+
+    /// Returns the effects of an internal function, following the internal calls.
+    fn summarize(&mut self, id: hir::FunctionId) -> Summary {
+        if let Some(&summary) = self.summaries.get(&id) {
+            // Recursive calls are already being accounted for.
+            return summary.unwrap_or_default();
+        }
+        self.summaries.insert(id, None);
+        let func = self.hir.function(id);
+        let mut analyzer = Analyzer::new(self.hir, self.erc20_transfers, self.summaries);
+        for stmt in func.body.iter().flat_map(|body| body.stmts) {
+            let _ = analyzer.visit_stmt(stmt);
+        }
+        let summary = analyzer.summary;
+        self.summaries.insert(id, Some(summary));
+        summary
+    }
+
+    /// Visits a branch from the current state, returning the state at its end, or `None` if the
+    /// branch never falls through.
+    fn visit_branch(&mut self, stmt: &'hir hir::Stmt<'hir>) -> Option<Option<Span>> {
+        let _ = self.visit_stmt(stmt);
+        (!always_exits(stmt)).then_some(self.call)
+    }
+}
+
+impl<'hir> Visit<'hir> for Analyzer<'_, 'hir> {
+    type BreakValue = Never;
+
+    fn hir(&self) -> &'hir hir::Hir<'hir> {
+        self.hir
+    }
+
+    fn visit_stmt(&mut self, stmt: &'hir hir::Stmt<'hir>) -> ControlFlow<Self::BreakValue> {
+        match &stmt.kind {
+            StmtKind::If(condition, then_stmt, else_stmt) => {
+                let _ = self.visit_expr(condition);
+                let before = self.call;
+                let after_then = self.visit_branch(then_stmt);
+                self.call = before;
+                let after_else = match else_stmt {
+                    Some(else_stmt) => self.visit_branch(else_stmt),
+                    None => Some(before),
+                };
+                self.call = after_then.flatten().or(after_else.flatten()).or(before);
+                ControlFlow::Continue(())
+            }
+            StmtKind::Loop(block, _) => {
+                // The calls at the end of the body precede the writes of the next iteration.
+                for _ in 0..2 {
+                    for stmt in block.stmts {
+                        let _ = self.visit_stmt(stmt);
+                    }
+                }
+                ControlFlow::Continue(())
+            }
+            StmtKind::Try(stmt_try) => {
+                let _ = self.visit_expr(&stmt_try.expr);
+                let before = self.call;
+                let mut after = None;
+                for clause in stmt_try.clauses {
+                    self.call = before;
+                    for stmt in clause.block.stmts {
+                        let _ = self.visit_stmt(stmt);
+                    }
+                    after = after.or(self.call);
+                }
+                self.call = after.or(before);
+                ControlFlow::Continue(())
+            }
+            _ => self.walk_stmt(stmt),
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'hir hir::Expr<'hir>) -> ControlFlow<Self::BreakValue> {
+        match &expr.kind {
+            // Storing the result of a call can only be done after it: only the calls preceding the
+            // assignment are considered.
+            ExprKind::Assign(lhs, _, rhs) => {
+                let call = self.call;
+                let _ = self.visit_expr(rhs);
+                let _ = self.visit_expr(lhs);
+                if is_state_lvalue(self.hir, lhs) {
+                    self.state_write(expr.span, call);
+                }
+                ControlFlow::Continue(())
+            }
+            ExprKind::Delete(inner) => {
+                let _ = self.visit_expr(inner);
+                if is_state_lvalue(self.hir, inner) {
+                    self.state_write(expr.span, self.call);
+                }
+                ControlFlow::Continue(())
+            }
+            ExprKind::Unary(op, inner) if op.kind.has_side_effects() => {
+                let _ = self.visit_expr(inner);
+                if is_state_lvalue(self.hir, inner) {
+                    self.state_write(expr.span, self.call);
+                }
+                ControlFlow::Continue(())
+            }
+            // The arguments are evaluated before the call.
+            ExprKind::Call(callee, ..) => {
+                let _ = self.walk_expr(expr);
+                if is_external_call(self.hir, callee) {
+                    // ERC20 transfers don't hand over the control flow, unlike the transfer hooks
+                    // of other token standards.
+                    if !is_erc20_transfer_call(self.hir, expr, self.erc20_transfers) {
+                        self.external_call(expr.span);
+                    }
+                } else if let ExprKind::Ident(resolutions) = &callee.kind {
+                    let mut summary = Summary::default();
+                    for res in *resolutions {
+                        if let Res::Item(ItemId::Function(id)) = res {
+                            let callee = self.summarize(*id);
+                            summary.external_call |= callee.external_call;
+                            summary.state_write |= callee.state_write;
+                        }
+                    }
+                    if summary.state_write {
+                        self.state_write(expr.span, self.call);
+                    }
+                    if summary.external_call {
+                        self.external_call(expr.span);
+                    }
+                }
+                ControlFlow::Continue(())
+            }
+            _ => self.walk_expr(expr),
+        }
+    }
+}
+
+/// Returns whether the statement never falls through to the next one.
+fn always_exits(stmt: &hir::Stmt<'_>) -> bool {
+    match &stmt.kind {
+        StmtKind::Return(_) | StmtKind::Revert(_) => true,
+        StmtKind::Block(block) | StmtKind::UncheckedBlock(block) => {
+            block.stmts.last().is_some_and(always_exits)
+        }
+        StmtKind::If(_, then_stmt, Some(else_stmt)) => {
+            always_exits(then_stmt) && always_exits(else_stmt)
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether the assigned expression is a state variable, or a storage reference.
+fn is_state_lvalue(hir: &hir::Hir<'_>, expr: &hir::Expr<'_>) -> bool {
+    match &expr.peel_parens().kind {
+        ExprKind::Ident(resolutions) => resolutions.iter().any(|res| {
+            let Res::Item(ItemId::Variable(id)) = res else { return false };
+            let var = hir.variable(*id);
+            var.kind.is_state() || var.data_location == Some(ast::DataLocation::Storage)
+        }),
+        ExprKind::Tuple(exprs) => exprs.iter().flatten().any(|expr| is_state_lvalue(hir, expr)),
+        ExprKind::Index(base, _) | ExprKind::Slice(base, _, _) | ExprKind::Member(base, _) => {
+            is_state_lvalue(hir, base)
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether the callee can hand over the control flow to another contract:
+/// - low-level calls: `target.call(..)`, `target.delegatecall(..)`,
+/// - state-mutating calls to other contracts: `vault.deposit(..)`, `IVault(vault).deposit(..)`.
+///
+/// `transfer` and `send` only forward a gas stipend that is too low to reenter, and static calls
+/// can't write state.
+fn is_external_call(hir: &hir::Hir<'_>, callee: &hir::Expr<'_>) -> bool {
+    // `target.call{value: x}(..)`
+    let callee = match &callee.kind {
+        ExprKind::Call(inner, ..) if matches!(inner.kind, ExprKind::Member(..)) => inner,
+        _ => callee,
+    };
+    let ExprKind::Member(base, member) = &callee.kind else { return false };
+    if member.name == kw::Call || member.name == kw::Delegatecall {
+        return true;
+    }
+
+    let Some(cid) = contract_type(hir, base) else { return false };
+    let contract = hir.contract(cid);
+    if contract.kind == ContractKind::Library {
+        return false;
+    }
+    let bases = contract.linearized_bases.iter().map(|&base| hir.contract(base));
+    let mut functions = bases
+        .clone()
+        .flat_map(|base| base.all_functions())
+        .map(|id| hir.function(id))
+        .filter(|func| func.name.is_some_and(|name| name.name == member.name))
+        .peekable();
+    if functions.peek().is_some() {
+        return functions.any(|func| func.mutates_state());
+    }
+    // Public state variable getters are views, other unresolved members are considered
+    // state-mutating.
+    !bases
+        .flat_map(|base| base.variables())
+        .any(|id| hir.variable(id).name.is_some_and(|name| name.name == member.name))
+}
+
+/// Returns the contract type of an expression, if it is a contract variable or a cast to a
+/// contract.
+fn contract_type(hir: &hir::Hir<'_>, expr: &hir::Expr<'_>) -> Option<hir::ContractId> {
+    match &expr.peel_parens().kind {
+        ExprKind::Ident([Res::Item(ItemId::Variable(id)), ..]) => match hir.variable(*id).ty.kind {
+            TypeKind::Custom(ItemId::Contract(cid)) => Some(cid),
+            _ => None,
+        },
+        ExprKind::Call(
+            hir::Expr { kind: ExprKind::Ident([Res::Item(ItemId::Contract(cid))]), .. },
+            ..,
+        ) => Some(*cid),
+        _ => None,
+    }
+}
@@ -12,7 +12,8 @@
     RTLO,
     Severity::High,
     "rtlo",
-    "unicode bidirectional override character can hide malicious code"
+    "unicode bidirectional override character can hide malicious code",
+    examples: ["bool isAdmin = false; // \u{202e} ;eurt = nimdAsi"]
 );
 
 impl<'ast> EarlyLintPass<'ast> for Rtlo {
@@ -1,11 +1,14 @@
 use super::{UncheckedCall, UncheckedTransferERC20};
 use crate::{
-    linter::{EarlyLintPass, LateLintPass, LintContext},
+    linter::{EarlyLintPass, LateLintPass, LintContext, Suggestion},
     sol::{Severity, SolLint},
 };
 use solar::{
     ast::{Expr, ExprKind, ItemFunction, Stmt, StmtKind, visit::Visit},
-    interface::kw,
+    interface::{
+        diagnostics::{Applicability, SuggestionStyle},
+        kw,
+    },
     sema::hir::{self},
 };
 use std::ops::ControlFlow;
@@ -14,14 +17,17 @@
     UNCHECKED_CALL,
     Severity::High,
     "unchecked-call",
-    "Low-level calls should check the success return value"
+    "Low-level calls should check the success return value",
+    examples: ["target.call(data);"]
 );
 
 declare_forge_lint!(
     ERC20_UNCHECKED_TRANSFER,
     Severity::High,
     "erc20-unchecked-transfer",
-    "ERC20 'transfer' and 'transferFrom' calls should check the return value"
+    "ERC20 'transfer' and 'transferFrom' calls should check the return value",
+    examples: ["token.transfer(to, amount);"],
+    config: ["erc20_unchecked_transfer_functions"]
 );
 
 // -- ERC20 UNCKECKED TRANSFERS -------------------------------------------------------------------
@@ -29,6 +35,8 @@
 /// Checks that calls to functions with the same signature as the ERC20 transfer methods, and which
 /// return a boolean are not ignored.
 ///
+/// The checked signatures are configured by `lint_specific.erc20_unchecked_transfer_functions`.
+///
 /// WARN: can issue false positives, as it doesn't check that the contract being called sticks to
 /// the full ERC20 specification.
 impl<'hir> LateLintPass<'hir> for UncheckedTransferERC20 {
@@ -40,19 +48,37 @@ fn check_stmt(
     ) {
         // Only expression statements can contain unchecked transfers.
         if let hir::StmtKind::Expr(expr) = &stmt.kind
-            && is_erc20_transfer_call(hir, expr)
+            && is_erc20_transfer_call(
+                hir,
+                expr,
+                &ctx.config.lint_specific.erc20_unchecked_transfer_functions,
+            )
         {
-            ctx.emit(&ERC20_UNCHECKED_TRANSFER, expr.span);
+            match ctx.span_to_snippet(expr.span) {
+                Some(call) => ctx.emit_with_suggestion(
+                    &ERC20_UNCHECKED_TRANSFER,
+                    expr.span,
+                    Suggestion::fix(format!("require({call})"), Applicability::MaybeIncorrect)
+                        .with_desc("require the call to succeed")
+                        .with_style(SuggestionStyle::CompletelyHidden),
+                ),
+                None => ctx.emit(&ERC20_UNCHECKED_TRANSFER, expr.span),
+            }
         }
     }
 }
 
-/// Checks if an expression is an ERC20 `transfer` or `transferFrom` call.
+/// Checks if an expression is a call to one of the given function signatures, returning a boolean.
+/// By default, the ERC20 `transfer` and `transferFrom` functions:
 /// * `function transfer(address to, uint256 amount) external returns bool;`
 /// * `function transferFrom(address from, address to, uint256 amount) external returns bool;`
 ///
 /// Validates the method name, the params (count + types), and the returns (count + types).
-fn is_erc20_transfer_call(hir: &hir::Hir<'_>, expr: &hir::Expr<'_>) -> bool {
+pub(crate) fn is_erc20_transfer_call(
+    hir: &hir::Hir<'_>,
+    expr: &hir::Expr<'_>,
+    signatures: &[String],
+) -> bool {
     let is_type = |var_id: hir::VariableId, type_str: &str| {
         matches!(
             &hir.variable(var_id).ty.kind,
@@ -70,12 +96,20 @@ fn is_erc20_transfer_call(hir: &hir::Hir<'_>, expr: &hir::Expr<'_>) -> bool {
         return false;
     };
 
-    // Determine the expected ERC20 signature from the call
-    let (expected_params, expected_returns): (&[&str], &[&str]) = match func_ident.as_str() {
-        "transferFrom" if args.len() == 3 => (&["address", "address", "uint256"], &["bool"]),
-        "transfer" if args.len() == 2 => (&["address", "uint256"], &["bool"]),
-        _ => return false,
-    };
+    // Determine the expected signatures from the call: `name(type1,type2)`
+    let candidates = signatures
+        .iter()
+        .filter_map(|signature| {
+            let (name, params) = signature.trim().strip_suffix(')')?.split_once('(')?;
+            let params = params.split(',').map(str::trim).filter(|p| !p.is_empty());
+            let params = params.collect::<Vec<_>>();
+            (name == func_ident.as_str() && params.len() == args.len()).then_some(params)
+        })
+        .collect::<Vec<_>>();
+    if candidates.is_empty() {
+        return false;
+    }
+    let expected_returns = ["bool"];
 
     let Some(cid) = (match &contract_expr.kind {
         // Call to pre-instantiated contract variable
@@ -106,10 +140,12 @@ fn is_erc20_transfer_call(hir: &hir::Hir<'_>, expr: &hir::Expr<'_>) -> bool {
         func.name.is_some_and(|name| name.as_str() == func_ident.as_str())
             && func.kind.is_function()
             && func.mutates_state()
-            && func.parameters.len() == expected_params.len()
             && func.returns.len() == expected_returns.len()
-            && func.parameters.iter().zip(expected_params).all(|(id, &ty)| is_type(*id, ty))
-            && func.returns.iter().zip(expected_returns).all(|(id, &ty)| is_type(*id, ty))
+            && func.returns.iter().zip(expected_returns).all(|(id, ty)| is_type(*id, ty))
+            && candidates.iter().any(|expected_params| {
+                func.parameters.len() == expected_params.len()
+                    && func.parameters.iter().zip(expected_params).all(|(id, ty)| is_type(*id, ty))
+            })
     })
 }
 
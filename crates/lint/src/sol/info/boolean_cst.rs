@@ -8,7 +8,13 @@
     interface::SpannedOption,
 };
 
-declare_forge_lint!(BOOLEAN_CST, Severity::Med, "boolean-cst", "misuse of a boolean constant");
+declare_forge_lint!(
+    BOOLEAN_CST,
+    Severity::Med,
+    "boolean-cst",
+    "misuse of a boolean constant",
+    examples: ["if (true) { withdraw(); }"]
+);
 
 impl<'ast> EarlyLintPass<'ast> for BooleanCst {
     fn check_stmt(&mut self, ctx: &LintContext, stmt: &'ast Stmt<'ast>) {
@@ -12,7 +12,8 @@
     BOOLEAN_EQUAL,
     Severity::Info,
     "boolean-equal",
-    "boolean comparisons to constants should be simplified"
+    "boolean comparisons to constants should be simplified",
+    examples: ["if (paused == false) { withdraw(); }"]
 );
 
 impl<'ast> EarlyLintPass<'ast> for BooleanEqual {
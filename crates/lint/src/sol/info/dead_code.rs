@@ -0,0 +1,254 @@
+use super::DeadCode;
+use crate::{
+    linter::{LateLintPass, LintContext},
+    sol::{Severity, SolLint},
+};
+use solar::{
+    ast::{ContractKind, Symbol, Visibility},
+    interface::{data_structures::Never, source_map::FileName},
+    sema::hir::{self, Visit as _},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    ops::ControlFlow,
+};
+
+declare_forge_lint!(
+    DEAD_CODE,
+    Severity::Info,
+    "dead-code",
+    "private or internal code is never used",
+    examples: ["function _unused() internal {}"]
+);
+
+impl<'hir> LateLintPass<'hir> for DeadCode {
+    fn check_nested_source(
+        &mut self,
+        ctx: &LintContext,
+        hir: &'hir hir::Hir<'hir>,
+        id: hir::SourceId,
+    ) {
+        // Test and script helpers are not dead code.
+        if is_test_or_script(ctx, hir, id) {
+            return;
+        }
+
+        let functions = hir
+            .functions_enumerated()
+            .filter(|(_, func)| func.source == id && is_dead_code_candidate(hir, func))
+            .collect::<Vec<_>>();
+        let events = hir
+            .events_enumerated()
+            .filter(|(_, event)| {
+                event.source == id
+                    && !event
+                        .contract
+                        .is_some_and(|c| hir.contract(c).kind == ContractKind::Interface)
+            })
+            .collect::<Vec<_>>();
+        if functions.is_empty() && events.is_empty() {
+            return;
+        }
+
+        let graph = CallGraph::new(hir);
+        // Only the code reachable from sources counts as used: code reachable from tests or
+        // scripts only is reported as such.
+        let used = graph.reachable(|source| !is_test_or_script(ctx, hir, source));
+        let used_in_tests = graph.reachable(|_| true);
+
+        let mut unused = Vec::new();
+        for (fid, func) in functions {
+            if used.functions.contains(&fid) {
+                continue;
+            }
+            let Some(name) = func.name else { continue };
+            let kind = if matches!(func.kind, hir::FunctionKind::Modifier) {
+                "modifier"
+            } else {
+                "function"
+            };
+            unused.push((name.span, kind, used_in_tests.functions.contains(&fid)));
+        }
+        for (eid, event) in events {
+            if !used.events.contains(&eid) {
+                unused.push((event.name.span, "event", used_in_tests.events.contains(&eid)));
+            }
+        }
+
+        unused.sort_by_key(|(span, ..)| span.lo());
+        for (span, kind, used_in_tests) in unused {
+            let msg = if used_in_tests {
+                format!("{kind} is only used in tests or scripts")
+            } else {
+                format!("{kind} is never used")
+            };
+            ctx.emit_with_msg(&DEAD_CODE, span, msg);
+        }
+    }
+}
+
+/// Returns whether the function or modifier can only be used in the project itself, and is
+/// therefore dead if it is never reached.
+///
+/// Virtual and overriding functions are always considered used, as they can be reached through
+/// the inheritance.
+fn is_dead_code_candidate(hir: &hir::Hir<'_>, func: &hir::Function<'_>) -> bool {
+    let is_virtual = func.virtual_ || func.override_;
+    let in_interface =
+        func.contract.is_some_and(|c| hir.contract(c).kind == ContractKind::Interface);
+    match func.kind {
+        hir::FunctionKind::Function => {
+            // Free functions are always internal.
+            let is_internal = func.contract.is_none()
+                || matches!(func.visibility, Visibility::Private | Visibility::Internal);
+            !is_virtual && !in_interface && is_internal
+        }
+        hir::FunctionKind::Modifier => !is_virtual,
+        _ => false,
+    }
+}
+
+/// Returns whether the source is a test or a script, based on its path.
+fn is_test_or_script(ctx: &LintContext, hir: &hir::Hir<'_>, id: hir::SourceId) -> bool {
+    let FileName::Real(path) = &hir.source(id).file.name else { return false };
+    let paths = ctx.config.path_config;
+    paths.is_test_or_script(&paths.root.join(path))
+}
+
+/// The functions, modifiers and events referenced by each function of the project.
+struct CallGraph<'hir> {
+    hir: &'hir hir::Hir<'hir>,
+    /// The references of each function, and of the variable initializers.
+    refs: HashMap<Node, References>,
+    /// The functions and events by name, to resolve member accesses.
+    by_name: HashMap<Symbol, References>,
+}
+
+/// A node of the call graph.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Node {
+    Function(hir::FunctionId),
+    Variable(hir::VariableId),
+}
+
+#[derive(Default)]
+struct References {
+    functions: HashSet<hir::FunctionId>,
+    events: HashSet<hir::EventId>,
+    /// The names of the members accessed, which are not resolved in the HIR.
+    members: HashSet<Symbol>,
+}
+
+impl<'hir> CallGraph<'hir> {
+    fn new(hir: &'hir hir::Hir<'hir>) -> Self {
+        let mut refs = HashMap::new();
+        let mut by_name = HashMap::<Symbol, References>::new();
+        for (fid, func) in hir.functions_enumerated() {
+            let mut collector = ReferenceCollector { hir, refs: References::default() };
+            let _ = collector.visit_nested_function(fid);
+            refs.insert(Node::Function(fid), collector.refs);
+            if let Some(name) = func.name {
+                by_name.entry(name.name).or_default().functions.insert(fid);
+            }
+        }
+        for vid in hir.variable_ids().filter(|&vid| is_global_or_state(hir, vid)) {
+            let mut collector = ReferenceCollector { hir, refs: References::default() };
+            let _ = collector.visit_nested_var(vid);
+            refs.insert(Node::Variable(vid), collector.refs);
+        }
+        for (eid, event) in hir.events_enumerated() {
+            by_name.entry(event.name.name).or_default().events.insert(eid);
+        }
+        Self { hir, refs, by_name }
+    }
+
+    /// Returns the functions and events reachable from the entry points of the sources matching
+    /// `filter`: the public and external functions, the special functions, the virtual and
+    /// overriding functions, and the variable initializers.
+    fn reachable(&self, filter: impl Fn(hir::SourceId) -> bool) -> References {
+        let mut queue = Vec::new();
+        for (fid, func) in self.hir.functions_enumerated() {
+            if filter(func.source) && !is_dead_code_candidate(self.hir, func) {
+                queue.push(Node::Function(fid));
+            }
+        }
+        for vid in self.hir.variable_ids().filter(|&vid| is_global_or_state(self.hir, vid)) {
+            if filter(self.hir.variable(vid).source) {
+                queue.push(Node::Variable(vid));
+            }
+        }
+
+        let mut reached = References::default();
+        let mut visited = HashSet::new();
+        while let Some(node) = queue.pop() {
+            if !visited.insert(node) {
+                continue;
+            }
+            let Some(refs) = self.refs.get(&node) else { continue };
+            let members = refs.members.iter().filter_map(|name| self.by_name.get(name));
+            for refs in std::iter::once(refs).chain(members) {
+                reached.events.extend(&refs.events);
+                for &fid in &refs.functions {
+                    reached.functions.insert(fid);
+                    queue.push(Node::Function(fid));
+                }
+            }
+        }
+        reached
+    }
+}
+
+/// Returns whether the variable is a file-level constant or a state variable, whose initializer
+/// is evaluated regardless of the functions called.
+fn is_global_or_state(hir: &hir::Hir<'_>, id: hir::VariableId) -> bool {
+    matches!(hir.variable(id).kind, hir::VarKind::Global | hir::VarKind::State)
+}
+
+/// Collects the functions, modifiers and events referenced by a function or variable.
+struct ReferenceCollector<'hir> {
+    hir: &'hir hir::Hir<'hir>,
+    refs: References,
+}
+
+impl<'hir> hir::Visit<'hir> for ReferenceCollector<'hir> {
+    type BreakValue = Never;
+
+    fn hir(&self) -> &'hir hir::Hir<'hir> {
+        self.hir
+    }
+
+    fn visit_modifier(
+        &mut self,
+        modifier: &'hir hir::Modifier<'hir>,
+    ) -> ControlFlow<Self::BreakValue> {
+        if let Some(fid) = modifier.id.as_function() {
+            self.refs.functions.insert(fid);
+        }
+        self.walk_modifier(modifier)
+    }
+
+    fn visit_expr(&mut self, expr: &'hir hir::Expr<'hir>) -> ControlFlow<Self::BreakValue> {
+        match &expr.kind {
+            hir::ExprKind::Ident(resolutions) => {
+                for res in *resolutions {
+                    match res {
+                        hir::Res::Item(hir::ItemId::Function(fid)) => {
+                            self.refs.functions.insert(*fid);
+                        }
+                        hir::Res::Item(hir::ItemId::Event(eid)) => {
+                            self.refs.events.insert(*eid);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            // Library, `using for` and external calls are only resolved during type checking, so
+            // all the functions and events with the member name are considered used.
+            hir::ExprKind::Member(_, member) => {
+                self.refs.members.insert(member.name);
+            }
+            _ => {}
+        }
+        self.walk_expr(expr)
+    }
+}
@@ -15,14 +15,16 @@
     UNUSED_IMPORT,
     Severity::Info,
     "unused-import",
-    "unused imports should be removed"
+    "unused imports should be removed",
+    examples: ["import {Unused} from \"./Unused.sol\";"]
 );
 
 declare_forge_lint!(
     UNALIASED_PLAIN_IMPORT,
     Severity::Info,
     "unaliased-plain-import",
-    "use named imports '{A, B}' or alias 'import \"..\" as X'"
+    "use named imports '{A, B}' or alias 'import \"..\" as X'",
+    examples: ["import \"./Token.sol\";"]
 );
 
 impl<'ast> EarlyLintPass<'ast> for Imports {
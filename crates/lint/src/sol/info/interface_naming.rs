@@ -9,14 +9,16 @@
     INTERFACE_FILE_NAMING,
     Severity::Info,
     "interface-file-naming",
-    "interface file names should be prefixed with 'I'"
+    "interface file names should be prefixed with 'I'",
+    examples: ["// Token.sol\ninterface IToken {}"]
 );
 
 declare_forge_lint!(
     INTERFACE_NAMING,
     Severity::Info,
     "interface-naming",
-    "interface names should be prefixed with 'I'"
+    "interface names should be prefixed with 'I'",
+    examples: ["interface Token {}"]
 );
 
 impl<'ast> EarlyLintPass<'ast> for InterfaceFileNaming {
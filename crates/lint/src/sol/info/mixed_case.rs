@@ -9,7 +9,9 @@
     MIXED_CASE_FUNCTION,
     Severity::Info,
     "mixed-case-function",
-    "function names should use mixedCase"
+    "function names should use mixedCase",
+    examples: ["function Transfer_Tokens() public {}"],
+    config: ["mixed_case_exceptions"]
 );
 
 impl<'ast> EarlyLintPass<'ast> for MixedCaseFunction {
@@ -27,7 +29,8 @@ fn check_item_function(&mut self, ctx: &LintContext, func: &'ast ItemFunction<'a
                 name.span,
                 Suggestion::fix(
                     expected,
-                    solar::interface::diagnostics::Applicability::MachineApplicable,
+                    // Renaming the declaration alone would break its references.
+                    solar::interface::diagnostics::Applicability::MaybeIncorrect,
                 )
                 .with_desc("consider using"),
             );
@@ -39,7 +42,9 @@ fn check_item_function(&mut self, ctx: &LintContext, func: &'ast ItemFunction<'a
     MIXED_CASE_VARIABLE,
     Severity::Info,
     "mixed-case-variable",
-    "mutable variables should use mixedCase"
+    "mutable variables should use mixedCase",
+    examples: ["uint256 Total_Supply;"],
+    config: ["mixed_case_exceptions"]
 );
 
 impl<'ast> EarlyLintPass<'ast> for MixedCaseVariable {
@@ -61,7 +66,8 @@ fn check_variable_definition(
                 name.span,
                 Suggestion::fix(
                     expected,
-                    solar::interface::diagnostics::Applicability::MachineApplicable,
+                    // Renaming the declaration alone would break its references.
+                    solar::interface::diagnostics::Applicability::MaybeIncorrect,
                 )
                 .with_desc("consider using"),
             );
@@ -9,7 +9,9 @@
     MULTI_CONTRACT_FILE,
     Severity::Info,
     "multi-contract-file",
-    "prefer having only one contract, interface or library per file"
+    "prefer having only one contract, interface or library per file",
+    examples: ["contract Vault {}\ncontract Router {}"],
+    config: ["multi_contract_file_exceptions"]
 );
 
 impl<'ast> EarlyLintPass<'ast> for MultiContractFile {
@@ -9,7 +9,8 @@
     NAMED_STRUCT_FIELDS,
     Severity::Info,
     "named-struct-fields",
-    "prefer initializing structs with named fields"
+    "prefer initializing structs with named fields",
+    examples: ["Position memory position = Position(owner, amount);"]
 );
 
 impl<'hir> LateLintPass<'hir> for NamedStructFields {
@@ -9,7 +9,8 @@
     PASCAL_CASE_STRUCT,
     Severity::Info,
     "pascal-case-struct",
-    "structs should use PascalCase"
+    "structs should use PascalCase",
+    examples: ["struct user_info { uint256 balance; }"]
 );
 
 impl<'ast> EarlyLintPass<'ast> for PascalCaseStruct {
@@ -21,7 +22,8 @@ fn check_item_struct(&mut self, ctx: &LintContext, strukt: &'ast ItemStruct<'ast
                 strukt.name.span,
                 Suggestion::fix(
                     expected,
-                    solar::interface::diagnostics::Applicability::MachineApplicable,
+                    // Renaming the declaration alone would break its references.
+                    solar::interface::diagnostics::Applicability::MaybeIncorrect,
                 )
                 .with_desc("consider using"),
             );
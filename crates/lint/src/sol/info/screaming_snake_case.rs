@@ -9,14 +9,16 @@
     SCREAMING_SNAKE_CASE_CONSTANT,
     Severity::Info,
     "screaming-snake-case-const",
-    "constants should use SCREAMING_SNAKE_CASE"
+    "constants should use SCREAMING_SNAKE_CASE",
+    examples: ["uint256 constant maxSupply = 1e18;"]
 );
 
 declare_forge_lint!(
     SCREAMING_SNAKE_CASE_IMMUTABLE,
     Severity::Info,
     "screaming-snake-case-immutable",
-    "immutables should use SCREAMING_SNAKE_CASE"
+    "immutables should use SCREAMING_SNAKE_CASE",
+    examples: ["address immutable owner;"]
 );
 
 impl<'ast> EarlyLintPass<'ast> for ScreamingSnakeCase {
@@ -30,7 +32,8 @@ fn check_variable_definition(
         {
             let suggestion = Suggestion::fix(
                 expected,
-                solar::interface::diagnostics::Applicability::MachineApplicable,
+                // Renaming the declaration alone would break its references.
+                solar::interface::diagnostics::Applicability::MaybeIncorrect,
             )
             .with_desc("consider using");
 
@@ -9,7 +9,8 @@
     UNSAFE_CHEATCODE_USAGE,
     Severity::Info,
     "unsafe-cheatcode",
-    "usage of unsafe cheatcodes that can perform dangerous operations"
+    "usage of unsafe cheatcodes that can perform dangerous operations",
+    examples: ["vm.ffi(inputs);"]
 );
 
 const UNSAFE_CHEATCODES: [&str; 9] = [
@@ -9,9 +9,11 @@
     BLOCK_TIMESTAMP,
     Severity::Low,
     "block-timestamp",
-    "usage of `block.timestamp` in a comparison may be manipulated by validators"
+    "usage of `block.timestamp` in a comparison may be manipulated by validators",
+    examples: ["require(block.timestamp > deadline);"]
 );
 
+/// Flags ordering comparisons only, strict equalities are reported by `block-strict-equality`.
 impl<'ast> EarlyLintPass<'ast> for BlockTimestamp {
     fn check_expr(&mut self, ctx: &LintContext, expr: &'ast Expr<'ast>) {
         if let ExprKind::Binary(lhs, BinOp { kind, .. }, rhs) = &expr.kind
@@ -24,19 +26,11 @@ fn check_expr(&mut self, ctx: &LintContext, expr: &'ast Expr<'ast>) {
 }
 
 const fn is_cmp(kind: BinOpKind) -> bool {
-    matches!(
-        kind,
-        BinOpKind::Lt
-            | BinOpKind::Le
-            | BinOpKind::Gt
-            | BinOpKind::Ge
-            | BinOpKind::Eq
-            | BinOpKind::Ne
-    )
+    matches!(kind, BinOpKind::Lt | BinOpKind::Le | BinOpKind::Gt | BinOpKind::Ge)
 }
 
 /// Returns `true` if `expr` is `block.timestamp`.
-fn is_block_timestamp(expr: &Expr<'_>) -> bool {
+pub(crate) fn is_block_timestamp(expr: &Expr<'_>) -> bool {
     matches!(
         &expr.kind,
         ExprKind::Member(base, member)
@@ -8,19 +8,33 @@
 /// - `$str_id`: A unique identifier used to reference a specific lint during configuration.
 /// - `$desc`: A short description of the lint.
 ///
+/// And the following optional fields, exported with the lint metadata:
+/// - `examples`: Snippets of Solidity code flagged by the lint.
+/// - `config`: The `lint_specific` config keys read by the lint.
+///
 /// # Note
 /// Each lint must have a `help` section in the foundry book. This help field is auto-generated by
 /// the macro. Because of that, to ensure that new lint rules have their corresponding docs in the
 /// book, the existence of the lint rule's help section is validated with a unit test.
 #[macro_export]
 macro_rules! declare_forge_lint {
-    ($id:ident, $severity:expr, $str_id:expr, $desc:expr) => {
+    (
+        $id:ident,
+        $severity:expr,
+        $str_id:expr,
+        $desc:expr
+        $(, examples: [$($example:expr),* $(,)?])?
+        $(, config: [$($config:expr),* $(,)?])?
+        $(,)?
+    ) => {
         // Declare the static `Lint` metadata
         pub static $id: SolLint = SolLint {
             id: $str_id,
             severity: $severity,
             description: $desc,
             help: concat!("https://book.getfoundry.sh/reference/forge/forge-lint#", $str_id),
+            examples: &[$($($example),*)?],
+            config: &[$($(concat!("lint_specific.", $config)),*)?],
         };
     };
 }
@@ -37,7 +51,8 @@ macro_rules! declare_forge_lint {
 /// # Outputs
 ///
 /// - Structs for each linting pass
-/// - Helper methods to create early and late passes with required lifetimes
+/// - Helper methods to create the early and late passes with at least one lint included by the
+///   config, along with the IDs of their included lints
 /// - `const REGISTERED_LINTS` containing all registered lint objects
 #[macro_export]
 macro_rules! register_lints {
@@ -68,10 +83,12 @@ impl $pass_id {
 
     // 3. Internal rule for declaring the helper functions.
     ( @declare_funcs $( ($pass_id:ident, $pass_type:ident, $lints:tt) ),* $(,)? ) => {
-        pub fn create_early_lint_passes<'ast>() -> Vec<(Box<dyn EarlyLintPass<'ast>>, &'static [SolLint])> {
+        pub fn create_early_lint_passes<'ast>(
+            include: &dyn Fn(&SolLint) -> bool,
+        ) -> Vec<(Box<dyn EarlyLintPass<'ast>>, Vec<&'static str>)> {
             [
                 $(
-                    register_lints!(@early_create $pass_id, $pass_type),
+                    register_lints!(@early_create $pass_id, $pass_type, include),
                 )*
             ]
             .into_iter()
@@ -79,10 +96,12 @@ pub fn create_early_lint_passes<'ast>() -> Vec<(Box<dyn EarlyLintPass<'ast>>, &'
             .collect()
         }
 
-        pub fn create_late_lint_passes<'hir>() -> Vec<(Box<dyn LateLintPass<'hir>>, &'static [SolLint])> {
+        pub fn create_late_lint_passes<'hir>(
+            include: &dyn Fn(&SolLint) -> bool,
+        ) -> Vec<(Box<dyn LateLintPass<'hir>>, Vec<&'static str>)> {
             [
                 $(
-                    register_lints!(@late_create $pass_id, $pass_type),
+                    register_lints!(@late_create $pass_id, $pass_type, include),
                 )*
             ]
             .into_iter()
@@ -106,15 +125,21 @@ pub fn as_late_lint_pass<'hir>() -> Box<dyn LateLintPass<'hir>> {
         }
     };
 
-    (@early_create $_pass_id:ident, late) => { None };
-    (@early_create $pass_id:ident, $_other:ident) => {
-        Some(($pass_id::as_early_lint_pass(), $pass_id::LINTS))
+    (@included $pass_id:ident, $include:ident) => {
+        $pass_id::LINTS.iter().filter(|&lint| $include(lint)).map(|lint| lint.id).collect::<Vec<_>>()
     };
 
-    (@late_create $_pass_id:ident, early) => { None };
-    (@late_create $pass_id:ident, $_other:ident) => {
-        Some(($pass_id::as_late_lint_pass(), $pass_id::LINTS))
-    };
+    (@early_create $_pass_id:ident, late, $_include:ident) => { None };
+    (@early_create $pass_id:ident, $_other:ident, $include:ident) => {{
+        let lints = register_lints!(@included $pass_id, $include);
+        (!lints.is_empty()).then(|| ($pass_id::as_early_lint_pass(), lints))
+    }};
+
+    (@late_create $_pass_id:ident, early, $_include:ident) => { None };
+    (@late_create $pass_id:ident, $_other:ident, $include:ident) => {{
+        let lints = register_lints!(@included $pass_id, $include);
+        (!lints.is_empty()).then(|| ($pass_id::as_late_lint_pass(), lints))
+    }};
 
     // --- ENTRY POINT ---------------------------------------------------------
     ( $($tokens:tt)* ) => {
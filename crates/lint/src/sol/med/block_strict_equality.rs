@@ -0,0 +1,86 @@
+use super::BlockStrictEquality;
+use crate::{
+    linter::{EarlyLintPass, LintContext, Suggestion},
+    sol::{Severity, SolLint, low::block_timestamp::is_block_timestamp},
+};
+use solar::{
+    ast::{BinOp, BinOpKind, Expr, ExprKind},
+    interface::{SpannedOption, diagnostics::Applicability},
+};
+
+declare_forge_lint!(
+    BLOCK_STRICT_EQUALITY,
+    Severity::Med,
+    "block-strict-equality",
+    "strict equality on `block.timestamp` or `blockhash` is unlikely to hold",
+    examples: ["if (block.timestamp == deadline) { settle(); }"]
+);
+
+impl<'ast> EarlyLintPass<'ast> for BlockStrictEquality {
+    fn check_expr(&mut self, ctx: &LintContext, expr: &'ast Expr<'ast>) {
+        let ExprKind::Binary(lhs, op @ BinOp { kind: BinOpKind::Eq | BinOpKind::Ne, .. }, rhs) =
+            &expr.kind
+        else {
+            return;
+        };
+        if !(contains_block_value(lhs) || contains_block_value(rhs)) {
+            return;
+        }
+
+        // A deadline compared to `block.timestamp` can be turned into a range check, e.g.
+        // `block.timestamp == t` to `block.timestamp >= t`.
+        let replacement = match (is_block_timestamp(lhs), is_block_timestamp(rhs), op.kind) {
+            (true, false, BinOpKind::Eq) => Some(">="),
+            (true, false, _) => Some("<"),
+            (false, true, BinOpKind::Eq) => Some("<="),
+            (false, true, _) => Some(">"),
+            _ => None,
+        }
+        .zip(ctx.span_to_snippet(lhs.span).zip(ctx.span_to_snippet(rhs.span)));
+
+        match replacement {
+            Some((op, (lhs, rhs))) => ctx.emit_with_suggestion(
+                &BLOCK_STRICT_EQUALITY,
+                expr.span,
+                Suggestion::fix(format!("{lhs} {op} {rhs}"), Applicability::MaybeIncorrect)
+                    .with_desc("use an inequality"),
+            ),
+            None => ctx.emit(&BLOCK_STRICT_EQUALITY, expr.span),
+        }
+    }
+}
+
+/// Returns `true` if `expr` is a `blockhash(..)` or `block.blockhash(..)` call.
+fn is_blockhash(expr: &Expr<'_>) -> bool {
+    let ExprKind::Call(callee, _) = &expr.peel_parens().kind else { return false };
+    match &callee.kind {
+        ExprKind::Ident(ident) => ident.as_str() == "blockhash",
+        ExprKind::Member(base, member) => {
+            member.as_str() == "blockhash"
+                && matches!(&base.kind, ExprKind::Ident(ident) if ident.as_str() == "block")
+        }
+        _ => false,
+    }
+}
+
+/// Recursively checks if an expression tree contains `block.timestamp` or a `blockhash(..)` call.
+fn contains_block_value(expr: &Expr<'_>) -> bool {
+    if is_blockhash(expr) || is_block_timestamp(expr) {
+        return true;
+    }
+    match &expr.kind {
+        ExprKind::Unary(_, inner) => contains_block_value(inner),
+        ExprKind::Binary(lhs, _, rhs) => contains_block_value(lhs) || contains_block_value(rhs),
+        ExprKind::Tuple(elems) => elems.iter().any(|e| {
+            if let SpannedOption::Some(inner) = e.as_ref() {
+                contains_block_value(inner)
+            } else {
+                false
+            }
+        }),
+        ExprKind::Call(callee, args) => {
+            contains_block_value(callee) || args.exprs().any(|e| contains_block_value(e))
+        }
+        _ => false,
+    }
+}
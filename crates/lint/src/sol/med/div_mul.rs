@@ -12,7 +12,8 @@
     DIVIDE_BEFORE_MULTIPLY,
     Severity::Med,
     "divide-before-multiply",
-    "multiplication should occur before division to avoid loss of precision"
+    "multiplication should occur before division to avoid loss of precision",
+    examples: ["uint256 fee = amount / 100 * rate;", "uint256 fee = amount.div(100).mul(rate);"]
 );
 
 impl<'ast> EarlyLintPass<'ast> for DivideBeforeMultiply {
@@ -22,6 +23,23 @@ fn check_expr(&mut self, ctx: &LintContext, expr: &'ast Expr<'ast>) {
         {
             ctx.emit(&DIVIDE_BEFORE_MULTIPLY, expr.span);
         }
+
+        // SafeMath-style `a.div(b).mul(c)`, common in pre-0.8 code.
+        if let ExprKind::Call(callee, _) = &expr.kind
+            && let Some(receiver) = member_call_receiver(callee, "mul")
+            && let ExprKind::Call(inner, _) = &receiver.kind
+            && member_call_receiver(inner, "div").is_some()
+        {
+            ctx.emit(&DIVIDE_BEFORE_MULTIPLY, expr.span);
+        }
+    }
+}
+
+/// If `callee` is `<receiver>.<name>`, returns the `<receiver>` expression.
+fn member_call_receiver<'ast>(callee: &'ast Expr<'ast>, name: &str) -> Option<&'ast Expr<'ast>> {
+    match &callee.kind {
+        ExprKind::Member(receiver, member) if member.as_str() == name => Some(receiver),
+        _ => None,
     }
 }
 
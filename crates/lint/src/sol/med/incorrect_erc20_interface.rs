@@ -9,7 +9,8 @@
     INCORRECT_ERC20_INTERFACE,
     Severity::Med,
     "incorrect-erc20-interface",
-    "incorrect ERC20 function interface"
+    "incorrect ERC20 function interface",
+    examples: ["interface IERC20 { function transfer(address to, uint256 amount) external; }"]
 );
 
 impl<'hir> LateLintPass<'hir> for IncorrectERC20Interface {
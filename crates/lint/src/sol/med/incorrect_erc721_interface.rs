@@ -9,7 +9,10 @@
     INCORRECT_ERC721_INTERFACE,
     Severity::Med,
     "incorrect-erc721-interface",
-    "incorrect ERC721 function interface"
+    "incorrect ERC721 function interface",
+    examples: [
+        "interface IERC721 { function ownerOf(uint256 tokenId) external view returns (uint256); }",
+    ]
 );
 
 impl<'hir> LateLintPass<'hir> for IncorrectERC721Interface {
@@ -0,0 +1,123 @@
+use super::IntegerOverflow;
+use crate::{
+    linter::{EarlyLintPass, LintContext},
+    sol::{Severity, SolLint},
+};
+use solar::{
+    ast::{self as ast, BinOp, BinOpKind, ExprKind, visit::Visit},
+    interface::data_structures::Never,
+};
+use std::ops::ControlFlow;
+
+declare_forge_lint!(
+    INTEGER_OVERFLOW,
+    Severity::Med,
+    "integer-overflow",
+    "arithmetic is unchecked before Solidity 0.8 and may overflow; use SafeMath or a 0.8 compiler",
+    examples: ["// pragma solidity ^0.7.0;
+function mint(uint256 amount) external { supply += amount; }"]
+);
+
+/// The latest release of each pre-0.8 minor version, used to tell whether a version pragma allows
+/// compilers without checked arithmetic.
+const PRE_CHECKED_ARITHMETIC_VERSIONS: [semver::Version; 4] = [
+    semver::Version::new(0, 4, 26),
+    semver::Version::new(0, 5, 17),
+    semver::Version::new(0, 6, 12),
+    semver::Version::new(0, 7, 6),
+];
+
+/// Flags the arithmetic that can silently wrap when the source unit can be compiled by a
+/// pre-0.8 compiler.
+///
+/// `SafeMath` itself and the contracts using it are skipped, as are binary operations on literals
+/// only, which are evaluated at compile time.
+impl<'ast> EarlyLintPass<'ast> for IntegerOverflow {
+    fn check_full_source_unit(
+        &mut self,
+        ctx: &LintContext<'ast, '_>,
+        ast: &'ast ast::SourceUnit<'ast>,
+    ) {
+        if !ctx.is_lint_enabled(INTEGER_OVERFLOW.id) || !allows_unchecked_arithmetic(ast) {
+            return;
+        }
+
+        // A file-level `using SafeMath for ..` applies to all the contracts of the file.
+        if ast.items.iter().any(is_safe_math_using) {
+            return;
+        }
+
+        let mut checker = OverflowChecker { ctx };
+        let _ = checker.visit_source_unit(ast);
+    }
+}
+
+/// Returns `true` if a `pragma solidity` directive of the source unit allows a pre-0.8 compiler.
+fn allows_unchecked_arithmetic(ast: &ast::SourceUnit<'_>) -> bool {
+    ast.items.iter().any(|item| {
+        if let ast::ItemKind::Pragma(pragma) = &item.kind
+            && let ast::PragmaTokens::Version(name, req) = &pragma.tokens
+            && name.as_str() == "solidity"
+        {
+            PRE_CHECKED_ARITHMETIC_VERSIONS.iter().any(|version| req.matches(version))
+        } else {
+            false
+        }
+    })
+}
+
+/// Returns `true` if `item` is a `using SafeMath for ..` directive.
+fn is_safe_math_using(item: &ast::Item<'_>) -> bool {
+    let ast::ItemKind::Using(using) = &item.kind else { return false };
+    match &using.list {
+        ast::UsingList::Single(path) => path.last().as_str().contains("SafeMath"),
+        ast::UsingList::Multiple(items) => {
+            items.iter().any(|(path, _)| path.last().as_str().contains("SafeMath"))
+        }
+    }
+}
+
+/// Returns `true` if `op` can overflow or underflow an integer.
+const fn is_overflowing_op(op: BinOp) -> bool {
+    matches!(op.kind, BinOpKind::Add | BinOpKind::Sub | BinOpKind::Mul | BinOpKind::Pow)
+}
+
+fn is_literal(expr: &ast::Expr<'_>) -> bool {
+    matches!(&expr.peel_parens().kind, ExprKind::Lit(..))
+}
+
+/// Visitor that flags the overflowing operations outside of `SafeMath`.
+struct OverflowChecker<'a, 's> {
+    ctx: &'a LintContext<'s, 'a>,
+}
+
+impl<'ast> Visit<'ast> for OverflowChecker<'_, '_> {
+    type BreakValue = Never;
+
+    fn visit_item_contract(
+        &mut self,
+        contract: &'ast ast::ItemContract<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        if contract.name.as_str().contains("SafeMath")
+            || contract.body.iter().any(is_safe_math_using)
+        {
+            return ControlFlow::Continue(());
+        }
+        self.walk_item_contract(contract)
+    }
+
+    fn visit_expr(&mut self, expr: &'ast ast::Expr<'ast>) -> ControlFlow<Self::BreakValue> {
+        match &expr.kind {
+            ExprKind::Binary(lhs, op, rhs)
+                if is_overflowing_op(*op) && !(is_literal(lhs) && is_literal(rhs)) =>
+            {
+                self.ctx.emit(&INTEGER_OVERFLOW, expr.span);
+            }
+            ExprKind::Assign(_, Some(op), _) if is_overflowing_op(*op) => {
+                self.ctx.emit(&INTEGER_OVERFLOW, expr.span);
+            }
+            _ => {}
+        }
+        self.walk_expr(expr)
+    }
+}
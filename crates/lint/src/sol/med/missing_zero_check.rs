@@ -15,9 +15,10 @@
 
 declare_forge_lint!(
     MISSING_ZERO_CHECK,
-    Severity::Low,
+    Severity::Med,
     "missing-zero-check",
-    "address parameter is used in a state write or value transfer without a zero-address check"
+    "address parameter is used in a state write or value transfer without a zero-address check",
+    examples: ["function setOwner(address newOwner) external { owner = newOwner; }"]
 );
 
 impl<'hir> LateLintPass<'hir> for MissingZeroCheck {
@@ -277,6 +278,15 @@ fn visit_expr(&mut self, expr: &'hir hir::Expr<'hir>) -> ControlFlow<Self::Break
                 return ControlFlow::Continue(());
             }
 
+            // `<storage array>.push(..)`: the pushed value is stored.
+            ExprKind::Call(callee, args, _) if is_storage_push(self.hir, callee) => {
+                let _ = self.visit_expr(callee);
+                self.sink_depth += 1;
+                let _ = self.visit_call_args(args);
+                self.sink_depth -= 1;
+                return ControlFlow::Continue(());
+            }
+
             // `<addr>.call/.delegatecall/.transfer/.send(..)`: receiver is the sink.
             ExprKind::Call(callee, args, _) => {
                 if let Some(receiver) = address_call_receiver(callee) {
@@ -289,8 +299,8 @@ fn visit_expr(&mut self, expr: &'hir hir::Expr<'hir>) -> ControlFlow<Self::Break
             }
 
             ExprKind::Assign(lhs, _, rhs) => {
-                // Sink: assignment to an address state variable.
-                if is_address_state_var_lhs(self.hir, lhs) {
+                // Sink: assignment of an address into storage.
+                if is_storage_address_lhs(self.hir, lhs) {
                     let _ = self.visit_expr(lhs);
                     self.sink_depth += 1;
                     let _ = self.visit_expr(rhs);
@@ -378,20 +388,46 @@ fn branch_always_exits(stmt: &hir::Stmt<'_>) -> bool {
     }
 }
 
-fn is_address_state_var_lhs(hir: &hir::Hir<'_>, lhs: &hir::Expr<'_>) -> bool {
-    if let ExprKind::Ident(reses) = &lhs.kind {
-        for res in *reses {
+/// Returns `true` if `lhs` writes an address into storage: an address or contract-typed state
+/// variable (`owner`, `treasury`, `token`), or a member/element of a state variable
+/// (`config.treasury`, `operators[i]`).
+fn is_storage_address_lhs(hir: &hir::Hir<'_>, lhs: &hir::Expr<'_>) -> bool {
+    match &lhs.peel_parens().kind {
+        ExprKind::Ident(reses) => reses.iter().any(|res| {
             if let Res::Item(ItemId::Variable(vid)) = res {
                 let v = hir.variable(*vid);
-                if v.kind.is_state()
-                    && matches!(v.ty.kind, TypeKind::Elementary(ElementaryType::Address(_)))
-                {
-                    return true;
-                }
+                v.kind.is_state()
+                    && matches!(
+                        v.ty.kind,
+                        TypeKind::Elementary(ElementaryType::Address(_))
+                            | TypeKind::Custom(ItemId::Contract(_))
+                    )
+            } else {
+                false
             }
-        }
+        }),
+        ExprKind::Member(base, _) | ExprKind::Index(base, _) => is_state_var_root(hir, base),
+        _ => false,
     }
-    false
+}
+
+/// Returns `true` if `expr` is a state variable, or a member/element access rooted at one.
+fn is_state_var_root(hir: &hir::Hir<'_>, expr: &hir::Expr<'_>) -> bool {
+    match &expr.peel_parens().kind {
+        ExprKind::Ident(reses) => reses.iter().any(|res| {
+            matches!(res, Res::Item(ItemId::Variable(vid)) if hir.variable(*vid).kind.is_state())
+        }),
+        ExprKind::Member(base, _) | ExprKind::Index(base, _) => is_state_var_root(hir, base),
+        _ => false,
+    }
+}
+
+/// Returns `true` if `callee` is `<storage array>.push`.
+fn is_storage_push(hir: &hir::Hir<'_>, callee: &hir::Expr<'_>) -> bool {
+    matches!(
+        &callee.kind,
+        ExprKind::Member(base, name) if name.as_str() == "push" && is_state_var_root(hir, base)
+    )
 }
 
 /// Maps each direct-ident modifier argument back to its caller-side parameter, runs the same guard
@@ -1,5 +1,8 @@
 use crate::sol::{EarlyLintPass, LateLintPass, SolLint};
 
+mod block_strict_equality;
+use block_strict_equality::BLOCK_STRICT_EQUALITY;
+
 mod div_mul;
 use div_mul::DIVIDE_BEFORE_MULTIPLY;
 
@@ -9,12 +12,29 @@
 mod incorrect_erc721_interface;
 use incorrect_erc721_interface::INCORRECT_ERC721_INTERFACE;
 
+mod integer_overflow;
+use integer_overflow::INTEGER_OVERFLOW;
+
+mod missing_zero_check;
+use missing_zero_check::MISSING_ZERO_CHECK;
+
+mod tx_origin;
+use tx_origin::TX_ORIGIN;
+
 mod unsafe_typecast;
 use unsafe_typecast::UNSAFE_TYPECAST;
 
+mod unused_return;
+use unused_return::UNUSED_RETURN;
+
 register_lints!(
+    (BlockStrictEquality, early, (BLOCK_STRICT_EQUALITY)),
     (DivideBeforeMultiply, early, (DIVIDE_BEFORE_MULTIPLY)),
     (IncorrectERC20Interface, late, (INCORRECT_ERC20_INTERFACE)),
     (IncorrectERC721Interface, late, (INCORRECT_ERC721_INTERFACE)),
-    (UnsafeTypecast, late, (UNSAFE_TYPECAST))
+    (IntegerOverflow, early, (INTEGER_OVERFLOW)),
+    (MissingZeroCheck, late, (MISSING_ZERO_CHECK)),
+    (TxOrigin, early, (TX_ORIGIN)),
+    (UnsafeTypecast, late, (UNSAFE_TYPECAST)),
+    (UnusedReturn, late, (UNUSED_RETURN))
 );
@@ -0,0 +1,57 @@
+use super::TxOrigin;
+use crate::{
+    linter::{EarlyLintPass, LintContext, Suggestion},
+    sol::{Severity, SolLint},
+};
+use solar::{
+    ast::{BinOp, BinOpKind, Expr, ExprKind},
+    interface::diagnostics::Applicability,
+};
+
+declare_forge_lint!(
+    TX_ORIGIN,
+    Severity::Med,
+    "tx-origin",
+    "`tx.origin` should not be used for authorization",
+    examples: ["require(tx.origin == owner);"]
+);
+
+impl<'ast> EarlyLintPass<'ast> for TxOrigin {
+    fn check_expr(&mut self, ctx: &LintContext, expr: &'ast Expr<'ast>) {
+        if let ExprKind::Binary(lhs, BinOp { kind: BinOpKind::Eq | BinOpKind::Ne, .. }, rhs) =
+            &expr.kind
+        {
+            // `tx.origin == msg.sender` checks that the caller is an EOA, not who it is.
+            let origin = match (is_tx_origin(lhs), is_tx_origin(rhs)) {
+                (true, false) if !is_msg_sender(rhs) => lhs,
+                (false, true) if !is_msg_sender(lhs) => rhs,
+                _ => return,
+            };
+            ctx.emit_with_suggestion(
+                &TX_ORIGIN,
+                origin.span,
+                // Contracts relying on `tx.origin` may have to be reworked for `msg.sender`.
+                Suggestion::fix("msg.sender".into(), Applicability::MaybeIncorrect)
+                    .with_desc("authorize the direct caller instead"),
+            );
+        }
+    }
+}
+
+/// Returns `true` if `expr` is `<base>.<member>`, with `<base>` an identifier.
+fn is_global_member(expr: &Expr<'_>, base: &str, member: &str) -> bool {
+    matches!(
+        &expr.peel_parens().kind,
+        ExprKind::Member(object, name)
+            if name.as_str() == member
+            && matches!(&object.kind, ExprKind::Ident(ident) if ident.as_str() == base)
+    )
+}
+
+fn is_tx_origin(expr: &Expr<'_>) -> bool {
+    is_global_member(expr, "tx", "origin")
+}
+
+fn is_msg_sender(expr: &Expr<'_>) -> bool {
+    is_global_member(expr, "msg", "sender")
+}
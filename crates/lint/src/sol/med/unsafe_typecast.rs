@@ -4,7 +4,7 @@
     sol::{Severity, SolLint},
 };
 use solar::{
-    ast::{LitKind, StrKind},
+    ast::{LitKind, StrKind, TypeSize},
     sema::hir::{self, ElementaryType, ExprKind, ItemId, Res, TypeKind},
 };
 
@@ -12,7 +12,8 @@
     UNSAFE_TYPECAST,
     Severity::Med,
     "unsafe-typecast",
-    "typecasts that can truncate values should be checked"
+    "typecasts that can truncate values should be checked",
+    examples: ["uint8 small = uint8(amount);"]
 );
 
 impl<'hir> LateLintPass<'hir> for UnsafeTypecast {
@@ -65,8 +66,9 @@ fn is_unsafe_typecast_hir(
 ///
 /// # Returns
 /// An `Option<ElementaryType>` containing the inferred type of the expression if it can be
-/// resolved to a single source (like variables, literals, or unary expressions).
-/// Returns `None` for expressions complex expressions (like binary operations).
+/// resolved to a single source (like variables, literals, builtin members, function calls, or
+/// unary expressions). Returns `None` for complex expressions (like binary operations or
+/// conditionals).
 fn infer_source_types(
     mut output: Option<&mut Vec<ElementaryType>>,
     hir: &hir::Hir<'_>,
@@ -90,6 +92,14 @@ fn infer_source_types(
                 // Recurse to find the original (inner-most) source type.
                 return infer_source_types(output, hir, inner);
             }
+            // A call to a function returning a single elementary value.
+            if let ExprKind::Ident(resolutions) = &call_expr.kind
+                && let Some(Res::Item(ItemId::Function(func_id))) = resolutions.first()
+                && let [ret] = hir.function(*func_id).returns
+                && let TypeKind::Elementary(elem_type) = &hir.variable(*ret).ty.kind
+            {
+                return track(*elem_type);
+            }
             None
         }
 
@@ -104,6 +114,54 @@ fn infer_source_types(
             None
         }
 
+        // Builtin members: `block.timestamp`, `msg.value`, `msg.sender`, ...
+        ExprKind::Member(base, member) => {
+            if let ExprKind::Ident([Res::Builtin(builtin), ..]) = &base.kind {
+                let ty = match (builtin.name().as_str(), member.as_str()) {
+                    ("block", "basefee" | "blobbasefee" | "chainid" | "difficulty")
+                    | ("block", "gaslimit" | "number" | "prevrandao" | "timestamp")
+                    | ("msg", "value")
+                    | ("tx", "gasprice") => ElementaryType::UInt(TypeSize::new_int_bits(256)),
+                    ("block", "coinbase") => ElementaryType::Address(true),
+                    ("msg", "sender") | ("tx", "origin") => ElementaryType::Address(false),
+                    ("msg", "sig") => ElementaryType::FixedBytes(TypeSize::new_fb_bytes(4)),
+                    ("msg", "data") => ElementaryType::Bytes,
+                    _ => return None,
+                };
+                return track(ty);
+            }
+            None
+        }
+
+        // Index accesses on arrays and mappings of elementary types: `values[i]`
+        ExprKind::Index(base, _) => {
+            if let ExprKind::Ident(resolutions) = &base.kind
+                && let Some(Res::Item(ItemId::Variable(var_id))) = resolutions.first()
+            {
+                let elem_type = match &hir.variable(*var_id).ty.kind {
+                    TypeKind::Array(array) => &array.element.kind,
+                    TypeKind::Mapping(mapping) => &mapping.value.kind,
+                    _ => return None,
+                };
+                if let TypeKind::Elementary(elem_type) = elem_type {
+                    return track(*elem_type);
+                }
+            }
+            None
+        }
+
+        // Conditional expressions: both branches may be the source.
+        ExprKind::Ternary(_, then_expr, else_expr) => {
+            if let Some(mut output) = output {
+                infer_source_types(Some(&mut output), hir, then_expr);
+                infer_source_types(Some(&mut output), hir, else_expr);
+            }
+            None
+        }
+
+        // Parenthesized expressions: `(a + b)`
+        ExprKind::Tuple([Some(inner)]) => infer_source_types(output, hir, inner),
+
         // Handle literal values
         ExprKind::Lit(hir::Lit { kind, .. }) => match kind {
             LitKind::Str(StrKind::Hex, ..) => track(ElementaryType::Bytes),
@@ -0,0 +1,138 @@
+use super::UnusedReturn;
+use crate::{
+    linter::{LateLintPass, LintContext},
+    sol::{Severity, SolLint, high::unchecked_calls::is_erc20_transfer_call},
+};
+use solar::{
+    ast::ContractKind,
+    sema::hir::{self, ExprKind, ItemId, Res, TypeKind},
+};
+
+declare_forge_lint!(
+    UNUSED_RETURN,
+    Severity::Med,
+    "unused-return",
+    "return values of function calls should be used",
+    examples: ["router.swap(amountIn);"],
+    config: ["erc20_unchecked_transfer_functions"]
+);
+
+/// The NatSpec tag excluding a function from the lint, for functions whose return value is
+/// informational only.
+const IGNORE_RETURN_TAG: &str = "@custom:ignore-return";
+
+/// Checks that the return values of the calls to functions declaring return values are used.
+///
+/// Only the calls resolved to a function of the project are checked: internal calls, library calls
+/// and calls to contract variables or casts. The ERC20 transfers are reported by
+/// `erc20-unchecked-transfer`, and the low-level calls by `unchecked-call`.
+impl<'hir> LateLintPass<'hir> for UnusedReturn {
+    fn check_stmt(
+        &mut self,
+        ctx: &LintContext,
+        hir: &'hir hir::Hir<'hir>,
+        stmt: &'hir hir::Stmt<'hir>,
+    ) {
+        // Only expression statements discard the returned values.
+        if let hir::StmtKind::Expr(expr) = &stmt.kind
+            && let ExprKind::Call(callee, ..) = &expr.kind
+            && let Some(name) = called_function_name(hir, callee)
+            && !is_erc20_transfer_call(
+                hir,
+                expr,
+                &ctx.config.lint_specific.erc20_unchecked_transfer_functions,
+            )
+        {
+            let candidates = called_functions(hir, callee, expr);
+            if !candidates.is_empty()
+                && candidates.iter().all(|func| !func.returns.is_empty())
+                && !candidates.iter().any(|func| is_ignore_return(ctx, func))
+            {
+                ctx.emit_with_msg(
+                    &UNUSED_RETURN,
+                    expr.span,
+                    format!("return value of `{name}` is not used"),
+                );
+            }
+        }
+    }
+}
+
+/// Returns the name of the called function, for calls to identifiers and members.
+fn called_function_name(hir: &hir::Hir<'_>, callee: &hir::Expr<'_>) -> Option<String> {
+    match &callee.kind {
+        ExprKind::Ident(resolutions) => resolutions.iter().find_map(|res| match res {
+            Res::Item(ItemId::Function(id)) => hir.function(*id).name.map(|name| name.to_string()),
+            _ => None,
+        }),
+        ExprKind::Member(_, member) => Some(member.to_string()),
+        _ => None,
+    }
+}
+
+/// Returns the functions the call can be resolved to, matching the number of arguments.
+fn called_functions<'hir>(
+    hir: &'hir hir::Hir<'hir>,
+    callee: &hir::Expr<'_>,
+    call: &hir::Expr<'_>,
+) -> Vec<&'hir hir::Function<'hir>> {
+    let ExprKind::Call(_, args, _) = &call.kind else { return Vec::new() };
+    let functions: Vec<_> = match &callee.kind {
+        ExprKind::Ident(resolutions) => resolutions
+            .iter()
+            .filter_map(|res| match res {
+                Res::Item(ItemId::Function(id)) => Some(hir.function(*id)),
+                _ => None,
+            })
+            .collect(),
+        ExprKind::Member(base, member) => {
+            let Some(cid) = called_contract(hir, base) else { return Vec::new() };
+            hir.contract(cid)
+                .linearized_bases
+                .iter()
+                .flat_map(|&base| hir.contract(base).all_functions())
+                .map(|id| hir.function(id))
+                .filter(|func| {
+                    func.kind.is_function()
+                        && func.name.is_some_and(|name| name.name == member.name)
+                })
+                .collect()
+        }
+        _ => return Vec::new(),
+    };
+    functions.into_iter().filter(|func| func.parameters.len() == args.len()).collect()
+}
+
+/// Returns the contract called by a member call: a contract variable, a cast to a contract, or a
+/// library.
+fn called_contract(hir: &hir::Hir<'_>, base: &hir::Expr<'_>) -> Option<hir::ContractId> {
+    match &base.peel_parens().kind {
+        ExprKind::Ident([Res::Item(ItemId::Variable(id)), ..]) => match hir.variable(*id).ty.kind {
+            TypeKind::Custom(ItemId::Contract(cid)) => Some(cid),
+            _ => None,
+        },
+        ExprKind::Ident([Res::Item(ItemId::Contract(cid))]) => {
+            (hir.contract(*cid).kind == ContractKind::Library).then_some(*cid)
+        }
+        ExprKind::Call(
+            hir::Expr { kind: ExprKind::Ident([Res::Item(ItemId::Contract(cid))]), .. },
+            ..,
+        ) => Some(*cid),
+        _ => None,
+    }
+}
+
+/// Returns whether the function is documented with the `@custom:ignore-return` NatSpec tag.
+fn is_ignore_return(ctx: &LintContext, func: &hir::Function<'_>) -> bool {
+    let loc = ctx.session().source_map().lookup_char_pos(func.span.lo());
+    // The doc comments are the comment lines right above the function.
+    (0..loc.line.saturating_sub(1))
+        .rev()
+        .map_while(|line| {
+            let text = loc.file.get_line(line)?;
+            let text = text.trim();
+            (text.starts_with("//") || text.starts_with("/*") || text.starts_with('*'))
+                .then(|| text.contains(IGNORE_RETURN_TAG))
+        })
+        .any(|tagged| tagged)
+}
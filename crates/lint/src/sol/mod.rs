@@ -1,7 +1,15 @@
-use crate::linter::{
-    EarlyLintPass, EarlyLintVisitor, LateLintPass, LateLintVisitor, Lint, LintContext, Linter,
-    LinterConfig,
+use crate::{
+    baseline::{Baseline, FileBaseline},
+    cache::{self, CachedLint, LINT_CACHE_FILE, LintCache, SourceHashes},
+    fixes::{FileFixes, Fix},
+    linter::{
+        EarlyLintPass, EarlyLintVisitor, LateLintPass, LateLintVisitor, Lint, LintContext, Linter,
+        LinterConfig, ProjectSymbols,
+    },
+    plugin,
+    report::{FileReport, LintReport},
 };
+use alloy_primitives::B256;
 use foundry_common::{
     comments::{
         Comments,
@@ -9,6 +17,7 @@
     },
     errors::convert_solar_errors,
     sh_warn,
+    version::SHORT_VERSION,
 };
 use foundry_compilers::{ProjectPathsConfig, solc::SolcLanguage};
 use foundry_config::{
@@ -29,8 +38,9 @@
     },
 };
 use std::{
+    collections::{BTreeMap, HashMap},
     path::{Path, PathBuf},
-    sync::{Arc, LazyLock},
+    sync::{Arc, LazyLock, Mutex},
 };
 use thiserror::Error;
 
@@ -44,16 +54,25 @@
 pub mod low;
 pub mod med;
 
-static ALL_REGISTERED_LINTS: LazyLock<Vec<&'static str>> = LazyLock::new(|| {
-    let mut lints = Vec::new();
-    lints.extend_from_slice(high::REGISTERED_LINTS);
-    lints.extend_from_slice(med::REGISTERED_LINTS);
-    lints.extend_from_slice(low::REGISTERED_LINTS);
-    lints.extend_from_slice(info::REGISTERED_LINTS);
-    lints.extend_from_slice(gas::REGISTERED_LINTS);
-    lints.extend_from_slice(codesize::REGISTERED_LINTS);
-    lints.into_iter().map(|lint| lint.id()).collect()
-});
+/// Returns all the registered lints, from the highest severity to the lowest, followed by the
+/// lints of the registered plugins.
+pub fn registered_lints() -> impl Iterator<Item = SolLint> {
+    all_lints().copied()
+}
+
+fn all_lints() -> impl Iterator<Item = &'static SolLint> {
+    [
+        high::REGISTERED_LINTS,
+        med::REGISTERED_LINTS,
+        low::REGISTERED_LINTS,
+        info::REGISTERED_LINTS,
+        gas::REGISTERED_LINTS,
+        codesize::REGISTERED_LINTS,
+    ]
+    .into_iter()
+    .chain(plugin::plugins().into_iter().map(|plugin| plugin.lints()))
+    .flatten()
+}
 
 static DEFAULT_LINT_SPECIFIC_CONFIG: LazyLock<LintSpecificConfig> =
     LazyLock::new(LintSpecificConfig::default);
@@ -70,6 +89,26 @@ pub struct SolidityLinter<'a> {
     with_json_emitter: bool,
     // lint-specific configuration
     lint_specific: &'a LintSpecificConfig,
+    /// The fixes of the linted files, when collected.
+    fixes: Option<Mutex<Vec<FileFixes>>>,
+    /// The reports of the linted files, when collected.
+    reports: Option<Mutex<Vec<FileReport>>>,
+    /// The overridden severities, by lint id.
+    severity_overrides: HashMap<&'static str, Severity>,
+    /// The directory of the lint cache, when caching.
+    cache_dir: Option<PathBuf>,
+    /// The lints which are not reported, when set.
+    baseline: Option<Baseline>,
+}
+
+/// The fixes, reports and lints to cache collected while linting a source file, and the lints
+/// baselined in it.
+#[derive(Default)]
+struct Collected {
+    fixes: Mutex<Vec<Fix>>,
+    reports: Mutex<Vec<LintReport>>,
+    cache: Mutex<Vec<CachedLint>>,
+    baseline: FileBaseline,
 }
 
 impl<'a> SolidityLinter<'a> {
@@ -82,6 +121,11 @@ pub fn new(path_config: ProjectPathsConfig) -> Self {
             lints_excluded: None,
             with_json_emitter: false,
             lint_specific: &DEFAULT_LINT_SPECIFIC_CONFIG,
+            fixes: None,
+            reports: None,
+            severity_overrides: HashMap::new(),
+            cache_dir: None,
+            baseline: None,
         }
     }
 
@@ -110,17 +154,100 @@ pub const fn with_json_emitter(mut self, with: bool) -> Self {
         self
     }
 
+    /// Overrides the severity of the given lints.
+    pub fn with_severity_overrides(
+        mut self,
+        overrides: impl IntoIterator<Item = (SolLint, Severity)>,
+    ) -> Self {
+        self.severity_overrides =
+            overrides.into_iter().map(|(lint, severity)| (lint.id, severity)).collect();
+        self
+    }
+
     pub const fn with_lint_specific(mut self, lint_specific: &'a LintSpecificConfig) -> Self {
         self.lint_specific = lint_specific;
         self
     }
 
+    /// Collects the fixes suggested by the lints, to be retrieved with [`Self::take_fixes`].
+    pub fn with_fixes(mut self, with: bool) -> Self {
+        self.fixes = with.then(Default::default);
+        self
+    }
+
+    /// Takes the fixes collected by the previous runs, sorted by path.
+    pub fn take_fixes(&self) -> Vec<FileFixes> {
+        let Some(fixes) = &self.fixes else { return Vec::new() };
+        let mut fixes = std::mem::take(&mut *fixes.lock().unwrap());
+        fixes.sort_by(|a, b| a.path.cmp(&b.path));
+        fixes
+    }
+
+    /// Collects the reports of the emitted lints, to be retrieved with [`Self::take_reports`].
+    pub fn with_reports(mut self, with: bool) -> Self {
+        self.reports = with.then(Default::default);
+        self
+    }
+
+    /// Takes the reports collected by the previous runs, sorted by path.
+    pub fn take_reports(&self) -> Vec<FileReport> {
+        let Some(reports) = &self.reports else { return Vec::new() };
+        let mut reports = std::mem::take(&mut *reports.lock().unwrap());
+        reports.sort_by(|a, b| a.path.cmp(&b.path));
+        reports
+    }
+
+    /// Caches the emitted lints in `cache_dir`, to only lint the files which changed, or whose
+    /// related sources changed, in the next runs.
+    ///
+    /// The cache is not used when fixes are collected.
+    pub fn with_cache(mut self, cache_dir: Option<PathBuf>) -> Self {
+        self.cache_dir = cache_dir;
+        self
+    }
+
+    /// Does not report the lints of `baseline`, to only report the lints introduced since it was
+    /// written.
+    pub fn with_baseline(mut self, baseline: Option<Baseline>) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Returns the path of the lint cache file, if the cache is used.
+    fn cache_file(&self) -> Option<PathBuf> {
+        let dir = self.cache_dir.as_ref().filter(|_| self.fixes.is_none())?;
+        Some(dir.join(LINT_CACHE_FILE))
+    }
+
+    /// Returns the hash of the config which determines the emitted lints.
+    fn config_hash(&self) -> B256 {
+        let ids = |lints: &Option<Vec<SolLint>>| {
+            lints.as_ref().map(|lints| lints.iter().map(|lint| lint.id).collect::<Vec<_>>())
+        };
+        cache::config_hash(&(
+            &self.severity,
+            ids(&self.lints_included),
+            ids(&self.lints_excluded),
+            self.severity_overrides.iter().collect::<BTreeMap<_, _>>(),
+            self.lint_specific,
+            &self.path_config.tests,
+            &self.path_config.scripts,
+            plugin::plugins().iter().map(|plugin| plugin.name()).collect::<Vec<_>>(),
+        ))
+    }
+
     const fn config(&'a self, inline: &'a InlineConfig<Vec<String>>) -> LinterConfig<'a> {
-        LinterConfig { inline, lint_specific: self.lint_specific }
+        LinterConfig {
+            inline,
+            lint_specific: self.lint_specific,
+            severity_overrides: &self.severity_overrides,
+            path_config: &self.path_config,
+        }
     }
 
     fn include_lint(&self, lint: SolLint) -> bool {
-        self.severity.as_ref().is_none_or(|sev| sev.contains(&lint.severity()))
+        let severity = self.severity_overrides.get(lint.id).copied().unwrap_or(lint.severity);
+        self.severity.as_ref().is_none_or(|sev| sev.contains(&severity))
             && self.lints_included.as_ref().is_none_or(|incl| incl.contains(&lint))
             && !self.lints_excluded.as_ref().is_some_and(|excl| excl.contains(&lint))
     }
@@ -132,39 +259,29 @@ fn process_source_ast<'gcx>(
         path: &Path,
         inline_config: &InlineConfig<Vec<String>>,
         source_file: Option<Arc<SourceFile>>,
+        collected: &Collected,
     ) -> Result<(), diagnostics::ErrorGuaranteed> {
-        // Declare all available passes and lints
+        // Declare the passes with at least one lint included by the linter config
+        let include = |lint: &SolLint| self.include_lint(*lint);
         let mut passes_and_lints = Vec::new();
-        passes_and_lints.extend(high::create_early_lint_passes());
-        passes_and_lints.extend(med::create_early_lint_passes());
-        passes_and_lints.extend(low::create_early_lint_passes());
-        passes_and_lints.extend(info::create_early_lint_passes());
+        passes_and_lints.extend(high::create_early_lint_passes(&include));
+        passes_and_lints.extend(med::create_early_lint_passes(&include));
+        passes_and_lints.extend(low::create_early_lint_passes(&include));
+        passes_and_lints.extend(info::create_early_lint_passes(&include));
+        passes_and_lints.extend(plugin::create_early_lint_passes(&include));
 
         // Do not apply 'gas' and 'codesize' severity rules on tests and scripts
         if !self.path_config.is_test_or_script(path) {
-            passes_and_lints.extend(gas::create_early_lint_passes());
-            passes_and_lints.extend(codesize::create_early_lint_passes());
+            passes_and_lints.extend(gas::create_early_lint_passes(&include));
+            passes_and_lints.extend(codesize::create_early_lint_passes(&include));
         }
 
-        // Filter passes based on linter config
-        let (mut passes, lints): (Vec<Box<dyn EarlyLintPass<'_>>>, Vec<_>) = passes_and_lints
-            .into_iter()
-            .fold((Vec::new(), Vec::new()), |(mut passes, mut ids), (pass, lints)| {
-                let included_ids: Vec<_> = lints
-                    .iter()
-                    .filter_map(|lint| self.include_lint(*lint).then_some(lint.id))
-                    .collect();
-
-                if !included_ids.is_empty() {
-                    passes.push(pass);
-                    ids.extend(included_ids);
-                }
-
-                (passes, ids)
-            });
+        let (mut passes, lints): (Vec<Box<dyn EarlyLintPass<'_>>>, Vec<Vec<_>>) =
+            passes_and_lints.into_iter().unzip();
+        let lints = lints.concat();
 
         // Initialize and run the early lint visitor
-        let ctx = LintContext::new(
+        let mut ctx = LintContext::new(
             sess,
             self.with_description,
             self.with_json_emitter,
@@ -172,6 +289,18 @@ fn process_source_ast<'gcx>(
             lints,
             source_file,
         );
+        if self.fixes.is_some() {
+            ctx = ctx.with_fixes(&collected.fixes);
+        }
+        if self.reports.is_some() {
+            ctx = ctx.with_reports(&collected.reports);
+        }
+        if self.cache_file().is_some() {
+            ctx = ctx.with_cache(&collected.cache);
+        }
+        if self.baseline.is_some() {
+            ctx = ctx.with_baseline(&collected.baseline);
+        }
         let mut early_visitor = EarlyLintVisitor::new(&ctx, &mut passes);
         _ = early_visitor.visit_source_unit(ast);
         early_visitor.post_source_unit(ast);
@@ -182,43 +311,34 @@ fn process_source_ast<'gcx>(
     fn process_source_hir<'gcx>(
         &self,
         gcx: Gcx<'gcx>,
+        project: &ProjectSymbols<'gcx>,
         source_id: hir::SourceId,
         path: &Path,
         inline_config: &InlineConfig<Vec<String>>,
         source_file: Option<Arc<SourceFile>>,
+        collected: &Collected,
     ) -> Result<(), diagnostics::ErrorGuaranteed> {
-        // Declare all available passes and lints
+        // Declare the passes with at least one lint included by the linter config
+        let include = |lint: &SolLint| self.include_lint(*lint);
         let mut passes_and_lints = Vec::new();
-        passes_and_lints.extend(high::create_late_lint_passes());
-        passes_and_lints.extend(med::create_late_lint_passes());
-        passes_and_lints.extend(low::create_late_lint_passes());
-        passes_and_lints.extend(info::create_late_lint_passes());
+        passes_and_lints.extend(high::create_late_lint_passes(&include));
+        passes_and_lints.extend(med::create_late_lint_passes(&include));
+        passes_and_lints.extend(low::create_late_lint_passes(&include));
+        passes_and_lints.extend(info::create_late_lint_passes(&include));
+        passes_and_lints.extend(plugin::create_late_lint_passes(&include));
 
         // Do not apply 'gas' and 'codesize' severity rules on tests and scripts
         if !self.path_config.is_test_or_script(path) {
-            passes_and_lints.extend(gas::create_late_lint_passes());
-            passes_and_lints.extend(codesize::create_late_lint_passes());
+            passes_and_lints.extend(gas::create_late_lint_passes(&include));
+            passes_and_lints.extend(codesize::create_late_lint_passes(&include));
         }
 
-        // Filter passes based on config
-        let (mut passes, lints): (Vec<Box<dyn LateLintPass<'_>>>, Vec<_>) = passes_and_lints
-            .into_iter()
-            .fold((Vec::new(), Vec::new()), |(mut passes, mut ids), (pass, lints)| {
-                let included_ids: Vec<_> = lints
-                    .iter()
-                    .filter_map(|lint| self.include_lint(*lint).then_some(lint.id))
-                    .collect();
-
-                if !included_ids.is_empty() {
-                    passes.push(pass);
-                    ids.extend(included_ids);
-                }
-
-                (passes, ids)
-            });
+        let (mut passes, lints): (Vec<Box<dyn LateLintPass<'_>>>, Vec<Vec<_>>) =
+            passes_and_lints.into_iter().unzip();
+        let lints = lints.concat();
 
         // Run late lint visitor
-        let ctx = LintContext::new(
+        let mut ctx = LintContext::new(
             gcx.sess,
             self.with_description,
             self.with_json_emitter,
@@ -226,13 +346,59 @@ fn process_source_hir<'gcx>(
             lints,
             source_file,
         );
+        if self.fixes.is_some() {
+            ctx = ctx.with_fixes(&collected.fixes);
+        }
+        if self.reports.is_some() {
+            ctx = ctx.with_reports(&collected.reports);
+        }
+        if self.cache_file().is_some() {
+            ctx = ctx.with_cache(&collected.cache);
+        }
+        if self.baseline.is_some() {
+            ctx = ctx.with_baseline(&collected.baseline);
+        }
         let mut late_visitor = LateLintVisitor::new(&ctx, &mut passes, &gcx.hir);
 
         // Visit this specific source
         let _ = late_visitor.visit_nested_source(source_id);
+        late_visitor.post_source(project, source_id);
 
         Ok(())
     }
+
+    /// Emits the lints cached by a previous run on the source file.
+    fn replay_source(
+        &self,
+        sess: &Session,
+        inline_config: &InlineConfig<Vec<String>>,
+        source_file: Option<Arc<SourceFile>>,
+        collected: &Collected,
+        cached: &[CachedLint],
+    ) {
+        let lints = cached
+            .iter()
+            .filter_map(|cached| SolLint::find(&cached.id).map(|lint| (lint, cached)))
+            .collect::<Vec<_>>();
+        let mut ctx = LintContext::new(
+            sess,
+            self.with_description,
+            self.with_json_emitter,
+            self.config(inline_config),
+            lints.iter().map(|(lint, _)| lint.id).collect(),
+            source_file,
+        );
+        if self.reports.is_some() {
+            ctx = ctx.with_reports(&collected.reports);
+        }
+        ctx = ctx.with_cache(&collected.cache);
+        if self.baseline.is_some() {
+            ctx = ctx.with_baseline(&collected.baseline);
+        }
+        for (lint, cached) in lints {
+            ctx.replay(lint, cached);
+        }
+    }
 }
 
 impl<'a> Linter for SolidityLinter<'a> {
@@ -275,6 +441,18 @@ fn lint(
 
             let gcx = compiler.gcx();
 
+            // Built once, as it is shared by the late passes of all the sources.
+            let project = ProjectSymbols::new(&gcx.hir);
+
+            let cache_file = self.cache_file();
+            let cache = cache_file.as_ref().map(|file| {
+                let cache = LintCache::read(file, SHORT_VERSION, self.config_hash());
+                (Mutex::new(cache), SourceHashes::new(&gcx.hir))
+            });
+
+            // Including the lints of the plugins, which are registered at runtime.
+            let lint_ids = registered_lints().map(|lint| lint.id()).collect::<Vec<_>>();
+
             input.par_iter().for_each(|path| {
                 let path = &self.path_config.root.join(path);
                 let Some((_, ast_source)) = gcx.get_ast_source(path) else {
@@ -290,30 +468,78 @@ fn lint(
                 // Parse inline config.
                 let file = &ast_source.file;
                 let comments = Comments::new(file, gcx.sess.source_map(), false, false, None);
-                let inline_config = parse_inline_config(gcx.sess, &comments, ast);
-
-                // Early lints.
-                let _ = self.process_source_ast(
-                    gcx.sess,
-                    ast,
-                    path,
-                    &inline_config,
-                    Some(file.clone()),
-                );
-
-                // Late lints.
+                let inline_config = parse_inline_config(gcx.sess, &comments, ast, &lint_ids);
+
+                // Fixes and reports are only collected when requested.
+                let mut collected = Collected::default();
+                if let Some(baseline) = &self.baseline {
+                    collected.baseline = baseline.for_file(&self.path_config.root, path);
+                }
+
                 let Some((hir_source_id, _)) = gcx.get_hir_source(path) else {
                     panic!("HIR source not found for {}", path.display());
                 };
-                let _ = self.process_source_hir(
-                    gcx,
-                    hir_source_id,
-                    path,
-                    &inline_config,
-                    Some(file.clone()),
-                );
+                let hash = cache.as_ref().map(|(_, hashes)| hashes.hash(hir_source_id));
+                let cached = cache.as_ref().zip(hash).and_then(|((cache, _), hash)| {
+                    cache.lock().unwrap().get(path, hash).map(<[_]>::to_vec)
+                });
+
+                if let Some(cached) = cached {
+                    // Unchanged since the previous run.
+                    self.replay_source(
+                        gcx.sess,
+                        &inline_config,
+                        Some(file.clone()),
+                        &collected,
+                        &cached,
+                    );
+                } else {
+                    // Early lints.
+                    let _ = self.process_source_ast(
+                        gcx.sess,
+                        ast,
+                        path,
+                        &inline_config,
+                        Some(file.clone()),
+                        &collected,
+                    );
+
+                    // Late lints.
+                    let _ = self.process_source_hir(
+                        gcx,
+                        &project,
+                        hir_source_id,
+                        path,
+                        &inline_config,
+                        Some(file.clone()),
+                        &collected,
+                    );
+                }
+
+                if let (Some((cache, _)), Some(hash)) = (&cache, hash) {
+                    let lints = collected.cache.into_inner().unwrap();
+                    cache.lock().unwrap().insert(path.clone(), hash, lints);
+                }
+
+                let fixes = collected.fixes.into_inner().unwrap();
+                if let Some(collected) = &self.fixes
+                    && !fixes.is_empty()
+                {
+                    let file = FileFixes::new(path.clone(), file.src.to_string(), fixes);
+                    collected.lock().unwrap().push(file);
+                }
+                let lints = collected.reports.into_inner().unwrap();
+                if let Some(collected) = &self.reports
+                    && !lints.is_empty()
+                {
+                    collected.lock().unwrap().push(FileReport::new(path.clone(), lints));
+                }
             });
 
+            if let (Some(file), Some((cache, _))) = (&cache_file, cache) {
+                cache.into_inner().unwrap().write(file)?;
+            }
+
             convert_solar_errors(compiler.dcx())
         })?;
 
@@ -358,6 +584,7 @@ fn parse_inline_config<'ast>(
     sess: &Session,
     comments: &Comments,
     ast: &'ast ast::SourceUnit<'ast>,
+    lint_ids: &[&str],
 ) -> InlineConfig<Vec<String>> {
     let items = comments.iter().filter_map(|comment| {
         let mut item = comment.lines.first()?.as_str();
@@ -369,7 +596,7 @@ fn parse_inline_config<'ast>(
         }
         let item = item.trim_start().strip_prefix("forge-lint:")?.trim();
         let span = comment.span;
-        match InlineConfigItem::parse(item, &ALL_REGISTERED_LINTS) {
+        match InlineConfigItem::parse(item, lint_ids) {
             Ok(item) => Some((span, item)),
             Err(e) => {
                 sess.dcx.warn(e.to_string()).span(span).emit();
@@ -393,6 +620,37 @@ pub struct SolLint {
     description: &'static str,
     help: &'static str,
     severity: Severity,
+    examples: &'static [&'static str],
+    config: &'static [&'static str],
+}
+
+impl SolLint {
+    /// Creates the metadata of a lint declared outside of forge-lint, e.g. by a
+    /// [`LintPlugin`](crate::plugin::LintPlugin).
+    pub const fn new(
+        id: &'static str,
+        severity: Severity,
+        description: &'static str,
+        help: &'static str,
+    ) -> Self {
+        Self { id, description, help, severity, examples: &[], config: &[] }
+    }
+
+    /// Sets the snippets of Solidity code flagged by the lint.
+    pub const fn with_examples(mut self, examples: &'static [&'static str]) -> Self {
+        self.examples = examples;
+        self
+    }
+
+    /// Returns snippets of Solidity code flagged by the lint.
+    pub const fn examples(&self) -> &'static [&'static str] {
+        self.examples
+    }
+
+    /// Returns the config keys read by the lint, e.g. `lint_specific.mixed_case_exceptions`.
+    pub const fn config_keys(&self) -> &'static [&'static str] {
+        self.config
+    }
 }
 
 impl Lint for SolLint {
@@ -410,46 +668,19 @@ fn help(&self) -> &'static str {
     }
 }
 
+impl SolLint {
+    /// Returns the registered lint with the given id.
+    pub fn find(id: &str) -> Option<&'static Self> {
+        all_lints().find(|lint| lint.id == id)
+    }
+}
+
 impl<'a> TryFrom<&'a str> for SolLint {
     type Error = SolLintError;
 
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        for &lint in high::REGISTERED_LINTS {
-            if lint.id() == value {
-                return Ok(lint);
-            }
-        }
-
-        for &lint in med::REGISTERED_LINTS {
-            if lint.id() == value {
-                return Ok(lint);
-            }
-        }
-
-        for &lint in low::REGISTERED_LINTS {
-            if lint.id() == value {
-                return Ok(lint);
-            }
-        }
-
-        for &lint in info::REGISTERED_LINTS {
-            if lint.id() == value {
-                return Ok(lint);
-            }
-        }
-
-        for &lint in gas::REGISTERED_LINTS {
-            if lint.id() == value {
-                return Ok(lint);
-            }
-        }
-
-        for &lint in codesize::REGISTERED_LINTS {
-            if lint.id() == value {
-                return Ok(lint);
-            }
-        }
-
-        Err(SolLintError::InvalidId(value.to_string()))
+        registered_lints()
+            .find(|lint| lint.id() == value)
+            .ok_or_else(|| SolLintError::InvalidId(value.to_string()))
     }
 }
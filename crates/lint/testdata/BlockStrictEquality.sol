@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.18;
+
+contract BlockStrictEquality {
+    uint256 public deadline;
+    bytes32 public seed;
+
+    // SHOULD FAIL:
+
+    function timestampEq() public view returns (bool) {
+        return block.timestamp == deadline; //~WARN: strict equality on `block.timestamp` or `blockhash` is unlikely to hold
+    }
+
+    function timestampNe() public view returns (bool) {
+        return block.timestamp != deadline; //~WARN: strict equality on `block.timestamp` or `blockhash` is unlikely to hold
+    }
+
+    function timestampOnRight() public view returns (bool) {
+        return deadline == block.timestamp; //~WARN: strict equality on `block.timestamp` or `blockhash` is unlikely to hold
+    }
+
+    function timestampInArithmetic() public view returns (bool) {
+        return block.timestamp % 2 == 0; //~WARN: strict equality on `block.timestamp` or `blockhash` is unlikely to hold
+    }
+
+    function blockhashEq(uint256 n) public view returns (bool) {
+        return blockhash(n) == seed; //~WARN: strict equality on `block.timestamp` or `blockhash` is unlikely to hold
+    }
+
+    function blockhashInRequire(bytes32 guess) public view {
+        require(guess == blockhash(block.number - 1)); //~WARN: strict equality on `block.timestamp` or `blockhash` is unlikely to hold
+    }
+
+    // SHOULD PASS:
+
+    function timestampRange() public view returns (bool) {
+        return block.timestamp >= deadline; //~WARN: usage of `block.timestamp` in a comparison may be manipulated by validators
+    }
+
+    function blockNumberEq() public view returns (bool) {
+        return block.number == 100;
+    }
+
+    function storeBlockhash(uint256 n) public {
+        seed = blockhash(n);
+    }
+}
@@ -11,11 +11,11 @@ contract BlockTimestamp {
     }
 
     function comparisonEq() public view returns (bool) {
-        return block.timestamp == 0; //~WARN: usage of `block.timestamp` in a comparison may be manipulated by validators
+        return block.timestamp == 0; //~WARN: strict equality on `block.timestamp` or `blockhash` is unlikely to hold
     }
 
     function comparisonNe() public view returns (bool) {
-        return block.timestamp != 0; //~WARN: usage of `block.timestamp` in a comparison may be manipulated by validators
+        return block.timestamp != 0; //~WARN: strict equality on `block.timestamp` or `blockhash` is unlikely to hold
     }
 
     function comparisonLe() public view returns (bool) {
@@ -39,7 +39,7 @@ contract BlockTimestamp {
     }
 
     function timestampInComplexExpr() public view returns (bool) {
-        return (block.timestamp / 3600) == 0; //~WARN: usage of `block.timestamp` in a comparison may be manipulated by validators
+        return (block.timestamp / 3600) == 0; //~WARN: strict equality on `block.timestamp` or `blockhash` is unlikely to hold
     }
 
     function inRequire() public view {
@@ -0,0 +1,55 @@
+//@compile-flags: --only-lint cache-array-length
+
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.18;
+
+contract CacheArrayLength {
+    uint256[] public values;
+    address[] public owners;
+    uint256 public total;
+
+    // SHOULD FAIL:
+
+    function sum() external {
+        for (uint256 i; i < values.length; ++i) { //~NOTE: cache the length of the storage array outside of the loop
+            total += values[i];
+        }
+    }
+
+    function nested() external view returns (uint256 count) {
+        for (uint256 i; i < owners.length; ++i) { //~NOTE: cache the length of the storage array outside of the loop
+            for (uint256 j; j < values.length && j < 10; ++j) { //~NOTE: cache the length of the storage array outside of the loop
+                count += 1;
+            }
+        }
+    }
+
+    // SHOULD PASS:
+
+    function cached() external {
+        uint256 length = values.length;
+        for (uint256 i; i < length; ++i) {
+            total += values[i];
+        }
+    }
+
+    function resized() external {
+        for (uint256 i; i < values.length; ++i) {
+            if (values[i] == 0) {
+                values.pop();
+            }
+        }
+    }
+
+    function memoryArray(uint256[] memory items) external pure returns (uint256 count) {
+        for (uint256 i; i < items.length; ++i) {
+            count += items[i];
+        }
+    }
+
+    function shadowed(uint256[] calldata values) external pure returns (uint256 count) {
+        for (uint256 i; i < values.length; ++i) {
+            count += values[i];
+        }
+    }
+}
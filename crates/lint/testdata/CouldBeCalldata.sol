@@ -0,0 +1,42 @@
+//@compile-flags: --only-lint could-be-calldata
+
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.18;
+
+contract CouldBeCalldata {
+    uint256 public total;
+
+    // SHOULD FAIL:
+
+    function sum(uint256[] memory values) external { //~NOTE: read-only parameter of an external function could be declared calldata instead of memory
+        for (uint256 i; i < values.length; ++i) {
+            total += values[i];
+        }
+    }
+
+    function hash(bytes memory data, string memory label) external pure returns (bytes32) {
+        //~^NOTE: read-only parameter of an external function could be declared calldata instead of memory
+        //~|NOTE: read-only parameter of an external function could be declared calldata instead of memory
+        return keccak256(abi.encode(data, label));
+    }
+
+    // SHOULD PASS:
+
+    function alreadyCalldata(bytes calldata data) external pure returns (bytes32) {
+        return keccak256(data);
+    }
+
+    function modified(uint256[] memory values) external pure returns (uint256[] memory) {
+        values[0] = 1;
+        return values;
+    }
+
+    function reassigned(bytes memory data) external pure returns (bytes memory) {
+        data = "";
+        return data;
+    }
+
+    function publicFunction(bytes memory data) public pure returns (bytes32) {
+        return keccak256(data);
+    }
+}
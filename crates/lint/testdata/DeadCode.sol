@@ -0,0 +1,64 @@
+//@compile-flags: --only-lint dead-code
+
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.18;
+
+library Math {
+    function max(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a > b ? a : b;
+    }
+
+    function min(uint256 a, uint256 b) internal pure returns (uint256) { //~NOTE: function is never used
+        return a < b ? a : b;
+    }
+}
+
+function double(uint256 value) pure returns (uint256) {
+    return value * 2;
+}
+
+function triple(uint256 value) pure returns (uint256) { //~NOTE: function is never used
+    return value * 3;
+}
+
+contract DeadCode {
+    using Math for uint256;
+
+    event Used(uint256 value);
+    event Unused(uint256 value); //~NOTE: event is never used
+
+    uint256 public value;
+    uint256 public immutable initial = _initialValue();
+
+    modifier onlyPositive(uint256 amount) {
+        require(amount > 0);
+        _;
+    }
+
+    modifier onlyOwner() { //~NOTE: modifier is never used
+        _;
+    }
+
+    function set(uint256 amount) external onlyPositive(amount) {
+        value = _clamp(amount);
+        emit Used(value);
+    }
+
+    function _clamp(uint256 amount) private view returns (uint256) {
+        return double(amount).max(value);
+    }
+
+    function _initialValue() private pure returns (uint256) {
+        return 1;
+    }
+
+    function _unused() internal {} //~NOTE: function is never used
+
+    function _unusedChain() private { //~NOTE: function is never used
+        _unusedHelper();
+    }
+
+    function _unusedHelper() private {} //~NOTE: function is never used
+
+    function _hook() internal virtual {}
+}
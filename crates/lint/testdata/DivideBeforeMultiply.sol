@@ -2,6 +2,8 @@
 pragma solidity ^0.8.18;
 
 contract DivideBeforeMultiply {
+    using SafeMath for uint256;
+
     function arithmetic() public {
         (1 / 2) * 3; //~WARN: multiplication should occur before division to avoid loss of precision
         (1 * 2) / 3;
@@ -18,4 +20,19 @@ contract DivideBeforeMultiply {
         1 / ((2 / 3) * 3); //~WARN: multiplication should occur before division to avoid loss of precision
         1 / ((2 * 3) + 3);
     }
+
+    function safeMath(uint256 a, uint256 b, uint256 c) public pure {
+        a.div(b).mul(c); //~WARN: multiplication should occur before division to avoid loss of precision
+        a.mul(c).div(b);
+    }
+}
+
+library SafeMath {
+    function mul(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a * b;
+    }
+
+    function div(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a / b;
+    }
 }
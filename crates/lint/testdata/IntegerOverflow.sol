@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+pragma solidity >=0.7.0;
+
+contract IntegerOverflow {
+    uint256 public total;
+    uint256 public constant MAX = 2 ** 128;
+
+    // SHOULD FAIL:
+
+    function add(uint256 a, uint256 b) public pure returns (uint256) {
+        return a + b; //~WARN: arithmetic is unchecked before Solidity 0.8 and may overflow; use SafeMath or a 0.8 compiler
+    }
+
+    function sub(uint256 a, uint256 b) public pure returns (uint256) {
+        return a - b; //~WARN: arithmetic is unchecked before Solidity 0.8 and may overflow; use SafeMath or a 0.8 compiler
+    }
+
+    function mul(uint256 a, uint256 b) public pure returns (uint256) {
+        return a * b; //~WARN: arithmetic is unchecked before Solidity 0.8 and may overflow; use SafeMath or a 0.8 compiler
+    }
+
+    function pow(uint256 a) public pure returns (uint256) {
+        return a ** 2; //~WARN: arithmetic is unchecked before Solidity 0.8 and may overflow; use SafeMath or a 0.8 compiler
+    }
+
+    function deposit(uint256 amount) public {
+        total += amount; //~WARN: arithmetic is unchecked before Solidity 0.8 and may overflow; use SafeMath or a 0.8 compiler
+    }
+
+    function withdraw() public {
+        total -= 1; //~WARN: arithmetic is unchecked before Solidity 0.8 and may overflow; use SafeMath or a 0.8 compiler
+    }
+
+    // SHOULD PASS:
+
+    function div(uint256 a, uint256 b) public pure returns (uint256) {
+        return a / b;
+    }
+
+    function compare(uint256 a, uint256 b) public pure returns (bool) {
+        return a < b;
+    }
+}
+
+library SafeMath {
+    function add(uint256 a, uint256 b) internal pure returns (uint256 c) {
+        c = a + b;
+        require(c >= a, "SafeMath: addition overflow");
+    }
+}
+
+contract UsesSafeMath {
+    using SafeMath for uint256;
+
+    uint256 public total;
+
+    function deposit(uint256 amount) public {
+        total = total.add(amount);
+        total += amount;
+    }
+}
@@ -9,6 +9,16 @@ contract MissingZeroCheck {
     address public owner;
     address payable public recipient;
     uint256 public n;
+    IExternal public token;
+
+    struct Config {
+        address treasury;
+        uint256 fee;
+    }
+
+    Config public config;
+    address[] public operators;
+    mapping(address => bool) public isOperator;
 
     modifier nonZero(address a) {
         require(a != address(0), "zero");
@@ -143,11 +153,42 @@ contract MissingZeroCheck {
         } catch {
             require(a != address(0));
         }
-        owner = a;
+        owner = a; //~WARN: state is written after an external call, which can lead to reentrancy
+    }
+
+    function setToken(address a) external { //~WARN: address parameter is used in a state write or value transfer without a zero-address check
+        token = IExternal(a);
+    }
+
+    function setTreasury(address a) external { //~WARN: address parameter is used in a state write or value transfer without a zero-address check
+        config.treasury = a;
+    }
+
+    function setOperatorAt(address a, uint256 i) external { //~WARN: address parameter is used in a state write or value transfer without a zero-address check
+        operators[i] = a;
+    }
+
+    function addOperator(address a) external { //~WARN: address parameter is used in a state write or value transfer without a zero-address check
+        operators.push(a);
     }
 
     // SHOULD PASS:
 
+    function setTokenGuarded(address a) external {
+        require(a != address(0));
+        token = IExternal(a);
+    }
+
+    function setTreasuryGuarded(address a) external {
+        if (a == address(0)) revert ZeroAddress();
+        config.treasury = a;
+    }
+
+    // The parameter is only used as a key, not stored.
+    function allowOperator(address a) external {
+        isOperator[a] = true;
+    }
+
     function setOwnerGuarded(address newOwner) external {
         require(newOwner != address(0), "zero");
         owner = newOwner;
@@ -26,7 +26,7 @@ contract MixedCaseTest {
     }
 
     function functionMixedCase() public {}
-    function _functionMixedCase() internal {}
+    function _functionMixedCase() internal {} //~NOTE: function is never used
     function functionmixedcase() public {}
 
     function Functionmixedcase() public {} //~NOTE: function names should use mixedCase
@@ -0,0 +1,33 @@
+//@compile-flags: --only-lint pre-increment
+
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.18;
+
+contract PreIncrement {
+    function loops(uint256 n) external pure returns (uint256 total) {
+        // SHOULD FAIL:
+
+        for (uint256 i; i < n; i++) { //~NOTE: prefer the prefix increment and decrement in loop updates
+            total += i;
+        }
+
+        for (uint256 i = n; i > 0; i--) { //~NOTE: prefer the prefix increment and decrement in loop updates
+            total += i;
+        }
+
+        // SHOULD PASS:
+
+        for (uint256 i; i < n; ++i) {
+            total += i;
+        }
+
+        for (uint256 i; i < n; i += 2) {
+            total += i;
+        }
+
+        uint256 j;
+        while (j < n) {
+            j++;
+        }
+    }
+}
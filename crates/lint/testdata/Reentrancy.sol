@@ -0,0 +1,134 @@
+//@compile-flags: --only-lint reentrancy
+
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.18;
+
+interface IVault {
+    function deposit(uint256 amount) external;
+    function totalAssets() external view returns (uint256);
+}
+
+interface IERC20 {
+    function transfer(address to, uint256 amount) external returns (bool);
+    function transferFrom(address from, address to, uint256 amount) external returns (bool);
+}
+
+contract Reentrancy {
+    struct Account {
+        uint256 balance;
+        uint256 nonce;
+    }
+
+    IVault public vault;
+    IERC20 public token;
+    mapping(address => uint256) public balances;
+    mapping(address => Account) public accounts;
+    uint256 public total;
+    bool private locked;
+
+    modifier nonReentrant() {
+        require(!locked);
+        locked = true;
+        _;
+        locked = false;
+    }
+
+    // SHOULD FAIL:
+
+    function withdraw(uint256 amount) external {
+        (bool ok,) = msg.sender.call{value: amount}("");
+        require(ok);
+        balances[msg.sender] -= amount; //~WARN: state is written after an external call, which can lead to reentrancy
+    }
+
+    function deposit(uint256 amount) external {
+        vault.deposit(amount);
+        total += amount; //~WARN: state is written after an external call, which can lead to reentrancy
+    }
+
+    function depositCast(address target, uint256 amount) external {
+        IVault(target).deposit(amount);
+        delete balances[msg.sender]; //~WARN: state is written after an external call, which can lead to reentrancy
+    }
+
+    function storageReference(uint256 amount) external {
+        Account storage account = accounts[msg.sender];
+        vault.deposit(amount);
+        account.nonce++; //~WARN: state is written after an external call, which can lead to reentrancy
+    }
+
+    function conditionalCall(uint256 amount, bool flag) external {
+        if (flag) {
+            vault.deposit(amount);
+        }
+        total = amount; //~WARN: state is written after an external call, which can lead to reentrancy
+    }
+
+    function callInLoop(uint256 amount, uint256 n) external {
+        for (uint256 i = 0; i < n; i++) {
+            total += amount; //~WARN: state is written after an external call, which can lead to reentrancy
+            vault.deposit(amount);
+        }
+    }
+
+    function internalCall(uint256 amount) external {
+        _deposit(amount);
+        total += amount; //~WARN: state is written after an external call, which can lead to reentrancy
+    }
+
+    function internalWrite(uint256 amount) external {
+        vault.deposit(amount);
+        _credit(amount); //~WARN: state is written after an external call, which can lead to reentrancy
+    }
+
+    // SHOULD PASS:
+
+    function checksEffectsInteractions(uint256 amount) external {
+        balances[msg.sender] -= amount;
+        (bool ok,) = msg.sender.call{value: amount}("");
+        require(ok);
+    }
+
+    function guarded(uint256 amount) external nonReentrant {
+        vault.deposit(amount);
+        total += amount;
+    }
+
+    function viewCall() external {
+        total = vault.totalAssets();
+        total += 1;
+    }
+
+    function storeResult(address target) external {
+        (locked,) = target.call("");
+    }
+
+    function transferEther(uint256 amount) external {
+        payable(msg.sender).transfer(amount);
+        total -= amount;
+    }
+
+    function erc20Transfer(address to, uint256 amount) external {
+        if (token.transfer(to, amount)) {
+            balances[to] += amount;
+        }
+        require(token.transferFrom(msg.sender, address(this), amount));
+        total += amount;
+    }
+
+    function exitingBranch(uint256 amount, bool flag) external {
+        if (flag) {
+            vault.deposit(amount);
+            return;
+        }
+        total = amount;
+    }
+
+    function _deposit(uint256 amount) internal {
+        vault.deposit(amount);
+    }
+
+    function _credit(uint256 amount) internal {
+        balances[msg.sender] += amount;
+    }
+}
@@ -0,0 +1,35 @@
+//@compile-flags: --only-lint storage-packing
+
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.18;
+
+// SHOULD FAIL:
+
+contract Unpacked { //~NOTE: state variables could be reordered to use 2 storage slots instead of 3
+    bool public paused;
+    uint256 public total;
+    address public owner;
+}
+
+contract UnpackedSmallTypes { //~NOTE: state variables could be reordered to use 3 storage slots instead of 4
+    uint128 public a;
+    uint256 public b;
+    uint128 public c;
+    mapping(address => uint256) public balances;
+}
+
+// SHOULD PASS:
+
+contract Packed {
+    uint256 public total;
+    address public owner;
+    bool public paused;
+    uint256 public constant MAX = 1;
+    uint64 public immutable deployedAt = uint64(block.timestamp);
+}
+
+contract FullSlots {
+    uint256 public a;
+    bytes32 public b;
+    mapping(address => uint256) public balances;
+}
@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.18;
+
+contract TxOrigin {
+    address public owner;
+
+    modifier onlyOwner() {
+        require(tx.origin == owner, "not owner"); //~WARN: `tx.origin` should not be used for authorization
+        _;
+    }
+
+    // SHOULD FAIL:
+
+    function withdraw() public {
+        if (owner != tx.origin) revert(); //~WARN: `tx.origin` should not be used for authorization
+    }
+
+    function isOwner() public view returns (bool) {
+        return (tx.origin) == owner; //~WARN: `tx.origin` should not be used for authorization
+    }
+
+    // SHOULD PASS:
+
+    function onlyEOA() public view returns (bool) {
+        return tx.origin == msg.sender;
+    }
+
+    function onlyEOAReversed() public view returns (bool) {
+        return msg.sender == tx.origin;
+    }
+
+    function authorizedCaller() public view returns (bool) {
+        return msg.sender == owner;
+    }
+
+    function origin() public view returns (address) {
+        return tx.origin;
+    }
+}
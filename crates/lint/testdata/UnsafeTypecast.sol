@@ -443,7 +443,7 @@ contract Repros {
         bytes memory stringToBytes = bytes("Initializable: contract is already initialized");
     }
 
-    function nestedCastsAreEvaluatedAtAllDepths(uint64 a, int128 b) internal pure returns (uint64) {
+    function nestedCastsAreEvaluatedAtAllDepths(uint64 a, int128 b) internal pure returns (uint64) { //~NOTE: function is never used
         uint64 aAloneIsSafe = uint64(uint128(int128(uint128(a))));
 
         uint128 aPlusB = uint128(int128(uint128(a)) + b);
@@ -456,5 +456,26 @@ contract Repros {
         //~^WARN: typecasts that can truncate values should be checked
         //~|WARN: typecasts that can truncate values should be checked
     }
+
+    mapping(address => uint256) internal balances;
+    uint256[] internal values;
+
+    function getValue() internal pure returns (uint256) { //~NOTE: function is never used
+        return 1;
+    }
+
+    function inferredSourceTypesAreChecked(bool flag, uint128 a, uint64 b) internal view { //~NOTE: function is never used
+        uint64 timestamp = uint64(block.timestamp); //~WARN: typecasts that can truncate values should be checked
+        uint128 value = uint128(msg.value); //~WARN: typecasts that can truncate values should be checked
+        uint160 sender = uint160(msg.sender);
+        uint96 origin = uint96(uint160(tx.origin)); //~WARN: typecasts that can truncate values should be checked
+        bytes2 selector = bytes2(msg.sig); //~WARN: typecasts that can truncate values should be checked
+        uint8 returned = uint8(getValue()); //~WARN: typecasts that can truncate values should be checked
+        uint32 balance = uint32(balances[msg.sender]); //~WARN: typecasts that can truncate values should be checked
+        int256 element = int256(values[0]); //~WARN: typecasts that can truncate values should be checked
+        uint64 either = uint64(flag ? a : b); //~WARN: typecasts that can truncate values should be checked
+        uint64 parenthesized = uint64((a)); //~WARN: typecasts that can truncate values should be checked
+        uint256 number = uint256(block.number);
+    }
 }
 // forge-lint: disable-end(mixed-case-variable)
@@ -0,0 +1,77 @@
+//@compile-flags: --only-lint unused-return
+
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.18;
+
+interface IERC20 {
+    function transfer(address to, uint256 amount) external returns (bool);
+    function approve(address spender, uint256 amount) external returns (bool);
+}
+
+interface IPool {
+    function swap(uint256 amountIn) external returns (uint256 amountOut);
+    function sync() external;
+    /// @notice Returns the previous fee, for information only.
+    /// @custom:ignore-return
+    function setFee(uint256 fee) external returns (uint256 previous);
+}
+
+library Math {
+    function max(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a > b ? a : b;
+    }
+}
+
+contract UnusedReturn {
+    IERC20 public token;
+    IPool public pool;
+
+    // SHOULD FAIL:
+
+    function externalCall(uint256 amount) external {
+        pool.swap(amount); //~WARN: return value of `swap` is not used
+    }
+
+    function castCall(address target, uint256 amount) external {
+        IPool(target).swap(amount); //~WARN: return value of `swap` is not used
+    }
+
+    function approveCall(address spender, uint256 amount) external {
+        token.approve(spender, amount); //~WARN: return value of `approve` is not used
+    }
+
+    function internalCall(uint256 amount) external {
+        _compute(amount); //~WARN: return value of `_compute` is not used
+    }
+
+    function libraryCall(uint256 a, uint256 b) external pure {
+        Math.max(a, b); //~WARN: return value of `max` is not used
+    }
+
+    // SHOULD PASS:
+
+    function usedReturn(uint256 amount) external returns (uint256) {
+        uint256 out = pool.swap(amount);
+        return out + _compute(amount);
+    }
+
+    function noReturn() external {
+        pool.sync();
+        _update();
+    }
+
+    function ignoredReturn(uint256 fee) external {
+        pool.setFee(fee);
+    }
+
+    function erc20Transfer(address to, uint256 amount) external {
+        // Reported by `erc20-unchecked-transfer`.
+        token.transfer(to, amount);
+    }
+
+    function _compute(uint256 amount) internal pure returns (uint256) {
+        return amount * 2;
+    }
+
+    function _update() internal {}
+}
@@ -50,3 +50,15 @@ contract MultiUnused {
         return usedVar;
     }
 }
+
+// State variables used only by derived contracts must not be flagged.
+contract BaseState {
+    uint256 internal inherited;
+    uint256 internal neverUsed; //~NOTE: state variable is never used
+}
+
+contract DerivedState is BaseState {
+    function set(uint256 v) external {
+        inherited = v;
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{FoundryNetwork, FoundryTransactionRequest, FoundryTxType};
+use alloy_network::TransactionBuilder;
+use alloy_provider::{
+    Provider, SendableTx,
+    fillers::{FillerControlFlow, GasFillable, GasFiller, TxFiller},
+};
+use alloy_transport::TransportResult;
+
+/// A [`TxFiller`] filling the gas fields of a transaction according to its [`FoundryTxType`].
+///
+/// Ethereum transactions are filled like with [`GasFiller`]. Deposit transactions only have a gas
+/// limit, as their gas is bought on L1, and Tempo transactions pay the fixed base fee of the chain
+/// without priority fee, unless set.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FoundryGasFiller;
+
+/// The gas fields filled by the [`FoundryGasFiller`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FoundryGasFillable {
+    /// The gas fields of an Ethereum transaction.
+    Ethereum(GasFillable),
+    /// The gas limit of a deposit transaction.
+    Deposit { gas_limit: u64 },
+    /// The gas fields of a Tempo transaction.
+    Tempo { gas_limit: u64, max_fee_per_gas: u128, max_priority_fee_per_gas: u128 },
+}
+
+impl TxFiller<FoundryNetwork> for FoundryGasFiller {
+    type Fillable = FoundryGasFillable;
+
+    fn status(&self, tx: &FoundryTransactionRequest) -> FillerControlFlow {
+        let finished = match tx.preferred_type() {
+            FoundryTxType::Deposit => tx.gas_limit().is_some(),
+            FoundryTxType::Tempo => {
+                tx.gas_limit().is_some()
+                    && tx.max_fee_per_gas().is_some()
+                    && tx.max_priority_fee_per_gas().is_some()
+            }
+            // Post-exec transactions are built by the sequencer.
+            FoundryTxType::PostExec => true,
+            _ => return TxFiller::<FoundryNetwork>::status(&GasFiller, tx),
+        };
+        if finished { FillerControlFlow::Finished } else { FillerControlFlow::Ready }
+    }
+
+    fn fill_sync(&self, _tx: &mut SendableTx<FoundryNetwork>) {}
+
+    async fn prepare<P: Provider<FoundryNetwork>>(
+        &self,
+        provider: &P,
+        tx: &FoundryTransactionRequest,
+    ) -> TransportResult<Self::Fillable> {
+        match tx.preferred_type() {
+            FoundryTxType::Deposit => {
+                Ok(FoundryGasFillable::Deposit { gas_limit: gas_limit(provider, tx).await? })
+            }
+            FoundryTxType::Tempo => {
+                let gas_limit = gas_limit(provider, tx).await?;
+                let max_fee_per_gas = match tx.max_fee_per_gas() {
+                    Some(max_fee_per_gas) => max_fee_per_gas,
+                    None => provider.get_gas_price().await?,
+                };
+                let max_priority_fee_per_gas = tx.max_priority_fee_per_gas().unwrap_or_default();
+                Ok(FoundryGasFillable::Tempo {
+                    gas_limit,
+                    max_fee_per_gas,
+                    max_priority_fee_per_gas,
+                })
+            }
+            _ => GasFiller.prepare(provider, tx).await.map(FoundryGasFillable::Ethereum),
+        }
+    }
+
+    async fn fill(
+        &self,
+        fillable: Self::Fillable,
+        mut tx: SendableTx<FoundryNetwork>,
+    ) -> TransportResult<SendableTx<FoundryNetwork>> {
+        let Some(builder) = tx.as_mut_builder() else { return Ok(tx) };
+        match fillable {
+            FoundryGasFillable::Ethereum(fillable) => return GasFiller.fill(fillable, tx).await,
+            FoundryGasFillable::Deposit { gas_limit } => builder.set_gas_limit(gas_limit),
+            FoundryGasFillable::Tempo { gas_limit, max_fee_per_gas, max_priority_fee_per_gas } => {
+                builder.set_gas_limit(gas_limit);
+                builder.set_max_fee_per_gas(max_fee_per_gas);
+                builder.set_max_priority_fee_per_gas(max_priority_fee_per_gas);
+            }
+        }
+        Ok(tx)
+    }
+}
+
+/// Returns the gas limit of `tx`, estimating it if unset.
+async fn gas_limit<P: Provider<FoundryNetwork>>(
+    provider: &P,
+    tx: &FoundryTransactionRequest,
+) -> TransportResult<u64> {
+    match tx.gas_limit() {
+        Some(gas_limit) => Ok(gas_limit),
+        None => provider.estimate_gas(tx.clone()).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, B256, U256};
+    use alloy_rpc_types::TransactionRequest;
+    use alloy_serde::{OtherFields, WithOtherFields};
+
+    fn request(fields: &[(&str, serde_json::Value)]) -> FoundryTransactionRequest {
+        let tx = TransactionRequest::default().from(Address::random()).to(Address::random());
+        let other = OtherFields::new(
+            fields.iter().map(|(key, value)| (key.to_string(), value.clone())).collect(),
+        );
+        WithOtherFields { inner: tx, other }.try_into().unwrap()
+    }
+
+    #[test]
+    fn deposit_only_needs_gas_limit() {
+        let mut tx = request(&[
+            ("sourceHash", serde_json::to_value(B256::ZERO).unwrap()),
+            ("mint", serde_json::to_value(U256::ZERO).unwrap()),
+            ("isSystemTx", false.into()),
+        ]);
+        assert_eq!(tx.preferred_type(), FoundryTxType::Deposit);
+        assert!(FoundryGasFiller.status(&tx).is_ready());
+
+        tx.set_gas_limit(21_000);
+        assert!(FoundryGasFiller.status(&tx).is_finished());
+    }
+
+    #[test]
+    fn tempo_needs_gas_limit_and_fees() {
+        let mut tx = request(&[("feeToken", serde_json::to_value(Address::random()).unwrap())]);
+        assert_eq!(tx.preferred_type(), FoundryTxType::Tempo);
+        tx.set_gas_limit(21_000);
+        tx.set_max_fee_per_gas(20_000_000_000);
+        assert!(FoundryGasFiller.status(&tx).is_ready());
+
+        tx.set_max_priority_fee_per_gas(0);
+        assert!(FoundryGasFiller.status(&tx).is_finished());
+    }
+
+    #[test]
+    fn ethereum_is_filled_like_gas_filler() {
+        let mut tx = request(&[]);
+        assert_eq!(tx.preferred_type(), FoundryTxType::Eip1559);
+        tx.set_gas_limit(21_000);
+        assert!(FoundryGasFiller.status(&tx).is_ready());
+
+        tx.set_gas_price(1);
+        assert!(FoundryGasFiller.status(&tx).is_finished());
+    }
+}
@@ -1,12 +1,19 @@
 use alloy_network::Network;
 
+mod gas;
+mod pooled;
 mod receipt;
 
 use alloy_provider::fillers::{
-    BlobGasFiller, ChainIdFiller, GasFiller, JoinFill, NonceFiller, RecommendedFillers,
+    BlobGasFiller, ChainIdFiller, JoinFill, NonceFiller, RecommendedFillers,
 };
+pub use gas::{FoundryGasFillable, FoundryGasFiller};
+pub use pooled::PooledTransactionsExt;
 pub use receipt::*;
 
+/// The transaction response of the [`FoundryNetwork`].
+pub type FoundryTransactionResponse = op_alloy_rpc_types::Transaction<crate::FoundryTxEnvelope>;
+
 /// Foundry network type.
 ///
 /// This network type supports Foundry-specific transaction types, including
@@ -36,7 +43,7 @@ impl Network for FoundryNetwork {
 
     type TransactionRequest = crate::FoundryTransactionRequest;
 
-    type TransactionResponse = op_alloy_rpc_types::Transaction<crate::FoundryTxEnvelope>;
+    type TransactionResponse = FoundryTransactionResponse;
 
     type ReceiptResponse = crate::FoundryTxReceipt;
 
@@ -48,7 +55,7 @@ impl Network for FoundryNetwork {
 
 impl RecommendedFillers for FoundryNetwork {
     type RecommendedFillers =
-        JoinFill<GasFiller, JoinFill<BlobGasFiller, JoinFill<NonceFiller, ChainIdFiller>>>;
+        JoinFill<FoundryGasFiller, JoinFill<BlobGasFiller, JoinFill<NonceFiller, ChainIdFiller>>>;
 
     fn recommended_fillers() -> Self::RecommendedFillers {
         Default::default()
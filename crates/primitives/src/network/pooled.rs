@@ -0,0 +1,51 @@
+use crate::{FoundryNetwork, FoundryTxEnvelope};
+use alloy_network::eip2718::{Decodable2718, Encodable2718};
+use alloy_primitives::TxHash;
+use alloy_provider::{PendingTransactionBuilder, Provider};
+use alloy_transport::{TransportErrorKind, TransportResult};
+
+/// Extension trait for [`FoundryNetwork`] providers to fetch and rebroadcast transactions in their
+/// pooled form, keeping the blob sidecars of EIP-4844 transactions intact.
+pub trait PooledTransactionsExt: Provider<FoundryNetwork> {
+    /// Fetches the transaction with the given hash from `eth_getRawTransactionByHash`.
+    ///
+    /// EIP-4844 transactions only carry their blob sidecar if the endpoint returns the network
+    /// encoding of the transaction, which is usually only the case while it is pending.
+    fn get_pooled_transaction(
+        &self,
+        hash: TxHash,
+    ) -> impl Future<Output = TransportResult<Option<FoundryTxEnvelope>>> + Send;
+
+    /// Broadcasts the network encoding of the transaction with `eth_sendRawTransaction`.
+    ///
+    /// Fails without sending the transaction if it is an EIP-4844 transaction without its blob
+    /// sidecar.
+    fn send_pooled_transaction(
+        &self,
+        tx: &FoundryTxEnvelope,
+    ) -> impl Future<Output = TransportResult<PendingTransactionBuilder<FoundryNetwork>>> + Send;
+}
+
+impl<P: Provider<FoundryNetwork>> PooledTransactionsExt for P {
+    async fn get_pooled_transaction(
+        &self,
+        hash: TxHash,
+    ) -> TransportResult<Option<FoundryTxEnvelope>> {
+        let Some(raw) = self.get_raw_transaction_by_hash(hash).await? else { return Ok(None) };
+        FoundryTxEnvelope::decode_2718(&mut raw.as_ref())
+            .map(Some)
+            .map_err(TransportErrorKind::custom)
+    }
+
+    async fn send_pooled_transaction(
+        &self,
+        tx: &FoundryTxEnvelope,
+    ) -> TransportResult<PendingTransactionBuilder<FoundryNetwork>> {
+        if tx.is_missing_sidecar() {
+            return Err(TransportErrorKind::custom_str(
+                "EIP-4844 transaction is missing its blob sidecar",
+            ));
+        }
+        self.send_raw_transaction(&tx.encoded_2718()).await
+    }
+}
@@ -41,6 +41,17 @@ pub fn with_fee_payer(mut self, fee_payer: Address) -> Self {
     pub fn block_timestamp(&self) -> Option<u64> {
         self.0.other.get_deserialized::<u64>("blockTimestamp").transpose().ok().flatten()
     }
+
+    /// Returns the nonce of the deposit, if this is the receipt of an OP deposit transaction.
+    pub fn deposit_nonce(&self) -> Option<u64> {
+        self.0.inner.inner.deposit_nonce()
+    }
+
+    /// Returns the receipt version of the deposit, if this is the receipt of an OP deposit
+    /// transaction.
+    pub fn deposit_receipt_version(&self) -> Option<u64> {
+        self.0.inner.inner.deposit_receipt_version()
+    }
 }
 
 impl ReceiptResponse for FoundryTxReceipt {
@@ -121,7 +132,7 @@ fn try_from(receipt: AnyTransactionReceipt) -> Result<Self, Self::Error> {
                     blob_gas_used,
                     inner: AnyReceiptEnvelope { inner: receipt_with_bloom, r#type },
                 },
-            other,
+            mut other,
         } = receipt.0;
 
         Ok(Self(WithOtherFields {
@@ -145,21 +156,18 @@ fn try_from(receipt: AnyTransactionReceipt) -> Result<Self, Self::Error> {
                     0x04 => FoundryReceiptEnvelope::Eip7702(receipt_with_bloom),
                     TEMPO_TX_TYPE_ID => FoundryReceiptEnvelope::Tempo(receipt_with_bloom),
                     0x7E => {
-                        // Construct the deposit receipt, extracting optional deposit fields
+                        // Construct the deposit receipt, moving the optional deposit fields out
+                        // of the other fields so that they are not serialized twice.
                         // These fields may not be present in all receipts, so missing/invalid
                         // values are None
-                        let deposit_nonce = other
-                            .get_deserialized::<U64>("depositNonce")
-                            .transpose()
-                            .ok()
-                            .flatten()
-                            .map(|v| v.to::<u64>());
-                        let deposit_receipt_version = other
-                            .get_deserialized::<U64>("depositReceiptVersion")
-                            .transpose()
-                            .ok()
-                            .flatten()
-                            .map(|v| v.to::<u64>());
+                        let mut take_deposit_field = |key: &str| {
+                            other
+                                .remove(key)
+                                .and_then(|value| serde_json::from_value::<U64>(value).ok())
+                                .map(|v| v.to::<u64>())
+                        };
+                        let deposit_nonce = take_deposit_field("depositNonce");
+                        let deposit_receipt_version = take_deposit_field("depositReceiptVersion");
 
                         FoundryReceiptEnvelope::Deposit(OpDepositReceiptWithBloom {
                             receipt: OpDepositReceipt {
@@ -200,4 +208,32 @@ fn test_receipt_convert() {
         let receipt: AnyTransactionReceipt = serde_json::from_str(s).unwrap();
         let _converted = FoundryTxReceipt::try_from(receipt).unwrap();
     }
+
+    #[test]
+    fn deposit_receipt_json_round_trip() {
+        let json: serde_json::Value =
+            serde_json::from_str(include_str!("../../test-data/deposit_receipt.json")).unwrap();
+
+        let receipt: FoundryTxReceipt = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(receipt.deposit_nonce(), Some(0x9b4b22));
+        assert_eq!(receipt.deposit_receipt_version(), Some(1));
+        assert!(receipt.0.other.is_empty());
+
+        let serialized = serde_json::to_value(&receipt).unwrap();
+        assert_eq!(serialized["depositNonce"], json["depositNonce"]);
+        assert_eq!(serialized["depositReceiptVersion"], json["depositReceiptVersion"]);
+        assert_eq!(serde_json::from_value::<FoundryTxReceipt>(serialized).unwrap(), receipt);
+
+        let any: AnyTransactionReceipt = serde_json::from_value(json).unwrap();
+        assert_eq!(FoundryTxReceipt::try_from(any).unwrap(), receipt);
+    }
+
+    #[test]
+    fn non_deposit_receipt_has_no_deposit_fields() {
+        let json: serde_json::Value =
+            serde_json::from_str(include_str!("../../test-data/tempo_receipt.json")).unwrap();
+        let receipt: FoundryTxReceipt = serde_json::from_value(json).unwrap();
+        assert_eq!(receipt.deposit_nonce(), None);
+        assert_eq!(receipt.deposit_receipt_version(), None);
+    }
 }
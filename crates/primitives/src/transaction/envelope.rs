@@ -3,7 +3,7 @@
     TxLegacy, TxType, Typed2718,
     crypto::RecoveryError,
     transaction::{
-        SignerRecoverable, TxEip7702, TxHashRef,
+        PooledTransaction, SignerRecoverable, TxEip7702, TxHashRef,
         eip4844::{TxEip4844Variant, TxEip4844WithSidecar},
     },
 };
@@ -21,6 +21,8 @@
 use tempo_primitives::{AASigned, TempoTransaction};
 use tempo_revm::TempoTxEnv;
 
+use crate::FoundryTransactionResponse;
+
 //
 /// Container type for signed, typed transactions.
 // NOTE(onbjerg): Boxing `Tempo(AASigned)` breaks `TransactionEnvelope` derive macro trait bounds.
@@ -86,6 +88,36 @@ pub fn try_into_eth(self) -> Result<TxEnvelope, Self> {
         }
     }
 
+    /// Converts the transaction into a [`PooledTransaction`], the form in which transactions are
+    /// propagated between nodes.
+    ///
+    /// Returns an error if the transaction is not part of the standard Ethereum transaction types,
+    /// or if it is an EIP-4844 transaction without its blob sidecar.
+    pub fn try_into_pooled(self) -> Result<PooledTransaction, Self> {
+        match self {
+            Self::Legacy(tx) => Ok(PooledTransaction::Legacy(tx)),
+            Self::Eip2930(tx) => Ok(PooledTransaction::Eip2930(tx)),
+            Self::Eip1559(tx) => Ok(PooledTransaction::Eip1559(tx)),
+            Self::Eip4844(tx) => {
+                let (tx, signature, hash) = tx.into_parts();
+                match tx {
+                    TxEip4844Variant::TxEip4844WithSidecar(tx) => {
+                        Ok(PooledTransaction::Eip4844(Signed::new_unchecked(tx, signature, hash)))
+                    }
+                    tx => Err(Self::Eip4844(Signed::new_unchecked(tx, signature, hash))),
+                }
+            }
+            Self::Eip7702(tx) => Ok(PooledTransaction::Eip7702(tx)),
+            Self::Deposit(_) | Self::PostExec(_) | Self::Tempo(_) => Err(self),
+        }
+    }
+
+    /// Returns `true` if this is an EIP-4844 transaction without its blob sidecar, which can not
+    /// be broadcast.
+    pub const fn is_missing_sidecar(&self) -> bool {
+        matches!(self, Self::Eip4844(tx) if matches!(tx.tx(), TxEip4844Variant::TxEip4844(_)))
+    }
+
     pub const fn sidecar(&self) -> Option<&TxEip4844WithSidecar> {
         match self {
             Self::Eip4844(signed_variant) => match signed_variant.tx() {
@@ -120,6 +152,22 @@ pub const fn is_tempo(&self) -> bool {
         matches!(self, Self::Tempo(_))
     }
 
+    /// Returns the signed Tempo transaction, if this is one.
+    pub const fn as_tempo(&self) -> Option<&AASigned> {
+        match self {
+            Self::Tempo(tx) => Some(tx),
+            _ => None,
+        }
+    }
+
+    /// Recovers the sender of a Tempo transaction from its signature over the Tempo signature
+    /// hash.
+    ///
+    /// Returns `None` if this is not a Tempo transaction.
+    pub fn recover_tempo_sender(&self) -> Option<Result<Address, RecoveryError>> {
+        self.as_tempo().map(|tx| Ok(tx.signature().recover_signer(&tx.signature_hash())?))
+    }
+
     /// Recovers the Ethereum address which was used to sign the transaction.
     pub fn recover(&self) -> Result<Address, RecoveryError> {
         Ok(match self {
@@ -197,6 +245,29 @@ fn from(tx: TxEnvelope) -> Self {
     }
 }
 
+impl TryFrom<FoundryTxEnvelope> for PooledTransaction {
+    type Error = FoundryTxEnvelope;
+
+    fn try_from(envelope: FoundryTxEnvelope) -> Result<Self, Self::Error> {
+        envelope.try_into_pooled()
+    }
+}
+
+impl From<PooledTransaction> for FoundryTxEnvelope {
+    fn from(tx: PooledTransaction) -> Self {
+        match tx {
+            PooledTransaction::Legacy(tx) => Self::Legacy(tx),
+            PooledTransaction::Eip2930(tx) => Self::Eip2930(tx),
+            PooledTransaction::Eip1559(tx) => Self::Eip1559(tx),
+            PooledTransaction::Eip4844(tx) => {
+                let (tx, signature, hash) = tx.into_parts();
+                Self::Eip4844(Signed::new_unchecked(tx.into(), signature, hash))
+            }
+            PooledTransaction::Eip7702(tx) => Self::Eip7702(tx),
+        }
+    }
+}
+
 impl From<op_alloy_consensus::OpTxEnvelope> for FoundryTxEnvelope {
     fn from(tx: op_alloy_consensus::OpTxEnvelope) -> Self {
         match tx {
@@ -268,6 +339,26 @@ fn try_from(value: AnyRpcTransaction) -> Result<Self, Self::Error> {
     }
 }
 
+/// Fails if the sender of the response does not match the one of the transaction, which is only
+/// part of deposit transactions.
+impl TryFrom<FoundryTransactionResponse> for FoundryTxEnvelope {
+    type Error = ConversionError;
+
+    fn try_from(tx: FoundryTransactionResponse) -> Result<Self, Self::Error> {
+        let from = tx.from();
+        let envelope = tx.inner.into_inner();
+        if let Self::Deposit(deposit_tx) = &envelope
+            && deposit_tx.from != from
+        {
+            return Err(ConversionError::Custom(format!(
+                "Deposit tx sender {} does not match the response sender {from}",
+                deposit_tx.from
+            )));
+        }
+        Ok(envelope)
+    }
+}
+
 impl FromRecoveredTx<FoundryTxEnvelope> for TxEnv {
     fn from_recovered_tx(tx: &FoundryTxEnvelope, caller: Address) -> Self {
         match tx {
@@ -631,6 +722,29 @@ fn test_decode_encode_deposit_tx() {
         assert_eq!(tx_hash, dep_tx.hash());
     }
 
+    #[test]
+    fn converts_deposit_tx_response() {
+        let deposit_tx = TxDeposit { from: Address::repeat_byte(1), ..Default::default() };
+        let response = |from| FoundryTransactionResponse {
+            inner: alloy_rpc_types::Transaction {
+                inner: alloy_consensus::transaction::Recovered::new_unchecked(
+                    FoundryTxEnvelope::Deposit(Sealed::new(deposit_tx.clone())),
+                    from,
+                ),
+                block_hash: None,
+                block_number: None,
+                transaction_index: None,
+                effective_gas_price: None,
+            },
+            deposit_nonce: None,
+            deposit_receipt_version: None,
+        };
+
+        let envelope = FoundryTxEnvelope::try_from(response(deposit_tx.from)).unwrap();
+        assert_eq!(envelope.as_deposit().map(|tx| tx.inner()), Some(&deposit_tx));
+        assert!(FoundryTxEnvelope::try_from(response(Address::repeat_byte(2))).is_err());
+    }
+
     #[test]
     fn can_recover_sender_not_normalized() {
         let bytes = hex::decode("f85f800182520894095e7baea6a6c7c4c2dfeb977efac326af552d870a801ba048b55bfa915ac795c431978d8a6a992b628d557da5ff759b307d495a36649353a0efffd310ac743f371de3b9f7f9cb56c0b28ad43601b4ab949f53faa07bd2c804").unwrap();
@@ -714,6 +828,91 @@ fn test_from_recovered_tx_legacy() {
         assert_eq!(op_tx.base.gas_limit, 0x5208);
     }
 
+    #[test]
+    fn converts_pooled_transactions() {
+        let raw = hex::decode("02f872018307910d808507204d2cb1827d0094388c818ca8b9251b393131c08a736a67ccb19297880320d04823e2701c80c001a0cf024f4815304df2867a1a74e9d2707b6abda0337d2d54a4438d453f4160f190a07ac0e6b3bc9395b5b9c8b9e6d77204a236577a5b18467b9175c01de4faa208d9").unwrap();
+        let tx = FoundryTxEnvelope::decode(&mut raw.as_slice()).unwrap();
+        let pooled = PooledTransaction::try_from(tx.clone()).unwrap();
+        assert_eq!(FoundryTxEnvelope::from(pooled), tx);
+
+        // An EIP-4844 transaction as included in a block, without its sidecar.
+        let raw = hex::decode("03f9011d83aa36a7820fa28477359400852e90edd0008252089411e9ca82a3a762b4b5bd264d4173a242e7a770648080c08504a817c800f8a5a0012ec3d6f66766bedb002a190126b3549fce0047de0d4c25cffce0dc1c57921aa00152d8e24762ff22b1cfd9f8c0683786a7ca63ba49973818b3d1e9512cd2cec4a0013b98c6c83e066d5b14af2b85199e3d4fc7d1e778dd53130d180f5077e2d1c7a001148b495d6e859114e670ca54fb6e2657f0cbae5b08063605093a4b3dc9f8f1a0011ac212f13c5dff2b2c6b600a79635103d6f580a4221079951181b25c7e654901a0c8de4cced43169f9aa3d36506363b2d2c44f6c49fc1fd91ea114c86f3757077ea01e11fdd0d1934eda0492606ee0bb80a7bf8f35cc5f86ec60fe5031ba48bfd544").unwrap();
+        let tx = FoundryTxEnvelope::decode(&mut raw.as_slice()).unwrap();
+        assert!(tx.is_missing_sidecar());
+        assert_eq!(tx.clone().try_into_pooled().unwrap_err(), tx);
+    }
+
+    /// An encoded transaction envelope stored in `test-data/tx_envelopes.json`.
+    #[derive(serde::Deserialize)]
+    struct GoldenVector {
+        name: String,
+        #[serde(rename = "type")]
+        ty: u8,
+        raw: Bytes,
+        hash: TxHash,
+        from: Address,
+    }
+
+    // Guards against encoding regressions: every vector must decode to its type, hash and sender,
+    // and re-encode to the same bytes, both directly and through its JSON representation.
+    #[test]
+    fn golden_vectors_round_trip() {
+        use alloy_network::eip2718::{Decodable2718, Encodable2718};
+
+        let vectors: Vec<GoldenVector> =
+            serde_json::from_str(include_str!("../../test-data/tx_envelopes.json")).unwrap();
+
+        let types =
+            vectors.iter().map(|vector| vector.ty).collect::<std::collections::BTreeSet<_>>();
+        for ty in [
+            FoundryTxType::Legacy,
+            FoundryTxType::Eip2930,
+            FoundryTxType::Eip1559,
+            FoundryTxType::Eip4844,
+            FoundryTxType::Eip7702,
+            FoundryTxType::Deposit,
+            FoundryTxType::Tempo,
+        ] {
+            assert!(types.contains(&u8::from(ty)), "missing golden vector for {ty}");
+        }
+
+        for GoldenVector { name, ty, raw, hash, from } in vectors {
+            let tx = FoundryTxEnvelope::decode_2718(&mut raw.as_ref())
+                .unwrap_or_else(|err| panic!("{name}: failed to decode: {err}"));
+            assert_eq!(tx.ty(), ty, "{name}: type");
+            assert_eq!(tx.hash(), hash, "{name}: hash");
+            assert_eq!(tx.recover().unwrap(), from, "{name}: sender");
+            assert_eq!(tx.encoded_2718(), raw.as_ref(), "{name}: encoding");
+
+            let json = serde_json::to_string(&tx).unwrap();
+            let from_json: FoundryTxEnvelope = serde_json::from_str(&json)
+                .unwrap_or_else(|err| panic!("{name}: failed to deserialize {json}: {err}"));
+            assert_eq!(from_json.encoded_2718(), raw.as_ref(), "{name}: JSON round trip");
+        }
+    }
+
+    // The RPC representation of a Tempo transaction must carry its type and signature, and be
+    // stable across round trips so that anvil and Tempo nodes agree on the wire format.
+    #[test]
+    fn tempo_tx_json_round_trip() {
+        use alloy_network::eip2718::Decodable2718;
+
+        let vectors: Vec<GoldenVector> =
+            serde_json::from_str(include_str!("../../test-data/tx_envelopes.json")).unwrap();
+        let vector = vectors.into_iter().find(|vector| vector.name == "tempo").unwrap();
+        let tx = FoundryTxEnvelope::decode_2718(&mut vector.raw.as_ref()).unwrap();
+
+        let json = serde_json::to_value(&tx).unwrap();
+        assert_eq!(json["type"], "0x76");
+        assert!(json.get("signature").is_some_and(|signature| !signature.is_null()));
+
+        let from_json: FoundryTxEnvelope = serde_json::from_value(json.clone()).unwrap();
+        assert!(from_json.is_tempo());
+        assert_eq!(from_json.hash(), vector.hash);
+        assert_eq!(from_json.recover().unwrap(), vector.from);
+        assert_eq!(serde_json::to_value(&from_json).unwrap(), json);
+    }
+
     // Test vector from Tempo testnet:
     // https://explorer.testnet.tempo.xyz/tx/0x6d6d8c102064e6dee44abad2024a8b1d37959230baab80e70efbf9b0c739c4fd
     #[test]
@@ -766,5 +965,6 @@ fn test_decode_encode_tempo_tx() {
         // Verify sender recovery (WebAuthn signature)
         let sender = tempo_tx.recover().unwrap();
         assert_eq!(sender, address!("0x566Ff0f4a6114F8072ecDC8A7A8A13d8d0C6B45F"));
+        assert_eq!(tempo_tx.recover_tempo_sender().unwrap().unwrap(), sender);
     }
 }
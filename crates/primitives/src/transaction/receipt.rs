@@ -669,4 +669,32 @@ fn tempo_receipt_map_logs() {
         assert_eq!(mapped.logs().len(), 1);
         assert_eq!(mapped.tx_type(), FoundryTxType::Tempo);
     }
+
+    // A Tempo receipt as returned by `eth_getTransactionReceipt`, including the Tempo specific
+    // fields, must deserialize to the Tempo variant, both directly and through the
+    // `AnyTransactionReceipt` conversion, and serialize back to the same JSON.
+    #[test]
+    fn tempo_receipt_json_round_trip() {
+        use crate::FoundryTxReceipt;
+        use alloy_network::AnyTransactionReceipt;
+
+        let json: serde_json::Value =
+            serde_json::from_str(include_str!("../../test-data/tempo_receipt.json")).unwrap();
+
+        let receipt: FoundryTxReceipt = serde_json::from_value(json.clone()).unwrap();
+        let envelope = &receipt.0.inner.inner;
+        assert_eq!(envelope.tx_type(), FoundryTxType::Tempo);
+        assert!(envelope.status());
+        assert_eq!(envelope.cumulative_gas_used(), 157716);
+        assert_eq!(envelope.logs().len(), 1);
+        assert_eq!(
+            *envelope.logs_bloom(),
+            logs_bloom(envelope.logs().iter().map(|log| &log.inner))
+        );
+        assert_eq!(receipt.0.other.get("feeToken"), json.get("feeToken"));
+        assert_eq!(serde_json::to_value(&receipt).unwrap(), json);
+
+        let any: AnyTransactionReceipt = serde_json::from_value(json).unwrap();
+        assert_eq!(FoundryTxReceipt::try_from(any).unwrap(), receipt);
+    }
 }
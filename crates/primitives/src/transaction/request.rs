@@ -1,19 +1,20 @@
 use alloy_consensus::{BlobTransactionSidecarVariant, EthereumTypedTransaction};
 use alloy_network::{
-    BuildResult, NetworkTransactionBuilder, NetworkWallet, TransactionBuilder,
+    BuildResult, Network, NetworkTransactionBuilder, NetworkWallet, TransactionBuilder,
     TransactionBuilder4844, TransactionBuilderError,
 };
 use alloy_primitives::{Address, B256, ChainId, TxKind, U256};
 use alloy_rpc_types::{AccessList, TransactionInputKind, TransactionRequest};
 use alloy_serde::{OtherFields, WithOtherFields};
 use op_alloy_consensus::{DEPOSIT_TX_TYPE_ID, POST_EXEC_TX_TYPE_ID, TxDeposit};
+use op_alloy_rpc_types::OpTransactionRequest;
 use op_revm::transaction::deposit::DepositTransactionParts;
 use serde::{Deserialize, Serialize};
 use tempo_alloy::rpc::TempoTransactionRequest;
-use tempo_primitives::{TEMPO_TX_TYPE_ID, TempoTxType};
+use tempo_primitives::{TEMPO_TX_TYPE_ID, TempoTransaction, TempoTxType};
 
 use super::{FoundryTxEnvelope, FoundryTxType, FoundryTypedTx};
-use crate::FoundryNetwork;
+use crate::{FoundryNetwork, FoundryTransactionResponse};
 
 /// Foundry transaction request builder.
 ///
@@ -35,9 +36,11 @@ pub enum FoundryTransactionRequest {
 impl FoundryTransactionRequest {
     /// Create a new [`FoundryTransactionRequest`] from given
     /// [`WithOtherFields<TransactionRequest>`].
+    ///
+    /// Fails if the request has Tempo fields that can't be deserialized.
     #[inline]
-    pub fn new(inner: WithOtherFields<TransactionRequest>) -> Self {
-        inner.into()
+    pub fn new(inner: WithOtherFields<TransactionRequest>) -> Result<Self, serde_json::Error> {
+        inner.try_into()
     }
 
     /// Consume the [`FoundryTransactionRequest`] and return the inner transaction request.
@@ -49,6 +52,25 @@ pub fn into_inner(self) -> TransactionRequest {
         }
     }
 
+    /// Converts the request into the request of another network, through their JSON-RPC
+    /// representation.
+    ///
+    /// Fields that are not supported by the other network are dropped, unless its request keeps
+    /// unknown fields like [`WithOtherFields`].
+    pub fn into_network_request<N: Network>(
+        self,
+    ) -> Result<N::TransactionRequest, serde_json::Error> {
+        serde_json::from_value(serde_json::to_value(self)?)
+    }
+
+    /// Creates a request from the request of another network, through their JSON-RPC
+    /// representation.
+    pub fn from_network_request<N: Network>(
+        tx: &N::TransactionRequest,
+    ) -> Result<Self, serde_json::Error> {
+        serde_json::from_value(serde_json::to_value(tx)?)
+    }
+
     /// Get the deposit transaction parts from the request, calling [`get_deposit_tx_parts`] helper
     /// with OtherFields.
     ///
@@ -133,23 +155,86 @@ pub fn missing_keys(&self) -> Result<FoundryTxType, (FoundryTxType, Vec<&'static
         }
     }
 
+    /// Builds a deposit transaction from this request.
+    ///
+    /// Returns the missing keys if the request lacks the deposit fields.
+    pub fn build_deposit(&self) -> Result<TxDeposit, Vec<&'static str>> {
+        let deposit_tx_parts = self.get_deposit_tx_parts()?;
+        Ok(TxDeposit {
+            from: self.from().unwrap_or_default(),
+            source_hash: deposit_tx_parts.source_hash,
+            to: self.kind().unwrap_or_default(),
+            mint: deposit_tx_parts.mint.unwrap_or_default(),
+            value: self.value().unwrap_or_default(),
+            gas_limit: self.gas_limit().unwrap_or_default(),
+            is_system_transaction: deposit_tx_parts.is_system_transaction,
+            input: self.input().cloned().unwrap_or_default(),
+        })
+    }
+
+    /// Builds a Tempo transaction from this request.
+    ///
+    /// Returns the missing keys if the request lacks the fields required by Tempo transactions.
+    pub fn build_tempo(self) -> Result<TempoTransaction, Vec<&'static str>> {
+        self.complete_tempo()?;
+        let Self::Tempo(tx) = self else { unreachable!("checked by complete_tempo") };
+        Ok(tx.build_aa().expect("checked by complete_tempo"))
+    }
+
+    /// Builds a transaction of type `ty` from this request, regardless of its preferred type.
+    ///
+    /// Returns an error with the missing keys if the request lacks the fields required by `ty`.
+    pub fn build_type(
+        self,
+        ty: FoundryTxType,
+    ) -> Result<FoundryTypedTx, TransactionBuilderError<FoundryNetwork>> {
+        let complete = match ty {
+            // The sidecar is optional, as for `missing_keys`.
+            FoundryTxType::Eip4844 => self.complete_4844(),
+            ty => self.complete_type(ty),
+        };
+        if let Err(missing) = complete {
+            return Err(TransactionBuilderError::InvalidTransactionRequest(ty, missing));
+        }
+        const CHECKED: &str = "checked by complete_type";
+        Ok(match ty {
+            FoundryTxType::Legacy => {
+                FoundryTypedTx::Legacy(self.into_inner().build_legacy().expect(CHECKED))
+            }
+            FoundryTxType::Eip2930 => {
+                FoundryTypedTx::Eip2930(self.into_inner().build_2930().expect(CHECKED))
+            }
+            FoundryTxType::Eip1559 => {
+                FoundryTypedTx::Eip1559(self.into_inner().build_1559().expect(CHECKED))
+            }
+            FoundryTxType::Eip4844 if self.blob_sidecar().is_none() => FoundryTypedTx::Eip4844(
+                self.into_inner().build_4844_without_sidecar().expect(CHECKED).into(),
+            ),
+            FoundryTxType::Eip4844 => {
+                // Drop the EIP-7702 fields, which would take precedence over the EIP-4844 ones.
+                let mut tx = self.into_inner();
+                tx.authorization_list = None;
+                match tx.build_typed_tx().expect(CHECKED) {
+                    EthereumTypedTransaction::Eip4844(tx) => FoundryTypedTx::Eip4844(tx),
+                    _ => unreachable!("{CHECKED}"),
+                }
+            }
+            FoundryTxType::Eip7702 => {
+                FoundryTypedTx::Eip7702(self.into_inner().build_7702().expect(CHECKED))
+            }
+            FoundryTxType::Deposit => FoundryTypedTx::Deposit(self.build_deposit().expect(CHECKED)),
+            FoundryTxType::Tempo => FoundryTypedTx::Tempo(self.build_tempo().expect(CHECKED)),
+            FoundryTxType::PostExec => unreachable!("{CHECKED}"),
+        })
+    }
+
     /// Build a typed transaction from this request.
     ///
     /// Converts the request into a `FoundryTypedTx`, handling all Ethereum and OP-stack transaction
     /// types.
     pub fn build_typed_tx(self) -> Result<FoundryTypedTx, Self> {
-        if let Ok(deposit_tx_parts) = self.get_deposit_tx_parts() {
-            // Build deposit transaction
-            Ok(FoundryTypedTx::Deposit(TxDeposit {
-                from: self.from().unwrap_or_default(),
-                source_hash: deposit_tx_parts.source_hash,
-                to: self.kind().unwrap_or_default(),
-                mint: deposit_tx_parts.mint.unwrap_or_default(),
-                value: self.value().unwrap_or_default(),
-                gas_limit: self.gas_limit().unwrap_or_default(),
-                is_system_transaction: deposit_tx_parts.is_system_transaction,
-                input: self.input().cloned().unwrap_or_default(),
-            }))
+        if let Ok(deposit_tx) = self.build_deposit() {
+            Ok(FoundryTypedTx::Deposit(deposit_tx))
         } else if self.complete_tempo().is_ok()
             && let Self::Tempo(tx_req) = self
         {
@@ -203,7 +288,9 @@ fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        WithOtherFields::<TransactionRequest>::deserialize(deserializer).map(Into::<Self>::into)
+        WithOtherFields::<TransactionRequest>::deserialize(deserializer)?
+            .try_into()
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -227,31 +314,112 @@ fn as_mut(&mut self) -> &mut TransactionRequest {
     }
 }
 
-impl From<WithOtherFields<TransactionRequest>> for FoundryTransactionRequest {
-    fn from(tx: WithOtherFields<TransactionRequest>) -> Self {
+/// Fails if the request has Tempo fields that can't be deserialized.
+impl TryFrom<WithOtherFields<TransactionRequest>> for FoundryTransactionRequest {
+    type Error = serde_json::Error;
+
+    fn try_from(tx: WithOtherFields<TransactionRequest>) -> Result<Self, Self::Error> {
         if tx.transaction_type == Some(TEMPO_TX_TYPE_ID)
             || tx.other.contains_key("feeToken")
             || tx.other.contains_key("nonceKey")
         {
-            let mut tempo_tx_req: TempoTransactionRequest = tx.inner.into();
-            if let Some(fee_token) =
-                tx.other.get_deserialized::<Address>("feeToken").transpose().ok().flatten()
-            {
+            // Deserialize the whole request to keep the other Tempo fields, e.g. the calls.
+            let mut tempo_tx_req: TempoTransactionRequest =
+                serde_json::from_value(serde_json::to_value(&tx)?)?;
+            if let Some(fee_token) = tx.other.get_deserialized::<Address>("feeToken").transpose()? {
                 tempo_tx_req.fee_token = Some(fee_token);
             }
-            if let Some(nonce_key) =
-                tx.other.get_deserialized::<U256>("nonceKey").transpose().ok().flatten()
-            {
+            if let Some(nonce_key) = tx.other.get_deserialized::<U256>("nonceKey").transpose()? {
                 tempo_tx_req.set_nonce_key(nonce_key);
             }
-            Self::Tempo(Box::new(tempo_tx_req))
+            Ok(Self::Tempo(Box::new(tempo_tx_req)))
         } else if tx.transaction_type == Some(DEPOSIT_TX_TYPE_ID)
             || tx.transaction_type == Some(POST_EXEC_TX_TYPE_ID)
             || get_deposit_tx_parts(&tx.other).is_ok()
         {
-            Self::Op(tx)
+            Ok(Self::Op(tx))
         } else {
-            Self::Ethereum(tx.into_inner())
+            Ok(Self::Ethereum(tx.into_inner()))
+        }
+    }
+}
+
+/// The Tempo fields that are not part of [`TransactionRequest`] end up in the other fields.
+///
+/// Fails if the Tempo fields can't be serialized into other fields.
+impl TryFrom<FoundryTransactionRequest> for WithOtherFields<TransactionRequest> {
+    type Error = serde_json::Error;
+
+    fn try_from(tx: FoundryTransactionRequest) -> Result<Self, Self::Error> {
+        match tx {
+            FoundryTransactionRequest::Ethereum(tx) => Ok(Self::new(tx)),
+            FoundryTransactionRequest::Op(tx) => Ok(tx),
+            FoundryTransactionRequest::Tempo(tx) => {
+                let mut other = OtherFields::try_from(serde_json::to_value(&tx)?)?;
+                // Only keep the fields that are not part of the inner request.
+                if let serde_json::Value::Object(inner) = serde_json::to_value(&tx.inner)? {
+                    for key in inner.keys() {
+                        other.remove(key);
+                    }
+                }
+                Ok(Self { inner: tx.inner, other })
+            }
+        }
+    }
+}
+
+impl From<TransactionRequest> for FoundryTransactionRequest {
+    fn from(tx: TransactionRequest) -> Self {
+        // Without other fields, the transaction type alone selects the variant.
+        match tx.transaction_type {
+            Some(TEMPO_TX_TYPE_ID) => Self::Tempo(Box::new(tx.into())),
+            Some(DEPOSIT_TX_TYPE_ID | POST_EXEC_TX_TYPE_ID) => Self::Op(WithOtherFields::new(tx)),
+            _ => Self::Ethereum(tx),
+        }
+    }
+}
+
+impl From<OpTransactionRequest> for FoundryTransactionRequest {
+    fn from(tx: OpTransactionRequest) -> Self {
+        TransactionRequest::from(tx).into()
+    }
+}
+
+impl From<TempoTransactionRequest> for FoundryTransactionRequest {
+    fn from(tx: TempoTransactionRequest) -> Self {
+        Self::Tempo(Box::new(tx))
+    }
+}
+
+/// Only Ethereum requests can be converted without losing the OP stack or Tempo fields.
+impl TryFrom<FoundryTransactionRequest> for TransactionRequest {
+    type Error = FoundryTransactionRequest;
+
+    fn try_from(tx: FoundryTransactionRequest) -> Result<Self, Self::Error> {
+        match tx {
+            FoundryTransactionRequest::Ethereum(tx) => Ok(tx),
+            tx => Err(tx),
+        }
+    }
+}
+
+/// OP stack requests don't support deposit fields, so only Ethereum requests can be converted.
+impl TryFrom<FoundryTransactionRequest> for OpTransactionRequest {
+    type Error = FoundryTransactionRequest;
+
+    fn try_from(tx: FoundryTransactionRequest) -> Result<Self, Self::Error> {
+        TransactionRequest::try_from(tx).map(Into::into)
+    }
+}
+
+impl TryFrom<FoundryTransactionRequest> for TempoTransactionRequest {
+    type Error = FoundryTransactionRequest;
+
+    fn try_from(tx: FoundryTransactionRequest) -> Result<Self, Self::Error> {
+        match tx {
+            FoundryTransactionRequest::Ethereum(tx) => Ok(tx.into()),
+            FoundryTransactionRequest::Tempo(tx) => Ok(*tx),
+            tx => Err(tx),
         }
     }
 }
@@ -270,19 +438,12 @@ fn from(tx: FoundryTypedTx) -> Self {
                     ("mint", tx.mint.to_string().into()),
                     ("isSystemTx", tx.is_system_transaction.to_string().into()),
                 ]);
-                WithOtherFields { inner: Into::<TransactionRequest>::into(tx), other }.into()
+                Self::Op(WithOtherFields { inner: Into::<TransactionRequest>::into(tx), other })
             }
-            FoundryTypedTx::PostExec(tx) => WithOtherFields {
-                inner: Into::<TransactionRequest>::into(tx),
-                other: OtherFields::default(),
+            FoundryTypedTx::PostExec(tx) => {
+                Self::Op(WithOtherFields::new(Into::<TransactionRequest>::into(tx)))
             }
-            .into(),
             FoundryTypedTx::Tempo(tx) => {
-                let mut other = OtherFields::default();
-                if let Some(fee_token) = tx.fee_token {
-                    other.insert("feeToken".to_string(), serde_json::to_value(fee_token).unwrap());
-                }
-                other.insert("nonceKey".to_string(), serde_json::to_value(tx.nonce_key).unwrap());
                 let first_call = tx.calls.first();
                 let mut inner = TransactionRequest::default()
                     .with_chain_id(tx.chain_id)
@@ -295,7 +456,10 @@ fn from(tx: FoundryTypedTx) -> Self {
                     .with_input(first_call.map(|c| c.input.clone()).unwrap_or_default())
                     .with_access_list(tx.access_list);
                 inner.transaction_type = Some(TEMPO_TX_TYPE_ID);
-                WithOtherFields { inner, other }.into()
+                let mut tempo_tx_req = TempoTransactionRequest::from(inner);
+                tempo_tx_req.fee_token = tx.fee_token;
+                tempo_tx_req.set_nonce_key(tx.nonce_key);
+                Self::Tempo(Box::new(tempo_tx_req))
             }
         }
     }
@@ -307,8 +471,8 @@ fn from(tx: FoundryTxEnvelope) -> Self {
     }
 }
 
-impl From<op_alloy_rpc_types::Transaction<FoundryTxEnvelope>> for FoundryTransactionRequest {
-    fn from(tx: op_alloy_rpc_types::Transaction<FoundryTxEnvelope>) -> Self {
+impl From<FoundryTransactionResponse> for FoundryTransactionRequest {
+    fn from(tx: FoundryTransactionResponse) -> Self {
         tx.inner.into_inner().into()
     }
 }
@@ -585,6 +749,8 @@ pub fn get_deposit_tx_parts(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_network::{AnyNetwork, Ethereum};
+    use tempo_primitives::transaction::Call;
 
     fn default_tx_req() -> TransactionRequest {
         TransactionRequest::default()
@@ -599,7 +765,7 @@ fn default_tx_req() -> TransactionRequest {
     #[test]
     fn test_routing_ethereum_default() {
         let tx = default_tx_req();
-        let req: FoundryTransactionRequest = WithOtherFields::new(tx).into();
+        let req: FoundryTransactionRequest = WithOtherFields::new(tx).try_into().unwrap();
 
         assert!(matches!(req, FoundryTransactionRequest::Ethereum(_)));
         assert!(matches!(req.build_unsigned(), Ok(FoundryTypedTx::Eip1559(_))));
@@ -611,7 +777,8 @@ fn test_routing_tempo_by_fee_token() {
         let mut other = OtherFields::default();
         other.insert("feeToken".to_string(), serde_json::to_value(Address::random()).unwrap());
 
-        let req: FoundryTransactionRequest = WithOtherFields { inner: tx, other }.into();
+        let req: FoundryTransactionRequest =
+            WithOtherFields { inner: tx, other }.try_into().unwrap();
 
         assert!(matches!(req, FoundryTransactionRequest::Tempo(_)));
         assert!(matches!(req.build_unsigned(), Ok(FoundryTypedTx::Tempo(_))));
@@ -625,7 +792,8 @@ fn test_routing_op_by_deposit_fields() {
         other.insert("mint".to_string(), serde_json::to_value(U256::from(1000)).unwrap());
         other.insert("isSystemTx".to_string(), serde_json::to_value(false).unwrap());
 
-        let req: FoundryTransactionRequest = WithOtherFields { inner: tx, other }.into();
+        let req: FoundryTransactionRequest =
+            WithOtherFields { inner: tx, other }.try_into().unwrap();
 
         assert!(matches!(req, FoundryTransactionRequest::Op(_)));
         assert!(matches!(req.build_unsigned(), Ok(FoundryTypedTx::Deposit(_))));
@@ -639,19 +807,64 @@ fn test_op_incomplete_routes_to_ethereum() {
         other.insert("sourceHash".to_string(), serde_json::to_value(B256::ZERO).unwrap());
         other.insert("mint".to_string(), serde_json::to_value(U256::from(1000)).unwrap());
 
-        let req: FoundryTransactionRequest = WithOtherFields { inner: tx, other }.into();
+        let req: FoundryTransactionRequest =
+            WithOtherFields { inner: tx, other }.try_into().unwrap();
 
         assert!(matches!(req, FoundryTransactionRequest::Ethereum(_)));
         assert!(matches!(req.build_unsigned(), Ok(FoundryTypedTx::Eip1559(_))));
     }
 
+    #[test]
+    fn test_build_type() {
+        let req = FoundryTransactionRequest::from(default_tx_req().with_gas_price(1000000));
+        assert!(matches!(
+            req.clone().build_type(FoundryTxType::Legacy),
+            Ok(FoundryTypedTx::Legacy(_))
+        ));
+        assert!(matches!(
+            req.clone().build_type(FoundryTxType::Eip1559),
+            Ok(FoundryTypedTx::Eip1559(_))
+        ));
+        assert!(matches!(
+            req.clone().build_type(FoundryTxType::Deposit),
+            Err(TransactionBuilderError::InvalidTransactionRequest(FoundryTxType::Deposit, _))
+        ));
+        assert!(matches!(
+            req.build_type(FoundryTxType::Tempo),
+            Err(TransactionBuilderError::InvalidTransactionRequest(FoundryTxType::Tempo, _))
+        ));
+
+        let mut other = OtherFields::default();
+        other.insert("sourceHash".to_string(), serde_json::to_value(B256::ZERO).unwrap());
+        other.insert("mint".to_string(), serde_json::to_value(U256::from(1000)).unwrap());
+        other.insert("isSystemTx".to_string(), serde_json::to_value(false).unwrap());
+        let req =
+            FoundryTransactionRequest::try_from(WithOtherFields { inner: default_tx_req(), other })
+                .unwrap();
+        let deposit_tx = req.build_deposit().unwrap();
+        assert_eq!(deposit_tx.mint, 1000);
+        assert!(matches!(
+            req.build_type(FoundryTxType::Deposit),
+            Ok(FoundryTypedTx::Deposit(tx)) if tx == deposit_tx
+        ));
+
+        let mut other = OtherFields::default();
+        other.insert("feeToken".to_string(), serde_json::to_value(Address::random()).unwrap());
+        let req =
+            FoundryTransactionRequest::try_from(WithOtherFields { inner: default_tx_req(), other })
+                .unwrap();
+        assert!(req.clone().build_tempo().is_ok());
+        assert!(matches!(req.build_type(FoundryTxType::Tempo), Ok(FoundryTypedTx::Tempo(_))));
+    }
+
     #[test]
     fn test_ethereum_with_unrelated_other_fields() {
         let tx = default_tx_req();
         let mut other = OtherFields::default();
         other.insert("anotherField".to_string(), serde_json::to_value(123).unwrap());
 
-        let req: FoundryTransactionRequest = WithOtherFields { inner: tx, other }.into();
+        let req: FoundryTransactionRequest =
+            WithOtherFields { inner: tx, other }.try_into().unwrap();
 
         assert!(matches!(req, FoundryTransactionRequest::Ethereum(_)));
         assert!(matches!(req.build_unsigned(), Ok(FoundryTypedTx::Eip1559(_))));
@@ -660,7 +873,7 @@ fn test_ethereum_with_unrelated_other_fields() {
     #[test]
     fn test_serialization_ethereum() {
         let tx = default_tx_req();
-        let original: FoundryTransactionRequest = WithOtherFields::new(tx).into();
+        let original: FoundryTransactionRequest = WithOtherFields::new(tx).try_into().unwrap();
 
         let serialized = serde_json::to_string(&original).unwrap();
         let deserialized: FoundryTransactionRequest = serde_json::from_str(&serialized).unwrap();
@@ -676,7 +889,8 @@ fn test_serialization_op() {
         other.insert("mint".to_string(), serde_json::to_value(U256::from(1000)).unwrap());
         other.insert("isSystemTx".to_string(), serde_json::to_value(false).unwrap());
 
-        let original: FoundryTransactionRequest = WithOtherFields { inner: tx, other }.into();
+        let original: FoundryTransactionRequest =
+            WithOtherFields { inner: tx, other }.try_into().unwrap();
 
         let serialized = serde_json::to_string(&original).unwrap();
         let deserialized: FoundryTransactionRequest = serde_json::from_str(&serialized).unwrap();
@@ -691,11 +905,102 @@ fn test_serialization_tempo() {
         other.insert("feeToken".to_string(), serde_json::to_value(Address::ZERO).unwrap());
         other.insert("nonceKey".to_string(), serde_json::to_value(U256::from(42)).unwrap());
 
-        let original: FoundryTransactionRequest = WithOtherFields { inner: tx, other }.into();
+        let original: FoundryTransactionRequest =
+            WithOtherFields { inner: tx, other }.try_into().unwrap();
 
         let serialized = serde_json::to_string(&original).unwrap();
         let deserialized: FoundryTransactionRequest = serde_json::from_str(&serialized).unwrap();
 
         assert!(matches!(deserialized, FoundryTransactionRequest::Tempo(_)));
     }
+
+    #[test]
+    fn test_conversions_ethereum() {
+        let tx = default_tx_req();
+        let req = FoundryTransactionRequest::from(tx.clone());
+        assert_eq!(req, FoundryTransactionRequest::Ethereum(tx.clone()));
+
+        assert_eq!(TransactionRequest::try_from(req.clone()).unwrap(), tx);
+        assert_eq!(OpTransactionRequest::try_from(req.clone()).unwrap(), tx.clone().into());
+        assert_eq!(TempoTransactionRequest::try_from(req.clone()).unwrap(), tx.clone().into());
+        assert_eq!(
+            FoundryTransactionRequest::from(OpTransactionRequest::from(tx.clone())),
+            req.clone()
+        );
+        assert_eq!(
+            WithOtherFields::<TransactionRequest>::try_from(req).unwrap(),
+            WithOtherFields::new(tx)
+        );
+    }
+
+    #[test]
+    fn test_conversions_op() {
+        let mut other = OtherFields::default();
+        other.insert("sourceHash".to_string(), serde_json::to_value(B256::ZERO).unwrap());
+        other.insert("mint".to_string(), serde_json::to_value(U256::from(1000)).unwrap());
+        other.insert("isSystemTx".to_string(), serde_json::to_value(false).unwrap());
+        let tx = WithOtherFields { inner: default_tx_req(), other };
+        let req = FoundryTransactionRequest::try_from(tx.clone()).unwrap();
+
+        // The deposit fields can't be dropped.
+        assert_eq!(TransactionRequest::try_from(req.clone()), Err(req.clone()));
+        assert_eq!(OpTransactionRequest::try_from(req.clone()), Err(req.clone()));
+        assert_eq!(WithOtherFields::<TransactionRequest>::try_from(req).unwrap(), tx);
+    }
+
+    #[test]
+    fn test_conversions_tempo() {
+        let tx = TempoTransactionRequest {
+            inner: default_tx_req(),
+            fee_token: Some(Address::random()),
+            calls: vec![Call {
+                to: TxKind::Call(Address::random()),
+                value: U256::from(1),
+                input: Default::default(),
+            }],
+            ..Default::default()
+        };
+        let req = FoundryTransactionRequest::from(tx.clone());
+        assert_eq!(TempoTransactionRequest::try_from(req.clone()).unwrap(), tx);
+        assert_eq!(TransactionRequest::try_from(req.clone()), Err(req.clone()));
+
+        // The calls are kept through the other fields.
+        let any = WithOtherFields::<TransactionRequest>::try_from(req.clone()).unwrap();
+        assert!(any.other.contains_key("calls"));
+        assert_eq!(FoundryTransactionRequest::try_from(any).unwrap(), req);
+    }
+
+    #[test]
+    fn test_conversions_tempo_invalid_fields() {
+        let mut other = OtherFields::default();
+        other.insert("feeToken".to_string(), serde_json::to_value(Address::random()).unwrap());
+        other.insert("calls".to_string(), "not calls".into());
+        let tx = WithOtherFields { inner: default_tx_req(), other };
+
+        // The calls are not silently dropped.
+        assert!(FoundryTransactionRequest::try_from(tx.clone()).is_err());
+        assert!(
+            serde_json::from_value::<FoundryTransactionRequest>(serde_json::to_value(tx).unwrap())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_network_request_adapter() {
+        let mut other = OtherFields::default();
+        other.insert("feeToken".to_string(), serde_json::to_value(Address::random()).unwrap());
+        let tx = default_tx_req();
+        let req: FoundryTransactionRequest =
+            WithOtherFields { inner: tx.clone(), other }.try_into().unwrap();
+
+        let any = req.clone().into_network_request::<AnyNetwork>().unwrap();
+        assert_eq!(
+            FoundryTransactionRequest::from_network_request::<AnyNetwork>(&any).unwrap(),
+            req
+        );
+
+        // Ethereum requests drop the Tempo fields.
+        let eth = req.into_network_request::<Ethereum>().unwrap();
+        assert_eq!(eth, tx);
+    }
 }
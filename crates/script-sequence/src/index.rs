@@ -0,0 +1,209 @@
+//! Index of the latest deployments found in the broadcast directory.
+
+use alloy_primitives::{Address, B256};
+use eyre::Result;
+use foundry_common::fs;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+/// The file name of the index, in the broadcast directory.
+pub const BROADCAST_INDEX_FILE: &str = "index.json";
+
+/// The latest deployment of a contract on a chain.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Deployment {
+    /// The address of the contract.
+    pub address: Address,
+    /// The hash of the deployment transaction, if it was sent.
+    pub tx_hash: Option<B256>,
+    /// The script file name, e.g. `Deploy.s.sol`.
+    pub script: String,
+    /// The run file of the deployment, relative to the broadcast directory.
+    pub run: PathBuf,
+    /// The timestamp of the run, in milliseconds.
+    pub timestamp: u128,
+}
+
+/// Maps chain IDs to the latest deployment of each contract.
+///
+/// The index is stored in `broadcast/index.json` and updated after every broadcast, so that the
+/// latest deployments can be queried without reading all the run files.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BroadcastIndex {
+    pub chains: BTreeMap<u64, BTreeMap<String, Deployment>>,
+}
+
+impl BroadcastIndex {
+    /// Reads the index of the given broadcast directory, building it if it doesn't exist.
+    pub fn load(broadcast_dir: &Path) -> Result<Self> {
+        let path = broadcast_dir.join(BROADCAST_INDEX_FILE);
+        if path.is_file() {
+            return Ok(fs::read_json_file(&path)?);
+        }
+        let index = Self::build(broadcast_dir)?;
+        index.save(broadcast_dir)?;
+        Ok(index)
+    }
+
+    /// Builds the index from all the run files of the broadcast directory.
+    pub fn build(broadcast_dir: &Path) -> Result<Self> {
+        let mut index = Self::default();
+        for run in run_files(broadcast_dir) {
+            index.record(broadcast_dir, &run.path)?;
+        }
+        Ok(index)
+    }
+
+    /// Writes the index to the broadcast directory.
+    pub fn save(&self, broadcast_dir: &Path) -> Result<()> {
+        fs::create_dir_all(broadcast_dir)?;
+        fs::write_pretty_json_file(&broadcast_dir.join(BROADCAST_INDEX_FILE), self)?;
+        Ok(())
+    }
+
+    /// Records the deployments of a run file, if they are more recent than the indexed ones.
+    pub fn record(&mut self, broadcast_dir: &Path, run: &Path) -> Result<()> {
+        let sequence: Value = fs::read_json_file(run)?;
+        let Some(chain) = sequence["chain"].as_u64() else { return Ok(()) };
+        let timestamp = sequence["timestamp"].as_u64().unwrap_or_default() as u128;
+        let relative = run.strip_prefix(broadcast_dir).unwrap_or(run).to_path_buf();
+        let script = relative
+            .components()
+            .next()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let deployments = self.chains.entry(chain).or_default();
+        for (name, address, tx_hash) in deployed_contracts(&sequence) {
+            if deployments.get(&name).is_some_and(|latest| latest.timestamp > timestamp) {
+                continue;
+            }
+            deployments.insert(
+                name,
+                Deployment {
+                    address,
+                    tx_hash,
+                    script: script.clone(),
+                    run: relative.clone(),
+                    timestamp,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the given run file holds one of the indexed deployments.
+    pub fn references(&self, broadcast_dir: &Path, run: &Path) -> bool {
+        let relative = run.strip_prefix(broadcast_dir).unwrap_or(run);
+        self.chains.values().flat_map(|deployments| deployments.values()).any(|d| d.run == relative)
+    }
+}
+
+/// Returns the contracts created by the transactions of a sequence, in order, with the hash of the
+/// creating transaction.
+fn deployed_contracts(sequence: &Value) -> Vec<(String, Address, Option<B256>)> {
+    let mut contracts = Vec::new();
+    let Some(transactions) = sequence["transactions"].as_array() else { return contracts };
+    for tx in transactions {
+        let hash = tx["hash"].as_str().and_then(|hash| hash.parse().ok());
+        if matches!(tx["transactionType"].as_str(), Some("CREATE" | "CREATE2"))
+            && let Some(contract) = named_contract(tx, "contractAddress")
+        {
+            contracts.push((contract.0, contract.1, hash));
+        }
+        for additional in tx["additionalContracts"].as_array().into_iter().flatten() {
+            if let Some(contract) = named_contract(additional, "address") {
+                contracts.push((contract.0, contract.1, hash));
+            }
+        }
+    }
+    contracts
+}
+
+fn named_contract(value: &Value, address_key: &str) -> Option<(String, Address)> {
+    let name = value["contractName"].as_str().filter(|name| !name.is_empty())?;
+    let address = value[address_key].as_str()?.parse::<Address>().ok()?;
+    (!address.is_zero()).then(|| (name.to_string(), address))
+}
+
+/// A timestamped run file of the broadcast directory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunFile {
+    /// The path of the run file.
+    pub path: PathBuf,
+    /// The directory of the runs of the script on the chain, e.g. `broadcast/Deploy.s.sol/1`.
+    pub dir: PathBuf,
+    /// The timestamp of the run, in milliseconds.
+    pub timestamp: u128,
+}
+
+/// Returns the timestamped run files of single-chain broadcasts, excluding dry runs, sorted by
+/// directory and ascending timestamp.
+///
+/// The `*-latest.json` files are copies of the latest runs and are not returned.
+pub fn run_files(broadcast_dir: &Path) -> Vec<RunFile> {
+    let mut runs = walkdir::WalkDir::new(broadcast_dir)
+        .min_depth(3)
+        .max_depth(3)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| {
+            let path = entry.into_path();
+            let timestamp = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix("run-")?
+                .strip_suffix(".json")?
+                .parse()
+                .ok()?;
+            let dir = path.parent()?.to_path_buf();
+            // Dry runs are one level deeper, in `<chain>/dry-run/`.
+            let in_multi = path.strip_prefix(broadcast_dir).ok()?.starts_with("multi");
+            (!in_multi).then_some(RunFile { path, dir, timestamp })
+        })
+        .collect::<Vec<_>>();
+    runs.sort_by(|a, b| a.dir.cmp(&b.dir).then(a.timestamp.cmp(&b.timestamp)));
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn lists_deployed_contracts() {
+        let sequence = json!({
+            "transactions": [
+                {
+                    "hash": "0x1111111111111111111111111111111111111111111111111111111111111111",
+                    "transactionType": "CREATE",
+                    "contractName": "Counter",
+                    "contractAddress": "0x5fbdb2315678afecb367f032d93f642f64180aa3",
+                    "additionalContracts": [{
+                        "transactionType": "CREATE",
+                        "contractName": "Lib",
+                        "address": "0xe7f1725e7734ce288f8367e1bb143e90bb3f0512",
+                    }],
+                },
+                {
+                    "hash": null,
+                    "transactionType": "CALL",
+                    "contractName": "Counter",
+                    "contractAddress": "0x5fbdb2315678afecb367f032d93f642f64180aa3",
+                },
+            ],
+        });
+        let contracts = deployed_contracts(&sequence);
+        assert_eq!(contracts.len(), 2);
+        assert_eq!(contracts[0].0, "Counter");
+        assert!(contracts[0].2.is_some());
+        assert_eq!(contracts[1].0, "Lib");
+    }
+}
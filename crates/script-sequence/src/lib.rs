@@ -4,10 +4,12 @@
 #[macro_use]
 extern crate foundry_common;
 
+pub mod index;
 pub mod reader;
 pub mod sequence;
 pub mod transaction;
 
+pub use index::*;
 pub use reader::*;
 pub use sequence::*;
 pub use transaction::*;
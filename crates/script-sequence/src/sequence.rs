@@ -1,8 +1,10 @@
-use crate::transaction::TransactionWithMetadata;
+use crate::{BroadcastIndex, transaction::TransactionWithMetadata};
 use alloy_network::{Network, ReceiptResponse};
 use alloy_primitives::{TxHash, hex, map::HashMap};
 use eyre::{ContextCompat, Result, WrapErr};
-use foundry_common::{SELECTOR_LEN, TransactionMaybeSigned, fs, shell};
+use foundry_common::{
+    SELECTOR_LEN, TransactionMaybeSigned, fs, provenance::BuildProvenance, shell,
+};
 use foundry_compilers::ArtifactId;
 use foundry_config::Config;
 use serde::{Deserialize, Serialize};
@@ -52,6 +54,10 @@ pub struct ScriptSequence<N: Network> {
     pub timestamp: u128,
     pub chain: u64,
     pub commit: Option<String>,
+    /// Provenance of the build the script was compiled from, if enabled. The git commit is only
+    /// recorded in `commit`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<BuildProvenance>,
 }
 
 impl<N: Network> Default for ScriptSequence<N> {
@@ -66,6 +72,7 @@ fn default() -> Self {
             timestamp: Default::default(),
             chain: Default::default(),
             commit: Default::default(),
+            provenance: Default::default(),
         }
     }
 }
@@ -134,9 +141,10 @@ pub fn save(&mut self, silent: bool, save_ts: bool) -> Result<()>
         // broadcast folder writes
         //../run-latest.json
         fs::write_pretty_json_file(path, &self)?;
+        let ts_path = path.with_file_name(&ts_name);
         if save_ts {
             //../run-[timestamp].json
-            fs::copy(path, path.with_file_name(&ts_name))?;
+            fs::copy(path, &ts_path)?;
         }
 
         // cache folder writes
@@ -145,6 +153,19 @@ pub fn save(&mut self, silent: bool, save_ts: bool) -> Result<()>
         if save_ts {
             //../run-[timestamp].json
             fs::copy(sensitive_path, sensitive_path.with_file_name(&ts_name))?;
+
+            // broadcast/index.json, which can be rebuilt from the runs: failing to update it
+            // must not fail the broadcast.
+            let is_dry_run = path.parent().is_some_and(|dir| dir.ends_with(DRY_RUN_DIR));
+            if let Some(broadcast_dir) = path.ancestors().nth(3)
+                && !is_dry_run
+                && let Err(err) = BroadcastIndex::load(broadcast_dir).and_then(|mut index| {
+                    index.record(broadcast_dir, &ts_path)?;
+                    index.save(broadcast_dir)
+                })
+            {
+                sh_warn!("failed to update the broadcast index: {err}")?;
+            }
         }
 
         if !silent {
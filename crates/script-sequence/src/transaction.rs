@@ -1,8 +1,9 @@
 use alloy_network::Network;
-use alloy_primitives::{Address, B256, Bytes};
+use alloy_primitives::{Address, B256, Bytes, Log};
 use foundry_common::TransactionMaybeSigned;
 use revm_inspectors::tracing::types::CallKind;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -14,6 +15,18 @@ pub struct AdditionalContract {
     pub init_code: Bytes,
 }
 
+/// The outcome of a transaction when it was simulated, to compare with its onchain execution.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationSnapshot {
+    /// Gas used by the transaction.
+    pub gas_used: u64,
+    /// Logs emitted by the transaction.
+    pub logs: Vec<Log>,
+    /// Storage slots written by the transaction, with their new values.
+    pub state_diff: BTreeMap<Address, BTreeMap<B256, B256>>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(
     rename_all = "camelCase",
@@ -41,6 +54,9 @@ pub struct TransactionWithMetadata<N: Network> {
     pub additional_contracts: Vec<AdditionalContract>,
     #[serde(default)]
     pub is_fixed_gas_limit: bool,
+    /// The outcome of the transaction in the onchain simulation, if it was simulated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub simulation: Option<SimulationSnapshot>,
 }
 
 const fn default_string() -> Option<String> {
@@ -68,6 +84,7 @@ pub fn from_tx_request(transaction: TransactionMaybeSigned<N>) -> Self {
             is_fixed_gas_limit: Default::default(),
             additional_contracts: Default::default(),
             rpc: Default::default(),
+            simulation: Default::default(),
         }
     }
 
@@ -8,7 +8,7 @@
 use alloy_consensus::{SignableTransaction, Signed};
 use alloy_eips::{BlockId, eip2718::Encodable2718};
 use alloy_network::{
-    EthereumWallet, Network, NetworkTransactionBuilder, ReceiptResponse, TransactionBuilder,
+    Network, NetworkTransactionBuilder, NetworkWallet, ReceiptResponse, TransactionBuilder,
 };
 use alloy_primitives::{
     Address, TxHash, TxKind, U256,
@@ -26,6 +26,7 @@
     FoundryTransactionBuilder, TransactionMaybeSigned,
     provider::{ProviderBuilder, try_get_http_provider},
     shell,
+    wallet::FoundryWallet,
 };
 use foundry_config::Config;
 use foundry_evm::core::evm::{FoundryEvmNetwork, TempoEvmNetwork};
@@ -75,7 +76,7 @@ pub async fn next_nonce(
 #[derive(Clone)]
 pub enum SendTransactionKind<'a, N: Network> {
     Unlocked(N::TransactionRequest),
-    Raw(N::TransactionRequest, &'a EthereumWallet),
+    Raw(N::TransactionRequest, &'a FoundryWallet),
     Browser(N::TransactionRequest, &'a BrowserSigner<N>),
     Signed(N::TxEnvelope),
     AccessKey(N::TransactionRequest, &'a WalletSigner, &'a TempoAccessKeyConfig),
@@ -162,9 +163,9 @@ pub async fn send(self, provider: Arc<RootProvider<N>>) -> Result<TxHash> {
                 let pending = provider.send_transaction(tx).await?;
                 Ok(*pending.tx_hash())
             }
-            Self::Raw(tx, signer) => {
+            Self::Raw(tx, wallet) => {
                 debug!("sending transaction: {:?}", tx);
-                let signed = tx.build(signer).await?;
+                let signed = tx.build(wallet).await?;
 
                 // Submit the raw transaction
                 let pending = provider.send_raw_transaction(signed.encoded_2718().as_ref()).await?;
@@ -231,13 +232,17 @@ pub enum SendTransactionsKind<N: Network> {
     Unlocked(AddressHashSet),
     /// Send a signed transaction via `eth_sendRawTransaction`, or via browser
     Raw {
-        eth_wallets: AddressHashMap<EthereumWallet>,
+        wallet: FoundryWallet,
         browser: Option<BrowserSigner<N>>,
         access_keys: AddressHashMap<(WalletSigner, TempoAccessKeyConfig)>,
     },
 }
 
-impl<N: Network> SendTransactionsKind<N> {
+impl<N: Network> SendTransactionsKind<N>
+where
+    N::TxEnvelope: From<Signed<N::UnsignedTx>>,
+    N::UnsignedTx: SignableTransaction<Signature>,
+{
     /// Returns the [`SendTransactionKind`] for the given address
     ///
     /// Returns an error if no matching signer is found or the address is not unlocked
@@ -253,10 +258,10 @@ pub fn for_sender(
                 }
                 Ok(SendTransactionKind::Unlocked(tx))
             }
-            Self::Raw { eth_wallets, browser, access_keys } => {
+            Self::Raw { wallet, browser, access_keys } => {
                 if let Some((signer, config)) = access_keys.get(addr) {
                     Ok(SendTransactionKind::AccessKey(tx, signer, config))
-                } else if let Some(wallet) = eth_wallets.get(addr) {
+                } else if NetworkWallet::<N>::has_signer_for(wallet, addr) {
                     Ok(SendTransactionKind::Raw(tx, wallet))
                 } else if let Some(b) = browser
                     && b.address() == *addr
@@ -270,6 +275,38 @@ pub fn for_sender(
     }
 }
 
+/// Signs the raw transactions of a batch beforehand, all the transactions of a sender at once.
+///
+/// Only meant for batches sent without waiting for each other, whose transactions are not changed
+/// by [`SendTransactionKind::prepare`].
+async fn sign_raw_transactions<N: Network>(
+    batch: &mut [(SendTransactionKind<'_, N>, bool)],
+) -> Result<()>
+where
+    N::TxEnvelope: From<Signed<N::UnsignedTx>>,
+    N::UnsignedTx: SignableTransaction<Signature>,
+{
+    let mut senders: AddressHashMap<(&FoundryWallet, Vec<usize>, Vec<N::UnsignedTx>)> =
+        AddressHashMap::default();
+    for (i, (kind, _)) in batch.iter().enumerate() {
+        if let SendTransactionKind::Raw(tx, wallet) = kind {
+            let from = tx.from().expect("no sender");
+            let (_, indices, txs) =
+                senders.entry(from).or_insert_with(|| (*wallet, Vec::new(), Vec::new()));
+            indices.push(i);
+            txs.push(tx.clone().build_unsigned().map_err(|err| err.error)?);
+        }
+    }
+
+    for (sender, (wallet, indices, txs)) in senders {
+        let envelopes = wallet.sign_transactions_from::<N>(sender, txs).await?;
+        for (i, envelope) in indices.into_iter().zip(envelopes) {
+            batch[i].0 = SendTransactionKind::Signed(envelope);
+        }
+    }
+    Ok(())
+}
+
 /// State after we have bundled all
 /// [`TransactionWithMetadata`](forge_script_sequence::TransactionWithMetadata) objects into a
 /// single [`ScriptSequenceKind`] object containing one or more script sequences.
@@ -378,13 +415,12 @@ pub async fn broadcast(mut self) -> Result<BroadcastedState<FEN>> {
             }
 
             let signers = self.script_wallets.into_multi_wallet().into_signers()?;
-            let mut eth_wallets: AddressHashMap<EthereumWallet> =
-                signers.into_iter().map(|(addr, signer)| (addr, signer.into())).collect();
-            for (addr, signer) in direct_signers {
-                eth_wallets.insert(addr, signer.into());
+            let mut wallet = FoundryWallet::default();
+            for signer in signers.into_values().chain(direct_signers.into_values()) {
+                wallet.register_signer(signer);
             }
 
-            SendTransactionsKind::Raw { eth_wallets, browser: self.browser_wallet, access_keys }
+            SendTransactionsKind::Raw { wallet, browser: self.browser_wallet, access_keys }
         };
 
         let progress = ScriptProgress::default();
@@ -455,7 +491,7 @@ pub async fn broadcast(mut self) -> Result<BroadcastedState<FEN>> {
 
                 // Iterate through transactions, matching the `from` field with the associated
                 // wallet. Then send the transaction. Panics if we find a unknown `from`
-                let transactions = sequence
+                let mut transactions = sequence
                     .transactions
                     .iter()
                     .skip(already_broadcasted)
@@ -509,6 +545,12 @@ pub async fn broadcast(mut self) -> Result<BroadcastedState<FEN>> {
                     || required_addresses.len() != 1
                     || !has_batch_support(sequence.chain);
 
+                // Transactions which don't wait for each other are sent as they are: sign them all
+                // beforehand.
+                if !sequential_broadcast {
+                    sign_raw_transactions(&mut transactions).await?;
+                }
+
                 // We send transactions and wait for receipts in batches of 100, since some networks
                 // cannot handle more than that.
                 let batch_size = if sequential_broadcast { 1 } else { 100 };
@@ -522,6 +564,7 @@ pub async fn broadcast(mut self) -> Result<BroadcastedState<FEN>> {
                     ));
 
                     if !batch.is_empty() {
+                        let batch_first_receipt = sequence.receipts.len();
                         let pending_transactions =
                             batch.iter().map(|(kind, is_fixed_gas_limit)| {
                                 let provider = provider.clone();
@@ -595,12 +638,44 @@ pub async fn broadcast(mut self) -> Result<BroadcastedState<FEN>> {
                                 &provider,
                                 self.script_config.config.transaction_timeout,
                             )
-                            .await?
+                            .await?;
+
+                        if self.args.slow
+                            && let Some(confirmations) = self.args.confirmations
+                        {
+                            progress
+                                .wait_for_confirmations(
+                                    i,
+                                    sequence,
+                                    &provider,
+                                    batch_first_receipt,
+                                    confirmations,
+                                    self.script_config.config.transaction_timeout,
+                                )
+                                .await?;
+                        }
                     }
                     // Checkpoint save
                     self.sequence.save(true, false)?;
                     sequence = self.sequence.sequences_mut().get_mut(i).unwrap();
                 }
+
+                if !self.args.slow
+                    && let Some(confirmations) = self.args.confirmations
+                {
+                    progress
+                        .wait_for_confirmations(
+                            i,
+                            sequence,
+                            &provider,
+                            already_broadcasted,
+                            confirmations,
+                            self.script_config.config.transaction_timeout,
+                        )
+                        .await?;
+                    self.sequence.save(true, false)?;
+                    sequence = self.sequence.sequences_mut().get_mut(i).unwrap();
+                }
             }
 
             let (total_gas, total_gas_price, total_paid) =
@@ -703,7 +778,7 @@ pub async fn broadcast_batch(mut self) -> Result<BroadcastedState<TempoEvmNetwor
         // Get wallet for signing
         enum BatchSigner {
             Unlocked,
-            Wallet(EthereumWallet),
+            Wallet(FoundryWallet),
             TempoKeychain(Box<WalletSigner>, Box<TempoAccessKeyConfig>),
         }
 
@@ -712,11 +787,11 @@ enum BatchSigner {
         } else {
             let mut signers = self.script_wallets.into_multi_wallet().into_signers()?;
             if let Some(signer) = signers.remove(&sender) {
-                BatchSigner::Wallet(EthereumWallet::new(signer))
+                BatchSigner::Wallet(FoundryWallet::new(signer))
             } else {
                 // Try Tempo keys.toml fallback
                 match lookup_signer(sender)? {
-                    TempoLookup::Direct(signer) => BatchSigner::Wallet(EthereumWallet::new(signer)),
+                    TempoLookup::Direct(signer) => BatchSigner::Wallet(FoundryWallet::new(signer)),
                     TempoLookup::Keychain(signer, config) => {
                         BatchSigner::TempoKeychain(Box::new(signer), config)
                     }
@@ -927,3 +1002,75 @@ enum BatchSigner {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{Transaction, transaction::SignerRecoverable};
+    use alloy_network::Ethereum;
+    use alloy_signer_local::PrivateKeySigner;
+
+    #[tokio::test]
+    async fn signs_raw_transactions_with_the_sender_signer() {
+        let signer = PrivateKeySigner::random();
+        let sender = signer.address();
+        let other = PrivateKeySigner::random();
+        let mut wallet = FoundryWallet::new(WalletSigner::Local(other));
+        wallet.register_signer(WalletSigner::Local(signer));
+        let send_kind = SendTransactionsKind::<Ethereum>::Raw {
+            wallet,
+            browser: None,
+            access_keys: AddressHashMap::default(),
+        };
+
+        let tx = TransactionRequest::default()
+            .with_from(sender)
+            .with_to(Address::ZERO)
+            .with_chain_id(1)
+            .with_nonce(0)
+            .with_gas_limit(21_000)
+            .with_max_fee_per_gas(2)
+            .with_max_priority_fee_per_gas(1);
+        let Ok(SendTransactionKind::Raw(tx, wallet)) = send_kind.for_sender(&sender, tx) else {
+            panic!("expected a raw transaction");
+        };
+        let signed = tx.build(wallet).await.unwrap();
+        assert_eq!(signed.recover_signer().unwrap(), sender);
+
+        let err = send_kind.for_sender(&Address::ZERO, TransactionRequest::default()).err();
+        assert!(err.unwrap().to_string().contains("No matching signer"));
+    }
+
+    #[tokio::test]
+    async fn signs_raw_transactions_of_a_batch() {
+        let signer = PrivateKeySigner::random();
+        let sender = signer.address();
+        let wallet = FoundryWallet::new(WalletSigner::Local(signer));
+
+        let tx = |nonce| {
+            TransactionRequest::default()
+                .with_from(sender)
+                .with_to(Address::ZERO)
+                .with_chain_id(1)
+                .with_nonce(nonce)
+                .with_gas_limit(21_000)
+                .with_max_fee_per_gas(2)
+                .with_max_priority_fee_per_gas(1)
+        };
+        let mut batch = vec![
+            (SendTransactionKind::<Ethereum>::Raw(tx(0), &wallet), false),
+            (SendTransactionKind::Unlocked(tx(1)), false),
+            (SendTransactionKind::Raw(tx(2), &wallet), false),
+        ];
+        sign_raw_transactions(&mut batch).await.unwrap();
+
+        for (i, nonce) in [(0, 0), (2, 2)] {
+            let SendTransactionKind::Signed(envelope) = &batch[i].0 else {
+                panic!("expected a signed transaction");
+            };
+            assert_eq!(envelope.nonce(), nonce);
+            assert_eq!(envelope.recover_signer().unwrap(), sender);
+        }
+        assert!(matches!(batch[1].0, SendTransactionKind::Unlocked(_)));
+    }
+}
@@ -18,6 +18,7 @@
     info::ContractInfo,
     utils::source_files_iter,
 };
+use foundry_config::ArtifactField;
 use foundry_evm::{core::evm::FoundryEvmNetwork, traces::debug::ContractSources};
 use foundry_linking::Linker;
 use foundry_wallets::wallet_browser::signer::BrowserSigner;
@@ -169,7 +170,7 @@ impl<FEN: FoundryEvmNetwork> PreprocessedState<FEN> {
     /// After compilation, finds exact [ArtifactId] of the target contract.
     pub fn compile(self) -> Result<CompiledState<FEN>> {
         let Self { args, script_config, script_wallets, browser_wallet } = self;
-        let project = script_config.config.project()?;
+        let project = script_config.config.slim_project(&ArtifactField::UNUSED_BY_EXECUTION)?;
 
         let mut target_name = args.target_contract.clone();
 
@@ -263,13 +264,7 @@ pub async fn link(self) -> Result<LinkedState<FEN>> {
 
     /// Tries loading the resumed state from the cache files, skipping simulation stage.
     pub async fn resume(self) -> Result<BundledState<FEN>> {
-        let chain = if self.args.multi {
-            None
-        } else {
-            let fork_url = self.script_config.evm_opts.fork_url.clone().ok_or_eyre("Missing --fork-url field, if you were trying to broadcast a multi-chain sequence, please use --multi flag")?;
-            let provider = Arc::new(ProviderBuilder::<AnyNetwork>::new(&fork_url).build()?);
-            Some(provider.get_chain_id().await?)
-        };
+        let chain = self.sequence_chain().await?;
 
         let sequence = match self.try_load_sequence(chain, false) {
             Ok(sequence) => sequence,
@@ -354,7 +349,17 @@ pub async fn resume(self) -> Result<BundledState<FEN>> {
         })
     }
 
-    fn try_load_sequence(
+    /// Returns the chain of the script sequence, or `None` for a multi-chain sequence.
+    pub async fn sequence_chain(&self) -> Result<Option<u64>> {
+        if self.args.multi {
+            return Ok(None);
+        }
+        let fork_url = self.script_config.evm_opts.fork_url.clone().ok_or_eyre("Missing --fork-url field, if you were trying to broadcast a multi-chain sequence, please use --multi flag")?;
+        let provider = Arc::new(ProviderBuilder::<AnyNetwork>::new(&fork_url).build()?);
+        Ok(Some(provider.get_chain_id().await?))
+    }
+
+    pub fn try_load_sequence(
         &self,
         chain: Option<u64>,
         dry_run: bool,
@@ -0,0 +1,358 @@
+//! Comparison of broadcast transactions with their simulation.
+
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use crate::build::CompiledState;
+use alloy_network::ReceiptResponse;
+use alloy_primitives::{Address, B256, Log, TxHash};
+use alloy_provider::Provider;
+use alloy_rpc_types::trace::geth::DiffMode;
+use eyre::Result;
+use forge_script_sequence::SimulationSnapshot;
+use foundry_common::{provider::ProviderBuilder, shell};
+use foundry_evm::core::evm::FoundryEvmNetwork;
+use serde::Serialize;
+
+/// A difference between the simulation of a transaction and its onchain execution.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+enum Divergence {
+    /// The transaction reverted onchain.
+    Reverted,
+    /// The transaction used a different amount of gas.
+    GasUsed { simulated: u64, onchain: u64 },
+    /// The transaction emitted different logs, starting at `index`.
+    #[serde(rename_all = "camelCase")]
+    Logs { index: usize, simulated: Option<Log>, onchain: Option<Log> },
+    /// The transaction wrote a different value to a storage slot, `None` if it didn't write it.
+    Storage { address: Address, slot: B256, simulated: Option<B256>, onchain: Option<B256> },
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Reverted => write!(f, "reverted onchain"),
+            Self::GasUsed { simulated, onchain } => {
+                write!(f, "gas used: simulated {simulated}, onchain {onchain}")
+            }
+            Self::Logs { index, simulated, onchain } => {
+                let format_log = |log: &Option<Log>| match log {
+                    Some(log) => format!("emitted by {}", log.address),
+                    None => "none".to_string(),
+                };
+                write!(
+                    f,
+                    "log {index}: simulated {}, onchain {}",
+                    format_log(simulated),
+                    format_log(onchain)
+                )
+            }
+            Self::Storage { address, slot, simulated, onchain } => {
+                let format_value = |value: &Option<B256>| match value {
+                    Some(value) => value.to_string(),
+                    None => "unchanged".to_string(),
+                };
+                write!(
+                    f,
+                    "storage slot {slot} of {address}: simulated {}, onchain {}",
+                    format_value(simulated),
+                    format_value(onchain)
+                )
+            }
+        }
+    }
+}
+
+/// The comparison of a broadcast transaction with its simulation.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionReport {
+    chain: u64,
+    hash: TxHash,
+    contract_name: Option<String>,
+    function: Option<String>,
+    divergences: Vec<Divergence>,
+}
+
+impl<FEN: FoundryEvmNetwork> CompiledState<FEN> {
+    /// Compares the broadcast transactions of the script with their simulation, and fails if any
+    /// of them diverged.
+    pub async fn verify_broadcast(self) -> Result<()> {
+        let chain = self.sequence_chain().await?;
+        let sequence = self.try_load_sequence(chain, false)?;
+
+        let mut reports = Vec::new();
+        for sequence in sequence.sequences() {
+            let provider = ProviderBuilder::<FEN::Network>::new(sequence.rpc_url()).build()?;
+            let mut trace_storage = true;
+
+            for tx in &sequence.transactions {
+                let Some(hash) = tx.hash else {
+                    sh_warn!("Skipping a transaction that was not broadcast.")?;
+                    continue;
+                };
+                let Some(simulation) = &tx.simulation else {
+                    sh_warn!("Skipping transaction {hash} which was not simulated.")?;
+                    continue;
+                };
+                let Some(receipt) = provider.get_transaction_receipt(hash).await? else {
+                    eyre::bail!("transaction {hash} was not found onchain");
+                };
+
+                let mut logs = serde_json::to_value(&receipt)?;
+                let logs: Vec<alloy_rpc_types::Log> = serde_json::from_value(logs["logs"].take())?;
+                let logs = logs.into_iter().map(|log| log.inner).collect::<Vec<_>>();
+
+                // Storage writes require the prestate tracer, which not all RPCs support.
+                let storage_writes = if trace_storage {
+                    match provider
+                        .raw_request::<_, DiffMode>(
+                            Cow::Borrowed("debug_traceTransaction"),
+                            (
+                                hash,
+                                serde_json::json!({
+                                    "tracer": "prestateTracer",
+                                    "tracerConfig": { "diffMode": true }
+                                }),
+                            ),
+                        )
+                        .await
+                    {
+                        Ok(diff) => Some(storage_writes(diff)),
+                        Err(err) => {
+                            sh_warn!(
+                                "Storage writes won't be compared on chain {}, failed to trace transaction {hash}: {err}",
+                                sequence.chain
+                            )?;
+                            trace_storage = false;
+                            None
+                        }
+                    }
+                } else {
+                    None
+                };
+
+                let divergences = compare(
+                    simulation,
+                    receipt.status(),
+                    receipt.gas_used(),
+                    &logs,
+                    storage_writes.as_ref(),
+                );
+                reports.push(TransactionReport {
+                    chain: sequence.chain,
+                    hash,
+                    contract_name: tx.contract_name.clone().filter(|name| !name.is_empty()),
+                    function: tx.function.clone().filter(|function| !function.is_empty()),
+                    divergences,
+                });
+            }
+        }
+
+        let diverged = reports.iter().filter(|report| !report.divergences.is_empty()).count();
+
+        if shell::is_json() {
+            sh_println!("{}", serde_json::to_string(&reports)?)?;
+        } else {
+            for report in &reports {
+                let target = match (&report.contract_name, &report.function) {
+                    (Some(name), Some(function)) => format!(" {name}::{function}"),
+                    (Some(name), None) => format!(" {name}"),
+                    (None, Some(function)) => format!(" {function}"),
+                    (None, None) => String::new(),
+                };
+                if report.divergences.is_empty() {
+                    sh_println!(
+                        "✅  [Match] Chain: {} Hash: {:?}{target}",
+                        report.chain,
+                        report.hash
+                    )?;
+                } else {
+                    sh_println!(
+                        "❌  [Diverged] Chain: {} Hash: {:?}{target}",
+                        report.chain,
+                        report.hash
+                    )?;
+                    for divergence in &report.divergences {
+                        sh_println!("    - {divergence}")?;
+                    }
+                }
+            }
+        }
+
+        if diverged > 0 {
+            eyre::bail!(
+                "{diverged} of {} broadcast transactions diverged from their simulation",
+                reports.len()
+            );
+        }
+
+        if !shell::is_json() {
+            sh_println!("\nAll {} broadcast transactions match their simulation.", reports.len())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the storage slots written by a transaction, with their new values, from its prestate
+/// diff.
+fn storage_writes(diff: DiffMode) -> BTreeMap<Address, BTreeMap<B256, B256>> {
+    let mut writes: BTreeMap<Address, BTreeMap<B256, B256>> = BTreeMap::new();
+    // Slots that were cleared are only present in the prestate.
+    for (address, account) in &diff.pre {
+        for (slot, value) in &account.storage {
+            let post = diff.post.get(address).and_then(|account| account.storage.get(slot));
+            if post.is_none() && !value.is_zero() {
+                writes.entry(*address).or_default().insert(*slot, B256::ZERO);
+            }
+        }
+    }
+    for (address, account) in diff.post {
+        writes.entry(address).or_default().extend(account.storage);
+    }
+    writes.retain(|_, storage| !storage.is_empty());
+    writes
+}
+
+/// Compares the simulation of a transaction with its onchain execution.
+///
+/// Storage writes are only compared if `storage_writes` is set.
+fn compare(
+    simulation: &SimulationSnapshot,
+    status: bool,
+    gas_used: u64,
+    logs: &[Log],
+    storage_writes: Option<&BTreeMap<Address, BTreeMap<B256, B256>>>,
+) -> Vec<Divergence> {
+    let mut divergences = Vec::new();
+
+    if !status {
+        divergences.push(Divergence::Reverted);
+    }
+
+    if simulation.gas_used != gas_used {
+        divergences.push(Divergence::GasUsed { simulated: simulation.gas_used, onchain: gas_used });
+    }
+
+    if let Some(index) =
+        (0..simulation.logs.len().max(logs.len())).find(|&i| simulation.logs.get(i) != logs.get(i))
+    {
+        divergences.push(Divergence::Logs {
+            index,
+            simulated: simulation.logs.get(index).cloned(),
+            onchain: logs.get(index).cloned(),
+        });
+    }
+
+    if let Some(storage_writes) = storage_writes {
+        let slots = simulation
+            .state_diff
+            .iter()
+            .chain(storage_writes)
+            .flat_map(|(address, storage)| storage.keys().map(|slot| (*address, *slot)))
+            .collect::<BTreeSet<_>>();
+        for (address, slot) in slots {
+            let simulated = simulation.state_diff.get(&address).and_then(|s| s.get(&slot)).copied();
+            let onchain = storage_writes.get(&address).and_then(|s| s.get(&slot)).copied();
+            if simulated != onchain {
+                divergences.push(Divergence::Storage { address, slot, simulated, onchain });
+            }
+        }
+    }
+
+    divergences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Bytes, address, b256};
+    use alloy_rpc_types::trace::geth::AccountState;
+
+    fn log(address: Address) -> Log {
+        Log::new_unchecked(address, vec![], Bytes::new())
+    }
+
+    #[test]
+    fn matching_execution_has_no_divergences() {
+        let contract = address!("0x5FbDB2315678afecb367f032d93F642f64180aa3");
+        let slot = b256!("0x0000000000000000000000000000000000000000000000000000000000000001");
+        let simulation = SimulationSnapshot {
+            gas_used: 43_000,
+            logs: vec![log(contract)],
+            state_diff: BTreeMap::from([(
+                contract,
+                BTreeMap::from([(slot, B256::with_last_byte(1))]),
+            )]),
+        };
+        let writes = simulation.state_diff.clone();
+        assert!(compare(&simulation, true, 43_000, &simulation.logs, Some(&writes)).is_empty());
+    }
+
+    #[test]
+    fn detects_divergences() {
+        let contract = address!("0x5FbDB2315678afecb367f032d93F642f64180aa3");
+        let slot = B256::with_last_byte(1);
+        let simulation = SimulationSnapshot {
+            gas_used: 43_000,
+            logs: vec![log(contract)],
+            state_diff: BTreeMap::from([(
+                contract,
+                BTreeMap::from([(slot, B256::with_last_byte(1))]),
+            )]),
+        };
+        let writes =
+            BTreeMap::from([(contract, BTreeMap::from([(slot, B256::with_last_byte(2))]))]);
+
+        let divergences = compare(&simulation, false, 48_000, &[], Some(&writes));
+        assert_eq!(
+            divergences,
+            vec![
+                Divergence::Reverted,
+                Divergence::GasUsed { simulated: 43_000, onchain: 48_000 },
+                Divergence::Logs { index: 0, simulated: Some(log(contract)), onchain: None },
+                Divergence::Storage {
+                    address: contract,
+                    slot,
+                    simulated: Some(B256::with_last_byte(1)),
+                    onchain: Some(B256::with_last_byte(2)),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn collects_storage_writes_from_prestate_diff() {
+        let contract = address!("0x5FbDB2315678afecb367f032d93F642f64180aa3");
+        let (set, cleared) = (B256::with_last_byte(1), B256::with_last_byte(2));
+        let diff = DiffMode {
+            pre: BTreeMap::from([(
+                contract,
+                AccountState {
+                    storage: BTreeMap::from([(cleared, B256::with_last_byte(7))]),
+                    ..Default::default()
+                },
+            )]),
+            post: BTreeMap::from([(
+                contract,
+                AccountState {
+                    storage: BTreeMap::from([(set, B256::with_last_byte(3))]),
+                    ..Default::default()
+                },
+            )]),
+        };
+
+        assert_eq!(
+            storage_writes(diff),
+            BTreeMap::from([(
+                contract,
+                BTreeMap::from([(set, B256::with_last_byte(3)), (cleared, B256::ZERO)])
+            )])
+        );
+    }
+}
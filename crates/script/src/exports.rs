@@ -0,0 +1,249 @@
+//! Address files written for frontends and other consumers after a deployment, configured in the
+//! `[script.exports]` section.
+
+use crate::verify::BroadcastedState;
+use alloy_network::Network;
+use alloy_primitives::Address;
+use eyre::Result;
+use forge_script_sequence::ScriptSequence;
+use foundry_common::{fs, shell};
+use foundry_config::{ScriptExportFormat, ScriptExportsConfig};
+use foundry_evm::core::evm::FoundryEvmNetwork;
+use revm_inspectors::tracing::types::CallKind;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+/// The manifest of the deployed addresses, the other files are generated from it.
+const MANIFEST_FILE: &str = "addresses.json";
+/// The JSON schema of the manifest.
+const SCHEMA_FILE: &str = "addresses.schema.json";
+const TYPESCRIPT_FILE: &str = "addresses.ts";
+const SOLIDITY_FILE: &str = "Addresses.sol";
+
+const GENERATED_NOTICE: &str = "This file was generated by `forge script`, do not edit it.";
+
+/// The addresses of the deployed contracts, by chain id and contract name.
+type Deployments = BTreeMap<u64, BTreeMap<String, Address>>;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Manifest {
+    #[serde(rename = "$schema", default)]
+    schema: String,
+    chains: Deployments,
+}
+
+impl<FEN: FoundryEvmNetwork> BroadcastedState<FEN> {
+    /// Writes the files configured in `[script.exports]` with the addresses of the deployed
+    /// contracts.
+    pub fn export_addresses(&self) -> Result<()> {
+        let config = &self.script_config.config;
+        let written =
+            write_exports(&config.script_exports, &config.root, self.sequence.sequences())?;
+        if !written.is_empty() && !shell::is_json() {
+            sh_println!("\nDeployed addresses exported to:")?;
+            for path in written {
+                sh_println!("{}", path.display())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes the exported files of the contracts deployed by `sequences`, and returns their paths.
+///
+/// The deployments of the chains which are not part of `sequences` are kept from the existing
+/// manifest. On the chains of `sequences`, a contract deployed several times resolves to its last
+/// deployment.
+pub fn write_exports<N: Network>(
+    config: &ScriptExportsConfig,
+    root: &Path,
+    sequences: &[ScriptSequence<N>],
+) -> Result<Vec<PathBuf>> {
+    if config.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let out = root.join(&config.out);
+    fs::create_dir_all(&out)?;
+
+    let manifest_path = out.join(MANIFEST_FILE);
+    let mut manifest: Manifest = if manifest_path.exists() {
+        fs::read_json_file(&manifest_path)?
+    } else {
+        Manifest::default()
+    };
+    manifest.schema = format!("./{SCHEMA_FILE}");
+    for sequence in sequences {
+        let contracts = deployed_contracts(sequence);
+        if !contracts.is_empty() {
+            manifest.chains.insert(sequence.chain, contracts);
+        }
+    }
+
+    let mut written = Vec::new();
+    let mut write = |file: &str, contents: String| -> Result<()> {
+        let path = out.join(file);
+        fs::write(&path, contents)?;
+        written.push(path);
+        Ok(())
+    };
+    write(MANIFEST_FILE, serde_json::to_string_pretty(&manifest)? + "\n")?;
+    write(SCHEMA_FILE, serde_json::to_string_pretty(&manifest_schema())? + "\n")?;
+    if config.formats.contains(&ScriptExportFormat::Typescript) {
+        write(TYPESCRIPT_FILE, typescript(&manifest.chains))?;
+    }
+    if config.formats.contains(&ScriptExportFormat::Solidity) {
+        write(SOLIDITY_FILE, solidity(&manifest.chains))?;
+    }
+    Ok(written)
+}
+
+/// Returns the named contracts deployed by the sequence.
+fn deployed_contracts<N: Network>(sequence: &ScriptSequence<N>) -> BTreeMap<String, Address> {
+    let mut contracts = BTreeMap::new();
+    for tx in &sequence.transactions {
+        if matches!(tx.call_kind, CallKind::Create | CallKind::Create2)
+            && let (Some(name), Some(address)) = (&tx.contract_name, tx.contract_address)
+        {
+            contracts.insert(name.clone(), address);
+        }
+        for contract in &tx.additional_contracts {
+            if let Some(name) = &contract.contract_name {
+                contracts.insert(name.clone(), contract.address);
+            }
+        }
+    }
+    contracts.retain(|name, _| !name.is_empty());
+    contracts
+}
+
+fn manifest_schema() -> serde_json::Value {
+    serde_json::json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "Deployed addresses",
+        "description": GENERATED_NOTICE,
+        "type": "object",
+        "required": ["chains"],
+        "properties": {
+            "$schema": { "type": "string" },
+            "chains": {
+                "description": "The addresses of the deployed contracts, by chain id and contract name.",
+                "type": "object",
+                "propertyNames": { "pattern": "^[0-9]+$" },
+                "additionalProperties": {
+                    "type": "object",
+                    "additionalProperties": {
+                        "type": "string",
+                        "pattern": "^0x[0-9a-fA-F]{40}$"
+                    }
+                }
+            }
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Returns a TypeScript module exporting the addresses as constants.
+fn typescript(deployments: &Deployments) -> String {
+    let mut out = format!("// {GENERATED_NOTICE}\n\nexport const addresses = {{\n");
+    for (chain, contracts) in deployments {
+        let _ = writeln!(out, "  {chain}: {{");
+        for (name, address) in contracts {
+            let _ = writeln!(out, "    {name}: \"{address}\",");
+        }
+        out.push_str("  },\n");
+    }
+    out.push_str("} as const;\n\nexport type ChainId = keyof typeof addresses;\n");
+    out
+}
+
+/// Returns a Solidity library with a getter per contract, resolving its address on the current
+/// chain.
+fn solidity(deployments: &Deployments) -> String {
+    let mut chains_by_contract = BTreeMap::<&str, Vec<(u64, Address)>>::new();
+    for (chain, contracts) in deployments {
+        for (name, address) in contracts {
+            chains_by_contract.entry(name).or_default().push((*chain, *address));
+        }
+    }
+
+    let mut out = format!(
+        "// SPDX-License-Identifier: UNLICENSED\n// {GENERATED_NOTICE}\npragma solidity >=0.8.4;\n\n\
+         library Addresses {{\n    error NotDeployed(string name, uint256 chainId);\n"
+    );
+    for (name, chains) in chains_by_contract {
+        let _ = write!(out, "\n    function {name}() internal view returns (address) {{\n");
+        for (chain, address) in chains {
+            let _ = writeln!(out, "        if (block.chainid == {chain}) return {address};");
+        }
+        let _ = writeln!(out, "        revert NotDeployed(\"{name}\", block.chainid);\n    }}");
+    }
+    out.push_str("}\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    fn deployments() -> Deployments {
+        let counter = address!("0x5FbDB2315678afecb367f032d93F642f64180aa3");
+        let token = address!("0xe7f1725E7734CE288F8367e1Bb143E90bb3F0512");
+        BTreeMap::from([
+            (1, BTreeMap::from([("Counter".to_string(), counter)])),
+            (10, BTreeMap::from([("Counter".to_string(), token), ("Token".to_string(), token)])),
+        ])
+    }
+
+    #[test]
+    fn generates_typescript() {
+        assert_eq!(
+            typescript(&deployments()),
+            r#"// This file was generated by `forge script`, do not edit it.
+
+export const addresses = {
+  1: {
+    Counter: "0x5FbDB2315678afecb367f032d93F642f64180aa3",
+  },
+  10: {
+    Counter: "0xe7f1725E7734CE288F8367e1Bb143E90bb3F0512",
+    Token: "0xe7f1725E7734CE288F8367e1Bb143E90bb3F0512",
+  },
+} as const;
+
+export type ChainId = keyof typeof addresses;
+"#
+        );
+    }
+
+    #[test]
+    fn generates_solidity() {
+        assert_eq!(
+            solidity(&deployments()),
+            r#"// SPDX-License-Identifier: UNLICENSED
+// This file was generated by `forge script`, do not edit it.
+pragma solidity >=0.8.4;
+
+library Addresses {
+    error NotDeployed(string name, uint256 chainId);
+
+    function Counter() internal view returns (address) {
+        if (block.chainid == 1) return 0x5FbDB2315678afecb367f032d93F642f64180aa3;
+        if (block.chainid == 10) return 0xe7f1725E7734CE288F8367e1Bb143E90bb3F0512;
+        revert NotDeployed("Counter", block.chainid);
+    }
+
+    function Token() internal view returns (address) {
+        if (block.chainid == 10) return 0xe7f1725E7734CE288F8367e1Bb143E90bb3F0512;
+        revert NotDeployed("Token", block.chainid);
+    }
+}
+"#
+        );
+    }
+}
@@ -25,7 +25,7 @@
 use clap::{Parser, ValueHint};
 use dialoguer::Confirm;
 use eyre::{ContextCompat, Result};
-use forge_script_sequence::{AdditionalContract, NestedValue};
+use forge_script_sequence::{AdditionalContract, NestedValue, SimulationSnapshot};
 use forge_verify::{RetryArgs, VerifierArgs};
 use foundry_cli::{
     opts::{BuildOpts, EvmArgs, GlobalArgs},
@@ -62,19 +62,25 @@
 };
 use foundry_evm_networks::NetworkConfigs;
 use foundry_wallets::MultiWalletOpts;
+use schedule::ScheduleArgs;
 use serde::Serialize;
+use sponsor::SponsorArgs;
 use std::path::PathBuf;
 
 mod broadcast;
 mod build;
+mod divergence;
 mod execute;
+mod exports;
 mod multi_sequence;
 mod progress;
 mod providers;
 mod receipts;
 mod runner;
+mod schedule;
 mod sequence;
 mod simulate;
+mod sponsor;
 mod transaction;
 mod verify;
 
@@ -168,7 +174,15 @@ pub struct ScriptArgs {
     #[arg(long)]
     pub resume: bool,
 
-    /// If present, --resume or --verify will be assumed to be a multi chain deployment.
+    /// Compares the broadcast transactions with their simulation.
+    ///
+    /// Flags the transactions whose onchain gas usage, logs or storage writes diverged from the
+    /// ones recorded at simulation time, e.g. because they were front-run or the state drifted.
+    #[arg(long, conflicts_with_all = ["broadcast", "resume", "verify", "debug"])]
+    pub verify_broadcast: bool,
+
+    /// If present, --resume, --verify or --verify-broadcast will be assumed to be a multi chain
+    /// deployment.
     #[arg(long)]
     pub multi: bool,
 
@@ -192,6 +206,14 @@ pub struct ScriptArgs {
     #[arg(long)]
     pub slow: bool,
 
+    /// The number of confirmations to wait for before considering the transactions sent.
+    ///
+    /// With `--slow`, each transaction is confirmed before the next one is sent. Otherwise, the
+    /// transactions of each chain are confirmed once they are all sent. Transactions dropped by
+    /// a reorg are re-broadcast.
+    #[arg(long, value_name = "CONFIRMATIONS")]
+    pub confirmations: Option<u64>,
+
     /// Disables interactive prompts that might appear when deploying big contracts.
     ///
     /// For more info on the contract size limit, see EIP-170: <https://eips.ethereum.org/EIPS/eip-170>
@@ -244,6 +266,12 @@ pub struct ScriptArgs {
 
     #[command(flatten)]
     pub retry: RetryArgs,
+
+    #[command(flatten)]
+    pub sponsor: SponsorArgs,
+
+    #[command(flatten)]
+    pub schedule: ScheduleArgs,
 }
 
 impl ScriptArgs {
@@ -310,13 +338,20 @@ pub async fn run_script(self) -> Result<()> {
 
         if is_tempo {
             let batch = self.batch;
+            let sponsored = self.sponsor.is_enabled();
             let bundled = match self.prepare_bundled::<TempoEvmNetwork>(config, evm_opts).await? {
                 Some(bundled) => bundled,
                 None => return Ok(()),
             };
             let bundled = bundled.wait_for_pending().await?;
-            let broadcasted =
-                if batch { bundled.broadcast_batch().await? } else { bundled.broadcast().await? };
+            let broadcasted = if batch {
+                bundled.broadcast_batch().await?
+            } else if sponsored {
+                bundled.broadcast_sponsored().await?
+            } else {
+                bundled.broadcast().await?
+            };
+            broadcasted.export_addresses()?;
             if broadcasted.args.verify {
                 broadcasted.verify().await?;
             }
@@ -341,13 +376,18 @@ async fn prepare_bundled<FEN: FoundryEvmNetwork>(
         let create2_deployer = state.script_config.evm_opts.create2_deployer;
         let compiled = state.compile()?;
 
+        if compiled.args.verify_broadcast {
+            compiled.verify_broadcast().await?;
+            return Ok(None);
+        }
+
         // Move from `CompiledState` to `BundledState` either by resuming or executing and
         // simulating script.
         let bundled = if compiled.args.resume {
             compiled.resume().await?
         } else {
             // Drive state machine to point at which we have everything needed for simulation.
-            let pre_simulation = compiled
+            let mut pre_simulation = compiled
                 .link()
                 .await?
                 .prepare_execution()
@@ -394,6 +434,9 @@ async fn prepare_bundled<FEN: FoundryEvmNetwork>(
                 return Ok(None);
             }
 
+            // Queue the transactions in a timelock instead of sending them, if requested.
+            pre_simulation.schedule().await?;
+
             pre_simulation.args.check_contract_sizes(
                 &pre_simulation.execution_result,
                 &pre_simulation.build_data.known_contracts,
@@ -437,7 +480,13 @@ async fn run_generic_script<FEN: FoundryEvmNetwork>(
         };
 
         // Wait for pending txes and broadcast others.
-        let broadcasted = bundled.wait_for_pending().await?.broadcast().await?;
+        let bundled = bundled.wait_for_pending().await?;
+        let broadcasted = if bundled.args.sponsor.is_enabled() {
+            bundled.broadcast_sponsored().await?
+        } else {
+            bundled.broadcast().await?
+        };
+        broadcasted.export_addresses()?;
 
         if broadcasted.args.verify {
             broadcasted.verify().await?;
@@ -643,6 +692,8 @@ pub struct ScriptResult<N: Network> {
     pub address: Option<Address>,
     #[serde(skip)]
     pub breakpoints: Breakpoints,
+    #[serde(skip)]
+    pub simulation: Option<SimulationSnapshot>,
 }
 
 impl<N: Network> Default for ScriptResult<N> {
@@ -658,6 +709,7 @@ fn default() -> Self {
             exit_reason: Default::default(),
             address: Default::default(),
             breakpoints: Default::default(),
+            simulation: Default::default(),
         }
     }
 }
@@ -834,6 +886,46 @@ fn can_parse_sig() {
         assert_eq!(args.sig, sig);
     }
 
+    #[test]
+    fn can_parse_schedule() {
+        let args = ScriptArgs::parse_from([
+            "foundry-cli",
+            "Contract.sol",
+            "--schedule",
+            "0x0000000000000000000000000000000000001234",
+            "--schedule.kind",
+            "compound",
+            "--schedule.delay",
+            "172800",
+        ]);
+        assert_eq!(args.schedule.timelock, Some(Address::left_padding_from(&[0x12, 0x34])));
+        assert_eq!(args.schedule.kind, schedule::TimelockKind::Compound);
+        assert_eq!(args.schedule.delay, Some(172800));
+        assert_eq!(args.schedule.buffer, 600);
+    }
+
+    #[test]
+    fn can_parse_sponsor() {
+        let args = ScriptArgs::parse_from([
+            "foundry-cli",
+            "Contract.sol",
+            "--broadcast",
+            "--sponsor.paymaster-url",
+            "http://localhost:4337",
+        ]);
+        assert!(args.sponsor.is_enabled());
+        assert_eq!(args.sponsor.bundler_url, None);
+
+        let err = ScriptArgs::try_parse_from([
+            "foundry-cli",
+            "Contract.sol",
+            "--sponsor.paymaster-url",
+            "http://localhost:4337",
+        ])
+        .unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::MissingRequiredArgument);
+    }
+
     #[test]
     fn can_parse_unlocked() {
         let args = ScriptArgs::parse_from([
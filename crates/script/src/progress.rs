@@ -9,7 +9,7 @@
 use eyre::Result;
 use forge_script_sequence::ScriptSequence;
 use foundry_cli::utils::init_progress;
-use foundry_common::shell;
+use foundry_common::{ConfirmationWaiter, shell};
 use futures::StreamExt;
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use parking_lot::RwLock;
@@ -302,4 +302,39 @@ pub async fn wait_for_pending<N: Network>(
 
         Ok(())
     }
+
+    /// Waits for the receipts of the sequence starting at `first_receipt` to reach the given
+    /// number of confirmations, re-broadcasting the transactions dropped by reorgs, and replaces
+    /// them with their confirmed receipts.
+    pub async fn wait_for_confirmations<N: Network>(
+        &self,
+        sequence_idx: usize,
+        deployment_sequence: &mut ScriptSequence<N>,
+        provider: &RootProvider<N>,
+        first_receipt: usize,
+        confirmations: u64,
+        timeout: u64,
+    ) -> Result<()> {
+        let hashes = deployment_sequence.receipts[first_receipt..]
+            .iter()
+            .map(|receipt| receipt.transaction_hash())
+            .collect::<Vec<_>>();
+        if hashes.is_empty() || confirmations <= 1 {
+            return Ok(());
+        }
+
+        let seq_progress = self.get_sequence_progress(sequence_idx, deployment_sequence);
+        seq_progress.inner.write().set_status(&format!(
+            "Waiting for {confirmations} confirmations of {} transactions",
+            hashes.len()
+        ));
+
+        let receipts = ConfirmationWaiter::new(confirmations, Duration::from_secs(timeout))
+            .wait_all(provider, &hashes)
+            .await?;
+        deployment_sequence.receipts.truncate(first_receipt);
+        deployment_sequence.receipts.extend(receipts);
+
+        Ok(())
+    }
 }
@@ -3,8 +3,9 @@
 use alloy_eips::eip7702::SignedAuthorization;
 use alloy_evm::revm::context::Transaction;
 use alloy_network::TransactionBuilder;
-use alloy_primitives::{Address, Bytes, U256};
+use alloy_primitives::{Address, B256, Bytes, Log, U256};
 use eyre::Result;
+use forge_script_sequence::SimulationSnapshot;
 use foundry_cheatcodes::BroadcastableTransaction;
 use foundry_common::{FoundryTransactionBuilder, TransactionMaybeSigned};
 use foundry_config::Config;
@@ -18,8 +19,9 @@
     opts::EvmOpts,
     revm::interpreter::{InstructionResult, return_ok},
     traces::{TraceKind, Traces},
+    utils::StateChangeset,
 };
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 
 /// Drives script execution
 #[derive(Debug)]
@@ -275,7 +277,10 @@ pub fn simulate(
                 value.unwrap_or(U256::ZERO),
                 None,
             );
-            let (address, RawCallResult { gas_used, logs, traces, exit_reason, .. }) = match res {
+            let (
+                address,
+                RawCallResult { gas_used, logs, traces, exit_reason, state_changeset, .. },
+            ) = match res {
                 Ok(DeployResult { address, raw }) => (address, raw),
                 Err(EvmError::Execution(err)) => {
                     let ExecutionErr { raw, reason } = *err;
@@ -285,6 +290,7 @@ pub fn simulate(
                 Err(e) => eyre::bail!("Failed deploying contract: {e:?}"),
             };
 
+            let simulation = simulation_snapshot(gas_used, &logs, &state_changeset);
             Ok(ScriptResult {
                 returned: Bytes::new(),
                 success: address != Address::ZERO,
@@ -296,6 +302,7 @@ pub fn simulate(
                     .unwrap_or_default(),
                 exit_reason,
                 address: Some(address),
+                simulation: Some(simulation),
                 ..Default::default()
             })
         }
@@ -349,6 +356,8 @@ fn call(
             }
         }
 
+        let simulation =
+            commit.then(|| simulation_snapshot(res.gas_used, &res.logs, &res.state_changeset));
         let RawCallResult {
             result, reverted, logs, traces, labels, transactions, exit_reason, ..
         } = res;
@@ -371,6 +380,7 @@ fn call(
             exit_reason,
             address: None,
             breakpoints,
+            simulation,
         })
     }
 
@@ -431,3 +441,22 @@ fn search_optimal_gas_usage(
         Ok(gas_used)
     }
 }
+
+/// Records the gas used, logs and storage writes of a simulated transaction.
+fn simulation_snapshot(
+    gas_used: u64,
+    logs: &[Log],
+    state_changeset: &StateChangeset,
+) -> SimulationSnapshot {
+    let state_diff = state_changeset
+        .iter()
+        .filter_map(|(address, account)| {
+            let storage = account
+                .changed_storage_slots()
+                .map(|(slot, value)| (B256::from(*slot), B256::from(value.present_value)))
+                .collect::<BTreeMap<_, _>>();
+            (!storage.is_empty()).then_some((*address, storage))
+        })
+        .collect();
+    SimulationSnapshot { gas_used, logs: logs.to_vec(), state_diff }
+}
@@ -0,0 +1,408 @@
+//! Scheduling of script transactions as timelock operations.
+
+use crate::simulate::PreSimulationState;
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockId;
+use alloy_network::{AnyNetwork, BlockResponse, TransactionBuilder};
+use alloy_primitives::{Address, B256, Bytes, U256, keccak256};
+use alloy_provider::Provider;
+use alloy_rpc_types::TransactionInputKind;
+use alloy_sol_types::{SolCall, SolValue, sol};
+use chrono::DateTime;
+use clap::{Parser, ValueEnum};
+use eyre::{OptionExt, Result, bail};
+use forge_script_sequence::ScriptSequence;
+use foundry_common::{FoundryTransactionBuilder, fs, provider::ProviderBuilder, shell};
+use foundry_evm::core::evm::FoundryEvmNetwork;
+use serde::Serialize;
+use std::{fmt::Write, path::Path};
+
+sol! {
+    interface ITimelockController {
+        function getMinDelay() external view returns (uint256);
+        function schedule(address target, uint256 value, bytes calldata data, bytes32 predecessor, bytes32 salt, uint256 delay) external;
+        function execute(address target, uint256 value, bytes calldata payload, bytes32 predecessor, bytes32 salt) external payable;
+    }
+
+    interface ICompoundTimelock {
+        function delay() external view returns (uint256);
+        function GRACE_PERIOD() external view returns (uint256);
+        function queueTransaction(address target, uint256 value, string memory signature, bytes memory data, uint256 eta) external returns (bytes32);
+        function executeTransaction(address target, uint256 value, string memory signature, bytes memory data, uint256 eta) external payable returns (bytes memory);
+    }
+}
+
+/// The timelock implementations supported by `--schedule`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TimelockKind {
+    /// OpenZeppelin's `TimelockController`.
+    #[default]
+    #[value(name = "oz")]
+    OpenZeppelin,
+    /// Compound's `Timelock`, as used by `GovernorBravo`.
+    Compound,
+}
+
+/// CLI arguments for queueing the transactions of `forge script` in a timelock.
+#[derive(Clone, Debug, Default, Parser)]
+#[command(next_help_heading = "Schedule options")]
+pub struct ScheduleArgs {
+    /// Queue the transactions in the timelock at this address instead of sending them, and write
+    /// the plan to execute them once the delay has passed.
+    ///
+    /// The transactions are sent by their sender, which must be allowed to queue operations.
+    #[arg(long = "schedule", value_name = "TIMELOCK")]
+    pub timelock: Option<Address>,
+
+    /// The implementation of the timelock.
+    #[arg(long = "schedule.kind", value_enum, default_value_t, requires = "timelock")]
+    pub kind: TimelockKind,
+
+    /// The delay of the operations in seconds.
+    ///
+    /// Defaults to the minimum delay of the timelock.
+    #[arg(long = "schedule.delay", value_name = "SECONDS", requires = "timelock")]
+    pub delay: Option<u64>,
+
+    /// Seconds added to the eta, to account for the time until the operations are queued.
+    #[arg(
+        long = "schedule.buffer",
+        value_name = "SECONDS",
+        default_value = "600",
+        requires = "timelock"
+    )]
+    pub buffer: u64,
+
+    /// The salt of the OpenZeppelin timelock operations.
+    #[arg(long = "schedule.salt", value_name = "SALT", requires = "timelock")]
+    pub salt: Option<B256>,
+}
+
+/// An operation queued in a timelock.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ScheduledOperation {
+    target: Address,
+    value: U256,
+    data: Bytes,
+    /// The id of the operation in an OpenZeppelin timelock.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<B256>,
+    /// The operation that must be executed before this one in an OpenZeppelin timelock.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    predecessor: Option<B256>,
+    /// The calldata queueing the operation.
+    queue_calldata: Bytes,
+    /// The calldata executing the operation once it's ready.
+    execute_calldata: Bytes,
+}
+
+/// The plan to execute the operations queued in a timelock on a chain.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SchedulePlan {
+    chain: u64,
+    timelock: Address,
+    kind: TimelockKind,
+    /// The delay of the operations, in seconds.
+    delay: u64,
+    /// The earliest timestamp at which the operations can be executed.
+    eta: u64,
+    /// The timestamp after which the operations can't be executed anymore, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expiry: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    salt: Option<B256>,
+    operations: Vec<ScheduledOperation>,
+}
+
+impl SchedulePlan {
+    /// Computes the delay and eta of the operations from the timelock state.
+    async fn new(args: &ScheduleArgs, timelock: Address, rpc: &str) -> Result<Self> {
+        let provider = ProviderBuilder::<AnyNetwork>::new(rpc).build()?;
+        let chain = provider.get_chain_id().await?;
+        let timestamp = provider
+            .get_block(BlockId::latest())
+            .await?
+            .ok_or_eyre("latest block not found")?
+            .header()
+            .timestamp();
+
+        let call = |input: Vec<u8>| {
+            provider.call(
+                <AnyNetwork as alloy_network::Network>::TransactionRequest::default()
+                    .with_to(timelock)
+                    .with_input(input),
+            )
+        };
+        let (min_delay, grace_period) = match args.kind {
+            TimelockKind::OpenZeppelin => {
+                let min_delay = call(ITimelockController::getMinDelayCall {}.abi_encode()).await?;
+                (ITimelockController::getMinDelayCall::abi_decode_returns(&min_delay)?, None)
+            }
+            TimelockKind::Compound => {
+                let min_delay = call(ICompoundTimelock::delayCall {}.abi_encode()).await?;
+                let grace_period =
+                    call(ICompoundTimelock::GRACE_PERIODCall {}.abi_encode()).await?;
+                (
+                    ICompoundTimelock::delayCall::abi_decode_returns(&min_delay)?,
+                    Some(ICompoundTimelock::GRACE_PERIODCall::abi_decode_returns(&grace_period)?),
+                )
+            }
+        };
+        let min_delay = min_delay.to::<u64>();
+
+        let delay = args.delay.unwrap_or(min_delay);
+        if delay < min_delay {
+            bail!(
+                "the delay of {delay}s is below the minimum delay of the timelock ({min_delay}s)"
+            );
+        }
+        let eta = timestamp + delay + args.buffer;
+
+        Ok(Self {
+            chain,
+            timelock,
+            kind: args.kind,
+            delay,
+            eta,
+            expiry: grace_period.map(|grace_period| eta + grace_period.to::<u64>()),
+            salt: (args.kind == TimelockKind::OpenZeppelin).then(|| args.salt.unwrap_or_default()),
+            operations: Vec::new(),
+        })
+    }
+
+    /// Adds an operation to the plan, after the previous ones.
+    fn push(&mut self, target: Address, value: U256, data: Bytes) -> &ScheduledOperation {
+        let operation = match self.kind {
+            TimelockKind::OpenZeppelin => {
+                let salt = self.salt.unwrap_or_default();
+                let predecessor = self.operations.last().and_then(|op| op.id).unwrap_or_default();
+                let id =
+                    keccak256((target, value, data.clone(), predecessor, salt).abi_encode_params());
+                ScheduledOperation {
+                    queue_calldata: ITimelockController::scheduleCall {
+                        target,
+                        value,
+                        data: data.clone(),
+                        predecessor,
+                        salt,
+                        delay: U256::from(self.delay),
+                    }
+                    .abi_encode()
+                    .into(),
+                    execute_calldata: ITimelockController::executeCall {
+                        target,
+                        value,
+                        payload: data.clone(),
+                        predecessor,
+                        salt,
+                    }
+                    .abi_encode()
+                    .into(),
+                    target,
+                    value,
+                    data,
+                    id: Some(id),
+                    predecessor: Some(predecessor),
+                }
+            }
+            TimelockKind::Compound => {
+                let eta = U256::from(self.eta);
+                ScheduledOperation {
+                    queue_calldata: ICompoundTimelock::queueTransactionCall {
+                        target,
+                        value,
+                        signature: String::new(),
+                        data: data.clone(),
+                        eta,
+                    }
+                    .abi_encode()
+                    .into(),
+                    execute_calldata: ICompoundTimelock::executeTransactionCall {
+                        target,
+                        value,
+                        signature: String::new(),
+                        data: data.clone(),
+                        eta,
+                    }
+                    .abi_encode()
+                    .into(),
+                    target,
+                    value,
+                    data,
+                    id: None,
+                    predecessor: None,
+                }
+            }
+        };
+        self.operations.push(operation);
+        self.operations.last().unwrap()
+    }
+
+    /// Returns the plan as an iCalendar file, with an event at the eta of the operations.
+    fn to_ical(&self) -> String {
+        let format = |timestamp: u64| {
+            DateTime::from_timestamp(timestamp as i64, 0)
+                .unwrap_or_default()
+                .format("%Y%m%dT%H%M%SZ")
+                .to_string()
+        };
+        let mut ical = String::new();
+        let _ = write!(
+            ical,
+            "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//Foundry//forge script//EN\r\n\
+             BEGIN:VEVENT\r\nUID:{timelock}-{chain}-{eta}@foundry\r\nDTSTAMP:{eta_utc}\r\n\
+             DTSTART:{eta_utc}\r\nDTEND:{end_utc}\r\n\
+             SUMMARY:Execute {count} timelock operation(s) on chain {chain}\r\n\
+             DESCRIPTION:Timelock {timelock}\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n",
+            timelock = self.timelock,
+            chain = self.chain,
+            eta = self.eta,
+            eta_utc = format(self.eta),
+            end_utc = format(self.expiry.unwrap_or(self.eta + 3600)),
+            count = self.operations.len(),
+        );
+        ical
+    }
+
+    /// Writes the plan and its calendar next to the broadcast artifacts of the script.
+    fn save(&self, sequence_path: &Path) -> Result<()> {
+        let plan_path = sequence_path.with_file_name(
+            sequence_path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or_default()
+                .replace("-latest.json", "-schedule.json"),
+        );
+        fs::write_json_file(&plan_path, self)?;
+        fs::write(plan_path.with_extension("ics"), self.to_ical())?;
+
+        if !shell::is_json() {
+            sh_println!(
+                "\nQueueing {} operation(s) in timelock {} on chain {}, executable from {} (unix {}).",
+                self.operations.len(),
+                self.timelock,
+                self.chain,
+                DateTime::from_timestamp(self.eta as i64, 0).unwrap_or_default().to_rfc3339(),
+                self.eta
+            )?;
+            sh_println!("Execution plan saved to: {}", plan_path.display())?;
+        }
+        Ok(())
+    }
+}
+
+impl<FEN: FoundryEvmNetwork> PreSimulationState<FEN> {
+    /// Replaces the transactions of the script by the ones queueing them in the timelock, and
+    /// writes the plan to execute them.
+    pub async fn schedule(&mut self) -> Result<()> {
+        let Some(timelock) = self.args.schedule.timelock else { return Ok(()) };
+        let Some(transactions) = self.execution_result.transactions.as_mut() else {
+            return Ok(());
+        };
+
+        // One plan per RPC, as the transactions of a script can target several chains.
+        let mut plans: Vec<(String, SchedulePlan)> = Vec::new();
+        for tx in transactions.iter_mut() {
+            let rpc = tx.rpc.clone().expect("missing broadcastable tx rpc url");
+            let index = match plans.iter().position(|(plan_rpc, _)| *plan_rpc == rpc) {
+                Some(index) => index,
+                None => {
+                    let plan = SchedulePlan::new(&self.args.schedule, timelock, &rpc).await?;
+                    plans.push((rpc, plan));
+                    plans.len() - 1
+                }
+            };
+
+            let Some(request) = tx.transaction.as_unsigned_mut() else {
+                bail!("pre-signed transactions can't be scheduled");
+            };
+            let Some(target) = request.to() else {
+                bail!(
+                    "contract creations can't be scheduled in a timelock, \
+                     deploy contracts with CREATE2 instead, e.g. `new Contract{{salt: salt}}()`"
+                );
+            };
+            let value = request.value().unwrap_or_default();
+            let data = request.input().cloned().unwrap_or_default();
+
+            let operation = plans[index].1.push(target, value, data);
+            request.set_to(timelock);
+            request.set_value(U256::ZERO);
+            *request = request
+                .clone()
+                .with_input_kind(operation.queue_calldata.clone(), TransactionInputKind::Both);
+            request.reset_gas_limit();
+        }
+
+        let dry_run = !self.args.should_broadcast();
+        for (_, plan) in &plans {
+            let (sequence_path, _) = ScriptSequence::<FEN::Network>::get_paths(
+                &self.script_config.config,
+                &self.args.sig,
+                &self.build_data.build_data.target,
+                plan.chain,
+                dry_run,
+            )?;
+            plan.save(&sequence_path)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    fn plan(kind: TimelockKind) -> SchedulePlan {
+        SchedulePlan {
+            chain: 1,
+            timelock: address!("0x0000000000000000000000000000000000001234"),
+            kind,
+            delay: 172_800,
+            eta: 1_800_000_000,
+            expiry: (kind == TimelockKind::Compound).then_some(1_801_209_600),
+            salt: (kind == TimelockKind::OpenZeppelin).then_some(B256::ZERO),
+            operations: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn chains_openzeppelin_operations() {
+        let mut plan = plan(TimelockKind::OpenZeppelin);
+        let target = Address::with_last_byte(1);
+        let first = plan.push(target, U256::ZERO, Bytes::from_static(&[1])).id.unwrap();
+        let second = plan.push(target, U256::ZERO, Bytes::from_static(&[2]));
+
+        assert_eq!(second.predecessor, Some(first));
+        let call = ITimelockController::scheduleCall::abi_decode(&second.queue_calldata).unwrap();
+        assert_eq!(call.predecessor, first);
+        assert_eq!(call.delay, U256::from(172_800));
+    }
+
+    #[test]
+    fn queues_compound_operations_at_eta() {
+        let mut plan = plan(TimelockKind::Compound);
+        let operation = plan.push(Address::with_last_byte(1), U256::ZERO, Bytes::from_static(&[1]));
+
+        let queue =
+            ICompoundTimelock::queueTransactionCall::abi_decode(&operation.queue_calldata).unwrap();
+        let execute =
+            ICompoundTimelock::executeTransactionCall::abi_decode(&operation.execute_calldata)
+                .unwrap();
+        assert_eq!(queue.eta, U256::from(1_800_000_000));
+        assert_eq!(execute.eta, queue.eta);
+        assert!(queue.signature.is_empty());
+    }
+
+    #[test]
+    fn renders_ical() {
+        let ical = plan(TimelockKind::Compound).to_ical();
+        assert!(ical.contains("DTSTART:20270115T080000Z\r\n"));
+        assert!(ical.contains("DTEND:20270129T080000Z\r\n"));
+    }
+}
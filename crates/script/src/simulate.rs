@@ -18,7 +18,7 @@
 use forge_script_sequence::{ScriptSequence, TransactionWithMetadata};
 use foundry_cheatcodes::Wallets;
 use foundry_cli::utils::{has_different_gas_calc, now};
-use foundry_common::{ContractData, shell};
+use foundry_common::{ContractData, provenance::BuildProvenance, shell};
 use foundry_evm::{
     core::{FoundryBlock, evm::FoundryEvmNetwork},
     traces::{decode_trace_arena, render_trace_arena},
@@ -450,7 +450,14 @@ fn create_sequence(
             )?)
         };
 
-        let commit = get_commit_hash(&self.script_config.config.root);
+        let config = &self.script_config.config;
+        let commit = get_commit_hash(&config.root);
+        // The commit is already recorded in the sequence, so it is left out of the provenance.
+        let provenance = if config.provenance {
+            Some(BuildProvenance::new(config, &self.build_data.build_data.output, None)?)
+        } else {
+            None
+        };
 
         let libraries = self
             .build_data
@@ -473,6 +480,7 @@ fn create_sequence(
             libraries,
             chain,
             commit,
+            provenance,
         };
         Ok(sequence)
     }
@@ -0,0 +1,683 @@
+//! Sponsored broadcasting of script transactions as ERC-4337 user operations.
+
+use std::{borrow::Cow, sync::Arc, time::Duration};
+
+use crate::{broadcast::BundledState, verify::BroadcastedState};
+use alloy_eips::eip7702::{
+    Authorization, SignedAuthorization, constants::EIP7702_DELEGATION_DESIGNATOR,
+};
+use alloy_network::{AnyNetwork, Network, TransactionBuilder};
+use alloy_primitives::{Address, B256, Bytes, TxHash, U256, address, hex, keccak256};
+use alloy_provider::Provider;
+use alloy_rpc_types::state::{AccountOverride, StateOverride};
+use alloy_signer::Signer;
+use alloy_sol_types::{SolCall, sol};
+use clap::Parser;
+use eyre::{Context, OptionExt, Result, bail};
+use forge_script_sequence::TransactionWithMetadata;
+use foundry_common::{TransactionMaybeSigned, provider::ProviderBuilder, shell};
+use foundry_config::Config;
+use foundry_evm::core::evm::FoundryEvmNetwork;
+use foundry_wallets::WalletSigner;
+use revm_inspectors::tracing::types::CallKind;
+use serde::{Deserialize, Serialize};
+
+sol! {
+    struct PackedUserOperation {
+        address sender;
+        uint256 nonce;
+        bytes initCode;
+        bytes callData;
+        bytes32 accountGasLimits;
+        uint256 preVerificationGas;
+        bytes32 gasFees;
+        bytes paymasterAndData;
+        bytes signature;
+    }
+
+    interface IEntryPoint {
+        function getNonce(address sender, uint192 key) external view returns (uint256 nonce);
+        function getUserOpHash(PackedUserOperation calldata userOp) external view returns (bytes32);
+    }
+
+    interface IAccount {
+        function execute(address target, uint256 value, bytes calldata data) external;
+    }
+}
+
+/// The ERC-4337 EntryPoint v0.8, which supports EIP-7702 delegated accounts.
+pub const ENTRY_POINT_V08: Address = address!("0x4337084D9E255Ff0702461CF8895CE9E3b5Ff108");
+
+/// The factory marking the user operations that delegate their sender with EIP-7702.
+const EIP7702_FACTORY: Address = address!("0x7702000000000000000000000000000000000000");
+
+/// A signature that recovers to some address, used to estimate the gas of user operations before
+/// they are signed.
+const DUMMY_SIGNATURE: [u8; 65] = hex!(
+    "fffffffffffffffffffffffffffffff0000000000000000000000000000000007aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa1c"
+);
+
+/// CLI arguments for broadcasting the transactions of `forge script` as sponsored user
+/// operations.
+#[derive(Clone, Debug, Default, Parser)]
+#[command(next_help_heading = "Sponsorship options")]
+pub struct SponsorArgs {
+    /// Broadcast the transactions as ERC-4337 user operations sponsored by the ERC-7677
+    /// paymaster at this URL.
+    ///
+    /// The sender doesn't need to hold gas tokens, but must be an EOA delegated with EIP-7702 to
+    /// an account implementing `execute(address,uint256,bytes)`, such as `Simple7702Account`, or
+    /// `--sponsor.delegate` must be set.
+    ///
+    /// Accounts can't create contracts with CREATE, so contract creations are sent to the CREATE2
+    /// deployer instead, and are deployed at a different address than simulated.
+    #[arg(
+        long = "sponsor.paymaster-url",
+        value_name = "URL",
+        requires = "broadcast",
+        conflicts_with_all = ["batch", "unlocked"]
+    )]
+    pub paymaster_url: Option<String>,
+
+    /// The bundler to send the user operations to.
+    ///
+    /// Defaults to the paymaster URL.
+    #[arg(long = "sponsor.bundler-url", value_name = "URL", requires = "paymaster_url")]
+    pub bundler_url: Option<String>,
+
+    /// The EntryPoint contract of the user operations.
+    ///
+    /// Defaults to the EntryPoint v0.8.
+    #[arg(long = "sponsor.entry-point", value_name = "ADDRESS", requires = "paymaster_url")]
+    pub entry_point: Option<Address>,
+
+    /// Delegate the sender to this account implementation with EIP-7702, if it isn't delegated
+    /// yet.
+    ///
+    /// The authorization is signed by the sender and included in the first user operation.
+    #[arg(long = "sponsor.delegate", value_name = "ADDRESS", requires = "paymaster_url")]
+    pub delegate: Option<Address>,
+
+    /// The context passed to the paymaster as JSON, e.g. `{"sponsorshipPolicyId":"sp_1"}`.
+    #[arg(long = "sponsor.context", value_name = "JSON", requires = "paymaster_url")]
+    pub context: Option<serde_json::Value>,
+}
+
+impl SponsorArgs {
+    /// Returns `true` if the transactions are broadcast as sponsored user operations.
+    pub const fn is_enabled(&self) -> bool {
+        self.paymaster_url.is_some()
+    }
+}
+
+/// An ERC-4337 v0.7+ user operation, in its JSON-RPC format.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UserOperation {
+    sender: Address,
+    nonce: U256,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    factory: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    factory_data: Option<Bytes>,
+    call_data: Bytes,
+    call_gas_limit: U256,
+    verification_gas_limit: U256,
+    pre_verification_gas: U256,
+    max_fee_per_gas: U256,
+    max_priority_fee_per_gas: U256,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    paymaster: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    paymaster_verification_gas_limit: Option<U256>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    paymaster_post_op_gas_limit: Option<U256>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    paymaster_data: Option<Bytes>,
+    signature: Bytes,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    eip7702_auth: Option<SignedAuthorization>,
+}
+
+/// The response of `pm_getPaymasterStubData` and `pm_getPaymasterData`, as defined by ERC-7677.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PaymasterData {
+    paymaster: Address,
+    paymaster_data: Bytes,
+    paymaster_verification_gas_limit: Option<U256>,
+    paymaster_post_op_gas_limit: Option<U256>,
+    /// Whether the stub data can be used as is, without calling `pm_getPaymasterData`.
+    #[serde(default)]
+    is_final: bool,
+}
+
+/// The response of `eth_estimateUserOperationGas`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UserOperationGas {
+    pre_verification_gas: U256,
+    verification_gas_limit: U256,
+    call_gas_limit: U256,
+    paymaster_verification_gas_limit: Option<U256>,
+    paymaster_post_op_gas_limit: Option<U256>,
+}
+
+/// The response of `eth_getUserOperationReceipt`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UserOperationReceipt {
+    success: bool,
+    #[serde(default)]
+    reason: Option<String>,
+    receipt: BundleReceipt,
+}
+
+/// The receipt of the transaction that included a user operation.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BundleReceipt {
+    transaction_hash: TxHash,
+}
+
+impl UserOperation {
+    fn set_paymaster(&mut self, data: PaymasterData) {
+        self.paymaster = Some(data.paymaster);
+        self.paymaster_data = Some(data.paymaster_data);
+        if let Some(gas) = data.paymaster_verification_gas_limit {
+            self.paymaster_verification_gas_limit = Some(gas);
+        }
+        if let Some(gas) = data.paymaster_post_op_gas_limit {
+            self.paymaster_post_op_gas_limit = Some(gas);
+        }
+    }
+
+    fn set_gas(&mut self, gas: UserOperationGas) {
+        self.pre_verification_gas = gas.pre_verification_gas;
+        self.verification_gas_limit = gas.verification_gas_limit;
+        self.call_gas_limit = gas.call_gas_limit;
+        if let Some(gas) = gas.paymaster_verification_gas_limit {
+            self.paymaster_verification_gas_limit = Some(gas);
+        }
+        if let Some(gas) = gas.paymaster_post_op_gas_limit {
+            self.paymaster_post_op_gas_limit = Some(gas);
+        }
+    }
+
+    /// Returns the user operation in the format of the EntryPoint.
+    fn pack(&self) -> PackedUserOperation {
+        let init_code = self
+            .factory
+            .map(|factory| {
+                [factory.as_slice(), self.factory_data.as_deref().unwrap_or_default()].concat()
+            })
+            .unwrap_or_default();
+        let paymaster_and_data = self
+            .paymaster
+            .map(|paymaster| {
+                let gas_limits = pack_u128s(
+                    self.paymaster_verification_gas_limit.unwrap_or_default(),
+                    self.paymaster_post_op_gas_limit.unwrap_or_default(),
+                );
+                [
+                    paymaster.as_slice(),
+                    gas_limits.as_slice(),
+                    self.paymaster_data.as_deref().unwrap_or_default(),
+                ]
+                .concat()
+            })
+            .unwrap_or_default();
+        PackedUserOperation {
+            sender: self.sender,
+            nonce: self.nonce,
+            initCode: init_code.into(),
+            callData: self.call_data.clone(),
+            accountGasLimits: pack_u128s(self.verification_gas_limit, self.call_gas_limit),
+            preVerificationGas: self.pre_verification_gas,
+            gasFees: pack_u128s(self.max_priority_fee_per_gas, self.max_fee_per_gas),
+            paymasterAndData: paymaster_and_data.into(),
+            signature: self.signature.clone(),
+        }
+    }
+}
+
+/// A call made by the account of a sender, with `execute`.
+#[derive(Debug, PartialEq, Eq)]
+struct AccountCall {
+    target: Address,
+    data: Bytes,
+    /// The address of the contract deployed by the call, if it is a contract creation.
+    deployed: Option<Address>,
+}
+
+impl AccountCall {
+    /// Returns the call made for a transaction of `sender` with `nonce`.
+    ///
+    /// Contract creations are sent to `create2_deployer`, salted with the sender and the nonce so
+    /// that identical creations of a sequence are deployed at distinct addresses.
+    fn new(
+        to: Option<Address>,
+        input: Bytes,
+        sender: Address,
+        nonce: u64,
+        create2_deployer: Address,
+    ) -> Self {
+        if let Some(target) = to {
+            return Self { target, data: input, deployed: None };
+        }
+        let salt = keccak256([sender.as_slice(), &nonce.to_be_bytes()].concat());
+        let deployed = create2_deployer.create2_from_code(salt, &input);
+        Self {
+            target: create2_deployer,
+            data: [salt.as_slice(), &input].concat().into(),
+            deployed: Some(deployed),
+        }
+    }
+}
+
+/// Fails if a transaction uses the simulated address of a contract created by an earlier one.
+///
+/// Sponsored contract creations are deployed by the CREATE2 deployer, at other addresses than the
+/// simulated ones, so transactions calling or passing these addresses would target nothing.
+fn check_sponsored_creations<N: Network>(
+    transactions: &[&TransactionWithMetadata<N>],
+    create2_deployer: Address,
+) -> Result<()> {
+    for (i, metadata) in transactions.iter().enumerate() {
+        let tx = metadata.tx();
+        let (None, Some(sender)) = (tx.to(), tx.from()) else { continue };
+        let call = AccountCall::new(
+            None,
+            tx.input().cloned().unwrap_or_default(),
+            sender,
+            tx.nonce().unwrap_or_default(),
+            create2_deployer,
+        );
+        let simulated = metadata
+            .contract_address
+            .into_iter()
+            .chain(metadata.additional_contracts.iter().map(|contract| contract.address))
+            .filter(|address| !address.is_zero() && Some(*address) != call.deployed);
+        for address in simulated {
+            if let Some(later) =
+                transactions[i + 1..].iter().position(|later| uses_address(later.tx(), address))
+            {
+                bail!(
+                    "transaction {} uses {address}, which is created by transaction {} in the \
+                     simulation, but sponsored contract creations are deployed at other \
+                     addresses by the CREATE2 deployer",
+                    i + later + 2,
+                    i + 1
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns `true` if the transaction calls `address` or passes it in its calldata.
+fn uses_address<N: Network>(tx: &TransactionMaybeSigned<N>, address: Address) -> bool {
+    tx.to() == Some(address)
+        || tx.input().is_some_and(|input| input.windows(20).any(|word| word == address.as_slice()))
+}
+
+/// Packs two 128-bit values in a word, `high` first.
+fn pack_u128s(high: U256, low: U256) -> B256 {
+    ((high << 128) | low).into()
+}
+
+/// The endpoints and the EntryPoint used to broadcast user operations.
+struct Sponsor {
+    paymaster: Arc<dyn Provider<AnyNetwork>>,
+    bundler: Arc<dyn Provider<AnyNetwork>>,
+    entry_point: Address,
+    context: serde_json::Value,
+    timeout: u64,
+}
+
+impl Sponsor {
+    fn new(args: &SponsorArgs, timeout: u64) -> Result<Self> {
+        let paymaster_url = args.paymaster_url.as_deref().ok_or_eyre("no paymaster URL")?;
+        let bundler_url = args.bundler_url.as_deref().unwrap_or(paymaster_url);
+        Ok(Self {
+            paymaster: Arc::new(ProviderBuilder::<AnyNetwork>::new(paymaster_url).build()?),
+            bundler: Arc::new(ProviderBuilder::<AnyNetwork>::new(bundler_url).build()?),
+            entry_point: args.entry_point.unwrap_or(ENTRY_POINT_V08),
+            context: args.context.clone().unwrap_or_default(),
+            timeout,
+        })
+    }
+
+    async fn paymaster_data(
+        &self,
+        method: &'static str,
+        user_op: &UserOperation,
+        chain: u64,
+    ) -> Result<PaymasterData> {
+        let params = (user_op, self.entry_point, U256::from(chain), &self.context);
+        self.paymaster
+            .raw_request(Cow::Borrowed(method), params)
+            .await
+            .wrap_err_with(|| format!("`{method}` failed"))
+    }
+
+    /// Sponsors, signs and sends the user operation, and returns the hash of the transaction that
+    /// included it.
+    async fn send<N: Network>(
+        &self,
+        provider: &impl Provider<N>,
+        mut user_op: UserOperation,
+        signer: &WalletSigner,
+        chain: u64,
+        delegate: Option<Address>,
+    ) -> Result<TxHash> {
+        let stub = self.paymaster_data("pm_getPaymasterStubData", &user_op, chain).await?;
+        let is_final = stub.is_final;
+        user_op.set_paymaster(stub);
+
+        let gas: UserOperationGas = self
+            .bundler
+            .raw_request(
+                Cow::Borrowed("eth_estimateUserOperationGas"),
+                (&user_op, self.entry_point),
+            )
+            .await
+            .wrap_err("failed to estimate the gas of the user operation")?;
+        user_op.set_gas(gas);
+
+        if !is_final {
+            let data = self.paymaster_data("pm_getPaymasterData", &user_op, chain).await?;
+            user_op.set_paymaster(data);
+        }
+
+        // The EntryPoint hashes the delegate of the sender, which has to be simulated until the
+        // authorization is included.
+        let call = N::TransactionRequest::default()
+            .with_to(self.entry_point)
+            .with_input(IEntryPoint::getUserOpHashCall { userOp: user_op.pack() }.abi_encode());
+        let mut call = provider.call(call);
+        if let Some(delegate) = delegate {
+            let code = [&EIP7702_DELEGATION_DESIGNATOR[..], delegate.as_slice()].concat();
+            let account = AccountOverride { code: Some(code.into()), ..Default::default() };
+            call = call.overrides(StateOverride::from_iter([(user_op.sender, account)]));
+        }
+        let hash = IEntryPoint::getUserOpHashCall::abi_decode_returns(&call.await?)?;
+        user_op.signature = signer.sign_hash(&hash).await?.as_bytes().into();
+
+        let user_op_hash: B256 = self
+            .bundler
+            .raw_request(Cow::Borrowed("eth_sendUserOperation"), (&user_op, self.entry_point))
+            .await
+            .wrap_err("failed to send the user operation")?;
+
+        let receipt = tokio::time::timeout(Duration::from_secs(self.timeout), async {
+            loop {
+                let receipt: Option<UserOperationReceipt> = self
+                    .bundler
+                    .raw_request(Cow::Borrowed("eth_getUserOperationReceipt"), (user_op_hash,))
+                    .await?;
+                if let Some(receipt) = receipt {
+                    return Ok::<_, eyre::Error>(receipt);
+                }
+                tokio::time::sleep(Duration::from_millis(500)).await;
+            }
+        })
+        .await
+        .map_err(|_| eyre::eyre!("timeout waiting for user operation {user_op_hash}"))??;
+
+        if !receipt.success {
+            bail!(
+                "user operation {user_op_hash} reverted{}",
+                receipt.reason.map(|reason| format!(": {reason}")).unwrap_or_default()
+            );
+        }
+        Ok(receipt.receipt.transaction_hash)
+    }
+}
+
+impl<FEN: FoundryEvmNetwork> BundledState<FEN> {
+    /// Broadcasts the transactions as ERC-4337 user operations sponsored by a paymaster.
+    ///
+    /// Each transaction is executed by the EIP-7702 delegated account of its sender, in its own
+    /// user operation, sent once the previous one is included.
+    pub async fn broadcast_sponsored(mut self) -> Result<BroadcastedState<FEN>> {
+        let sponsor =
+            Sponsor::new(&self.args.sponsor, self.script_config.config.transaction_timeout)?;
+        let signers = self.script_wallets.into_multi_wallet().into_signers()?;
+        let create2_deployer = self.script_config.evm_opts.create2_deployer;
+
+        // Check all the sequences before broadcasting anything.
+        for sequence in self.sequence.sequences() {
+            let pending =
+                sequence.transactions.iter().skip(sequence.receipts.len()).collect::<Vec<_>>();
+            check_sponsored_creations(&pending, create2_deployer)?;
+        }
+
+        for i in 0..self.sequence.sequences().len() {
+            let sequence = &self.sequence.sequences()[i];
+            let provider = ProviderBuilder::<FEN::Network>::new(sequence.rpc_url()).build()?;
+            let chain = sequence.chain;
+            let already_broadcasted = sequence.receipts.len();
+            let transactions =
+                sequence.transactions.iter().skip(already_broadcasted).cloned().collect::<Vec<_>>();
+
+            let fees = provider.estimate_eip1559_fees().await?;
+            let max_fee_per_gas =
+                self.args.with_gas_price.map(|p| p.to()).unwrap_or(fees.max_fee_per_gas);
+            let max_priority_fee_per_gas = self
+                .args
+                .priority_gas_price
+                .map(|p| p.to())
+                .unwrap_or(fees.max_priority_fee_per_gas);
+
+            for (index, metadata) in (already_broadcasted..).zip(transactions) {
+                let tx = metadata.tx();
+                if !tx.is_unsigned() {
+                    bail!("pre-signed transactions can't be sponsored");
+                }
+                let sender = tx
+                    .from()
+                    .ok_or_else(|| eyre::eyre!("transaction {} has no sender", index + 1))?;
+                if sender == Config::DEFAULT_SENDER {
+                    bail!(
+                        "You seem to be using Foundry's default sender. Be sure to set your own --sender."
+                    );
+                }
+                let call = AccountCall::new(
+                    tx.to(),
+                    tx.input().cloned().unwrap_or_default(),
+                    sender,
+                    tx.nonce().unwrap_or_default(),
+                    create2_deployer,
+                );
+                if call.deployed.is_some()
+                    && provider.get_code_at(create2_deployer).await?.is_empty()
+                {
+                    bail!(
+                        "contract creations are sponsored through the CREATE2 deployer, \
+                         which is not deployed at {create2_deployer}"
+                    );
+                }
+                let Some(signer) = signers.get(&sender) else {
+                    bail!("No wallet found for sender {sender}");
+                };
+
+                let code = provider.get_code_at(sender).await?;
+                let delegate = if code.starts_with(&EIP7702_DELEGATION_DESIGNATOR) {
+                    None
+                } else if !code.is_empty() {
+                    bail!("sender {sender} is a contract and can't be sponsored");
+                } else if let Some(delegate) = self.args.sponsor.delegate {
+                    Some(delegate)
+                } else {
+                    bail!(
+                        "sender {sender} is not delegated with EIP-7702, \
+                         set `--sponsor.delegate` to delegate it"
+                    );
+                };
+
+                let nonce = provider
+                    .call(
+                        <FEN::Network as Network>::TransactionRequest::default()
+                            .with_to(sponsor.entry_point)
+                            .with_input(
+                                IEntryPoint::getNonceCall { sender, key: Default::default() }
+                                    .abi_encode(),
+                            ),
+                    )
+                    .await?;
+                let mut user_op = UserOperation {
+                    sender,
+                    nonce: IEntryPoint::getNonceCall::abi_decode_returns(&nonce)?,
+                    call_data: IAccount::executeCall {
+                        target: call.target,
+                        value: tx.value().unwrap_or_default(),
+                        data: call.data,
+                    }
+                    .abi_encode()
+                    .into(),
+                    max_fee_per_gas: U256::from(max_fee_per_gas),
+                    max_priority_fee_per_gas: U256::from(max_priority_fee_per_gas),
+                    signature: DUMMY_SIGNATURE.into(),
+                    ..Default::default()
+                };
+                if let Some(delegate) = delegate {
+                    let authorization = Authorization {
+                        chain_id: U256::from(chain),
+                        address: delegate,
+                        nonce: provider.get_transaction_count(sender).await?,
+                    };
+                    let signature = signer.sign_hash(&authorization.signature_hash()).await?;
+                    user_op.factory = Some(EIP7702_FACTORY);
+                    user_op.eip7702_auth = Some(authorization.into_signed(signature));
+                }
+
+                sh_println!("\n## Sponsoring transaction {} on chain {chain}...", index + 1)?;
+                let tx_hash = sponsor.send(&provider, user_op, signer, chain, delegate).await?;
+                let receipt = provider
+                    .get_transaction_receipt(tx_hash)
+                    .await?
+                    .ok_or_eyre("missing receipt of the sponsored transaction")?;
+                sh_println!("Sponsored transaction included: {tx_hash:#x}")?;
+
+                let sequence = self.sequence.sequences_mut().get_mut(i).unwrap();
+                if let Some(deployed) = call.deployed {
+                    let metadata = &mut sequence.transactions[index];
+                    metadata.call_kind = CallKind::Create2;
+                    metadata.contract_address = Some(deployed);
+                }
+                sequence.add_pending(index, tx_hash);
+                sequence.remove_pending(tx_hash);
+                sequence.add_receipt(receipt);
+
+                // Checkpoint save
+                self.sequence.save(true, false)?;
+            }
+        }
+
+        if !shell::is_json() {
+            sh_println!("\n\n==========================")?;
+            sh_println!("\nONCHAIN EXECUTION COMPLETE & SUCCESSFUL.")?;
+        }
+
+        Ok(BroadcastedState {
+            args: self.args,
+            script_config: self.script_config,
+            build_data: self.build_data,
+            sequence: self.sequence,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_network::Ethereum;
+    use alloy_rpc_types::TransactionRequest;
+
+    #[test]
+    fn packs_user_operations() {
+        let user_op = UserOperation {
+            sender: Address::with_last_byte(1),
+            factory: Some(EIP7702_FACTORY),
+            call_gas_limit: U256::from(2),
+            verification_gas_limit: U256::from(1),
+            max_fee_per_gas: U256::from(4),
+            max_priority_fee_per_gas: U256::from(3),
+            paymaster: Some(Address::with_last_byte(2)),
+            paymaster_verification_gas_limit: Some(U256::from(5)),
+            paymaster_post_op_gas_limit: Some(U256::from(6)),
+            paymaster_data: Some(Bytes::from_static(&[0xaa])),
+            ..Default::default()
+        };
+        let packed = user_op.pack();
+
+        assert_eq!(packed.initCode, Bytes::from(EIP7702_FACTORY.to_vec()));
+        assert_eq!(
+            U256::from_be_bytes(packed.accountGasLimits.0),
+            (U256::ONE << 128) | U256::from(2)
+        );
+        assert_eq!(U256::from_be_bytes(packed.gasFees.0), (U256::from(3) << 128) | U256::from(4));
+        assert_eq!(
+            packed.paymasterAndData,
+            Bytes::from(hex!(
+                "00000000000000000000000000000000000000020000000000000000000000000000000500000000000000000000000000000006aa"
+            ))
+        );
+    }
+
+    #[test]
+    fn sends_creations_to_the_create2_deployer() {
+        let sender = Address::with_last_byte(1);
+        let deployer = Config::DEFAULT_CREATE2_DEPLOYER;
+        let init_code = Bytes::from_static(&[0x60, 0x00]);
+
+        let target = Address::with_last_byte(2);
+        let call = AccountCall::new(Some(target), init_code.clone(), sender, 0, deployer);
+        assert_eq!(call, AccountCall { target, data: init_code.clone(), deployed: None });
+
+        let call = AccountCall::new(None, init_code.clone(), sender, 0, deployer);
+        assert_eq!(call.target, deployer);
+        let (salt, code) = call.data.split_at(32);
+        assert_eq!(code, &init_code[..]);
+        assert_eq!(call.deployed, Some(deployer.create2_from_code(B256::from_slice(salt), code)));
+
+        // Identical creations are deployed at distinct addresses.
+        let next = AccountCall::new(None, init_code, sender, 1, deployer);
+        assert_ne!(next.deployed, call.deployed);
+    }
+
+    #[test]
+    fn rejects_uses_of_relocated_creations() {
+        let sender = Address::with_last_byte(1);
+        let simulated = sender.create(0);
+        let tx = |request: TransactionRequest| {
+            TransactionWithMetadata::<Ethereum>::from_tx_request(TransactionMaybeSigned::new(
+                request.from(sender),
+            ))
+        };
+        let mut create =
+            tx(TransactionRequest::default().input(Bytes::from_static(&[0x60, 0x00]).into()));
+        create.contract_address = Some(simulated);
+        let unrelated = tx(TransactionRequest::default().to(Address::with_last_byte(2)));
+        let call = tx(TransactionRequest::default().to(simulated));
+        let argument = tx(TransactionRequest::default()
+            .to(Address::with_last_byte(2))
+            .input(Bytes::from(simulated.into_word()).into()));
+
+        let deployer = Config::DEFAULT_CREATE2_DEPLOYER;
+        assert!(check_sponsored_creations(&[&create, &unrelated], deployer).is_ok());
+        assert!(check_sponsored_creations(&[&call, &create], deployer).is_ok());
+        assert!(check_sponsored_creations(&[&create, &unrelated, &call], deployer).is_err());
+        assert!(check_sponsored_creations(&[&create, &argument], deployer).is_err());
+    }
+
+    #[test]
+    fn serializes_user_operations() {
+        let user_op = UserOperation { nonce: U256::from(1), ..Default::default() };
+        let json = serde_json::to_value(&user_op).unwrap();
+        assert_eq!(json["nonce"], "0x1");
+        assert!(json.get("paymaster").is_none());
+        assert!(json.get("eip7702Auth").is_none());
+    }
+}
@@ -160,6 +160,7 @@ pub fn with_execution_result(
         });
 
         self.transaction.additional_contracts = created_contracts;
+        self.transaction.simulation = result.simulation.clone();
 
         if !self.transaction.is_fixed_gas_limit
             && let Some(unsigned) = self.transaction.transaction.as_unsigned_mut()
@@ -19,7 +19,7 @@
     opts::EtherscanOpts,
     utils::{LoadConfig, get_provider, read_constructor_args_file},
 };
-use foundry_common::{abi::encode_function_args, retry::RetryError};
+use foundry_common::{abi::encode_function_args, etherscan::EtherscanThrottle, retry::RetryError};
 use foundry_compilers::{Artifact, artifacts::BytecodeObject};
 use foundry_config::Config;
 use foundry_evm::constants::DEFAULT_CREATE2_DEPLOYER;
@@ -62,9 +62,10 @@ async fn preflight_verify_check(
 
     async fn verify(&mut self, args: VerifyArgs, context: VerificationContext) -> Result<()> {
         let (etherscan, verify_args) = self.prepare_verify_request(&args, &context).await?;
+        let throttle = self.throttle(&args.etherscan, &context.config)?;
 
         if !args.skip_is_verified_check
-            && self.is_contract_verified(&etherscan, &verify_args).await?
+            && self.is_contract_verified(&etherscan, &throttle, &verify_args).await?
         {
             sh_println!(
                 "\nContract [{}] {:?} is already verified. Skipping verification.",
@@ -86,6 +87,10 @@ async fn verify(&mut self, args: VerifyArgs, context: VerificationContext) -> Re
                     verify_args.contract_name,
                     verify_args.address
                 )?;
+                // Submissions aren't idempotent, so they only wait for the request budget and are
+                // not retried on rate limits: a retried submission would queue the verification
+                // again.
+                throttle.wait().await;
                 let resp = etherscan
                     .submit_contract_verification(&verify_args)
                     .await
@@ -155,11 +160,12 @@ async fn verify(&mut self, args: VerifyArgs, context: VerificationContext) -> Re
     async fn check(&self, args: VerifyCheckArgs) -> Result<()> {
         let config = args.load_config()?;
         let etherscan = self.client(&args.etherscan, &args.verifier, &config)?;
+        let throttle = self.throttle(&args.etherscan, &config)?;
         args.retry
             .into_retry()
             .run_async_until_break(|| async {
-                let resp = etherscan
-                    .check_contract_verification_status(args.id.clone())
+                let resp = throttle
+                    .run(|| etherscan.check_contract_verification_status(args.id.clone()))
                     .await
                     .wrap_err("Failed to request verification status")
                     .map_err(RetryError::Retry)?;
@@ -233,9 +239,10 @@ async fn prepare_verify_request(
     async fn is_contract_verified(
         &self,
         etherscan: &Client,
+        throttle: &EtherscanThrottle,
         verify_contract: &VerifyContract,
     ) -> Result<bool> {
-        let check = etherscan.contract_abi(verify_contract.address).await;
+        let check = throttle.run(|| etherscan.contract_abi(verify_contract.address)).await;
 
         if let Err(err) = check {
             return match err {
@@ -249,6 +256,24 @@ async fn is_contract_verified(
         Ok(true)
     }
 
+    /// Create the request throttle for the Etherscan chain, see [`EtherscanThrottle`].
+    ///
+    /// If the chain is unknown, the throttle doesn't share the request budget of any chain.
+    pub(crate) fn throttle(
+        &self,
+        etherscan_opts: &EtherscanOpts,
+        config: &Config,
+    ) -> Result<EtherscanThrottle> {
+        let Some(chain) = etherscan_opts.chain else {
+            return Ok(EtherscanThrottle::unshared(Default::default()));
+        };
+        let limits = config
+            .get_etherscan_config_with_chain(Some(chain))?
+            .map(|config| config.limits)
+            .unwrap_or_default();
+        Ok(EtherscanThrottle::new(chain, limits))
+    }
+
     /// Create an Etherscan client.
     pub(crate) fn client(
         &self,
@@ -406,8 +431,9 @@ async fn guess_constructor_args(
     ) -> Result<String> {
         let provider = get_provider(&context.config)?;
         let client = self.client(&args.etherscan, &args.verifier, &context.config)?;
+        let throttle = self.throttle(&args.etherscan, &context.config)?;
 
-        let creation_data = client.contract_creation_data(args.address).await?;
+        let creation_data = throttle.run(|| client.contract_creation_data(args.address)).await?;
         let transaction = provider
             .get_transaction_by_hash(creation_data.transaction_hash)
             .await?
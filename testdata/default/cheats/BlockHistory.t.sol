@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+pragma solidity ^0.8.25;
+
+import "utils/Test.sol";
+
+contract BlockHistoryTest is Test {
+    function testSetPrevrandaoPerBlock() public {
+        vm.roll(10);
+        vm.setPrevrandao(10, bytes32(uint256(1)));
+        vm.setPrevrandao(11, bytes32(uint256(2)));
+        assertEq(block.prevrandao, 1);
+
+        vm.roll(11);
+        assertEq(block.prevrandao, 2);
+
+        vm.roll(12);
+        assertEq(block.prevrandao, 2, "prevrandao of unset blocks is unchanged");
+
+        vm.roll(10);
+        assertEq(block.prevrandao, 1);
+    }
+
+    function testSetBlobBaseFee() public {
+        vm.setBlobBaseFee(6969);
+        assertEq(block.blobbasefee, 6969);
+    }
+
+    function testSetBlockhashes() public {
+        vm.roll(1000);
+        bytes32[] memory hashes = new bytes32[](256);
+        for (uint256 i; i < hashes.length; i++) {
+            hashes[i] = keccak256(abi.encode(i));
+        }
+        vm.setBlockhashes(744, hashes);
+
+        assertEq(blockhash(744), hashes[0]);
+        assertEq(blockhash(999), hashes[255]);
+    }
+
+    function testSetBlockhashesInTheFuture() public {
+        vm.roll(10);
+        bytes32[] memory hashes = new bytes32[](2);
+        vm._expectCheatcodeRevert("block numbers must be less than or equal to the current block number");
+        vm.setBlockhashes(10, hashes);
+    }
+}
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+pragma solidity ^0.8.18;
+
+import "utils/Test.sol";
+
+contract SimpleToken {
+    uint256 public totalSupply;
+    mapping(address => uint256) public balanceOf;
+}
+
+contract PackedToken {
+    struct Account {
+        uint64 nonce;
+        uint128 balance;
+        bool frozen;
+    }
+
+    mapping(address => Account) internal accounts;
+
+    function balanceOf(address account) external view returns (uint256) {
+        return accounts[account].balance;
+    }
+
+    function nonceOf(address account) external view returns (uint256) {
+        return accounts[account].nonce;
+    }
+
+    function setNonce(address account, uint64 nonce) external {
+        accounts[account].nonce = nonce;
+    }
+}
+
+contract SharesToken {
+    uint256 public totalSupply = 2_000;
+    uint256 public totalShares = 1_000;
+    mapping(address => uint256) public sharesOf;
+
+    function balanceOf(address account) external view returns (uint256) {
+        return sharesOf[account] * totalSupply / totalShares;
+    }
+}
+
+contract DealTokenTest is Test {
+    address constant alice = address(0xa11ce);
+
+    function testDealToken() public {
+        SimpleToken token = new SimpleToken();
+        vm.dealToken(address(token), alice, 100 ether);
+        assertEq(token.balanceOf(alice), 100 ether);
+        assertEq(token.totalSupply(), 0);
+    }
+
+    function testDealPackedToken() public {
+        PackedToken token = new PackedToken();
+        token.setNonce(alice, 7);
+        vm.dealToken(address(token), alice, 1234);
+        assertEq(token.balanceOf(alice), 1234);
+        assertEq(token.nonceOf(alice), 7);
+    }
+
+    function testDealSharesToken() public {
+        SharesToken token = new SharesToken();
+        vm.setTokenDealStrategy(address(token), SharesToken.sharesOf.selector, SharesToken.totalShares.selector);
+        vm.dealToken(address(token), alice, 500);
+        assertEq(token.sharesOf(alice), 250);
+        assertEq(token.balanceOf(alice), 500);
+    }
+
+    function testDealPackedTokenOverflow() public {
+        PackedToken token = new PackedToken();
+        vm._expectCheatcodeRevert("vm.dealToken: balance does not fit in the 128 bits of the balance slot");
+        vm.dealToken(address(token), alice, type(uint256).max);
+    }
+}
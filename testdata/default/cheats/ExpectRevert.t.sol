@@ -443,3 +443,45 @@ contract ExpectRevertWithErrorTest is Test {
         }
     }
 }
+
+contract ExpectPartialRevertArgsTest is Test {
+    error InsufficientBalance(address account, uint256 requested, uint256 available);
+
+    function withdraw(uint256 requested) external view {
+        revert InsufficientBalance(msg.sender, requested, block.timestamp);
+    }
+
+    function testExpectPartialRevertCheckedArgs() public {
+        // Only the account and requested amount are checked, the available balance is ignored.
+        bool[] memory checkArgs = new bool[](3);
+        checkArgs[0] = true;
+        checkArgs[1] = true;
+        vm.expectPartialRevert(
+            abi.encodeWithSelector(InsufficientBalance.selector, address(this), 100, 0), checkArgs
+        );
+        this.withdraw(100);
+    }
+
+    function testExpectPartialRevertCheckedArgsWithReverter() public {
+        bool[] memory checkArgs = new bool[](2);
+        checkArgs[1] = true;
+        vm.expectPartialRevert(
+            abi.encodeWithSelector(InsufficientBalance.selector, address(0), 42, 0), checkArgs, address(this)
+        );
+        this.withdraw(42);
+    }
+
+    error Rejected(string reason, uint256 code);
+
+    function reject(string memory reason) external view {
+        revert Rejected(reason, block.timestamp);
+    }
+
+    function testExpectPartialRevertCheckedDynamicArgs() public {
+        // The reason is compared by its decoded value, the code is ignored.
+        bool[] memory checkArgs = new bool[](2);
+        checkArgs[0] = true;
+        vm.expectPartialRevert(abi.encodeWithSelector(Rejected.selector, "not allowed", 0), checkArgs);
+        this.reject("not allowed");
+    }
+}
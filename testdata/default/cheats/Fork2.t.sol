@@ -307,6 +307,46 @@ contract ForkTest is Test {
         assertTrue(listening, "net_listening should return true");
     }
 
+    function testRpcUint() public {
+        assertEq(vm.rpcUint("sepolia", "eth_chainId", "[]"), 11155111);
+
+        vm.selectFork(mainnetFork);
+        assertEq(vm.rpcUint("eth_chainId", "[]"), 1);
+    }
+
+    function testRpcBytes32() public {
+        bytes32 value = vm.rpcBytes32(
+            "sepolia", "eth_getStorageAt", '["0x0000000000000000000000000000000000000000", "0x0", "0x588b24"]'
+        );
+        assertEq(value, bytes32(0));
+    }
+
+    function testRpcAddressRejectsQuantity() public {
+        vm._expectCheatcodeRevert('vm.rpcAddress: "eth_chainId": result is not an address: "0xaa36a7"');
+        vm.rpcAddress("sepolia", "eth_chainId", "[]");
+    }
+
+    function testRpcFallbackEndpoints() public {
+        string[] memory endpoints = new string[](2);
+        endpoints[0] = "http://127.0.0.1:1";
+        endpoints[1] = "sepolia";
+        bytes memory data = vm.rpc(endpoints, "eth_chainId", "[]");
+        assertEq(data, hex"aa36a7");
+    }
+
+    function testRpcBatch() public {
+        string[] memory methods = new string[](2);
+        string[] memory params = new string[](2);
+        methods[0] = "eth_chainId";
+        params[0] = "[]";
+        methods[1] = "net_listening";
+        params[1] = "[]";
+        bytes[] memory data = vm.rpcBatch("sepolia", methods, params);
+        assertEq(data.length, 2);
+        assertEq(data[0], hex"aa36a7");
+        assertTrue(abi.decode(data[1], (bool)));
+    }
+
     // Verify abi.decode works for eth_chainId (simple hex scalar to uint).
     function testRpcChainId() public {
         bytes memory data = vm.rpc("sepolia", "eth_chainId", "[]");
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+pragma solidity ^0.8.18;
+
+import "utils/Test.sol";
+
+contract NamedAccountsTest is Test {
+    function setUp() public {
+        vm.loadNamedAccounts(string.concat(vm.projectRoot(), "/fixtures/Toml/named_accounts.toml"));
+    }
+
+    function testNamedAccountsAreFunded() public view {
+        assertEq(vm.namedAccount("deployer").balance, 10_000 ether);
+        assertEq(vm.namedAccount("treasury").balance, 1_000_000 ether);
+        assertEq(vm.namedAccount("user1").balance, 100 ether);
+        assertEq(vm.namedAccount("vault").balance, 1_000_000.5 ether);
+    }
+
+    function testNamedAccountsAddresses() public {
+        assertEq(vm.namedAccount("deployer"), makeAddr("deployer"));
+        assertEq(vm.namedAccount("vault"), address(0xdEaD));
+    }
+
+    function testNamedAccountsCode() public view {
+        assertEq(vm.namedAccount("treasury").code, hex"00");
+        assertEq(vm.namedAccount("user1").code, abi.encodePacked(hex"ef0100", address(0x420)));
+        assertEq(vm.namedAccount("deployer").code.length, 0);
+    }
+
+    function testNamedAccountsCanSign() public view {
+        address deployer = vm.namedAccount("deployer");
+        bytes32 digest = keccak256("named");
+        (uint8 v, bytes32 r, bytes32 s) = vm.sign(deployer, digest);
+        assertEq(ecrecover(digest, v, r, s), deployer);
+    }
+
+    function testUnknownNamedAccount() public {
+        vm._expectCheatcodeRevert("vm.namedAccount: unknown named account `alice`, load it with `vm.loadNamedAccounts`");
+        vm.namedAccount("alice");
+    }
+}
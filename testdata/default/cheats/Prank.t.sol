@@ -605,3 +605,38 @@ contract Issue10528 is Test {
         counter.increment();
     }
 }
+
+contract PrankScopeTest is Test {
+    function testPrankScopeAppliesToNestedCalls() public {
+        address sender = address(0xbeef);
+        Victim innerVictim = new Victim();
+        NestedVictim victim = new NestedVictim(innerVictim);
+
+        vm.prankScope(sender);
+        // The nested call made by `NestedVictim` is pranked as well.
+        vm.expectRevert("msg.sender was incorrectly set for nested victim");
+        victim.assertCallerAndOrigin(sender, "msg.sender was not set", tx.origin, "tx.origin changed");
+
+        innerVictim.assertCallerAndOrigin(sender, "msg.sender was not set", tx.origin, "tx.origin changed");
+        vm.stopPrankScope();
+
+        innerVictim.assertCallerAndOrigin(
+            address(this), "msg.sender was not reset", tx.origin, "tx.origin changed"
+        );
+    }
+
+    function testPrankScopeExcludedTargets() public {
+        address sender = address(0xbeef);
+        Victim innerVictim = new Victim();
+        NestedVictim victim = new NestedVictim(innerVictim);
+
+        address[] memory excluded = new address[](1);
+        excluded[0] = address(innerVictim);
+        vm.prankScope(sender, excluded);
+        victim.assertCallerAndOrigin(sender, "msg.sender was not set", tx.origin, "tx.origin changed");
+        innerVictim.assertCallerAndOrigin(
+            address(this), "msg.sender was set for excluded target", tx.origin, "tx.origin changed"
+        );
+        vm.stopPrankScope();
+    }
+}
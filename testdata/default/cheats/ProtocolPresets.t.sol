@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+pragma solidity ^0.8.18;
+
+import "utils/Test.sol";
+
+contract ProtocolPresetsTest is Test {
+    address constant USDC = 0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48;
+    address constant WETH = 0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2;
+    address constant USDC_WETH_500 = 0x88e6A0c2dDD26FEEb64F039a2c41296FcB3f5640;
+
+    function testProtocolAddress() public {
+        vm.chainId(1);
+        assertEq(vm.protocolAddress("stables", "USDC"), USDC);
+        assertEq(vm.protocolAddress("uniswap-v3", "FACTORY"), 0x1F98431c8aD98523631AE4a59f267346ea31F984);
+        assertEq(vm.protocolAddress("aave-v3", "pool"), 0x87870Bca3F3fD6335C3F4ce8392D69350B4fA4E2);
+    }
+
+    function testProtocolAddressUnknown() public {
+        vm._expectCheatcodeRevert("vm.protocolAddress: no USDC contract for the stables preset on chain 31337");
+        vm.protocolAddress("stables", "USDC");
+    }
+
+    function testUniswapV3Pool() public {
+        vm.chainId(1);
+        assertEq(vm.uniswapV3Pool(USDC, WETH, 500), USDC_WETH_500);
+        assertEq(vm.uniswapV3Pool(WETH, USDC, 500), USDC_WETH_500);
+    }
+
+    function testUniswapV3PoolsWithFork() public {
+        vm.createSelectFork("mainnet", 22985278);
+        address[] memory pools = vm.uniswapV3Pools(USDC, WETH);
+        bool found;
+        for (uint256 i = 0; i < pools.length; i++) {
+            assertGt(pools[i].code.length, 0);
+            found = found || pools[i] == USDC_WETH_500;
+        }
+        assertTrue(found);
+    }
+
+    function testAaveV3ReserveWithFork() public {
+        vm.createSelectFork("mainnet", 22985278);
+        (address aToken, address variableDebtToken) = vm.aaveV3Reserve(WETH);
+        assertEq(aToken, 0x4d5F47FA6A74757f35C14fD3a6Ef8E3C9BC514E8);
+        assertEq(variableDebtToken, 0xeA51d7853EEFb32b6ee06b1C12E6dcCA88Be0fFE);
+
+        vm._expectCheatcodeRevert();
+        vm.aaveV3Reserve(address(0xdead));
+    }
+}
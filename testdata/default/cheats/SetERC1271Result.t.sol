@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+pragma solidity ^0.8.18;
+
+import "utils/Test.sol";
+
+interface IERC1271 {
+    function isValidSignature(bytes32 hash, bytes memory signature) external view returns (bytes4);
+}
+
+contract SignatureVerifier {
+    function isValid(address signer, bytes32 hash, bytes memory signature) external view returns (bool) {
+        if (signer.code.length == 0) {
+            return false;
+        }
+        return IERC1271(signer).isValidSignature(hash, signature) == IERC1271.isValidSignature.selector;
+    }
+}
+
+contract SetERC1271ResultTest is Test {
+    SignatureVerifier verifier;
+    address wallet = address(0xC0FFEE);
+
+    function setUp() public {
+        verifier = new SignatureVerifier();
+    }
+
+    function testSetERC1271ResultValid() public {
+        bytes32 hash = keccak256("hello");
+        vm.setERC1271Result(wallet, hash, true);
+
+        assertGt(wallet.code.length, 0);
+        assertTrue(verifier.isValid(wallet, hash, hex"1234"));
+        assertTrue(verifier.isValid(wallet, hash, ""));
+    }
+
+    function testSetERC1271ResultInvalid() public {
+        bytes32 hash = keccak256("hello");
+        vm.setERC1271Result(wallet, hash, false);
+
+        assertFalse(verifier.isValid(wallet, hash, hex"1234"));
+        assertEq(IERC1271(wallet).isValidSignature(hash, ""), bytes4(0xffffffff));
+    }
+
+    function testSetERC1271ResultOverride() public {
+        bytes32 hash = keccak256("hello");
+        vm.setERC1271Result(wallet, hash, true);
+        vm.setERC1271Result(wallet, hash, false);
+
+        assertFalse(verifier.isValid(wallet, hash, hex"1234"));
+    }
+
+    function testSetERC1271ResultPerHash() public {
+        vm.setERC1271Result(wallet, keccak256("a"), true);
+        vm.setERC1271Result(wallet, keccak256("b"), false);
+
+        assertTrue(verifier.isValid(wallet, keccak256("a"), ""));
+        assertFalse(verifier.isValid(wallet, keccak256("b"), ""));
+    }
+}
@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+pragma solidity ^0.8.18;
+
+import "utils/Test.sol";
+
+contract CounterV1 {
+    uint256 public count;
+
+    function increment() public {
+        count += 1;
+    }
+}
+
+contract CounterV2 {
+    uint256 public count;
+    uint256 public immutable step;
+    uint256 public version;
+
+    constructor(uint256 _step) {
+        step = _step;
+    }
+
+    function increment() public {
+        count += step;
+    }
+
+    function initialize(uint256 _version) public returns (uint256) {
+        require(version == 0, "already initialized");
+        version = _version;
+        return count;
+    }
+}
+
+contract UpgradeToTest is Test {
+    CounterV1 counter;
+
+    function setUp() public {
+        counter = new CounterV1();
+        counter.increment();
+    }
+
+    function testUpgradeToKeepsStorage() public {
+        vm.upgradeTo(address(counter), "cheats/UpgradeTo.t.sol:CounterV2", abi.encode(5));
+        CounterV2 upgraded = CounterV2(address(counter));
+
+        assertEq(upgraded.count(), 1);
+        assertEq(upgraded.step(), 5);
+        upgraded.increment();
+        assertEq(upgraded.count(), 6);
+    }
+
+    function testUpgradeToLeavesNoScratchDeployment() public {
+        uint64 nonce = vm.getNonce(address(this));
+        vm.upgradeTo(address(counter), "cheats/UpgradeTo.t.sol:CounterV2", abi.encode(5));
+
+        assertEq(vm.getNonce(address(this)), nonce);
+        assertEq(vm.computeCreateAddress(address(this), nonce).code.length, 0);
+        assertEq(CounterV2(address(counter)).step(), 5);
+    }
+
+    function testUpgradeToReplaysInitializer() public {
+        bytes memory data = vm.upgradeTo(
+            address(counter),
+            "cheats/UpgradeTo.t.sol:CounterV2",
+            abi.encode(2),
+            abi.encodeCall(CounterV2.initialize, (2))
+        );
+        CounterV2 upgraded = CounterV2(address(counter));
+
+        assertEq(abi.decode(data, (uint256)), 1);
+        assertEq(upgraded.version(), 2);
+    }
+
+    function testUpgradeToRevertsWithInitializer() public {
+        vm.upgradeTo(
+            address(counter),
+            "cheats/UpgradeTo.t.sol:CounterV2",
+            abi.encode(2),
+            abi.encodeCall(CounterV2.initialize, (2))
+        );
+
+        vm._expectCheatcodeRevert("already initialized");
+        vm.upgradeTo(
+            address(counter),
+            "cheats/UpgradeTo.t.sol:CounterV2",
+            abi.encode(2),
+            abi.encodeCall(CounterV2.initialize, (3))
+        );
+    }
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+pragma solidity ^0.8.18;
+
+import "utils/Test.sol";
+
+contract WellKnownTest is Test {
+    function testWellKnownUniversal() public {
+        assertEq(vm.wellKnown("MULTICALL3"), 0xcA11bde05977b3631167028862bE2a173976CA11);
+        assertEq(vm.wellKnown("PERMIT2"), 0x000000000022D473030F116dDEE9F6B43aC78BA3);
+        assertEq(vm.wellKnown("CREATE2_DEPLOYER"), 0x4e59b44847b379578588920cA78FbF26c0B4956C);
+    }
+
+    function testWellKnownCurrentChain() public {
+        vm.chainId(1);
+        assertEq(vm.wellKnown("WETH"), 0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2);
+
+        vm.chainId(8453);
+        assertEq(vm.wellKnown("weth"), 0x4200000000000000000000000000000000000006);
+    }
+
+    function testWellKnownOtherChain() public {
+        assertEq(vm.wellKnown("WETH", 42161), 0x82aF49447D8a07e3bd95BD0d56f35241523fBab1);
+    }
+
+    function testWellKnownUnknownChain() public {
+        vm._expectCheatcodeRevert("vm.wellKnown: no canonical address for WETH on chain 31337");
+        vm.wellKnown("WETH");
+    }
+}
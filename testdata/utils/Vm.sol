@@ -30,6 +30,7 @@ interface Vm {
     function _expectCheatcodeRevert() external;
     function _expectCheatcodeRevert(bytes4 revertData) external;
     function _expectCheatcodeRevert(bytes calldata revertData) external;
+    function aaveV3Reserve(address asset) external view returns (address aToken, address variableDebtToken);
     function accessList(AccessListItem[] calldata access) external;
     function accesses(address target) external view returns (bytes32[] memory readSlots, bytes32[] memory writeSlots);
     function activeFork() external view returns (uint256 forkId);
@@ -194,6 +195,7 @@ interface Vm {
     function createWallet(uint256 privateKey, string calldata walletLabel) external returns (Wallet memory wallet);
     function currentFilePath() external view returns (string memory path);
     function deal(address account, uint256 newBalance) external;
+    function dealToken(address token, address account, uint256 newBalance) external;
     function deleteSnapshot(uint256 snapshotId) external returns (bool success);
     function deleteSnapshots() external;
     function deleteStateSnapshot(uint256 snapshotId) external returns (bool success);
@@ -275,6 +277,8 @@ interface Vm {
     function expectEmit(address emitter, uint64 count) external;
     function expectPartialRevert(bytes4 revertData) external;
     function expectPartialRevert(bytes4 revertData, address reverter) external;
+    function expectPartialRevert(bytes calldata revertData, bool[] calldata checkArgs) external;
+    function expectPartialRevert(bytes calldata revertData, bool[] calldata checkArgs, address reverter) external;
     function expectRevert() external;
     function expectRevert(bytes4 revertData) external;
     function expectRevert(bytes4 revertData, address reverter, uint64 count) external;
@@ -341,6 +345,7 @@ interface Vm {
     function lastCallGas() external view returns (Gas memory gas);
     function load(address target, bytes32 slot) external view returns (bytes32 data);
     function loadAllocs(string calldata pathToAllocsJson) external;
+    function loadNamedAccounts(string calldata path) external returns (address[] memory accounts);
     function makePersistent(address account) external;
     function makePersistent(address account0, address account1) external;
     function makePersistent(address account0, address account1, address account2) external;
@@ -356,6 +361,7 @@ interface Vm {
     function mockCalls(address callee, bytes calldata data, bytes[] calldata returnData) external;
     function mockCalls(address callee, uint256 msgValue, bytes calldata data, bytes[] calldata returnData) external;
     function mockFunction(address callee, address target, bytes calldata data) external;
+    function namedAccount(string calldata name) external view returns (address account);
     function noAccessList() external;
     function parseAddress(string calldata stringifiedValue) external pure returns (address parsedValue);
     function parseBool(string calldata stringifiedValue) external pure returns (bool parsedValue);
@@ -405,6 +411,8 @@ interface Vm {
     function parseUint(string calldata stringifiedValue) external pure returns (uint256 parsedValue);
     function pauseGasMetering() external;
     function pauseTracing() external view;
+    function prankScope(address msgSender) external;
+    function prankScope(address msgSender, address[] calldata excluded) external;
     function prank(address msgSender) external;
     function prank(address msgSender, address txOrigin) external;
     function prank(address msgSender, bool delegateCall) external;
@@ -417,6 +425,7 @@ interface Vm {
     function promptSecret(string calldata promptText) external returns (string memory input);
     function promptSecretUint(string calldata promptText) external returns (uint256);
     function promptUint(string calldata promptText) external returns (uint256);
+    function protocolAddress(string calldata preset, string calldata name) external view returns (address);
     function publicKeyEd25519(bytes32 privateKey) external pure returns (bytes32 publicKey);
     function publicKeyP256(uint256 privateKey) external pure returns (uint256 publicKeyX, uint256 publicKeyY);
     function randomAddress() external view returns (address);
@@ -461,11 +470,20 @@ interface Vm {
     function rollFork(bytes32 txHash) external;
     function rollFork(uint256 forkId, uint256 blockNumber) external;
     function rollFork(uint256 forkId, bytes32 txHash) external;
+    function rpcAddress(string calldata method, string calldata params) external returns (address data);
+    function rpcAddress(string calldata urlOrAlias, string calldata method, string calldata params) external returns (address data);
+    function rpcBatch(string calldata urlOrAlias, string[] calldata methods, string[] calldata params) external returns (bytes[] memory data);
+    function rpcBytes32(string calldata method, string calldata params) external returns (bytes32 data);
+    function rpcBytes32(string calldata urlOrAlias, string calldata method, string calldata params) external returns (bytes32 data);
+    function rpcUint(string calldata method, string calldata params) external returns (uint256 data);
+    function rpcUint(string calldata urlOrAlias, string calldata method, string calldata params) external returns (uint256 data);
     function rpcUrl(string calldata rpcAlias) external view returns (string memory json);
     function rpcUrlStructs() external view returns (Rpc[] memory urls);
     function rpcUrls() external view returns (string[2][] memory urls);
     function rpc(string calldata method, string calldata params) external returns (bytes memory data);
     function rpc(string calldata urlOrAlias, string calldata method, string calldata params) external returns (bytes memory data);
+    function rpc(string[] calldata urlsOrAliases, string calldata method, string calldata params) external returns (bytes memory data);
+    function selectChain(string calldata chain) external returns (uint256 forkId);
     function selectFork(uint256 forkId) external;
     function serializeAddress(string calldata objectKey, string calldata valueKey, address value) external returns (string memory json);
     function serializeAddress(string calldata objectKey, string calldata valueKey, address[] calldata values) external returns (string memory json);
@@ -487,12 +505,17 @@ interface Vm {
     function serializeUint(string calldata objectKey, string calldata valueKey, uint256[] calldata values) external returns (string memory json);
     function setArbitraryStorage(address target) external;
     function setArbitraryStorage(address target, bool overwrite) external;
+    function setBlobBaseFee(uint256 newBlobBaseFee) external;
     function setBlockhash(uint256 blockNumber, bytes32 blockHash) external;
+    function setBlockhashes(uint256 fromBlock, bytes32[] calldata blockHashes) external;
+    function setERC1271Result(address wallet, bytes32 hash, bool valid) external;
     function setEnv(string calldata name, string calldata value) external;
     function setEvmVersion(string calldata evm) external;
     function setNonce(address account, uint64 newNonce) external;
     function setNonceUnsafe(address account, uint64 newNonce) external;
+    function setPrevrandao(uint256 blockNumber, bytes32 newPrevrandao) external;
     function setSeed(uint256 seed) external;
+    function setTokenDealStrategy(address token, bytes4 sharesOf, bytes4 totalShares) external;
     function shuffle(uint256[] calldata array) external returns (uint256[] memory);
     function signAndAttachDelegation(address implementation, uint256 privateKey) external returns (SignedDelegation memory signedDelegation);
     function signAndAttachDelegation(address implementation, uint256 privateKey, uint64 nonce) external returns (SignedDelegation memory signedDelegation);
@@ -540,6 +563,7 @@ interface Vm {
     function stopExpectSafeMemory() external;
     function stopMappingRecording() external;
     function stopPrank() external;
+    function stopPrankScope() external;
     function stopRecord() external;
     function stopSnapshotGas() external returns (uint256 gasUsed);
     function stopSnapshotGas(string calldata name) external returns (uint256 gasUsed);
@@ -563,10 +587,17 @@ interface Vm {
     function trim(string calldata input) external pure returns (string memory output);
     function tryFfi(string[] calldata commandInput) external returns (FfiResult memory result);
     function txGasPrice(uint256 newGasPrice) external;
+    function uniswapV3Pool(address tokenA, address tokenB, uint24 fee) external view returns (address pool);
+    function uniswapV3Pools(address tokenA, address tokenB) external view returns (address[] memory pools);
     function unixTime() external view returns (uint256 milliseconds);
+    function upgradeTo(address target, string calldata artifactPath) external;
+    function upgradeTo(address target, string calldata artifactPath, bytes calldata constructorArgs) external;
+    function upgradeTo(address target, string calldata artifactPath, bytes calldata constructorArgs, bytes calldata initData) external returns (bytes memory data);
     function verifyEd25519(bytes calldata signature, bytes calldata namespace, bytes calldata message, bytes32 publicKey) external pure returns (bool valid);
     function warmSlot(address target, bytes32 slot) external;
     function warp(uint256 newTimestamp) external;
+    function wellKnown(string calldata name) external view returns (address);
+    function wellKnown(string calldata name, uint256 chainId) external pure returns (address);
     function writeFile(string calldata path, string calldata data) external;
     function writeFileBinary(string calldata path, bytes calldata data) external;
     function writeJson(string calldata json, string calldata path) external;